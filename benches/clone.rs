@@ -0,0 +1,45 @@
+//! Cost of cloning deep notation trees, whose children are shared between clones.
+
+use algebra::notation::{
+    expr::{
+        trig::{Trig, TrigFunction},
+        Expr,
+    },
+    Notation,
+};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::hint::black_box;
+
+/// A trig function nested `depth` times.
+fn nested(depth: usize) -> Notation {
+    (0..depth).fold(Notation::from(10), |arg, _| {
+        Notation::from(Trig::new(TrigFunction::Sin, arg))
+    })
+}
+
+/// Copies every node of the tree, as cloning would if children were not shared.
+fn deep_copy(value: &Notation) -> Notation {
+    match value {
+        Notation::Expr(Expr::Trig(Trig { func, arg })) => {
+            Notation::from(Trig::new(*func, deep_copy(arg)))
+        }
+        value => value.clone(),
+    }
+}
+
+fn bench_clone(c: &mut Criterion) {
+    let mut group = c.benchmark_group("clone");
+    for depth in [1, 10, 100] {
+        let tree = nested(depth);
+        group.bench_with_input(BenchmarkId::new("shared", depth), &tree, |b, tree| {
+            b.iter(|| black_box(tree).clone())
+        });
+        group.bench_with_input(BenchmarkId::new("deep", depth), &tree, |b, tree| {
+            b.iter(|| deep_copy(black_box(tree)))
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_clone);
+criterion_main!(benches);
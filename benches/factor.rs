@@ -0,0 +1,83 @@
+//! Hot paths of factoring and simplification, on inputs large enough to show their growth.
+
+use algebra::{
+    factor::{gcf, Factoring},
+    notation::{
+        expr::{radical::Radical, simplify::Simplify},
+        Notation,
+    },
+    NumericFlags,
+};
+use criterion::{criterion_group, criterion_main, BenchmarkId, Criterion};
+use std::hint::black_box;
+
+/// A prime, a highly composite number, and a large perfect square times a prime.
+const INPUTS: [i32; 3] = [1_000_003, 720_720, 998_001 * 2];
+
+fn bench_factors(c: &mut Criterion) {
+    let mut group = c.benchmark_group("factors");
+    for n in INPUTS {
+        group.bench_with_input(BenchmarkId::from_parameter(n), &n, |b, n| {
+            b.iter(|| black_box(n).factors())
+        });
+    }
+    group.finish();
+}
+
+fn bench_is_prime(c: &mut Criterion) {
+    let mut group = c.benchmark_group("is_prime");
+    for n in INPUTS {
+        group.bench_with_input(BenchmarkId::from_parameter(n), &n, |b, n| {
+            b.iter(|| black_box(n).is_prime())
+        });
+    }
+    group.finish();
+}
+
+fn bench_gcf(c: &mut Criterion) {
+    let mut group = c.benchmark_group("gcf");
+    for (a, b) in [
+        (1_000_003, 999_983),
+        (720_720, 524_288),
+        (998_001, 1_996_002),
+    ] {
+        group.bench_with_input(BenchmarkId::new(a.to_string(), b), &[a, b], |bench, ns| {
+            bench.iter(|| gcf(*black_box(ns)))
+        });
+    }
+    group.finish();
+}
+
+fn bench_radical_simplify(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Radical::simplify");
+    for n in INPUTS {
+        group.bench_with_input(BenchmarkId::from_parameter(n), &n, |b, n| {
+            b.iter(|| Radical::new(*black_box(n)).simplify())
+        });
+    }
+    group.finish();
+}
+
+fn bench_pow(c: &mut Criterion) {
+    let mut group = c.benchmark_group("pow");
+    for (base, exp) in [(2, 30), (3, 19), (-7, 11)] {
+        group.bench_with_input(
+            BenchmarkId::new(base.to_string(), exp),
+            &(base, exp),
+            |b, (base, exp)| {
+                b.iter(|| Notation::from(*black_box(base)).pow(Notation::from(*black_box(exp))))
+            },
+        );
+    }
+    group.finish();
+}
+
+criterion_group!(
+    benches,
+    bench_factors,
+    bench_is_prime,
+    bench_gcf,
+    bench_radical_simplify,
+    bench_pow
+);
+criterion_main!(benches);
@@ -0,0 +1,51 @@
+//! Simplifying large trees by cloning them first, compared to simplifying them in place.
+
+use algebra::notation::{
+    expr::{
+        radical::Radical,
+        simplify::Simplify,
+        sum::Sum,
+        trig::{Trig, TrigFunction},
+    },
+    Notation,
+};
+use criterion::{criterion_group, criterion_main, BatchSize, BenchmarkId, Criterion};
+use std::hint::black_box;
+
+/// A sum of `width` radicals, each a trig function nested `depth` times deep, so that nothing combines.
+fn tree(width: usize, depth: usize) -> Notation {
+    let term = |i: usize| {
+        (0..depth).fold(Notation::from(Radical::new(i as i32 + 2)), |arg, _| {
+            Notation::from(Trig::new(TrigFunction::Sin, arg))
+        })
+    };
+    Notation::from(Sum::new((0..width).map(term)))
+}
+
+fn bench_simplify(c: &mut Criterion) {
+    let mut group = c.benchmark_group("simplify");
+    for (width, depth) in [(10, 1), (100, 1), (10, 10)] {
+        let id = format!("{width}x{depth}");
+        group.bench_function(BenchmarkId::new("simplified", &id), |b| {
+            b.iter_batched(
+                || tree(width, depth),
+                |tree| black_box(&tree).simplified(),
+                BatchSize::SmallInput,
+            )
+        });
+        group.bench_function(BenchmarkId::new("in_place", &id), |b| {
+            b.iter_batched(
+                || tree(width, depth),
+                |mut tree| {
+                    black_box(&mut tree).simplify_in_place();
+                    tree
+                },
+                BatchSize::SmallInput,
+            )
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_simplify);
+criterion_main!(benches);
@@ -0,0 +1,191 @@
+//! Simplifies notation from the command line.
+//!
+//! ```text
+//! algebra [--decimal] [--steps] [--ascii] [NOTATION...]
+//! algebra --repl
+//! ```
+//!
+//! Notation is read from the arguments, or one per line from stdin when there are none.
+//! With `--repl`, or when run in a terminal without any notation, starts an interactive [`Repl`].
+
+use algebra::{
+    notation::{
+        expr::simplify::Simplify,
+        format::{Charset, FormatOptions, FormatWith},
+        Notation,
+    },
+    repl::Repl,
+};
+use rustyline::{
+    completion::Completer, highlight::Highlighter, hint::Hinter, history::DefaultHistory,
+    validate::Validator, Context, Editor, Helper,
+};
+use std::io::{BufRead, IsTerminal};
+
+const USAGE: &str = "\
+Usage: algebra [OPTIONS] [NOTATION...]
+
+Prints the simplest exact form of the notation.
+Reads one notation per line from stdin when none is given.
+
+Options:
+  -r, --repl     Start an interactive session, with variables
+  -d, --decimal  Also print a decimal approximation
+  -s, --steps    Print each step of simplification
+  -a, --ascii    Write symbols as plain ASCII
+  -h, --help     Print this message";
+
+/// How simplified notation is printed.
+struct Options {
+    decimal: bool,
+    steps: bool,
+    format: FormatOptions,
+}
+
+/// Simplifies the notation until it stops getting simpler, returning each form along the way.
+fn steps(value: Notation) -> Vec<Notation> {
+    // Guards against simplification which never settles
+    const LIMIT: usize = 16;
+    let mut steps = vec![value];
+    while steps.len() <= LIMIT {
+        let last = steps.last().unwrap();
+        let next = last.simplified();
+        // Rearranging, such as moving a sign to the numerator, is kept even though it isn't any simpler
+        if next.to_string() == last.to_string() || next.complexity() > last.complexity() {
+            break;
+        }
+        steps.push(next);
+    }
+    steps
+}
+
+/// Simplifies and prints one notation, or returns why it could not be read.
+fn run(input: &str, options: &Options) -> Result<(), String> {
+    let value = input
+        .parse::<Notation>()
+        .map_err(|e| format!("{input}: {e}"))?;
+    let steps = steps(value);
+    if options.steps {
+        for (i, step) in steps.iter().enumerate() {
+            println!("{i}. {}", step.format_with(&options.format));
+        }
+    } else {
+        println!("{}", steps.last().unwrap().format_with(&options.format));
+    }
+    if options.decimal {
+        let approx = match steps.last().unwrap().eval_f64() {
+            Some(approx) => approx.to_string(),
+            None => "no real value".to_string(),
+        };
+        let about = match options.format.charset {
+            Charset::Unicode => "≈",
+            Charset::Ascii => "~",
+        };
+        println!("{about} {approx}");
+    }
+    Ok(())
+}
+
+/// Lets the line editor complete the names known to the [`Repl`] with tab.
+struct ReplHelper(Repl);
+
+impl Completer for ReplHelper {
+    type Candidate = String;
+
+    fn complete(
+        &self,
+        line: &str,
+        pos: usize,
+        _: &Context<'_>,
+    ) -> rustyline::Result<(usize, Vec<String>)> {
+        let start = line[..pos]
+            .rfind(|c: char| !c.is_ascii_alphabetic())
+            .map_or(0, |i| i + 1);
+        let prefix = &line[start..pos];
+        if prefix.is_empty() {
+            return Ok((pos, Vec::new()));
+        }
+        Ok((start, self.0.completions(prefix)))
+    }
+}
+
+impl Hinter for ReplHelper {
+    type Hint = String;
+}
+
+impl Highlighter for ReplHelper {}
+
+impl Validator for ReplHelper {}
+
+impl Helper for ReplHelper {}
+
+/// Evaluates lines from the terminal until it is closed.
+fn repl() -> rustyline::Result<()> {
+    let mut editor = Editor::<ReplHelper, DefaultHistory>::new()?;
+    editor.set_helper(Some(ReplHelper(Repl::new())));
+    println!("Type \"help\" for a list of commands, or press Ctrl+D to exit.");
+    loop {
+        let line = match editor.readline("> ") {
+            Ok(line) => line,
+            Err(rustyline::error::ReadlineError::Interrupted) => continue,
+            Err(rustyline::error::ReadlineError::Eof) => return Ok(()),
+            Err(e) => return Err(e),
+        };
+        editor.add_history_entry(&line)?;
+        let repl = &mut editor.helper_mut().unwrap().0;
+        match repl.eval(&line) {
+            Ok(text) if text.is_empty() => (),
+            Ok(text) => println!("{text}"),
+            Err(e) => println!("error: {e}"),
+        }
+    }
+}
+
+fn main() {
+    let mut options = Options {
+        decimal: false,
+        steps: false,
+        format: FormatOptions::default(),
+    };
+    let mut inputs = Vec::new();
+    let mut interactive = false;
+    for arg in std::env::args().skip(1) {
+        match arg.as_str() {
+            "-d" | "--decimal" => options.decimal = true,
+            "-s" | "--steps" => options.steps = true,
+            "-a" | "--ascii" => options.format = FormatOptions::ascii(),
+            "-r" | "--repl" => interactive = true,
+            "-h" | "--help" => {
+                println!("{USAGE}");
+                return;
+            }
+            _ => inputs.push(arg),
+        }
+    }
+    if interactive || inputs.is_empty() && std::io::stdin().is_terminal() {
+        if let Err(e) = repl() {
+            eprintln!("error: {e}");
+            std::process::exit(1);
+        }
+        return;
+    }
+    if inputs.is_empty() {
+        inputs = std::io::stdin()
+            .lock()
+            .lines()
+            .map_while(Result::ok)
+            .filter(|line| !line.trim().is_empty())
+            .collect();
+    }
+
+    let mut failed = false;
+    for input in &inputs {
+        if let Err(e) = run(input, &options) {
+            eprintln!("error: {e}");
+            failed = true;
+        }
+    }
+    if failed {
+        std::process::exit(1);
+    }
+}
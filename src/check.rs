@@ -0,0 +1,251 @@
+//! Marking answers written by students against the expected answer.
+
+use crate::{
+    context::AlgebraContext,
+    notation::{
+        expr::simplify::Simplify,
+        parse::{ParseError, ParseOptions},
+        Notation,
+    },
+};
+
+/// How a submitted answer compares to the expected answer.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum AnswerVerdict {
+    /// The answer is correct and in its simplest form.
+    Equivalent,
+
+    /// The answer has the right value, but could be simplified further, such as 2/4 instead of 1/2.
+    EquivalentButNotSimplified,
+
+    /// The answer has a different value.
+    Incorrect,
+
+    /// The answer could not be read as notation.
+    Invalid(ParseError),
+}
+
+impl AnswerVerdict {
+    /// Returns true if the answer has the right value, whether or not it is simplified.
+    pub fn is_equivalent(&self) -> bool {
+        matches!(
+            self,
+            AnswerVerdict::Equivalent | AnswerVerdict::EquivalentButNotSimplified
+        )
+    }
+}
+
+impl std::fmt::Display for AnswerVerdict {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            AnswerVerdict::Equivalent => "correct".fmt(f),
+            AnswerVerdict::EquivalentButNotSimplified => "correct, but not fully simplified".fmt(f),
+            AnswerVerdict::Incorrect => "incorrect".fmt(f),
+            AnswerVerdict::Invalid(e) => format!("not understood: {e}").fmt(f),
+        }
+    }
+}
+
+/// Reads a submitted answer and compares it to the expected answer.
+///
+/// The answer is untrusted, so it is read with the limits of [`ParseOptions::untrusted`].
+/// Both are simplified before comparing, so the expected answer doesn't need to be in its simplest form.
+/// A correct answer is only [`Equivalent`][AnswerVerdict::Equivalent] if simplifying it changes nothing.
+///
+/// Terms of sums may be written in any order, as with [`eq_ac`][Notation::eq_ac()].
+///
+/// ```
+/// # use algebra::{check::{check_answer, AnswerVerdict}, notation::{Notation, expr::fraction::Fraction}};
+/// let expected = Notation::from(Fraction::new(3, 4));
+/// assert_eq!(check_answer(&expected, "3/4"), AnswerVerdict::Equivalent);
+/// assert_eq!(check_answer(&expected, "6/8"), AnswerVerdict::EquivalentButNotSimplified);
+/// assert_eq!(check_answer(&expected, "4/3"), AnswerVerdict::Incorrect);
+/// assert!(matches!(check_answer(&expected, "3/"), AnswerVerdict::Invalid(_)));
+/// ```
+pub fn check_answer(expected: &Notation, submitted: &str) -> AnswerVerdict {
+    let submitted = match Notation::parse_with(submitted, &ParseOptions::untrusted()) {
+        Ok(submitted) => submitted,
+        Err(e) => return AnswerVerdict::Invalid(e),
+    };
+    let simplified = submitted.clone().simplify_full();
+    verdict(&submitted, &simplified, &expected.clone().simplify_full())
+}
+
+/// Reads a submitted answer and compares it to the expected answer like [`check_answer`], in a context.
+///
+/// The answer is still read with the limits of [`ParseOptions::untrusted`], whatever the context's are,
+/// and its variables are interned in the context.
+/// Both are simplified with [`simplify_with_ctx`][Notation::simplify_with_ctx],
+/// so in the reals an answer with a part which isn't real, such as √-4·√-4 for -4, is [`Incorrect`][AnswerVerdict::Incorrect].
+/// If either overflows under [`OverflowPolicy::Error`][crate::context::OverflowPolicy::Error],
+/// the answer is [`Incorrect`][AnswerVerdict::Incorrect], since its exact value isn't known.
+///
+/// ```
+/// # use algebra::{check::{check_answer_with_ctx, AnswerVerdict}, context::{AlgebraContext, NumberDomain}, notation::Notation};
+/// let expected = Notation::from(-4);
+/// let real = AlgebraContext::new().with_domain(NumberDomain::Real);
+/// assert_eq!(check_answer_with_ctx(&expected, "-4", &real), AnswerVerdict::Equivalent);
+/// assert_eq!(
+///     check_answer_with_ctx(&expected, "√-4·√-4", &AlgebraContext::new()),
+///     AnswerVerdict::EquivalentButNotSimplified
+/// );
+/// assert_eq!(check_answer_with_ctx(&expected, "√-4·√-4", &real), AnswerVerdict::Incorrect);
+/// ```
+pub fn check_answer_with_ctx(
+    expected: &Notation,
+    submitted: &str,
+    ctx: &AlgebraContext,
+) -> AnswerVerdict {
+    let options = ParseOptions::untrusted();
+    let submitted = match Notation::parse_into(submitted, &options, ctx.interner()) {
+        Ok(submitted) => submitted,
+        Err(e) => return AnswerVerdict::Invalid(e),
+    };
+    match (
+        submitted.clone().simplify_with_ctx(ctx),
+        expected.clone().simplify_with_ctx(ctx),
+    ) {
+        (Ok(simplified), Ok(expected)) => verdict(&submitted, &simplified, &expected),
+        _ => AnswerVerdict::Incorrect,
+    }
+}
+
+/// Compares a submitted answer, and its simplified form, to the simplified expected answer.
+fn verdict(submitted: &Notation, simplified: &Notation, expected: &Notation) -> AnswerVerdict {
+    if !simplified.eq_ac(expected) {
+        AnswerVerdict::Incorrect
+    } else if !simplified.eq_ac(submitted) {
+        AnswerVerdict::EquivalentButNotSimplified
+    } else {
+        AnswerVerdict::Equivalent
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::{
+        atom::Atom,
+        expr::{fraction::Fraction, radical::Radical, radical_fraction::RadicalFraction},
+    };
+
+    #[test]
+    fn test_check_answer() {
+        use AnswerVerdict::*;
+        let cases: [(Notation, &str, AnswerVerdict); 10] = [
+            (Radical::new(12).into(), "2√3", Equivalent),
+            (
+                Radical::new(12).into(),
+                "sqrt(12)",
+                EquivalentButNotSimplified,
+            ),
+            (Radical::new(12).into(), "3√2", Incorrect),
+            (Fraction::new(1, -2).into(), "-1/2", Equivalent),
+            (Fraction::new(1, -2).into(), "1/2", Incorrect),
+            (Fraction::new(4, 2).into(), "2", Equivalent),
+            (
+                Fraction::new(4, 2).into(),
+                "4/2",
+                EquivalentButNotSimplified,
+            ),
+            (RadicalFraction::new(1, 3, 2).into(), " √3 / 2 ", Equivalent),
+            (Atom::Pi.into(), "pi", Equivalent),
+            (Atom::Pi.into(), "", Invalid(ParseError::Empty)),
+        ];
+        for (expected, submitted, verdict) in cases {
+            assert_eq!(
+                check_answer(&expected, submitted),
+                verdict,
+                "{submitted:?} for {expected}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_terms_in_any_order() {
+        let expected = "1+√2".parse::<Notation>().unwrap();
+        assert_eq!(check_answer(&expected, "√2+1"), AnswerVerdict::Equivalent);
+        assert_eq!(
+            check_answer(&expected, "√2+2/2"),
+            AnswerVerdict::EquivalentButNotSimplified
+        );
+        assert_eq!(check_answer(&expected, "√2+2"), AnswerVerdict::Incorrect);
+    }
+
+    #[test]
+    fn test_untrusted_answer() {
+        let expected = Notation::from(1);
+        let nested = "(".repeat(100) + "1" + &")".repeat(100);
+        assert!(matches!(
+            check_answer(&expected, &nested),
+            AnswerVerdict::Invalid(ParseError::TooDeep { .. })
+        ));
+        assert!(matches!(
+            check_answer(&expected, "10000000/10000000"),
+            AnswerVerdict::Invalid(ParseError::IntegerTooLarge { .. })
+        ));
+    }
+
+    #[test]
+    fn test_check_answer_with_ctx() {
+        use crate::context::{NumberDomain, OverflowPolicy};
+        use AnswerVerdict::*;
+        let complex = AlgebraContext::new();
+        let real = AlgebraContext::new().with_domain(NumberDomain::Real);
+        let strict = AlgebraContext::new().with_overflow(OverflowPolicy::Error);
+        let expected = Notation::from(Fraction::new(3, 4));
+        for ctx in [&complex, &real, &strict] {
+            assert_eq!(check_answer_with_ctx(&expected, "3/4", ctx), Equivalent);
+            assert_eq!(
+                check_answer_with_ctx(&expected, "6/8", ctx),
+                EquivalentButNotSimplified
+            );
+            assert_eq!(check_answer_with_ctx(&expected, "4/3", ctx), Incorrect);
+        }
+        // √-1 × √-1 is -1 in the complex numbers, but undefined in the reals
+        let expected = Notation::from(-1);
+        assert_eq!(
+            check_answer_with_ctx(&expected, "√-1·√-1", &complex),
+            EquivalentButNotSimplified
+        );
+        assert_eq!(
+            check_answer_with_ctx(&expected, "√-1·√-1", &real),
+            Incorrect
+        );
+        // Odd roots of negatives are real
+        let expected = Notation::from(-2);
+        assert_eq!(
+            check_answer_with_ctx(&expected, "(-8)^(1/3)", &real),
+            EquivalentButNotSimplified
+        );
+        // An answer which overflows has no exact value
+        let expected = Notation::from(Atom::Huge);
+        assert_eq!(
+            check_answer_with_ctx(&expected, "999999*999999*999999", &complex),
+            EquivalentButNotSimplified
+        );
+        assert_eq!(
+            check_answer_with_ctx(&expected, "999999*999999*999999", &strict),
+            Incorrect
+        );
+        // Answers are read as untrusted, and their variables interned in the context
+        let nested = "(".repeat(100) + "1" + &")".repeat(100);
+        assert!(matches!(
+            check_answer_with_ctx(&expected, &nested, &complex),
+            Invalid(ParseError::TooDeep { .. })
+        ));
+        let expected = "2x".parse::<Notation>().unwrap();
+        assert_eq!(
+            check_answer_with_ctx(&expected, "x+x", &complex),
+            EquivalentButNotSimplified
+        );
+        assert_eq!(complex.interner().len(), 1);
+    }
+
+    #[test]
+    fn test_undefined_answer() {
+        let expected = Notation::from(Fraction::new(1, 0));
+        assert_eq!(check_answer(&expected, "∅"), AnswerVerdict::Equivalent);
+        assert_eq!(check_answer(&expected, "0"), AnswerVerdict::Incorrect);
+    }
+}
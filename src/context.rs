@@ -0,0 +1,495 @@
+//! Settings and caches shared by everything working on the same problems.
+
+use std::{
+    collections::HashMap,
+    sync::{Arc, Mutex},
+};
+
+use crate::{
+    notation::{
+        atom::Atom,
+        expr::Expr,
+        format::{FormatOptions, FormatWith},
+        ops::ratio,
+        parse::{ParseError, ParseOptions},
+        Notation,
+    },
+    solve::var::{Interner, Var},
+};
+
+/// What to do when a result is too large or too small to represent exactly.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum OverflowPolicy {
+    /// Replace the value with [`Huge`][Atom::Huge] or [`Epsilon`][Atom::Epsilon], the way operators always do.
+    #[default]
+    Saturate,
+
+    /// Fail with an [`OverflowError`].
+    Error,
+}
+
+/// A result was too large or too small to represent exactly, under [`OverflowPolicy::Error`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct OverflowError {
+    /// The result, with the values that didn't fit saturated.
+    pub saturated: Notation,
+}
+
+impl std::fmt::Display for OverflowError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        format!(
+            "{} is too large or too small to represent exactly",
+            self.saturated
+        )
+        .fmt(f)
+    }
+}
+
+impl std::error::Error for OverflowError {}
+
+/// Which numbers results may be.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum NumberDomain {
+    /// Results may be complex, so √-4 simplifies to 2𝑖.
+    #[default]
+    Complex,
+
+    /// Results must be real. Anything which isn't real, such as √-4, is undefined in the reals
+    /// and simplifies to [`Atom::Complex`], which is written as ℂ to show why.
+    Real,
+}
+
+/// Settings and caches for a set of related work, such as every problem in one assignment.
+///
+/// A context is [`Send`] and [`Sync`], so one context can be shared between threads in an [`Arc`].
+/// Its caches are locked internally, and its settings are only changed while building it.
+///
+/// Functions which depend on a setting have a `_with_ctx` variant taking a context,
+/// while the plain function uses [`AlgebraContext::default`].
+///
+/// ```
+/// # use algebra::{context::{AlgebraContext, OverflowPolicy}, notation::{Notation, atom::Atom, expr::sum::Sum, format::FormatOptions}};
+/// # use std::sync::Arc;
+/// let ctx = Arc::new(
+///     AlgebraContext::new()
+///         .with_format(FormatOptions::ascii())
+///         .with_overflow(OverflowPolicy::Error),
+/// );
+/// assert_eq!(Notation::from(Atom::Pi).format_with_ctx(&ctx), "pi");
+/// assert_eq!(Notation::parse_with_ctx("2x", &ctx).unwrap().format_with_ctx(&ctx), "2*x");
+///
+/// let sum = Notation::from(Sum::new([Notation::from(i32::MAX), Notation::from(1)]));
+/// assert!(sum.simplify_with_ctx(&ctx).is_err());
+/// ```
+#[derive(Debug, Default)]
+pub struct AlgebraContext {
+    interner: Interner,
+
+    /// The proper divisors of each absolute value factored so far.
+    divisors: Mutex<HashMap<u32, Arc<[i32]>>>,
+
+    format: FormatOptions,
+    parse: ParseOptions,
+    overflow: OverflowPolicy,
+    domain: NumberDomain,
+}
+
+impl AlgebraContext {
+    /// Constructs a context with default settings and empty caches.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets how notation is written out as text.
+    pub fn with_format(self, format: FormatOptions) -> Self {
+        Self { format, ..self }
+    }
+
+    /// Sets the limits text is read within.
+    pub fn with_parse_options(self, parse: ParseOptions) -> Self {
+        Self { parse, ..self }
+    }
+
+    /// Sets what to do when a result doesn't fit.
+    pub fn with_overflow(self, overflow: OverflowPolicy) -> Self {
+        Self { overflow, ..self }
+    }
+
+    /// Sets which numbers results may be.
+    pub fn with_domain(self, domain: NumberDomain) -> Self {
+        Self { domain, ..self }
+    }
+
+    /// Returns how notation is written out as text.
+    pub fn format(&self) -> &FormatOptions {
+        &self.format
+    }
+
+    /// Returns the limits text is read within.
+    pub fn parse_options(&self) -> &ParseOptions {
+        &self.parse
+    }
+
+    /// Returns what to do when a result doesn't fit.
+    pub fn overflow(&self) -> OverflowPolicy {
+        self.overflow
+    }
+
+    /// Returns which numbers results may be.
+    pub fn domain(&self) -> NumberDomain {
+        self.domain
+    }
+
+    /// Returns the interner holding the variables made or read in this context.
+    ///
+    /// Variables are equal by name whichever interner they came from,
+    /// so this records which names the context has used rather than keeping them apart from other contexts.
+    pub fn interner(&self) -> &Interner {
+        &self.interner
+    }
+
+    /// Returns the variable named `name`, interned in this context.
+    pub fn var(&self, name: &str) -> Var {
+        self.interner.intern(name)
+    }
+
+    /// Returns the factors of `n` other than 1 and `n` itself, in ascending order,
+    /// only computing them the first time `n` or `-n` is asked for.
+    pub(crate) fn proper_divisors(&self, n: i32) -> Arc<[i32]> {
+        let key = n.unsigned_abs();
+        // Entries are only ever added, so a panic while holding the lock can't leave the cache inconsistent
+        let cached = self
+            .divisors
+            .lock()
+            .unwrap_or_else(|e| e.into_inner())
+            .get(&key)
+            .cloned();
+        cached.unwrap_or_else(|| {
+            // Factored without holding the lock, so other threads aren't kept waiting
+            let divisors = Arc::<[i32]>::from(crate::factor::proper_divisors(n));
+            self.divisors
+                .lock()
+                .unwrap_or_else(|e| e.into_inner())
+                .entry(key)
+                .or_insert(divisors)
+                .clone()
+        })
+    }
+}
+
+/// Returns the number of [`Huge`][Atom::Huge] and [`Epsilon`][Atom::Epsilon] atoms in the notation, of either sign.
+fn saturated_count(value: &Notation) -> usize {
+    let atom = |atom: &Atom| usize::from(atom.is_huge() || atom.is_epsilon());
+    match value {
+        Notation::Atom(value) => atom(value),
+        Notation::Expr(expr) => match expr {
+            Expr::Fraction(value) => atom(&value.num) + atom(&value.den),
+            Expr::Multiple(value) => {
+                atom(&value.coef.num) + atom(&value.coef.den) + atom(&value.constant)
+            }
+            Expr::Radical(_) | Expr::RadicalFraction(_) => 0,
+            Expr::Trig(value) => saturated_count(&value.arg),
+            Expr::Complex(value) => saturated_count(&value.re) + saturated_count(&value.im),
+            Expr::Sum(value) => value.terms.iter().map(saturated_count).sum(),
+            Expr::NestedFraction(value) => {
+                saturated_count(&value.num) + saturated_count(&value.den)
+            }
+            Expr::NestedRadical(value) => saturated_count(&value.rad),
+            Expr::Product(value) => value.factors.iter().map(saturated_count).sum(),
+            Expr::Power(value) => saturated_count(&value.base) + saturated_count(&value.exp),
+        },
+    }
+}
+
+/// Returns false if the node isn't a real number, such as an imaginary number or the square root of a negative,
+/// or holds [`Complex`][Atom::Complex] directly.
+///
+/// Only the node itself is checked, taking its children to be simplified and real already.
+fn is_real(node: &Notation) -> bool {
+    let Notation::Expr(expr) = node else {
+        return !matches!(node, Notation::Atom(Atom::Complex));
+    };
+    let holds_complex = node
+        .children()
+        .into_iter()
+        .any(|child| matches!(child, Notation::Atom(Atom::Complex)));
+    !holds_complex
+        && match expr {
+            Expr::Fraction(value) => !value.num.is_complex() && !value.den.is_complex(),
+            Expr::Multiple(value) => !value.constant.is_complex(),
+            Expr::Complex(value) => value.im.is_zero(),
+            Expr::Radical(value) => value.coef == 0 || value.rad >= 0,
+            Expr::RadicalFraction(value) => value.num.coef == 0 || value.num.rad >= 0,
+            Expr::NestedRadical(value) => value.coef == 0 || !value.rad.is_negative(),
+            // A negative base only has real powers which are whole numbers or have odd denominators,
+            // such as (-8)^(1/3) = -2
+            Expr::Power(value) => {
+                !value.base.is_negative() || ratio(&value.exp).is_some_and(|(_, den)| den % 2 != 0)
+            }
+            Expr::Trig(_) | Expr::Sum(_) | Expr::Product(_) | Expr::NestedFraction(_) => true,
+        }
+}
+
+impl AlgebraContext {
+    /// Simplifies a node whose children are already simplified, in the context's [`NumberDomain`].
+    ///
+    /// In the reals, a node which isn't real gives [`Complex`][Atom::Complex],
+    /// which then makes every node holding it give [`Complex`][Atom::Complex] too.
+    fn simplify_node(&self, node: Notation) -> Notation {
+        if self.domain == NumberDomain::Complex {
+            return node.simplify_node();
+        }
+        if !is_real(&node) {
+            return Notation::from(Atom::Complex);
+        }
+        match node.simplify_node() {
+            simplified if is_real(&simplified) => simplified,
+            _ => Notation::from(Atom::Complex),
+        }
+    }
+}
+
+impl Notation {
+    /// Simplifies the notation fully, following the context's [`OverflowPolicy`] and [`NumberDomain`].
+    ///
+    /// Under [`OverflowPolicy::Error`], fails if simplifying saturated any value.
+    /// Values which were already saturated beforehand aren't counted.
+    ///
+    /// Under [`NumberDomain::Real`], each part is checked as it is simplified,
+    /// and notation with any part that isn't real simplifies to [`Atom::Complex`],
+    /// even if that part would cancel out, since √-4 × √-4 is undefined in the reals.
+    ///
+    /// ```
+    /// # use algebra::{context::{AlgebraContext, NumberDomain}, notation::{Notation, atom::Atom, expr::complex::Complex}};
+    /// let complex = AlgebraContext::new();
+    /// let real = AlgebraContext::new().with_domain(NumberDomain::Real);
+    /// let root = Notation::from(-4).sqrt();
+    /// assert_eq!(root.clone().simplify_with_ctx(&complex).unwrap(), Complex::new(0, 2));
+    /// assert!(root.simplify_with_ctx(&real).unwrap().atom().is_some_and(|x| x.is_complex()));
+    /// ```
+    pub fn simplify_with_ctx(self, ctx: &AlgebraContext) -> Result<Notation, OverflowError> {
+        let simplify = |value: Notation| value.simplify_full_with(&|node| ctx.simplify_node(node));
+        match ctx.overflow {
+            OverflowPolicy::Saturate => Ok(simplify(self)),
+            OverflowPolicy::Error => {
+                let before = saturated_count(&self);
+                let simplified = simplify(self);
+                match saturated_count(&simplified) > before {
+                    true => Err(OverflowError {
+                        saturated: simplified,
+                    }),
+                    false => Ok(simplified),
+                }
+            }
+        }
+    }
+
+    /// Reads notation from text like [`parse_with`][Notation::parse_with], within the context's [`ParseOptions`],
+    /// interning its variables in the context.
+    ///
+    /// ```
+    /// # use algebra::{context::AlgebraContext, notation::{Notation, parse::{ParseError, ParseOptions}}};
+    /// let ctx = AlgebraContext::new().with_parse_options(ParseOptions::untrusted());
+    /// let value = Notation::parse_with_ctx("x + y", &ctx).unwrap();
+    /// assert_eq!(value.variables(), ["x", "y"]);
+    /// assert_eq!(ctx.interner().len(), 2);
+    /// assert!(matches!(
+    ///     Notation::parse_with_ctx("10000000", &ctx),
+    ///     Err(ParseError::IntegerTooLarge { .. })
+    /// ));
+    /// ```
+    pub fn parse_with_ctx(s: &str, ctx: &AlgebraContext) -> Result<Notation, ParseError> {
+        Notation::parse_into(s, &ctx.parse, &ctx.interner)
+    }
+
+    /// Writes the notation as text in the context's display style.
+    pub fn format_with_ctx(&self, ctx: &AlgebraContext) -> String {
+        self.format_with(&ctx.format)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        factor::{factors_with_ctx, Factoring},
+        notation::expr::{fraction::Fraction, simplify::Simplify, sum::Sum},
+    };
+
+    #[test]
+    fn test_shared_between_threads() {
+        let ctx = Arc::new(AlgebraContext::new());
+        let handles = (0..8)
+            .map(|i| {
+                let ctx = Arc::clone(&ctx);
+                std::thread::spawn(move || {
+                    let var = ctx.var("shared");
+                    let factors = factors_with_ctx(360 * (i % 2 + 1), &ctx).len();
+                    (var, factors)
+                })
+            })
+            .collect::<Vec<_>>();
+        for (i, handle) in handles.into_iter().enumerate() {
+            let (var, factors) = handle.join().unwrap();
+            assert_eq!(var, "shared");
+            assert_eq!(factors, (360 * (i as i32 % 2 + 1)).count_factors());
+        }
+        assert_eq!(ctx.interner().len(), 1);
+        assert_eq!(ctx.divisors.lock().unwrap().len(), 2);
+    }
+
+    #[test]
+    fn test_divisor_cache() {
+        let ctx = AlgebraContext::new();
+        for n in [-100, 0, 1, 12, 97, 100, i32::MAX] {
+            let expected = n.factors();
+            let factors = factors_with_ctx(n, &ctx);
+            assert_eq!(factors.len(), expected.len(), "{n}");
+            for (a, b) in factors.iter().zip(&expected) {
+                assert_eq!((a.common, a.associated), (b.common, b.associated));
+            }
+        }
+        // -100 and 100 share an entry
+        assert_eq!(ctx.divisors.lock().unwrap().len(), 6);
+    }
+
+    #[test]
+    fn test_overflow_policy() {
+        let strict = AlgebraContext::new().with_overflow(OverflowPolicy::Error);
+        let lenient = AlgebraContext::new();
+        let sum = Notation::from(Sum::new([Notation::from(i32::MAX), Notation::from(1)]));
+        assert_eq!(lenient.overflow(), OverflowPolicy::Saturate);
+        let is_huge = |value: &Notation| matches!(value, Notation::Atom(x) if x.is_huge());
+        assert!(sum
+            .clone()
+            .simplify_with_ctx(&lenient)
+            .is_ok_and(|value| is_huge(&value)));
+        assert!(sum
+            .simplify_with_ctx(&strict)
+            .is_err_and(|e| is_huge(&e.saturated)));
+        let fits = Notation::from(Fraction::new(6, 4));
+        assert_eq!(
+            fits.clone().simplify_with_ctx(&strict),
+            Ok(fits.simplify_full())
+        );
+        // Saturated before simplifying isn't an overflow
+        let already = Notation::from(Atom::Huge);
+        assert!(already
+            .simplify_with_ctx(&strict)
+            .is_ok_and(|value| is_huge(&value)));
+    }
+
+    #[test]
+    fn test_number_domain() {
+        use crate::notation::expr::{
+            complex::Complex, nested_radical::NestedRadical, power::Power, radical::Radical,
+            radical_fraction::RadicalFraction,
+        };
+        let real = AlgebraContext::new().with_domain(NumberDomain::Real);
+        let complex = AlgebraContext::new();
+        assert_eq!(complex.domain(), NumberDomain::Complex);
+        let not_real = |value: Notation| {
+            value
+                .simplify_with_ctx(&real)
+                .is_ok_and(|x| x.atom().is_some_and(|x| x.is_complex()))
+        };
+        // Every way of writing √-4 agrees
+        let roots = [
+            Notation::from(Radical::new(-4)),
+            Notation::from(NestedRadical::new(-4)),
+            Notation::from(NestedRadical::new(Notation::from(1) - Notation::from(5))),
+            Notation::from(RadicalFraction::new(2, -4, 2)),
+        ];
+        for root in roots {
+            assert_eq!(
+                root.clone().simplify_with_ctx(&complex),
+                Ok(Notation::from(Complex::new(0, 2))),
+                "{root}"
+            );
+            assert!(not_real(root.clone()), "{root}");
+        }
+        // Undefined in the reals even though it cancels out to 0
+        let cancels = Notation::from(Sum::new([
+            Radical::new(-1).into(),
+            Complex::new(0, -1).into(),
+        ]));
+        assert_eq!(
+            cancels.clone().simplify_with_ctx(&complex),
+            Ok(Notation::from(0))
+        );
+        assert!(not_real(cancels));
+        assert!(not_real(Notation::from(Complex::i())));
+        // Parts which aren't real are found as they are simplified, wherever they are
+        let nested = Notation::from(Sum::new([
+            Notation::from(crate::notation::expr::trig::Trig::new(
+                crate::notation::expr::trig::TrigFunction::Sin,
+                Radical::new(-4),
+            )),
+            Notation::from(1),
+        ]));
+        assert!(not_real(nested));
+        assert!(not_real(
+            Notation::from(-4).pow(Notation::from(Fraction::new(1, 2)))
+        ));
+        // Odd roots of negatives are real
+        let cube_root = Notation::from(Power::new(-8, Fraction::new(1, 3)));
+        assert_eq!(
+            cube_root.clone().simplify_with_ctx(&real),
+            Ok(Notation::from(-2))
+        );
+        assert_eq!(
+            cube_root.simplify_with_ctx(&complex),
+            Ok(Notation::from(-2))
+        );
+        // Real values are unaffected
+        for value in [
+            Notation::from(Radical::new(8)),
+            Notation::from(Fraction::new(-6, 4)),
+            Notation::from(Complex::new(3, 0)),
+            Notation::from(Radical { coef: 0, rad: -4 }),
+        ] {
+            assert_eq!(
+                value.clone().simplify_with_ctx(&real),
+                Ok(value.simplify_full())
+            );
+        }
+        // Overflow is still reported
+        let strict = AlgebraContext::new()
+            .with_domain(NumberDomain::Real)
+            .with_overflow(OverflowPolicy::Error);
+        let sum = Notation::from(Sum::new([Notation::from(i32::MAX), Notation::from(1)]));
+        assert!(sum.simplify_with_ctx(&strict).is_err());
+    }
+
+    #[test]
+    fn test_parse() {
+        let ctx = AlgebraContext::new().with_parse_options(ParseOptions::untrusted());
+        assert_eq!(ctx.parse_options(), &ParseOptions::untrusted());
+        assert_eq!(
+            AlgebraContext::new().parse_options(),
+            &ParseOptions::default()
+        );
+        let value = Notation::parse_with_ctx("2x + y·x", &ctx).unwrap();
+        assert_eq!(Some(value), "2x + y·x".parse().ok());
+        assert_eq!(ctx.interner().len(), 2);
+        assert!(matches!(
+            Notation::parse_with_ctx("10000000", &ctx),
+            Err(ParseError::IntegerTooLarge { .. })
+        ));
+        assert_eq!(
+            Notation::parse_with_ctx("10000000", &AlgebraContext::new()),
+            Ok(Notation::from(10000000))
+        );
+    }
+
+    #[test]
+    fn test_format() {
+        let ctx = AlgebraContext::new().with_format(FormatOptions::ascii());
+        let value = Notation::from(crate::notation::expr::radical::Radical::new(2));
+        assert_eq!(value.format_with_ctx(&ctx), value.format_with(ctx.format()));
+        assert_eq!(
+            value.format_with_ctx(&AlgebraContext::new()),
+            value.to_string()
+        );
+    }
+}
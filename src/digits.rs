@@ -0,0 +1,289 @@
+//! Digits of integers in any base, and the divisibility rules which use them.
+
+use crate::notation::atom::number::Number;
+
+/// Returns the decimal digits of the absolute value of `n`, most significant first.
+///
+/// 0 has the single digit 0.
+///
+/// ```
+/// # use algebra::digits::digits;
+/// assert_eq!(digits(342), [3, 4, 2]);
+/// assert_eq!(digits(-70), [7, 0]);
+/// assert_eq!(digits(0), [0]);
+/// ```
+pub fn digits(n: i32) -> Vec<u8> {
+    let mut rest = n.unsigned_abs();
+    let mut digits = Vec::new();
+    loop {
+        digits.push((rest % 10) as u8);
+        rest /= 10;
+        if rest == 0 {
+            break;
+        }
+    }
+    digits.reverse();
+    digits
+}
+
+/// Writes `n` in base `radix`, using the lowercase letters `a` through `z` for digits above 9.
+///
+/// The same as displaying [`Number::in_base`].
+///
+/// # Panics
+/// Panics if `radix` is not from 2 to 36.
+///
+/// ```
+/// # use algebra::digits::to_base;
+/// assert_eq!(to_base(10, 2), "1010");
+/// assert_eq!(to_base(-255, 16), "-ff");
+/// ```
+pub fn to_base(n: i32, radix: u32) -> String {
+    Number::from(n).in_base(radix).to_string()
+}
+
+/// Reads a number written in base `radix`, accepting letters of either case for digits above 9,
+/// along with an optional leading `+` or `-`.
+///
+/// # Panics
+/// Panics if `radix` is not from 2 to 36.
+///
+/// ```
+/// # use algebra::digits::from_base;
+/// assert_eq!(from_base("1010", 2), Ok(10));
+/// assert_eq!(from_base("-FF", 16), Ok(-255));
+/// assert!(from_base("12", 2).is_err());
+/// ```
+pub fn from_base(s: &str, radix: u32) -> Result<i32, std::num::ParseIntError> {
+    assert!((2..=36).contains(&radix), "radix must be from 2 to 36");
+    i32::from_str_radix(s, radix)
+}
+
+/// Returns the sum of the decimal digits of the absolute value of `n`.
+///
+/// ```
+/// # use algebra::digits::digit_sum;
+/// assert_eq!(digit_sum(342), 9);
+/// assert_eq!(digit_sum(-99), 18);
+/// ```
+pub fn digit_sum(n: i32) -> u32 {
+    digits(n).into_iter().map(u32::from).sum()
+}
+
+/// Returns the sum of the decimal digits of the absolute value of `n` with alternating signs,
+/// starting with a positive ones digit.
+fn alternating_digit_sum(n: i32) -> i32 {
+    digits(n)
+        .into_iter()
+        .rev()
+        .enumerate()
+        .map(|(i, digit)| match i % 2 {
+            0 => digit as i32,
+            _ => -(digit as i32),
+        })
+        .sum()
+}
+
+/// Returns the number formed by the last `count` decimal digits of the absolute value of `n`.
+fn last_digits(n: i32, count: u32) -> u32 {
+    n.unsigned_abs() % 10u32.pow(count)
+}
+
+/// Describes whether `value` is divisible by `d`, as in "18 is divisible by 9".
+fn divisible(value: impl std::fmt::Display, is_divisible: bool, d: u32) -> String {
+    match is_divisible {
+        true => format!("{value} is divisible by {d}"),
+        false => format!("{value} is not divisible by {d}"),
+    }
+}
+
+/// Tests whether `n` is divisible by `d`, returning the verdict with an explanation of why.
+///
+/// Divisors with a well-known rule (1 through 6, 8 through 12, and 25) are explained using the digits of `n`,
+/// the way the rule would be checked by hand.
+/// Other divisors are explained by dividing, as in "100 = 7 × 14 + 2".
+///
+/// Only the absolute values matter, since negating either number doesn't change whether it divides the other.
+/// Nothing is divisible by 0.
+///
+/// ```
+/// # use algebra::digits::is_divisible_by_rule;
+/// assert_eq!(is_divisible_by_rule(1233, 9), (true, "digit sum 9 is divisible by 9".to_string()));
+/// assert_eq!(is_divisible_by_rule(1236, 4), (true, "last two digits 36 are divisible by 4".to_string()));
+/// assert_eq!(is_divisible_by_rule(97, 5), (false, "last digit 7 is not 0 or 5".to_string()));
+/// ```
+pub fn is_divisible_by_rule(n: i32, d: i32) -> (bool, String) {
+    let d = d.unsigned_abs();
+    let last = |count| last_digits(n, count);
+    match d {
+        0 => (false, "nothing is divisible by 0".to_string()),
+        1 => (true, "every integer is divisible by 1".to_string()),
+        2 => {
+            let is_even = last(1) % 2 == 0;
+            let parity = if is_even { "even" } else { "odd" };
+            (is_even, format!("last digit {} is {parity}", last(1)))
+        }
+        3 | 9 => {
+            let sum = digit_sum(n);
+            let is_divisible = sum.is_multiple_of(d);
+            (
+                is_divisible,
+                format!("digit sum {}", divisible(sum, is_divisible, d)),
+            )
+        }
+        4 | 8 => {
+            // 100 is divisible by 4, and 1000 by 8
+            let (count, words) = match d {
+                4 => (2, "two"),
+                _ => (3, "three"),
+            };
+            let ending = last(count);
+            let is_divisible = ending % d == 0;
+            let verb = if is_divisible { "are" } else { "are not" };
+            (
+                is_divisible,
+                format!("last {words} digits {ending} {verb} divisible by {d}"),
+            )
+        }
+        5 => {
+            let is_divisible = last(1) % 5 == 0;
+            let verb = if is_divisible { "is" } else { "is not" };
+            (
+                is_divisible,
+                format!("last digit {} {verb} 0 or 5", last(1)),
+            )
+        }
+        10 => {
+            let is_divisible = last(1) == 0;
+            let verb = if is_divisible { "is" } else { "is not" };
+            (is_divisible, format!("last digit {} {verb} 0", last(1)))
+        }
+        11 => {
+            let sum = alternating_digit_sum(n);
+            let is_divisible = sum % 11 == 0;
+            (
+                is_divisible,
+                format!("alternating digit sum {}", divisible(sum, is_divisible, 11)),
+            )
+        }
+        25 => {
+            let ending = last(2);
+            let is_divisible = ending % 25 == 0;
+            let verb = if is_divisible { "are" } else { "are not" };
+            (
+                is_divisible,
+                format!("last two digits {ending:02} {verb} 00, 25, 50, or 75"),
+            )
+        }
+        // Divisible by both of a pair of coprime factors
+        6 | 12 => {
+            let (a, b) = (d / 3, 3);
+            let ((a_verdict, a_reason), (b_verdict, b_reason)) = (
+                is_divisible_by_rule(n, a as i32),
+                is_divisible_by_rule(n, b),
+            );
+            let (is_divisible, joiner) = match (a_verdict, b_verdict) {
+                (true, true) => (true, "and"),
+                (false, false) => (false, "and"),
+                _ => (false, "but"),
+            };
+            (is_divisible, format!("{a_reason} {joiner} {b_reason}"))
+        }
+        _ => {
+            let (n, d) = (n.unsigned_abs(), d);
+            let (quotient, remainder) = (n / d, n % d);
+            match remainder {
+                0 => (true, format!("{n} = {d} × {quotient}")),
+                _ => (false, format!("{n} = {d} × {quotient} + {remainder}")),
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_digits() {
+        assert_eq!(digits(7), [7]);
+        assert_eq!(digits(1_000_000), [1, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(digits(i32::MAX), [2, 1, 4, 7, 4, 8, 3, 6, 4, 7]);
+        assert_eq!(digits(i32::MIN), [2, 1, 4, 7, 4, 8, 3, 6, 4, 8]);
+        assert_eq!(digit_sum(i32::MIN), 47);
+        assert_eq!(digit_sum(0), 0);
+    }
+
+    #[test]
+    fn test_bases() {
+        for radix in 2..=36 {
+            for n in (-100..=100).chain([i32::MAX, i32::MIN]) {
+                let text = to_base(n, radix);
+                assert_eq!(from_base(&text, radix), Ok(n), "{n} in base {radix}");
+                assert_eq!(from_base(&text.to_uppercase(), radix), Ok(n));
+            }
+        }
+        assert_eq!(to_base(i32::MIN, 2), format!("-1{}", "0".repeat(31)));
+        assert_eq!(to_base(0, 7), "0");
+        assert_eq!(to_base(i32::MAX, 36), "zik0zj");
+        assert!(from_base("", 10).is_err());
+        assert!(from_base("2147483648", 10).is_err());
+    }
+
+    #[test]
+    fn test_display_in_base() {
+        let number = |value: i32| Number::from(value);
+        assert_eq!(format!("{:#}", number(5).in_base(2)), "0b101");
+        assert_eq!(format!("{:#}", number(8).in_base(8)), "0o10");
+        assert_eq!(format!("{:#}", number(-1).in_base(16)), "-0x1");
+        assert_eq!(format!("{:#}", number(12).in_base(12)), "10");
+        assert_eq!(format!("{:>6}", number(255).in_base(16)), "    ff");
+    }
+
+    #[test]
+    #[should_panic(expected = "radix must be from 2 to 36")]
+    fn test_invalid_radix() {
+        to_base(1, 37);
+    }
+
+    #[test]
+    fn test_verdicts() {
+        for d in -30..=30 {
+            for n in (-500..=500).chain([i32::MAX, i32::MIN, i32::MIN + 1]) {
+                let expected = d != 0 && n as i64 % d as i64 == 0;
+                let (verdict, reason) = is_divisible_by_rule(n, d);
+                assert_eq!(verdict, expected, "{n} by {d}: {reason}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_reasons() {
+        let reason = |n, d| is_divisible_by_rule(n, d).1;
+        assert_eq!(reason(738, 9), "digit sum 18 is divisible by 9");
+        assert_eq!(reason(-73, 3), "digit sum 10 is not divisible by 3");
+        assert_eq!(reason(14, 2), "last digit 4 is even");
+        assert_eq!(reason(1030, 4), "last two digits 30 are not divisible by 4");
+        assert_eq!(reason(17_016, 8), "last three digits 16 are divisible by 8");
+        assert_eq!(reason(90, 10), "last digit 0 is 0");
+        assert_eq!(
+            reason(918_082, 11),
+            "alternating digit sum -22 is divisible by 11"
+        );
+        assert_eq!(
+            reason(1_405, 25),
+            "last two digits 05 are not 00, 25, 50, or 75"
+        );
+        assert_eq!(
+            reason(34, 6),
+            "last digit 4 is even but digit sum 7 is not divisible by 3"
+        );
+        assert_eq!(
+            reason(132, -12),
+            "last two digits 32 are divisible by 4 and digit sum 6 is divisible by 3"
+        );
+        assert_eq!(reason(100, 7), "100 = 7 × 14 + 2");
+        assert_eq!(reason(-91, 7), "91 = 7 × 13");
+        assert_eq!(reason(5, 0), "nothing is divisible by 0");
+    }
+}
@@ -1,6 +1,15 @@
 //! Functions related to factoring numbers.
 
-use crate::notation::atom::Atom;
+use std::collections::HashMap;
+
+use crate::{
+    context::AlgebraContext,
+    notation::{
+        atom::{number::Number, Atom},
+        expr::{complex::Complex, simplify::Simplify},
+        Notation,
+    },
+};
 
 /// A single factor of a number.
 ///
@@ -50,11 +59,12 @@ pub trait Factoring: Sized {
     /// If you need to use the factors anyway, find the [`len`][Vec::len] of [`factors`][Factoring::factors] instead.
     fn count_factors(&self) -> usize;
 
-    /// Returns true if the number is composite, false if it is prime.
+    /// Returns true if the absolute value of the number has a factor other than 1 and itself.
     ///
     /// Employs logical short-circuiting, stopping on the first factor that isn't 1.
     ///
-    /// Used in [`is_prime`][crate::NumericFlags::is_prime()].
+    /// Used in [`is_prime`][crate::NumericFlags::is_prime()] and [`is_composite`][crate::NumericFlags::is_composite()],
+    /// which only apply to natural numbers.
     fn has_multiple_factors(&self) -> bool;
 }
 
@@ -81,50 +91,68 @@ impl Factoring for i32 {
             associated: *self,
         }]);
 
-        let abs_n = self.abs();
-
-        // Potential factor
-        for pot_fac in 2..abs_n {
-            if pot_fac.is_factor_of(abs_n) {
-                let fac = pot_fac; // Confirmed
-                factors.push(Factor {
-                    common: fac,
-                    associated: self / fac,
-                });
-            }
-        }
+        factors.extend(proper_divisors(*self).into_iter().map(|fac| Factor {
+            common: fac,
+            associated: self / fac,
+        }));
 
         factors
     }
 
     fn count_factors(&self) -> usize {
-        let abs_n = self.abs();
-
-        let mut count = 1; // 1 is always a factor.
-
-        // Potential factor
-        for pot_fac in 2..abs_n {
-            if pot_fac.is_factor_of(abs_n) {
-                count += 1;
-            }
-        }
-
-        count
+        1 + proper_divisors(*self).len() // 1 is always a factor.
     }
 
     fn has_multiple_factors(&self) -> bool {
-        let abs_n = self.abs();
-
-        for fac in 2..abs_n {
-            if fac.is_factor_of(abs_n) {
-                return true;
-            }
-        }
+        let abs_n = self.unsigned_abs();
 
-        false
+        // Any factor above the square root is paired with one below it
+        (2..=abs_n.isqrt()).any(|fac| abs_n.is_multiple_of(fac))
     }
 }
 
+/// Returns the factors of `n` other than 1 and `n` itself, in ascending order.
+///
+/// Only tests up to the square root of `n`, pairing each factor found with the factor associated with it.
+pub(crate) fn proper_divisors(n: i32) -> Vec<i32> {
+    // Unsigned, so that the size of i32::MIN can be found; its proper divisors all fit in an i32
+    let abs_n = n.unsigned_abs();
+    let small = (2..=abs_n.isqrt())
+        .filter(|fac| abs_n.is_multiple_of(*fac))
+        .collect::<Vec<_>>();
+    let large = small
+        .iter()
+        .rev()
+        .filter(|&&fac| fac * fac != abs_n) // A square root is only one factor
+        .map(|fac| abs_n / fac)
+        .collect::<Vec<_>>();
+    [small, large]
+        .concat()
+        .into_iter()
+        .map(|fac| fac as i32)
+        .collect()
+}
+
+/// Returns all factors of `n`, like [`factors`][Factoring::factors], caching its divisors in the context.
+///
+/// Factoring the same number, or its negative, again in the same context doesn't repeat the search.
+///
+/// ```
+/// # use algebra::{context::AlgebraContext, factor::factors_with_ctx};
+/// let ctx = AlgebraContext::new();
+/// let commons = factors_with_ctx(12, &ctx).iter().map(|f| f.common).collect::<Vec<_>>();
+/// assert_eq!(commons, [1, 2, 3, 4, 6]);
+/// ```
+pub fn factors_with_ctx(n: i32, ctx: &AlgebraContext) -> Vec<Factor> {
+    std::iter::once(1)
+        .chain(ctx.proper_divisors(n).iter().copied())
+        .map(|fac| Factor {
+            common: fac,
+            associated: n / fac,
+        })
+        .collect()
+}
+
 /// Given a set of numbers, returns the factors shared between them.
 pub fn common_factors<const COUNT: usize>(ns: [i32; COUNT]) -> Vec<CommonFactor<COUNT>> {
     assert!(COUNT > 0, "Empty set has no factors.");
@@ -150,19 +178,26 @@ pub fn common_factors<const COUNT: usize>(ns: [i32; COUNT]) -> Vec<CommonFactor<
 }
 
 /// Returns the Greatest Common Factor of the provided numbers.
+///
+/// Every number is a factor of 0, so zeros don't affect the result, except that the GCF of only zeros is 0.
+///
+/// ```
+/// # use algebra::factor::gcf;
+/// assert_eq!(gcf([12, 18]), 6);
+/// assert_eq!(gcf([-12, 18, 8]), 2);
+/// assert_eq!(gcf([7, 0]), 7);
+/// ```
 pub fn gcf<const COUNT: usize>(ns: [i32; COUNT]) -> i32 {
     assert!(COUNT > 0, "Empty set has no factors.");
 
-    let abs_ns = ns.map(|x| x.abs());
-    let n_min = *abs_ns.iter().min().unwrap();
-
-    for gcf in (2..=n_min).rev() {
-        if gcf.is_common_factor_of(&abs_ns) {
-            return gcf;
+    // Euclid's algorithm: gcf(a, b) = gcf(b, a mod b), and gcf(a, 0) = a
+    ns.iter().fold(0, |mut a, n| {
+        let mut b = n.abs();
+        while b != 0 {
+            (a, b) = (b, a % b);
         }
-    }
-
-    1 // 1 is a factor of every number, so we don't need to bother testing `is_factor_of` on it.
+        a
+    })
 }
 
 /// Returns the Least Common Multiple of the provided numbers.
@@ -215,9 +250,327 @@ pub fn lcm<const COUNT: usize>(ns: [i32; COUNT]) -> Atom {
     Atom::from(prod)
 }
 
+// # Primes
+
+/// Iterator over the primes in increasing order, produced by [`primes()`].
+///
+/// An incremental sieve of Eratosthenes: each prime found so far is stored under its next odd multiple,
+/// so checking a number only looks at the primes which divide it rather than dividing by every smaller prime.
+#[derive(Debug, Clone)]
+pub struct Primes {
+    /// The next number to check, or [`None`] once past the largest [`i32`].
+    next: Option<i64>,
+
+    /// The primes dividing each upcoming odd composite, keyed by the composite.
+    composites: HashMap<i64, Vec<i64>>,
+}
+
+impl Iterator for Primes {
+    type Item = i32;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let n = self.next?;
+            // 2 is the only even prime, so only odd numbers are checked after it
+            self.next = match n {
+                2 => Some(3),
+                _ => Some(n + 2).filter(|&next| next <= i32::MAX as i64),
+            };
+            if n == 2 {
+                return Some(2);
+            }
+            match self.composites.remove(&n) {
+                Some(factors) => {
+                    for p in factors {
+                        // Even multiples are skipped
+                        self.composites.entry(n + 2 * p).or_default().push(p);
+                    }
+                }
+                None => {
+                    // Smaller multiples of a prime have a smaller prime factor
+                    self.composites.entry(n * n).or_default().push(n);
+                    return Some(n as i32);
+                }
+            }
+        }
+    }
+}
+
+/// Returns every prime in increasing order, without an upper bound to decide on beforehand.
+///
+/// Ends after 2147483647, the largest prime which fits in an [`i32`].
+///
+/// ```
+/// # use algebra::factor::primes;
+/// assert_eq!(primes().take(6).collect::<Vec<_>>(), [2, 3, 5, 7, 11, 13]);
+/// assert_eq!(primes().find(|p| *p > 1000), Some(1009));
+/// ```
+pub fn primes() -> Primes {
+    Primes {
+        next: Some(2),
+        composites: HashMap::new(),
+    }
+}
+
+/// Returns the `n`th prime, counting 2 as the 1st.
+///
+/// Returns [`None`] for the 0th prime, and for primes too large for an [`i32`].
+///
+/// ```
+/// # use algebra::factor::nth_prime;
+/// assert_eq!(nth_prime(1), Some(2));
+/// assert_eq!(nth_prime(100), Some(541));
+/// assert_eq!(nth_prime(0), None);
+/// ```
+pub fn nth_prime(n: usize) -> Option<i32> {
+    primes().nth(n.checked_sub(1)?)
+}
+
+/// Returns every prime less than `n` in increasing order, by the sieve of Eratosthenes.
+///
+/// Faster than [`primes()`] when the bound is known, but uses a byte of memory for each odd number below `n`.
+///
+/// ```
+/// # use algebra::factor::primes_below;
+/// assert_eq!(primes_below(20), [2, 3, 5, 7, 11, 13, 17, 19]);
+/// assert!(primes_below(2).is_empty());
+/// ```
+pub fn primes_below(n: i32) -> Vec<i32> {
+    if n <= 2 {
+        return Vec::new();
+    }
+    // Index `i` is the odd number 2i + 1
+    let len = n as usize / 2;
+    let mut is_prime = vec![true; len];
+    is_prime[0] = false;
+    let mut i = 1;
+    while (2 * i + 1) * (2 * i + 1) < n as usize {
+        if is_prime[i] {
+            let p = 2 * i + 1;
+            for j in (p * p / 2..len).step_by(p) {
+                is_prime[j] = false;
+            }
+        }
+        i += 1;
+    }
+    let odd_primes = (0..len).filter(|&i| is_prime[i]).map(|i| 2 * i as i32 + 1);
+    std::iter::once(2).chain(odd_primes).collect()
+}
+
+// # Sums of two squares
+
+/// Returns non-negative integers `(a, b)` with `a ≤ b` such that `a² + b² = n`, using the smallest such `a`.
+///
+/// Returns [`None`] if `n` is negative or isn't a sum of two squares.
+///
+/// ```
+/// # use algebra::factor::sum_of_two_squares;
+/// assert_eq!(sum_of_two_squares(25), Some((0, 5)));
+/// assert_eq!(sum_of_two_squares(65), Some((1, 8)));
+/// assert_eq!(sum_of_two_squares(21), None);
+/// ```
+pub fn sum_of_two_squares(n: i32) -> Option<(i32, i32)> {
+    if n < 0 {
+        return None;
+    }
+    (0..=(n / 2).isqrt()).find_map(|a| {
+        let b = (n - a * a).isqrt();
+        (a * a + b * b == n).then_some((a, b))
+    })
+}
+
+// # Gaussian integers
+
+/// A Gaussian integer `a + b𝑖` as its parts, wide enough that norms and products of [`i32`] parts don't overflow.
+type Gaussian = (i128, i128);
+
+/// The square of the distance from 0, `a² + b²`.
+fn norm((a, b): Gaussian) -> i128 {
+    a * a + b * b
+}
+
+fn gaussian_mul((a, b): Gaussian, (c, d): Gaussian) -> Gaussian {
+    (a * c - b * d, a * d + b * c)
+}
+
+/// Divides `lhs` by `rhs`, rounding each part to the nearest integer.
+///
+/// The remainder `lhs - rhs × quotient` always has a smaller norm than `rhs`.
+fn gaussian_div_round(lhs: Gaussian, (c, d): Gaussian) -> Gaussian {
+    let n = norm((c, d));
+    let (a, b) = gaussian_mul(lhs, (c, -d));
+    let round = |x: i128| (2 * x + n).div_euclid(2 * n);
+    (round(a), round(b))
+}
+
+/// Returns `lhs / rhs` if `rhs` is a factor of `lhs` over the Gaussian integers.
+fn gaussian_div_exact(lhs: Gaussian, rhs: Gaussian) -> Option<Gaussian> {
+    let quotient = gaussian_div_round(lhs, rhs);
+    (gaussian_mul(quotient, rhs) == lhs).then_some(quotient)
+}
+
+/// Greatest common divisor by Euclid's algorithm, which works the same way for Gaussian integers.
+fn gaussian_gcd(mut a: Gaussian, mut b: Gaussian) -> Gaussian {
+    while b != (0, 0) {
+        let q = gaussian_div_round(a, b);
+        let (qb_re, qb_im) = gaussian_mul(q, b);
+        (a, b) = (b, (a.0 - qb_re, a.1 - qb_im));
+    }
+    a
+}
+
+/// `base^exp mod modulus`, by repeated squaring.
+fn pow_mod(mut base: i128, mut exp: i128, modulus: i128) -> i128 {
+    let mut result = 1;
+    base %= modulus;
+    while exp > 0 {
+        if exp & 1 == 1 {
+            result = result * base % modulus;
+        }
+        base = base * base % modulus;
+        exp >>= 1;
+    }
+    result
+}
+
+/// Splits a prime `p ≡ 1 (mod 4)` into a Gaussian prime `x + y𝑖` with `x² + y² = p`.
+///
+/// Finds `t` with `t² ≡ -1 (mod p)`, so that `p` divides `(t + 𝑖)(t - 𝑖)`;
+/// their common factor with `p` is then one of its two Gaussian prime factors.
+fn split_prime(p: i128) -> Gaussian {
+    // Half of all nonzero numbers are non-residues, and c^((p - 1)/4) of any of them squares to -1
+    let t = (2..p)
+        .map(|c| pow_mod(c, (p - 1) / 4, p))
+        .find(|t| t * t % p == p - 1)
+        .expect("a prime of the form 4k + 1 has a square root of -1");
+    gaussian_gcd((p, 0), (t, 1))
+}
+
+/// Multiplies the Gaussian integer by a unit so that it lies in the first quadrant,
+/// with a positive real part and a non-negative imaginary part.
+///
+/// Returns the unit it was multiplied by along with the result.
+fn first_quadrant(mut value: Gaussian) -> (Gaussian, Gaussian) {
+    let mut unit = (1, 0);
+    while !(value.0 > 0 && value.1 >= 0) {
+        // Rotate a quarter turn by multiplying by 𝑖
+        value = (-value.1, value.0);
+        unit = (-unit.1, unit.0);
+    }
+    (unit, value)
+}
+
+/// If both parts of the complex number simplify to integers, returns them.
+fn gaussian_parts(value: &Complex) -> Option<Gaussian> {
+    let integer = |part: &Notation| part.clone().simplify();
+    match (integer(&value.re), integer(&value.im)) {
+        (
+            Notation::Atom(Atom::Number(Number { value: a })),
+            Notation::Atom(Atom::Number(Number { value: b })),
+        ) => Some((a as i128, b as i128)),
+        _ => None,
+    }
+}
+
+fn to_complex((a, b): Gaussian) -> Option<Complex> {
+    Some(Complex::new(i32::try_from(a).ok()?, i32::try_from(b).ok()?))
+}
+
+/// Factors a Gaussian integer `a + b𝑖`, whose parts are integers, into Gaussian primes.
+///
+/// Returns a unit (1, -1, 𝑖, or -𝑖) and the prime factors in order of increasing norm, repeated by multiplicity,
+/// such that the unit times every factor is the number.
+/// Each factor is in the first quadrant, with a positive real part and a non-negative imaginary part.
+///
+/// Returns [`None`] if either part isn't an integer, if the number is 0, or if a factor doesn't fit in an [`i32`].
+///
+/// Uses trial division on the norm `a² + b²`, so it is slow when the norm has a very large prime factor.
+///
+/// ```
+/// # use algebra::{factor::gaussian_factors, notation::expr::complex::Complex};
+/// // 5 = -𝑖(1 + 2𝑖)(2 + 𝑖)
+/// let (unit, factors) = gaussian_factors(&Complex::from(5)).unwrap();
+/// assert_eq!(unit, Complex::new(0, -1));
+/// assert_eq!(factors, [Complex::new(1, 2), Complex::new(2, 1)]);
+/// ```
+pub fn gaussian_factors(value: &Complex) -> Option<(Complex, Vec<Complex>)> {
+    let mut rest = gaussian_parts(value)?;
+    if rest == (0, 0) {
+        return None;
+    }
+
+    let mut factors = Vec::new();
+    let mut p = 2;
+    while norm(rest) > 1 {
+        let n = norm(rest);
+        // The last prime factor of the norm may be larger than its square root
+        if p * p > n {
+            p = n;
+        }
+        if n % p != 0 {
+            p += 1;
+            continue;
+        }
+        let factor = match p % 4 {
+            // 2 = -𝑖(1 + 𝑖)²
+            2 => (1, 1),
+            // Primes of the form 4k + 3 stay prime, and divide both parts
+            3 => (p, 0),
+            _ => {
+                let factor = split_prime(p);
+                match gaussian_div_exact(rest, factor) {
+                    Some(_) => factor,
+                    None => (factor.0, -factor.1),
+                }
+            }
+        };
+        rest = gaussian_div_exact(rest, factor)
+            .expect("a prime factor of the norm has a Gaussian factor dividing the number");
+        factors.push(factor);
+    }
+
+    // Move each factor into the first quadrant, keeping track of the units that takes
+    let mut unit = rest;
+    let mut normalized = Vec::with_capacity(factors.len());
+    for factor in factors {
+        let (rotation, factor) = first_quadrant(factor);
+        // factor = rotation⁻¹ × normalized, and the inverse of a unit is its conjugate
+        unit = gaussian_mul(unit, (rotation.0, -rotation.1));
+        normalized.push(factor);
+    }
+    normalized.sort_by_key(|&factor| (norm(factor), factor));
+
+    let factors = normalized
+        .into_iter()
+        .map(to_complex)
+        .collect::<Option<Vec<_>>>()?;
+    Some((to_complex(unit)?, factors))
+}
+
+/// Returns true if the complex number is a Gaussian prime, false otherwise.
+///
+/// A Gaussian prime is a Gaussian integer (both parts integers) with no factors other than units (1, -1, 𝑖, and -𝑖)
+/// and itself times a unit.
+/// Some primes, such as 5 = (1 + 2𝑖)(1 - 2𝑖), are not Gaussian primes, while others, such as 3, are.
+///
+/// ```
+/// # use algebra::{factor::is_gaussian_prime, notation::expr::complex::Complex};
+/// assert!(is_gaussian_prime(&Complex::from(3)));
+/// assert!(!is_gaussian_prime(&Complex::from(5)));
+/// assert!(is_gaussian_prime(&Complex::new(1, 2)));
+/// assert!(!is_gaussian_prime(&Complex::i()));
+/// ```
+pub fn is_gaussian_prime(value: &Complex) -> bool {
+    match gaussian_factors(value) {
+        Some((_, factors)) => factors.len() == 1,
+        None => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::NumericFlags;
 
     #[test]
     fn test_is_factor_of() {
@@ -228,6 +581,138 @@ mod tests {
         }
     }
 
+    #[test]
+    fn test_factors() {
+        for n in [-36_i32, -7, 0, 1, 2, 12, 36, 97, 720] {
+            let slow = (2..n.abs())
+                .filter(|fac| fac.is_factor_of(n))
+                .collect::<Vec<_>>();
+            let fast = n
+                .factors()
+                .into_iter()
+                .map(|f| f.common)
+                .collect::<Vec<_>>();
+            assert_eq!(fast, [vec![1], slow].concat(), "factors of {n}");
+            assert_eq!(n.count_factors(), fast.len());
+        }
+        let Factor { common, associated } = &(-12).factors()[2];
+        assert_eq!((*common, *associated), (3, -4));
+        // -2³¹ has no positive i32, but its proper divisors all fit
+        let factors = i32::MIN.factors();
+        assert_eq!(factors.len(), 31);
+        assert_eq!((factors[30].common, factors[30].associated), (1 << 30, -2));
+        assert!(i32::MIN.has_multiple_factors());
+    }
+
+    #[test]
+    fn test_gcf() {
+        assert_eq!(gcf([1_000_003, 999_983]), 1);
+        assert_eq!(gcf([720_720, 524_288]), 16);
+        assert_eq!(gcf([0, 0]), 0);
+        assert_eq!(gcf([5]), 5);
+    }
+
+    /// Primes by trial division.
+    fn slow_primes(n: i32) -> Vec<i32> {
+        (0..n).filter(|p| p.is_prime()).collect()
+    }
+
+    #[test]
+    fn test_primes() {
+        assert_eq!(
+            primes().take_while(|&p| p < 2000).collect::<Vec<_>>(),
+            slow_primes(2000)
+        );
+        assert_eq!(primes().nth(10_000), Some(104_743));
+    }
+
+    #[test]
+    fn test_nth_prime() {
+        for (n, p) in slow_primes(500).into_iter().enumerate() {
+            assert_eq!(nth_prime(n + 1), Some(p));
+        }
+        assert_eq!(nth_prime(0), None);
+    }
+
+    #[test]
+    fn test_primes_below() {
+        for n in -1..500 {
+            assert_eq!(primes_below(n), slow_primes(n), "primes below {n}");
+        }
+        assert_eq!(primes_below(1_000_000).len(), 78_498);
+    }
+
+    #[test]
+    fn test_sum_of_two_squares() {
+        for n in 0..200 {
+            let brute = (0..=n)
+                .flat_map(|a| (a..=n).map(move |b| (a, b)))
+                .find(|(a, b)| a * a + b * b == n);
+            assert_eq!(sum_of_two_squares(n), brute, "{n}");
+        }
+        assert_eq!(sum_of_two_squares(-1), None);
+        assert_eq!(sum_of_two_squares(i32::MAX), None);
+    }
+
+    #[test]
+    fn test_gaussian_factors() {
+        let product = |unit: Complex, factors: Vec<Complex>| {
+            factors
+                .into_iter()
+                .fold(unit.simplify(), |product, factor| {
+                    product * Notation::from(factor)
+                })
+        };
+        for a in -12..=12 {
+            for b in -12..=12 {
+                let value = Complex::new(a, b);
+                let Some((unit, factors)) = gaussian_factors(&value) else {
+                    assert_eq!((a, b), (0, 0));
+                    continue;
+                };
+                for factor in &factors {
+                    assert!(is_gaussian_prime(factor), "{factor} in {value}");
+                }
+                assert_eq!(product(unit, factors), value.simplified(), "{value}");
+            }
+        }
+        // 2 = -𝑖(1 + 𝑖)²
+        assert_eq!(
+            gaussian_factors(&Complex::from(2)),
+            Some((
+                Complex::new(0, -1),
+                vec![Complex::new(1, 1), Complex::new(1, 1)]
+            ))
+        );
+        // 2¹⁶(1 + 𝑖) = (1 + 𝑖)³³ times a unit
+        let (_, factors) = gaussian_factors(&Complex::new(65536, 65536)).unwrap();
+        assert_eq!(factors, vec![Complex::new(1, 1); 33]);
+        assert_eq!(gaussian_factors(&Complex::new(0, 0)), None);
+    }
+
+    #[test]
+    fn test_is_gaussian_prime() {
+        let primes = [
+            Complex::new(1, 1),
+            Complex::from(3),
+            Complex::new(2, 3),
+            Complex::new(0, 7),
+        ];
+        for prime in primes {
+            assert!(is_gaussian_prime(&prime), "{prime}");
+        }
+        let composites = [
+            Complex::from(2),
+            Complex::from(13),
+            Complex::new(3, 3),
+            Complex::from(1),
+            Complex::from(0),
+        ];
+        for composite in composites {
+            assert!(!is_gaussian_prime(&composite), "{composite}");
+        }
+    }
+
     #[test]
     fn test_lcm() {
         assert_eq!(lcm([2, 12]), 12);
@@ -0,0 +1,312 @@
+//! Entry points for fuzzing the parser and the simplifier with `cargo fuzz`.
+//!
+//! Enabled by the `fuzzing` feature. Each entry point takes the raw bytes chosen by the fuzzer,
+//! and panics if it finds a bug, so that the fuzzer records the input.
+//! The targets which call them are in the `fuzz` directory:
+//!
+//! ```text
+//! cargo +nightly fuzz run parse
+//! cargo +nightly fuzz run simplify
+//! ```
+//!
+//! A crashing input can be reproduced without the fuzzer by passing its bytes to the same entry point.
+
+use crate::{
+    notation::{
+        atom::Atom,
+        canonical::Canonical,
+        expr::{
+            complex::Complex,
+            fraction::Fraction,
+            multiple::Multiple,
+            nested_fraction::NestedFraction,
+            nested_radical::NestedRadical,
+            power::Power,
+            product::Product,
+            radical::Radical,
+            radical_fraction::RadicalFraction,
+            simplify::Simplify,
+            sum::Sum,
+            trig::{Trig, TrigFunction},
+        },
+        Notation,
+    },
+    solve::var::Var,
+};
+use std::sync::Arc;
+
+/// How many levels of [`Trig`], [`Sum`], [`Product`], [`NestedFraction`], [`NestedRadical`], and [`Power`]
+/// [`notation_from_bytes`] nests at most.
+const MAX_DEPTH: u32 = 4;
+
+/// Reads choices from the fuzzer's bytes, acting as if the bytes are followed by infinitely many zeroes.
+struct Bytes<'a> {
+    data: &'a [u8],
+}
+
+impl Bytes<'_> {
+    fn byte(&mut self) -> u8 {
+        match self.data.split_first() {
+            Some((&byte, rest)) => {
+                self.data = rest;
+                byte
+            }
+            None => 0,
+        }
+    }
+
+    /// Reads any [`i32`], favoring the small values where most special cases are.
+    fn integer(&mut self) -> i32 {
+        match self.byte() {
+            choice @ 0..=199 => choice as i32 % 9 - 4,
+            _ => i32::from_le_bytes([self.byte(), self.byte(), self.byte(), self.byte()]),
+        }
+    }
+
+    fn atom(&mut self) -> Atom {
+        match self.byte() % 13 {
+            0 => Atom::Complex,
+            1 => Atom::Undefined,
+            2 => Atom::Huge,
+            3 => Atom::NegativeHuge,
+            4 => Atom::Epsilon,
+            5 => Atom::NegativeEpsilon,
+            6 => Atom::Pi,
+            7 => Atom::E,
+            8 => Atom::Unknown,
+            9 => self.variable(),
+            _ => Atom::from(self.integer()),
+        }
+    }
+
+    fn constant(&mut self) -> Atom {
+        match self.byte() % 2 {
+            0 => Atom::Pi,
+            _ => Atom::E,
+        }
+    }
+
+    fn variable(&mut self) -> Atom {
+        let name = match self.byte() % 3 {
+            0 => "x",
+            1 => "y",
+            _ => "θ",
+        };
+        Atom::Variable(Var::new(name))
+    }
+
+    fn notation(&mut self, depth: u32) -> Notation {
+        let kinds = if depth < MAX_DEPTH { 13 } else { 7 };
+        match self.byte() % kinds {
+            0 => Notation::from(self.atom()),
+            1 => Notation::from(Fraction {
+                num: self.atom(),
+                den: self.atom(),
+            }),
+            2 => Notation::from(Radical {
+                coef: self.integer(),
+                rad: self.integer(),
+            }),
+            3 => Notation::from(RadicalFraction::new(
+                self.integer(),
+                self.integer(),
+                self.integer(),
+            )),
+            4 => {
+                let (num, den) = (self.integer(), self.integer());
+                let constant = match self.byte() % 2 {
+                    0 => self.constant(),
+                    _ => self.variable(),
+                };
+                Notation::from(Multiple::new(num, den, constant))
+            }
+            5 => Notation::from(Complex::new(self.integer(), self.integer())),
+            6 => Notation::from(Complex::new(
+                Fraction::new(self.integer(), self.integer()),
+                Radical {
+                    coef: self.integer(),
+                    rad: self.integer(),
+                },
+            )),
+            7 => {
+                let func = match self.byte() % 3 {
+                    0 => TrigFunction::Sin,
+                    1 => TrigFunction::Cos,
+                    _ => TrigFunction::Tan,
+                };
+                Notation::from(Trig::new(func, self.notation(depth + 1)))
+            }
+            8 => {
+                let len = self.byte() % 4;
+                Notation::from(Sum::new(
+                    (0..len)
+                        .map(|_| self.notation(depth + 1))
+                        .collect::<Vec<_>>(),
+                ))
+            }
+            11 => Notation::from(NestedRadical {
+                coef: self.integer(),
+                rad: Arc::new(self.notation(depth + 1)),
+            }),
+            10 => Notation::from(NestedFraction::new(
+                self.notation(depth + 1),
+                self.notation(depth + 1),
+            )),
+            9 => Notation::from(Power::new(
+                self.notation(depth + 1),
+                self.notation(depth + 1),
+            )),
+            _ => {
+                let len = self.byte() % 4;
+                Notation::from(Product::new(
+                    (0..len)
+                        .map(|_| self.notation(depth + 1))
+                        .collect::<Vec<_>>(),
+                ))
+            }
+        }
+    }
+}
+
+/// Builds notation from the fuzzer's bytes.
+///
+/// Every byte string builds some notation, nested at most a few levels deep,
+/// and small changes to the bytes make small changes to the notation.
+/// The notation isn't simplified.
+pub fn notation_from_bytes(data: &[u8]) -> Notation {
+    Bytes { data }.notation(0)
+}
+
+/// Simplifies the notation, checking that simplifying the result again doesn't change it.
+///
+/// # Panics
+/// Panics if simplifying panics, or if simplifying isn't idempotent.
+pub fn check_simplify(value: &Notation) {
+    let once = value.clone().simplify_full();
+    let twice = once.clone().simplify_full();
+    assert_eq!(
+        Canonical(once),
+        Canonical(twice),
+        "simplifying {value} again changed it"
+    );
+}
+
+/// Parses the bytes as text, if they are UTF-8.
+/// Anything which parses is simplified by [`check_simplify`],
+/// and must display as text which parses back to the same display.
+///
+/// # Panics
+/// Panics if parsing, displaying, or simplifying panics, or if either check fails.
+pub fn parse(data: &[u8]) {
+    let Ok(text) = std::str::from_utf8(data) else {
+        return;
+    };
+    let Ok(value) = text.parse::<Notation>() else {
+        return;
+    };
+    let display = value.to_string();
+    let reparsed = display
+        .parse::<Notation>()
+        .unwrap_or_else(|e| panic!("{text:?} displays as {display:?}, which fails to parse: {e}"));
+    assert_eq!(
+        reparsed.to_string(),
+        display,
+        "{text:?} does not round trip"
+    );
+    check_simplify(&value);
+}
+
+/// Builds notation from the bytes with [`notation_from_bytes`] and simplifies it with [`check_simplify`].
+///
+/// # Panics
+/// Panics if simplifying panics or isn't idempotent.
+pub fn simplify(data: &[u8]) {
+    check_simplify(&notation_from_bytes(data));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The depth of trig, sum, product, nested fraction, nested radical, and power nodes in the notation.
+    fn depth(value: &Notation) -> u32 {
+        use crate::notation::expr::Expr;
+        match value {
+            Notation::Expr(Expr::Trig(trig)) => 1 + depth(&trig.arg),
+            Notation::Expr(Expr::Sum(sum)) => 1 + sum.terms.iter().map(depth).max().unwrap_or(0),
+            Notation::Expr(Expr::Product(product)) => {
+                1 + product.factors.iter().map(depth).max().unwrap_or(0)
+            }
+            Notation::Expr(Expr::NestedFraction(frac)) => {
+                1 + depth(&frac.num).max(depth(&frac.den))
+            }
+            Notation::Expr(Expr::NestedRadical(root)) => 1 + depth(&root.rad),
+            Notation::Expr(Expr::Power(power)) => 1 + depth(&power.base).max(depth(&power.exp)),
+            _ => 0,
+        }
+    }
+
+    #[test]
+    fn test_every_input_builds() {
+        for a in 0..=u8::MAX {
+            for b in [0, 1, 7, 8, 100, 200, 255] {
+                let value = notation_from_bytes(&[a, b, a ^ b, 255, b, a]);
+                assert!(depth(&value) <= MAX_DEPTH);
+                let _ = value.to_string();
+            }
+        }
+        assert!(depth(&notation_from_bytes(&[8, 1, 8, 1, 8, 1, 8, 1, 8, 1, 8, 1])) <= MAX_DEPTH);
+    }
+
+    #[test]
+    fn test_deterministic() {
+        let data = [8, 3, 1, 5, 6, 7, 2, 9, 9];
+        assert_eq!(
+            Canonical(notation_from_bytes(&data)),
+            Canonical(notation_from_bytes(&data))
+        );
+        // Running out of bytes reads zeroes
+        assert_eq!(
+            Canonical(notation_from_bytes(&[])),
+            Canonical(notation_from_bytes(&[0; 16]))
+        );
+    }
+
+    #[test]
+    fn test_parse() {
+        for text in [
+            "",
+            "(",
+            "√",
+            "1/2",
+            "-3√5/4",
+            "2π/3",
+            "sin(30°)",
+            "1+2𝑖",
+            "π-3-1/3",
+            "π·𝑒",
+            "2*(1+√2)",
+            "2x-y/3",
+            "π²",
+            "(√2)^(√2)",
+            "(1/2)/(3/4)",
+            "2√(2+√3)",
+        ] {
+            parse(text.as_bytes());
+        }
+        parse(&[0xff, 0xfe]);
+    }
+
+    #[test]
+    fn test_simplify() {
+        for data in [
+            &[][..],
+            &[1, 8, 4, 8, 8],
+            &[2, 100, 150],
+            &[3, 1, 2, 3],
+            &[5, 9, 10],
+        ] {
+            simplify(data);
+        }
+    }
+}
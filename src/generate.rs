@@ -0,0 +1,331 @@
+//! Random exercises with exact answers, for practice and for testing.
+//!
+//! Randomness comes from an [`Rng`], so that a seeded generator such as [`SplitMix64`]
+//! produces the same exercises every time.
+//!
+//! ```
+//! # use algebra::generate::{exercise, Difficulty, ExerciseKind, SplitMix64};
+//! let mut rng = SplitMix64::new(7);
+//! let first = exercise(&mut rng, ExerciseKind::ReduceFraction, Difficulty::Easy);
+//!
+//! let mut rng = SplitMix64::new(7);
+//! let again = exercise(&mut rng, ExerciseKind::ReduceFraction, Difficulty::Easy);
+//! assert_eq!(first.to_string(), again.to_string());
+//! assert_eq!(first.answer, again.answer);
+//! ```
+
+use crate::{
+    check::{check_answer, AnswerVerdict},
+    factor::gcf,
+    notation::{
+        expr::{fraction::Fraction, radical::Radical},
+        Notation,
+    },
+    solve::{equation::Equation, linear::Linear, set::SolutionSet},
+};
+
+/// A source of random numbers.
+///
+/// Implement this to generate exercises from another random number generator.
+pub trait Rng {
+    /// Returns the next random number, with every bit equally likely to be set.
+    fn next_u64(&mut self) -> u64;
+
+    /// Returns a random integer in `min..=max`.
+    ///
+    /// Panics if `min > max`.
+    fn between(&mut self, min: i32, max: i32) -> i32 {
+        assert!(min <= max, "Empty range {min}..={max}.");
+        let span = (max as i64 - min as i64 + 1) as u64;
+        (min as i64 + (self.next_u64() % span) as i64) as i32
+    }
+
+    /// Returns true or false with equal chance.
+    fn coin(&mut self) -> bool {
+        self.next_u64() & 1 == 1
+    }
+}
+
+/// A small, fast, seedable random number generator.
+///
+/// Not suitable for cryptography.
+#[derive(Debug, Clone)]
+pub struct SplitMix64 {
+    state: u64,
+}
+
+impl SplitMix64 {
+    /// Constructs a generator which always produces the same numbers for the same seed.
+    pub fn new(seed: u64) -> Self {
+        Self { state: seed }
+    }
+}
+
+impl Rng for SplitMix64 {
+    fn next_u64(&mut self) -> u64 {
+        self.state = self.state.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.state;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+}
+
+/// How hard an exercise is, mostly through the size of its numbers.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Difficulty {
+    /// Small positive numbers.
+    Easy,
+
+    /// Larger numbers.
+    Medium,
+
+    /// Larger numbers, negatives, and answers which aren't integers.
+    Hard,
+}
+
+impl Difficulty {
+    /// The largest number most parts of an exercise are built from.
+    fn limit(self) -> i32 {
+        match self {
+            Difficulty::Easy => 9,
+            Difficulty::Medium => 20,
+            Difficulty::Hard => 50,
+        }
+    }
+}
+
+/// The kinds of exercises which can be generated.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum ExerciseKind {
+    /// Reduce a fraction to lowest terms, such as 6/8 to 3/4.
+    ReduceFraction,
+
+    /// Move square factors out of a radical, such as √12 to 2√3.
+    SimplifyRadical,
+
+    /// Solve a linear equation in one variable, such as 4x - 3 = x.
+    LinearEquation,
+}
+
+/// What an exercise asks for.
+#[derive(Debug, PartialEq, Clone)]
+pub enum Problem {
+    /// Write the notation in its simplest form.
+    Simplify(Notation),
+
+    /// Find the value of the variable that makes the equation true.
+    Solve {
+        /// The equation to solve.
+        equation: Equation,
+
+        /// The name of the variable to solve for.
+        var: String,
+    },
+}
+
+impl std::fmt::Display for Problem {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Problem::Simplify(value) => format!("Simplify {value}").fmt(f),
+            Problem::Solve { equation, var } => format!("Solve {equation} for {var}").fmt(f),
+        }
+    }
+}
+
+/// A problem together with its exact answer.
+#[derive(Debug, PartialEq, Clone)]
+pub struct Exercise {
+    /// What the exercise asks for.
+    pub problem: Problem,
+
+    /// The simplest form of the answer.
+    pub answer: Notation,
+}
+
+impl Exercise {
+    /// Marks a submitted answer against the exercise's answer with [`check_answer`].
+    pub fn check(&self, submitted: &str) -> AnswerVerdict {
+        check_answer(&self.answer, submitted)
+    }
+}
+
+impl std::fmt::Display for Exercise {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.problem.fmt(f)
+    }
+}
+
+/// Returns true if no square number other than 1 is a factor of `n`.
+fn is_square_free(n: i32) -> bool {
+    (2..=n.isqrt()).all(|root| n % (root * root) != 0)
+}
+
+/// Generates a fraction which reduces to a fraction in lowest terms with a denominator other than 1.
+pub fn reduce_fraction(rng: &mut impl Rng, difficulty: Difficulty) -> Exercise {
+    let limit = difficulty.limit();
+    let (num, den) = loop {
+        let num = rng.between(1, limit);
+        let den = rng.between(2, limit);
+        if gcf([num, den]) == 1 {
+            break (num, den);
+        }
+    };
+    let num = match difficulty {
+        Difficulty::Hard if rng.coin() => -num,
+        _ => num,
+    };
+    let scale = rng.between(2, limit / 2);
+    Exercise {
+        problem: Problem::Simplify(Notation::from(Fraction::new(num * scale, den * scale))),
+        answer: Notation::from(Fraction::new(num, den)),
+    }
+}
+
+/// Generates a radical with a square factor to move out from under it, leaving a square-free radicand.
+pub fn simplify_radical(rng: &mut impl Rng, difficulty: Difficulty) -> Exercise {
+    let limit = difficulty.limit();
+    let rad = loop {
+        let rad = rng.between(2, limit);
+        if is_square_free(rad) {
+            break rad;
+        }
+    };
+    let root = rng.between(2, limit / 3 + 1);
+    let coef = match difficulty {
+        Difficulty::Hard => rng.between(1, 5) * if rng.coin() { -1 } else { 1 },
+        _ => 1,
+    };
+    Exercise {
+        problem: Problem::Simplify(Notation::from(Radical {
+            coef,
+            rad: root * root * rad,
+        })),
+        answer: Notation::from(Radical {
+            coef: coef * root,
+            rad,
+        }),
+    }
+}
+
+/// Generates an equation `ax + b = cx + d` with exactly one solution.
+///
+/// The solution is an integer, except on [`Hard`][Difficulty::Hard] where it may be a fraction.
+pub fn linear_equation(rng: &mut impl Rng, difficulty: Difficulty) -> Exercise {
+    let limit = difficulty.limit();
+    let small = limit / 3 + 1;
+    let (a, c) = loop {
+        let (a, c) = (rng.between(-small, small), rng.between(-small, small));
+        if a != c && a != 0 {
+            break (a, c);
+        }
+    };
+    let b = rng.between(-limit, limit);
+    let d = match difficulty {
+        Difficulty::Hard => rng.between(-limit, limit),
+        // d = (a - c)x + b, for an integer x
+        _ => (a - c) * rng.between(-small, small) + b,
+    };
+    let equation = Equation {
+        lhs: Linear::term(a, "x") + Linear::from(b),
+        rhs: Linear::term(c, "x") + Linear::from(d),
+    };
+    let answer = match equation.solve_for("x") {
+        Ok(SolutionSet::Finite(mut values)) if values.len() == 1 => values.remove(0),
+        other => unreachable!("a ≠ c, so there is exactly one solution, but found {other:?}"),
+    };
+    Exercise {
+        problem: Problem::Solve {
+            equation,
+            var: "x".to_string(),
+        },
+        answer,
+    }
+}
+
+/// Generates an exercise of the given kind.
+pub fn exercise(rng: &mut impl Rng, kind: ExerciseKind, difficulty: Difficulty) -> Exercise {
+    match kind {
+        ExerciseKind::ReduceFraction => reduce_fraction(rng, difficulty),
+        ExerciseKind::SimplifyRadical => simplify_radical(rng, difficulty),
+        ExerciseKind::LinearEquation => linear_equation(rng, difficulty),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::expr::simplify::Simplify;
+
+    const DIFFICULTIES: [Difficulty; 3] = [Difficulty::Easy, Difficulty::Medium, Difficulty::Hard];
+
+    #[test]
+    fn test_between() {
+        let mut rng = SplitMix64::new(0);
+        for _ in 0..1000 {
+            assert!((-3..=3).contains(&rng.between(-3, 3)));
+        }
+        assert_eq!(rng.between(i32::MIN, i32::MIN), i32::MIN);
+        rng.between(i32::MIN, i32::MAX);
+    }
+
+    #[test]
+    fn test_simplify_answers() {
+        let mut rng = SplitMix64::new(1);
+        for difficulty in DIFFICULTIES {
+            for kind in [ExerciseKind::ReduceFraction, ExerciseKind::SimplifyRadical] {
+                for _ in 0..200 {
+                    let exercise = exercise(&mut rng, kind, difficulty);
+                    let Problem::Simplify(Notation::Expr(problem)) = &exercise.problem else {
+                        panic!("{exercise} is not an expression to simplify");
+                    };
+                    assert_eq!(problem.simplified(), exercise.answer, "{exercise}");
+                    let answer = exercise.answer.to_string();
+                    assert_eq!(exercise.check(&answer), AnswerVerdict::Equivalent);
+                    assert_eq!(
+                        exercise.check(&problem.to_string()),
+                        AnswerVerdict::EquivalentButNotSimplified
+                    );
+                    assert_ne!(
+                        Notation::from(problem.clone()).to_string(),
+                        exercise.answer.to_string()
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_linear_answers() {
+        let mut rng = SplitMix64::new(2);
+        for difficulty in DIFFICULTIES {
+            for _ in 0..200 {
+                let exercise = linear_equation(&mut rng, difficulty);
+                let Problem::Solve { equation, .. } = &exercise.problem else {
+                    unreachable!()
+                };
+                let zeroed = equation.zeroed();
+                let check = zeroed.coefficient("x") * exercise.answer.clone() + zeroed.constant;
+                assert_eq!(check, 0, "{exercise} is not solved by {}", exercise.answer);
+                if difficulty != Difficulty::Hard {
+                    assert!(exercise.answer.clone().atom().is_some(), "{exercise}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let exercise = Exercise {
+            problem: Problem::Solve {
+                equation: Equation {
+                    lhs: Linear::term(2, "x") + Linear::from(1),
+                    rhs: Linear::from(5),
+                },
+                var: "x".to_string(),
+            },
+            answer: Notation::from(2),
+        };
+        assert_eq!(exercise.to_string(), "Solve 2x + 1 = 5 for x");
+    }
+}
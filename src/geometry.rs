@@ -0,0 +1,134 @@
+//! Coordinate geometry on points with integer coordinates.
+//!
+//! Every result is exact: slopes and midpoints are fractions, and distances are radicals.
+
+use crate::{
+    notation::{ops::rational, Notation},
+    solve::{equation::Equation, linear::Linear},
+    vector::Vec2,
+};
+
+/// Returns the slope of the line through two points: rise over run, as a reduced fraction.
+///
+/// The slope of a vertical line is [`Undefined`][crate::notation::atom::Atom::Undefined].
+///
+/// ```
+/// # use algebra::{geometry::slope, notation::expr::fraction::Fraction, vector::Vec2};
+/// assert_eq!(slope(&Vec2::from([1, 1]), &Vec2::from([5, 3])), Fraction::new(1, 2));
+/// ```
+pub fn slope(a: &Vec2, b: &Vec2) -> Notation {
+    let [x1, y1] = a.components.map(i128::from);
+    let [x2, y2] = b.components.map(i128::from);
+    rational(y2 - y1, x2 - x1)
+}
+
+/// Returns the point halfway between two points, with each coordinate as a reduced fraction.
+///
+/// ```
+/// # use algebra::{geometry::midpoint, notation::{Notation, expr::fraction::Fraction}, vector::Vec2};
+/// let [x, y] = midpoint(&Vec2::from([0, 1]), &Vec2::from([3, 5]));
+/// assert_eq!(x, Fraction::new(3, 2));
+/// assert_eq!(y, 3);
+/// ```
+pub fn midpoint(a: &Vec2, b: &Vec2) -> [Notation; 2] {
+    let [x1, y1] = a.components.map(i128::from);
+    let [x2, y2] = b.components.map(i128::from);
+    [rational(x1 + x2, 2), rational(y1 + y2, 2)]
+}
+
+/// Returns the distance between two points as a simplified radical.
+///
+/// See [`VecN::distance()`][crate::vector::VecN::distance()].
+pub fn distance(a: &Vec2, b: &Vec2) -> Notation {
+    a.distance(b)
+}
+
+/// Returns the equation of the line through two distinct points, in slope-intercept form: `y = mx + b`.
+///
+/// A vertical line has no slope, so its equation is `x = c` instead.
+///
+/// Returns [`None`] if the points are the same, since infinitely many lines pass through a single point.
+///
+/// ```
+/// # use algebra::{geometry::line_through, vector::Vec2};
+/// let line = line_through(&Vec2::from([0, 1]), &Vec2::from([2, 5])).unwrap();
+/// assert_eq!(line.to_string(), "y = 2x + 1");
+///
+/// let line = line_through(&Vec2::from([3, 0]), &Vec2::from([3, 7])).unwrap();
+/// assert_eq!(line.to_string(), "x = 3");
+/// ```
+pub fn line_through(a: &Vec2, b: &Vec2) -> Option<Equation> {
+    let [x1, y1] = a.components;
+    let [x2, _] = b.components;
+
+    if a == b {
+        return None;
+    }
+
+    if x1 == x2 {
+        return Some(Equation {
+            lhs: Linear::var("x"),
+            rhs: Linear::from(x1),
+        });
+    }
+
+    let m = slope(a, b);
+    // b = y - mx
+    let intercept = Notation::from(y1) - m.clone() * Notation::from(x1);
+    Some(Equation {
+        lhs: Linear::var("y"),
+        rhs: Linear::term(m, "x") + Linear::from(intercept),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::expr::{fraction::Fraction, radical::Radical};
+
+    #[test]
+    fn test_slope() {
+        let origin = Vec2::zero();
+        assert_eq!(slope(&origin, &Vec2::from([2, 4])), 2);
+        assert_eq!(slope(&origin, &Vec2::from([4, -2])), Fraction::new(-1, 2));
+        assert_eq!(slope(&origin, &Vec2::from([3, 0])), 0);
+        assert!(slope(&origin, &Vec2::from([0, 3]))
+            .atom()
+            .is_some_and(|x| x.is_undefined()));
+    }
+
+    #[test]
+    fn test_slope_does_not_overflow() {
+        let a = Vec2::from([i32::MIN, i32::MIN]);
+        let b = Vec2::from([i32::MAX, i32::MAX]);
+        assert_eq!(slope(&a, &b), 1);
+    }
+
+    #[test]
+    fn test_midpoint() {
+        let [x, y] = midpoint(&Vec2::from([-1, 2]), &Vec2::from([4, -2]));
+        assert_eq!(x, Fraction::new(3, 2));
+        assert_eq!(y, 0);
+    }
+
+    #[test]
+    fn test_distance() {
+        assert_eq!(distance(&Vec2::from([0, 0]), &Vec2::from([6, 8])), 10);
+        assert_eq!(
+            distance(&Vec2::from([1, 1]), &Vec2::from([3, 3])),
+            Radical { coef: 2, rad: 2 }
+        );
+    }
+
+    #[test]
+    fn test_line_through() {
+        let line = line_through(&Vec2::from([1, 1]), &Vec2::from([3, 2])).unwrap();
+        assert_eq!(line.rhs.coefficient("x"), Fraction::new(1, 2));
+        assert_eq!(line.rhs.constant, Fraction::new(1, 2));
+
+        let line = line_through(&Vec2::from([-2, 4]), &Vec2::from([5, 4])).unwrap();
+        assert_eq!(line.to_string(), "y = 4");
+
+        assert_eq!(line_through(&Vec2::from([1, 1]), &Vec2::from([1, 1])), None);
+    }
+}
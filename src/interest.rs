@@ -0,0 +1,217 @@
+//! Percentages and interest, computed exactly.
+//!
+//! Rates are fractions, so 5% a year is 1/20, and time is a whole number of years.
+//! Results are reduced fractions, or [`Huge`][crate::notation::atom::Atom::Huge]
+//! when the exact amount has a numerator or denominator too large to write down.
+
+use crate::notation::{
+    atom::Atom::Undefined,
+    expr::{fraction::Fraction, simplify::Simplify},
+    ops::{gcd, lowest_terms, rational, saturate},
+    Notation,
+};
+
+/// Multiplies two fractions, reducing across them first so they overflow as late as they can.
+fn mul((a, b): (i128, i128), (c, d): (i128, i128)) -> Option<(i128, i128)> {
+    let (ad, cb) = (gcd(a, d).max(1), gcd(c, b).max(1));
+    Some(((a / ad).checked_mul(c / cb)?, (b / cb).checked_mul(d / ad)?))
+}
+
+/// Returns `value`% as a reduced fraction, such as 5% = 1/20.
+///
+/// To find what percent one number is of another, see [`percent`][crate::solve::proportion::percent].
+///
+/// ```
+/// # use algebra::{interest::from_percent, notation::expr::fraction::Fraction};
+/// assert_eq!(from_percent(5), Fraction::new(1, 20));
+/// assert_eq!(from_percent(250), Fraction::new(5, 2));
+/// ```
+pub fn from_percent(value: i32) -> Fraction {
+    let gcf = gcd(value as i128, 100) as i32;
+    Fraction::new(value / gcf, 100 / gcf)
+}
+
+/// Returns the simple interest earned on `principal` at `rate` per year over `years`: `I = Prt`.
+///
+/// Returns [`Undefined`][crate::notation::atom::Atom::Undefined] if the principal or rate isn't a ratio of numbers.
+///
+/// ```
+/// # use algebra::{interest::{from_percent, simple_interest}, notation::expr::fraction::Fraction};
+/// // $1000 at 3% for 2 years earns $60
+/// assert_eq!(simple_interest(1000, from_percent(3), 2), 60);
+/// ```
+pub fn simple_interest(
+    principal: impl Into<Fraction>,
+    rate: impl Into<Fraction>,
+    years: u32,
+) -> Notation {
+    let (Some(principal), Some(rate)) = (lowest_terms(principal), lowest_terms(rate)) else {
+        return Notation::from(Undefined);
+    };
+    let (num, den) = mul(principal, rate)
+        .and_then(|product| mul(product, (years as i128, 1)))
+        .expect("the product of three i32s fits in an i128");
+    rational(num, den)
+}
+
+/// Returns the amount `principal` grows to with simple interest at `rate` per year over `years`: `A = P(1 + rt)`.
+///
+/// ```
+/// # use algebra::{interest::{from_percent, simple_amount}, notation::expr::fraction::Fraction};
+/// assert_eq!(simple_amount(1000, from_percent(3), 2), 1060);
+/// assert_eq!(simple_amount(Fraction::new(1, 2), from_percent(10), 1), Fraction::new(11, 20));
+/// ```
+pub fn simple_amount(
+    principal: impl Into<Fraction>,
+    rate: impl Into<Fraction>,
+    years: u32,
+) -> Notation {
+    let principal = principal.into();
+    Notation::from(principal.clone()).simplify() + simple_interest(principal, rate, years)
+}
+
+/// Returns the amount `principal` grows to at `rate` per year, compounded `per_year` times a year for `years`:
+/// `A = P(1 + r/n)^(nt)`.
+///
+/// Returns [`Undefined`][crate::notation::atom::Atom::Undefined] if `per_year` is 0,
+/// or if the principal or rate isn't a ratio of numbers.
+///
+/// ```
+/// # use algebra::{interest::{compound_amount, from_percent}, notation::expr::fraction::Fraction};
+/// // $1000 at 10%, compounded once a year for 2 years
+/// assert_eq!(compound_amount(1000, from_percent(10), 1, 2), 1210);
+/// // $100 at 4%, compounded twice a year for 1 year: 100(1.02)² = 104.04
+/// assert_eq!(compound_amount(100, from_percent(4), 2, 1), Fraction::new(2601, 25));
+/// // Too many compoundings to write down exactly
+/// assert!(compound_amount(100, from_percent(4), 12, 30).atom().is_some_and(|x| x.is_positive_huge()));
+/// ```
+pub fn compound_amount(
+    principal: impl Into<Fraction>,
+    rate: impl Into<Fraction>,
+    per_year: u32,
+    years: u32,
+) -> Notation {
+    let (Some(principal), Some((rate, rate_den))) = (lowest_terms(principal), lowest_terms(rate))
+    else {
+        return Notation::from(Undefined);
+    };
+    if per_year == 0 {
+        return Notation::from(Undefined);
+    }
+    // 1 + r/n = (n·den + r) / (n·den)
+    let step_den = per_year as i128 * rate_den;
+    let step = (step_den + rate, step_den);
+    let g = gcd(step.0, step.1).max(1);
+    let step = (step.0 / g, step.1 / g);
+
+    let exact = per_year.checked_mul(years).and_then(|periods| {
+        let power = (step.0.checked_pow(periods)?, step.1.checked_pow(periods)?);
+        mul(principal, power)
+    });
+    match exact {
+        Some((num, den)) => rational(num, den),
+        None => {
+            let periods = per_year as f64 * years as f64;
+            let amount = principal.0 as f64 / principal.1 as f64
+                * (step.0 as f64 / step.1 as f64).powf(periods);
+            saturate(amount.abs() >= 1.0, amount < 0.0)
+        }
+    }
+}
+
+/// Returns the interest earned on `principal` at `rate` per year, compounded `per_year` times a year for `years`:
+/// the [compound amount][compound_amount] less the principal.
+///
+/// ```
+/// # use algebra::interest::{compound_interest, from_percent};
+/// assert_eq!(compound_interest(1000, from_percent(10), 1, 2), 210);
+/// ```
+pub fn compound_interest(
+    principal: impl Into<Fraction>,
+    rate: impl Into<Fraction>,
+    per_year: u32,
+    years: u32,
+) -> Notation {
+    let principal = principal.into();
+    compound_amount(principal.clone(), rate, per_year, years) - Notation::from(principal).simplify()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::atom::Atom;
+
+    #[test]
+    fn test_from_percent() {
+        assert_eq!(from_percent(50), Fraction::new(1, 2));
+        assert_eq!(from_percent(-20), Fraction::new(-1, 5));
+        assert_eq!(from_percent(7), Fraction::new(7, 100));
+        assert_eq!(from_percent(0), Fraction::new(0, 1));
+    }
+
+    #[test]
+    fn test_simple() {
+        assert_eq!(simple_interest(500, from_percent(4), 3), 60);
+        assert_eq!(
+            simple_interest(Fraction::new(1, 3), from_percent(6), 1),
+            Fraction::new(1, 50)
+        );
+        assert_eq!(simple_interest(500, from_percent(4), 0), 0);
+        assert_eq!(simple_amount(800, from_percent(5), 2), 880);
+        // Depreciating by 10% of the original value a year
+        assert_eq!(simple_amount(1000, from_percent(-10), 3), 700);
+        assert_eq!(
+            simple_interest(i32::MAX, i32::MAX, u32::MAX).to_string(),
+            Notation::from(Atom::Huge).to_string()
+        );
+    }
+
+    #[test]
+    fn test_compound() {
+        assert_eq!(compound_amount(1000, from_percent(5), 1, 0), 1000);
+        assert_eq!(
+            compound_amount(1000, from_percent(5), 1, 2),
+            Fraction::new(2205, 2)
+        );
+        assert_eq!(
+            compound_interest(1000, from_percent(5), 1, 2),
+            Fraction::new(205, 2)
+        );
+        // Compounding more often earns more
+        assert_eq!(
+            compound_amount(10000, from_percent(8), 4, 1),
+            Fraction::new(6765201, 625)
+        );
+        // Losing half a year
+        assert_eq!(compound_amount(64, from_percent(-50), 1, 3), 8);
+        assert!(compound_amount(1, from_percent(-300), 1, 201)
+            .atom()
+            .is_some_and(|x| x.is_negative_huge()));
+        assert!(compound_amount(1, from_percent(1), 365, 10)
+            .atom()
+            .is_some_and(|x| x.is_positive_huge()));
+        assert!(compound_amount(1, from_percent(1), u32::MAX, u32::MAX)
+            .atom()
+            .is_some_and(|x| x.is_positive_huge()));
+    }
+
+    #[test]
+    fn test_undefined() {
+        let pi = Fraction {
+            num: Atom::Pi,
+            den: 2.into(),
+        };
+        assert!(compound_amount(100, pi.clone(), 1, 1)
+            .atom()
+            .is_some_and(|x| x.is_undefined()));
+        assert!(simple_interest(pi, from_percent(5), 1)
+            .atom()
+            .is_some_and(|x| x.is_undefined()));
+        assert!(compound_amount(100, from_percent(5), 0, 1)
+            .atom()
+            .is_some_and(|x| x.is_undefined()));
+        assert!(simple_interest(Fraction::new(1, 0), from_percent(5), 1)
+            .atom()
+            .is_some_and(|x| x.is_undefined()));
+    }
+}
@@ -2,8 +2,26 @@
 
 //! A library for handling algebra.
 
+pub mod check;
+pub mod context;
+pub mod digits;
 pub mod factor;
+#[cfg(any(test, feature = "fuzzing"))]
+pub mod fuzz;
+pub mod generate;
+pub mod geometry;
+pub mod interest;
+pub mod matrix;
 pub mod notation;
+pub mod ratio;
+#[cfg(feature = "cli")]
+pub mod repl;
+pub mod solve;
+pub mod stats;
+pub mod testing;
+pub mod vector;
+#[cfg(feature = "wasm")]
+pub mod wasm;
 
 use factor::Factoring;
 use notation::Notation;
@@ -16,11 +34,30 @@ pub trait NumericFlags {
     /// Returns true for even numbers, false for odd numbers.
     fn is_even(&self) -> bool;
 
-    /// Returns true for prime numbers, false for composites.
+    /// Returns true for prime numbers: natural numbers greater than 1 whose only factors are 1 and themselves.
+    ///
+    /// 0 and 1 are not prime, and neither are negative numbers, even when their absolute value is.
+    ///
+    /// ```
+    /// # use algebra::NumericFlags;
+    /// assert!(7.is_prime());
+    /// assert!(!1.is_prime());
+    /// assert!(!(-7).is_prime());
+    /// ```
     fn is_prime(&self) -> bool;
 
-    /// Returns true for composite numbers, false for primes.
+    /// Returns true for composite numbers: natural numbers greater than 1 which aren't prime.
+    ///
+    /// 0 and 1 are neither prime nor composite, and negative numbers are never composite.
     fn is_composite(&self) -> bool;
+
+    /// Returns true for 1 and -1, the only integers whose reciprocal is also an integer.
+    ///
+    /// Every integer is a multiple of a unit, so units are neither prime nor composite.
+    fn is_unit(&self) -> bool;
+
+    /// Returns true for the natural numbers 1, 2, 3, and so on, false for 0 and negative numbers.
+    fn is_natural(&self) -> bool;
 }
 
 impl NumericFlags for i32 {
@@ -33,11 +70,19 @@ impl NumericFlags for i32 {
     }
 
     fn is_prime(&self) -> bool {
-        *self != 0 && !self.has_multiple_factors()
+        *self > 1 && !self.has_multiple_factors()
     }
 
     fn is_composite(&self) -> bool {
-        self.has_multiple_factors()
+        *self > 1 && self.has_multiple_factors()
+    }
+
+    fn is_unit(&self) -> bool {
+        self.unsigned_abs() == 1
+    }
+
+    fn is_natural(&self) -> bool {
+        *self > 0
     }
 }
 
@@ -74,20 +119,24 @@ mod tests {
         assert!(0.is_even());
     }
 
-    const PRIMES: [i32; 10] = [1, 2, 3, 5, 7, 11, 13, 17, 19, 23];
+    const PRIMES: [i32; 10] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29];
     const COMPOSITES: [i32; 10] = [4, 6, 8, 9, 10, 12, 14, 15, 16, 18];
 
     #[test]
     fn test_is_prime() {
         for prime in PRIMES {
             assert!(prime.is_prime());
-            assert!((-prime).is_prime());
+            assert!(!(-prime).is_prime());
         }
         for composite in COMPOSITES {
             assert!(!composite.is_prime());
             assert!(!(-composite).is_prime());
         }
         assert!(!0.is_prime());
+        assert!(!1.is_prime());
+        assert!(!(-1).is_prime());
+        assert!(i32::MAX.is_prime());
+        assert!(!i32::MIN.is_prime());
     }
 
     #[test]
@@ -98,8 +147,37 @@ mod tests {
         }
         for composite in COMPOSITES {
             assert!(composite.is_composite());
-            assert!((-composite).is_composite());
+            assert!(!(-composite).is_composite());
         }
         assert!(!0.is_composite());
+        assert!(!1.is_composite());
+        assert!(!i32::MIN.is_composite());
+    }
+
+    #[test]
+    fn test_is_unit() {
+        assert!(1.is_unit());
+        assert!((-1).is_unit());
+        for n in [0, 2, -2, i32::MAX, i32::MIN] {
+            assert!(!n.is_unit());
+        }
+    }
+
+    #[test]
+    fn test_is_natural() {
+        assert!(1.is_natural());
+        assert!(i32::MAX.is_natural());
+        assert!(!0.is_natural());
+        assert!(!(-1).is_natural());
+        assert!(!i32::MIN.is_natural());
+    }
+
+    #[test]
+    fn test_classification() {
+        // Every integer is exactly one of zero, a unit, prime, composite, or negative and not a unit
+        for n in -100..=100 {
+            let flags = [n == 0, n.is_unit(), n.is_prime(), n.is_composite(), n < -1];
+            assert_eq!(flags.iter().filter(|&&flag| flag).count(), 1, "{n}");
+        }
     }
 }
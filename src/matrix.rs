@@ -0,0 +1,404 @@
+//! Matrices of exact values.
+
+use crate::notation::{ops::ratio, Notation};
+
+/// A rectangular grid of values.
+///
+/// Arithmetic on entries is exact, so row reduction and determinants never lose precision to rounding.
+///
+/// ```
+/// # use algebra::{matrix::Matrix, notation::{Notation, expr::fraction::Fraction}};
+/// let m = Matrix::from([[2, 1], [4, 3]]);
+/// assert_eq!(m.determinant(), Some(Notation::from(2)));
+///
+/// let reduced = Matrix::from([[2, 1, 1], [4, 3, 1]]).row_reduced().unwrap();
+/// assert_eq!(reduced, Matrix::new(vec![
+///     vec![Notation::from(1), Notation::from(0), Notation::from(1)],
+///     vec![Notation::from(0), Notation::from(1), Notation::from(-1)],
+/// ]));
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Matrix {
+    rows: usize,
+    cols: usize,
+
+    /// Entries in row-major order.
+    entries: Vec<Notation>,
+}
+
+impl<const ROWS: usize, const COLS: usize> From<[[i32; COLS]; ROWS]> for Matrix {
+    /// Construct a matrix of integers.
+    fn from(value: [[i32; COLS]; ROWS]) -> Self {
+        Self {
+            rows: ROWS,
+            cols: COLS,
+            entries: value.into_iter().flatten().map(Notation::from).collect(),
+        }
+    }
+}
+
+impl Matrix {
+    /// Constructs a matrix from a list of rows.
+    ///
+    /// Panics if the rows are not all the same length.
+    pub fn new(rows: Vec<Vec<Notation>>) -> Self {
+        let cols = rows.first().map_or(0, Vec::len);
+        assert!(
+            rows.iter().all(|row| row.len() == cols),
+            "Every row of a matrix must be the same length."
+        );
+        Self {
+            rows: rows.len(),
+            cols,
+            entries: rows.into_iter().flatten().collect(),
+        }
+    }
+
+    /// Constructs a matrix of the given size where every entry is 0.
+    pub fn zero(rows: usize, cols: usize) -> Self {
+        Self {
+            rows,
+            cols,
+            entries: vec![Notation::from(0); rows * cols],
+        }
+    }
+
+    /// Constructs the `size`×`size` identity matrix, with 1 along the diagonal and 0 elsewhere.
+    pub fn identity(size: usize) -> Self {
+        let mut result = Self::zero(size, size);
+        for i in 0..size {
+            result[(i, i)] = Notation::from(1);
+        }
+        result
+    }
+
+    /// Returns the number of rows.
+    pub fn rows(&self) -> usize {
+        self.rows
+    }
+
+    /// Returns the number of columns.
+    pub fn cols(&self) -> usize {
+        self.cols
+    }
+
+    /// Returns true if the matrix has as many rows as columns, false otherwise.
+    pub fn is_square(&self) -> bool {
+        self.rows == self.cols
+    }
+
+    /// Returns the entries of the given row.
+    pub fn row(&self, row: usize) -> &[Notation] {
+        &self.entries[row * self.cols..(row + 1) * self.cols]
+    }
+
+    /// Returns the matrix with its rows and columns swapped.
+    pub fn transpose(&self) -> Self {
+        let mut entries = Vec::with_capacity(self.entries.len());
+        for col in 0..self.cols {
+            for row in 0..self.rows {
+                entries.push(self[(row, col)].clone());
+            }
+        }
+        Self {
+            rows: self.cols,
+            cols: self.rows,
+            entries,
+        }
+    }
+
+    /// Returns the matrix in reduced row echelon form, found by Gauss-Jordan elimination.
+    ///
+    /// Returns [`None`] if an entry isn't an integer or fraction, or becomes too large to be operated on exactly.
+    pub fn row_reduced(&self) -> Option<Self> {
+        Some(self.eliminate()?.reduced)
+    }
+
+    /// Like [`row_reduced`][Matrix::row_reduced()], but also returns the column of the leading 1 in each nonzero row.
+    pub(crate) fn row_reduced_with_pivots(&self) -> Option<(Self, Vec<usize>)> {
+        let Elimination {
+            reduced, pivots, ..
+        } = self.eliminate()?;
+        Some((reduced, pivots))
+    }
+
+    /// Returns the number of linearly independent rows.
+    ///
+    /// Returns [`None`] under the same conditions as [`row_reduced`][Matrix::row_reduced()].
+    pub fn rank(&self) -> Option<usize> {
+        Some(self.eliminate()?.pivots.len())
+    }
+
+    /// Returns the determinant of a square matrix.
+    ///
+    /// Returns [`None`] if the matrix isn't square,
+    /// or under the same conditions as [`row_reduced`][Matrix::row_reduced()].
+    pub fn determinant(&self) -> Option<Notation> {
+        if !self.is_square() {
+            return None;
+        }
+        let Elimination {
+            pivots,
+            determinant,
+            ..
+        } = self.eliminate()?;
+        Some(if pivots.len() == self.rows {
+            determinant
+        } else {
+            Notation::from(0)
+        })
+    }
+
+    /// Performs Gauss-Jordan elimination, recording what's needed to find the rank and determinant.
+    fn eliminate(&self) -> Option<Elimination> {
+        let exact = |value: Notation| ratio(&value).map(|_| value);
+
+        let mut m = self.clone();
+        for value in &m.entries {
+            exact(value.clone())?;
+        }
+
+        let mut pivots = Vec::new();
+        let mut determinant = Notation::from(1);
+        for col in 0..m.cols {
+            let rank = pivots.len();
+            let Some(found) = (rank..m.rows).find(|&r| m[(r, col)] != 0) else {
+                continue;
+            };
+            if found != rank {
+                m.swap_rows(rank, found);
+                determinant = exact(determinant * Notation::from(-1))?;
+            }
+
+            let pivot = m[(rank, col)].clone();
+            determinant = exact(determinant * pivot.clone())?;
+            for c in 0..m.cols {
+                m[(rank, c)] = exact(m[(rank, c)].clone() / pivot.clone())?;
+            }
+
+            for r in 0..m.rows {
+                let factor = m[(r, col)].clone();
+                if r != rank && factor != 0 {
+                    for c in 0..m.cols {
+                        let eliminated = m[(r, c)].clone() - factor.clone() * m[(rank, c)].clone();
+                        m[(r, c)] = exact(eliminated)?;
+                    }
+                }
+            }
+
+            pivots.push(col);
+        }
+
+        Some(Elimination {
+            reduced: m,
+            pivots,
+            determinant,
+        })
+    }
+
+    fn swap_rows(&mut self, a: usize, b: usize) {
+        for col in 0..self.cols {
+            self.entries.swap(a * self.cols + col, b * self.cols + col);
+        }
+    }
+}
+
+/// The outcome of [`Matrix::eliminate()`].
+struct Elimination {
+    /// The matrix in reduced row echelon form.
+    reduced: Matrix,
+
+    /// The column of the leading 1 in each nonzero row of `reduced`.
+    pivots: Vec<usize>,
+
+    /// The determinant of the leading square of the original matrix, if every column had a pivot.
+    determinant: Notation,
+}
+
+impl std::ops::Index<(usize, usize)> for Matrix {
+    type Output = Notation;
+
+    /// Returns the entry at `(row, col)`.
+    fn index(&self, (row, col): (usize, usize)) -> &Self::Output {
+        assert!(row < self.rows && col < self.cols, "Index out of bounds.");
+        &self.entries[row * self.cols + col]
+    }
+}
+
+impl std::ops::IndexMut<(usize, usize)> for Matrix {
+    /// Returns the entry at `(row, col)`.
+    fn index_mut(&mut self, (row, col): (usize, usize)) -> &mut Self::Output {
+        assert!(row < self.rows && col < self.cols, "Index out of bounds.");
+        &mut self.entries[row * self.cols + col]
+    }
+}
+
+impl std::ops::Add for Matrix {
+    type Output = Self;
+
+    /// Add two matrices entry by entry.
+    ///
+    /// Panics if the matrices are not the same size.
+    fn add(self, rhs: Self) -> Self::Output {
+        assert!(
+            self.rows == rhs.rows && self.cols == rhs.cols,
+            "Matrices must be the same size to be added."
+        );
+        Self {
+            entries: self
+                .entries
+                .into_iter()
+                .zip(rhs.entries)
+                .map(|(a, b)| a + b)
+                .collect(),
+            ..self
+        }
+    }
+}
+
+impl std::ops::Sub for Matrix {
+    type Output = Self;
+
+    /// Subtract two matrices entry by entry.
+    ///
+    /// Panics if the matrices are not the same size.
+    fn sub(self, rhs: Self) -> Self::Output {
+        assert!(
+            self.rows == rhs.rows && self.cols == rhs.cols,
+            "Matrices must be the same size to be subtracted."
+        );
+        Self {
+            entries: self
+                .entries
+                .into_iter()
+                .zip(rhs.entries)
+                .map(|(a, b)| a - b)
+                .collect(),
+            ..self
+        }
+    }
+}
+
+impl std::ops::Mul for Matrix {
+    type Output = Self;
+
+    /// Multiply two matrices.
+    ///
+    /// Panics if the left matrix doesn't have as many columns as the right matrix has rows.
+    fn mul(self, rhs: Self) -> Self::Output {
+        assert!(
+            self.cols == rhs.rows,
+            "Left matrix must have as many columns as the right has rows to be multiplied."
+        );
+        let mut result = Self::zero(self.rows, rhs.cols);
+        for row in 0..self.rows {
+            for col in 0..rhs.cols {
+                result[(row, col)] = (0..self.cols)
+                    .map(|i| self[(row, i)].clone() * rhs[(i, col)].clone())
+                    .fold(Notation::from(0), |sum, x| sum + x);
+            }
+        }
+        result
+    }
+}
+
+impl std::ops::Mul<Notation> for Matrix {
+    type Output = Self;
+
+    /// Multiply every entry by a scalar.
+    fn mul(self, rhs: Notation) -> Self::Output {
+        Self {
+            entries: self.entries.into_iter().map(|x| x * rhs.clone()).collect(),
+            ..self
+        }
+    }
+}
+
+impl std::fmt::Display for Matrix {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        (0..self.rows)
+            .map(|row| {
+                let entries = self
+                    .row(row)
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<_>>();
+                format!("[{}]", entries.join(", "))
+            })
+            .collect::<Vec<_>>()
+            .join("\n")
+            .fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::expr::fraction::Fraction;
+
+    #[test]
+    fn test_transpose() {
+        let m = Matrix::from([[1, 2, 3], [4, 5, 6]]);
+        assert_eq!(m.transpose(), Matrix::from([[1, 4], [2, 5], [3, 6]]));
+    }
+
+    #[test]
+    fn test_add_and_sub() {
+        let a = Matrix::from([[1, 2], [3, 4]]);
+        let b = Matrix::from([[4, 3], [2, 1]]);
+        assert_eq!(a.clone() + b.clone(), Matrix::from([[5, 5], [5, 5]]));
+        assert_eq!(a - b, Matrix::from([[-3, -1], [1, 3]]));
+    }
+
+    #[test]
+    fn test_mul() {
+        let a = Matrix::from([[1, 2], [3, 4]]);
+        let b = Matrix::from([[0, 1], [1, 0]]);
+        assert_eq!(a.clone() * b, Matrix::from([[2, 1], [4, 3]]));
+        assert_eq!(a.clone() * Matrix::identity(2), a);
+        assert_eq!(
+            Matrix::from([[1, 2, 3]]) * Matrix::from([[1], [1], [1]]),
+            Matrix::from([[6]])
+        );
+    }
+
+    #[test]
+    fn test_determinant() {
+        assert_eq!(
+            Matrix::from([[1, 2], [3, 4]]).determinant(),
+            Some(Notation::from(-2))
+        );
+        assert_eq!(
+            Matrix::from([[2, 0, 1], [1, 3, 2], [1, 1, 2]]).determinant(),
+            Some(Notation::from(6))
+        );
+        assert_eq!(
+            Matrix::from([[1, 2], [2, 4]]).determinant(),
+            Some(Notation::from(0))
+        );
+        assert_eq!(Matrix::from([[1, 2, 3]]).determinant(), None);
+    }
+
+    #[test]
+    fn test_row_reduced_with_fractions() {
+        let reduced = Matrix::from([[3, 1], [1, 2]]).row_reduced().unwrap();
+        assert_eq!(reduced, Matrix::identity(2));
+
+        let reduced = Matrix::from([[2, 1, 1]]).row_reduced().unwrap();
+        assert_eq!(
+            reduced,
+            Matrix::new(vec![vec![
+                Notation::from(1),
+                Notation::from(Fraction::new(1, 2)),
+                Notation::from(Fraction::new(1, 2)),
+            ]])
+        );
+    }
+
+    #[test]
+    fn test_rank() {
+        assert_eq!(Matrix::from([[1, 2], [2, 4]]).rank(), Some(1));
+        assert_eq!(Matrix::identity(3).rank(), Some(3));
+        assert_eq!(Matrix::zero(2, 2).rank(), Some(0));
+    }
+}
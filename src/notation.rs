@@ -1,11 +1,43 @@
 //! Definitions of algebraic types.
 
+#[cfg(any(test, feature = "arbitrary"))]
+pub mod arbitrary;
+pub mod arena;
 pub mod atom;
+pub mod canonical;
+pub mod dot;
+pub mod explain;
 pub mod expr;
+pub mod format;
+pub mod json;
+pub mod latex;
+pub mod lazy;
+pub mod mathml;
+pub mod metrics;
+#[cfg(any(
+    feature = "num-traits",
+    feature = "num-rational",
+    feature = "num-complex"
+))]
+pub mod num;
 pub mod ops;
+pub mod parse;
+pub mod render;
+pub mod rules;
+pub mod sci;
+pub mod sexpr;
+pub mod sympy;
+pub mod traverse;
 
 use atom::{number::Number, Atom};
-use expr::{fraction::Fraction, radical::Radical, Expr};
+use expr::{
+    complex::Complex, fraction::Fraction, multiple::Multiple, nested_fraction::NestedFraction,
+    nested_radical::NestedRadical, power::Power, product::Product, radical::Radical,
+    radical_fraction::RadicalFraction, simplify::Simplify, sum::Sum, trig::Trig, Expr,
+};
+use format::{FormatOptions, FormatWith};
+
+use crate::solve::var::Var;
 
 /// Algebraic Notation.
 ///
@@ -16,12 +48,13 @@ use expr::{fraction::Fraction, radical::Radical, Expr};
 /// # Equality operation is intended only to be used on notation that has already been simplified.
 ///
 /// **Does not simplify.** Fractions are not considered equal to radicals, even if they are mathematically equivalent.\
+/// Radicals are compared in their [normalized][crate::notation::expr::radical::Radical::normalized] form, so √8 is equal to 2√2.\
 /// **Does not test literal equality either.** [`Undefined`][Atom::Undefined] is not equal to [`Undefined`][Atom::Undefined].
 ///
 /// </div>
 ///
 /// ```
-/// # use algebra::notation::{Notation, expr::{fraction::Fraction, radical::Radical, Simplify}};
+/// # use algebra::notation::{Notation, expr::{fraction::Fraction, radical::Radical, simplify::Simplify}};
 /// let a = Notation::from(Fraction::from_ints(1, 5));
 /// let b = Notation::from(Fraction::from_ints(1, 5));
 /// assert_eq!(a, b);
@@ -36,13 +69,14 @@ use expr::{fraction::Fraction, radical::Radical, Expr};
 ///
 /// let a = Notation::from(Radical::from_ints(1, 8));
 /// let b = Notation::from(Radical::from_ints(2, 2));
-/// assert_ne!(a, b); // Even though they are equivalent mathematically
+/// assert_eq!(a, b); // Radicals are compared in their normalized form
 /// if let Notation::Expr(expr) = a {
-///     assert_eq!(expr.simplified(), b); // They need to be simplified first
+///     assert_eq!(expr.simplified(), b);
 /// } else {
 ///     unreachable!();
 /// }
 /// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, PartialEq, Clone)]
 pub enum Notation {
     /// The smallest unit, a single value.
@@ -56,13 +90,37 @@ pub enum Notation {
     Expr(Expr),
 }
 
+impl Simplify for Notation {
+    /// Simplifies an expression, leaving atoms as they are.
+    ///
+    /// Nested notation is simplified from the leaves up without recursing,
+    /// so notation of any depth can be simplified without running out of stack.
+    fn simplify(self) -> Notation {
+        self.simplify_to_depth(usize::MAX, &std::cell::Cell::new(false))
+    }
+}
+
+impl Notation {
+    /// Replaces the notation with its simplest form, the same as [`simplify`][Simplify::simplify()].
+    ///
+    /// Unlike [`simplified`][Simplify::simplified()], nothing is cloned first:
+    /// the notation is taken out of `self`, so children which aren't shared with another clone
+    /// are moved into the result rather than copied.
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, expr::radical::Radical};
+    /// let mut value = Notation::from(Radical::new(12));
+    /// value.simplify_in_place();
+    /// assert_eq!(value.to_string(), "2√3");
+    /// ```
+    pub fn simplify_in_place(&mut self) {
+        *self = std::mem::take(self).simplify();
+    }
+}
+
 impl std::fmt::Display for Notation {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        use Notation::*;
-        match self {
-            Atom(atom) => atom.fmt(f),
-            Expr(expr) => expr.fmt(f),
-        }
+        self.format_with(&FormatOptions::default()).fmt(f)
     }
 }
 
@@ -77,10 +135,7 @@ impl Notation {
 
     /// Returns true if the notation represents an [`Atom`][Atom], false otherwise.
     pub fn is_atom(&self) -> bool {
-        match self {
-            Notation::Atom(_) => true,
-            _ => false,
-        }
+        matches!(self, Notation::Atom(_))
     }
 
     /// If the notation represents an [`Expr`][Expr], returns that expr. Otherwise returns [`None`].
@@ -93,11 +148,132 @@ impl Notation {
 
     /// Returns true if the notation represents an [`Expr`][Expr], false otherwise.
     pub fn is_expr(&self) -> bool {
+        matches!(self, Notation::Expr(_))
+    }
+
+    /// Compares the values represented by two notations.
+    ///
+    /// Unlike [`PartialEq`], this compares mathematical value,
+    /// so a [`Fraction`] and a [`Radical`] both equal to 1 compare as [`Equal`][std::cmp::Ordering::Equal].
+    ///
+    /// Returns [`None`] when the comparison can't be made, such as between two [`Huge`][Atom::Huge] values
+    /// (which may have any magnitude) or with [`Complex`][Atom::Complex] and [`Undefined`][Atom::Undefined] values.
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, atom::Atom, expr::{fraction::Fraction, radical::Radical}};
+    /// # use std::cmp::Ordering::*;
+    /// let half = Notation::from(Fraction::new(1, 2));
+    /// let root_two = Notation::from(Radical::new(2));
+    /// assert_eq!(half.compare(&root_two), Some(Less));
+    /// assert_eq!(root_two.compare(&Notation::from(2)), Some(Less));
+    /// assert_eq!(Notation::from(Atom::Epsilon).compare(&half), Some(Less));
+    /// assert_eq!(Notation::from(Atom::Huge).compare(&Notation::from(Atom::Huge)), None);
+    /// ```
+    pub fn compare(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        let (lhs, rhs) = (Magnitude::of(self)?, Magnitude::of(other)?);
+        lhs.compare(&rhs)
+    }
+
+    /// Returns true if the notation represents a value less than zero, false otherwise.
+    ///
+    /// See [`Atom::is_negative()`] and [`Expr::is_negative()`].
+    pub fn is_negative(&self) -> bool {
         match self {
-            Notation::Expr(_) => true,
-            _ => false,
+            Notation::Atom(atom) => atom.is_negative(),
+            Notation::Expr(expr) => expr.is_negative(),
         }
     }
+
+    /// Returns true if the notation simplifies to 0, such as `0/5` or `0√2`, false otherwise.
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, expr::{fraction::Fraction, radical::Radical}};
+    /// assert!(Notation::from(Fraction::new(0, 5)).is_zero());
+    /// assert!(Notation::from(Radical { coef: 0, rad: 2 }).is_zero());
+    /// assert!(!Notation::from(Fraction::new(0, 0)).is_zero());
+    /// ```
+    pub fn is_zero(&self) -> bool {
+        self.simplifies_to(0)
+    }
+
+    /// Returns true if the notation simplifies to 1, such as `3/3` or `√1`, false otherwise.
+    pub fn is_one(&self) -> bool {
+        self.simplifies_to(1)
+    }
+
+    /// Returns true if the notation simplifies to -1, such as `-2/2` or `-√1`, false otherwise.
+    pub fn is_negative_one(&self) -> bool {
+        self.simplifies_to(-1)
+    }
+
+    fn simplifies_to(&self, value: i32) -> bool {
+        match self {
+            Notation::Atom(atom) => *atom == value,
+            Notation::Expr(expr) => expr.simplified() == value,
+        }
+    }
+
+    /// Approximates the value of the notation as a float.
+    ///
+    /// Exact forms such as 2π and √3/2 are kept until this is called, so that rounding only happens once.
+    ///
+    /// Returns [`None`] if the notation has no specific real value.
+    /// See [`Atom::eval_f64()`] and [`Expr::eval_f64()`].
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, atom::Atom};
+    /// let circumference = Notation::from(2) * Notation::from(Atom::Pi);
+    /// assert_eq!(circumference.eval_f64(), Some(std::f64::consts::TAU));
+    /// assert_eq!(Notation::from(Atom::Undefined).eval_f64(), None);
+    /// ```
+    pub fn eval_f64(&self) -> Option<f64> {
+        match self {
+            Notation::Atom(atom) => atom.eval_f64(),
+            Notation::Expr(expr) => expr.eval_f64(),
+        }
+    }
+
+    /// Returns the number of bytes used by the notation, including its children on the heap.
+    ///
+    /// Children shared between clones are counted in full by every notation sharing them.
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, expr::trig::{Trig, TrigFunction}};
+    /// let angle = Notation::from(30);
+    /// let sin = Notation::from(Trig::new(TrigFunction::Sin, angle.clone()));
+    /// assert_eq!(angle.deep_size(), std::mem::size_of::<Notation>());
+    /// assert!(sin.deep_size() > 2 * angle.deep_size());
+    /// ```
+    pub fn deep_size(&self) -> usize {
+        let children = match self {
+            // An `Arc` allocates its reference counts alongside its value
+            Notation::Expr(Expr::Trig(Trig { arg, .. })) => {
+                2 * std::mem::size_of::<usize>() + arg.deep_size()
+            }
+            Notation::Expr(Expr::Complex(Complex { re, im })) => {
+                4 * std::mem::size_of::<usize>() + re.deep_size() + im.deep_size()
+            }
+            Notation::Expr(Expr::NestedFraction(NestedFraction { num, den })) => {
+                4 * std::mem::size_of::<usize>() + num.deep_size() + den.deep_size()
+            }
+            Notation::Expr(Expr::NestedRadical(NestedRadical { rad, .. })) => {
+                2 * std::mem::size_of::<usize>() + rad.deep_size()
+            }
+            Notation::Expr(Expr::Power(Power { base, exp })) => {
+                4 * std::mem::size_of::<usize>() + base.deep_size() + exp.deep_size()
+            }
+            Notation::Expr(Expr::Sum(Sum { terms })) => {
+                2 * std::mem::size_of::<usize>()
+                    + terms.iter().map(Notation::deep_size).sum::<usize>()
+            }
+            Notation::Expr(Expr::Product(Product { factors })) => {
+                2 * std::mem::size_of::<usize>()
+                    + factors.iter().map(Notation::deep_size).sum::<usize>()
+            }
+            _ => 0,
+        };
+        std::mem::size_of::<Self>() + children
+    }
 }
 
 // # Conversion
@@ -124,6 +300,21 @@ impl From<i32> for Notation {
     }
 }
 
+// ### Variable
+
+impl From<Var> for Notation {
+    fn from(value: Var) -> Self {
+        Self::from(Atom::Variable(value))
+    }
+}
+
+impl Default for Notation {
+    /// Construct the number 0.
+    fn default() -> Self {
+        Self::from(0)
+    }
+}
+
 // ## Expressions
 
 impl From<Expr> for Notation {
@@ -148,6 +339,173 @@ impl From<Radical> for Notation {
     }
 }
 
+// ### RadicalFraction
+
+impl From<RadicalFraction> for Notation {
+    fn from(value: RadicalFraction) -> Self {
+        Self::from(Expr::from(value))
+    }
+}
+
+// ### Trig
+
+impl From<Trig> for Notation {
+    fn from(value: Trig) -> Self {
+        Self::from(Expr::from(value))
+    }
+}
+
+// ### Multiple
+
+impl From<Multiple> for Notation {
+    fn from(value: Multiple) -> Self {
+        Self::from(Expr::from(value))
+    }
+}
+
+// ### Complex
+
+impl From<Complex> for Notation {
+    fn from(value: Complex) -> Self {
+        Self::from(Expr::from(value))
+    }
+}
+
+// ### Sum
+
+impl From<Sum> for Notation {
+    fn from(value: Sum) -> Self {
+        Self::from(Expr::from(value))
+    }
+}
+
+// ### NestedFraction
+
+impl From<NestedFraction> for Notation {
+    fn from(value: NestedFraction) -> Self {
+        Self::from(Expr::from(value))
+    }
+}
+
+// ### NestedRadical
+
+impl From<NestedRadical> for Notation {
+    fn from(value: NestedRadical) -> Self {
+        Self::from(Expr::from(value))
+    }
+}
+
+// ### Product
+
+impl From<Product> for Notation {
+    fn from(value: Product) -> Self {
+        Self::from(Expr::from(value))
+    }
+}
+
+// ### Power
+
+impl From<Power> for Notation {
+    fn from(value: Power) -> Self {
+        Self::from(Expr::from(value))
+    }
+}
+
+// ## Into concrete values
+
+/// Reasons notation could not be converted to a plain value.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ConversionError {
+    /// The notation is not an integer, such as 1/2 or √2.
+    NotInteger(String),
+
+    /// The notation is not a ratio of integers, such as √2 or π.
+    NotRational(String),
+
+    /// The notation has no specific real value, such as [`Undefined`][Atom::Undefined] or [`Huge`][Atom::Huge].
+    NotReal(String),
+
+    /// The value is too large for the type being converted to.
+    TooLarge(String),
+}
+
+impl std::fmt::Display for ConversionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ConversionError::NotInteger(value) => format!("{value} is not an integer").fmt(f),
+            ConversionError::NotRational(value) => {
+                format!("{value} is not a ratio of integers").fmt(f)
+            }
+            ConversionError::NotReal(value) => format!("{value} has no real value").fmt(f),
+            ConversionError::TooLarge(value) => format!("{value} is too large to convert").fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ConversionError {}
+
+impl TryFrom<Notation> for i32 {
+    type Error = ConversionError;
+
+    /// Extracts the value of a [`Number`].
+    ///
+    /// Notation is not simplified first, so an integer written as a fraction, such as 4/2, is not converted.
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, ConversionError, expr::fraction::Fraction};
+    /// assert_eq!(i32::try_from(Notation::from(-3)), Ok(-3));
+    /// assert_eq!(
+    ///     i32::try_from(Notation::from(Fraction::new(1, 2))),
+    ///     Err(ConversionError::NotInteger("1/2".to_string()))
+    /// );
+    /// ```
+    fn try_from(value: Notation) -> Result<Self, Self::Error> {
+        match value {
+            Notation::Atom(Atom::Number(n)) => Ok(n.value),
+            other => Err(ConversionError::NotInteger(other.to_string())),
+        }
+    }
+}
+
+impl TryFrom<Notation> for f64 {
+    type Error = ConversionError;
+
+    /// Approximates the notation with [`eval_f64`][Notation::eval_f64].
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, atom::Atom};
+    /// assert_eq!(f64::try_from(Notation::from(Atom::Pi)), Ok(std::f64::consts::PI));
+    /// assert!(f64::try_from(Notation::from(Atom::Huge)).is_err());
+    /// ```
+    fn try_from(value: Notation) -> Result<Self, Self::Error> {
+        value
+            .eval_f64()
+            .ok_or_else(|| ConversionError::NotReal(value.to_string()))
+    }
+}
+
+impl TryFrom<Notation> for Fraction {
+    type Error = ConversionError;
+
+    /// Extracts a [`Number`] or a [`Fraction`] of numbers as a fraction of integers.
+    ///
+    /// Fractions are not reduced, and a fraction with a denominator of 0 is [`NotReal`][ConversionError::NotReal].
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, atom::Atom, expr::fraction::Fraction};
+    /// assert_eq!(Fraction::try_from(Notation::from(3)), Ok(Fraction::new(3, 1)));
+    /// assert_eq!(Fraction::try_from(Notation::from(Fraction::new(2, 4))), Ok(Fraction::new(2, 4)));
+    /// assert!(Fraction::try_from(Notation::from(Atom::Pi)).is_err());
+    /// ```
+    fn try_from(value: Notation) -> Result<Self, Self::Error> {
+        match ops::ratio(&value) {
+            Some((num, den)) => Ok(Fraction::new(num, den)),
+            None if value.eval_f64().is_none() => Err(ConversionError::NotReal(value.to_string())),
+            None => Err(ConversionError::NotRational(value.to_string())),
+        }
+    }
+}
+
 // # Equality
 
 // ## Atoms
@@ -214,7 +572,404 @@ impl std::cmp::PartialEq<Radical> for Notation {
     }
 }
 
+// ### RadicalFraction
+
+impl std::cmp::PartialEq<RadicalFraction> for Notation {
+    fn eq(&self, other: &RadicalFraction) -> bool {
+        match self {
+            Self::Expr(expr) => expr == other,
+            _ => false,
+        }
+    }
+}
+
+// ### Trig
+
+impl std::cmp::PartialEq<Trig> for Notation {
+    fn eq(&self, other: &Trig) -> bool {
+        match self {
+            Self::Expr(expr) => expr == other,
+            _ => false,
+        }
+    }
+}
+
+// ### Multiple
+
+impl std::cmp::PartialEq<Multiple> for Notation {
+    fn eq(&self, other: &Multiple) -> bool {
+        match self {
+            Self::Expr(expr) => expr == other,
+            _ => false,
+        }
+    }
+}
+
+// ### Complex
+
+impl std::cmp::PartialEq<Complex> for Notation {
+    fn eq(&self, other: &Complex) -> bool {
+        match self {
+            Self::Expr(expr) => expr == other,
+            _ => false,
+        }
+    }
+}
+
+// ### Sum
+
+impl std::cmp::PartialEq<Sum> for Notation {
+    fn eq(&self, other: &Sum) -> bool {
+        match self {
+            Self::Expr(expr) => expr == other,
+            _ => false,
+        }
+    }
+}
+
+// ### NestedFraction
+
+impl std::cmp::PartialEq<NestedFraction> for Notation {
+    fn eq(&self, other: &NestedFraction) -> bool {
+        match self {
+            Self::Expr(expr) => expr == other,
+            _ => false,
+        }
+    }
+}
+
+// ### NestedRadical
+
+impl std::cmp::PartialEq<NestedRadical> for Notation {
+    fn eq(&self, other: &NestedRadical) -> bool {
+        match self {
+            Self::Expr(expr) => expr == other,
+            _ => false,
+        }
+    }
+}
+
+// ### Product
+
+impl std::cmp::PartialEq<Product> for Notation {
+    fn eq(&self, other: &Product) -> bool {
+        match self {
+            Self::Expr(expr) => expr == other,
+            _ => false,
+        }
+    }
+}
+
+// ### Power
+
+impl std::cmp::PartialEq<Power> for Notation {
+    fn eq(&self, other: &Power) -> bool {
+        match self {
+            Self::Expr(expr) => expr == other,
+            _ => false,
+        }
+    }
+}
+
+// # Ordering
+
+/// The value of a [`Notation`], reduced to a form where any two values can be compared.
+enum Magnitude {
+    NegativeHuge,
+    NegativeEpsilon,
+    Epsilon,
+    Huge,
+
+    /// A real number, stored as its sign and its square (as a fraction with positive denominator).
+    ///
+    /// Squaring lets rational numbers and square roots be compared exactly.
+    Real {
+        sign: i8,
+        square: (i128, i128),
+    },
+}
+
+impl Magnitude {
+    fn of(value: &Notation) -> Option<Self> {
+        use atom::Atom::*;
+        let rational = |num: i32, den: i32| {
+            let sign = (num.signum() * den.signum()) as i8;
+            let (num, den) = (num as i128, den as i128);
+            Magnitude::Real {
+                sign,
+                square: (num * num, den * den),
+            }
+        };
+        match value {
+            Notation::Atom(atom) => match atom {
+                Number(n) => Some(rational(n.value, 1)),
+                NegativeHuge => Some(Magnitude::NegativeHuge),
+                NegativeEpsilon => Some(Magnitude::NegativeEpsilon),
+                Epsilon => Some(Magnitude::Epsilon),
+                Huge => Some(Magnitude::Huge),
+                Complex | Undefined | Unknown | Variable(_) => None,
+                // Irrational, so the square isn't a ratio of integers
+                Pi | E => None,
+            },
+            Notation::Expr(Expr::Fraction(Fraction { num, den })) => match (num, den) {
+                (_, Number(atom::number::Number { value: 0 })) => None,
+                (Number(num), Number(den)) => Some(rational(num.value, den.value)),
+                _ => None,
+            },
+            Notation::Expr(Expr::Radical(Radical { coef, rad })) => match rad {
+                ..=-1 => None,
+                _ => Some(Magnitude::Real {
+                    sign: (coef.signum() * rad.signum()) as i8,
+                    square: ((*coef as i128) * (*coef as i128) * (*rad as i128), 1),
+                }),
+            },
+            Notation::Expr(Expr::RadicalFraction(RadicalFraction {
+                num: Radical { coef, rad },
+                den,
+            })) => match (rad, den) {
+                (..=-1, _) | (_, 0) => None,
+                _ => Some(Magnitude::Real {
+                    sign: (coef.signum() * rad.signum() * den.signum()) as i8,
+                    square: (
+                        (*coef as i128) * (*coef as i128) * (*rad as i128),
+                        (*den as i128) * (*den as i128),
+                    ),
+                }),
+            },
+            Notation::Expr(
+                Expr::Trig(_)
+                | Expr::Multiple(_)
+                | Expr::Complex(_)
+                | Expr::Sum(_)
+                | Expr::NestedFraction(_)
+                | Expr::NestedRadical(_)
+                | Expr::Product(_)
+                | Expr::Power(_),
+            ) => None,
+        }
+    }
+
+    /// Orders the categories of magnitude from least to greatest.
+    fn rank(&self) -> i8 {
+        use Magnitude::*;
+        match self {
+            NegativeHuge => -3,
+            Real { sign: -1, .. } => -2,
+            NegativeEpsilon => -1,
+            Real { sign: 0, .. } => 0,
+            Epsilon => 1,
+            Real { .. } => 2,
+            Huge => 3,
+        }
+    }
+
+    fn compare(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        use Magnitude::*;
+        match (self, other) {
+            (
+                Real {
+                    sign: sign_a,
+                    square: (num_a, den_a),
+                },
+                Real {
+                    sign: sign_b,
+                    square: (num_b, den_b),
+                },
+            ) if sign_a == sign_b => {
+                let cmp = num_a.checked_mul(*den_b)?.cmp(&num_b.checked_mul(*den_a)?);
+                Some(if *sign_a < 0 { cmp.reverse() } else { cmp })
+            }
+            (Real { .. }, _) | (_, Real { .. }) => Some(self.rank().cmp(&other.rank())),
+            _ if self.rank() == other.rank() => None,
+            _ => Some(self.rank().cmp(&other.rank())),
+        }
+    }
+}
+
 // Tests
 
 #[cfg(test)]
-mod tests {}
+mod tests {
+    use super::{atom::Atom::*, *};
+    use std::cmp::Ordering::*;
+
+    #[test]
+    fn test_size() {
+        // Children which could make notation large are kept on the heap
+        assert!(std::mem::size_of::<Notation>() <= 24);
+    }
+
+    #[test]
+    fn test_simplify_in_place() {
+        let values: [Notation; 4] = [
+            Atom::Pi.into(),
+            Fraction::new(6, -8).into(),
+            Sum::new([Radical::new(8).into(), Radical::new(2).into(), 1.into()]).into(),
+            Trig::new(
+                expr::trig::TrigFunction::Sin,
+                Sum::new([Notation::from(20), Notation::from(10)]),
+            )
+            .into(),
+        ];
+        for value in values {
+            let mut in_place = value.clone();
+            in_place.simplify_in_place();
+            assert_eq!(in_place, value.simplified(), "{value}");
+        }
+    }
+
+    #[test]
+    fn test_variables_pass_through() {
+        let x = || Notation::from(Var::new("x"));
+        let over = |num: Atom, den: Atom| Notation::from(Fraction { num, den });
+        assert_eq!(x().simplified(), x());
+        assert_ne!(x(), Notation::from(Var::new("y")));
+        let half_x = over(Atom::from(Var::new("x")), Atom::from(2));
+        assert_eq!(half_x.clone().simplified(), half_x);
+        assert_eq!(half_x.to_string(), "x/2");
+        assert!(over(Atom::from(Var::new("x")), Atom::from(0))
+            .simplified()
+            .atom()
+            .is_some_and(|x| x.is_undefined()));
+        assert_eq!((-x()).to_string(), "-x");
+        assert_eq!((-(-x())).simplified(), x());
+        assert!(!x().is_zero() && x().eval_f64().is_none());
+        assert_eq!(x().compare(&Notation::from(0)), None);
+    }
+
+    #[test]
+    fn test_identity_values() {
+        let values = [
+            (Notation::from(Fraction::new(0, 5)), 0),
+            (Notation::from(Radical { coef: 0, rad: 2 }), 0),
+            (Notation::from(expr::complex::Complex::new(0, 0)), 0),
+            (Notation::from(Fraction::new(3, 3)), 1),
+            (Notation::from(Radical::new(1)), 1),
+            (
+                Notation::from(Sum::new([
+                    Radical::new(2).into(),
+                    Radical { coef: -1, rad: 2 }.into(),
+                    1.into(),
+                ])),
+                1,
+            ),
+            (Notation::from(Fraction::new(-2, 2)), -1),
+            (Notation::from(Radical { coef: -1, rad: 1 }), -1),
+        ];
+        for (value, expected) in values {
+            assert_eq!(value.is_zero(), expected == 0, "{value:?}");
+            assert_eq!(value.is_one(), expected == 1, "{value:?}");
+            assert_eq!(value.is_negative_one(), expected == -1, "{value:?}");
+        }
+        for value in [
+            Notation::from(Undefined),
+            Notation::from(Epsilon),
+            Notation::from(Fraction::new(0, 0)),
+        ] {
+            assert!(!value.is_zero() && !value.is_one() && !value.is_negative_one());
+        }
+        assert!(Notation::default().is_zero());
+        assert!(Notation::from(Fraction::default()).is_zero());
+    }
+
+    #[test]
+    fn test_compare_rationals() {
+        for a in -5..=5 {
+            for b in -5..=5 {
+                let (lhs, rhs) = (Notation::from(a), Notation::from(b));
+                assert_eq!(lhs.compare(&rhs), Some(a.cmp(&b)));
+            }
+        }
+        let third = Notation::from(Fraction::new(1, 3));
+        let half = Notation::from(Fraction::new(-1, -2));
+        assert_eq!(third.compare(&half), Some(Less));
+        assert_eq!(
+            half.compare(&Notation::from(Fraction::new(2, 4))),
+            Some(Equal)
+        );
+    }
+
+    #[test]
+    fn test_compare_radicals() {
+        let root_two = Notation::from(Radical::new(2));
+        let neg_root_two = Notation::from(Radical { coef: -1, rad: 2 });
+        assert_eq!(
+            root_two.compare(&Notation::from(Fraction::new(3, 2))),
+            Some(Less)
+        );
+        assert_eq!(
+            root_two.compare(&Notation::from(Fraction::new(4, 3))),
+            Some(Greater)
+        );
+        assert_eq!(neg_root_two.compare(&Notation::from(-1)), Some(Less));
+        assert_eq!(Notation::from(Radical::new(-4)).compare(&root_two), None);
+    }
+
+    #[test]
+    fn test_compare_extremes() {
+        let one = Notation::from(1);
+        assert_eq!(Notation::from(Huge).compare(&one), Some(Greater));
+        assert_eq!(Notation::from(NegativeHuge).compare(&one), Some(Less));
+        assert_eq!(
+            Notation::from(Epsilon).compare(&Notation::from(0)),
+            Some(Greater)
+        );
+        assert_eq!(
+            Notation::from(NegativeEpsilon).compare(&Notation::from(-1)),
+            Some(Greater)
+        );
+        assert_eq!(
+            Notation::from(Epsilon).compare(&Notation::from(Epsilon)),
+            None
+        );
+        assert_eq!(Notation::from(Undefined).compare(&one), None);
+    }
+
+    #[test]
+    fn test_eval_f64() {
+        use expr::trig::TrigFunction;
+        let close = |value: Notation, expected: f64| {
+            assert!((value.eval_f64().unwrap() - expected).abs() < 1e-12);
+        };
+        close(Notation::from(Fraction::new(-3, 4)), -0.75);
+        close(Notation::from(Radical { coef: 2, rad: 2 }), 8f64.sqrt());
+        close(
+            Notation::from(RadicalFraction::new(1, 3, 2)),
+            3f64.sqrt() / 2.0,
+        );
+        close(
+            Notation::from(Trig::new(TrigFunction::Sin, 20)),
+            20f64.to_radians().sin(),
+        );
+        close(
+            Notation::from(Multiple::new(1, 2, Pi)),
+            std::f64::consts::FRAC_PI_2,
+        );
+        assert_eq!(Notation::from(Radical::new(-1)).eval_f64(), None);
+        assert_eq!(Notation::from(Fraction::new(1, 0)).eval_f64(), None);
+    }
+
+    #[test]
+    fn test_try_from() {
+        assert_eq!(i32::try_from(Notation::from(i32::MIN)), Ok(i32::MIN));
+        assert!(i32::try_from(Notation::from(Huge)).is_err());
+        assert!(i32::try_from(Notation::from(Radical::new(4))).is_err());
+        assert_eq!(f64::try_from(Notation::from(Fraction::new(1, 4))), Ok(0.25));
+        assert_eq!(
+            f64::try_from(Notation::from(Radical::new(-1))),
+            Err(ConversionError::NotReal("√-1".to_string()))
+        );
+        assert_eq!(
+            Fraction::try_from(Notation::from(Fraction::new(-1, 3))),
+            Ok(Fraction::new(-1, 3))
+        );
+        assert_eq!(
+            Fraction::try_from(Notation::from(Radical::new(2))),
+            Err(ConversionError::NotRational("√2".to_string()))
+        );
+        assert_eq!(
+            Fraction::try_from(Notation::from(Fraction::new(1, 0))),
+            Err(ConversionError::NotReal("1/0".to_string()))
+        );
+    }
+}
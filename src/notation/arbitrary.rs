@@ -0,0 +1,218 @@
+//! [`proptest`] strategies for generating random notation.
+//!
+//! Enabled by the `arbitrary` feature, which also implements [`Arbitrary`] so that
+//! [`any::<Atom>()`][any], [`any::<Fraction>()`][any], [`any::<Radical>()`][any], and
+//! [`any::<Notation>()`][any] work in downstream property tests.
+//!
+//! ```
+//! # use algebra::notation::{Notation, arbitrary};
+//! use proptest::prelude::*;
+//!
+//! proptest!(|(value in arbitrary::notation())| {
+//!     let _ = value.to_string();
+//! });
+//! proptest!(|(value: Notation)| {
+//!     let _ = value.to_dot();
+//! });
+//! ```
+
+use crate::{
+    notation::{
+        atom::Atom,
+        expr::{
+            complex::Complex,
+            fraction::Fraction,
+            multiple::Multiple,
+            nested_fraction::NestedFraction,
+            nested_radical::NestedRadical,
+            power::Power,
+            product::Product,
+            radical::Radical,
+            radical_fraction::RadicalFraction,
+            sum::Sum,
+            trig::{Trig, TrigFunction},
+        },
+        Notation,
+    },
+    solve::var::Var,
+};
+use proptest::{prelude::*, strategy::BoxedStrategy};
+use std::sync::Arc;
+
+/// Generates any [`i32`], favoring the small values where most special cases are.
+pub fn integer() -> impl Strategy<Value = i32> {
+    prop_oneof![-4..=4, any::<i32>()]
+}
+
+/// Generates one of a few [`Atom::Variable`]s, which can be read back from how they are displayed.
+pub fn variable() -> impl Strategy<Value = Atom> {
+    prop_oneof![Just("x"), Just("y"), Just("θ")].prop_map(|name| Atom::Variable(Var::new(name)))
+}
+
+/// Generates any [`Atom`].
+pub fn atom() -> impl Strategy<Value = Atom> {
+    prop_oneof![
+        4 => integer().prop_map(Atom::from),
+        1 => variable(),
+        1 => prop_oneof![
+            Just(Atom::Complex),
+            Just(Atom::Undefined),
+            Just(Atom::Huge),
+            Just(Atom::NegativeHuge),
+            Just(Atom::Epsilon),
+            Just(Atom::NegativeEpsilon),
+            Just(Atom::Unknown),
+            Just(Atom::Pi),
+            Just(Atom::E),
+        ],
+    ]
+}
+
+/// Generates one of the constants [`Atom::Pi`] and [`Atom::E`].
+pub fn constant() -> impl Strategy<Value = Atom> {
+    prop_oneof![Just(Atom::Pi), Just(Atom::E)]
+}
+
+/// Generates any [`Fraction`], not necessarily simplified, with any atoms for its numerator and denominator.
+pub fn fraction() -> impl Strategy<Value = Fraction> {
+    (atom(), atom()).prop_map(|(num, den)| Fraction { num, den })
+}
+
+/// Generates any [`Radical`], not necessarily simplified.
+pub fn radical() -> impl Strategy<Value = Radical> {
+    (integer(), integer()).prop_map(|(coef, rad)| Radical { coef, rad })
+}
+
+/// Generates any [`RadicalFraction`], not necessarily simplified.
+pub fn radical_fraction() -> impl Strategy<Value = RadicalFraction> {
+    (integer(), integer(), integer())
+        .prop_map(|(coef, rad, den)| RadicalFraction::new(coef, rad, den))
+}
+
+/// Generates any [`Multiple`] of a [`constant`] or [`variable`], not necessarily simplified.
+pub fn multiple() -> impl Strategy<Value = Multiple> {
+    (integer(), integer(), prop_oneof![constant(), variable()])
+        .prop_map(|(num, den, constant)| Multiple::new(num, den, constant))
+}
+
+/// Generates any [`Complex`] number, not necessarily simplified,
+/// with an integer, a fraction of integers, a [`Radical`], or a [`RadicalFraction`] for each part.
+pub fn complex() -> impl Strategy<Value = Complex> {
+    let part = || {
+        prop_oneof![
+            integer().prop_map(Notation::from),
+            (integer(), integer()).prop_map(|(num, den)| Notation::from(Fraction::new(num, den))),
+            radical().prop_map(Notation::from),
+            radical_fraction().prop_map(Notation::from),
+        ]
+    };
+    (part(), part()).prop_map(|(re, im)| Complex::new(re, im))
+}
+
+/// Generates any [`TrigFunction`].
+pub fn trig_function() -> impl Strategy<Value = TrigFunction> {
+    prop_oneof![
+        Just(TrigFunction::Sin),
+        Just(TrigFunction::Cos),
+        Just(TrigFunction::Tan),
+    ]
+}
+
+/// Generates a [`Trig`] of any of the arguments generated by `arg`.
+pub fn trig(arg: impl Strategy<Value = Notation>) -> impl Strategy<Value = Trig> {
+    (trig_function(), arg).prop_map(|(func, arg)| Trig::new(func, arg))
+}
+
+/// Generates a [`Sum`] of two or three of the terms generated by `term`, not necessarily simplified.
+pub fn sum(term: impl Strategy<Value = Notation>) -> impl Strategy<Value = Sum> {
+    proptest::collection::vec(term, 2..=3).prop_map(Sum::new)
+}
+
+/// Generates a [`Product`] of two or three of the factors generated by `factor`, not necessarily simplified.
+pub fn product(factor: impl Strategy<Value = Notation>) -> impl Strategy<Value = Product> {
+    proptest::collection::vec(factor, 2..=3).prop_map(Product::new)
+}
+
+/// Generates a [`NestedFraction`] with a numerator and denominator generated by `part`, not necessarily simplified.
+pub fn nested_fraction(
+    part: impl Strategy<Value = Notation> + Clone,
+) -> impl Strategy<Value = NestedFraction> {
+    (part.clone(), part).prop_map(|(num, den)| NestedFraction::new(num, den))
+}
+
+/// Generates a [`NestedRadical`] with any coefficient and a radicand generated by `rad`, not necessarily simplified.
+pub fn nested_radical(
+    rad: impl Strategy<Value = Notation>,
+) -> impl Strategy<Value = NestedRadical> {
+    (integer(), rad).prop_map(|(coef, rad)| NestedRadical {
+        coef,
+        rad: Arc::new(rad),
+    })
+}
+
+/// Generates a [`Power`] with a base and exponent generated by `part`, not necessarily simplified.
+pub fn power(part: impl Strategy<Value = Notation> + Clone) -> impl Strategy<Value = Power> {
+    (part.clone(), part).prop_map(|(base, exp)| Power::new(base, exp))
+}
+
+/// Generates notation without any nested notation inside it.
+pub fn leaf() -> impl Strategy<Value = Notation> {
+    prop_oneof![
+        atom().prop_map(Notation::from),
+        fraction().prop_map(Notation::from),
+        radical().prop_map(Notation::from),
+        radical_fraction().prop_map(Notation::from),
+        multiple().prop_map(Notation::from),
+        complex().prop_map(Notation::from),
+    ]
+}
+
+/// Generates any [`Notation`], including nested trees.
+pub fn notation() -> impl Strategy<Value = Notation> {
+    leaf().prop_recursive(4, 16, 3, |inner| {
+        prop_oneof![
+            trig(inner.clone()).prop_map(Notation::from),
+            sum(inner.clone()).prop_map(Notation::from),
+            product(inner.clone()).prop_map(Notation::from),
+            nested_fraction(inner.clone()).prop_map(Notation::from),
+            nested_radical(inner.clone()).prop_map(Notation::from),
+            power(inner).prop_map(Notation::from),
+        ]
+    })
+}
+
+impl Arbitrary for Atom {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        atom().boxed()
+    }
+}
+
+impl Arbitrary for Fraction {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        fraction().boxed()
+    }
+}
+
+impl Arbitrary for Radical {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        radical().boxed()
+    }
+}
+
+impl Arbitrary for Notation {
+    type Parameters = ();
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with(_: Self::Parameters) -> Self::Strategy {
+        notation().boxed()
+    }
+}
@@ -0,0 +1,182 @@
+//! Interning of notation, for building large numbers of expressions which share their parts.
+
+use std::{collections::HashMap, sync::Arc};
+
+use crate::notation::{
+    atom::Atom,
+    canonical::Canonical,
+    expr::{
+        fraction::Fraction,
+        radical::Radical,
+        trig::{Trig, TrigFunction},
+        Expr,
+    },
+    Notation,
+};
+
+/// A handle to notation stored in an [`ExprArena`].
+///
+/// Only meaningful to the arena which returned it.
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy, PartialOrd, Ord)]
+pub struct NodeId(usize);
+
+impl NodeId {
+    /// The position of the notation in its arena, counting from 0 in the order notation was first added.
+    pub fn index(self) -> usize {
+        self.0
+    }
+}
+
+/// What makes two nodes the same.
+///
+/// The children of a node are interned before it, so comparing their ids is enough to compare them.
+#[derive(PartialEq, Eq, Hash)]
+enum Key {
+    Leaf(Canonical),
+    Trig(TrigFunction, NodeId),
+}
+
+/// Storage which interns notation, handing out a [`NodeId`] for each distinct value.
+///
+/// Adding notation which is already in the arena returns the existing id without storing anything,
+/// so building many expressions out of the same atoms and sub-expressions only stores each of them once.
+/// Notation is compared by its [`Canonical`] structure, so `2/4` and `1/2` are stored separately.
+///
+/// Nested notation shares its children with the arena, so [`ExprArena::get`] never copies a tree.
+///
+/// ```
+/// # use algebra::notation::{Notation, arena::ExprArena, expr::trig::TrigFunction};
+/// let mut arena = ExprArena::new();
+/// let angle = arena.number(30);
+/// let sin = arena.trig(TrigFunction::Sin, angle);
+/// let cos = arena.trig(TrigFunction::Cos, angle);
+/// assert_eq!(arena.number(30), angle);
+/// assert_eq!(arena.trig(TrigFunction::Sin, angle), sin);
+/// assert_eq!(arena.len(), 3);
+/// assert_eq!(arena[cos].to_string(), "cos(30°)");
+/// ```
+#[derive(Default)]
+pub struct ExprArena {
+    nodes: Vec<Arc<Notation>>,
+    ids: HashMap<Key, NodeId>,
+}
+
+impl ExprArena {
+    /// Constructs an empty arena.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of distinct notations stored.
+    pub fn len(&self) -> usize {
+        self.nodes.len()
+    }
+
+    /// Returns true if nothing has been added, false otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.nodes.is_empty()
+    }
+
+    /// Returns the notation with the given id.
+    pub fn get(&self, id: NodeId) -> &Notation {
+        &self.nodes[id.0]
+    }
+
+    /// Returns the id of notation stored under `key`, storing it first if it is new.
+    fn insert(&mut self, key: Key, value: impl FnOnce() -> Notation) -> NodeId {
+        *self.ids.entry(key).or_insert_with(|| {
+            self.nodes.push(Arc::new(value()));
+            NodeId(self.nodes.len() - 1)
+        })
+    }
+
+    /// Adds notation without any nested notation inside it.
+    fn leaf(&mut self, value: Notation) -> NodeId {
+        self.insert(Key::Leaf(Canonical(value.clone())), || value)
+    }
+
+    /// Adds notation and everything nested inside it, returning its id.
+    pub fn add(&mut self, value: Notation) -> NodeId {
+        match value {
+            Notation::Expr(Expr::Trig(Trig { func, arg })) => {
+                let arg = self.add(Arc::unwrap_or_clone(arg));
+                self.trig(func, arg)
+            }
+            value => self.leaf(value),
+        }
+    }
+
+    /// Adds an atom, returning its id.
+    pub fn atom(&mut self, atom: Atom) -> NodeId {
+        self.leaf(Notation::from(atom))
+    }
+
+    /// Adds an integer, returning its id.
+    pub fn number(&mut self, value: i32) -> NodeId {
+        self.atom(Atom::from(value))
+    }
+
+    /// Adds the fraction `num/den`, returning its id.
+    pub fn fraction(&mut self, num: Atom, den: Atom) -> NodeId {
+        self.leaf(Notation::from(Fraction { num, den }))
+    }
+
+    /// Adds the radical `coef√rad`, returning its id.
+    pub fn radical(&mut self, coef: i32, rad: i32) -> NodeId {
+        self.leaf(Notation::from(Radical { coef, rad }))
+    }
+
+    /// Adds a trig function of notation already in the arena, returning its id.
+    ///
+    /// The argument is shared with the arena rather than copied.
+    pub fn trig(&mut self, func: TrigFunction, arg: NodeId) -> NodeId {
+        let child = Arc::clone(&self.nodes[arg.0]);
+        self.insert(Key::Trig(func, arg), || {
+            Notation::from(Trig { func, arg: child })
+        })
+    }
+}
+
+impl std::ops::Index<NodeId> for ExprArena {
+    type Output = Notation;
+
+    fn index(&self, id: NodeId) -> &Self::Output {
+        self.get(id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interning() {
+        let mut arena = ExprArena::new();
+        let half = arena.fraction(Atom::from(1), Atom::from(2));
+        assert_eq!(arena.fraction(Atom::from(1), Atom::from(2)), half);
+        assert_ne!(arena.fraction(Atom::from(2), Atom::from(4)), half);
+        assert_eq!(arena.atom(Atom::Undefined), arena.atom(Atom::Undefined));
+        assert_ne!(arena.radical(1, 2), arena.radical(2, 1));
+        assert_eq!(arena.len(), 5);
+        assert_eq!(arena[half], Fraction::new(1, 2));
+    }
+
+    #[test]
+    fn test_nested() {
+        let mut arena = ExprArena::new();
+        let nested = Trig::new(TrigFunction::Tan, Trig::new(TrigFunction::Sin, 45));
+        let id = arena.add(Notation::from(nested.clone()));
+        assert_eq!(arena.len(), 3);
+        assert_eq!(arena[id], nested);
+
+        let angle = arena.number(45);
+        let sin = arena.trig(TrigFunction::Sin, angle);
+        assert_eq!(arena.trig(TrigFunction::Tan, sin), id);
+        assert_eq!(arena.len(), 3);
+
+        let Notation::Expr(Expr::Trig(tan)) = &arena[id] else {
+            unreachable!()
+        };
+        assert!(std::ptr::eq(tan.arg.as_ref(), &arena[sin]));
+    }
+}
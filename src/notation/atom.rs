@@ -2,17 +2,33 @@
 
 pub mod number;
 
+use crate::{
+    notation::{
+        expr::simplify::Simplify,
+        format::{FormatOptions, FormatWith},
+        Notation,
+    },
+    solve::var::Var,
+};
 use number::Number;
 
 /// Algebraic Atom.
 ///
 /// The smallest unit of an algebraic expression.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug)]
 pub enum Atom {
     /// An explicit integer value.
     Number(Number),
 
-    /// The square root of a negative.
+    /// A number which isn't real, but can't be written exactly, such as a negative number to the power of π.
+    ///
+    /// Complex numbers which can be written exactly, such as the square root of a negative,
+    /// are [`Complex`][crate::notation::expr::complex::Complex] expressions instead,
+    /// so that √-4 and √-9 stay different values.
+    ///
+    /// When only real numbers are allowed, as under [`NumberDomain::Real`][crate::context::NumberDomain::Real],
+    /// anything which isn't real simplifies to this, meaning it's undefined in the reals.
     Complex,
 
     /// Any number divided by zero.
@@ -29,31 +45,40 @@ pub enum Atom {
 
     /// A negative fraction which isn't zero, but is too small to be operated on.
     NegativeEpsilon,
+
+    /// A value which exists, but can't be determined from the values which produced it,
+    /// such as [`Epsilon`] divided by [`Epsilon`], which could be any positive number.
+    Unknown,
+
+    /// The ratio of a circle's circumference to its diameter, π.
+    Pi,
+
+    /// Euler's number, 𝑒, the base of the natural logarithm.
+    E,
+
+    /// A variable, such as `x`, whose value isn't known.
+    Variable(Var),
 }
 
 impl std::ops::Neg for Atom {
-    type Output = Atom;
+    type Output = Notation;
 
+    /// Negates the atom, the same as negating it as [`Notation`].
+    ///
+    /// The negative of π, 𝑒, or a [`Variable`] isn't an atom, so it's a [`Multiple`][crate::notation::expr::multiple::Multiple] of it,
+    /// and the negative of [`i32::MIN`] doesn't fit in a [`Number`], so it's [`Huge`].
+    ///
+    /// ```
+    /// # use algebra::notation::{atom::Atom, expr::multiple::Multiple};
+    /// assert_eq!(-Atom::from(3), -3);
+    /// assert_eq!(-Atom::Pi, Multiple::new(-1, 1, Atom::Pi));
+    /// assert!((-Atom::Huge).atom().is_some_and(|x| x.is_negative_huge()));
+    /// ```
     fn neg(self) -> Self::Output {
-        match self {
-            Atom::Number(n) => Atom::Number(-n),
-            Complex => Complex,
-            Undefined => Undefined,
-            Huge => NegativeHuge,
-            NegativeHuge => Huge,
-            Epsilon => NegativeEpsilon,
-            NegativeEpsilon => Epsilon,
-        }
+        -Notation::from(self)
     }
 }
 
-macro_rules! symbol {
-    [Imaginary] => ("𝑖");
-    [EmptySet] => ("∅");
-    [Huge] => ("𝓗");
-    [Epsilon] => ("ε");
-}
-
 use Atom::*;
 
 impl Atom {
@@ -65,103 +90,250 @@ impl Atom {
         }
     }
 
+    /// Returns the negative of the atom, or [`None`] if it isn't an atom, as with π, 𝑒, [`Variable`]s, and [`i32::MIN`].
+    ///
+    /// ```
+    /// # use algebra::notation::atom::Atom;
+    /// assert!(Atom::Epsilon.checked_neg().is_some_and(|x| x.is_negative_epsilon()));
+    /// assert!(Atom::Pi.checked_neg().is_none());
+    /// assert!(Atom::from(i32::MIN).checked_neg().is_none());
+    /// ```
+    pub fn checked_neg(&self) -> Option<Atom> {
+        Some(match self {
+            Number(n) => Atom::from(n.value.checked_neg()?),
+            Complex => Complex,
+            Undefined => Undefined,
+            Huge => NegativeHuge,
+            NegativeHuge => Huge,
+            Epsilon => NegativeEpsilon,
+            NegativeEpsilon => Epsilon,
+            Unknown => Unknown,
+            Pi | E | Variable(_) => return None,
+        })
+    }
+
     /// Returns true for [`Atom::Number`], false otherwise.
     pub fn is_number(&self) -> bool {
-        match self {
-            Number(_) => true,
-            _ => false,
-        }
+        matches!(self, Number(_))
     }
 
     /// Returns true for
     /// - [`Atom::Number`] where >= 0
     /// - [`Huge`]
     /// - [`Epsilon`]
-    /// and false otherwise.
+    /// - [`Pi`]
+    /// - [`E`]
+    ///
+    /// Returns false otherwise.
     pub fn is_positive(&self) -> bool {
-        match self {
-            Number(Number { value: 0.. }) | Huge | Epsilon => true,
-            _ => false,
-        }
+        matches!(
+            self,
+            Number(Number { value: 0.. }) | Huge | Epsilon | Pi | E
+        )
     }
 
     /// Returns true for
     /// - [`Atom::Number`] where < 0
     /// - [`NegativeHuge`]
     /// - [`NegativeEpsilon`]
-    /// and false otherwise.
+    ///
+    /// Returns false otherwise.
     pub fn is_negative(&self) -> bool {
-        match self {
-            Number(Number { value: ..=-1 }) | NegativeHuge | NegativeEpsilon => true,
-            _ => false,
-        }
+        matches!(
+            self,
+            Number(Number { value: ..=-1 }) | NegativeHuge | NegativeEpsilon
+        )
     }
 
     /// Returns true for [`Complex`], false otherwise.
     pub fn is_complex(&self) -> bool {
-        match self {
-            Complex => true,
-            _ => false,
-        }
+        matches!(self, Complex)
     }
 
     /// Returns true for [`Undefined`], false otherwise.
     pub fn is_undefined(&self) -> bool {
-        match self {
-            Undefined => true,
-            _ => false,
-        }
+        matches!(self, Undefined)
+    }
+
+    /// Returns true for [`Unknown`], false otherwise.
+    pub fn is_unknown(&self) -> bool {
+        matches!(self, Unknown)
     }
 
     /// Returns true for [`Huge`] and [`NegativeHuge`], false otherwise.
     pub fn is_huge(&self) -> bool {
-        match self {
-            Huge | NegativeHuge => true,
-            _ => false,
-        }
+        matches!(self, Huge | NegativeHuge)
     }
 
     /// Returns true for [`Huge`], false otherwise.
     pub fn is_positive_huge(&self) -> bool {
-        match self {
-            Huge => true,
-            _ => false,
-        }
+        matches!(self, Huge)
     }
 
     /// Returns true for [`Huge`], false otherwise.
     pub fn is_negative_huge(&self) -> bool {
-        match self {
-            NegativeHuge => true,
-            _ => false,
-        }
+        matches!(self, NegativeHuge)
     }
 
     /// Returns true for [`Epsilon`] and [`NegativeEpsilon`], false otherwise.
     pub fn is_epsilon(&self) -> bool {
-        match self {
-            Epsilon | NegativeEpsilon => true,
-            _ => false,
-        }
+        matches!(self, Epsilon | NegativeEpsilon)
     }
 
     /// Returns true for [`Epsilon`], false otherwise.
     pub fn is_positive_epsilon(&self) -> bool {
-        match self {
-            Epsilon => true,
-            _ => false,
-        }
+        matches!(self, Epsilon)
     }
 
     /// Returns true for [`NegativeEpsilon`], false otherwise.
     pub fn is_negative_epsilon(&self) -> bool {
+        matches!(self, NegativeEpsilon)
+    }
+
+    /// Returns true for [`Pi`] and [`E`], false otherwise.
+    pub fn is_constant(&self) -> bool {
+        matches!(self, Pi | E)
+    }
+
+    /// Returns true for [`Pi`], false otherwise.
+    pub fn is_pi(&self) -> bool {
+        matches!(self, Pi)
+    }
+
+    /// Returns true for [`E`], false otherwise.
+    pub fn is_e(&self) -> bool {
+        matches!(self, E)
+    }
+
+    /// Returns true for [`Variable`], false otherwise.
+    pub fn is_variable(&self) -> bool {
+        matches!(self, Variable(_))
+    }
+
+    /// Returns which kind of atom this is, without any value it holds.
+    ///
+    /// ```
+    /// # use algebra::notation::atom::{Atom, AtomKind};
+    /// assert_eq!(Atom::from(7).kind(), AtomKind::Number);
+    /// assert_eq!(Atom::Undefined.kind().as_str(), "undefined");
+    /// ```
+    pub fn kind(&self) -> AtomKind {
         match self {
-            NegativeEpsilon => true,
-            _ => false,
+            Number(_) => AtomKind::Number,
+            Complex => AtomKind::Complex,
+            Undefined => AtomKind::Undefined,
+            Huge => AtomKind::Huge,
+            NegativeHuge => AtomKind::NegativeHuge,
+            Epsilon => AtomKind::Epsilon,
+            NegativeEpsilon => AtomKind::NegativeEpsilon,
+            Unknown => AtomKind::Unknown,
+            Pi => AtomKind::Pi,
+            E => AtomKind::E,
+            Variable(_) => AtomKind::Variable,
+        }
+    }
+
+    /// Approximates the value of the atom as a float.
+    ///
+    /// Returns [`None`] for atoms without a specific real value:
+    /// [`Complex`], [`Undefined`], [`Unknown`], [`Variable`]s, and [`Huge`] and [`Epsilon`] and their negatives.
+    pub fn eval_f64(&self) -> Option<f64> {
+        match self {
+            Number(n) => Some(n.value as f64),
+            Pi => Some(std::f64::consts::PI),
+            E => Some(std::f64::consts::E),
+            _ => None,
         }
     }
 }
 
+/// The kinds of [`Atom`], without the values they hold, each with a stable identifier.
+///
+/// The identifiers never change between versions, so front ends can use them as keys,
+/// such as for translating the names of atoms like [`Undefined`] into a student's language.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub enum AtomKind {
+    /// [`Atom::Number`].
+    Number,
+
+    /// [`Atom::Complex`].
+    Complex,
+
+    /// [`Atom::Undefined`].
+    Undefined,
+
+    /// [`Atom::Huge`].
+    Huge,
+
+    /// [`Atom::NegativeHuge`].
+    NegativeHuge,
+
+    /// [`Atom::Epsilon`].
+    Epsilon,
+
+    /// [`Atom::NegativeEpsilon`].
+    NegativeEpsilon,
+
+    /// [`Atom::Unknown`].
+    Unknown,
+
+    /// [`Atom::Pi`].
+    Pi,
+
+    /// [`Atom::E`].
+    E,
+
+    /// [`Atom::Variable`].
+    Variable,
+}
+
+impl AtomKind {
+    /// Every kind, in order.
+    pub const ALL: [AtomKind; 11] = [
+        AtomKind::Number,
+        AtomKind::Complex,
+        AtomKind::Undefined,
+        AtomKind::Huge,
+        AtomKind::NegativeHuge,
+        AtomKind::Epsilon,
+        AtomKind::NegativeEpsilon,
+        AtomKind::Unknown,
+        AtomKind::Pi,
+        AtomKind::E,
+        AtomKind::Variable,
+    ];
+
+    /// Returns the kind's stable identifier, in `snake_case`.
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            AtomKind::Number => "number",
+            AtomKind::Complex => "complex",
+            AtomKind::Undefined => "undefined",
+            AtomKind::Huge => "huge",
+            AtomKind::NegativeHuge => "negative_huge",
+            AtomKind::Epsilon => "epsilon",
+            AtomKind::NegativeEpsilon => "negative_epsilon",
+            AtomKind::Unknown => "unknown",
+            AtomKind::Pi => "pi",
+            AtomKind::E => "e",
+            AtomKind::Variable => "variable",
+        }
+    }
+}
+
+impl std::fmt::Display for AtomKind {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl Simplify for Atom {
+    /// Atoms are already as simple as they can be, and are returned unchanged.
+    fn simplify(self) -> Notation {
+        Notation::Atom(self)
+    }
+}
+
 impl From<i32> for Atom {
     /// Construct an [`Atom::Number`] from an integer.
     fn from(value: i32) -> Self {
@@ -169,23 +341,23 @@ impl From<i32> for Atom {
     }
 }
 
+impl From<Var> for Atom {
+    /// Construct an [`Atom::Variable`] from a variable.
+    fn from(value: Var) -> Self {
+        Variable(value)
+    }
+}
+
 impl std::fmt::Display for Atom {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        use Atom::*;
-        match self {
-            Number(n) => n.fmt(f),
-            Complex => symbol![Imaginary].fmt(f),
-            Undefined => symbol![EmptySet].fmt(f),
-            Huge => symbol![Huge].fmt(f),
-            NegativeHuge => concat!("-", symbol![Huge]).fmt(f),
-            Epsilon => symbol![Epsilon].fmt(f),
-            NegativeEpsilon => concat!("-", symbol![Epsilon]).fmt(f),
-        }
+        self.format_with(&FormatOptions::default()).fmt(f)
     }
 }
 
 impl std::cmp::PartialEq for Atom {
-    /// In the current implementation, only [`Atom::Number`]s can be meaningfully tested for equality.
+    /// In the current implementation, only [`Atom::Number`]s, the constants [`Pi`] and [`E`],
+    /// and [`Variable`]s can be meaningfully tested for equality.
+    /// Variables are equal if they have the same name.
     ///
     /// [`Complex`], [`Huge`], and [`Epsilon`]
     /// do not store distinguishing information, despite equality being mathematical defined.
@@ -196,6 +368,8 @@ impl std::cmp::PartialEq for Atom {
         use Atom::*;
         match (self, other) {
             (Number(a), Number(b)) => a == b,
+            (Pi, Pi) | (E, E) => true,
+            (Variable(a), Variable(b)) => a == b,
             _ => false,
         }
     }
@@ -220,3 +394,93 @@ impl std::cmp::PartialEq<i32> for Atom {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_constants() {
+        assert_eq!(Pi, Pi);
+        assert_ne!(Pi, E);
+        assert!(Pi.is_positive() && E.is_positive());
+        assert_eq!(Pi.to_string(), "π");
+        assert_eq!(E.to_string(), "𝑒");
+    }
+
+    #[test]
+    fn test_eval_f64() {
+        assert_eq!(Atom::from(-3).eval_f64(), Some(-3.0));
+        assert_eq!(E.eval_f64(), Some(std::f64::consts::E));
+        assert_eq!(Huge.eval_f64(), None);
+        assert_eq!(Undefined.eval_f64(), None);
+        assert_eq!(Unknown.eval_f64(), None);
+        assert_eq!(Variable(Var::new("x")).eval_f64(), None);
+    }
+
+    #[test]
+    fn test_variables() {
+        let (x, y) = (Variable(Var::new("x")), Variable(Var::new("y")));
+        assert_eq!(x, Variable(Var::new("x")));
+        assert_ne!(x, y);
+        assert_ne!(x, Pi);
+        assert!(x.is_variable() && !x.is_positive() && !x.is_negative());
+        assert_eq!(x.to_string(), "x");
+        assert_eq!(Variable(Var::new("θ")).to_string(), "θ");
+    }
+
+    #[test]
+    fn test_kinds() {
+        let atoms = [
+            Atom::from(-4),
+            Complex,
+            Undefined,
+            Huge,
+            NegativeHuge,
+            Epsilon,
+            NegativeEpsilon,
+            Unknown,
+            Pi,
+            E,
+            Atom::from(Var::from("x")),
+        ];
+        let kinds = atoms.iter().map(Atom::kind).collect::<Vec<_>>();
+        assert_eq!(kinds, AtomKind::ALL);
+
+        let mut ids = AtomKind::ALL.map(|kind| kind.as_str());
+        ids.sort();
+        ids.windows(2).for_each(|pair| assert_ne!(pair[0], pair[1]));
+        assert_eq!(NegativeEpsilon.kind().to_string(), "negative_epsilon");
+    }
+
+    #[test]
+    fn test_neg() {
+        use crate::notation::expr::multiple::Multiple;
+        let atoms = [
+            Atom::from(-4),
+            Atom::from(i32::MIN),
+            Complex,
+            Undefined,
+            Huge,
+            NegativeHuge,
+            Epsilon,
+            NegativeEpsilon,
+            Unknown,
+            Pi,
+            E,
+            Atom::from(Var::from("x")),
+        ];
+        for atom in atoms {
+            let negative = -atom.clone();
+            match atom.checked_neg() {
+                Some(neg) => assert_eq!(negative.to_string(), neg.to_string(), "-{atom}"),
+                None => assert!(!matches!(negative, Notation::Atom(_)) || atom.is_number()),
+            }
+        }
+        assert_eq!(-Pi, Multiple::new(-1, 1, Pi));
+        assert_eq!(-E, Multiple::new(-1, 1, E));
+        assert!((-Atom::from(i32::MIN))
+            .atom()
+            .is_some_and(|x| x.is_positive_huge()));
+    }
+}
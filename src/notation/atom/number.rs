@@ -1,6 +1,10 @@
 //! A single number.
 
+mod roman;
+mod words;
+
 /// A single integer number.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq, Eq)]
 pub struct Number {
     /// The value the number represents.
@@ -21,6 +25,63 @@ impl std::ops::Neg for Number {
     }
 }
 
+// # Bases
+
+impl Number {
+    /// Returns an adapter which displays the number in base `radix`, using the letters `a` through `z` for digits above 9.
+    ///
+    /// The alternate flag (`{:#}`) adds a `0b`, `0o`, or `0x` prefix in binary, octal, and hexadecimal.
+    ///
+    /// # Panics
+    /// Panics if `radix` is not from 2 to 36.
+    ///
+    /// ```
+    /// # use algebra::notation::atom::number::Number;
+    /// assert_eq!(Number::from(10).in_base(2).to_string(), "1010");
+    /// assert_eq!(format!("{:#}", Number::from(-255).in_base(16)), "-0xff");
+    /// assert_eq!(Number::from(35).in_base(36).to_string(), "z");
+    /// ```
+    pub fn in_base(&self, radix: u32) -> InBase {
+        assert!((2..=36).contains(&radix), "radix must be from 2 to 36");
+        InBase {
+            value: self.value,
+            radix,
+        }
+    }
+}
+
+/// Displays a [`Number`] in a base other than 10.
+///
+/// Produced by [`in_base()`][Number::in_base()].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct InBase {
+    value: i32,
+    radix: u32,
+}
+
+impl std::fmt::Display for InBase {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut rest = self.value.unsigned_abs();
+        let mut digits = Vec::new();
+        loop {
+            digits.push(std::char::from_digit(rest % self.radix, self.radix).unwrap());
+            rest /= self.radix;
+            if rest == 0 {
+                break;
+            }
+        }
+        let sign = if self.value < 0 { "-" } else { "" };
+        let prefix = match (f.alternate(), self.radix) {
+            (true, 2) => "0b",
+            (true, 8) => "0o",
+            (true, 16) => "0x",
+            _ => "",
+        };
+        let digits = digits.into_iter().rev().collect::<String>();
+        format!("{sign}{prefix}{digits}").fmt(f)
+    }
+}
+
 // # Conversion
 
 impl From<i32> for Number {
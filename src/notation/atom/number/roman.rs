@@ -0,0 +1,99 @@
+//! Roman numerals.
+
+use super::Number;
+
+/// The value of each numeral, including the subtractive pairs, from largest to smallest.
+const NUMERALS: [(i32, &str); 13] = [
+    (1000, "M"),
+    (900, "CM"),
+    (500, "D"),
+    (400, "CD"),
+    (100, "C"),
+    (90, "XC"),
+    (50, "L"),
+    (40, "XL"),
+    (10, "X"),
+    (9, "IX"),
+    (5, "V"),
+    (4, "IV"),
+    (1, "I"),
+];
+
+impl Number {
+    /// Writes the number in Roman numerals, using subtractive pairs such as `IV` for 4.
+    ///
+    /// Returns [`None`] outside of 1 to 3999, which standard numerals can't write.
+    ///
+    /// ```
+    /// # use algebra::notation::atom::number::Number;
+    /// assert_eq!(Number::from(1994).to_roman(), Some("MCMXCIV".to_string()));
+    /// assert_eq!(Number::from(0).to_roman(), None);
+    /// ```
+    pub fn to_roman(&self) -> Option<String> {
+        if !(1..=3999).contains(&self.value) {
+            return None;
+        }
+        let mut rest = self.value;
+        let mut roman = String::new();
+        for (value, numeral) in NUMERALS {
+            while rest >= value {
+                roman.push_str(numeral);
+                rest -= value;
+            }
+        }
+        Some(roman)
+    }
+
+    /// Reads a number written in Roman numerals of either case.
+    ///
+    /// Only accepts the standard form written by [`to_roman`][Number::to_roman()],
+    /// so `IIII` and `IC` are rejected in favor of `IV` and `XCIX`.
+    ///
+    /// ```
+    /// # use algebra::notation::atom::number::Number;
+    /// assert_eq!(Number::from_roman("xlii"), Some(Number::from(42)));
+    /// assert_eq!(Number::from_roman("IC"), None);
+    /// ```
+    pub fn from_roman(s: &str) -> Option<Self> {
+        let upper = s.to_uppercase();
+        let mut rest = upper.as_str();
+        let mut value = 0;
+        for (numeral_value, numeral) in NUMERALS {
+            while let Some(after) = rest.strip_prefix(numeral) {
+                value += numeral_value;
+                rest = after;
+            }
+        }
+        // Numerals which are out of order or repeated too often don't write back the same way
+        let number = Number::from(value);
+        (number.to_roman().as_deref() == Some(upper.as_str())).then_some(number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_roman() {
+        let roman = |value: i32| Number::from(value).to_roman();
+        assert_eq!(roman(1).as_deref(), Some("I"));
+        assert_eq!(roman(4).as_deref(), Some("IV"));
+        assert_eq!(roman(49).as_deref(), Some("XLIX"));
+        assert_eq!(roman(2024).as_deref(), Some("MMXXIV"));
+        assert_eq!(roman(3999).as_deref(), Some("MMMCMXCIX"));
+        assert_eq!(roman(4000), None);
+        assert_eq!(roman(-5), None);
+    }
+
+    #[test]
+    fn test_from_roman() {
+        for value in 1..=3999 {
+            let roman = Number::from(value).to_roman().unwrap();
+            assert_eq!(Number::from_roman(&roman), Some(Number::from(value)));
+        }
+        for invalid in ["", "IIII", "VX", "IL", "MMMM", "XIIV", "ABC", "I V"] {
+            assert_eq!(Number::from_roman(invalid), None, "{invalid}");
+        }
+    }
+}
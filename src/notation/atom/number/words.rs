@@ -0,0 +1,191 @@
+//! Numbers written out in English words.
+
+use super::Number;
+
+const ONES: [&str; 20] = [
+    "zero",
+    "one",
+    "two",
+    "three",
+    "four",
+    "five",
+    "six",
+    "seven",
+    "eight",
+    "nine",
+    "ten",
+    "eleven",
+    "twelve",
+    "thirteen",
+    "fourteen",
+    "fifteen",
+    "sixteen",
+    "seventeen",
+    "eighteen",
+    "nineteen",
+];
+
+/// The words for each multiple of ten, starting from 20 at index 2.
+const TENS: [&str; 10] = [
+    "", "", "twenty", "thirty", "forty", "fifty", "sixty", "seventy", "eighty", "ninety",
+];
+
+/// The words for each power of a thousand, from largest to smallest.
+const SCALES: [(u32, &str); 3] = [
+    (1_000_000_000, "billion"),
+    (1_000_000, "million"),
+    (1_000, "thousand"),
+];
+
+/// Writes a number from 1 to 999, such as "three hundred forty-two".
+fn hundreds_to_words(n: u32) -> String {
+    let (hundreds, rest) = (n / 100, n % 100);
+    let rest = match rest {
+        0 => None,
+        1..20 => Some(ONES[rest as usize].to_string()),
+        _ => Some(match rest % 10 {
+            0 => TENS[rest as usize / 10].to_string(),
+            ones => format!("{}-{}", TENS[rest as usize / 10], ONES[ones as usize]),
+        }),
+    };
+    match (hundreds, rest) {
+        (0, rest) => rest.unwrap_or_default(),
+        (hundreds, None) => format!("{} hundred", ONES[hundreds as usize]),
+        (hundreds, Some(rest)) => format!("{} hundred {rest}", ONES[hundreds as usize]),
+    }
+}
+
+/// Splits words on spaces and hyphens, ignoring case and the word "and".
+fn tokens(s: &str) -> Vec<String> {
+    s.to_lowercase()
+        .split(|c: char| c.is_whitespace() || c == '-')
+        .filter(|token| !token.is_empty() && *token != "and")
+        .map(String::from)
+        .collect()
+}
+
+impl Number {
+    /// Writes the number out in English words, such as "three hundred forty-two".
+    ///
+    /// Uses the short scale, where a billion is 1,000,000,000, and starts negative numbers with "negative".
+    ///
+    /// ```
+    /// # use algebra::notation::atom::number::Number;
+    /// assert_eq!(Number::from(342).to_words(), "three hundred forty-two");
+    /// assert_eq!(Number::from(-1_000_017).to_words(), "negative one million seventeen");
+    /// ```
+    pub fn to_words(&self) -> String {
+        if self.value == 0 {
+            return ONES[0].to_string();
+        }
+        let mut rest = self.value.unsigned_abs();
+        let mut words = Vec::new();
+        if self.value < 0 {
+            words.push("negative".to_string());
+        }
+        for (scale, name) in SCALES {
+            if rest >= scale {
+                words.push(format!("{} {name}", hundreds_to_words(rest / scale)));
+                rest %= scale;
+            }
+        }
+        if rest > 0 {
+            words.push(hundreds_to_words(rest));
+        }
+        words.join(" ")
+    }
+
+    /// Reads a number written out in English words, the way [`to_words`][Number::to_words()] writes it.
+    ///
+    /// Ignores case, accepts spaces in place of hyphens, and allows "and" between words, as in "one hundred and five".
+    /// Negative numbers may start with "negative" or "minus".
+    ///
+    /// ```
+    /// # use algebra::notation::atom::number::Number;
+    /// assert_eq!(Number::from_words("Forty Two"), Some(Number::from(42)));
+    /// assert_eq!(Number::from_words("one hundred and five"), Some(Number::from(105)));
+    /// assert_eq!(Number::from_words("hundred five"), None);
+    /// ```
+    pub fn from_words(s: &str) -> Option<Self> {
+        let mut words = tokens(s);
+        let sign = match words.first().map(String::as_str) {
+            Some("negative" | "minus") => {
+                words[0] = "negative".to_string();
+                -1
+            }
+            _ => 1,
+        };
+        let (mut total, mut current) = (0i64, 0i64);
+        for token in words.iter().skip(usize::from(sign < 0)) {
+            if let Some(value) = ONES.iter().position(|word| word == token) {
+                current = current.saturating_add(value as i64);
+            } else if let Some(tens) = TENS[2..].iter().position(|word| word == token) {
+                current = current.saturating_add((tens as i64 + 2) * 10);
+            } else if token == "hundred" {
+                current = current.saturating_mul(100);
+            } else if let Some((scale, _)) = SCALES.iter().find(|(_, name)| name == token) {
+                total = total.saturating_add(current.saturating_mul(*scale as i64));
+                current = 0;
+            } else {
+                return None;
+            }
+        }
+        // Saturates rather than overflowing on nonsense like "one hundred hundred hundred…"
+        let number = Number::from(i32::try_from(sign * total.saturating_add(current)).ok()?);
+        // Words which are out of order, such as "five hundred two thousand thousand", don't write back the same way
+        (tokens(&number.to_words()) == words).then_some(number)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_to_words() {
+        let words = |value: i32| Number::from(value).to_words();
+        assert_eq!(words(0), "zero");
+        assert_eq!(words(13), "thirteen");
+        assert_eq!(words(40), "forty");
+        assert_eq!(words(99), "ninety-nine");
+        assert_eq!(words(100), "one hundred");
+        assert_eq!(words(1_002), "one thousand two");
+        assert_eq!(words(20_300_000), "twenty million three hundred thousand");
+        assert_eq!(
+            words(i32::MIN),
+            "negative two billion one hundred forty-seven million four hundred eighty-three thousand six hundred forty-eight"
+        );
+    }
+
+    #[test]
+    fn test_from_words() {
+        for value in (-1100..=1100).chain([1_000_000, 1_234_567, -90_000_009, i32::MAX, i32::MIN]) {
+            let words = Number::from(value).to_words();
+            assert_eq!(
+                Number::from_words(&words),
+                Some(Number::from(value)),
+                "{words}"
+            );
+        }
+        assert_eq!(Number::from_words("Minus SIX"), Some(Number::from(-6)));
+        assert_eq!(Number::from_words("twenty one"), Some(Number::from(21)));
+        for invalid in [
+            "",
+            "negative",
+            "negative zero",
+            "one one",
+            "twenty ninety",
+            "ten hundred",
+            "thousand",
+            "one million million",
+            "two thousand one million",
+            "three billion",
+            "seventy-eleven",
+            "forty two apples",
+            &"one hundred ".repeat(20),
+            &"nine billion ".repeat(20),
+        ] {
+            assert_eq!(Number::from_words(invalid), None, "{invalid}");
+        }
+    }
+}
@@ -0,0 +1,505 @@
+//! A wrapper giving notation total, structural equality, for use as a key in sets and maps.
+
+use crate::{
+    notation::{
+        atom::Atom,
+        expr::{
+            complex::Complex,
+            fraction::Fraction,
+            multiple::Multiple,
+            nested_fraction::NestedFraction,
+            nested_radical::NestedRadical,
+            power::Power,
+            product::Product,
+            radical::Radical,
+            radical_fraction::RadicalFraction,
+            sum::Sum,
+            trig::{Trig, TrigFunction},
+            Expr,
+        },
+        ops::{multiple_of, ratio},
+        Notation,
+    },
+    solve::var::Var,
+};
+use std::sync::Arc;
+
+/// Notation compared by its structure rather than its value.
+///
+/// [`PartialEq`] on [`Notation`] is intentionally partial: [`Undefined`][Atom::Undefined] is not equal to itself,
+/// so notation can't be put in a [`HashSet`][std::collections::HashSet] or used as a key in a [`HashMap`][std::collections::HashMap].
+/// `Canonical` implements [`Eq`], [`Hash`], and [`Ord`] instead, with two notations being equal only if they are built
+/// from the same variants holding the same values.
+///
+/// This is not mathematical equality: `2/4` and `1/2` are different, so simplify notation before wrapping it
+/// if equivalent forms should be deduplicated.
+///
+/// Ordering is also structural and only meant to be consistent, for sorted collections.
+/// Atoms come before expressions, [`Number`][Atom::Number]s are ordered by value, and other variants are ordered by their declaration.
+///
+/// ```
+/// # use algebra::notation::{Notation, atom::Atom, canonical::Canonical, expr::fraction::Fraction};
+/// # use std::collections::HashSet;
+/// let undefined = Notation::from(Atom::Undefined);
+/// assert_ne!(undefined, undefined.clone());
+/// assert_eq!(Canonical(undefined.clone()), Canonical(undefined.clone()));
+///
+/// let set = HashSet::from([
+///     Canonical(undefined.clone()),
+///     Canonical(undefined),
+///     Canonical(Notation::from(Fraction::new(1, 2))),
+///     Canonical(Notation::from(Fraction::new(2, 4))),
+/// ]);
+/// assert_eq!(set.len(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Canonical(pub Notation);
+
+/// Writes the structure of notation as integers, one tag for each variant followed by its values.
+///
+/// Each tag is followed by a fixed number of parts, or by the number of parts that follow it,
+/// so no two structures are written the same.
+/// Nodes are written in [preorder][Notation::preorder], each followed by the notation inside it.
+fn structure(value: &Notation, out: &mut Vec<i64>) {
+    for node in value.preorder() {
+        match node {
+            Notation::Atom(atom) => atom_structure(atom, out),
+            Notation::Expr(expr) => expr_structure(expr, out),
+        }
+    }
+}
+
+fn atom_structure(atom: &Atom, out: &mut Vec<i64>) {
+    use Atom::*;
+    match atom {
+        Number(n) => out.extend([0, n.value as i64]),
+        Complex => out.push(1),
+        Undefined => out.push(2),
+        Huge => out.push(3),
+        NegativeHuge => out.push(4),
+        Epsilon => out.push(5),
+        NegativeEpsilon => out.push(6),
+        Unknown => out.push(7),
+        Pi => out.push(8),
+        E => out.push(9),
+        Variable(var) => {
+            out.extend([10, var.chars().count() as i64]);
+            out.extend(var.chars().map(|c| c as i64));
+        }
+    }
+}
+
+fn expr_structure(expr: &Expr, out: &mut Vec<i64>) {
+    match expr {
+        Expr::Fraction(Fraction { num, den }) => {
+            out.push(11);
+            atom_structure(num, out);
+            atom_structure(den, out);
+        }
+        Expr::Radical(Radical { coef, rad }) => out.extend([12, *coef as i64, *rad as i64]),
+        Expr::RadicalFraction(RadicalFraction { num, den }) => {
+            out.extend([13, num.coef as i64, num.rad as i64, *den as i64])
+        }
+        Expr::Trig(Trig { func, .. }) => {
+            let func = match func {
+                TrigFunction::Sin => 0,
+                TrigFunction::Cos => 1,
+                TrigFunction::Tan => 2,
+            };
+            out.extend([14, func]);
+        }
+        Expr::Multiple(Multiple { coef, constant }) => {
+            out.push(15);
+            atom_structure(&coef.num, out);
+            atom_structure(&coef.den, out);
+            atom_structure(constant, out);
+        }
+        // The notation inside is written after, by `structure`
+        Expr::Complex(_) => out.push(16),
+        Expr::Sum(Sum { terms }) => out.extend([17, terms.len() as i64]),
+        Expr::NestedFraction(_) => out.push(18),
+        Expr::NestedRadical(NestedRadical { coef, .. }) => out.extend([19, *coef as i64]),
+        Expr::Product(Product { factors }) => out.extend([20, factors.len() as i64]),
+        Expr::Power(_) => out.push(21),
+    }
+}
+
+impl Canonical {
+    fn structure(&self) -> Vec<i64> {
+        let mut out = Vec::new();
+        structure(&self.0, &mut out);
+        out
+    }
+}
+
+impl From<Notation> for Canonical {
+    fn from(value: Notation) -> Self {
+        Self(value)
+    }
+}
+
+impl From<Canonical> for Notation {
+    fn from(value: Canonical) -> Self {
+        value.0
+    }
+}
+
+impl std::fmt::Display for Canonical {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+// # Equality
+
+impl PartialEq for Canonical {
+    fn eq(&self, other: &Self) -> bool {
+        self.structure() == other.structure()
+    }
+}
+
+impl Eq for Canonical {}
+
+impl std::hash::Hash for Canonical {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.structure().hash(state);
+    }
+}
+
+impl PartialOrd for Canonical {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Canonical {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.structure().cmp(&other.structure())
+    }
+}
+
+// # Term order
+
+/// A deterministic total order over notation, for sorting the terms of sums into a canonical order.
+///
+/// Constants come first, then radicals, then variables, then compound notation such as trig functions, complex numbers, and sums.
+/// Rational constants come before the others and are ordered by value, so `1/2` comes before `2/3`.
+/// Variables are ordered by name, so `x` comes before `2y`.
+/// Anything else is ordered by structure, the same way as [`Canonical`],
+/// so the order agrees with [`Canonical`] equality.
+///
+/// ```
+/// # use algebra::notation::{Notation, atom::Atom, canonical::TermOrder, expr::{fraction::Fraction, radical::Radical, trig::{Trig, TrigFunction}}};
+/// let mut terms = vec![
+///     Notation::from(Trig::new(TrigFunction::Sin, 30)),
+///     Notation::from(Radical::new(2)),
+///     Notation::from(Atom::Pi),
+///     Notation::from(Fraction::new(2, 3)),
+///     Notation::from(Fraction::new(1, 2)),
+/// ];
+/// terms.sort_by(TermOrder::canonical_cmp);
+/// let terms = terms.iter().map(|term| term.to_string()).collect::<Vec<_>>();
+/// assert_eq!(terms, ["1/2", "2/3", "π", "√2", "sin(30°)"]);
+/// ```
+pub trait TermOrder {
+    /// Compares two terms by their canonical order.
+    fn canonical_cmp(&self, other: &Self) -> std::cmp::Ordering;
+}
+
+/// Groups of notation in the order they are sorted.
+#[derive(PartialEq, Eq, PartialOrd, Ord)]
+enum TermRank {
+    /// Integers and fractions of them.
+    Rational,
+
+    /// Any other atom, or a multiple of one.
+    Constant,
+
+    /// Radicals, and fractions of them.
+    Radical,
+
+    /// Variables, and multiples of them.
+    Variable,
+
+    /// Notation made of other notation.
+    Compound,
+}
+
+fn term_rank(value: &Notation) -> TermRank {
+    match value {
+        _ if ratio(value).is_some() => TermRank::Rational,
+        _ if variable_of(value).is_some() => TermRank::Variable,
+        Notation::Atom(_) | Notation::Expr(Expr::Fraction(_) | Expr::Multiple(_)) => {
+            TermRank::Constant
+        }
+        Notation::Expr(Expr::Radical(_) | Expr::RadicalFraction(_) | Expr::NestedRadical(_)) => {
+            TermRank::Radical
+        }
+        Notation::Expr(
+            Expr::Trig(_)
+            | Expr::Complex(_)
+            | Expr::Sum(_)
+            | Expr::NestedFraction(_)
+            | Expr::Product(_)
+            | Expr::Power(_),
+        ) => TermRank::Compound,
+    }
+}
+
+/// If the notation is a variable or a multiple of one, returns the variable.
+fn variable_of(value: &Notation) -> Option<Var> {
+    match multiple_of(value) {
+        Some((_, _, Atom::Variable(var))) => Some(var),
+        _ => None,
+    }
+}
+
+impl TermOrder for Notation {
+    fn canonical_cmp(&self, other: &Self) -> std::cmp::Ordering {
+        let by_value = || match (ratio(self), ratio(other)) {
+            // Compared by cross-multiplying, with the denominators made positive
+            (Some((a, b)), Some((c, d))) => {
+                let (a, b, c, d) = (a as i128, b as i128, c as i128, d as i128);
+                (a * b.signum() * d.abs()).cmp(&(c * d.signum() * b.abs()))
+            }
+            _ => std::cmp::Ordering::Equal,
+        };
+        let by_name = || match (variable_of(self), variable_of(other)) {
+            (Some(a), Some(b)) => a.cmp(&b),
+            _ => std::cmp::Ordering::Equal,
+        };
+        term_rank(self)
+            .cmp(&term_rank(other))
+            .then_with(by_value)
+            .then_with(by_name)
+            .then_with(|| Canonical(self.clone()).cmp(&Canonical(other.clone())))
+    }
+}
+
+// # Commutativity and associativity
+
+impl Notation {
+    /// Tests if two notations are the same up to the order and grouping of terms in sums and factors in products,
+    /// so `a+b` equals `b+a`, and `(a+b)+c` equals `a+(b+c)`.
+    ///
+    /// Otherwise compares structure the same way as [`Canonical`], without simplifying:
+    /// `1+1` is not equal to `2`.
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, atom::Atom, expr::{radical::Radical, sum::Sum}};
+    /// let one = Notation::from(1);
+    /// let root_two = Notation::from(Radical::new(2));
+    /// let pi = Notation::from(Atom::Pi);
+    /// let lhs = Sum::new([Sum::new([one.clone(), root_two.clone()]).into(), pi.clone()]);
+    /// let rhs = Sum::new([pi, Sum::new([root_two, one]).into()]);
+    /// assert!(Notation::from(lhs).eq_ac(&rhs.into()));
+    /// ```
+    pub fn eq_ac(&self, other: &Self) -> bool {
+        Canonical(ac_normalized(self)) == Canonical(ac_normalized(other))
+    }
+}
+
+/// Flattens nested sums and products into one and sorts the terms of each by [`TermOrder`], throughout the notation.
+fn ac_normalized(value: &Notation) -> Notation {
+    match value {
+        Notation::Atom(_) => value.clone(),
+        Notation::Expr(Expr::Sum(Sum { terms })) => {
+            let mut terms = terms
+                .iter()
+                .flat_map(|term| match ac_normalized(term) {
+                    Notation::Expr(Expr::Sum(Sum { terms })) => terms.to_vec(),
+                    term => vec![term],
+                })
+                .collect::<Vec<_>>();
+            terms.sort_by(TermOrder::canonical_cmp);
+            Sum::new(terms).into()
+        }
+        Notation::Expr(Expr::Product(Product { factors })) => {
+            let mut factors = factors
+                .iter()
+                .flat_map(|factor| match ac_normalized(factor) {
+                    Notation::Expr(Expr::Product(Product { factors })) => factors.to_vec(),
+                    factor => vec![factor],
+                })
+                .collect::<Vec<_>>();
+            factors.sort_by(TermOrder::canonical_cmp);
+            Product::new(factors).into()
+        }
+        Notation::Expr(Expr::Trig(Trig { func, arg })) => {
+            Trig::new(*func, ac_normalized(arg)).into()
+        }
+        Notation::Expr(Expr::Complex(Complex { re, im })) => {
+            Complex::new(ac_normalized(re), ac_normalized(im)).into()
+        }
+        Notation::Expr(Expr::NestedFraction(NestedFraction { num, den })) => {
+            NestedFraction::new(ac_normalized(num), ac_normalized(den)).into()
+        }
+        Notation::Expr(Expr::Power(Power { base, exp })) => {
+            Power::new(ac_normalized(base), ac_normalized(exp)).into()
+        }
+        Notation::Expr(Expr::NestedRadical(NestedRadical { coef, rad })) => NestedRadical {
+            coef: *coef,
+            rad: Arc::new(ac_normalized(rad)),
+        }
+        .into(),
+        Notation::Expr(_) => value.clone(),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::BTreeSet;
+
+    fn canonical(value: impl Into<Notation>) -> Canonical {
+        Canonical(value.into())
+    }
+
+    #[test]
+    fn test_structural_equality() {
+        assert_eq!(canonical(Atom::Huge), canonical(Atom::Huge));
+        assert_ne!(canonical(Atom::Huge), canonical(Atom::NegativeHuge));
+        assert_ne!(canonical(Fraction::new(1, 1)), canonical(1));
+        assert_ne!(
+            canonical(Radical { coef: 1, rad: 5 }),
+            canonical(RadicalFraction::new(1, 5, 1))
+        );
+        assert_eq!(
+            canonical(Trig::new(TrigFunction::Sin, Atom::Undefined)),
+            canonical(Trig::new(TrigFunction::Sin, Atom::Undefined))
+        );
+        assert_ne!(
+            canonical(Trig::new(TrigFunction::Sin, 30)),
+            canonical(Trig::new(TrigFunction::Cos, 30))
+        );
+    }
+
+    #[test]
+    fn test_ordering() {
+        let sorted = BTreeSet::from([
+            canonical(Fraction::new(1, 2)),
+            canonical(3),
+            canonical(Atom::Pi),
+            canonical(-3),
+            canonical(3),
+        ])
+        .into_iter()
+        .map(|value| value.to_string())
+        .collect::<Vec<_>>();
+        assert_eq!(sorted, ["-3", "3", "π", "1/2"]);
+    }
+
+    #[test]
+    fn test_canonical_cmp() {
+        use std::cmp::Ordering::*;
+        let cmp = |a: Notation, b: Notation| a.canonical_cmp(&b);
+        assert_eq!(cmp(Fraction::new(1, -2).into(), 0.into()), Less);
+        assert_eq!(
+            cmp(Fraction::new(-1, -2).into(), Fraction::new(1, 3).into()),
+            Greater
+        );
+        assert_eq!(
+            cmp(Fraction::new(1, 2).into(), Fraction::new(1, 2).into()),
+            Equal
+        );
+        // Equal values written differently are still ordered
+        assert_eq!(
+            cmp(Fraction::new(2, 4).into(), Fraction::new(1, 2).into()),
+            Greater
+        );
+        assert_eq!(cmp(i32::MAX.into(), Atom::NegativeHuge.into()), Less);
+        assert_eq!(cmp(Atom::E.into(), Radical::new(2).into()), Less);
+        assert_eq!(
+            cmp(
+                RadicalFraction::new(1, 3, 2).into(),
+                Trig::new(TrigFunction::Cos, 0).into()
+            ),
+            Less
+        );
+        assert_eq!(cmp(Atom::Undefined.into(), Atom::Undefined.into()), Equal);
+
+        // Variables come after radicals and before compound notation, ordered by name
+        let var = |name: &str| Atom::Variable(Var::new(name));
+        assert_eq!(cmp(Radical::new(2).into(), var("x").into()), Less);
+        assert_eq!(
+            cmp(var("x").into(), Trig::new(TrigFunction::Sin, 0).into()),
+            Less
+        );
+        assert_eq!(
+            cmp(var("x").into(), Multiple::new(2, 1, var("y")).into()),
+            Less
+        );
+        assert_eq!(
+            cmp(Multiple::new(2, 1, var("y")).into(), var("x").into()),
+            Greater
+        );
+    }
+
+    #[test]
+    fn test_canonical_cmp_is_total() {
+        let values: Vec<Notation> = vec![
+            0.into(),
+            (-7).into(),
+            Fraction::new(3, -4).into(),
+            Fraction::new(-6, 8).into(),
+            Fraction::new(1, 0).into(),
+            Atom::Pi.into(),
+            Atom::Huge.into(),
+            Atom::Epsilon.into(),
+            Radical::new(3).into(),
+            RadicalFraction::new(2, 3, 5).into(),
+            Trig::new(TrigFunction::Tan, Atom::Pi).into(),
+            Complex::new(1, 1).into(),
+            sum([1.into(), Radical::new(2).into()]),
+        ];
+        for a in &values {
+            for b in &values {
+                assert_eq!(
+                    a.canonical_cmp(b),
+                    b.canonical_cmp(a).reverse(),
+                    "{a} and {b}"
+                );
+                assert_eq!(
+                    a.canonical_cmp(b).is_eq(),
+                    Canonical(a.clone()) == Canonical(b.clone())
+                );
+                for c in &values {
+                    if a.canonical_cmp(b).is_le() && b.canonical_cmp(c).is_le() {
+                        assert!(a.canonical_cmp(c).is_le(), "{a}, {b}, and {c}");
+                    }
+                }
+            }
+        }
+    }
+
+    fn sum(terms: impl IntoIterator<Item = Notation>) -> Notation {
+        Sum::new(terms).into()
+    }
+
+    #[test]
+    fn test_eq_ac() {
+        let [a, b, c] = [
+            Notation::from(Radical::new(2)),
+            Notation::from(Atom::E),
+            Notation::from(Fraction::new(1, 3)),
+        ];
+        assert!(sum([a.clone(), b.clone()]).eq_ac(&sum([b.clone(), a.clone()])));
+        let left = sum([sum([a.clone(), b.clone()]), c.clone()]);
+        let right = sum([a.clone(), sum([b.clone(), c.clone()])]);
+        assert!(left.eq_ac(&right));
+        assert!(left.eq_ac(&sum([c.clone(), b.clone(), a.clone()])));
+        // Normalized inside other notation too
+        assert!(Notation::from(Trig::new(TrigFunction::Sin, left.clone()))
+            .eq_ac(&Trig::new(TrigFunction::Sin, right.clone()).into()));
+        assert!(Notation::from(Complex::new(left.clone(), 1))
+            .eq_ac(&Complex::new(right.clone(), 1).into()));
+
+        assert!(!left.eq_ac(&sum([a.clone(), b.clone()])));
+        assert!(!sum([a.clone(), a.clone()]).eq_ac(&sum([a.clone()])));
+        assert!(!sum([Notation::from(1), Notation::from(1)]).eq_ac(&Notation::from(2)));
+        // The real and imaginary parts are not interchangeable
+        assert!(!Notation::from(Complex::new(1, 2)).eq_ac(&Complex::new(2, 1).into()));
+        let undefined = Notation::from(Atom::Undefined);
+        assert!(undefined.eq_ac(&undefined));
+    }
+}
@@ -0,0 +1,211 @@
+//! Export of notation trees to the Graphviz DOT language, for visualizing their structure.
+
+use crate::notation::{
+    atom::Atom,
+    expr::{
+        complex::Complex, fraction::Fraction, multiple::Multiple, nested_fraction::NestedFraction,
+        nested_radical::NestedRadical, power::Power, product::Product, radical::Radical,
+        radical_fraction::RadicalFraction, sum::Sum, trig::Trig, Expr,
+    },
+    Notation,
+};
+
+/// A DOT graph being built up one node at a time.
+struct Graph {
+    lines: Vec<String>,
+    nodes: usize,
+}
+
+impl Graph {
+    /// Adds a node and returns its id.
+    ///
+    /// `\n` in the label starts a new line.
+    fn node(&mut self, label: &str) -> usize {
+        let id = self.nodes;
+        self.nodes += 1;
+        let label = label.replace('"', "\\\"");
+        self.lines.push(format!("    n{id} [label=\"{label}\"];"));
+        id
+    }
+
+    fn edge(&mut self, from: usize, to: usize, label: &str) {
+        self.lines
+            .push(format!("    n{from} -> n{to} [label=\"{label}\"];"));
+    }
+
+    fn integer(&mut self, value: i32) -> usize {
+        self.node(&format!("Integer\\n{value}"))
+    }
+
+    fn atom(&mut self, atom: &Atom) -> usize {
+        use Atom::*;
+        let label = match atom {
+            Number(n) => format!("Number\\n{n}"),
+            Complex => format!("Complex\\n{atom}"),
+            Undefined => format!("Undefined\\n{atom}"),
+            Huge => format!("Huge\\n{atom}"),
+            NegativeHuge => format!("NegativeHuge\\n{atom}"),
+            Epsilon => format!("Epsilon\\n{atom}"),
+            NegativeEpsilon => format!("NegativeEpsilon\\n{atom}"),
+            Unknown => format!("Unknown\\n{atom}"),
+            Pi => format!("Pi\\n{atom}"),
+            E => format!("E\\n{atom}"),
+            Variable(_) => format!("Variable\\n{atom}"),
+        };
+        self.node(&label)
+    }
+
+    fn fraction(&mut self, Fraction { num, den }: &Fraction) -> usize {
+        let id = self.node("Fraction");
+        let num = self.atom(num);
+        self.edge(id, num, "num");
+        let den = self.atom(den);
+        self.edge(id, den, "den");
+        id
+    }
+
+    fn radical(&mut self, Radical { coef, rad }: &Radical) -> usize {
+        let id = self.node("Radical");
+        let coef = self.integer(*coef);
+        self.edge(id, coef, "coef");
+        let rad = self.integer(*rad);
+        self.edge(id, rad, "rad");
+        id
+    }
+
+    fn expr(&mut self, expr: &Expr) -> usize {
+        match expr {
+            Expr::Fraction(frac) => self.fraction(frac),
+            Expr::Radical(rad) => self.radical(rad),
+            Expr::RadicalFraction(RadicalFraction { num, den }) => {
+                let id = self.node("RadicalFraction");
+                let num = self.radical(num);
+                self.edge(id, num, "num");
+                let den = self.integer(*den);
+                self.edge(id, den, "den");
+                id
+            }
+            Expr::Trig(Trig { func, arg }) => {
+                let id = self.node(&format!("Trig\\n{func}"));
+                let arg = self.notation(arg);
+                self.edge(id, arg, "arg");
+                id
+            }
+            Expr::Multiple(Multiple { coef, constant }) => {
+                let id = self.node("Multiple");
+                let coef = self.fraction(coef);
+                self.edge(id, coef, "coef");
+                let constant = self.atom(constant);
+                self.edge(id, constant, "constant");
+                id
+            }
+            Expr::Complex(Complex { re, im }) => {
+                let id = self.node("Complex");
+                let re = self.notation(re);
+                self.edge(id, re, "re");
+                let im = self.notation(im);
+                self.edge(id, im, "im");
+                id
+            }
+            Expr::Sum(Sum { terms }) => {
+                let id = self.node("Sum");
+                for (i, term) in terms.iter().enumerate() {
+                    let term = self.notation(term);
+                    self.edge(id, term, &format!("term {i}"));
+                }
+                id
+            }
+            Expr::NestedFraction(NestedFraction { num, den }) => {
+                let id = self.node("NestedFraction");
+                let num = self.notation(num);
+                self.edge(id, num, "num");
+                let den = self.notation(den);
+                self.edge(id, den, "den");
+                id
+            }
+            Expr::NestedRadical(NestedRadical { coef, rad }) => {
+                let id = self.node("NestedRadical");
+                let coef = self.integer(*coef);
+                self.edge(id, coef, "coef");
+                let rad = self.notation(rad);
+                self.edge(id, rad, "rad");
+                id
+            }
+            Expr::Product(Product { factors }) => {
+                let id = self.node("Product");
+                for (i, factor) in factors.iter().enumerate() {
+                    let factor = self.notation(factor);
+                    self.edge(id, factor, &format!("factor {i}"));
+                }
+                id
+            }
+            Expr::Power(Power { base, exp }) => {
+                let id = self.node("Power");
+                let base = self.notation(base);
+                self.edge(id, base, "base");
+                let exp = self.notation(exp);
+                self.edge(id, exp, "exp");
+                id
+            }
+        }
+    }
+
+    fn notation(&mut self, value: &Notation) -> usize {
+        match value {
+            Notation::Atom(atom) => self.atom(atom),
+            Notation::Expr(expr) => self.expr(expr),
+        }
+    }
+}
+
+impl Notation {
+    /// Describes the notation's tree as a [Graphviz](https://graphviz.org) graph in the DOT language.
+    ///
+    /// Each node is labeled with its variant and value, and each edge with the part of its parent it fills.
+    /// Useful for seeing how a large expression is built, for example while debugging simplification.
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, expr::fraction::Fraction};
+    /// let dot = Notation::from(Fraction::new(1, 2)).to_dot();
+    /// assert_eq!(dot, "digraph {
+    ///     n0 [label=\"Fraction\"];
+    ///     n1 [label=\"Number\\n1\"];
+    ///     n0 -> n1 [label=\"num\"];
+    ///     n2 [label=\"Number\\n2\"];
+    ///     n0 -> n2 [label=\"den\"];
+    /// }");
+    /// ```
+    pub fn to_dot(&self) -> String {
+        let mut graph = Graph {
+            lines: Vec::new(),
+            nodes: 0,
+        };
+        graph.notation(self);
+        format!("digraph {{\n{}\n}}", graph.lines.join("\n"))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::expr::trig::TrigFunction;
+
+    #[test]
+    fn test_atom() {
+        assert_eq!(
+            Notation::from(Atom::Pi).to_dot(),
+            "digraph {\n    n0 [label=\"Pi\\nπ\"];\n}"
+        );
+    }
+
+    #[test]
+    fn test_nested() {
+        let trig = Notation::from(Trig::new(TrigFunction::Sin, RadicalFraction::new(1, 3, 2)));
+        let dot = trig.to_dot();
+        assert!(dot.contains("[label=\"Trig\\nsin\"]"));
+        assert!(dot.contains("[label=\"RadicalFraction\"]"));
+        assert!(dot.contains("[label=\"Integer\\n3\"]"));
+        assert_eq!(dot.matches(" -> ").count(), 5);
+        assert_eq!(dot.matches("[label=").count(), 5 + 6);
+    }
+}
@@ -0,0 +1,525 @@
+//! Simplifying one step at a time, with a sentence explaining each step.
+
+use std::sync::Arc;
+
+use crate::{
+    factor::gcf,
+    notation::{
+        atom::{Atom, AtomKind},
+        canonical::Canonical,
+        expr::{
+            complex::Complex, fraction::Fraction, multiple::Multiple,
+            nested_fraction::NestedFraction, nested_radical::NestedRadical, power::Power,
+            product::Product, radical::Radical, radical_fraction::RadicalFraction, sum::Sum,
+            trig::Trig, Expr,
+        },
+        ops::table::{division_reason, special_quotient},
+        rules::{AtomClass, Rule, RuleId, RuleSet},
+        Notation,
+    },
+};
+
+/// One rule applied to one part of some notation while simplifying it.
+#[derive(Debug, PartialEq, Clone)]
+pub struct SimplificationStep {
+    /// The rule which was applied.
+    pub rule: Rule,
+
+    /// The part before the rule was applied.
+    pub before: Notation,
+
+    /// The part after the rule was applied.
+    pub after: Notation,
+}
+
+/// Writes notation the way it would be read aloud in a sentence,
+/// spelling out the atoms which stand for more than one value.
+fn describe(value: &Notation) -> String {
+    match value {
+        Notation::Atom(atom @ (Atom::Undefined | Atom::Complex | Atom::Unknown)) => {
+            atom.kind().to_string()
+        }
+        value => value.to_string(),
+    }
+}
+
+/// Explains reducing `coef/den` by their greatest common factor, as the numerator and denominator of `before`.
+fn reduction(before: &Notation, after: &Notation, coef: i32, den: i32) -> (RuleId, String) {
+    let after = describe(after);
+    match gcf([coef, den]) {
+        // The remaining cases only move the sign or drop a denominator of 1
+        0 | 1 if den == 1 => (
+            RuleId::DivideByOne,
+            format!("{before} is {after}, since dividing by 1 changes nothing"),
+        ),
+        0 | 1 => (
+            RuleId::MoveSign,
+            format!("{before} is {after}, with the sign moved to the numerator"),
+        ),
+        gcf => (
+            RuleId::ReduceByGcf,
+            format!("{before} reduces by the greatest common factor {gcf} to {after}"),
+        ),
+    }
+}
+
+/// Returns the radicand of a radical, or of the numerator of a radical fraction.
+fn radicand(value: &Notation) -> Option<i32> {
+    match value {
+        Notation::Expr(Expr::Radical(Radical { rad, .. }))
+        | Notation::Expr(Expr::RadicalFraction(RadicalFraction {
+            num: Radical { rad, .. },
+            ..
+        })) => Some(*rad),
+        _ => None,
+    }
+}
+
+/// Explains taking square factors out of the radicand `rad`.
+fn root_extraction(before: &Notation, after: &Notation, rad: i32) -> (RuleId, String) {
+    let described = describe(after);
+    match (rad, radicand(after)) {
+        (..=-1, None) => (
+            RuleId::ImaginaryRoot,
+            format!("{before} is {described}, since √-1 is 𝑖"),
+        ),
+        (0 | 1, None) => (
+            RuleId::PerfectSquare,
+            format!("{before} is {described}, since √{rad} is {rad}"),
+        ),
+        (rad, None) => (
+            RuleId::PerfectSquare,
+            format!(
+                "{before} is {described}, since {rad} is the square of {}",
+                rad.isqrt()
+            ),
+        ),
+        (rad, Some(rest)) => (
+            RuleId::ExtractSquareFactor,
+            format!(
+                "{before} simplifies to {described} by taking the square factor {} out from under the root",
+                rad / rest
+            ),
+        ),
+    }
+}
+
+/// Explains dividing by or into one of the special values.
+fn special_division(before: &Notation, after: &Notation, num: &Atom, den: &Atom) -> String {
+    let (Some(num_class), Some(den_class)) = (AtomClass::of(num), AtomClass::of(den)) else {
+        return format!("{before} is {}", describe(after));
+    };
+    // A rule set may give a different quotient than the built-in table, which the reason wouldn't explain
+    let is_built_in = match (special_quotient(num, den), after) {
+        (Some(quotient), Notation::Atom(after)) => AtomClass::of(&quotient) == AtomClass::of(after),
+        _ => false,
+    };
+    match division_reason(num_class, den_class).filter(|_| is_built_in) {
+        Some(reason) => format!("{before} is {}, since {reason}", describe(after)),
+        None => format!("{before} is {}", describe(after)),
+    }
+}
+
+impl SimplificationStep {
+    /// Describes the step in a sentence, such as "12/18 reduces by the greatest common factor 6 to 2/3".
+    pub fn explain(&self) -> String {
+        self.explanation().1
+    }
+
+    /// Returns the stable identifier of the kind of step this is, which matches the kind of sentence [`explain`][Self::explain] gives.
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, expr::radical::Radical, rules::RuleId};
+    /// let steps = Notation::from(Radical::new(12)).simplify_steps(&Default::default());
+    /// assert_eq!(steps[0].id(), RuleId::ExtractSquareFactor);
+    /// ```
+    pub fn id(&self) -> RuleId {
+        self.explanation().0
+    }
+
+    /// Returns the kind of atom the step simplified to, if it gave an atom.
+    ///
+    /// Along with [`id`][Self::id], lets a front end name results such as [`Undefined`][Atom::Undefined] in its own words.
+    pub fn result_kind(&self) -> Option<AtomKind> {
+        match &self.after {
+            Notation::Atom(atom) => Some(atom.kind()),
+            Notation::Expr(_) => None,
+        }
+    }
+
+    /// Identifies and describes the step.
+    fn explanation(&self) -> (RuleId, String) {
+        let Self {
+            rule,
+            before,
+            after,
+        } = self;
+        match (rule, before) {
+            (Rule::ReduceFractions, Notation::Expr(expr)) => match expr {
+                Expr::Fraction(Fraction {
+                    num: Atom::Number(num),
+                    den: Atom::Number(den),
+                }) => reduction(before, after, num.value, den.value),
+                Expr::Multiple(Multiple {
+                    coef:
+                        Fraction {
+                            num: Atom::Number(num),
+                            den: Atom::Number(den),
+                        },
+                    ..
+                }) => reduction(before, after, num.value, den.value),
+                Expr::RadicalFraction(RadicalFraction {
+                    num: Radical { coef, .. },
+                    den,
+                }) => reduction(before, after, *coef, *den),
+                _ => (
+                    RuleId::ReduceFraction,
+                    format!("{before} reduces to {}", describe(after)),
+                ),
+            },
+            (Rule::SimplifyRadicals, Notation::Expr(Expr::Radical(Radical { rad, .. })))
+            | (
+                Rule::SimplifyRadicals,
+                Notation::Expr(Expr::RadicalFraction(RadicalFraction {
+                    num: Radical { rad, .. },
+                    ..
+                })),
+            ) => root_extraction(before, after, *rad),
+            (Rule::SpecialValues, Notation::Expr(Expr::Fraction(Fraction { num, den }))) => (
+                RuleId::SpecialDivision,
+                special_division(before, after, num, den),
+            ),
+            (Rule::EvaluateTrig, Notation::Expr(Expr::Trig(Trig { arg, .. }))) => (
+                RuleId::TrigSpecialAngle,
+                format!(
+                    "{before} is {}, its exact value at the special angle {arg}°",
+                    describe(after)
+                ),
+            ),
+            (Rule::CombineComplex, _) => (
+                RuleId::CombineComplex,
+                format!("{before} combines into {}", describe(after)),
+            ),
+            (Rule::CombineLikeTerms, _) => (
+                RuleId::CombineLikeTerms,
+                format!("{before} combines like terms into {}", describe(after)),
+            ),
+            (rule, _) => {
+                let id = match rule {
+                    Rule::SpecialValues => RuleId::SpecialDivision,
+                    Rule::ReduceFractions => RuleId::ReduceFraction,
+                    Rule::SimplifyRadicals => RuleId::SimplifyRoot,
+                    Rule::EvaluateTrig => RuleId::TrigSpecialAngle,
+                    Rule::CombineComplex => RuleId::CombineComplex,
+                    Rule::CombineLikeTerms => RuleId::CombineLikeTerms,
+                };
+                (id, format!("{before} simplifies to {}", describe(after)))
+            }
+        }
+    }
+}
+
+/// The rules which can apply to a part of notation itself, rather than to its children, in the order they are tried.
+fn node_rules(value: &Notation) -> &'static [Rule] {
+    match value {
+        Notation::Atom(_) => &[],
+        Notation::Expr(expr) => match expr {
+            Expr::Fraction(_) => &[Rule::SpecialValues, Rule::ReduceFractions],
+            Expr::Radical(_) => &[Rule::SimplifyRadicals],
+            Expr::RadicalFraction(_) => &[Rule::SimplifyRadicals, Rule::ReduceFractions],
+            Expr::Multiple(_) => &[Rule::ReduceFractions],
+            Expr::Trig(_) => &[Rule::EvaluateTrig],
+            Expr::Complex(_) => &[Rule::CombineComplex],
+            Expr::Sum(_) | Expr::Product(_) => &[Rule::CombineLikeTerms],
+            Expr::NestedFraction(_) => &[Rule::ReduceFractions],
+            Expr::NestedRadical(_) | Expr::Power(_) => &[Rule::SimplifyRadicals],
+        },
+    }
+}
+
+/// Simplifies the children of `value`, then `value` itself one rule at a time, recording each rule which changed something.
+fn trace(value: Notation, rules: &RuleSet, steps: &mut Vec<SimplificationStep>) -> Notation {
+    let mut child = |value: Arc<Notation>| trace(Arc::unwrap_or_clone(value), rules, steps);
+    let value = match value {
+        Notation::Expr(Expr::Trig(Trig { func, arg })) => Notation::from(Trig {
+            func,
+            arg: Arc::new(child(arg)),
+        }),
+        Notation::Expr(Expr::Complex(Complex { re, im })) => {
+            let re = child(re);
+            Notation::from(Complex::new(re, child(im)))
+        }
+        Notation::Expr(Expr::NestedFraction(NestedFraction { num, den })) => {
+            let num = child(num);
+            Notation::from(NestedFraction::new(num, child(den)))
+        }
+        Notation::Expr(Expr::NestedRadical(NestedRadical { coef, rad })) => {
+            Notation::from(NestedRadical {
+                coef,
+                rad: Arc::new(child(rad)),
+            })
+        }
+        Notation::Expr(Expr::Power(Power { base, exp })) => {
+            let base = child(base);
+            Notation::from(Power::new(base, child(exp)))
+        }
+        Notation::Expr(Expr::Sum(sum)) => Notation::from(Sum::new(
+            sum.terms
+                .iter()
+                .cloned()
+                .map(|term| child(Arc::new(term)))
+                .collect::<Vec<_>>(),
+        )),
+        Notation::Expr(Expr::Product(product)) => Notation::from(Product::new(
+            product
+                .factors
+                .iter()
+                .cloned()
+                .map(|factor| child(Arc::new(factor)))
+                .collect::<Vec<_>>(),
+        )),
+        value => value,
+    };
+    node_rules(&value)
+        .iter()
+        .filter(|&&rule| rules.is_enabled(rule))
+        .fold(value, |before, &rule| {
+            let after = before
+                .clone()
+                .simplify_with_rules(&rules.restricted_to(rule));
+            if Canonical(after.clone()) == Canonical(before.clone()) {
+                return before;
+            }
+            steps.push(SimplificationStep {
+                rule,
+                before,
+                after: after.clone(),
+            });
+            after
+        })
+}
+
+impl Notation {
+    /// Simplifies the notation once, like [`simplify_with_rules`][Notation::simplify_with_rules],
+    /// returning each rule applied along the way.
+    ///
+    /// The parts inside a sum, complex number, or trig function are simplified before the part containing them,
+    /// and the rules for each part are applied one at a time, in the order of [`Rule`].
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, expr::{radical::Radical, sum::Sum}, rules::{Rule, RuleSet}};
+    /// let sum = Notation::from(Sum::new([Radical::new(12).into(), Radical::new(3).into()]));
+    /// let steps = sum.simplify_steps(&RuleSet::default());
+    /// let rules = steps.iter().map(|step| step.rule).collect::<Vec<_>>();
+    /// assert_eq!(rules, [Rule::SimplifyRadicals, Rule::CombineLikeTerms]);
+    /// assert_eq!(steps[1].after.to_string(), "3√3");
+    /// ```
+    pub fn simplify_steps(&self, rules: &RuleSet) -> Vec<SimplificationStep> {
+        let mut steps = Vec::new();
+        trace(self.clone(), rules, &mut steps);
+        steps
+    }
+
+    /// Describes each step of simplifying the notation in a sentence, for showing a student how it simplifies.
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, expr::fraction::Fraction};
+    /// let steps = Notation::from(Fraction::new(12, 18)).explain();
+    /// assert_eq!(steps, ["12/18 reduces by the greatest common factor 6 to 2/3"]);
+    /// ```
+    pub fn explain(&self) -> Vec<String> {
+        self.explain_only(&Rule::ALL)
+    }
+
+    /// Simplifies the notation with every rule, but only explains the steps using the rules given.
+    ///
+    /// Useful for leaving out steps a student already knows, such as combining like terms.
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, expr::{radical::Radical, sum::Sum}, rules::Rule};
+    /// let sum = Notation::from(Sum::new([Radical::new(12).into(), Radical::new(3).into()]));
+    /// assert_eq!(
+    ///     sum.explain_only(&[Rule::SimplifyRadicals]),
+    ///     ["√12 simplifies to 2√3 by taking the square factor 4 out from under the root"]
+    /// );
+    /// ```
+    pub fn explain_only(&self, rules: &[Rule]) -> Vec<String> {
+        self.simplify_steps(&RuleSet::default())
+            .into_iter()
+            .filter(|step| rules.contains(&step.rule))
+            .map(|step| step.explain())
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::expr::{simplify::Simplify, trig::TrigFunction};
+
+    fn explain(value: impl Into<Notation>) -> Vec<String> {
+        value.into().explain()
+    }
+
+    #[test]
+    fn test_fractions() {
+        assert_eq!(
+            explain(Fraction::new(12, 4)),
+            ["12/4 reduces by the greatest common factor 4 to 3"]
+        );
+        assert_eq!(
+            explain(Fraction::new(1, -2)),
+            ["1/-2 is -1/2, with the sign moved to the numerator"]
+        );
+        assert_eq!(
+            explain(Fraction::new(7, 1)),
+            ["7/1 is 7, since dividing by 1 changes nothing"]
+        );
+        assert_eq!(explain(Fraction::new(2, 3)), Vec::<String>::new());
+        assert_eq!(
+            explain(Multiple::new(4, 6, Atom::E)),
+            ["4𝑒/6 reduces by the greatest common factor 2 to 2𝑒/3"]
+        );
+    }
+
+    #[test]
+    fn test_special_values() {
+        assert_eq!(
+            explain(Fraction::new(5, 0)),
+            ["5/0 is undefined, since nothing can be divided by 0"]
+        );
+        assert_eq!(
+            explain(Fraction::new(0, 5)),
+            ["0/5 is 0, since 0 divided by anything else is 0"]
+        );
+        assert_eq!(
+            explain(Fraction {
+                num: Atom::from(3),
+                den: Atom::NegativeHuge,
+            }),
+            ["3/-𝓗 is -ε, since dividing by a huge number gives a number very close to 0"]
+        );
+        assert_eq!(
+            explain(Fraction {
+                num: Atom::Epsilon,
+                den: Atom::NegativeEpsilon,
+            }),
+            ["ε/-ε is unknown, since dividing two numbers very close to 0 could give a number of any size"]
+        );
+        assert_eq!(
+            explain(Fraction {
+                num: Atom::Huge,
+                den: Atom::Huge,
+            }),
+            ["𝓗/𝓗 is unknown, since dividing two huge numbers could give a number of any size"]
+        );
+        assert_eq!(
+            explain(Fraction {
+                num: Atom::Unknown,
+                den: Atom::Huge,
+            }),
+            ["?/𝓗 is unknown, since part of it is unknown"]
+        );
+        // A quotient the built-in table doesn't give isn't given its reason
+        let mut rules = RuleSet::default();
+        rules.set_quotient(AtomClass::Positive, AtomClass::Zero, AtomClass::Complex);
+        let steps = Notation::from(Fraction::new(5, 0)).simplify_steps(&rules);
+        assert_eq!(steps[0].explain(), "5/0 is complex");
+    }
+
+    #[test]
+    fn test_radicals() {
+        assert_eq!(
+            explain(Radical { coef: 3, rad: 16 }),
+            ["3√16 is 12, since 16 is the square of 4"]
+        );
+        assert_eq!(explain(Radical::new(-4)), ["√-4 is 2𝑖, since √-1 is 𝑖"]);
+        assert_eq!(
+            explain(RadicalFraction::new(6, 8, 4)),
+            [
+                "6√8/4 simplifies to 12√2/4 by taking the square factor 4 out from under the root",
+                "12√2/4 reduces by the greatest common factor 4 to 3√2",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_nested() {
+        let trig = Trig::new(TrigFunction::Sin, Fraction::new(60, 2));
+        let steps = Notation::from(trig.clone()).simplify_steps(&RuleSet::default());
+        assert_eq!(
+            steps.iter().map(|step| step.rule).collect::<Vec<_>>(),
+            [Rule::ReduceFractions, Rule::EvaluateTrig]
+        );
+        assert_eq!(
+            steps[1].explain(),
+            "sin(30°) is 1/2, its exact value at the special angle 30°"
+        );
+        assert_eq!(
+            Canonical(steps.last().unwrap().after.clone()),
+            Canonical(trig.simplify())
+        );
+    }
+
+    #[test]
+    fn test_disabled_rules_are_skipped() {
+        let value = Notation::from(RadicalFraction::new(6, 8, 4));
+        let rules = RuleSet::default().without(Rule::SimplifyRadicals);
+        let steps = value.simplify_steps(&rules);
+        assert_eq!(steps.len(), 1);
+        assert_eq!(
+            steps[0].explain(),
+            "6√8/4 reduces by the greatest common factor 2 to 3√8/2"
+        );
+        assert_eq!(
+            value.explain_only(&[Rule::ReduceFractions]),
+            ["12√2/4 reduces by the greatest common factor 4 to 3√2"]
+        );
+    }
+
+    #[test]
+    fn test_ids() {
+        let ids = |value: Notation| {
+            value
+                .simplify_steps(&RuleSet::default())
+                .iter()
+                .map(SimplificationStep::id)
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(ids(Fraction::new(12, 4).into()), [RuleId::ReduceByGcf]);
+        assert_eq!(ids(Fraction::new(1, -2).into()), [RuleId::MoveSign]);
+        assert_eq!(ids(Fraction::new(7, 1).into()), [RuleId::DivideByOne]);
+        assert_eq!(ids(Fraction::new(5, 0).into()), [RuleId::SpecialDivision]);
+        assert_eq!(ids(Radical::new(16).into()), [RuleId::PerfectSquare]);
+        assert_eq!(ids(Radical::new(-4).into()), [RuleId::ImaginaryRoot]);
+        assert_eq!(
+            ids(RadicalFraction::new(6, 8, 4).into()),
+            [RuleId::ExtractSquareFactor, RuleId::ReduceByGcf]
+        );
+        assert_eq!(
+            ids(Trig::new(TrigFunction::Sin, 30).into()),
+            [RuleId::TrigSpecialAngle]
+        );
+        assert_eq!(
+            ids(NestedFraction::new(Fraction::new(1, 2), Fraction::new(3, 4)).into()),
+            [RuleId::ReduceFraction]
+        );
+        assert_eq!(
+            ids(Sum::new([Radical::new(3).into(), Radical::new(3).into()]).into()),
+            [RuleId::CombineLikeTerms]
+        );
+        // Every step's id belongs to the rule that took it
+        let value = Notation::from(Complex::new(Fraction::new(2, 4), Radical::new(8)));
+        for step in value.simplify_steps(&RuleSet::default()) {
+            assert_eq!(step.id().rule(), step.rule);
+        }
+    }
+
+    #[test]
+    fn test_result_kind() {
+        let steps = Notation::from(Fraction::new(5, 0)).simplify_steps(&RuleSet::default());
+        assert_eq!(steps[0].result_kind(), Some(AtomKind::Undefined));
+        let steps = Notation::from(Fraction::new(2, 4)).simplify_steps(&RuleSet::default());
+        assert_eq!(steps[0].result_kind(), None);
+    }
+}
@@ -1,13 +1,35 @@
 //! Algebraic expressions comprised of multiple parts, which can be simplified.
 
+pub mod binomial;
+pub mod complex;
 pub mod fraction;
+pub mod multiple;
+pub mod nested_fraction;
+pub mod nested_radical;
+pub mod power;
+pub mod product;
 pub mod radical;
+pub mod radical_fraction;
 pub mod simplify;
+pub mod sum;
+pub mod trig;
 
-use crate::Notation;
+use crate::notation::{
+    format::{FormatOptions, FormatWith},
+    Notation,
+};
+use complex::Complex;
 use fraction::Fraction;
+use multiple::Multiple;
+use nested_fraction::NestedFraction;
+use nested_radical::NestedRadical;
+use power::Power;
+use product::Product;
 use radical::Radical;
+use radical_fraction::RadicalFraction;
 use simplify::Simplify;
+use sum::Sum;
+use trig::Trig;
 
 /// Algebraic Expression.
 ///
@@ -19,11 +41,12 @@ use simplify::Simplify;
 /// # Equality operation is intended only to be used on notation that has already been simplified.
 ///
 /// **Does not simplify.** Fractions are not considered equal to radicals, even if they are mathematically equivalent.\
+/// Radicals are compared in their [normalized][crate::notation::expr::radical::Radical::normalized] form, so √8 is equal to 2√2.
 ///
 /// </div>
 ///
 /// ```
-/// # use algebra::notation::{Notation, expr::{fraction::Fraction, radical::Radical, Expr, Simplify}};
+/// # use algebra::notation::{Notation, expr::{fraction::Fraction, radical::Radical, simplify::Simplify, Expr}};
 /// let a = Expr::from(Fraction::from_ints(1, 5));
 /// let b = Expr::from(Fraction::from_ints(1, 5));
 /// assert_eq!(a, b);
@@ -38,9 +61,10 @@ use simplify::Simplify;
 ///
 /// let a = Expr::from(Radical::new(8));
 /// let b = Expr::from(Radical{ coef: 2, rad: 2 });
-/// assert_ne!(a, b); // Even though they are equivalent mathematically
-/// assert_eq!(a.simplified(), Notation::Expr(b)); // They need to be simplified first
+/// assert_eq!(a, b); // Radicals are compared in their normalized form
+/// assert_eq!(a.simplified(), Notation::Expr(b));
 /// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Debug, PartialEq, Clone)]
 pub enum Expr {
     /// A fraction.
@@ -52,6 +76,51 @@ pub enum Expr {
     ///
     /// See [`Radical`]
     Radical(Radical),
+
+    /// A radical divided by an integer.
+    ///
+    /// See [`RadicalFraction`]
+    RadicalFraction(RadicalFraction),
+
+    /// A trigonometric function of an angle.
+    ///
+    /// See [`Trig`]
+    Trig(Trig),
+
+    /// A multiple of a constant.
+    ///
+    /// See [`Multiple`]
+    Multiple(Multiple),
+
+    /// A number with an imaginary part.
+    ///
+    /// See [`Complex`]
+    Complex(Complex),
+
+    /// Terms which can't be combined into one.
+    ///
+    /// See [`Sum`]
+    Sum(Sum),
+
+    /// A fraction with a compound numerator or denominator.
+    ///
+    /// See [`NestedFraction`]
+    NestedFraction(NestedFraction),
+
+    /// A root of a compound radicand.
+    ///
+    /// See [`NestedRadical`]
+    NestedRadical(NestedRadical),
+
+    /// Factors which can't be combined into one.
+    ///
+    /// See [`Product`]
+    Product(Product),
+
+    /// A base raised to an exponent, which can't be written any other way.
+    ///
+    /// See [`Power`]
+    Power(Power),
 }
 
 impl Expr {
@@ -65,10 +134,7 @@ impl Expr {
 
     /// Returns true if the expression represents a [`Fraction`], false otherwise.
     pub fn is_fraction(&self) -> bool {
-        match self {
-            Expr::Fraction(_) => true,
-            _ => false,
-        }
+        matches!(self, Expr::Fraction(_))
     }
 
     /// If the expression represents a [`Radical`], returns that radical. Otherwise returns [`None`].
@@ -81,9 +147,192 @@ impl Expr {
 
     /// Returns true if the expression represents a [`Radical`], false otherwise.
     pub fn is_radical(&self) -> bool {
+        matches!(self, Expr::Radical(_))
+    }
+
+    /// If the expression represents a [`RadicalFraction`], returns that fraction. Otherwise returns [`None`].
+    pub fn radical_fraction(self) -> Option<RadicalFraction> {
+        match self {
+            Expr::RadicalFraction(frac) => Some(frac),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the expression represents a [`RadicalFraction`], false otherwise.
+    pub fn is_radical_fraction(&self) -> bool {
+        matches!(self, Expr::RadicalFraction(_))
+    }
+
+    /// If the expression represents a [`Trig`] function, returns that function. Otherwise returns [`None`].
+    pub fn trig(self) -> Option<Trig> {
+        match self {
+            Expr::Trig(trig) => Some(trig),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the expression represents a [`Trig`] function, false otherwise.
+    pub fn is_trig(&self) -> bool {
+        matches!(self, Expr::Trig(_))
+    }
+
+    /// If the expression represents a [`Multiple`] of a constant, returns that multiple. Otherwise returns [`None`].
+    pub fn multiple(self) -> Option<Multiple> {
+        match self {
+            Expr::Multiple(mult) => Some(mult),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the expression represents a [`Multiple`] of a constant, false otherwise.
+    pub fn is_multiple(&self) -> bool {
+        matches!(self, Expr::Multiple(_))
+    }
+
+    /// If the expression represents a [`Complex`] number, returns that number. Otherwise returns [`None`].
+    pub fn complex(self) -> Option<Complex> {
+        match self {
+            Expr::Complex(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the expression represents a [`Complex`] number, false otherwise.
+    pub fn is_complex(&self) -> bool {
+        matches!(self, Expr::Complex(_))
+    }
+
+    /// If the expression represents a [`Sum`] of terms, returns that sum. Otherwise returns [`None`].
+    pub fn sum(self) -> Option<Sum> {
+        match self {
+            Expr::Sum(sum) => Some(sum),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the expression represents a [`Sum`] of terms, false otherwise.
+    pub fn is_sum(&self) -> bool {
+        matches!(self, Expr::Sum(_))
+    }
+
+    /// If the expression represents a [`NestedFraction`], returns that fraction. Otherwise returns [`None`].
+    pub fn nested_fraction(self) -> Option<NestedFraction> {
+        match self {
+            Expr::NestedFraction(frac) => Some(frac),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the expression represents a [`NestedFraction`], false otherwise.
+    pub fn is_nested_fraction(&self) -> bool {
+        matches!(self, Expr::NestedFraction(_))
+    }
+
+    /// If the expression represents a [`NestedRadical`], returns that root. Otherwise returns [`None`].
+    pub fn nested_radical(self) -> Option<NestedRadical> {
         match self {
-            Expr::Radical(_) => true,
-            _ => false,
+            Expr::NestedRadical(root) => Some(root),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the expression represents a [`NestedRadical`], false otherwise.
+    pub fn is_nested_radical(&self) -> bool {
+        matches!(self, Expr::NestedRadical(_))
+    }
+
+    /// If the expression represents a [`Product`] of factors, returns that product. Otherwise returns [`None`].
+    pub fn product(self) -> Option<Product> {
+        match self {
+            Expr::Product(product) => Some(product),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the expression represents a [`Product`] of factors, false otherwise.
+    pub fn is_product(&self) -> bool {
+        matches!(self, Expr::Product(_))
+    }
+
+    /// If the expression represents a [`Power`], returns that power. Otherwise returns [`None`].
+    pub fn power(self) -> Option<Power> {
+        match self {
+            Expr::Power(power) => Some(power),
+            _ => None,
+        }
+    }
+
+    /// Returns true if the expression represents a [`Power`], false otherwise.
+    pub fn is_power(&self) -> bool {
+        matches!(self, Expr::Power(_))
+    }
+
+    /// Returns true if the expression represents a value less than zero, false otherwise.
+    ///
+    /// See [`Fraction::is_negative()`], [`Radical::is_negative()`], [`RadicalFraction::is_negative()`], [`Multiple::is_negative()`], and [`NestedRadical::is_negative()`].
+    /// The sign of a [`Trig`] function that could not be simplified is not known, so it is never negative.
+    /// A [`Complex`] number has no sign, so it is never negative either.
+    /// A [`Sum`], [`Product`], [`Power`], or [`NestedFraction`] is negative if its approximate value is.
+    pub fn is_negative(&self) -> bool {
+        use Expr::*;
+        match self {
+            Fraction(frac) => frac.is_negative(),
+            Radical(rad) => rad.is_negative(),
+            RadicalFraction(frac) => frac.is_negative(),
+            Trig(_) | Complex(_) => false,
+            Multiple(mult) => mult.is_negative(),
+            NestedRadical(root) => root.is_negative(),
+            Sum(_) | Product(_) | Power(_) | NestedFraction(_) => {
+                self.eval_f64().is_some_and(|value| value < 0.0)
+            }
+        }
+    }
+
+    /// Approximates the value of the expression as a float.
+    ///
+    /// Returns [`None`] if the expression has no specific real value,
+    /// such as a [`Complex`][crate::notation::atom::Atom::Complex] radical, a [`Complex`] number, or a fraction involving [`Undefined`][crate::notation::atom::Atom::Undefined].
+    pub fn eval_f64(&self) -> Option<f64> {
+        use Expr::*;
+        let eval_fraction = |frac: &fraction::Fraction| match frac.den.eval_f64()? {
+            0.0 => None,
+            den => Some(frac.num.eval_f64()? / den),
+        };
+        let eval_radical = |rad: &radical::Radical| match rad.rad {
+            ..=-1 => None,
+            r => Some(rad.coef as f64 * (r as f64).sqrt()),
+        };
+        match self {
+            Fraction(frac) => eval_fraction(frac),
+            Radical(rad) => eval_radical(rad),
+            RadicalFraction(frac) => match frac.den {
+                0 => None,
+                den => Some(eval_radical(&frac.num)? / den as f64),
+            },
+            Trig(trig) => {
+                let radians = trig.arg.eval_f64()?.to_radians();
+                match trig.func {
+                    trig::TrigFunction::Sin => Some(radians.sin()),
+                    trig::TrigFunction::Cos => Some(radians.cos()),
+                    trig::TrigFunction::Tan => Some(radians.tan()),
+                }
+            }
+            Multiple(mult) => Some(eval_fraction(&mult.coef)? * mult.constant.eval_f64()?),
+            Complex(_) => None,
+            Sum(sum) => sum.terms.iter().map(Notation::eval_f64).sum(),
+            NestedFraction(frac) => match frac.den.eval_f64()? {
+                0.0 => None,
+                den => Some(frac.num.eval_f64()? / den),
+            },
+            NestedRadical(root) => match root.rad.eval_f64()? {
+                rad if rad < 0.0 => None,
+                rad => Some(root.coef as f64 * rad.sqrt()),
+            },
+            Product(product) => product.factors.iter().map(Notation::eval_f64).product(),
+            Power(power) => match power.base.eval_f64()?.powf(power.exp.eval_f64()?) {
+                value if value.is_nan() => None,
+                value => Some(value),
+            },
         }
     }
 }
@@ -94,17 +343,22 @@ impl Simplify for Expr {
         match self {
             Fraction(f) => f.simplify(),
             Radical(r) => r.simplify(),
+            RadicalFraction(r) => r.simplify(),
+            Trig(t) => t.simplify(),
+            Multiple(m) => m.simplify(),
+            Complex(c) => c.simplify(),
+            Sum(s) => s.simplify(),
+            NestedFraction(f) => f.simplify(),
+            NestedRadical(r) => r.simplify(),
+            Product(p) => p.simplify(),
+            Power(p) => p.simplify(),
         }
     }
 }
 
 impl std::fmt::Display for Expr {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        use Expr::*;
-        match self {
-            Fraction(frac) => frac.fmt(f),
-            Radical(rad) => rad.fmt(f),
-        }
+        self.format_with(&FormatOptions::default()).fmt(f)
     }
 }
 
@@ -126,6 +380,78 @@ impl From<Radical> for Expr {
     }
 }
 
+// ## RadicalFraction
+
+impl From<RadicalFraction> for Expr {
+    fn from(value: RadicalFraction) -> Self {
+        Expr::RadicalFraction(value)
+    }
+}
+
+// ## Trig
+
+impl From<Trig> for Expr {
+    fn from(value: Trig) -> Self {
+        Expr::Trig(value)
+    }
+}
+
+// ## Multiple
+
+impl From<Multiple> for Expr {
+    fn from(value: Multiple) -> Self {
+        Expr::Multiple(value)
+    }
+}
+
+// ## Complex
+
+impl From<Complex> for Expr {
+    fn from(value: Complex) -> Self {
+        Expr::Complex(value)
+    }
+}
+
+// ## Sum
+
+impl From<Sum> for Expr {
+    fn from(value: Sum) -> Self {
+        Expr::Sum(value)
+    }
+}
+
+// ## NestedFraction
+
+impl From<NestedFraction> for Expr {
+    fn from(value: NestedFraction) -> Self {
+        Expr::NestedFraction(value)
+    }
+}
+
+// ## NestedRadical
+
+impl From<NestedRadical> for Expr {
+    fn from(value: NestedRadical) -> Self {
+        Expr::NestedRadical(value)
+    }
+}
+
+// ## Product
+
+impl From<Product> for Expr {
+    fn from(value: Product) -> Self {
+        Expr::Product(value)
+    }
+}
+
+// ## Power
+
+impl From<Power> for Expr {
+    fn from(value: Power) -> Self {
+        Expr::Power(value)
+    }
+}
+
 // # Equality
 
 // ## Fraction
@@ -153,3 +479,179 @@ impl std::cmp::PartialEq<Radical> for Expr {
         }
     }
 }
+
+// ## RadicalFraction
+
+impl std::cmp::PartialEq<RadicalFraction> for Expr {
+    fn eq(&self, other: &RadicalFraction) -> bool {
+        use Expr::*;
+        if let RadicalFraction(frac) = self {
+            frac == other
+        } else {
+            false
+        }
+    }
+}
+
+// ## Trig
+
+impl std::cmp::PartialEq<Trig> for Expr {
+    fn eq(&self, other: &Trig) -> bool {
+        use Expr::*;
+        if let Trig(trig) = self {
+            trig == other
+        } else {
+            false
+        }
+    }
+}
+
+// ## Multiple
+
+impl std::cmp::PartialEq<Multiple> for Expr {
+    fn eq(&self, other: &Multiple) -> bool {
+        use Expr::*;
+        if let Multiple(mult) = self {
+            mult == other
+        } else {
+            false
+        }
+    }
+}
+
+// ## Complex
+
+impl std::cmp::PartialEq<Complex> for Expr {
+    fn eq(&self, other: &Complex) -> bool {
+        use Expr::*;
+        if let Complex(value) = self {
+            value == other
+        } else {
+            false
+        }
+    }
+}
+
+// ## Sum
+
+impl std::cmp::PartialEq<Sum> for Expr {
+    fn eq(&self, other: &Sum) -> bool {
+        use Expr::*;
+        if let Sum(sum) = self {
+            sum == other
+        } else {
+            false
+        }
+    }
+}
+
+// ## NestedFraction
+
+impl std::cmp::PartialEq<NestedFraction> for Expr {
+    fn eq(&self, other: &NestedFraction) -> bool {
+        use Expr::*;
+        if let NestedFraction(frac) = self {
+            frac == other
+        } else {
+            false
+        }
+    }
+}
+
+// ## NestedRadical
+
+impl std::cmp::PartialEq<NestedRadical> for Expr {
+    fn eq(&self, other: &NestedRadical) -> bool {
+        use Expr::*;
+        if let NestedRadical(root) = self {
+            root == other
+        } else {
+            false
+        }
+    }
+}
+
+// ## Product
+
+impl std::cmp::PartialEq<Product> for Expr {
+    fn eq(&self, other: &Product) -> bool {
+        use Expr::*;
+        if let Product(product) = self {
+            product == other
+        } else {
+            false
+        }
+    }
+}
+
+// ## Power
+
+impl std::cmp::PartialEq<Power> for Expr {
+    fn eq(&self, other: &Power) -> bool {
+        use Expr::*;
+        if let Power(power) = self {
+            power == other
+        } else {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::{atom::Atom, expr::trig::TrigFunction};
+
+    #[test]
+    fn test_display_matches_each_kind() {
+        let cases: [(Expr, String); 11] = [
+            (
+                Expr::from(Fraction::new(1, 2)),
+                Fraction::new(1, 2).to_string(),
+            ),
+            (Expr::from(Radical::new(3)), Radical::new(3).to_string()),
+            (
+                Expr::from(RadicalFraction::new(1, 3, 2)),
+                RadicalFraction::new(1, 3, 2).to_string(),
+            ),
+            (
+                Expr::from(Trig::new(TrigFunction::Sin, 10)),
+                Trig::new(TrigFunction::Sin, 10).to_string(),
+            ),
+            (
+                Expr::from(Multiple::new(2, 3, Atom::Pi)),
+                Multiple::new(2, 3, Atom::Pi).to_string(),
+            ),
+            (
+                Expr::from(Complex::new(1, 2)),
+                Complex::new(1, 2).to_string(),
+            ),
+            (
+                Expr::from(Sum::new([
+                    Notation::from(1),
+                    Notation::from(Radical::new(2)),
+                ])),
+                "1+√2".to_string(),
+            ),
+            (
+                Expr::from(NestedFraction::new(Atom::Pi, Radical::new(2))),
+                NestedFraction::new(Atom::Pi, Radical::new(2)).to_string(),
+            ),
+            (
+                Expr::from(NestedRadical::new(Radical::from(2) + Radical::new(2))),
+                "√(2+√2)".to_string(),
+            ),
+            (
+                Expr::from(Product::new([
+                    Notation::from(Atom::Pi),
+                    Notation::from(Atom::E),
+                ])),
+                "π·𝑒".to_string(),
+            ),
+            (Expr::from(Power::new(Atom::Pi, Atom::E)), "π^𝑒".to_string()),
+        ];
+        for (expr, expected) in cases {
+            assert_eq!(expr.to_string(), expected);
+        }
+    }
+}
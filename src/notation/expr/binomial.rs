@@ -0,0 +1,162 @@
+//! Binomial radicals, the sum of a rational number and a square root.
+
+use crate::notation::{
+    expr::{radical::Radical, sum::Sum, Expr, Simplify},
+    ops::{normalized_radical_of, rational},
+    Notation,
+};
+
+/// A rational number plus a rational multiple of a square root, `a + b√rad`, such as 2 + √3 or 1/2 - √5/2.
+///
+/// Binomials are written as a [`Sum`] of their two terms.
+/// This is that sum taken apart, so that it can be rationalized:
+/// multiplying by the [conjugate][Binomial::conjugate] `a - b√rad` leaves the rational [norm][Binomial::norm] `a² - b²rad`.
+/// Dividing notation by a binomial does this, so that no root is left in the denominator.
+///
+/// ```
+/// # use algebra::notation::{Notation, expr::{binomial::Binomial, simplify::Simplify}};
+/// let den = Binomial::new(2, 1, 3);
+/// assert_eq!(den.simplified().to_string(), "2+√3");
+/// assert_eq!(den.norm(), 1);
+///
+/// // 1/(2 + √3) = 2 - √3
+/// let quotient = Notation::from(1) / den.simplified();
+/// assert_eq!(quotient.to_string(), "2-√3");
+/// assert_eq!(Binomial::of(&quotient), Some(den.conjugate()));
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Binomial {
+    /// The rational term.
+    pub a: Notation,
+
+    /// The rational coefficient of the root.
+    pub b: Notation,
+
+    /// The radicand.
+    pub rad: i32,
+}
+
+impl Binomial {
+    /// Constructs the binomial `a + b√rad`.
+    pub fn new(a: impl Into<Notation>, b: impl Into<Notation>, rad: i32) -> Self {
+        Self {
+            a: a.into(),
+            b: b.into(),
+            rad,
+        }
+    }
+
+    /// If the notation is a [`Sum`] of a ratio and a real radical, in either order, takes it apart into a binomial.
+    /// Otherwise returns [`None`].
+    ///
+    /// The radicand is made square-free, so that `1 + √8` is taken apart as `1 + 2√2`.
+    pub fn of(value: &Notation) -> Option<Self> {
+        let Notation::Expr(Expr::Sum(Sum { terms })) = value else {
+            return None;
+        };
+        let [lhs, rhs] = terms.as_ref() else {
+            return None;
+        };
+        let term = |(coef, rad, den): (i32, i32, i32)| (rational(coef as i128, den as i128), rad);
+        match (
+            term(normalized_radical_of(lhs)?),
+            term(normalized_radical_of(rhs)?),
+        ) {
+            ((a, 1), (b, rad)) | ((b, rad), (a, 1)) if rad > 1 => Some(Self::new(a, b, rad)),
+            _ => None,
+        }
+    }
+
+    /// Returns the conjugate `a - b√rad`, which has the opposite root term.
+    pub fn conjugate(&self) -> Self {
+        Self {
+            b: -self.b.clone(),
+            ..self.clone()
+        }
+    }
+
+    /// Returns the binomial times its conjugate, `a² - b²rad`, which is always rational.
+    pub fn norm(&self) -> Notation {
+        let (a, b) = (self.a.clone(), self.b.clone());
+        a.clone() * a - b.clone() * b * Notation::from(self.rad)
+    }
+
+    /// Returns `1 / (a + b√rad)` with a rational denominator, which is the conjugate divided by the norm.
+    ///
+    /// ```
+    /// # use algebra::notation::expr::binomial::Binomial;
+    /// // 1/(1 + √2) = -1 + √2
+    /// assert_eq!(Binomial::new(1, 1, 2).recip().to_string(), "-1+√2");
+    /// // 1/(3 - √5) = 3/4 + √5/4
+    /// assert_eq!(Binomial::new(3, -1, 5).recip().to_string(), "3/4+√5/4");
+    /// ```
+    pub fn recip(&self) -> Notation {
+        self.conjugate().simplify() / self.norm()
+    }
+}
+
+impl Simplify for Binomial {
+    fn simplify(self) -> Notation {
+        self.a + self.b * Notation::from(Radical::new(self.rad))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::expr::{fraction::Fraction, radical_fraction::RadicalFraction};
+
+    #[test]
+    fn test_of() {
+        let value = Notation::from(Sum::new([
+            Radical { coef: 3, rad: 8 }.into(),
+            Fraction::new(1, 2).into(),
+        ]));
+        assert_eq!(
+            Binomial::of(&value),
+            Some(Binomial::new(Fraction::new(1, 2), 6, 2))
+        );
+        let value = Notation::from(Sum::new([1.into(), RadicalFraction::new(-1, 5, 2).into()]));
+        assert_eq!(
+            Binomial::of(&value),
+            Some(Binomial::new(1, Fraction::new(-1, 2), 5))
+        );
+        let roots = Notation::from(Sum::new([Radical::new(2).into(), Radical::new(3).into()]));
+        assert_eq!(Binomial::of(&roots), None);
+        assert_eq!(Binomial::of(&Notation::from(Radical::new(2))), None);
+    }
+
+    #[test]
+    fn test_recip() {
+        // 1/(2 + √3) = 2 - √3
+        assert_eq!(
+            Binomial::new(2, 1, 3).recip(),
+            Binomial::new(2, -1, 3).simplify()
+        );
+        // 1/(1/2 + √5/2) = -1/2 + √5/2
+        assert_eq!(
+            Binomial::new(Fraction::new(1, 2), Fraction::new(1, 2), 5).recip(),
+            Binomial::new(Fraction::new(-1, 2), Fraction::new(1, 2), 5).simplify()
+        );
+        assert_eq!(
+            Binomial::new(0, 2, 3).recip(),
+            RadicalFraction::new(1, 3, 6)
+        );
+    }
+
+    #[test]
+    fn test_div() {
+        let den = Binomial::new(2, 1, 3).simplify();
+        assert_eq!((Notation::from(1) / den.clone()).to_string(), "2-√3");
+        // √3/(2 + √3) = 2√3 - 3
+        assert_eq!(
+            (Notation::from(Radical::new(3)) / den.clone()).to_string(),
+            "2√3-3"
+        );
+        // (1 + √3)/(1 - √3) = -2 - √3
+        let num = Binomial::new(1, 1, 3).simplify();
+        let den = Binomial::new(1, -1, 3).simplify();
+        assert_eq!((num / den.clone()).to_string(), "-2-√3");
+        assert_eq!(den.clone() / den, 1);
+    }
+}
@@ -0,0 +1,422 @@
+//! Numbers with an imaginary part.
+
+use crate::notation::{
+    atom::Atom,
+    expr::{
+        radical::Radical,
+        trig::{Trig, TrigFunction},
+        Expr, Simplify,
+    },
+    format::{FormatOptions, FormatWith},
+    Notation,
+};
+use std::sync::Arc;
+
+/// A real part plus an imaginary part, such as 3 + 2𝑖 or 1/2 + 𝑖√3/2.
+///
+/// 𝑖 is the square root of -1, so 𝑖 × 𝑖 = -1.
+///
+/// Both parts may be any real notation, such as fractions and radicals.
+/// Simplifying puts the number in the form `a + b𝑖` with both parts simplified,
+/// and gives just the real part when the imaginary part is 0.
+///
+/// ```
+/// # use algebra::notation::{Notation, expr::{complex::Complex, fraction::Fraction, radical::Radical, radical_fraction::RadicalFraction, simplify::Simplify}};
+/// let i = Complex::i();
+/// assert_eq!(i.clone() * i.clone(), -1);
+/// assert_eq!(Complex::from(1) + i, Complex::new(1, 1));
+/// assert_eq!(Complex::imaginary(12).simplified().to_string(), "2𝑖√3");
+///
+/// let root = Complex::new(Fraction::new(1, 2), RadicalFraction::new(1, 3, 2));
+/// assert_eq!(root.to_string(), "1/2+𝑖√3/2");
+/// assert_eq!(root.clone() * root.conjugate(), 1);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, PartialEq, Clone)]
+pub struct Complex {
+    /// The real part.
+    pub re: Arc<Notation>,
+
+    /// The imaginary part.
+    ///
+    /// The real value being multiplied by 𝑖.
+    pub im: Arc<Notation>,
+}
+
+impl From<i32> for Complex {
+    /// Convert an integer value into a complex number with an imaginary part of 0.
+    fn from(re: i32) -> Self {
+        Self::new(re, 0)
+    }
+}
+
+impl Complex {
+    /// Constructs the complex number `re + im𝑖`.
+    pub fn new(re: impl Into<Notation>, im: impl Into<Notation>) -> Self {
+        Self {
+            re: Arc::new(re.into()),
+            im: Arc::new(im.into()),
+        }
+    }
+
+    /// Constructs 𝑖, the square root of -1.
+    pub fn i() -> Self {
+        Self::new(0, 1)
+    }
+
+    /// Constructs `𝑖√rad`, the square root of `-rad`.
+    pub fn imaginary(rad: i32) -> Self {
+        Self::new(0, Radical::new(rad))
+    }
+
+    /// Returns the complex conjugate, which has the opposite imaginary part.
+    ///
+    /// A complex number times its conjugate is always real.
+    pub fn conjugate(&self) -> Self {
+        Self {
+            re: self.re.clone(),
+            im: Arc::new(-self.im.as_ref().clone()),
+        }
+    }
+
+    /// Returns the square of the distance from 0, `re² + im²`, which is the number times its conjugate.
+    pub fn norm(&self) -> Notation {
+        let (re, im) = self.parts();
+        re.clone() * re + im.clone() * im
+    }
+
+    /// Returns the distance from 0, `√(re² + im²)`.
+    ///
+    /// Exact whenever both parts are integers, fractions, or radicals, such as |1 + 𝑖| = √2.
+    pub fn modulus(&self) -> Notation {
+        self.clone().reduced().norm().sqrt()
+    }
+
+    /// Returns the angle from the positive real axis in degrees, greater than -180 and at most 180.
+    ///
+    /// Returns [`None`] if the angle isn't a multiple of 30° or 45°, which have exact trig values,
+    /// or if the number is 0 and has no angle.
+    ///
+    /// ```
+    /// # use algebra::notation::expr::{complex::Complex, radical::Radical};
+    /// assert_eq!(Complex::new(1, 1).argument(), Some(45.into()));
+    /// assert_eq!(Complex::new(-1, Radical::new(3)).argument(), Some(120.into()));
+    /// assert_eq!(Complex::new(1, 2).argument(), None);
+    /// ```
+    pub fn argument(&self) -> Option<Notation> {
+        let reduced = self.clone().reduced();
+        let (x, y) = (reduced.re.eval_f64()?, reduced.im.eval_f64()?);
+        if x == 0.0 && y == 0.0 {
+            return None;
+        }
+        // Round to the nearest special angle, then check that it is exactly right
+        let degrees = match (y.atan2(x).to_degrees() / 15.0).round() as i32 * 15 {
+            -180 => 180,
+            degrees => degrees,
+        };
+        if degrees % 30 != 0 && degrees % 45 != 0 {
+            return None;
+        }
+        let modulus = reduced.modulus();
+        let cos = modulus.clone() * Trig::new(TrigFunction::Cos, degrees).simplify();
+        let sin = modulus * Trig::new(TrigFunction::Sin, degrees).simplify();
+        (cos == *reduced.re && sin == *reduced.im).then(|| Notation::from(degrees))
+    }
+
+    /// Returns the polar form `(r, θ)` of the number, where it equals `r(cos θ + 𝑖 sin θ)`
+    /// with `r` the [`modulus`][Complex::modulus] and `θ` the [`argument`][Complex::argument] in degrees.
+    ///
+    /// Returns [`None`] if the number has no exact argument.
+    pub fn to_polar(&self) -> Option<(Notation, Notation)> {
+        Some((self.modulus(), self.argument()?))
+    }
+
+    /// Constructs `r(cos θ + 𝑖 sin θ)` from a modulus `r` and an angle `θ` in degrees.
+    ///
+    /// The parts are exact when the angle is a multiple of 30° or 45°,
+    /// and are left as trig functions when the modulus is 1.
+    ///
+    /// ```
+    /// # use algebra::notation::expr::{complex::Complex, radical::Radical};
+    /// assert_eq!(Complex::from_polar(2, 60), Complex::new(1, Radical::new(3)));
+    /// assert_eq!(Complex::from_polar(Radical::new(2), -45), Complex::new(1, -1));
+    /// assert_eq!(Complex::from_polar(1, 10).to_string(), "cos(10°)+sin(10°)𝑖");
+    /// ```
+    pub fn from_polar(modulus: impl Into<Notation>, degrees: impl Into<Notation>) -> Notation {
+        let (modulus, degrees) = (modulus.into(), degrees.into());
+        let part = |func| {
+            let value = Trig::new(func, degrees.clone()).simplify();
+            match modulus == 1 {
+                true => value,
+                false => modulus.clone() * value,
+            }
+        };
+        Self::new(part(TrigFunction::Cos), part(TrigFunction::Sin)).simplify()
+    }
+
+    /// Returns copies of the real and imaginary parts.
+    fn parts(&self) -> (Notation, Notation) {
+        (self.re.as_ref().clone(), self.im.as_ref().clone())
+    }
+
+    /// Simplifies the number, keeping it complex even if its imaginary part is 0.
+    ///
+    /// Used before arithmetic, so that both parts are real and as simple as possible.
+    fn reduced(self) -> Self {
+        match self.simplify() {
+            Notation::Expr(Expr::Complex(value)) => value,
+            real => Self::new(real, 0),
+        }
+    }
+}
+
+impl Simplify for Complex {
+    fn simplify(self) -> Notation {
+        self.simplify_with(Notation::simplify)
+    }
+}
+
+impl Complex {
+    /// Simplifies each part with `simplify`, then combines them.
+    pub(crate) fn simplify_with(self, simplify: impl Fn(Notation) -> Notation) -> Notation {
+        let simplified = |part: Arc<Notation>| simplify(Arc::unwrap_or_clone(part));
+        match (simplified(self.re), simplified(self.im)) {
+            (Notation::Atom(Atom::Undefined), _) | (_, Notation::Atom(Atom::Undefined)) => {
+                Notation::from(Atom::Undefined)
+            }
+            (Notation::Atom(Atom::Complex), _) | (_, Notation::Atom(Atom::Complex)) => {
+                Notation::from(Atom::Complex)
+            }
+            // (a + b𝑖) + (c + d𝑖)𝑖 = (a - d) + (b + c)𝑖
+            (re, Notation::Expr(Expr::Complex(Self { re: c, im: d }))) => {
+                re + Notation::from(Self {
+                    re: Arc::new(-Arc::unwrap_or_clone(d)),
+                    im: c,
+                })
+            }
+            (Notation::Expr(Expr::Complex(re)), im) => {
+                Notation::from(re) + Notation::from(Self::new(0, im))
+            }
+            (re, im) if im.is_zero() => re,
+            (re, im) => Notation::from(Self::new(re, im)),
+        }
+    }
+}
+
+impl std::ops::Neg for Complex {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        let (re, im) = self.parts();
+        Self::new(-re, -im)
+    }
+}
+
+impl std::ops::Add for Complex {
+    type Output = Notation;
+
+    /// Adds the real parts and the imaginary parts.
+    fn add(self, rhs: Self) -> Self::Output {
+        let ((a, b), (c, d)) = (self.reduced().parts(), rhs.reduced().parts());
+        Self::new(a + c, b + d).simplify()
+    }
+}
+
+impl std::ops::Sub for Complex {
+    type Output = Notation;
+
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl std::ops::Mul for Complex {
+    type Output = Notation;
+
+    /// Multiplies out both parts of each number, using 𝑖 × 𝑖 = -1.
+    fn mul(self, rhs: Self) -> Self::Output {
+        // (a + b𝑖)(c + d𝑖) = (ac - bd) + (ad + bc)𝑖
+        let ((a, b), (c, d)) = (self.reduced().parts(), rhs.reduced().parts());
+        let re = a.clone() * c.clone() - b.clone() * d.clone();
+        let im = a * d + b * c;
+        Self::new(re, im).simplify()
+    }
+}
+
+impl std::ops::Div for Complex {
+    type Output = Notation;
+
+    /// Divides by multiplying both numbers by the conjugate of the divisor, which makes the divisor real.
+    ///
+    /// Gives [`Complex`][Atom::Complex] if either number holds it,
+    /// since a divisor which could be any complex number can't be made real.
+    fn div(self, rhs: Self) -> Self::Output {
+        let rhs = rhs.reduced();
+        let norm = rhs.norm();
+        if norm == 0 {
+            return Notation::from(Atom::Undefined);
+        }
+        if [&self.re, &self.im, &rhs.re, &rhs.im]
+            .into_iter()
+            .any(|part| holds_complex(part))
+            || holds_complex(&norm)
+        {
+            return Notation::from(Atom::Complex);
+        }
+        match self * rhs.conjugate() {
+            Notation::Expr(Expr::Complex(product)) => {
+                let (re, im) = product.parts();
+                Self::new(re / norm.clone(), im / norm).simplify()
+            }
+            real => real / norm,
+        }
+    }
+}
+
+/// Returns true if the notation holds the [`Complex`][Atom::Complex] atom anywhere, false otherwise.
+fn holds_complex(value: &Notation) -> bool {
+    value
+        .preorder()
+        .any(|node| matches!(node, Notation::Atom(Atom::Complex)))
+}
+
+impl std::fmt::Display for Complex {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.format_with(&FormatOptions::default()).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::expr::{fraction::Fraction, radical_fraction::RadicalFraction};
+
+    #[test]
+    fn test_simplify() {
+        assert_eq!(
+            Complex::imaginary(12).simplify(),
+            Complex::new(0, Radical { coef: 2, rad: 3 })
+        );
+        assert_eq!(Complex::imaginary(9).simplify(), Complex::new(0, 3));
+        assert_eq!(Complex::new(4, Radical::new(0)).simplify(), 4);
+        assert_eq!(
+            Complex::new(Fraction::new(2, 4), Fraction::new(6, 3)).simplify(),
+            Complex::new(Fraction::new(1, 2), 2)
+        );
+        // 1 + (2 + 3𝑖)𝑖 = -2 + 2𝑖
+        assert_eq!(
+            Complex::new(1, Complex::new(2, 3)).simplify(),
+            Complex::new(-2, 2)
+        );
+        assert!(Complex::new(Fraction::new(1, 0), 1)
+            .simplify()
+            .atom()
+            .is_some_and(|x| x.is_undefined()));
+    }
+
+    #[test]
+    fn test_add() {
+        assert_eq!(
+            Complex::new(1, Radical { coef: 2, rad: 3 }) + Complex::new(4, Radical::new(3)),
+            Complex::new(5, Radical { coef: 3, rad: 3 })
+        );
+        assert_eq!(Complex::new(1, 2) - Complex::new(1, 2), 0);
+        assert_eq!(
+            Complex::new(Fraction::new(1, 2), 1) + Complex::new(Fraction::new(1, 3), 1),
+            Complex::new(Fraction::new(5, 6), 2)
+        );
+    }
+
+    #[test]
+    fn test_mul() {
+        let i = Complex::i();
+        assert_eq!(i.clone() * i.clone(), -1);
+        assert_eq!(Complex::from(3) * i.clone(), Complex::new(0, 3));
+        assert_eq!(Complex::new(1, 1) * Complex::new(1, 1), Complex::new(0, 2));
+        assert_eq!(Complex::imaginary(2) * Complex::imaginary(8), -4);
+        let z = Complex::new(2, Radical::new(5));
+        assert_eq!(z.clone() * z.conjugate(), 9);
+        // The cube roots of 1
+        let root = Complex::new(Fraction::new(-1, 2), RadicalFraction::new(1, 3, 2));
+        let square = root.clone() * root.clone();
+        assert_eq!(square, root.conjugate());
+        assert_eq!(square * Notation::from(root), 1);
+    }
+
+    #[test]
+    fn test_div() {
+        // (1 + 𝑖)/(1 - 𝑖) = 𝑖
+        assert_eq!(Complex::new(1, 1) / Complex::new(1, -1), Complex::i());
+        assert_eq!(Complex::new(4, 2) / Complex::from(2), Complex::new(2, 1));
+        assert_eq!(Complex::from(1) / Complex::i(), Complex::new(0, -1));
+        assert_eq!(Complex::from(5) / Complex::new(2, 1), Complex::new(2, -1));
+        assert_eq!(
+            Complex::from(1) / Complex::new(1, 1),
+            Complex::new(Fraction::new(1, 2), Fraction::new(-1, 2))
+        );
+        assert_eq!(Complex::i() / Complex::i(), 1);
+        assert!((Complex::i() / Complex::from(0))
+            .atom()
+            .is_some_and(|x| x.is_undefined()));
+        // Dividing by any complex number gives any complex number, rather than never finishing
+        let is_complex = |value: Notation| value.atom().is_some_and(|x| x.is_complex());
+        for divisor in ["1-3𝑖+ℂ", "𝑒+𝑖+ℂ"] {
+            let divisor: Notation = divisor.parse().unwrap();
+            assert!(is_complex(Notation::from(Complex::from(2)) / divisor));
+        }
+        assert!(is_complex(
+            Complex::new(1, Atom::Complex) / Complex::new(1, 1)
+        ));
+        assert!(is_complex(
+            Complex::from(1) / Complex::new(Atom::Complex, 1)
+        ));
+    }
+
+    #[test]
+    fn test_polar() {
+        let cases: [(Complex, Notation, i32); 6] = [
+            (Complex::new(3, 0), 3.into(), 0),
+            (Complex::new(0, 2), 2.into(), 90),
+            (Complex::new(-1, -1), Radical::new(2).into(), -135),
+            (Complex::new(-4, 0), 4.into(), 180),
+            (Complex::new(Radical::new(3), -1), 2.into(), -30),
+            (
+                Complex::new(Fraction::new(-1, 2), RadicalFraction::new(1, 3, 2)),
+                1.into(),
+                120,
+            ),
+        ];
+        for (value, modulus, degrees) in cases {
+            assert_eq!(value.modulus(), modulus);
+            assert_eq!(value.argument(), Some(Notation::from(degrees)));
+            assert_eq!(
+                Complex::from_polar(modulus, degrees),
+                value.reduced().simplify()
+            );
+        }
+        assert_eq!(Complex::new(1, 2).modulus(), Radical::new(5));
+        assert_eq!(Complex::new(3, 4).to_polar(), None);
+        assert_eq!(Complex::from(0).argument(), None);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Complex::i().to_string(), "𝑖");
+        assert_eq!(Complex::new(0, -1).to_string(), "-𝑖");
+        assert_eq!(Complex::imaginary(3).to_string(), "𝑖√3");
+        assert_eq!(
+            Complex::new(0, Radical { coef: 2, rad: 3 }).to_string(),
+            "2𝑖√3"
+        );
+        assert_eq!(Complex::new(1, Radical::new(2)).to_string(), "1+𝑖√2");
+        assert_eq!(Complex::new(-1, -4).to_string(), "-1-4𝑖");
+        assert_eq!(
+            Complex::new(Radical::new(2), Fraction::new(-1, 2)).to_string(),
+            "√2-𝑖/2"
+        );
+        assert_eq!(
+            Complex::new(0, RadicalFraction::new(3, 5, 4)).to_string(),
+            "3𝑖√5/4"
+        );
+    }
+}
@@ -1,15 +1,36 @@
 //! A fraction made from a combination of algebraic atomics.
 
-use crate::{
-    factor::{gcf, Factoring},
-    notation::{
-        atom::{number, Atom},
-        expr::Simplify,
-        Notation,
-    },
+use crate::notation::{
+    atom::{number, Atom},
+    expr::{multiple::Multiple, sum::Sum, Simplify},
+    format::{FormatOptions, FormatWith},
+    ops::{gcd, table::special_quotient},
+    Notation,
 };
 
+pub mod decimal;
+pub mod farey;
+pub mod stern_brocot;
+
 /// A fraction made from a combination of algebraic atomics.
+///
+/// ## Construction
+///
+/// let 𝑛 and 𝑚 be integers:
+///
+/// [Fraction::from]\(𝑛) is equal to 𝑛/1, which simplifies to exactly 𝑛.
+///
+/// [Fraction::new]\(𝑛, 𝑚) and [Fraction::from_ints]\(𝑛, 𝑚) are equal to 𝑛/𝑚.
+///
+/// [Fraction] { num, den } is a fraction of any two [`Atom`]s, such as π/2.
+///
+/// ```
+/// # use algebra::notation::{atom::Atom, expr::{fraction::Fraction, simplify::Simplify}};
+/// assert_eq!(Fraction::from(4).simplified(), 4);
+/// assert_eq!(Fraction::from_ints(2, 6).simplified(), Fraction::new(1, 3));
+/// assert_eq!(Fraction { num: Atom::Pi, den: Atom::from(2) }.to_string(), "π/2");
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 #[derive(Clone, Debug, PartialEq)]
 pub struct Fraction {
     /// The numerator.
@@ -36,6 +57,13 @@ impl From<i32> for Fraction {
     }
 }
 
+impl Default for Fraction {
+    /// Construct the fraction 0/1.
+    fn default() -> Self {
+        Self::from(0)
+    }
+}
+
 impl Fraction {
     /// Constructs a fraction from integer numerator and denominator.
     ///
@@ -47,12 +75,267 @@ impl Fraction {
             den: den.into(),
         }
     }
+
+    /// Constructs a fraction from integer numerator and denominator, the same as [`new`][Fraction::new()].
+    ///
+    /// Named to match [`Radical::from_ints`][crate::notation::expr::radical::Radical::from_ints].
+    pub fn from_ints(num: i32, den: i32) -> Self {
+        Self::new(num, den)
+    }
+
+    /// Returns true if the numerator and denominator have opposite signs, false otherwise.
+    ///
+    /// A zero numerator is neither positive nor negative, and a zero denominator is [`Undefined`][Atom::Undefined].
+    pub fn is_negative(&self) -> bool {
+        let Self { num, den } = self;
+        *num != 0
+            && *den != 0
+            && ((num.is_negative() && den.is_positive())
+                || (num.is_positive() && den.is_negative()))
+    }
+
+    /// Returns the reciprocal, with the numerator and denominator swapped.
+    ///
+    /// The reciprocal of a fraction equal to 0 has a denominator of 0, so it simplifies to [`Undefined`][Atom::Undefined].
+    ///
+    /// ```
+    /// # use algebra::notation::expr::fraction::Fraction;
+    /// assert_eq!(Fraction::new(-2, 3).recip(), Fraction::new(3, -2));
+    /// assert_eq!(Fraction::new(2, 3) * Fraction::new(2, 3).recip(), 1);
+    /// ```
+    pub fn recip(&self) -> Self {
+        Self {
+            num: self.den.clone(),
+            den: self.num.clone(),
+        }
+    }
+
+    /// Constructs the fraction exactly equal to a float, if its numerator and denominator fit in an [`i32`].
+    ///
+    /// Every finite float is an integer over a power of 2, such as 0.375 = 3/8.
+    /// Most decimals, such as 0.1, can only be stored as floats close to them, whose exact fraction is far too large;
+    /// use [`approximate`][Fraction::approximate] for those.
+    ///
+    /// Returns [`None`] for infinities and NaN, and for floats whose reduced fraction is too large.
+    ///
+    /// ```
+    /// # use algebra::notation::expr::fraction::Fraction;
+    /// assert_eq!(Fraction::from_f64_exact(-0.375), Some(Fraction::new(-3, 8)));
+    /// assert_eq!(Fraction::from_f64_exact(6.0), Some(Fraction::from(6)));
+    /// assert_eq!(Fraction::from_f64_exact(0.1), None);
+    /// ```
+    pub fn from_f64_exact(value: f64) -> Option<Self> {
+        if !value.is_finite() {
+            return None;
+        }
+        if value == 0.0 {
+            return Some(Self::from(0));
+        }
+        // value = ±mantissa × 2^exponent
+        let bits = value.to_bits();
+        let sign = if value.is_sign_negative() { -1 } else { 1 };
+        let (mantissa, exponent) = match ((bits >> 52) & 0x7ff) as i32 {
+            // Subnormal floats have no implicit leading bit
+            0 => (bits & ((1 << 52) - 1), -1074),
+            biased => ((bits & ((1 << 52) - 1)) | (1 << 52), biased - 1075),
+        };
+        let shift = mantissa.trailing_zeros();
+        let (num, exponent) = (sign * (mantissa >> shift) as i128, exponent + shift as i32);
+        match exponent {
+            0.. if exponent < 32 => i32::try_from(num << exponent).ok().map(Self::from),
+            ..0 if exponent > -32 => Some(Self::new(
+                i32::try_from(num).ok()?,
+                i32::try_from(1i64 << -exponent).ok()?,
+            )),
+            _ => None,
+        }
+    }
+
+    /// Finds the fraction closest to a float with a denominator no larger than `max_den`,
+    /// such as 0.333… ≈ 1/3 or π ≈ 22/7 with a denominator of at most 10.
+    ///
+    /// The approximation comes from the continued fraction of the float,
+    /// stopping at the last convergent or semiconvergent whose denominator fits.
+    ///
+    /// Returns [`None`] for infinities and NaN, for floats too large for an [`i32`], and if `max_den` is less than 1.
+    ///
+    /// ```
+    /// # use algebra::notation::expr::fraction::Fraction;
+    /// assert_eq!(Fraction::approximate(1.0 / 3.0, 10), Some(Fraction::new(1, 3)));
+    /// assert_eq!(Fraction::approximate(std::f64::consts::PI, 10), Some(Fraction::new(22, 7)));
+    /// assert_eq!(Fraction::approximate(std::f64::consts::PI, 1000), Some(Fraction::new(355, 113)));
+    /// assert_eq!(Fraction::approximate(0.1, 100), Some(Fraction::new(1, 10)));
+    /// ```
+    pub fn approximate(value: f64, max_den: i32) -> Option<Self> {
+        if !value.is_finite() || value.abs() > i32::MAX as f64 + 1.0 || max_den < 1 {
+            return None;
+        }
+        let max_den = max_den as i128;
+        let error = |(num, den): (i128, i128)| (num as f64 / den as f64 - value).abs();
+        // The two latest convergents, starting from 0/1 and 1/0
+        let (mut before, mut last) = ((0i128, 1i128), (1i128, 0i128));
+        let mut x = value;
+        loop {
+            let a = x.floor();
+            let next = (a as i128)
+                .checked_mul(last.0)
+                .and_then(|p| p.checked_add(before.0))
+                .zip(
+                    (a as i128)
+                        .checked_mul(last.1)
+                        .and_then(|q| q.checked_add(before.1)),
+                );
+            match next {
+                Some(next) if next.1 <= max_den => (before, last) = (last, next),
+                _ => {
+                    // The closest fraction may be between the last two convergents
+                    let k = (max_den - before.1) / last.1;
+                    let semi = (before.0 + k * last.0, before.1 + k * last.1);
+                    if error(semi) < error(last) {
+                        last = semi;
+                    }
+                    break;
+                }
+            }
+            let rest = x - a;
+            if rest == 0.0 {
+                break;
+            }
+            x = rest.recip();
+        }
+        Some(Self::new(
+            i32::try_from(last.0).ok()?,
+            i32::try_from(last.1).ok()?,
+        ))
+    }
+
+    /// Writes a positive fraction as a sum of distinct unit fractions, such as 4/13 = 1/4 + 1/18 + 1/468.
+    ///
+    /// Uses the greedy algorithm: each term is the largest unit fraction no larger than what is left,
+    /// and smaller than the term before it. Fractions of 1 or more start with 1, 1/2, 1/3, and so on.
+    /// A unit fraction is a sum of just itself.
+    ///
+    /// Returns [`None`] if the fraction isn't a positive ratio of [`Number`][Atom::Number]s,
+    /// or if a denominator grows too large for an [`i32`], as the greedy algorithm's can.
+    ///
+    /// ```
+    /// # use algebra::notation::expr::{fraction::Fraction, simplify::Simplify};
+    /// let sum = Fraction::new(4, 13).to_egyptian().unwrap();
+    /// assert_eq!(sum.to_string(), "1/4+1/18+1/468");
+    /// assert_eq!(sum.simplify(), Fraction::new(4, 13));
+    ///
+    /// assert_eq!(Fraction::new(3, 2).to_egyptian().unwrap().to_string(), "1+1/2");
+    /// assert_eq!(Fraction::new(-1, 2).to_egyptian(), None);
+    /// ```
+    pub fn to_egyptian(&self) -> Option<Sum> {
+        let (Atom::Number(num), Atom::Number(den)) = (&self.num, &self.den) else {
+            return None;
+        };
+        // The part left to write, kept reduced, with both parts positive
+        let sign = (num.value as i128).signum() * (den.value as i128).signum();
+        let (mut num, mut den) = ((num.value as i128).abs(), (den.value as i128).abs());
+        if sign != 1 {
+            return None;
+        }
+        let mut terms = Vec::new();
+        let mut last = 0;
+        while num != 0 {
+            // The smallest denominator whose unit fraction fits in what is left, and is smaller than the last
+            let next = ((den + num - 1) / num).max(last + 1);
+            let unit = i32::try_from(next).ok()?;
+            terms.push(match unit {
+                1 => Notation::from(1),
+                unit => Notation::from(Fraction::new(1, unit)),
+            });
+            // num/den - 1/next = (num·next - den)/(den·next)
+            (num, den) = (num.checked_mul(next)? - den, den.checked_mul(next)?);
+            let gcf = gcd(num, den).max(1);
+            (num, den) = (num / gcf, den / gcf);
+            last = next;
+        }
+        Some(Sum::new(terms))
+    }
+}
+
+/// Returns the mediant of two fractions, `(a + c)/(b + d)`, which is between them if both denominators are positive.
+///
+/// The mediant depends on how the fractions are written rather than just their values, so 1/2 and 2/3 have a mediant of 3/5,
+/// but 2/4 and 2/3 have a mediant of 4/7.
+/// Mediants build the [Stern–Brocot tree][stern_brocot::SternBrocot] and the [Farey sequences][farey::Farey].
+///
+/// Returns [`None`] if either fraction isn't made of [`Number`][Atom::Number]s, or if a sum doesn't fit in an [`i32`].
+///
+/// ```
+/// # use algebra::notation::expr::fraction::{mediant, Fraction};
+/// assert_eq!(mediant(&Fraction::new(1, 2), &Fraction::new(2, 3)), Some(Fraction::new(3, 5)));
+/// assert_eq!(mediant(&Fraction::new(2, 4), &Fraction::new(2, 3)), Some(Fraction::new(4, 7)));
+/// assert_eq!(mediant(&Fraction::new(i32::MAX, 1), &Fraction::new(1, 1)), None);
+/// ```
+pub fn mediant(lhs: &Fraction, rhs: &Fraction) -> Option<Fraction> {
+    match (lhs, rhs) {
+        (
+            Fraction {
+                num: Atom::Number(a),
+                den: Atom::Number(b),
+            },
+            Fraction {
+                num: Atom::Number(c),
+                den: Atom::Number(d),
+            },
+        ) => Some(Fraction::new(
+            a.value.checked_add(c.value)?,
+            b.value.checked_add(d.value)?,
+        )),
+        _ => None,
+    }
+}
+
+impl std::ops::Add for Fraction {
+    type Output = Notation;
+
+    /// Adds two fractions over their least common denominator, such as 1/6 + 1/10 = 5/30 + 3/30 = 4/15.
+    ///
+    /// If the result is too large to be a fraction, returns [`Huge`][Atom::Huge] or [`NegativeHuge`][Atom::NegativeHuge],
+    /// and if it is too small, returns [`Epsilon`][Atom::Epsilon] or [`NegativeEpsilon`][Atom::NegativeEpsilon].
+    fn add(self, rhs: Self) -> Self::Output {
+        Notation::from(self) + Notation::from(rhs)
+    }
+}
+
+impl std::ops::Sub for Fraction {
+    type Output = Notation;
+
+    /// Subtracts two fractions over their least common denominator, saturating the same way as adding.
+    fn sub(self, rhs: Self) -> Self::Output {
+        Notation::from(self) - Notation::from(rhs)
+    }
+}
+
+impl std::ops::Mul for Fraction {
+    type Output = Notation;
+
+    /// Multiplies two fractions, cancelling common factors across them first,
+    /// so that (999999/4) × (8/3) = (333333/1) × (2/1) doesn't overflow on the way to 666666.
+    fn mul(self, rhs: Self) -> Self::Output {
+        Notation::from(self) * Notation::from(rhs)
+    }
+}
+
+impl std::ops::Div for Fraction {
+    type Output = Notation;
+
+    /// Divides two fractions by multiplying by the [reciprocal][Fraction::recip] of the divisor,
+    /// cancelling the same way as multiplying.
+    ///
+    /// Dividing by 0 gives [`Undefined`][Atom::Undefined].
+    fn div(self, rhs: Self) -> Self::Output {
+        Notation::from(self) / Notation::from(rhs)
+    }
 }
 
 impl std::fmt::Display for Fraction {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        let Self { num, den } = self;
-        format!("{num}/{den}").fmt(f)
+        self.format_with(&FormatOptions::default()).fmt(f)
     }
 }
 
@@ -61,61 +344,283 @@ impl Simplify for Fraction {
         use number::Number as Num;
         use Atom::*;
         let Fraction { num, den } = self;
+        // Dividing by or into 0 and the special values follows the division table
+        if let Some(quotient) = special_quotient(&num, &den) {
+            return Notation::from(quotient);
+        }
         match (num, den) {
-            (Complex, _) | (_, Complex) => Notation::from(Complex),
-
-            (Undefined, _) | (_, Undefined) | (_, Number(Num { value: 0 })) => {
-                Notation::from(Undefined)
-            }
-
-            (Number(Num { value: 0 }), _) => Notation::from(0),
-
-            (num @ (Huge | NegativeHuge | Epsilon | NegativeEpsilon), den @ Number(_)) => {
-                Notation::from(if num.is_positive() == den.is_positive() {
-                    num
-                } else {
-                    -num
-                })
-            }
-
             (Number(Num { value: num }), Number(Num { value: den })) => {
-                if den.is_factor_of(num) {
+                // Widened, because the magnitude of i32::MIN doesn't fit in an i32
+                let (num, den) = (num as i128, den as i128);
+                // Transfer sign to the top
+                let sign = num.signum() * den.signum();
+                let (num_abs, den_abs) = (num.abs(), den.abs());
+                let gcf = gcd(num_abs, den_abs);
+                match (
+                    i32::try_from(sign * num_abs / gcf),
+                    i32::try_from(den_abs / gcf),
+                ) {
                     // Division leaves no remainder
-                    Notation::from(num / den)
-                } else {
-                    // Transfer sign to the top
-                    let sign = if (num < 0) != (den < 0) { -1 } else { 1 };
-                    let (num_abs, den_abs) = (num.abs(), den.abs());
-                    let gcf = gcf([num_abs, den_abs]);
-                    Notation::from(Fraction::new(sign * num_abs / gcf, den_abs / gcf))
+                    (Ok(num), Ok(1)) => Notation::from(num),
+                    (Ok(num), Ok(den)) => Notation::from(Fraction::new(num, den)),
+                    // A denominator of 2^31 only fits as i32::MIN, which carries the sign instead
+                    (Ok(num), Err(_)) => Notation::from(Fraction::new(-num, i32::MIN)),
+                    // Only i32::MIN / -1 is too large to be a number
+                    (Err(_), Ok(1)) => Notation::from(Huge),
+                    // Otherwise the numerator is 2^31, so the sign stays on the bottom
+                    (Err(_), Ok(den)) => Notation::from(Fraction::new(i32::MIN, -den)),
+                    (Err(_), Err(_)) => unreachable!("2^31 and 2^31 share a factor"),
                 }
             }
 
-            (num @ (Huge | NegativeHuge), den @ (Huge | NegativeHuge)) => {
-                Notation::from(if num.is_positive() == den.is_positive() {
-                    Huge
-                } else {
-                    NegativeHuge
-                })
+            (constant @ (Pi | E), Number(Num { value: den })) => {
+                Multiple::new(1, den, constant).simplify()
             }
 
-            (num @ (Number(_) | Epsilon | NegativeEpsilon), den @ (Huge | NegativeHuge)) => {
-                Notation::from(if num.is_positive() == den.is_positive() {
-                    Epsilon
-                } else {
-                    NegativeEpsilon
-                })
+            (Pi, Pi) | (E, E) => Notation::from(1),
+
+            // No simpler form, such as 1/π or π/𝑒
+            (num @ (Number(_) | Pi | E), den @ (Pi | E)) => Notation::from(Fraction { num, den }),
+
+            // A variable could have any value, so only dividing it by 0 or by something undefined is settled
+            (Undefined, Variable(_)) | (Variable(_), Undefined | Number(Num { value: 0 })) => {
+                Notation::from(Undefined)
             }
+            (num @ Variable(_), den) | (num, den @ Variable(_)) => {
+                Notation::from(Fraction { num, den })
+            }
+
+            _ => unreachable!("Quotients of special values are all in the division table."),
+        }
+    }
+}
+
+#[cfg(test)]
+mod add_fraction_tests {
+    use super::*;
 
-            (num, den @ (Epsilon | NegativeEpsilon)) => {
-                Notation::from(if num.is_positive() == den.is_positive() {
-                    Huge
-                } else {
-                    NegativeHuge
-                })
+    #[test]
+    fn test_common_denominator() {
+        assert_eq!(
+            Fraction::new(1, 6) + Fraction::new(1, 10),
+            Fraction::new(4, 15)
+        );
+        assert_eq!(
+            Fraction::new(3, 4) - Fraction::new(5, 6),
+            Fraction::new(-1, 12)
+        );
+        assert_eq!(Fraction::new(2, -3) + Fraction::new(4, 6), 0);
+        assert_eq!(Fraction::new(1, 2) + Fraction::from(1), Fraction::new(3, 2));
+    }
+
+    #[test]
+    fn test_saturates() {
+        assert!((Fraction::new(i32::MAX, 1) + Fraction::new(1, 2))
+            .atom()
+            .is_some_and(|x| x.is_positive_huge()));
+        assert!((Fraction::new(i32::MIN, 1) - Fraction::new(1, 2))
+            .atom()
+            .is_some_and(|x| x.is_negative_huge()));
+        assert!(
+            (Fraction::new(1, i32::MAX) - Fraction::new(1, i32::MAX - 1))
+                .atom()
+                .is_some_and(|x| x.is_negative_epsilon())
+        );
+    }
+
+    #[test]
+    fn test_non_ratios() {
+        assert!((Fraction::new(1, 0) + Fraction::new(1, 2))
+            .atom()
+            .is_some_and(|x| x.is_undefined()));
+        let pi = Fraction {
+            num: Atom::Pi,
+            den: 2.into(),
+        };
+        assert!((pi.clone() + pi).atom().is_some_and(|x| x.is_pi()));
+    }
+}
+
+#[cfg(test)]
+mod f64_fraction_tests {
+    use super::*;
+    use std::f64::consts::{E, PI};
+
+    #[test]
+    fn test_from_f64_exact() {
+        assert_eq!(Fraction::from_f64_exact(0.0), Some(Fraction::from(0)));
+        assert_eq!(Fraction::from_f64_exact(-0.0), Some(Fraction::from(0)));
+        assert_eq!(Fraction::from_f64_exact(0.5), Some(Fraction::new(1, 2)));
+        assert_eq!(
+            Fraction::from_f64_exact(-1234.5625),
+            Some(Fraction::new(-19753, 16))
+        );
+        assert_eq!(
+            Fraction::from_f64_exact(-2147483648.0),
+            Some(Fraction::from(i32::MIN))
+        );
+        assert_eq!(
+            Fraction::from_f64_exact(1.0 / 1073741824.0),
+            Some(Fraction::new(1, 1 << 30))
+        );
+        assert_eq!(Fraction::from_f64_exact(2147483648.0), None);
+        assert_eq!(Fraction::from_f64_exact(1.0 / 2147483648.0), None);
+        assert_eq!(Fraction::from_f64_exact(1.0 / 3.0), None);
+        assert_eq!(Fraction::from_f64_exact(f64::MIN_POSITIVE / 2.0), None);
+        assert_eq!(Fraction::from_f64_exact(f64::NAN), None);
+        assert_eq!(Fraction::from_f64_exact(f64::INFINITY), None);
+    }
+
+    #[test]
+    fn test_approximate() {
+        assert_eq!(Fraction::approximate(PI, 1), Some(Fraction::from(3)));
+        // 311/99 is a semiconvergent between 22/7 and 333/106
+        assert_eq!(Fraction::approximate(PI, 100), Some(Fraction::new(311, 99)));
+        assert_eq!(Fraction::approximate(-E, 10), Some(Fraction::new(-19, 7)));
+        assert_eq!(Fraction::approximate(-0.75, 4), Some(Fraction::new(-3, 4)));
+        assert_eq!(Fraction::approximate(0.001, 10), Some(Fraction::from(0)));
+        assert_eq!(
+            Fraction::approximate(2147483647.25, 2),
+            Some(Fraction::from(i32::MAX))
+        );
+        assert_eq!(
+            Fraction::approximate(1.0 / 7.0, i32::MAX),
+            Some(Fraction::new(1, 7))
+        );
+        assert_eq!(Fraction::approximate(5e9, 10), None);
+        assert_eq!(Fraction::approximate(0.5, 0), None);
+        assert_eq!(Fraction::approximate(f64::NAN, 10), None);
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for (num, den) in [(1, 3), (-22, 7), (355, 113), (1, 1000), (i32::MAX, 2)] {
+            let value = num as f64 / den as f64;
+            assert_eq!(
+                Fraction::approximate(value, den),
+                Some(Fraction::new(num, den))
+            );
+        }
+    }
+}
+
+#[cfg(test)]
+mod egyptian_fraction_tests {
+    use super::*;
+
+    fn egyptian(num: i32, den: i32) -> Option<String> {
+        Fraction::new(num, den)
+            .to_egyptian()
+            .map(|sum| sum.to_string())
+    }
+
+    #[test]
+    fn test_greedy() {
+        assert_eq!(egyptian(5, 6).as_deref(), Some("1/2+1/3"));
+        assert_eq!(egyptian(7, 15).as_deref(), Some("1/3+1/8+1/120"));
+        assert_eq!(egyptian(1, 7).as_deref(), Some("1/7"));
+        assert_eq!(egyptian(6, 14).as_deref(), Some("1/3+1/11+1/231"));
+        assert_eq!(egyptian(-2, -3).as_deref(), Some("1/2+1/6"));
+    }
+
+    #[test]
+    fn test_at_least_one() {
+        assert_eq!(egyptian(1, 1).as_deref(), Some("1"));
+        assert_eq!(egyptian(2, 1).as_deref(), Some("1+1/2+1/3+1/6"));
+        let sum = Fraction::new(7, 3).to_egyptian().unwrap();
+        assert_eq!(Notation::from(sum.clone()).simplify(), Fraction::new(7, 3));
+        // Every denominator is distinct
+        let mut dens = sum
+            .terms
+            .iter()
+            .map(|term| term.to_string())
+            .collect::<Vec<_>>();
+        dens.dedup();
+        assert_eq!(dens.len(), sum.terms.len());
+    }
+
+    #[test]
+    fn test_sums_to_fraction() {
+        for den in 2..20 {
+            for num in 1..den {
+                let fraction = Fraction::new(num, den);
+                let sum = fraction.to_egyptian().unwrap();
+                assert_eq!(Notation::from(sum).simplify(), fraction.simplified());
             }
         }
     }
+
+    #[test]
+    fn test_not_positive() {
+        assert_eq!(egyptian(0, 5), None);
+        assert_eq!(egyptian(-3, 4), None);
+        assert_eq!(egyptian(3, 0), None);
+        let pi = Fraction {
+            num: Atom::Pi,
+            den: 2.into(),
+        };
+        assert_eq!(pi.to_egyptian(), None);
+    }
+
+    #[test]
+    fn test_denominators_too_large() {
+        // 5/121 = 1/25 + 1/757 + 1/763309 + 1/873960180913 + ...
+        assert_eq!(egyptian(5, 121), None);
+    }
+}
+
+#[cfg(test)]
+mod mul_fraction_tests {
+    use super::*;
+
+    #[test]
+    fn test_cross_cancels() {
+        assert_eq!(Fraction::new(999_999, 4) * Fraction::new(8, 3), 666_666);
+        assert_eq!(Fraction::new(i32::MAX, 10) * Fraction::new(10, i32::MAX), 1);
+        assert_eq!(Fraction::new(i32::MAX, 2) / Fraction::new(i32::MAX, 4), 2);
+        assert_eq!(
+            Fraction::new(-3, 4) * Fraction::new(2, 9),
+            Fraction::new(-1, 6)
+        );
+    }
+
+    #[test]
+    fn test_div() {
+        assert_eq!(
+            Fraction::new(3, 4) / Fraction::new(9, 8),
+            Fraction::new(2, 3)
+        );
+        assert_eq!(Fraction::new(1, 2) / Fraction::new(-1, 2), -1);
+        assert!((Fraction::new(1, 2) / Fraction::from(0))
+            .atom()
+            .is_some_and(|x| x.is_undefined()));
+    }
+
+    #[test]
+    fn test_saturates() {
+        assert!((Fraction::new(i32::MAX, 1) * Fraction::new(3, 2))
+            .atom()
+            .is_some_and(|x| x.is_positive_huge()));
+        assert!((Fraction::new(1, i32::MAX) / Fraction::new(-3, 1))
+            .atom()
+            .is_some_and(|x| x.is_negative_epsilon()));
+    }
+
+    #[test]
+    fn test_recip() {
+        assert_eq!(Fraction::new(1, 5).recip(), Fraction::from(5));
+        let pi = Fraction {
+            num: Atom::Pi,
+            den: 2.into(),
+        };
+        assert_eq!(
+            pi.recip(),
+            Fraction {
+                num: 2.into(),
+                den: Atom::Pi
+            }
+        );
+    }
 }
 
 #[cfg(test)]
@@ -148,6 +653,21 @@ mod simplify_fraction_tests {
         }
     }
 
+    #[test]
+    fn test_min_keeps_sign_on_bottom() {
+        for frac in [
+            Fraction::new(i32::MIN, -3),
+            Fraction::new(3, i32::MIN),
+            Fraction::new(-3, i32::MIN),
+        ] {
+            assert_eq!(frac.simplified(), frac);
+        }
+        assert_eq!(
+            Fraction::new(i32::MIN, -6).simplified(),
+            Fraction::new(1 << 30, 3)
+        );
+    }
+
     #[test]
     fn test_simplifies_to_half() {
         for num in 1..=10 {
@@ -179,7 +699,7 @@ mod simplify_fraction_tests {
 
     #[test]
     fn test_negative_division_by_huge() {
-        for num in -1..=-10 {
+        for num in -10..=-1 {
             let simple = Fraction {
                 num: num.into(),
                 den: Huge,
@@ -203,7 +723,7 @@ mod simplify_fraction_tests {
 
     #[test]
     fn test_negative_division_by_negative_huge() {
-        for num in -1..=-10 {
+        for num in -10..=-1 {
             let simple = Fraction {
                 num: num.into(),
                 den: NegativeHuge,
@@ -227,7 +747,7 @@ mod simplify_fraction_tests {
 
     #[test]
     fn test_negative_division_by_epsilon() {
-        for num in -1..=-10 {
+        for num in -10..=-1 {
             let simple = Fraction {
                 num: num.into(),
                 den: Epsilon,
@@ -251,13 +771,92 @@ mod simplify_fraction_tests {
 
     #[test]
     fn test_negative_division_by_negative_epsilon() {
-        for num in -1..=-10 {
+        for num in -10..=-1 {
             let simple = Fraction {
                 num: num.into(),
-                den: Epsilon,
+                den: NegativeEpsilon,
             }
             .simplify();
             assert!(simple.atom().is_some_and(|x| x.is_positive_huge()));
         }
     }
+
+    #[test]
+    fn test_epsilon_over_epsilon_is_unknown() {
+        for num in [Epsilon, NegativeEpsilon] {
+            for den in [Epsilon, NegativeEpsilon] {
+                let simple = Fraction {
+                    num: num.clone(),
+                    den,
+                }
+                .simplify();
+                assert!(simple.atom().is_some_and(|x| x.is_unknown()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_huge_over_huge_is_unknown() {
+        for num in [Huge, NegativeHuge] {
+            for den in [Huge, NegativeHuge] {
+                let simple = Fraction {
+                    num: num.clone(),
+                    den,
+                }
+                .simplify();
+                assert!(simple.atom().is_some_and(|x| x.is_unknown()));
+            }
+        }
+    }
+
+    #[test]
+    fn test_agrees_with_division_and_rules() {
+        use crate::notation::{canonical::Canonical, rules::RuleSet};
+        let atoms = [
+            Atom::from(0),
+            Atom::from(2),
+            Atom::from(-3),
+            Atom::from(i32::MAX),
+            Atom::from(i32::MIN),
+            Pi,
+            E,
+            Complex,
+            Undefined,
+            Huge,
+            NegativeHuge,
+            Epsilon,
+            NegativeEpsilon,
+            Unknown,
+        ];
+        let rules = RuleSet::default();
+        for num in &atoms {
+            for den in &atoms {
+                let fraction = Fraction {
+                    num: num.clone(),
+                    den: den.clone(),
+                };
+                let simple = Canonical(fraction.clone().simplify());
+                let quotient = Notation::from(num.clone()) / Notation::from(den.clone());
+                assert_eq!(simple, Canonical(quotient), "{num}/{den}");
+                let ruled = Notation::from(fraction).simplify_with_rules(&rules);
+                assert_eq!(simple, Canonical(ruled), "{num}/{den}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_unknown_propagates() {
+        let over = |num: Atom, den: Atom| Fraction { num, den }.simplify();
+        assert!(over(Unknown, 3.into())
+            .atom()
+            .is_some_and(|x| x.is_unknown()));
+        assert!(over(Huge, Unknown).atom().is_some_and(|x| x.is_unknown()));
+        assert!(over(Unknown, 0.into())
+            .atom()
+            .is_some_and(|x| x.is_undefined()));
+        assert!(over(Unknown, Undefined)
+            .atom()
+            .is_some_and(|x| x.is_undefined()));
+        assert_eq!(over(0.into(), Unknown), 0);
+    }
 }
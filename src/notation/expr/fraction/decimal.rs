@@ -0,0 +1,294 @@
+//! Converting between fractions and decimals whose digits repeat, such as 5/12 = 0.41(6).
+//!
+//! The repeating digits are written in parentheses, so 0.41(6) is 0.41666…, and 0.(3) is 0.333….
+
+use crate::notation::{atom::Atom, expr::fraction::Fraction, ops::gcd};
+
+/// The most repeating digits [`Fraction::to_repeating_decimal`] writes.
+///
+/// A fraction's repeating digits can be as many as one less than its denominator,
+/// so without a limit, a large denominator could need billions of them.
+pub const MAX_PERIOD: usize = 1000;
+
+/// Reasons text could not be read as a repeating decimal.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ParseDecimalError {
+    /// There was no number to read.
+    Empty,
+
+    /// The text isn't a decimal, with or without repeating digits in parentheses.
+    Invalid(String),
+
+    /// The decimal's fraction has a numerator or denominator too large for an [`i32`].
+    TooPrecise,
+}
+
+impl std::fmt::Display for ParseDecimalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use ParseDecimalError::*;
+        match self {
+            Empty => "no decimal to parse".fmt(f),
+            Invalid(text) => format!("invalid decimal \"{text}\"").fmt(f),
+            TooPrecise => "decimal has too many digits".fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ParseDecimalError {}
+
+/// Reads a string of digits as an integer, and gives it with `10^len`, where `len` is the number of digits.
+fn digits(text: &str) -> Option<Result<(i128, i128), ParseDecimalError>> {
+    let mut value: i128 = 0;
+    let mut scale: i128 = 1;
+    for c in text.chars() {
+        let digit = c.to_digit(10)?;
+        let next = value
+            .checked_mul(10)
+            .and_then(|value| value.checked_add(digit as i128))
+            .zip(scale.checked_mul(10));
+        match next {
+            Some(next) => (value, scale) = next,
+            None => return Some(Err(ParseDecimalError::TooPrecise)),
+        }
+    }
+    Some(Ok((value, scale)))
+}
+
+impl Fraction {
+    /// Reads a decimal whose last digits may repeat, written in parentheses, as the reduced fraction it equals.
+    ///
+    /// `0.41(6)` is 0.41666… = 5/12, and `0.(9)` is 1.
+    /// A decimal without parentheses, such as `2.5`, is read as it is.
+    ///
+    /// Gives [`TooPrecise`][ParseDecimalError::TooPrecise] if the decimal has more than about 38 digits,
+    /// or if its fraction doesn't fit in an [`i32`].
+    ///
+    /// ```
+    /// # use algebra::notation::expr::fraction::Fraction;
+    /// assert_eq!(Fraction::from_repeating("0.41(6)"), Ok(Fraction::new(5, 12)));
+    /// assert_eq!(Fraction::from_repeating("-1.(142857)"), Ok(Fraction::new(-8, 7)));
+    /// assert_eq!(Fraction::from_repeating("2.5"), Ok(Fraction::new(5, 2)));
+    /// ```
+    pub fn from_repeating(text: &str) -> Result<Self, ParseDecimalError> {
+        let text = text.trim();
+        if text.is_empty() {
+            return Err(ParseDecimalError::Empty);
+        }
+        let invalid = || ParseDecimalError::Invalid(text.to_string());
+        let (sign, unsigned) = match text.strip_prefix('-') {
+            Some(rest) => (-1, rest),
+            None => (1, text.strip_prefix('+').unwrap_or(text)),
+        };
+        let (whole, places) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        let (fixed, repeating) = match places.split_once('(') {
+            Some((fixed, rest)) => match rest.strip_suffix(')') {
+                Some(repeating) if !repeating.is_empty() => (fixed, repeating),
+                _ => return Err(invalid()),
+            },
+            None => (places, ""),
+        };
+        if whole.is_empty() && places.is_empty() {
+            return Err(invalid());
+        }
+
+        let ((whole, _), (fixed, fixed_scale), (repeating, repeating_scale)) = (
+            digits(whole).ok_or_else(invalid)??,
+            digits(fixed).ok_or_else(invalid)??,
+            digits(repeating).ok_or_else(invalid)??,
+        );
+        // whole + (fixed·(10^r - 1) + repeating) / (10^k·(10^r - 1)), where k and r count the digits
+        let period = repeating_scale - 1;
+        let (num, den) = match period {
+            0 => (fixed, fixed_scale),
+            period => (
+                fixed
+                    .checked_mul(period)
+                    .and_then(|fixed| fixed.checked_add(repeating))
+                    .ok_or(ParseDecimalError::TooPrecise)?,
+                fixed_scale
+                    .checked_mul(period)
+                    .ok_or(ParseDecimalError::TooPrecise)?,
+            ),
+        };
+        let num = whole
+            .checked_mul(den)
+            .and_then(|whole| whole.checked_add(num))
+            .ok_or(ParseDecimalError::TooPrecise)?;
+        let gcf = gcd(num, den).max(1);
+        match (i32::try_from(sign * num / gcf), i32::try_from(den / gcf)) {
+            (Ok(num), Ok(den)) => Ok(Self::new(num, den)),
+            _ => Err(ParseDecimalError::TooPrecise),
+        }
+    }
+
+    /// Writes the fraction as a decimal, with the digits which repeat forever in parentheses, such as 5/12 = 0.41(6).
+    ///
+    /// Decimals which end are written without parentheses, such as 1/4 = 0.25.
+    /// The repeating digits can be as many as one less than the denominator, such as the 16 of 1/17.
+    ///
+    /// Returns [`None`] if the fraction isn't a ratio of [`Number`][Atom::Number]s, if its denominator is 0,
+    /// or if more than [`MAX_PERIOD`] digits repeat.
+    ///
+    /// ```
+    /// # use algebra::notation::expr::fraction::Fraction;
+    /// assert_eq!(Fraction::new(5, 12).to_repeating_decimal().as_deref(), Some("0.41(6)"));
+    /// assert_eq!(Fraction::new(-1, 3).to_repeating_decimal().as_deref(), Some("-0.(3)"));
+    /// assert_eq!(Fraction::new(9, 4).to_repeating_decimal().as_deref(), Some("2.25"));
+    /// assert_eq!(Fraction::new(1, 0).to_repeating_decimal(), None);
+    /// ```
+    pub fn to_repeating_decimal(&self) -> Option<String> {
+        let (Atom::Number(num), Atom::Number(den)) = (&self.num, &self.den) else {
+            return None;
+        };
+        let (num, den) = (num.value as i128, den.value as i128);
+        if den == 0 {
+            return None;
+        }
+        let sign = if num.signum() * den.signum() < 0 {
+            "-"
+        } else {
+            ""
+        };
+        let gcf = gcd(num.abs(), den.abs()).max(1);
+        let (num, den) = (num.abs() / gcf, den.abs() / gcf);
+
+        let mut text = format!("{sign}{}", num / den);
+        let mut rest = num % den;
+        if rest == 0 {
+            return Some(text);
+        }
+        text.push('.');
+        let next_digit = |rest: &mut i128| {
+            *rest *= 10;
+            let digit = (*rest / den) as u8;
+            *rest %= den;
+            char::from(b'0' + digit)
+        };
+
+        // The digits before the repeating ones are as many as the larger power of 2 or 5 in the denominator
+        let power = |mut n: i128, p: i128| {
+            let mut count = 0;
+            while n % p == 0 {
+                n /= p;
+                count += 1;
+            }
+            count
+        };
+        for _ in 0..power(den, 2).max(power(den, 5)) {
+            text.push(next_digit(&mut rest));
+        }
+        if rest == 0 {
+            return Some(text);
+        }
+
+        // The remainder comes back around once every repeating digit has been written
+        let start = rest;
+        text.push('(');
+        for _ in 0..MAX_PERIOD {
+            text.push(next_digit(&mut rest));
+            if rest == start {
+                text.push(')');
+                return Some(text);
+            }
+        }
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::expr::Simplify;
+
+    #[test]
+    fn test_from_repeating() {
+        assert_eq!(Fraction::from_repeating("0.(3)"), Ok(Fraction::new(1, 3)));
+        assert_eq!(Fraction::from_repeating("0.(9)"), Ok(Fraction::new(1, 1)));
+        assert_eq!(Fraction::from_repeating("0.1(6)"), Ok(Fraction::new(1, 6)));
+        assert_eq!(
+            Fraction::from_repeating(".(142857)"),
+            Ok(Fraction::new(1, 7))
+        );
+        assert_eq!(
+            Fraction::from_repeating("+3.(27)"),
+            Ok(Fraction::new(36, 11))
+        );
+        assert_eq!(Fraction::from_repeating("-4"), Ok(Fraction::new(-4, 1)));
+        assert_eq!(Fraction::from_repeating("0.250"), Ok(Fraction::new(1, 4)));
+        assert_eq!(Fraction::from_repeating(" 7. "), Ok(Fraction::new(7, 1)));
+    }
+
+    #[test]
+    fn test_from_repeating_errors() {
+        use ParseDecimalError::*;
+        assert_eq!(Fraction::from_repeating(""), Err(Empty));
+        for text in [
+            ".", "-", "0.()", "0.(3", "0.3)", "1.2.3", "0.(3)4", "x", "0.(a)",
+        ] {
+            assert_eq!(
+                Fraction::from_repeating(text),
+                Err(Invalid(text.to_string())),
+                "{text}"
+            );
+        }
+        assert_eq!(Fraction::from_repeating("0.(01234567891)"), Err(TooPrecise));
+        assert_eq!(
+            Fraction::from_repeating("0.00000000000000000000000000000000000000001"),
+            Err(TooPrecise)
+        );
+    }
+
+    #[test]
+    fn test_to_repeating_decimal() {
+        let decimal = |num, den| Fraction::new(num, den).to_repeating_decimal().unwrap();
+        assert_eq!(decimal(1, 7), "0.(142857)");
+        assert_eq!(decimal(1, 6), "0.1(6)");
+        assert_eq!(decimal(22, 7), "3.(142857)");
+        assert_eq!(decimal(1, 17), "0.(0588235294117647)");
+        assert_eq!(decimal(7, 12), "0.58(3)");
+        assert_eq!(decimal(6, -4), "-1.5");
+        assert_eq!(decimal(0, 5), "0");
+        assert_eq!(decimal(i32::MIN, 1), "-2147483648");
+        assert_eq!(decimal(1, 1 << 20), "0.00000095367431640625");
+        let pi = Fraction {
+            num: Atom::Pi,
+            den: 2.into(),
+        };
+        assert_eq!(pi.to_repeating_decimal(), None);
+    }
+
+    #[test]
+    fn test_long_periods() {
+        // 1/983 repeats 982 digits, which is within the limit
+        let decimal = Fraction::new(1, 983).to_repeating_decimal().unwrap();
+        assert_eq!(decimal.len(), "0.()".len() + 982);
+        assert!(decimal.starts_with("0.(001017293997"));
+        // 1/1019 would repeat 1018 digits, and i32::MIN/i32::MAX would repeat hundreds of millions
+        assert_eq!(Fraction::new(1, 1019).to_repeating_decimal(), None);
+        assert_eq!(
+            Fraction::new(i32::MIN, i32::MAX).to_repeating_decimal(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        // 1/47 is the first to repeat more digits than an i128 can read back
+        assert_eq!(
+            Fraction::from_repeating(&Fraction::new(1, 47).to_repeating_decimal().unwrap()),
+            Err(ParseDecimalError::TooPrecise)
+        );
+        for den in 1..47 {
+            for num in -60..60 {
+                let fraction = Fraction::new(num, den);
+                let decimal = fraction.to_repeating_decimal().unwrap();
+                let read = Fraction::from_repeating(&decimal).unwrap();
+                assert_eq!(
+                    read.simplify(),
+                    fraction.simplified(),
+                    "{num}/{den} = {decimal}"
+                );
+            }
+        }
+    }
+}
@@ -0,0 +1,107 @@
+//! Farey sequences, every fraction between 0 and 1 up to a largest denominator.
+
+use crate::notation::expr::fraction::Fraction;
+
+/// The Farey sequence of an order: every reduced fraction from 0/1 to 1/1 whose denominator is at most the order, from least to greatest.
+///
+/// Any two neighbors `a/b < c/d` in the sequence have `bc - ad = 1`,
+/// and the first fraction to appear between them in a higher order is their [mediant][super::mediant].
+///
+/// ```
+/// # use algebra::notation::expr::fraction::{farey::Farey, Fraction};
+/// let fifths = Farey::new(5).map(|x| x.to_string()).collect::<Vec<_>>();
+/// assert_eq!(
+///     fifths,
+///     ["0/1", "1/5", "1/4", "1/3", "2/5", "1/2", "3/5", "2/3", "3/4", "4/5", "1/1"]
+/// );
+/// assert_eq!(Farey::new(100).count(), 3045);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Farey {
+    order: i64,
+
+    /// The next two fractions, or [`None`] once the sequence is finished.
+    next: Option<((i64, i64), (i64, i64))>,
+}
+
+impl Farey {
+    /// Constructs the Farey sequence with denominators up to `order`.
+    ///
+    /// Orders less than 1 give an empty sequence.
+    pub fn new(order: i32) -> Self {
+        Self {
+            order: order as i64,
+            next: (order >= 1).then_some(((0, 1), (1, order as i64))),
+        }
+    }
+}
+
+impl Iterator for Farey {
+    type Item = Fraction;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let ((a, b), (c, d)) = self.next?;
+        self.next = match (a, b) {
+            (1, 1) => None,
+            // The fraction after c/d is the one which makes it their mediant, with the largest denominator allowed
+            _ => {
+                let k = (self.order + b) / d;
+                Some(((c, d), (k * c - a, k * d - b)))
+            }
+        };
+        // Every fraction in the sequence is between 0/1 and 1/1, with a denominator no larger than an `i32`
+        Some(Fraction::new(a as i32, b as i32))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        factor::gcf,
+        notation::{ops::ratio, Notation},
+    };
+
+    #[test]
+    fn test_small_orders() {
+        let farey = |order| Farey::new(order).collect::<Vec<_>>();
+        assert_eq!(farey(0), []);
+        assert_eq!(farey(1), [Fraction::new(0, 1), Fraction::new(1, 1)]);
+        assert_eq!(
+            farey(3),
+            [
+                Fraction::new(0, 1),
+                Fraction::new(1, 3),
+                Fraction::new(1, 2),
+                Fraction::new(2, 3),
+                Fraction::new(1, 1),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_neighbors() {
+        let fractions = Farey::new(30)
+            .map(|x| ratio(&Notation::from(x)).unwrap())
+            .collect::<Vec<_>>();
+        for pair in fractions.windows(2) {
+            let [(a, b), (c, d)] = pair else {
+                unreachable!()
+            };
+            assert_eq!(b * c - a * d, 1);
+            assert!(*b <= 30 && *d <= 30);
+        }
+        // One fraction for each numerator coprime to its denominator, plus 0/1
+        let count = (1..=30)
+            .map(|den| (1..=den).filter(|&num| gcf([num, den]) == 1).count())
+            .sum::<usize>();
+        assert_eq!(fractions.len(), count + 1);
+    }
+
+    #[test]
+    fn test_large_order() {
+        let mut farey = Farey::new(i32::MAX);
+        assert_eq!(farey.nth(1), Some(Fraction::new(1, i32::MAX)));
+        assert_eq!(farey.next(), Some(Fraction::new(1, i32::MAX - 1)));
+    }
+}
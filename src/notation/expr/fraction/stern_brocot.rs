@@ -0,0 +1,296 @@
+//! The Stern–Brocot tree, which holds every positive fraction exactly once.
+
+use crate::notation::{expr::fraction::Fraction, ops::ratio, Notation};
+
+/// Which child to move to in the [`SternBrocot`] tree.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Direction {
+    /// Towards smaller fractions.
+    Left,
+
+    /// Towards larger fractions.
+    Right,
+}
+
+/// A node of the Stern–Brocot tree, a binary search tree holding every positive reduced fraction exactly once.
+///
+/// The root is 1/1, between the bounds 0/1 and 1/0.
+/// Each node is the [mediant][super::mediant] of its bounds,
+/// and moving left or right replaces one bound with the node.
+///
+/// Searching down the tree finds fractions in order of increasing denominator,
+/// so the closest fraction to a value with a limited denominator is one of the bounds reached when the denominator runs out.
+///
+/// ```
+/// # use algebra::notation::expr::fraction::{stern_brocot::{Direction::*, SternBrocot}, Fraction};
+/// let node = SternBrocot::root().step(Left).unwrap().step(Right).unwrap();
+/// assert_eq!(node.fraction(), Fraction::new(2, 3));
+/// assert_eq!(SternBrocot::path(&Fraction::new(2, 3)), Some(vec![(Left, 1), (Right, 1)]));
+/// assert_eq!(SternBrocot::find(&Fraction::new(4, 6)), Some(node));
+///
+/// let pi = SternBrocot::best_approximation(std::f64::consts::PI, 100);
+/// assert_eq!(pi, Some(Fraction::new(311, 99)));
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct SternBrocot {
+    /// The greatest fraction less than every fraction under the node.
+    left: (i64, i64),
+
+    /// The least fraction greater than every fraction under the node.
+    right: (i64, i64),
+}
+
+impl Default for SternBrocot {
+    /// Returns the root, 1/1.
+    fn default() -> Self {
+        Self::root()
+    }
+}
+
+impl SternBrocot {
+    /// Returns the root of the tree, 1/1.
+    pub fn root() -> Self {
+        Self {
+            left: (0, 1),
+            right: (1, 0),
+        }
+    }
+
+    /// Returns the fraction at the node, which is always reduced.
+    pub fn fraction(&self) -> Fraction {
+        let (num, den) = self.node();
+        // Steps never reach a node which doesn't fit in an `i32`
+        Fraction::new(num as i32, den as i32)
+    }
+
+    fn node(&self) -> (i64, i64) {
+        (self.left.0 + self.right.0, self.left.1 + self.right.1)
+    }
+
+    /// Moves to the left or right child `count` times at once,
+    /// or returns [`None`] if the fraction there doesn't fit in an [`i32`].
+    pub fn steps(&self, direction: Direction, count: u32) -> Option<Self> {
+        let Self { left, right } = self.clone();
+        // Each step replaces the bound on that side with the mediant of the bounds
+        let count = count as i64;
+        let moved = match direction {
+            Direction::Left => Self {
+                left,
+                right: (right.0 + count * left.0, right.1 + count * left.1),
+            },
+            Direction::Right => Self {
+                left: (left.0 + count * right.0, left.1 + count * right.1),
+                right,
+            },
+        };
+        let (num, den) = moved.node();
+        (i32::try_from(num).is_ok() && i32::try_from(den).is_ok()).then_some(moved)
+    }
+
+    /// Moves to the left or right child, or returns [`None`] if the fraction there doesn't fit in an [`i32`].
+    pub fn step(&self, direction: Direction) -> Option<Self> {
+        self.steps(direction, 1)
+    }
+
+    /// Returns the moves from the root to a positive fraction, grouping moves in the same direction together.
+    ///
+    /// The number of moves in each group are the terms of the fraction's continued fraction, with 1 less in the last group.
+    /// The root has no moves.
+    ///
+    /// Returns [`None`] if the fraction isn't a positive ratio of [`Number`][crate::notation::atom::Atom::Number]s.
+    pub fn path(fraction: &Fraction) -> Option<Vec<(Direction, u32)>> {
+        let (num, den) = ratio(&Notation::from(fraction.clone()))?;
+        let (mut num, mut den) = (num.unsigned_abs(), den.unsigned_abs());
+        if num == 0 || (fraction.is_negative()) {
+            return None;
+        }
+        let mut path = vec![];
+        let mut direction = Direction::Right;
+        // Euclid's algorithm gives the terms of the continued fraction
+        while den != 0 {
+            let count = num / den;
+            (num, den) = (den, num % den);
+            // The last group stops on the fraction rather than passing it
+            let count = if den == 0 { count - 1 } else { count };
+            if count > 0 {
+                path.push((direction, count));
+            }
+            direction = match direction {
+                Direction::Left => Direction::Right,
+                Direction::Right => Direction::Left,
+            };
+        }
+        Some(path)
+    }
+
+    /// Returns the node holding a positive fraction, which may be written unreduced.
+    ///
+    /// Returns [`None`] if the fraction isn't a positive ratio of [`Number`][crate::notation::atom::Atom::Number]s.
+    pub fn find(fraction: &Fraction) -> Option<Self> {
+        Self::path(fraction)?
+            .into_iter()
+            .try_fold(Self::root(), |node, (direction, count)| {
+                node.steps(direction, count)
+            })
+    }
+
+    /// Finds the fraction closest to a float with a denominator no larger than `max_den`,
+    /// by searching down the tree until the denominator runs out.
+    ///
+    /// Gives the same fraction as [`Fraction::approximate`], which uses continued fractions,
+    /// except possibly when two fractions are equally close.
+    /// Both compare fractions as floats, so they may also disagree when `max_den` is large enough
+    /// for neighboring fractions to be closer together than floats can tell apart.
+    ///
+    /// Returns [`None`] for infinities and NaN, for floats too large for an [`i32`], and if `max_den` is less than 1.
+    pub fn best_approximation(value: f64, max_den: i32) -> Option<Fraction> {
+        if !value.is_finite() || value.abs() > i32::MAX as f64 + 1.0 || max_den < 1 {
+            return None;
+        }
+        // The tree only holds positive fractions, so the sign is put back on at the end
+        let (sign, x) = (value.signum() as i32, value.abs());
+        let max_den = max_den as i64;
+        let (mut left, mut right) = ((0i64, 1i64), (1i64, 0i64));
+        loop {
+            let node = (left.0 + right.0, left.1 + right.1);
+            if node.1 > max_den || x == node.0 as f64 / node.1 as f64 {
+                break;
+            }
+            // Takes every step in the same direction at once, keeping the bounds on either side of the value
+            // and their denominators no larger than `max_den`
+            let is_above = |(num, den): (i64, i64)| x < num as f64 / den as f64;
+            if is_above(node) {
+                // right + k × left is above the value while k < (right.0 - x right.1) / (x left.1 - left.0)
+                let limit =
+                    (right.0 as f64 - x * right.1 as f64) / (x * left.1 as f64 - left.0 as f64);
+                let mut k = (limit.ceil() as i64 - 1).clamp(1, (max_den - right.1) / left.1);
+                // Rounding may overshoot
+                while k > 1 && !is_above((right.0 + k * left.0, right.1 + k * left.1)) {
+                    k -= 1;
+                }
+                right = (right.0 + k * left.0, right.1 + k * left.1);
+            } else {
+                // left + k × right is below the value while k < (x left.1 - left.0) / (right.0 - x right.1)
+                let limit =
+                    (x * left.1 as f64 - left.0 as f64) / (right.0 as f64 - x * right.1 as f64);
+                let most = match right.1 {
+                    // Moving towards 1/0 doesn't change the denominator
+                    0 => i64::MAX,
+                    _ => (max_den - left.1) / right.1,
+                };
+                let mut k = (limit.ceil() as i64 - 1).clamp(1, most);
+                while k > 1 && is_above((left.0 + k * right.0, left.1 + k * right.1)) {
+                    k -= 1;
+                }
+                left = (left.0 + k * right.0, left.1 + k * right.1);
+            }
+        }
+        let node = (left.0 + right.0, left.1 + right.1);
+        let error = |(num, den): (i64, i64)| (num as f64 / den as f64 - x).abs();
+        let best = if node.1 <= max_den {
+            node
+        } else if right.1 == 0 || error(left) <= error(right) {
+            left
+        } else {
+            right
+        };
+        // The magnitude of -2147483648 only fits once the sign is back on
+        let num = i32::try_from(sign as i64 * best.0).ok()?;
+        Some(Fraction::new(num, best.1 as i32))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Direction::*, *};
+
+    #[test]
+    fn test_steps() {
+        let root = SternBrocot::root();
+        assert_eq!(root.fraction(), Fraction::new(1, 1));
+        assert_eq!(root.step(Left).unwrap().fraction(), Fraction::new(1, 2));
+        assert_eq!(root.step(Right).unwrap().fraction(), Fraction::new(2, 1));
+        assert_eq!(
+            root.steps(Right, 3).unwrap().step(Left).unwrap().fraction(),
+            Fraction::new(7, 2)
+        );
+        assert_eq!(
+            root.steps(Left, i32::MAX as u32 - 1).unwrap().fraction(),
+            Fraction::new(1, i32::MAX)
+        );
+        assert_eq!(root.steps(Left, i32::MAX as u32), None);
+        assert_eq!(root.steps(Right, u32::MAX), None);
+    }
+
+    #[test]
+    fn test_path() {
+        assert_eq!(SternBrocot::path(&Fraction::new(1, 1)), Some(vec![]));
+        // 7/2 = 3 + 1/2
+        assert_eq!(
+            SternBrocot::path(&Fraction::new(7, 2)),
+            Some(vec![(Right, 3), (Left, 1)])
+        );
+        // 3/8 = 0 + 1/(2 + 1/(1 + 1/2))
+        assert_eq!(
+            SternBrocot::path(&Fraction::new(-3, -8)),
+            Some(vec![(Left, 2), (Right, 1), (Left, 1)])
+        );
+        assert_eq!(SternBrocot::path(&Fraction::new(0, 1)), None);
+        assert_eq!(SternBrocot::path(&Fraction::new(-1, 2)), None);
+        assert_eq!(SternBrocot::path(&Fraction::new(1, 0)), None);
+    }
+
+    #[test]
+    fn test_find() {
+        for (num, den) in [
+            (1, 1),
+            (3, 8),
+            (8, 3),
+            (355, 113),
+            (i32::MAX, 1),
+            (1, i32::MAX),
+        ] {
+            let node = SternBrocot::find(&Fraction::new(num, den)).unwrap();
+            assert_eq!(node.fraction(), Fraction::new(num, den));
+        }
+        assert_eq!(
+            SternBrocot::find(&Fraction::new(6, 16)).map(|node| node.fraction()),
+            Some(Fraction::new(3, 8))
+        );
+    }
+
+    #[test]
+    fn test_best_approximation() {
+        let values = [
+            0.0,
+            0.001,
+            1.0 / 3.0,
+            -0.7,
+            std::f64::consts::PI,
+            -std::f64::consts::E,
+            std::f64::consts::SQRT_2,
+            2147483647.25,
+            123.456,
+        ];
+        for value in values {
+            for max_den in [1, 2, 3, 7, 10, 100, 1000, 65536, 1 << 24] {
+                assert_eq!(
+                    SternBrocot::best_approximation(value, max_den),
+                    Fraction::approximate(value, max_den),
+                    "{value} with denominators up to {max_den}"
+                );
+            }
+        }
+        assert_eq!(
+            SternBrocot::best_approximation(0.1, i32::MAX),
+            Some(Fraction::new(1, 10))
+        );
+        assert_eq!(
+            SternBrocot::best_approximation(-2147483648.0, 1),
+            Some(Fraction::new(i32::MIN, 1))
+        );
+        assert_eq!(SternBrocot::best_approximation(2147483648.0, 1), None);
+        assert_eq!(SternBrocot::best_approximation(f64::NAN, 10), None);
+        assert_eq!(SternBrocot::best_approximation(1.0, 0), None);
+    }
+}
@@ -0,0 +1,103 @@
+//! Rational multiples of constants.
+
+use crate::notation::{
+    atom::{number::Number, Atom},
+    expr::{fraction::Fraction, Expr, Simplify},
+    format::{FormatOptions, FormatWith},
+    Notation,
+};
+
+/// A constant such as [`Pi`][Atom::Pi] or [`E`][Atom::E], or a [`Variable`][Atom::Variable], multiplied by a rational coefficient.
+///
+/// Keeps constants exact through arithmetic, so that twice π is 2π rather than 6.283….
+///
+/// ```
+/// # use algebra::notation::{Notation, atom::Atom, expr::{multiple::Multiple, simplify::Simplify}};
+/// let tau = Notation::from(2) * Notation::from(Atom::Pi);
+/// assert_eq!(tau, Multiple::new(2, 1, Atom::Pi));
+/// assert_eq!(tau.to_string(), "2π");
+///
+/// let half_pi = Multiple::new(2, 4, Atom::Pi);
+/// assert_eq!(half_pi.simplified().to_string(), "π/2");
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, PartialEq, Clone)]
+pub struct Multiple {
+    /// The coefficient.
+    ///
+    /// The number the constant is being multiplied by.
+    pub coef: Fraction,
+
+    /// The constant being multiplied.
+    pub constant: Atom,
+}
+
+impl Multiple {
+    /// Constructs the multiple `(num/den) × constant`.
+    pub fn new(num: i32, den: i32, constant: Atom) -> Self {
+        Self {
+            coef: Fraction::new(num, den),
+            constant,
+        }
+    }
+
+    /// Returns true if the multiple represents a value less than zero, false otherwise.
+    ///
+    /// Every constant is positive, so this is the sign of the coefficient.
+    /// For a variable, which could have either sign, it is only the sign written in front of it.
+    pub fn is_negative(&self) -> bool {
+        self.coef.is_negative()
+    }
+}
+
+impl std::fmt::Display for Multiple {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.format_with(&FormatOptions::default()).fmt(f)
+    }
+}
+
+impl Simplify for Multiple {
+    fn simplify(self) -> Notation {
+        let Self { coef, constant } = self;
+        match coef.simplify() {
+            Notation::Atom(Atom::Number(Number { value: 0 })) => Notation::from(0),
+            Notation::Atom(Atom::Number(Number { value: 1 })) => Notation::from(constant),
+            Notation::Atom(Atom::Number(n)) => Notation::from(Multiple {
+                coef: Fraction::from(n.value),
+                constant,
+            }),
+            // A constant doesn't change whether something is too large or small to operate on
+            Notation::Atom(atom) => Notation::from(atom),
+            Notation::Expr(Expr::Fraction(coef)) => Notation::from(Multiple { coef, constant }),
+            Notation::Expr(_) => unreachable!("Fractions simplify to atoms or fractions."),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Atom::*, *};
+
+    #[test]
+    fn test_simplify() {
+        assert_eq!(Multiple::new(0, 3, Pi).simplify(), 0);
+        assert!(Multiple::new(3, 3, Pi)
+            .simplify()
+            .atom()
+            .is_some_and(|x| x.is_pi()));
+        assert_eq!(Multiple::new(6, 4, E).simplify(), Multiple::new(3, 2, E));
+        assert_eq!(Multiple::new(4, -2, E).simplify(), Multiple::new(-2, 1, E));
+        assert!(Multiple::new(1, 0, Pi)
+            .simplify()
+            .atom()
+            .is_some_and(|x| x.is_undefined()));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Multiple::new(2, 1, Pi).to_string(), "2π");
+        assert_eq!(Multiple::new(-1, 1, Pi).to_string(), "-π");
+        assert_eq!(Multiple::new(1, 3, E).to_string(), "𝑒/3");
+        assert_eq!(Multiple::new(-3, 4, Pi).to_string(), "-3π/4");
+    }
+}
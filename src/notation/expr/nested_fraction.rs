@@ -0,0 +1,160 @@
+//! Fractions whose numerator or denominator is itself an expression.
+
+use crate::notation::{
+    expr::Simplify,
+    format::{FormatOptions, FormatWith},
+    Notation,
+};
+use std::sync::Arc;
+
+/// Any notation divided by any other notation, such as (1/2)/(3/4) or π/√2.
+///
+/// A [`Fraction`][super::fraction::Fraction] only holds atoms, so this is how a quotient of
+/// compound values is written down before it is simplified.
+///
+/// Simplifying simplifies both parts and then divides them, which inverts and multiplies
+/// fractions and rationalizes radical denominators.
+/// A quotient that can't be written any more simply, such as π/√2, stays nested.
+///
+/// ```
+/// # use algebra::notation::expr::{fraction::Fraction, nested_fraction::NestedFraction, radical::Radical, radical_fraction::RadicalFraction, simplify::Simplify};
+/// let nested = NestedFraction::new(Fraction::new(1, 2), Fraction::new(3, 4));
+/// assert_eq!(nested.to_string(), "(1/2)/(3/4)");
+/// assert_eq!(nested.simplify(), Fraction::new(2, 3));
+///
+/// let nested = NestedFraction::new(1, Radical::new(2));
+/// assert_eq!(nested.simplify(), RadicalFraction::new(1, 2, 2));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, PartialEq, Clone)]
+pub struct NestedFraction {
+    /// The numerator.
+    ///
+    /// The value being divided.
+    pub num: Arc<Notation>,
+
+    /// The denominator.
+    ///
+    /// The value the numerator is being divided by.
+    pub den: Arc<Notation>,
+}
+
+impl NestedFraction {
+    /// Constructs the quotient `num/den`.
+    pub fn new(num: impl Into<Notation>, den: impl Into<Notation>) -> Self {
+        Self {
+            num: Arc::new(num.into()),
+            den: Arc::new(den.into()),
+        }
+    }
+
+    /// Returns the quotient with its numerator and denominator swapped.
+    pub fn recip(&self) -> Self {
+        Self {
+            num: self.den.clone(),
+            den: self.num.clone(),
+        }
+    }
+}
+
+impl Simplify for NestedFraction {
+    fn simplify(self) -> Notation {
+        self.simplify_with(Notation::simplify)
+    }
+}
+
+impl NestedFraction {
+    /// Simplifies each part with `simplify`, then divides them.
+    pub(crate) fn simplify_with(self, simplify: impl Fn(Notation) -> Notation) -> Notation {
+        let simplified = |part: Arc<Notation>| simplify(Arc::unwrap_or_clone(part));
+        simplified(self.num) / simplified(self.den)
+    }
+}
+
+impl std::fmt::Display for NestedFraction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.format_with(&FormatOptions::default()).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::{
+        atom::Atom,
+        expr::{fraction::Fraction, radical::Radical, radical_fraction::RadicalFraction},
+    };
+
+    #[test]
+    fn test_invert_and_multiply() {
+        let half = Fraction::new(1, 2);
+        let three_quarters = Fraction::new(3, 4);
+        assert_eq!(
+            NestedFraction::new(half.clone(), three_quarters.clone()).simplify(),
+            Fraction::new(2, 3)
+        );
+        assert_eq!(
+            NestedFraction::new(half.clone(), half.clone()).simplify(),
+            1
+        );
+        assert_eq!(NestedFraction::new(3, three_quarters).simplify(), 4);
+        assert_eq!(
+            NestedFraction::new(half, 5).simplify(),
+            Fraction::new(1, 10)
+        );
+    }
+
+    #[test]
+    fn test_radical_denominators() {
+        assert_eq!(
+            NestedFraction::new(1, Radical::new(2)).simplify(),
+            RadicalFraction::new(1, 2, 2)
+        );
+        assert_eq!(
+            NestedFraction::new(1, RadicalFraction::new(1, 2, 2)).simplify(),
+            Radical::new(2)
+        );
+        assert_eq!(
+            NestedFraction::new(Radical::new(6), Radical::new(3)).simplify(),
+            Radical::new(2)
+        );
+        assert_eq!(
+            NestedFraction::new(1, Radical::new(2) + Radical::from(1))
+                .simplify()
+                .to_string(),
+            "-1+√2"
+        );
+    }
+
+    #[test]
+    fn test_nested_parts() {
+        let inner = NestedFraction::new(Fraction::new(1, 2), Fraction::new(3, 4));
+        assert_eq!(
+            NestedFraction::new(inner.clone(), inner.clone()).simplify(),
+            1
+        );
+        assert_eq!(
+            NestedFraction::new(1, inner.clone()).simplify(),
+            Fraction::new(3, 2)
+        );
+        assert_eq!(inner.recip().simplify(), Fraction::new(3, 2));
+    }
+
+    #[test]
+    fn test_stays_nested() {
+        let nested = NestedFraction::new(Atom::Pi, Radical::new(2)).simplify();
+        assert!(nested
+            .clone()
+            .expr()
+            .is_some_and(|x| x.is_nested_fraction()));
+        assert_eq!(nested.to_string(), "π/√2");
+    }
+
+    #[test]
+    fn test_special_values() {
+        let over_zero = NestedFraction::new(Fraction::new(1, 2), 0).simplify();
+        assert!(over_zero.atom().is_some_and(|x| x.is_undefined()));
+        let over_huge = NestedFraction::new(Fraction::new(1, 2), Atom::Huge).simplify();
+        assert!(over_huge.atom().is_some_and(|x| x.is_positive_epsilon()));
+    }
+}
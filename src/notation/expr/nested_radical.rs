@@ -0,0 +1,254 @@
+//! Roots of expressions.
+
+use crate::notation::{
+    atom::Atom,
+    expr::{complex::Complex, Simplify},
+    format::{FormatOptions, FormatWith},
+    ops::{gcd, radical_of, ratio, rational, saturate, terms_of},
+    Notation,
+};
+use std::sync::Arc;
+
+/// The square root of any notation, such as √(2+√3), times an integer coefficient.
+///
+/// A [`Radical`][super::radical::Radical] only holds an integer radicand, so this is how the root of a
+/// compound value is written down.
+///
+/// Simplifying simplifies the radicand and takes its root exactly when it can:
+/// the roots of ratios become radicals, and roots of the form √(a+2√b) are denested into √m+√n,
+/// where m+n = a and mn = b.
+/// Any other root stays nested.
+///
+/// ```
+/// # use algebra::notation::{Notation, expr::{nested_radical::NestedRadical, radical::Radical, simplify::Simplify}};
+/// // √(3+2√2) = √2+1
+/// let root = NestedRadical::new(Radical::from(3) + Radical { coef: 2, rad: 2 });
+/// assert_eq!(root.to_string(), "√(3+2√2)");
+/// assert_eq!(root.simplify().to_string(), "√2+1");
+///
+/// // √(2+√2) can't be denested
+/// let root = NestedRadical::new(Radical::from(2) + Radical::new(2));
+/// assert_eq!(root.clone().simplify(), root);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, PartialEq, Clone)]
+pub struct NestedRadical {
+    /// The coefficient.
+    ///
+    /// The number the root is being multiplied by.
+    pub coef: i32,
+
+    /// The radicand.
+    ///
+    /// The notation being rooted.
+    pub rad: Arc<Notation>,
+}
+
+impl NestedRadical {
+    /// Constructs the root `√rad`, with a coefficient of 1.
+    pub fn new(rad: impl Into<Notation>) -> Self {
+        Self {
+            coef: 1,
+            rad: Arc::new(rad.into()),
+        }
+    }
+
+    /// Returns true if the root represents a real value less than zero, false otherwise.
+    pub fn is_negative(&self) -> bool {
+        self.coef < 0 && self.rad.eval_f64().is_some_and(|rad| rad > 0.0)
+    }
+}
+
+impl std::ops::Mul<i32> for NestedRadical {
+    type Output = Notation;
+
+    /// Multiplies the coefficient, saturating if it overflows.
+    fn mul(self, rhs: i32) -> Self::Output {
+        match self.coef.checked_mul(rhs) {
+            Some(coef) => Notation::from(Self { coef, ..self }),
+            None => saturate(true, (self.coef < 0) != (rhs < 0)),
+        }
+    }
+}
+
+/// Denests `√(A + B√d)` into `√m ± √n`, with `A > 0`, where `m = (A + S)/2`, `n = (A - S)/2`, and `S = √(A² - B²d)`.
+///
+/// Returns [`None`] unless the radicand is a ratio plus a real radical and `A² - B²d` is the square of a ratio.
+fn denest(rad: &Notation) -> Option<Notation> {
+    let [lhs, rhs] = <[Notation; 2]>::try_from(terms_of(rad)).ok()?;
+    let ((a, a_den), (c, d, e)) = match (ratio(&lhs), ratio(&rhs)) {
+        (Some(a), None) => (a, radical_of(&rhs)?),
+        (None, Some(a)) => (a, radical_of(&lhs)?),
+        _ => return None,
+    };
+    // A = a/a_den and B√d = c√d/e, with both denominators positive
+    let (a, a_den) = (
+        a as i128 * a_den.signum() as i128,
+        a_den.unsigned_abs() as i128,
+    );
+    let (c, d, e) = (
+        c as i128 * e.signum() as i128,
+        d as i128,
+        e.unsigned_abs() as i128,
+    );
+    if a <= 0 || d <= 1 {
+        return None;
+    }
+
+    // A² - B²d = (a²e² - c²d·a_den²) / (a_den·e)²
+    let square = (a * e).checked_mul(a * e)?.checked_sub(
+        c.checked_mul(c)?
+            .checked_mul(d)?
+            .checked_mul(a_den * a_den)?,
+    )?;
+    let root = match square {
+        ..=0 => return None,
+        square => square.isqrt(),
+    };
+    if root * root != square {
+        return None;
+    }
+
+    // m and n share the denominator 2·a_den·e
+    let den = 2 * a_den * e;
+    let part = |num: i128| {
+        let g = gcd(num, den).max(1);
+        let value = rational(num / g, den / g);
+        ratio(&value).map(|_| value.sqrt())
+    };
+    let (m, n) = (part(a * e + root)?, part(a * e - root)?);
+    Some(match c < 0 {
+        true => m - n,
+        false => m + n,
+    })
+}
+
+impl Simplify for NestedRadical {
+    fn simplify(self) -> Notation {
+        self.simplify_with(Notation::simplify)
+    }
+}
+
+impl NestedRadical {
+    /// Simplifies the radicand with `simplify`, then takes its root if it can.
+    pub(crate) fn simplify_with(self, simplify: impl Fn(Notation) -> Notation) -> Notation {
+        use Atom::{Epsilon, Huge, NegativeEpsilon, NegativeHuge, Undefined, Unknown};
+        if self.coef == 0 {
+            return Notation::from(0);
+        }
+        let coef = Notation::from(self.coef);
+        let rad = simplify(Arc::unwrap_or_clone(self.rad));
+        if ratio(&rad).is_some() {
+            return coef * rad.sqrt();
+        }
+        match rad {
+            rad @ Notation::Atom(
+                Atom::Complex
+                | Undefined
+                | Unknown
+                | Huge
+                | NegativeHuge
+                | Epsilon
+                | NegativeEpsilon,
+            ) => coef * rad.sqrt(),
+            rad => match denest(&rad) {
+                Some(root) => coef * root,
+                // √-x = 𝑖√x
+                None if rad.is_negative() => {
+                    let root = Self {
+                        coef: self.coef,
+                        rad: Arc::new(-rad),
+                    };
+                    Complex::new(0, root).simplify()
+                }
+                None => Notation::from(Self {
+                    coef: self.coef,
+                    rad: Arc::new(rad),
+                }),
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for NestedRadical {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.format_with(&FormatOptions::default()).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::expr::{fraction::Fraction, radical::Radical};
+
+    fn root(a: i32, coef: i32, rad: i32) -> NestedRadical {
+        NestedRadical::new(Radical::from(a) + Radical { coef, rad })
+    }
+
+    #[test]
+    fn test_denest() {
+        // √(a+2√b) = √m+√n, where m+n = a and mn = b
+        assert_eq!(root(3, 2, 2).simplify().to_string(), "√2+1");
+        assert_eq!(root(5, 2, 6).simplify().to_string(), "√3+√2");
+        assert_eq!(root(5, -2, 6).simplify().to_string(), "√3-√2");
+        assert_eq!(root(7, 4, 3).simplify().to_string(), "2+√3");
+        // √(2+√3) = (√2+√6)/2
+        assert_eq!(root(2, 1, 3).simplify().to_string(), "√6/2+√2/2");
+        assert_eq!(
+            (root(2, 1, 3).simplify() * root(2, 1, 3).simplify()).to_string(),
+            "2+√3"
+        );
+    }
+
+    #[test]
+    fn test_stays_nested() {
+        for value in [root(2, 1, 2), root(1, 1, 2), root(3, 1, 3)] {
+            assert_eq!(value.clone().simplify(), value);
+        }
+        assert_eq!(root(2, 1, 2).to_string(), "√(2+√2)");
+        assert_eq!(
+            (NestedRadical {
+                coef: -3,
+                ..root(2, 1, 2)
+            })
+            .to_string(),
+            "-3√(2+√2)"
+        );
+    }
+
+    #[test]
+    fn test_rational_radicand() {
+        assert_eq!(
+            NestedRadical::new(Fraction::new(9, 4)).simplify(),
+            Fraction::new(3, 2)
+        );
+        assert_eq!(
+            NestedRadical {
+                coef: 2,
+                rad: Arc::new(Notation::from(12))
+            }
+            .simplify(),
+            Radical { coef: 4, rad: 3 }
+        );
+        assert_eq!(
+            NestedRadical::new(Radical::new(4) + Radical::new(9)).simplify(),
+            Radical::new(5)
+        );
+    }
+
+    #[test]
+    fn test_negative_radicand() {
+        // √(1-√2) = 𝑖√(-1+√2)
+        let root = root(1, -1, 2).simplify();
+        assert!(root.clone().expr().is_some_and(|x| x.is_complex()));
+        assert_eq!(root.to_string(), "𝑖√(-1+√2)");
+    }
+
+    #[test]
+    fn test_special_radicands() {
+        let undefined = NestedRadical::new(Atom::Undefined).simplify();
+        assert!(undefined.atom().is_some_and(|x| x.is_undefined()));
+        let huge = NestedRadical::new(Atom::Huge).simplify();
+        assert!(huge.atom().is_some_and(|x| x.is_positive_huge()));
+    }
+}
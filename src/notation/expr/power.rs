@@ -0,0 +1,111 @@
+//! Powers which can't be written any other way.
+
+use crate::notation::{
+    expr::Simplify,
+    format::{FormatOptions, FormatWith},
+    ops::pow::pow_simplified,
+    Notation,
+};
+use std::sync::Arc;
+
+/// A base raised to an exponent, where the result can't be written any other way, such as 2^π or π².
+///
+/// Simplifying simplifies both parts and then raises the base to the exponent,
+/// so a power such as 4^(1/2) becomes 2.
+///
+/// ```
+/// # use algebra::notation::{Notation, atom::Atom, expr::{fraction::Fraction, power::Power, simplify::Simplify}};
+/// let power = Notation::from(2).pow(Notation::from(Atom::Pi));
+/// assert_eq!(power, Power::new(2, Atom::Pi));
+/// assert_eq!(power.to_string(), "2^π");
+///
+/// assert_eq!(Power::new(Atom::Pi, 2).to_string(), "π²");
+/// assert_eq!(Power::new(4, Fraction::new(1, 2)).simplify(), 2);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, PartialEq, Clone)]
+pub struct Power {
+    /// The base.
+    ///
+    /// The value being multiplied by itself.
+    pub base: Arc<Notation>,
+
+    /// The exponent.
+    ///
+    /// How many times the base is multiplied by itself.
+    pub exp: Arc<Notation>,
+}
+
+impl Power {
+    /// Constructs the power `base^exp`.
+    pub fn new(base: impl Into<Notation>, exp: impl Into<Notation>) -> Self {
+        Self {
+            base: Arc::new(base.into()),
+            exp: Arc::new(exp.into()),
+        }
+    }
+}
+
+impl Simplify for Power {
+    fn simplify(self) -> Notation {
+        self.simplify_with(Notation::simplify)
+    }
+}
+
+impl Power {
+    /// Simplifies each part with `simplify`, then raises the base to the exponent.
+    pub(crate) fn simplify_with(self, simplify: impl Fn(Notation) -> Notation) -> Notation {
+        let simplified = |part: Arc<Notation>| simplify(Arc::unwrap_or_clone(part));
+        pow_simplified(
+            simplified(self.base),
+            simplified(self.exp),
+            Notation::from(1),
+        )
+    }
+}
+
+impl std::fmt::Display for Power {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.format_with(&FormatOptions::default()).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::{
+        atom::Atom,
+        expr::{fraction::Fraction, radical::Radical},
+        format::FormatOptions,
+    };
+
+    #[test]
+    fn test_simplify() {
+        assert_eq!(Power::new(2, 10).simplify(), 1024);
+        assert_eq!(
+            Power::new(Fraction::new(8, 2), Fraction::new(2, 4)).simplify(),
+            2
+        );
+        assert_eq!(
+            Power::new(Atom::Pi, Fraction::new(4, 2)).simplify(),
+            Power::new(Atom::Pi, 2)
+        );
+        assert_eq!(Power::new(Radical::new(2), 2).simplify(), 2);
+    }
+
+    #[test]
+    fn test_display() {
+        let ascii = FormatOptions::ascii();
+        let value = Power::new(Atom::Pi, -2);
+        assert_eq!(value.to_string(), "π⁻²");
+        assert_eq!(value.format_with(&ascii), "pi^-2");
+
+        let value = Power::new(Radical::new(2), Radical::new(2));
+        assert_eq!(value.to_string(), "(√2)^(√2)");
+        assert_eq!(value.format_with(&ascii), "(sqrt(2))^(sqrt(2))");
+
+        let value = Power::new(-2, Fraction::new(1, 3));
+        assert_eq!(value.to_string(), "(-2)^(1/3)");
+        assert_eq!(Power::new(2, Atom::E).to_string(), "2^𝑒");
+    }
+}
@@ -0,0 +1,240 @@
+//! Products of factors which can't be combined into one.
+
+use crate::notation::{
+    atom::Atom,
+    expr::{Expr, Simplify},
+    format::{FormatOptions, FormatWith},
+    ops::mul::combine,
+    Notation,
+};
+use std::sync::Arc;
+
+/// Factors multiplied together which can't be combined into a single value, such as π·𝑒.
+///
+/// Simplifying multiplies together the factors which can be combined, such as 2 and π into 2π,
+/// and gives just the remaining factor if the others are all 1.
+///
+/// ```
+/// # use algebra::notation::{Notation, atom::Atom, expr::{product::Product, simplify::Simplify}};
+/// let product = Product::new([Notation::from(2), Notation::from(Atom::Pi), Notation::from(Atom::E)]);
+/// assert_eq!(product.to_string(), "2·π·𝑒");
+/// assert_eq!(product.simplified().to_string(), "2π·𝑒");
+///
+/// let product = Notation::from(Atom::Pi) * Notation::from(Atom::E);
+/// assert_eq!(product, Product::new([Atom::Pi.into(), Atom::E.into()]));
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, PartialEq, Clone)]
+pub struct Product {
+    /// The factors being multiplied, in the order they are written.
+    ///
+    /// Shared between clones, like the terms of a [`Sum`][crate::notation::expr::sum::Sum].
+    pub factors: Arc<[Notation]>,
+}
+
+impl Product {
+    /// Constructs the product of the factors, without combining any of them.
+    pub fn new(factors: impl IntoIterator<Item = Notation>) -> Self {
+        Self {
+            factors: factors.into_iter().collect(),
+        }
+    }
+}
+
+/// Multiplies a simplified factor into simplified factors which can't be combined with each other,
+/// combining it with any factor it can be.
+///
+/// A combined factor takes the place of the first factor it was combined with.
+fn push_factor(factors: &mut Vec<Notation>, mut factor: Notation) {
+    let mut position = factors.len();
+    let mut i = 0;
+    while i < factors.len() {
+        match combine(factors[i].clone(), factor) {
+            Ok(product) => {
+                factors.remove(i);
+                position = position.min(i);
+                factor = product;
+                // The combined factor may now combine with factors which have already been passed
+                i = 0;
+            }
+            Err((_, unlike)) => {
+                factor = unlike;
+                i += 1;
+            }
+        }
+    }
+    factors.insert(position.min(factors.len()), factor);
+}
+
+impl Simplify for Product {
+    fn simplify(self) -> Notation {
+        self.simplify_with(Notation::simplify)
+    }
+}
+
+impl Product {
+    /// Simplifies each factor with `simplify`, then combines the factors which can be.
+    pub(crate) fn simplify_with(self, simplify: impl Fn(Notation) -> Notation) -> Notation {
+        // Factors are moved out rather than cloned when no other clone shares them
+        let mut shared = self.factors;
+        let owned = match Arc::get_mut(&mut shared) {
+            Some(factors) => factors.iter_mut().map(std::mem::take).collect(),
+            None => shared.to_vec(),
+        };
+        let mut factors = Vec::with_capacity(owned.len());
+        for factor in owned {
+            match simplify(factor) {
+                Notation::Expr(Expr::Product(product)) => {
+                    factors.extend(product.factors.iter().cloned())
+                }
+                factor => factors.push(factor),
+            }
+        }
+        // Special atoms absorb factors which the tables can't settle, such as trigonometric functions,
+        // with the same precedence as in the tables
+        let any = |is: fn(&Atom) -> bool| {
+            factors
+                .iter()
+                .any(|factor| matches!(factor, Notation::Atom(atom) if is(atom)))
+        };
+        if any(Atom::is_undefined) {
+            return Notation::from(Atom::Undefined);
+        }
+        if factors.iter().any(Notation::is_zero) {
+            return Notation::from(0);
+        }
+        if any(Atom::is_complex) {
+            return Notation::from(Atom::Complex);
+        }
+        if any(Atom::is_unknown) {
+            return Notation::from(Atom::Unknown);
+        }
+        let mut combined = Vec::with_capacity(factors.len());
+        for factor in factors {
+            push_factor(&mut combined, factor);
+        }
+        // Combined factors are already simplified, so they aren't simplified again to check for 1,
+        // which would multiply the base of a power such as π² by itself again
+        combined.retain(|factor| !matches!(factor, Notation::Atom(atom) if *atom == 1));
+        match combined.len() {
+            0 => Notation::from(1),
+            1 => combined.remove(0),
+            _ => Notation::from(Self::new(combined)),
+        }
+    }
+}
+
+impl std::ops::Neg for Product {
+    type Output = Self;
+
+    /// Negates the first factor, or gives a factor of -1 if there are none.
+    fn neg(self) -> Self::Output {
+        let mut factors = self.factors.iter().cloned();
+        let first = factors.next().map_or(Notation::from(-1), |factor| -factor);
+        Self::new(std::iter::once(first).chain(factors))
+    }
+}
+
+impl std::fmt::Display for Product {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.format_with(&FormatOptions::default()).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::expr::{fraction::Fraction, multiple::Multiple, radical::Radical};
+
+    fn product<const N: usize>(factors: [Notation; N]) -> Product {
+        Product::new(factors)
+    }
+
+    #[test]
+    fn test_simplify_combines_factors() {
+        assert_eq!(
+            product([2.into(), Atom::Pi.into(), Fraction::new(1, 4).into()]).simplify(),
+            Multiple::new(1, 2, Atom::Pi)
+        );
+        assert_eq!(
+            product([
+                Radical::new(2).into(),
+                Atom::E.into(),
+                Radical::new(2).into()
+            ])
+            .simplify(),
+            Multiple::new(2, 1, Atom::E)
+        );
+        assert_eq!(
+            product([Atom::Pi.into(), 3.into(), Atom::E.into()]).simplify(),
+            product([Multiple::new(3, 1, Atom::Pi).into(), Atom::E.into()])
+        );
+        assert_eq!(product([]).simplify(), 1);
+        assert_eq!(product([1.into(), Atom::E.into()]).simplify(), Atom::E);
+    }
+
+    #[test]
+    fn test_simplify_flattens() {
+        let inner = Notation::from(product([Atom::Pi.into(), Atom::E.into()]));
+        assert_eq!(
+            product([inner, 2.into()]).simplify(),
+            product([Multiple::new(2, 1, Atom::Pi).into(), Atom::E.into()])
+        );
+    }
+
+    #[test]
+    fn test_special_factors() {
+        let pi_e = || [Notation::from(Atom::Pi), Notation::from(Atom::E)];
+        let with = |factor: Notation| {
+            let [pi, e] = pi_e();
+            product([pi, factor, e]).simplify()
+        };
+        assert_eq!(with(0.into()), 0);
+        assert!(with(Atom::Undefined.into())
+            .atom()
+            .is_some_and(|x| x.is_undefined()));
+        assert!(with(Atom::Unknown.into())
+            .atom()
+            .is_some_and(|x| x.is_unknown()));
+        assert!(with(Atom::Complex.into())
+            .atom()
+            .is_some_and(|x| x.is_complex()));
+        // Undefined takes precedence over 0
+        assert!(product([0.into(), Atom::Undefined.into()])
+            .simplify()
+            .atom()
+            .is_some_and(|x| x.is_undefined()));
+    }
+
+    #[test]
+    fn test_neg() {
+        let value = -product([Atom::Pi.into(), Atom::E.into()]);
+        assert_eq!(value.to_string(), "(-π)·𝑒");
+        assert_eq!(
+            (Notation::from(value) + Notation::from(product([Atom::Pi.into(), Atom::E.into()])))
+                .simplify(),
+            0
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let value = product([Atom::Pi.into(), Radical::new(2).into(), (-2).into()]);
+        assert_eq!(value.to_string(), "π·√2·(-2)");
+        assert_eq!(
+            value.format_with(&FormatOptions::ascii()),
+            "pi*sqrt(2)*(-2)"
+        );
+        assert_eq!(product([]).to_string(), "1");
+
+        // Written without parentheses, this would be read as the radical 2√3
+        let value = product([2.into(), Radical::new(3).into()]);
+        assert_eq!(value.to_string(), "2·√3");
+        assert_eq!(value.format_with(&FormatOptions::ascii()), "(2)*sqrt(3)");
+        let value = product([
+            Multiple::new(1, 2, Atom::Pi).into(),
+            Multiple::new(2, 1, Atom::E).into(),
+        ]);
+        assert_eq!(value.to_string(), "(π/2)·2𝑒");
+    }
+}
@@ -1,8 +1,9 @@
 //! Roots of numbers.
 
-use crate::{
-    factor::{Factor, Factoring},
-    notation::{expr::Simplify, Atom, Notation},
+use crate::notation::{
+    expr::{complex::Complex, Simplify},
+    format::{FormatOptions, FormatWith},
+    Atom, Notation,
 };
 
 /// The root of some number.
@@ -17,7 +18,7 @@ use crate::{
 ///
 /// [Radical::new]\(𝑚) is equal to 1√𝑚, or simply √𝑚.
 ///
-/// [Radical] { coef: 𝑛, rad: 𝑚 } is equal to 𝑛√𝑚.
+/// [Radical::from_ints]\(𝑛, 𝑚) and [Radical] { coef: 𝑛, rad: 𝑚 } are equal to 𝑛√𝑚.
 ///
 /// ```
 /// # use algebra::notation::expr::{radical::Radical, simplify::Simplify};
@@ -32,7 +33,8 @@ use crate::{
 /// let from_explicit = Radical { coef: n, rad: m };
 /// assert_eq!(from_explicit.simplified(), from_explicit);
 /// ```
-#[derive(Debug, PartialEq, Eq, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, Clone)]
 pub struct Radical {
     /// The coefficient.
     ///
@@ -49,7 +51,7 @@ impl From<i32> for Radical {
     /// Convert an integer value into a radical with radicand of 1.
     ///
     /// Use [`new`][Radical::new()] if you need to set the radicand and have a coefficient of 1.\
-    /// Use `Radical { coef, rad }` if you need to set both the coefficient and radicand.
+    /// Use [`from_ints`][Radical::from_ints()] if you need to set both the coefficient and radicand.
     fn from(coef: i32) -> Self {
         Self { coef, rad: 1 }
     }
@@ -59,19 +61,94 @@ impl Radical {
     /// Construct a new radical from its radicand. Its coefficient will be 1.
     ///
     /// Use [`from`][Radical::from()] if you are creating a radical equivalent to an integer value.\
-    /// Use `Radical { coef, rad }` if you need to set both the coefficient and radicand.
+    /// Use [`from_ints`][Radical::from_ints()] if you need to set both the coefficient and radicand.
     pub fn new(rad: i32) -> Self {
         Self { coef: 1, rad }
     }
 
+    /// Construct a new radical from its coefficient and radicand, as `coef√rad`.
+    ///
+    /// Use [`new`][Radical::new()] if the coefficient is 1, or [`from`][Radical::from()] if the radicand is 1.
+    ///
+    /// ```
+    /// # use algebra::notation::expr::radical::Radical;
+    /// assert_eq!(Radical::from_ints(2, 3), Radical { coef: 2, rad: 3 });
+    /// assert_eq!(Radical::from_ints(1, 5), Radical::new(5));
+    /// assert_eq!(Radical::from_ints(4, 1), Radical::from(4));
+    /// ```
+    pub fn from_ints(coef: i32, rad: i32) -> Self {
+        Self { coef, rad }
+    }
+
     /// Returns the square of the radical.
     ///
     /// Because the radical is already a square root, squaring it turns it into a whole number.
     pub fn squared(&self) -> i32 {
         self.coef * self.coef * self.rad
     }
+
+    /// Returns the same radical with its radicand square-free and any sign on its coefficient.
+    ///
+    /// Square factors are moved out from under the root, as in √12 = 2√3.
+    /// A radicand's sign stays under the root, since a negative radicand isn't a negative value.
+    /// A radical equal to 0 becomes 0√1.
+    ///
+    /// Unlike [`simplify`][Simplify::simplify], the result is always a radical.
+    /// If moving a factor out would overflow the coefficient, returns the radical unchanged.
+    ///
+    /// ```
+    /// # use algebra::notation::expr::radical::Radical;
+    /// assert_eq!(Radical { coef: -1, rad: 12 }.normalized(), Radical { coef: -2, rad: 3 });
+    /// assert_eq!(Radical { coef: 3, rad: -8 }.normalized(), Radical { coef: 6, rad: -2 });
+    /// assert_eq!(Radical { coef: 0, rad: 5 }.normalized(), Radical::from(0));
+    /// ```
+    pub fn normalized(&self) -> Self {
+        if self.coef == 0 || self.rad == 0 {
+            return Self::from(0);
+        }
+
+        // Move each square factor p² out from under the root as p
+        let mut rest = self.rad.unsigned_abs();
+        let mut root = 1_u32;
+        let mut p = 2;
+        while p * p <= rest {
+            while rest.is_multiple_of(p * p) {
+                rest /= p * p;
+                root *= p;
+            }
+            p += 1;
+        }
+
+        let coef = i32::try_from(root)
+            .ok()
+            .and_then(|root| self.coef.checked_mul(root));
+        match coef {
+            Some(coef) => Self {
+                coef,
+                rad: self.rad.signum() * rest as i32,
+            },
+            None => self.clone(),
+        }
+    }
+
+    /// Returns true if the radical represents a real value less than zero, false otherwise.
+    ///
+    /// A negative radicand is imaginary, not negative.
+    pub fn is_negative(&self) -> bool {
+        self.coef < 0 && self.rad > 0
+    }
+}
+
+impl PartialEq for Radical {
+    /// Radicals are equal when their [normalized][Radical::normalized] forms are, so that √8 = 2√2.
+    fn eq(&self, other: &Self) -> bool {
+        let (lhs, rhs) = (self.normalized(), other.normalized());
+        lhs.coef == rhs.coef && lhs.rad == rhs.rad
+    }
 }
 
+impl Eq for Radical {}
+
 impl std::ops::Mul<i32> for Radical {
     type Output = Self;
 
@@ -83,73 +160,64 @@ impl std::ops::Mul<i32> for Radical {
     }
 }
 
+impl std::ops::Add for Radical {
+    type Output = Notation;
+
+    /// Adds two radicals, combining them if their radicands are alike once [normalized][Radical::normalized],
+    /// such as √12 + √3 = 3√3.
+    ///
+    /// Otherwise gives a [`Sum`][crate::notation::expr::sum::Sum], such as √2 + √3.
+    fn add(self, rhs: Self) -> Self::Output {
+        Notation::from(self) + Notation::from(rhs)
+    }
+}
+
+impl std::ops::Sub for Radical {
+    type Output = Notation;
+
+    /// Subtracts two radicals, combining them if their radicands are alike once [normalized][Radical::normalized].
+    fn sub(self, rhs: Self) -> Self::Output {
+        Notation::from(self) - Notation::from(rhs)
+    }
+}
+
 impl std::fmt::Display for Radical {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match (self.coef, self.rad) {
-            (c @ (..=0 | 2..), r @ (..=0 | 2..)) => format!("{c}√{r}").fmt(f),
-            (1, r @ (..=0 | 2..)) => format!("√{r}").fmt(f),
-            (c, 1) => c.fmt(f),
-        }
+        self.format_with(&FormatOptions::default()).fmt(f)
     }
 }
 
 /// If the square root of n can be expressed as an integer, returns that integer. Otherwise returns [`None`].
 pub fn sqrt_i(n: i32) -> Option<i32> {
-    use std::cmp::Ordering::*;
     match n {
         ..=-1 => None,
-        0..=1 => Some(n),
-        2.. => {
-            let mut root = 2;
-            loop {
-                match (root * root).cmp(&n) {
-                    Less => root += 1,
-                    Equal => break Some(root),
-                    Greater => break None,
-                }
-            }
+        _ => {
+            let root = n.isqrt();
+            (root * root == n).then_some(root)
         }
     }
 }
 
 impl Simplify for Radical {
     fn simplify(self) -> Notation {
+        if self.coef == 0 {
+            return Notation::from(0);
+        }
         match self.rad {
-            ..=-1 => Notation::from(Atom::Complex),
-            0 => Notation::from(0),
-            1 => Notation::from(self.coef),
-            2.. => {
-                if let Some(root) = sqrt_i(self.rad) {
-                    // Simple
-
-                    Notation::from(self.coef * root)
-                } else {
-                    // Perfect squares
-
-                    let n = self.squared();
-
-                    let mut gps_fac = 1; // Greatest perfect square factor
-                    let mut gps_mul = n; // Factor associated with gps_fac
-
-                    for Factor { common, associated } in n.factors() {
-                        let permutations: [(i32, i32); 2] =
-                            [(common, associated), (associated, common)];
-
-                        for (a, b) in permutations {
-                            if let Some(a_root) = sqrt_i(a) {
-                                if a_root > gps_fac {
-                                    (gps_fac, gps_mul) = (a_root, b);
-                                }
-                            }
-                        }
-                    }
-
-                    Notation::from(Radical {
-                        coef: gps_fac,
-                        rad: gps_mul,
-                    })
+            // √-r = 𝑖√r
+            ..=-1 => {
+                let Radical { coef, rad } = self.normalized();
+                match rad.checked_neg() {
+                    Some(rad) => Complex::new(0, Radical { coef, rad }).simplify(),
+                    None => Notation::from(Atom::Complex),
                 }
             }
+            0 => Notation::from(0),
+            1 => Notation::from(self.coef),
+            2.. => match self.normalized() {
+                Radical { coef, rad: 1 } => Notation::from(coef),
+                normalized => Notation::from(normalized),
+            },
         }
     }
 }
@@ -177,5 +245,110 @@ mod tests {
 
         // Simplifies to a radical
         assert_eq!(Radical::new(8).simplify(), Radical { coef: 2, rad: 2 });
+
+        // Keeps its sign
+        assert_eq!(
+            Radical { coef: -1, rad: 8 }.simplify(),
+            Radical { coef: -2, rad: 2 }
+        );
+
+        // Doesn't overflow when squaring the coefficient
+        assert_eq!(
+            Radical {
+                coef: 100_000,
+                rad: 18
+            }
+            .simplify(),
+            Radical {
+                coef: 300_000,
+                rad: 2
+            }
+        );
+    }
+
+    #[test]
+    fn test_simplify_imaginary() {
+        assert_eq!(Radical::new(-4).simplify(), Complex::new(0, 2));
+        assert_eq!(Radical::new(-9).simplify(), Complex::new(0, 3));
+        assert_ne!(Radical::new(-4).simplify(), Radical::new(-9).simplify());
+        assert_eq!(
+            Radical { coef: -1, rad: -12 }.simplify(),
+            Complex::new(0, Radical { coef: -2, rad: 3 })
+        );
+        assert_eq!(
+            Radical::new(i32::MIN).simplify(),
+            Complex::new(
+                0,
+                Radical {
+                    coef: 32768,
+                    rad: 2
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn test_normalized() {
+        for coef in -6..=6 {
+            for rad in -50..=50 {
+                let radical = Radical { coef, rad };
+                let normalized = radical.normalized();
+                let squared = |r: &Radical| r.coef as i64 * r.coef as i64 * r.rad as i64;
+                assert_eq!(squared(&normalized), squared(&radical), "{radical:?}");
+                assert!(
+                    (2..=7).all(|p| normalized.rad % (p * p) != 0),
+                    "{normalized:?} is not square-free"
+                );
+            }
+        }
+        let huge = Radical {
+            coef: i32::MAX,
+            rad: 4,
+        };
+        assert_eq!(huge.normalized().rad, 4);
+        assert_eq!(
+            Radical::new(i32::MIN).normalized(),
+            Radical {
+                coef: 32768,
+                rad: -2
+            }
+        );
+    }
+
+    #[test]
+    fn test_eq() {
+        assert_eq!(Radical::new(8), Radical { coef: 2, rad: 2 });
+        assert_eq!(Radical { coef: 0, rad: 3 }, Radical::new(0));
+        assert_eq!(Radical::new(-4), Radical { coef: 2, rad: -1 });
+        assert_ne!(Radical::new(-4), Radical::from(2));
+        assert_ne!(Radical { coef: -1, rad: 3 }, Radical::new(3));
+    }
+
+    #[test]
+    fn test_add() {
+        assert_eq!(
+            Radical { coef: 2, rad: 3 } + Radical { coef: 5, rad: 3 },
+            Radical { coef: 7, rad: 3 }
+        );
+        assert_eq!(
+            Radical::new(8) + Radical::new(2),
+            Radical { coef: 3, rad: 2 }
+        );
+        assert_eq!(Radical::new(4) + Radical::from(1), 3);
+        assert_eq!(Radical::new(12) - Radical::new(3), Radical::new(3));
+        assert_eq!(Radical::new(5) - Radical::new(5), 0);
+        let sum = Radical::new(2) + Radical::new(3);
+        assert!(sum.clone().expr().is_some_and(|x| x.is_sum()));
+        assert_eq!(sum.to_string(), "√2+√3");
+        assert_eq!((Radical::new(2) - Radical::new(3)).to_string(), "√2-√3");
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Radical { coef: -1, rad: 3 }.to_string(), "-√3");
+        assert_eq!(Radical { coef: -1, rad: -3 }.to_string(), "-√-3");
+        assert_eq!(Radical { coef: -3, rad: 1 }.to_string(), "-3");
+        // Written as it is, to be simplified later
+        assert_eq!(Radical::new(12).to_string(), "√12");
     }
 }
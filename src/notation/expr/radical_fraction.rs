@@ -1 +1,175 @@
+//! Roots of numbers divided by integers.
 
+use crate::notation::{
+    atom::Atom,
+    expr::{fraction::Fraction, radical::Radical, Simplify},
+    format::{FormatOptions, FormatWith},
+    ops::gcd,
+    Notation,
+};
+
+/// A radical divided by an integer, such as √3/2.
+///
+/// Produced when simplification leaves a root in the numerator of a fraction.
+///
+/// ```
+/// # use algebra::notation::expr::{radical::Radical, radical_fraction::RadicalFraction, simplify::Simplify};
+/// let frac = RadicalFraction { num: Radical { coef: 2, rad: 12 }, den: 8 };
+/// assert_eq!(frac.simplified(), RadicalFraction { num: Radical::new(3), den: 2 });
+/// assert_eq!(frac.simplified().to_string(), "√3/2");
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RadicalFraction {
+    /// The numerator.
+    ///
+    /// The radical being divided.
+    pub num: Radical,
+
+    /// The denominator.
+    ///
+    /// The integer dividing the radical.
+    pub den: i32,
+}
+
+impl RadicalFraction {
+    /// Constructs the fraction `coef√rad / den`.
+    pub fn new(coef: i32, rad: i32, den: i32) -> Self {
+        Self {
+            num: Radical { coef, rad },
+            den,
+        }
+    }
+
+    /// Returns true if the fraction represents a real value less than zero, false otherwise.
+    pub fn is_negative(&self) -> bool {
+        self.num.rad > 0
+            && self.num.coef != 0
+            && self.den != 0
+            && (self.num.coef < 0) != (self.den < 0)
+    }
+}
+
+impl std::fmt::Display for RadicalFraction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.format_with(&FormatOptions::default()).fmt(f)
+    }
+}
+
+impl Simplify for RadicalFraction {
+    fn simplify(self) -> Notation {
+        let Self { num, den } = self;
+        if den == 0 {
+            return Notation::from(Atom::Undefined);
+        }
+        match num.simplify() {
+            Notation::Expr(super::Expr::Radical(Radical { coef, rad })) => {
+                // Widened, because the magnitude of i32::MIN doesn't fit in an i32
+                let (coef, den) = (coef as i128, den as i128);
+                // Transfer sign to the top
+                let sign = coef.signum() * den.signum();
+                let (coef_abs, den_abs) = (coef.abs(), den.abs());
+                let gcf = gcd(coef_abs, den_abs);
+                let (coef, den) = match (
+                    i32::try_from(sign * coef_abs / gcf),
+                    i32::try_from(den_abs / gcf),
+                ) {
+                    (Ok(coef), Ok(den)) => (coef, den),
+                    // A denominator of 2^31 only fits as i32::MIN, which carries the sign instead
+                    (Ok(coef), Err(_)) => (-coef, i32::MIN),
+                    // Only a numerator of 2^31 is too large, so the sign stays on the bottom
+                    (Err(_), Ok(den)) => (i32::MIN, -den),
+                    (Err(_), Err(_)) => unreachable!("2^31 and 2^31 share a factor"),
+                };
+                if gcf > 1 {
+                    // A smaller coefficient may now leave room to take a square out of the radicand
+                    RadicalFraction {
+                        num: Radical { coef, rad },
+                        den,
+                    }
+                    .simplify()
+                } else if den == 1 {
+                    Notation::from(Radical { coef, rad })
+                } else {
+                    Notation::from(RadicalFraction {
+                        num: Radical { coef, rad },
+                        den,
+                    })
+                }
+            }
+            // 𝑖√r / d
+            Notation::Expr(super::Expr::Complex(num)) => Notation::from(num) / Notation::from(den),
+            Notation::Atom(num) => Fraction {
+                num,
+                den: den.into(),
+            }
+            .simplify(),
+            other => other,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_simplify_reduces() {
+        assert_eq!(
+            RadicalFraction::new(3, 2, 6).simplify(),
+            RadicalFraction::new(1, 2, 2)
+        );
+        assert_eq!(
+            RadicalFraction::new(1, 8, 4).simplify(),
+            RadicalFraction::new(1, 2, 2)
+        );
+    }
+
+    #[test]
+    fn test_simplify_sign_to_top() {
+        assert_eq!(
+            RadicalFraction::new(1, 3, -2).simplify(),
+            RadicalFraction::new(-1, 3, 2)
+        );
+        assert_eq!(
+            RadicalFraction::new(-1, 3, -2).simplify(),
+            RadicalFraction::new(1, 3, 2)
+        );
+    }
+
+    #[test]
+    fn test_simplify_to_radical_or_integer() {
+        assert_eq!(
+            RadicalFraction::new(4, 3, 2).simplify(),
+            Radical { coef: 2, rad: 3 }
+        );
+        assert_eq!(RadicalFraction::new(1, 16, 2).simplify(), 2);
+        assert_eq!(
+            RadicalFraction::new(1, 4, 4).simplify(),
+            Fraction::new(1, 2)
+        );
+        assert!(RadicalFraction::new(1, 3, 0)
+            .simplify()
+            .atom()
+            .is_some_and(|x| x.is_undefined()));
+    }
+
+    #[test]
+    fn test_simplify_imaginary() {
+        use crate::notation::expr::complex::Complex;
+        assert_eq!(
+            RadicalFraction::new(1, -3, 2).simplify(),
+            Complex::new(0, RadicalFraction::new(1, 3, 2))
+        );
+        assert_eq!(
+            RadicalFraction::new(3, -4, 2).simplify(),
+            Complex::new(0, 3)
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(RadicalFraction::new(1, 3, 2).to_string(), "√3/2");
+        assert_eq!(RadicalFraction::new(-3, 2, 4).to_string(), "-3√2/4");
+    }
+}
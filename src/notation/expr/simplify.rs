@@ -1,8 +1,14 @@
 //! The trait giving expressions the ability to be simplified.
 
-use crate::Notation;
+use crate::{
+    notation::{canonical::Canonical, expr::Expr},
+    Notation,
+};
+use std::cell::Cell;
 
 /// An expression capable of being simplified.
+///
+/// Implemented for [`Notation`] and all of its parts, including [`Atom`][crate::notation::atom::Atom]s, which simplify to themselves.
 pub trait Simplify {
     /// Converts the expression to its simplest form.
     fn simplify(self) -> Notation;
@@ -14,4 +20,403 @@ pub trait Simplify {
     {
         self.clone().simplify()
     }
+
+    /// Simplifies the expression, then simplifies the result again until it stops changing.
+    ///
+    /// One simplification can leave behind notation which simplifies further,
+    /// such as when reducing a fraction leaves a coefficient small enough to take a square out of its radicand.
+    /// Stops after [`MAX_SIMPLIFY_PASSES`] in case simplifying never settles.
+    /// Use [`simplify_within`][Simplify::simplify_within] to also limit how deep into the notation it goes.
+    ///
+    /// ```
+    /// # use algebra::notation::expr::{radical_fraction::RadicalFraction, simplify::Simplify};
+    /// // 6√8/3 = 2√8 = 4√2
+    /// assert_eq!(RadicalFraction::new(6, 8, 3).simplify_full().to_string(), "4√2");
+    /// ```
+    fn simplify_full(self) -> Notation
+    where
+        Self: Sized,
+    {
+        let mut value = Canonical(self.simplify());
+        for _ in 1..MAX_SIMPLIFY_PASSES {
+            let next = Canonical(value.0.clone().simplify());
+            if next == value {
+                break;
+            }
+            value = next;
+        }
+        value.0
+    }
+
+    /// Simplifies the expression like [`simplify_full`][Simplify::simplify_full], but only as far as the budget allows.
+    ///
+    /// Parts nested deeper than [`max_depth`][SimplifyBudget::max_depth] levels are left as they are,
+    /// and simplifying stops after [`max_passes`][SimplifyBudget::max_passes] passes.
+    /// Instead of running out of stack on deeply nested notation, this gives back what it managed to simplify,
+    /// marked as [partial][Budgeted::is_partial].
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, expr::{fraction::Fraction, simplify::{Simplify, SimplifyBudget}, trig::{Trig, TrigFunction}}};
+    /// let value = Notation::from(Trig::new(TrigFunction::Sin, Trig::new(TrigFunction::Cos, Fraction::new(2, 4))));
+    ///
+    /// let full = value.clone().simplify_within(&SimplifyBudget::default());
+    /// assert!(!full.is_partial);
+    /// assert_eq!(full.value.to_string(), "sin(cos(1/2°)°)");
+    ///
+    /// let shallow = value.simplify_within(&SimplifyBudget::default().with_max_depth(2));
+    /// assert!(shallow.is_partial);
+    /// assert_eq!(shallow.value.to_string(), "sin(cos(2/4°)°)");
+    /// ```
+    fn simplify_within(self, budget: &SimplifyBudget) -> Budgeted
+    where
+        Self: Sized + Into<Notation>,
+    {
+        if budget.max_passes == 0 {
+            return Budgeted {
+                value: self.into(),
+                is_partial: true,
+            };
+        }
+        let exhausted = Cell::new(false);
+        let once = |value: Notation| value.simplify_to_depth(budget.max_depth, &exhausted);
+        let mut value = Canonical(once(self.into()));
+        let mut settled = false;
+        for _ in 1..budget.max_passes {
+            let next = Canonical(once(value.0.clone()));
+            if next == value {
+                settled = true;
+                break;
+            }
+            value = next;
+        }
+        Budgeted {
+            value: value.0,
+            is_partial: exhausted.get() || !settled,
+        }
+    }
+}
+
+impl Notation {
+    /// Simplifies the notation once, leaving any part which doesn't fit within `levels` levels as it is.
+    ///
+    /// Levels are counted the same way as [`depth`][Notation::depth].
+    /// Sets `exhausted` if anything was left.
+    ///
+    /// Works from the leaves up, keeping its own stack of work rather than recursing,
+    /// so that notation nested too deeply for the call stack can still be simplified.
+    pub(crate) fn simplify_to_depth(self, levels: usize, exhausted: &Cell<bool>) -> Notation {
+        self.simplify_nodes(levels, exhausted, &Notation::simplify_node)
+    }
+
+    /// Simplifies the notation fully like [`simplify_full`][Simplify::simplify_full],
+    /// simplifying each node with `node` once its children are simplified, rather than with [`simplify_node`][Notation::simplify_node].
+    pub(crate) fn simplify_full_with(self, node: &impl Fn(Notation) -> Notation) -> Notation {
+        let once = |value: Notation| value.simplify_nodes(usize::MAX, &Cell::new(false), node);
+        let mut value = Canonical(once(self));
+        for _ in 1..MAX_SIMPLIFY_PASSES {
+            let next = Canonical(once(value.0.clone()));
+            if next == value {
+                break;
+            }
+            value = next;
+        }
+        value.0
+    }
+
+    /// Simplifies the notation once like [`simplify_to_depth`][Notation::simplify_to_depth],
+    /// simplifying each node with `node` once its children are simplified.
+    fn simplify_nodes(
+        self,
+        levels: usize,
+        exhausted: &Cell<bool>,
+        node: &impl Fn(Notation) -> Notation,
+    ) -> Notation {
+        enum Work {
+            /// Notation to simplify, with the levels left to simplify within.
+            Enter(Notation, usize),
+            /// Notation whose children have been simplified and are waiting on the results.
+            Exit(Notation, usize),
+        }
+        if !self.is_compound() {
+            return self.simplify_flat(levels, exhausted, node);
+        }
+        let mut work = vec![Work::Enter(self, levels)];
+        let mut simplified = Vec::new();
+        while let Some(next) = work.pop() {
+            match next {
+                Work::Enter(value, levels) if !value.is_compound() => {
+                    simplified.push(value.simplify_flat(levels, exhausted, node))
+                }
+                Work::Enter(value, ..=1) => {
+                    exhausted.set(true);
+                    simplified.push(value);
+                }
+                Work::Enter(value, levels) => {
+                    let (value, children) = value.take_children();
+                    work.push(Work::Exit(value, children.len()));
+                    work.extend(
+                        children
+                            .into_iter()
+                            .rev()
+                            .map(|child| Work::Enter(child, levels - 1)),
+                    );
+                }
+                Work::Exit(value, count) => {
+                    let children = simplified.split_off(simplified.len() - count);
+                    simplified.push(node(value.with_children(children)));
+                }
+            }
+        }
+        simplified.pop().expect("every node leaves one result")
+    }
+
+    /// Simplifies notation which holds no other notation with `node`, if it fits within `levels` levels.
+    fn simplify_flat(
+        self,
+        levels: usize,
+        exhausted: &Cell<bool>,
+        node: &impl Fn(Notation) -> Notation,
+    ) -> Notation {
+        match self.depth() <= levels {
+            true => node(self),
+            false => {
+                exhausted.set(true);
+                self
+            }
+        }
+    }
+
+    /// Simplifies the notation itself, taking its children to be simplified already.
+    pub(crate) fn simplify_node(self) -> Notation {
+        let simplified = |child: Notation| child;
+        match self {
+            Notation::Atom(atom) => atom.simplify(),
+            Notation::Expr(expr) => match expr {
+                Expr::Trig(value) => value.simplify_with(simplified),
+                Expr::Complex(value) => value.simplify_with(simplified),
+                Expr::Sum(value) => value.simplify_with(simplified),
+                Expr::NestedFraction(value) => value.simplify_with(simplified),
+                Expr::NestedRadical(value) => value.simplify_with(simplified),
+                Expr::Product(value) => value.simplify_with(simplified),
+                Expr::Power(value) => value.simplify_with(simplified),
+                expr => expr.simplify(),
+            },
+        }
+    }
+}
+
+/// How much work [`simplify_within`][Simplify::simplify_within] may do before giving back what it has.
+///
+/// The default simplifies up to [`DEFAULT_MAX_DEPTH`] levels deep, for up to [`MAX_SIMPLIFY_PASSES`] passes.
+///
+/// ```
+/// # use algebra::notation::expr::simplify::{SimplifyBudget, MAX_SIMPLIFY_PASSES};
+/// let budget = SimplifyBudget::default().with_max_depth(32);
+/// assert_eq!(budget.max_depth, 32);
+/// assert_eq!(budget.max_passes, MAX_SIMPLIFY_PASSES);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct SimplifyBudget {
+    /// The most levels of notation to simplify, counted the same way as [`depth`][Notation::depth].
+    ///
+    /// Anything nested deeper is left as it is.
+    pub max_depth: usize,
+
+    /// The most times to simplify the whole notation.
+    ///
+    /// Simplifying has only settled once a pass changes nothing,
+    /// so running out of passes before that leaves the result partial.
+    pub max_passes: usize,
+}
+
+impl Default for SimplifyBudget {
+    fn default() -> Self {
+        Self {
+            max_depth: DEFAULT_MAX_DEPTH,
+            max_passes: MAX_SIMPLIFY_PASSES,
+        }
+    }
+}
+
+impl SimplifyBudget {
+    /// Sets the most levels of notation to simplify.
+    pub fn with_max_depth(self, max_depth: usize) -> Self {
+        Self { max_depth, ..self }
+    }
+
+    /// Sets the most times to simplify the whole notation.
+    pub fn with_max_passes(self, max_passes: usize) -> Self {
+        Self { max_passes, ..self }
+    }
+}
+
+/// The default [`max_depth`][SimplifyBudget::max_depth] of a [`SimplifyBudget`].
+pub const DEFAULT_MAX_DEPTH: usize = 256;
+
+/// Notation simplified within a [`SimplifyBudget`].
+#[derive(Debug, PartialEq, Clone)]
+pub struct Budgeted {
+    /// As much of the simplified notation as the budget allowed.
+    pub value: Notation,
+
+    /// Whether the budget ran out, either leaving parts too deep to reach or stopping before simplifying settled.
+    pub is_partial: bool,
+}
+
+/// The most times [`simplify_full`][Simplify::simplify_full] simplifies notation.
+pub const MAX_SIMPLIFY_PASSES: usize = 16;
+
+/// Simplifies every notation in a batch, such as a class's worth of answers, keeping their order.
+///
+/// Atoms are already as simple as they can be, and are returned unchanged.
+/// With the `rayon` feature, the notation is simplified in parallel.
+///
+/// ```
+/// # use algebra::notation::{Notation, expr::{fraction::Fraction, radical::Radical, simplify::simplify_all}};
+/// let answers = [
+///     Notation::from(Fraction::new(2, 4)),
+///     Notation::from(Radical::new(8)),
+///     Notation::from(3),
+/// ];
+/// let simplified = simplify_all(answers);
+/// assert_eq!(simplified[0], Fraction::new(1, 2));
+/// assert_eq!(simplified[1], Radical { coef: 2, rad: 2 });
+/// assert_eq!(simplified[2], 3);
+/// ```
+pub fn simplify_all(values: impl IntoIterator<Item = Notation>) -> Vec<Notation> {
+    #[cfg(feature = "rayon")]
+    {
+        use rayon::prelude::*;
+        values
+            .into_iter()
+            .collect::<Vec<_>>()
+            .into_par_iter()
+            .map(Notation::simplify)
+            .collect()
+    }
+    #[cfg(not(feature = "rayon"))]
+    {
+        values.into_iter().map(Notation::simplify).collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::atom::{number::Number, Atom};
+    use crate::notation::expr::{
+        fraction::Fraction,
+        multiple::Multiple,
+        radical::Radical,
+        radical_fraction::RadicalFraction,
+        trig::{Trig, TrigFunction},
+        Expr,
+    };
+
+    #[test]
+    fn test_simplify_all_keeps_order() {
+        let values = (1..=100).map(|n| Notation::from(Fraction::new(n, 2 * n)));
+        let simplified = simplify_all(values);
+        assert_eq!(simplified.len(), 100);
+        assert!(simplified.iter().all(|value| *value == Fraction::new(1, 2)));
+
+        let values = (1..=100).map(|n| Notation::from(Fraction::new(n * 2, 2)));
+        assert!(simplify_all(values)
+            .into_iter()
+            .zip(1..)
+            .all(|(value, n)| value == n));
+    }
+
+    #[test]
+    fn test_simplify_notation() {
+        assert!(Atom::Pi.simplify().atom().is_some_and(|x| x.is_pi()));
+        assert_eq!(Notation::from(7).simplify(), 7);
+        assert_eq!(Notation::from(Fraction::new(6, 3)).simplify(), 2);
+        assert_eq!(
+            Notation::from(Radical::new(8)).simplified(),
+            Radical { coef: 2, rad: 2 }
+        );
+    }
+
+    #[test]
+    fn test_ops_give_simplified_results() {
+        let half = || Notation::from(Fraction::new(2, 4));
+        assert!(matches!(
+            half() + Notation::from(0),
+            Notation::Expr(Expr::Fraction(Fraction {
+                num: Atom::Number(Number { value: 1 }),
+                ..
+            }))
+        ));
+        assert!(matches!(
+            Notation::from(0) - Notation::from(Multiple::new(-1, 1, Atom::Pi)),
+            Notation::Atom(Atom::Pi)
+        ));
+    }
+
+    #[test]
+    fn test_simplify_full() {
+        // -1,265,275,218√4/3 = -421,758,406√4 = -843,516,812
+        let value = RadicalFraction {
+            num: Radical {
+                coef: -1_265_275_218,
+                rad: 4,
+            },
+            den: 3,
+        };
+        assert_eq!(value.simplify_full(), -843_516_812);
+        let value = Trig::new(TrigFunction::Sin, Fraction::new(60, 2));
+        let simplified = value.simplified();
+        assert_eq!(value.simplify_full(), simplified);
+        assert_eq!(simplified, Fraction::new(1, 2));
+    }
+
+    /// sin(sin(...sin(2/4)...)), with `depth` levels in all.
+    fn nested_trig(depth: usize) -> Notation {
+        (2..depth).fold(Notation::from(Fraction::new(2, 4)), |arg, _| {
+            Notation::from(Trig::new(TrigFunction::Sin, arg))
+        })
+    }
+
+    #[test]
+    fn test_simplify_within_depth() {
+        let value = nested_trig(400);
+        assert_eq!(value.depth(), 400);
+
+        let shallow = value
+            .clone()
+            .simplify_within(&SimplifyBudget::default().with_max_depth(300));
+        assert!(shallow.is_partial);
+        assert_eq!(shallow.value.depth(), 400);
+        assert!(shallow.value.to_string().contains("(2/4°)"));
+
+        let deep = value
+            .clone()
+            .simplify_within(&SimplifyBudget::default().with_max_depth(400));
+        assert!(!deep.is_partial);
+        assert_eq!(deep.value, value.simplify_full());
+        assert!(deep.value.to_string().contains("(1/2°)"));
+
+        let nothing = Notation::from(Fraction::new(2, 4))
+            .simplify_within(&SimplifyBudget::default().with_max_depth(0));
+        assert!(nothing.is_partial);
+        assert_eq!(nothing.value.to_string(), "2/4");
+    }
+
+    #[test]
+    fn test_simplify_within_passes() {
+        // Simplifying 2/4 takes one pass, and it takes a second to see it has settled
+        let value = Fraction::new(2, 4);
+        let within = |passes| {
+            value
+                .clone()
+                .simplify_within(&SimplifyBudget::default().with_max_passes(passes))
+        };
+        assert!(within(0).is_partial);
+        assert_eq!(within(0).value, value.clone());
+        assert!(within(1).is_partial);
+        assert_eq!(within(1).value, Fraction::new(1, 2));
+        assert!(!within(2).is_partial);
+        assert_eq!(within(2).value, value.simplify_full());
+    }
 }
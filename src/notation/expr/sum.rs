@@ -0,0 +1,299 @@
+//! Sums of terms which can't be combined into one.
+
+use crate::notation::{
+    atom::Atom,
+    expr::{Expr, Simplify},
+    format::{FormatOptions, FormatWith},
+    ops::{add::combine, terms_of},
+    Notation,
+};
+use std::sync::Arc;
+
+/// Terms added together which can't be combined into a single term, such as 1 + √2.
+///
+/// Simplifying combines like terms, such as 2√3 + 5√3 = 7√3 or √8 + √2 = 3√2,
+/// and gives just the remaining term if the others all cancel out.
+///
+/// ```
+/// # use algebra::notation::{Notation, expr::{radical::Radical, simplify::Simplify, sum::Sum}};
+/// let sum = Sum::new([Notation::from(1), Notation::from(Radical::new(2)), Notation::from(2)]);
+/// assert_eq!(sum.to_string(), "1+√2+2");
+/// assert_eq!(sum.simplified().to_string(), "3+√2");
+///
+/// let sum = Radical::new(12) + Radical::new(3);
+/// assert_eq!(sum, Radical { coef: 3, rad: 3 });
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, PartialEq, Clone)]
+pub struct Sum {
+    /// The terms being added, in the order they are written.
+    ///
+    /// Shared between clones, like the parts of a [`Complex`][crate::notation::expr::complex::Complex] number.
+    pub terms: Arc<[Notation]>,
+}
+
+impl Sum {
+    /// Constructs the sum of the terms, without combining any of them.
+    pub fn new(terms: impl IntoIterator<Item = Notation>) -> Self {
+        Self {
+            terms: terms.into_iter().collect(),
+        }
+    }
+}
+
+/// Adds a simplified term to simplified terms which are all unlike each other,
+/// combining it with any terms it is like.
+///
+/// A combined term takes the place of the first term it was combined with.
+fn push_term(terms: &mut Vec<Notation>, mut term: Notation) {
+    let mut position = terms.len();
+    let mut i = 0;
+    while i < terms.len() {
+        match combine(terms[i].clone(), term) {
+            Ok(sum) => {
+                terms.remove(i);
+                position = position.min(i);
+                term = sum;
+                // The combined term may now be like terms which have already been passed
+                i = 0;
+            }
+            Err((_, unlike)) => {
+                term = unlike;
+                i += 1;
+            }
+        }
+    }
+    terms.insert(position.min(terms.len()), term);
+}
+
+impl Simplify for Sum {
+    fn simplify(self) -> Notation {
+        self.simplify_with(Notation::simplify)
+    }
+}
+
+impl Sum {
+    /// Simplifies each term with `simplify`, then combines like terms.
+    pub(crate) fn simplify_with(self, simplify: impl Fn(Notation) -> Notation) -> Notation {
+        // Terms are moved out rather than cloned when no other clone shares them
+        let mut shared = self.terms;
+        let owned = match Arc::get_mut(&mut shared) {
+            Some(terms) => terms.iter_mut().map(std::mem::take).collect(),
+            None => shared.to_vec(),
+        };
+        let mut terms = Vec::with_capacity(owned.len());
+        for term in owned {
+            match simplify(term) {
+                Notation::Expr(Expr::Sum(sum)) => {
+                    for term in sum.terms.iter().cloned() {
+                        push_term(&mut terms, term);
+                    }
+                }
+                term => push_term(&mut terms, term),
+            }
+        }
+        // Combining terms such as complex numbers can give a sum, which is flattened the same way
+        let mut terms = terms.iter().flat_map(terms_of).collect::<Vec<_>>();
+        // Special atoms absorb terms which the tables can't settle, such as trigonometric functions,
+        // with the same precedence as in the tables
+        let any = |is: fn(&Atom) -> bool| {
+            terms
+                .iter()
+                .any(|term| matches!(term, Notation::Atom(atom) if is(atom)))
+        };
+        if any(Atom::is_undefined) {
+            return Notation::from(Atom::Undefined);
+        }
+        if any(Atom::is_complex) {
+            return Notation::from(Atom::Complex);
+        }
+        if any(Atom::is_unknown) {
+            return Notation::from(Atom::Unknown);
+        }
+        terms.retain(|term| !term.is_zero());
+        match terms.len() {
+            0 => Notation::from(0),
+            1 => terms.remove(0),
+            _ => Notation::from(Self::new(terms)),
+        }
+    }
+}
+
+impl std::ops::Neg for Sum {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self::new(self.terms.iter().cloned().map(|term| -term))
+    }
+}
+
+impl std::fmt::Display for Sum {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.format_with(&FormatOptions::default()).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::expr::{
+        complex::Complex, fraction::Fraction, radical::Radical, radical_fraction::RadicalFraction,
+    };
+
+    fn sum<const N: usize>(terms: [Notation; N]) -> Sum {
+        Sum::new(terms)
+    }
+
+    #[test]
+    fn test_simplify_combines_like_terms() {
+        let two_root_3 = Notation::from(Radical { coef: 2, rad: 3 });
+        let five_root_3 = Notation::from(Radical { coef: 5, rad: 3 });
+        assert_eq!(
+            sum([two_root_3.clone(), five_root_3]).simplify(),
+            Radical { coef: 7, rad: 3 }
+        );
+        // Radicands are normalized before being compared
+        assert_eq!(
+            sum([Radical::new(8).into(), Radical::new(2).into()]).simplify(),
+            Radical { coef: 3, rad: 2 }
+        );
+        assert_eq!(
+            sum([
+                1.into(),
+                Radical::new(2).into(),
+                Fraction::new(1, 2).into(),
+                RadicalFraction::new(1, 2, 2).into(),
+            ])
+            .simplify(),
+            sum([
+                Fraction::new(3, 2).into(),
+                RadicalFraction::new(3, 2, 2).into()
+            ])
+        );
+        assert_eq!(
+            sum([Radical::new(2).into(), Radical::new(3).into()]).simplify(),
+            sum([Radical::new(2).into(), Radical::new(3).into()])
+        );
+    }
+
+    #[test]
+    fn test_simplify_cancels() {
+        let root = Notation::from(Radical::new(3));
+        assert_eq!(sum([root.clone(), -root.clone()]).simplify(), 0);
+        assert_eq!(sum([root.clone(), 1.into(), -root.clone()]).simplify(), 1);
+        assert_eq!(sum([]).simplify(), 0);
+        assert_eq!(
+            sum([
+                root.clone(),
+                Complex::new(0, Radical { coef: 0, rad: 5 }).into()
+            ])
+            .simplify(),
+            root
+        );
+        assert!(sum([root, Fraction::new(1, 0).into()])
+            .simplify()
+            .atom()
+            .is_some_and(|x| x.is_undefined()));
+    }
+
+    #[test]
+    fn test_simplify_flattens() {
+        let inner = Notation::from(sum([1.into(), Radical::new(2).into()]));
+        assert_eq!(
+            sum([inner, Radical::new(3).into(), Radical::new(2).into()]).simplify(),
+            sum([
+                1.into(),
+                Radical { coef: 2, rad: 2 }.into(),
+                Radical::new(3).into()
+            ])
+        );
+    }
+
+    #[test]
+    fn test_simplify_flattens_combined_terms() {
+        // (1 + 𝑖) + (√2 - 𝑖) = 1 + √2
+        let value = sum([
+            Complex::new(1, 1).into(),
+            Complex::new(Radical::new(2), -1).into(),
+            Radical::new(3).into(),
+        ])
+        .simplify();
+        assert_eq!(
+            value,
+            sum([1.into(), Radical::new(2).into(), Radical::new(3).into()])
+        );
+    }
+
+    #[test]
+    fn test_complex_terms() {
+        // √2 + (1 + 𝑖) = (√2 + 1) + 𝑖
+        assert_eq!(
+            sum([Radical::new(2).into(), Complex::new(1, 1).into()]).simplify(),
+            Complex::new(sum([Radical::new(2).into(), 1.into()]), 1)
+        );
+    }
+
+    #[test]
+    fn test_special_terms() {
+        use crate::notation::expr::trig::{Trig, TrigFunction};
+        let with = |term: Notation| {
+            let sin = Notation::from(Trig::new(TrigFunction::Sin, 10));
+            sum([1.into(), sin, term]).simplify()
+        };
+        assert!(with(Atom::Undefined.into())
+            .atom()
+            .is_some_and(|x| x.is_undefined()));
+        assert!(with(Atom::Unknown.into())
+            .atom()
+            .is_some_and(|x| x.is_unknown()));
+        assert!(with(Atom::Complex.into())
+            .atom()
+            .is_some_and(|x| x.is_complex()));
+        let complex = || Notation::from(Atom::Complex);
+        assert!(sum([1.into(), complex()])
+            .simplify()
+            .atom()
+            .is_some_and(|x| x.is_complex()));
+        assert!(sum([complex(), complex(), complex()])
+            .simplify()
+            .atom()
+            .is_some_and(|x| x.is_complex()));
+        // Undefined takes precedence over complex, and complex over unknown
+        assert!(sum([complex(), Atom::Undefined.into()])
+            .simplify()
+            .atom()
+            .is_some_and(|x| x.is_undefined()));
+        assert!(sum([Atom::Unknown.into(), complex()])
+            .simplify()
+            .atom()
+            .is_some_and(|x| x.is_complex()));
+        let parsed: Notation = "1+ℂ".parse().unwrap();
+        assert!(parsed.simplify().atom().is_some_and(|x| x.is_complex()));
+    }
+
+    #[test]
+    fn test_neg() {
+        let value = -sum([1.into(), Radical { coef: -2, rad: 3 }.into()]);
+        assert_eq!(
+            value,
+            sum([(-1).into(), Radical { coef: 2, rad: 3 }.into()])
+        );
+        assert_eq!(value.to_string(), "-1+2√3");
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(
+            sum([1.into(), Radical { coef: -1, rad: 2 }.into()]).to_string(),
+            "1-√2"
+        );
+        assert_eq!(
+            sum([
+                Fraction::new(1, 2).into(),
+                RadicalFraction::new(1, 3, 2).into()
+            ])
+            .to_string(),
+            "1/2+√3/2"
+        );
+    }
+}
@@ -0,0 +1,230 @@
+//! Trigonometric functions of angles.
+
+use crate::notation::{
+    atom::{number::Number, Atom},
+    expr::{radical_fraction::RadicalFraction, Simplify},
+    format::{FormatOptions, FormatWith},
+    Notation,
+};
+use std::sync::Arc;
+
+/// A trigonometric function.
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, PartialEq, Eq, Hash, Clone, Copy)]
+pub enum TrigFunction {
+    /// Sine.
+    Sin,
+
+    /// Cosine.
+    Cos,
+
+    /// Tangent.
+    Tan,
+}
+
+impl std::fmt::Display for TrigFunction {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use TrigFunction::*;
+        match self {
+            Sin => "sin",
+            Cos => "cos",
+            Tan => "tan",
+        }
+        .fmt(f)
+    }
+}
+
+/// A trigonometric function applied to an angle.
+///
+/// Simplifies to an exact value when the angle is a multiple of 30° or 45°,
+/// and otherwise stays as it is.
+///
+/// ```
+/// # use algebra::notation::expr::{radical_fraction::RadicalFraction, simplify::Simplify, trig::{Trig, TrigFunction}};
+/// let sin_60 = Trig::new(TrigFunction::Sin, 60);
+/// assert_eq!(sin_60.simplified(), RadicalFraction::new(1, 3, 2));
+/// assert_eq!(sin_60.simplified().to_string(), "√3/2");
+///
+/// let sin_10 = Trig::new(TrigFunction::Sin, 10);
+/// assert_eq!(sin_10.simplified(), sin_10);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, PartialEq, Clone)]
+pub struct Trig {
+    /// The function being applied.
+    pub func: TrigFunction,
+
+    /// The angle, in degrees.
+    ///
+    /// Shared between clones, so that cloning a deep tree doesn't copy it;
+    /// the angle is only copied when the trig function is taken apart while another clone still uses it.
+    pub arg: Arc<Notation>,
+}
+
+impl Trig {
+    /// Constructs a trigonometric function of an angle in degrees.
+    pub fn new(func: TrigFunction, degrees: impl Into<Notation>) -> Self {
+        Self {
+            func,
+            arg: Arc::new(degrees.into()),
+        }
+    }
+}
+
+impl std::fmt::Display for Trig {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.format_with(&FormatOptions::default()).fmt(f)
+    }
+}
+
+/// An exact value `coef√rad / den`.
+///
+/// A `den` of 0 is [`Undefined`][Atom::Undefined].
+type Exact = (i32, i32, i32);
+
+/// Exact values of each function at the special angles of the first quadrant.
+///
+/// Other quadrants are found by reflecting back into the first.
+const SPECIAL_ANGLES: [(i32, [Exact; 3]); 5] = [
+    // angle, [sin, cos, tan]
+    (0, [(0, 1, 1), (1, 1, 1), (0, 1, 1)]),
+    (30, [(1, 1, 2), (1, 3, 2), (1, 3, 3)]),
+    (45, [(1, 2, 2), (1, 2, 2), (1, 1, 1)]),
+    (60, [(1, 3, 2), (1, 1, 2), (1, 3, 1)]),
+    (90, [(1, 1, 1), (0, 1, 1), (1, 1, 0)]),
+];
+
+/// Returns the exact value of `func` at a whole number of degrees, if that angle is special.
+fn special_value(func: TrigFunction, degrees: i32) -> Option<Notation> {
+    let degrees = degrees.rem_euclid(360);
+
+    // Reference angle in the first quadrant
+    let reference = match degrees {
+        0..=90 => degrees,
+        91..=180 => 180 - degrees,
+        181..=270 => degrees - 180,
+        _ => 360 - degrees,
+    };
+
+    let sin_sign = if degrees < 180 { 1 } else { -1 };
+    let cos_sign = if degrees <= 90 || degrees >= 270 {
+        1
+    } else {
+        -1
+    };
+    let (index, sign) = match func {
+        TrigFunction::Sin => (0, sin_sign),
+        TrigFunction::Cos => (1, cos_sign),
+        TrigFunction::Tan => (2, sin_sign * cos_sign),
+    };
+
+    let (_, values) = SPECIAL_ANGLES
+        .iter()
+        .find(|(angle, _)| *angle == reference)?;
+    let (coef, rad, den) = values[index];
+    Some(RadicalFraction::new(sign * coef, rad, den).simplify())
+}
+
+impl Simplify for Trig {
+    fn simplify(self) -> Notation {
+        self.simplify_with(Notation::simplify)
+    }
+}
+
+impl Trig {
+    /// Simplifies the argument with `simplify`, then evaluates the function at special angles.
+    pub(crate) fn simplify_with(self, simplify: impl Fn(Notation) -> Notation) -> Notation {
+        let Self { func, arg } = self;
+        match simplify(Arc::unwrap_or_clone(arg)) {
+            Notation::Atom(Atom::Number(Number { value })) => {
+                special_value(func, value).unwrap_or_else(|| Notation::from(Trig::new(func, value)))
+            }
+            Notation::Atom(Atom::Complex) => Notation::from(Atom::Complex),
+            Notation::Atom(Atom::Undefined) => Notation::from(Atom::Undefined),
+            arg => Notation::from(Trig {
+                func,
+                arg: Arc::new(arg),
+            }),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{TrigFunction::*, *};
+    use crate::notation::expr::{fraction::Fraction, radical::Radical};
+
+    #[test]
+    fn test_first_quadrant() {
+        assert_eq!(Trig::new(Sin, 0).simplify(), 0);
+        assert_eq!(Trig::new(Sin, 30).simplify(), Fraction::new(1, 2));
+        assert_eq!(Trig::new(Sin, 45).simplify(), RadicalFraction::new(1, 2, 2));
+        assert_eq!(Trig::new(Cos, 60).simplify(), Fraction::new(1, 2));
+        assert_eq!(Trig::new(Cos, 90).simplify(), 0);
+        assert_eq!(Trig::new(Tan, 30).simplify(), RadicalFraction::new(1, 3, 3));
+        assert_eq!(Trig::new(Tan, 45).simplify(), 1);
+        assert_eq!(Trig::new(Tan, 60).simplify(), Radical::new(3));
+    }
+
+    #[test]
+    fn test_other_quadrants() {
+        assert_eq!(Trig::new(Sin, 150).simplify(), Fraction::new(1, 2));
+        assert_eq!(Trig::new(Cos, 120).simplify(), Fraction::new(-1, 2));
+        assert_eq!(Trig::new(Sin, 180).simplify(), 0);
+        assert_eq!(Trig::new(Cos, 180).simplify(), -1);
+        assert_eq!(
+            Trig::new(Sin, 225).simplify(),
+            RadicalFraction::new(-1, 2, 2)
+        );
+        assert_eq!(Trig::new(Tan, 225).simplify(), 1);
+        assert_eq!(Trig::new(Sin, 270).simplify(), -1);
+        assert_eq!(
+            Trig::new(Cos, 330).simplify(),
+            RadicalFraction::new(1, 3, 2)
+        );
+        assert_eq!(Trig::new(Tan, 300).simplify(), Radical { coef: -1, rad: 3 });
+    }
+
+    #[test]
+    fn test_periodic_and_negative_angles() {
+        assert_eq!(Trig::new(Sin, 390).simplify(), Fraction::new(1, 2));
+        assert_eq!(Trig::new(Sin, -30).simplify(), Fraction::new(-1, 2));
+        assert_eq!(Trig::new(Cos, -720).simplify(), 1);
+    }
+
+    #[test]
+    fn test_tan_of_right_angle_is_undefined() {
+        for degrees in [90, 270, -90] {
+            let tan = Trig::new(Tan, degrees).simplify();
+            assert!(tan.atom().is_some_and(|x| x.is_undefined()));
+        }
+    }
+
+    #[test]
+    fn test_other_angles_stay_symbolic() {
+        let sin = Trig::new(Sin, 20);
+        assert_eq!(sin.clone().simplify(), sin);
+        let cos = Trig::new(Cos, Fraction::new(1, 2));
+        assert_eq!(cos.clone().simplify(), cos);
+        assert_eq!(
+            Trig::new(Cos, Fraction::new(180, 3)).simplify(),
+            Fraction::new(1, 2)
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Trig::new(Sin, 20).to_string(), "sin(20°)");
+    }
+
+    #[test]
+    fn test_clones_share_arg() {
+        let mut nested = Trig::new(Sin, 10);
+        for _ in 0..100 {
+            nested = Trig::new(Cos, nested);
+        }
+        let clone = nested.clone();
+        assert!(Arc::ptr_eq(&nested.arg, &clone.arg));
+        assert_eq!(nested, clone);
+    }
+}
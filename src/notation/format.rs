@@ -0,0 +1,831 @@
+//! Options for how notation is written out as text.
+//!
+//! Every [`Display`][std::fmt::Display] implementation in [`notation`][crate::notation]
+//! is [`FormatWith`] using the default [`FormatOptions`].
+
+use crate::notation::{
+    atom::{number::Number, Atom},
+    expr::{
+        complex::Complex, fraction::Fraction, multiple::Multiple, nested_fraction::NestedFraction,
+        nested_radical::NestedRadical, power::Power, product::Product, radical::Radical,
+        radical_fraction::RadicalFraction, sum::Sum, trig::Trig, Expr,
+    },
+    Notation,
+};
+
+/// Which characters may appear in the output.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Charset {
+    /// Mathematical symbols such as 𝑖, ∅, π, and ×.
+    #[default]
+    Unicode,
+
+    /// Only plain ASCII characters, spelling symbols out as words: `i`, `undefined`, `pi`, `*`.
+    Ascii,
+}
+
+/// How square roots are written.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum RootStyle {
+    /// With a radical sign: `√2`.
+    ///
+    /// Written as a [`Function`][RootStyle::Function] when the [`Charset`] is [`Ascii`][Charset::Ascii].
+    #[default]
+    Symbol,
+
+    /// As a function call: `sqrt(2)`.
+    Function,
+}
+
+/// How exponents, and the indices of roots other than square roots, are written.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Exponents {
+    /// With superscript characters: `x²`, `2⁵`, and `³√2`.
+    ///
+    /// Written with a [`Caret`][Exponents::Caret] when the [`Charset`] is [`Ascii`][Charset::Ascii].
+    #[default]
+    Superscript,
+
+    /// With a caret: `x^2`, `2^5`, and `2^(1/3)`.
+    Caret,
+}
+
+/// How a coefficient is joined to what it multiplies.
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub enum Multiplication {
+    /// Side by side: `2√3`, `2π`.
+    #[default]
+    Implicit,
+
+    /// With a multiplication sign: `2×√3`, `2×π`.
+    Symbol,
+}
+
+/// Controls how notation is written out as text.
+///
+/// The default matches [`Display`][std::fmt::Display]: Unicode symbols, radical signs, implicit multiplication, superscript exponents, and no spacing.
+///
+/// ```
+/// # use algebra::notation::{Notation, atom::Atom, expr::{fraction::Fraction, radical::Radical}, format::{FormatOptions, FormatWith}};
+/// let root = Notation::from(Radical { coef: 2, rad: 3 });
+/// assert_eq!(root.format_with(&FormatOptions::default()), "2√3");
+/// assert_eq!(root.format_with(&FormatOptions::ascii()), "2*sqrt(3)");
+///
+/// let spaced = FormatOptions::default().with_spacing(true);
+/// assert_eq!(Notation::from(Fraction::new(1, 2)).format_with(&spaced), "1 / 2");
+///
+/// let undefined = Notation::from(Atom::Undefined);
+/// assert_eq!(undefined.format_with(&FormatOptions::ascii()), "undefined");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Default)]
+pub struct FormatOptions {
+    /// Which characters may appear in the output.
+    pub charset: Charset,
+
+    /// How square roots are written.
+    pub roots: RootStyle,
+
+    /// How a coefficient is joined to what it multiplies.
+    pub multiplication: Multiplication,
+
+    /// How exponents and the indices of roots are written.
+    pub exponents: Exponents,
+
+    /// Whether to put spaces around operators, as in `1 / 2` rather than `1/2`.
+    pub spacing: bool,
+}
+
+impl FormatOptions {
+    /// The options used by [`Display`][std::fmt::Display]: Unicode symbols, `√`, implicit multiplication, superscript exponents, and no spacing.
+    pub fn unicode() -> Self {
+        Self::default()
+    }
+
+    /// Options which only produce plain ASCII: words for symbols, `sqrt()`, `*` for multiplication, and `^` for exponents.
+    pub fn ascii() -> Self {
+        Self {
+            charset: Charset::Ascii,
+            roots: RootStyle::Function,
+            multiplication: Multiplication::Symbol,
+            exponents: Exponents::Caret,
+            spacing: false,
+        }
+    }
+
+    /// Sets which characters may appear in the output.
+    pub fn with_charset(self, charset: Charset) -> Self {
+        Self { charset, ..self }
+    }
+
+    /// Sets how square roots are written.
+    pub fn with_roots(self, roots: RootStyle) -> Self {
+        Self { roots, ..self }
+    }
+
+    /// Sets how a coefficient is joined to what it multiplies.
+    pub fn with_multiplication(self, multiplication: Multiplication) -> Self {
+        Self {
+            multiplication,
+            ..self
+        }
+    }
+
+    /// Sets how exponents and the indices of roots are written.
+    pub fn with_exponents(self, exponents: Exponents) -> Self {
+        Self { exponents, ..self }
+    }
+
+    /// Sets whether to put spaces around operators.
+    pub fn with_spacing(self, spacing: bool) -> Self {
+        Self { spacing, ..self }
+    }
+
+    /// Picks between the Unicode and ASCII spelling of a symbol.
+    pub(crate) fn glyph(&self, unicode: &'static str, ascii: &'static str) -> &'static str {
+        match self.charset {
+            Charset::Unicode => unicode,
+            Charset::Ascii => ascii,
+        }
+    }
+
+    /// Surrounds an operator with spaces, if spacing is on.
+    fn operator(&self, op: &str) -> String {
+        if self.spacing {
+            format!(" {op} ")
+        } else {
+            op.to_string()
+        }
+    }
+
+    /// The text between a coefficient and what it multiplies.
+    fn times(&self) -> String {
+        match self.multiplication {
+            Multiplication::Implicit => String::new(),
+            Multiplication::Symbol => self.operator(self.glyph("×", "*")),
+        }
+    }
+
+    /// The text between a numerator and denominator.
+    fn over(&self) -> String {
+        self.operator("/")
+    }
+
+    /// The square root of some text.
+    fn root(&self, rad: impl std::fmt::Display) -> String {
+        match (self.roots, self.charset) {
+            (RootStyle::Symbol, Charset::Unicode) => format!("√{rad}"),
+            _ => format!("sqrt({rad})"),
+        }
+    }
+
+    /// The square root of some compound text, which needs parentheses when written with a symbol.
+    fn grouped_root(&self, rad: impl std::fmt::Display) -> String {
+        match (self.roots, self.charset) {
+            (RootStyle::Symbol, Charset::Unicode) => format!("√({rad})"),
+            _ => format!("sqrt({rad})"),
+        }
+    }
+
+    /// Returns true if exponents are written with superscripts, false if with a caret.
+    fn is_superscript(&self) -> bool {
+        (self.exponents, self.charset) == (Exponents::Superscript, Charset::Unicode)
+    }
+
+    /// Writes `base` raised to the power `exp`, as in `x²` or `x^2`.
+    ///
+    /// The base is written as it is, so a base such as a sum needs parentheses around it first.
+    ///
+    /// ```
+    /// # use algebra::notation::format::{Exponents, FormatOptions};
+    /// assert_eq!(FormatOptions::default().power("x", 2), "x²");
+    /// assert_eq!(FormatOptions::default().power(4, -1), "4⁻¹");
+    /// assert_eq!(FormatOptions::default().with_exponents(Exponents::Caret).power("x", 2), "x^2");
+    /// assert_eq!(FormatOptions::ascii().power(2, -3), "2^-3");
+    /// ```
+    pub fn power(&self, base: impl std::fmt::Display, exp: i64) -> String {
+        match self.is_superscript() {
+            true => format!("{base}{}", superscript(exp)),
+            false => format!("{base}^{exp}"),
+        }
+    }
+
+    /// Writes the root of `rad` with the index `index`, as in `³√2`.
+    ///
+    /// An index of 2 is a square root, written the same way as a [`Radical`].
+    /// Other roots are written with a superscript index, or as a fractional power such as `2^(1/3)`
+    /// when exponents are written with a [caret][Exponents::Caret] or square roots as a [function][RootStyle::Function].
+    ///
+    /// ```
+    /// # use algebra::notation::format::FormatOptions;
+    /// assert_eq!(FormatOptions::default().nth_root(2, 5), "√5");
+    /// assert_eq!(FormatOptions::default().nth_root(3, 2), "³√2");
+    /// assert_eq!(FormatOptions::ascii().nth_root(3, 2), "2^(1/3)");
+    /// ```
+    pub fn nth_root(&self, index: u32, rad: impl std::fmt::Display) -> String {
+        match (
+            index,
+            self.is_superscript() && self.roots == RootStyle::Symbol,
+        ) {
+            (2, _) => self.root(rad),
+            (index, true) => format!("{}√{rad}", superscript(index as i64)),
+            (index, false) => format!("{rad}^(1/{index})"),
+        }
+    }
+}
+
+/// Writes an integer with superscript characters, such as `⁻¹²`.
+///
+/// ```
+/// # use algebra::notation::format::superscript;
+/// assert_eq!(superscript(25), "²⁵");
+/// assert_eq!(superscript(-1), "⁻¹");
+/// ```
+pub fn superscript(n: i64) -> String {
+    const DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+    script(n, '⁻', DIGITS)
+}
+
+/// Writes an integer with subscript characters, such as `₁₂`, for indices like the one in `x₁`.
+///
+/// ```
+/// # use algebra::notation::format::subscript;
+/// assert_eq!(subscript(12), "₁₂");
+/// assert_eq!(subscript(-3), "₋₃");
+/// ```
+pub fn subscript(n: i64) -> String {
+    const DIGITS: [char; 10] = ['₀', '₁', '₂', '₃', '₄', '₅', '₆', '₇', '₈', '₉'];
+    script(n, '₋', DIGITS)
+}
+
+/// Writes an integer with the given minus sign and digits.
+fn script(n: i64, minus: char, digits: [char; 10]) -> String {
+    let sign = (n < 0).then_some(minus);
+    sign.into_iter()
+        .chain(
+            n.unsigned_abs()
+                .to_string()
+                .chars()
+                .map(|digit| digits[digit.to_digit(10).unwrap() as usize]),
+        )
+        .collect()
+}
+
+/// Writes the radical `coef × unit × √rad`, where `unit` is a symbol such as 𝑖, or empty.
+///
+/// Every text format writes its radicals and imaginary parts with this, passing how it writes a product and a root,
+/// so that they agree on every coefficient and radicand:
+/// a coefficient of 0 is written as just 0 (times the unit, if any), a radicand of 1 isn't written,
+/// and a coefficient of 1 or -1 is written as just its sign when something follows it.
+/// Any other radicand, including 0 and negatives, is written as it is.
+pub(crate) fn write_radical(
+    coef: i32,
+    unit: &str,
+    rad: i32,
+    times: &str,
+    root: impl Fn(i32) -> String,
+) -> String {
+    let scaled = match (coef, unit) {
+        (0, "") => return "0".to_string(),
+        (0, unit) => return format!("0{times}{unit}"),
+        (coef, "") if rad == 1 => return coef.to_string(),
+        (1, unit) => unit.to_string(),
+        (-1, unit) => format!("-{unit}"),
+        (coef, "") => coef.to_string(),
+        (coef, unit) => format!("{coef}{times}{unit}"),
+    };
+    match rad {
+        1 => scaled,
+        rad if scaled.is_empty() || scaled == "-" => format!("{scaled}{}", root(rad)),
+        rad => format!("{scaled}{times}{}", root(rad)),
+    }
+}
+
+/// Notation which can be written out as text in more than one style.
+pub trait FormatWith {
+    /// Writes the notation as text, following the options given.
+    fn format_with(&self, options: &FormatOptions) -> String;
+}
+
+impl FormatWith for Atom {
+    fn format_with(&self, options: &FormatOptions) -> String {
+        use Atom::*;
+        let o = options;
+        match self {
+            Number(n) => n.to_string(),
+            Complex => o.glyph("ℂ", "complex").to_string(),
+            Undefined => o.glyph("∅", "undefined").to_string(),
+            Huge => o.glyph("𝓗", "huge").to_string(),
+            NegativeHuge => format!("-{}", o.glyph("𝓗", "huge")),
+            Epsilon => o.glyph("ε", "epsilon").to_string(),
+            NegativeEpsilon => format!("-{}", o.glyph("ε", "epsilon")),
+            Unknown => "?".to_string(),
+            Pi => o.glyph("π", "pi").to_string(),
+            E => o.glyph("𝑒", "e").to_string(),
+            Variable(var) => var.to_string(),
+        }
+    }
+}
+
+impl FormatWith for Fraction {
+    fn format_with(&self, options: &FormatOptions) -> String {
+        let Self { num, den } = self;
+        format!(
+            "{}{}{}",
+            num.format_with(options),
+            options.over(),
+            den.format_with(options)
+        )
+    }
+}
+
+impl FormatWith for Radical {
+    /// Writes the radical as it is, without moving square factors out,
+    /// so that radicals such as √12 can be shown before they are simplified.
+    /// A coefficient of -1 is written as just its sign, and a coefficient of 0 as just 0.
+    fn format_with(&self, options: &FormatOptions) -> String {
+        write_radical(self.coef, "", self.rad, &options.times(), |rad| {
+            options.root(rad)
+        })
+    }
+}
+
+impl FormatWith for RadicalFraction {
+    fn format_with(&self, options: &FormatOptions) -> String {
+        let Self { num, den } = self;
+        format!("{}{}{den}", num.format_with(options), options.over())
+    }
+}
+
+impl FormatWith for Trig {
+    fn format_with(&self, options: &FormatOptions) -> String {
+        write_tree(&Notation::from(self.clone()), options)
+    }
+}
+
+impl Trig {
+    /// Writes the function around its argument, already written as `arg`.
+    fn write(&self, arg: &str, options: &FormatOptions) -> String {
+        format!("{}({arg}{})", self.func, options.glyph("°", "deg"))
+    }
+}
+
+impl FormatWith for Multiple {
+    fn format_with(&self, options: &FormatOptions) -> String {
+        use Atom::Number as Num;
+        let Self { coef, constant } = self;
+        let constant = constant.format_with(options);
+        let scaled = match &coef.num {
+            Num(Number { value: 1 }) => constant,
+            Num(Number { value: -1 }) => format!("-{constant}"),
+            num => format!("{}{}{constant}", num.format_with(options), options.times()),
+        };
+        match &coef.den {
+            Num(Number { value: 1 }) => scaled,
+            den => format!("{scaled}{}{}", options.over(), den.format_with(options)),
+        }
+    }
+}
+
+impl Complex {
+    /// Writes the imaginary part with its 𝑖, such as `-2𝑖√3` or `𝑖/2`, given the imaginary part already written as `im`.
+    ///
+    /// 𝑖 goes after the coefficient and before any root, so that it isn't mistaken for being under the root.
+    fn format_imaginary(&self, im: &Written, options: &FormatOptions) -> String {
+        use Atom::Number as Num;
+        let (i, times) = (options.glyph("𝑖", "i"), options.times());
+        let radical_i = |radical: &Radical| {
+            let Radical { coef, rad } = radical.normalized();
+            write_radical(coef, i, rad, &times, |rad| options.root(rad))
+        };
+        let coef_i = |coef: i32| radical_i(&Radical::from(coef));
+        match self.im.as_ref() {
+            Notation::Atom(Num(Number { value })) => coef_i(*value),
+            Notation::Expr(Expr::Radical(rad)) => radical_i(rad),
+            Notation::Expr(Expr::Fraction(Fraction {
+                num: Num(Number { value }),
+                den,
+            })) => format!(
+                "{}{}{}",
+                coef_i(*value),
+                options.over(),
+                den.format_with(options)
+            ),
+            Notation::Expr(Expr::RadicalFraction(RadicalFraction { num, den })) => {
+                format!("{}{}{den}", radical_i(num), options.over())
+            }
+            Notation::Expr(Expr::NestedRadical(root)) => {
+                let rad = im
+                    .root
+                    .as_ref()
+                    .expect("nested radicals are written with their root");
+                format!("{}{times}{rad}", coef_i(root.coef))
+            }
+            Notation::Expr(Expr::Sum(_)) => format!("({}){times}{i}", im.text),
+            _ => format!("{}{times}{i}", im.text),
+        }
+    }
+
+    /// Writes the number from its parts, already written as `re` and `im`.
+    fn write(&self, re: &str, im: &Written, options: &FormatOptions) -> String {
+        let imaginary = self.format_imaginary(im, options);
+        // A real part of 0 isn't written
+        if re == "0" {
+            return imaginary;
+        }
+        match imaginary.strip_prefix('-') {
+            Some(magnitude) => format!("{re}{}{magnitude}", options.operator("-")),
+            None => format!("{re}{}{imaginary}", options.operator("+")),
+        }
+    }
+}
+
+impl FormatWith for Complex {
+    fn format_with(&self, options: &FormatOptions) -> String {
+        write_tree(&Notation::from(self.clone()), options)
+    }
+}
+
+impl FormatWith for Sum {
+    fn format_with(&self, options: &FormatOptions) -> String {
+        write_tree(&Notation::from(self.clone()), options)
+    }
+}
+
+impl Sum {
+    /// Writes the terms, already written, joined by their signs.
+    fn write(terms: impl IntoIterator<Item = String>, options: &FormatOptions) -> String {
+        let mut terms = terms.into_iter();
+        let Some(mut result) = terms.next() else {
+            return "0".to_string();
+        };
+        for term in terms {
+            match term.strip_prefix('-') {
+                Some(magnitude) => result += &format!("{}{magnitude}", options.operator("-")),
+                None => result += &format!("{}{term}", options.operator("+")),
+            }
+        }
+        result
+    }
+}
+
+/// Returns true if `text`, the way `part` is written, is also how an atom is written, such as the multiple 1π.
+fn is_written_as_atom(part: &Notation, text: &str, options: &FormatOptions) -> bool {
+    match part {
+        Notation::Atom(_) => true,
+        Notation::Expr(Expr::Multiple(Multiple { constant, .. })) => {
+            text == constant.format_with(options)
+        }
+        _ => text.bytes().all(|c| c.is_ascii_digit()),
+    }
+}
+
+impl FormatWith for NestedFraction {
+    /// Compound parts are written in parentheses, so that `(1/2)/(3/4)` isn't read as `1/2/3/4`.
+    fn format_with(&self, options: &FormatOptions) -> String {
+        write_tree(&Notation::from(self.clone()), options)
+    }
+}
+
+impl NestedFraction {
+    /// Writes the fraction from its parts, already written as `num` and `den`.
+    fn write(&self, num: &str, den: &str, options: &FormatOptions) -> String {
+        let part = |part: &Notation, text: &str| match part {
+            Notation::Expr(Expr::Radical(_) | Expr::NestedRadical(_) | Expr::Trig(_)) => {
+                text.to_string()
+            }
+            part if is_written_as_atom(part, text, options) => text.to_string(),
+            _ => format!("({text})"),
+        };
+        format!(
+            "{}{}{}",
+            part(&self.num, num),
+            options.over(),
+            part(&self.den, den)
+        )
+    }
+}
+
+impl FormatWith for Power {
+    /// Parts other than atoms are written in parentheses, so that `(√2)^(√2)` isn't read as `√(2^√2)`,
+    /// unless they are written the same as an atom, such as the multiple 1π.
+    /// Exponents written as whole numbers are written the way [`FormatOptions::power`] writes them.
+    fn format_with(&self, options: &FormatOptions) -> String {
+        write_tree(&Notation::from(self.clone()), options)
+    }
+}
+
+impl Power {
+    /// Writes the power from its parts, already written as `base` and `exp`.
+    fn write(&self, base: &str, exp: &str, options: &FormatOptions) -> String {
+        let part = |part: &Notation, text: &str| match is_written_as_atom(part, text, options)
+            && !text.starts_with('-')
+        {
+            true => text.to_string(),
+            false => format!("({text})"),
+        };
+        match exp.parse::<i64>() {
+            Ok(exp) => options.power(part(&self.base, base), exp),
+            Err(_) => format!("{}^{}", part(&self.base, base), part(&self.exp, exp)),
+        }
+    }
+}
+
+impl FormatWith for Product {
+    /// Negative, compound, and fractional factors are written in parentheses,
+    /// so that `π·(-2)` isn't read as `π-2`.
+    /// When coefficients are written with the same sign as products,
+    /// numbers before other factors are too, so that `(2)*sqrt(3)` isn't read as a [`Radical`].
+    fn format_with(&self, options: &FormatOptions) -> String {
+        write_tree(&Notation::from(self.clone()), options)
+    }
+}
+
+impl Product {
+    /// Writes the product from its factors, already written in order.
+    fn write(&self, factors: Vec<String>, options: &FormatOptions) -> String {
+        let separator = options.operator(options.glyph("·", "*"));
+        let is_last = |i: usize| i + 1 == self.factors.len();
+        let factor = |(i, (factor, text)): (usize, (&Notation, String))| match factor {
+            _ if separator == options.times()
+                && !is_last(i)
+                && text.bytes().all(|c| c.is_ascii_digit()) =>
+            {
+                format!("({text})")
+            }
+            Notation::Expr(Expr::Multiple(Multiple { coef, .. })) if coef.den != 1 => {
+                format!("({text})")
+            }
+            Notation::Atom(_)
+            | Notation::Expr(
+                Expr::Radical(_)
+                | Expr::Trig(_)
+                | Expr::Multiple(_)
+                | Expr::NestedRadical(_)
+                | Expr::Power(_),
+            ) if !text.starts_with('-') => text,
+            _ => format!("({text})"),
+        };
+        let factors = self
+            .factors
+            .iter()
+            .zip(factors)
+            .enumerate()
+            .map(factor)
+            .collect::<Vec<_>>();
+        match factors.is_empty() {
+            true => "1".to_string(),
+            false => factors.join(&separator),
+        }
+    }
+}
+
+impl FormatWith for NestedRadical {
+    /// A radicand written as an integer is written the same way as a [`Radical`].
+    fn format_with(&self, options: &FormatOptions) -> String {
+        write_tree(&Notation::from(self.clone()), options)
+    }
+}
+
+impl NestedRadical {
+    /// Writes the root with a coefficient of `coef`, given the radicand already written as `rad`.
+    fn write(&self, coef: i32, rad: &str, options: &FormatOptions) -> String {
+        if let Ok(value) = rad.parse::<i32>() {
+            return Radical::from_ints(coef, value).format_with(options);
+        }
+        let root = match is_written_as_atom(&self.rad, rad, options) {
+            true => options.root(rad),
+            false => options.grouped_root(rad),
+        };
+        match coef {
+            1 => root,
+            -1 => format!("-{root}"),
+            coef => format!("{coef}{}{root}", options.times()),
+        }
+    }
+}
+
+impl FormatWith for Expr {
+    fn format_with(&self, options: &FormatOptions) -> String {
+        write_tree(&Notation::Expr(self.clone()), options)
+    }
+}
+
+impl FormatWith for Notation {
+    fn format_with(&self, options: &FormatOptions) -> String {
+        write_tree(self, options)
+    }
+}
+
+/// Notation written out as text.
+struct Written {
+    /// The text.
+    text: String,
+
+    /// For a [`NestedRadical`], how its root is written with a coefficient of 1,
+    /// which a [`Complex`] number writes after 𝑖.
+    root: Option<String>,
+}
+
+impl From<String> for Written {
+    fn from(text: String) -> Self {
+        Self { text, root: None }
+    }
+}
+
+/// Writes the notation from its leaves up, without recursing,
+/// so that notation nested too deeply for the call stack can still be written.
+fn write_tree(value: &Notation, options: &FormatOptions) -> String {
+    value
+        .fold(|node, parts: Vec<Written>| write_node(node, parts, options))
+        .text
+}
+
+/// Writes one node, given its [`children`][Notation::children] already written, in order.
+fn write_node(node: &Notation, parts: Vec<Written>, options: &FormatOptions) -> Written {
+    let expr = match node {
+        Notation::Atom(atom) => return Written::from(atom.format_with(options)),
+        Notation::Expr(expr) => expr,
+    };
+    let text = |i: usize| parts[i].text.as_str();
+    Written::from(match expr {
+        Expr::Fraction(frac) => frac.format_with(options),
+        Expr::Radical(rad) => rad.format_with(options),
+        Expr::RadicalFraction(frac) => frac.format_with(options),
+        Expr::Multiple(mult) => mult.format_with(options),
+        Expr::Trig(trig) => trig.write(text(0), options),
+        Expr::Complex(value) => value.write(text(0), &parts[1], options),
+        Expr::NestedFraction(frac) => frac.write(text(0), text(1), options),
+        Expr::Power(power) => power.write(text(0), text(1), options),
+        Expr::NestedRadical(root) => {
+            return Written {
+                text: root.write(root.coef, text(0), options),
+                root: Some(root.write(1, text(0), options)),
+            }
+        }
+        Expr::Sum(_) => Sum::write(parts.into_iter().map(|part| part.text), options),
+        Expr::Product(product) => {
+            product.write(parts.into_iter().map(|part| part.text).collect(), options)
+        }
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::expr::trig::TrigFunction;
+
+    fn samples() -> Vec<Notation> {
+        use Atom::*;
+        let mut samples: Vec<Notation> = [
+            Complex,
+            Undefined,
+            Huge,
+            NegativeHuge,
+            Epsilon,
+            NegativeEpsilon,
+            Pi,
+            E,
+        ]
+        .into_iter()
+        .map(Notation::from)
+        .collect();
+        for a in -3..=3 {
+            for b in -3..=3 {
+                samples.push(Notation::from(Fraction::new(a, b)));
+                samples.push(Notation::from(Radical { coef: a, rad: b }));
+                samples.push(Notation::from(RadicalFraction::new(a, 2, b)));
+                samples.push(Notation::from(Multiple::new(a, b, Pi)));
+                samples.push(Notation::from(super::Complex::new(
+                    a,
+                    Radical { coef: b, rad: 3 },
+                )));
+                samples.push(Notation::from(super::Complex::new(
+                    Fraction::new(a, 2),
+                    Fraction::new(b, 2),
+                )));
+            }
+        }
+        samples.push(Notation::from(Trig::new(TrigFunction::Cos, 10)));
+        samples
+    }
+
+    #[test]
+    fn test_ascii_is_ascii() {
+        for sample in samples() {
+            let text = sample.format_with(&FormatOptions::ascii());
+            assert!(text.is_ascii(), "{text} is not ASCII");
+        }
+    }
+
+    #[test]
+    fn test_radical_grid() {
+        // Each row is a coefficient from -3 to 3, and each column a radicand from -3 to 3
+        let expected = [
+            ["-3√-3", "-3√-2", "-3√-1", "-3√0", "-3", "-3√2", "-3√3"],
+            ["-2√-3", "-2√-2", "-2√-1", "-2√0", "-2", "-2√2", "-2√3"],
+            ["-√-3", "-√-2", "-√-1", "-√0", "-1", "-√2", "-√3"],
+            ["0", "0", "0", "0", "0", "0", "0"],
+            ["√-3", "√-2", "√-1", "√0", "1", "√2", "√3"],
+            ["2√-3", "2√-2", "2√-1", "2√0", "2", "2√2", "2√3"],
+            ["3√-3", "3√-2", "3√-1", "3√0", "3", "3√2", "3√3"],
+        ];
+        for (coef, row) in (-3..=3).zip(expected) {
+            for (rad, expected) in (-3..=3).zip(row) {
+                let radical = Radical { coef, rad };
+                assert_eq!(radical.to_string(), expected, "{radical:?}");
+                // The other formats write the same parts, with their own roots and products
+                let (ascii, latex) = match expected.split_once('√') {
+                    Some((scale @ ("" | "-"), rad)) => (
+                        format!("{scale}sqrt({rad})"),
+                        format!("{scale}\\sqrt{{{rad}}}"),
+                    ),
+                    Some((scale, rad)) => (
+                        format!("{scale}*sqrt({rad})"),
+                        format!("{scale}\\sqrt{{{rad}}}"),
+                    ),
+                    None => (expected.to_string(), expected.to_string()),
+                };
+                assert_eq!(radical.format_with(&FormatOptions::ascii()), ascii);
+                assert_eq!(Notation::from(radical).to_latex(), latex);
+            }
+        }
+    }
+
+    #[test]
+    fn test_ascii() {
+        let ascii = FormatOptions::ascii();
+        let format = |value: Notation| value.format_with(&ascii);
+        assert_eq!(format(Notation::from(Atom::Complex)), "complex");
+        assert_eq!(format(Notation::from(Atom::NegativeHuge)), "-huge");
+        assert_eq!(
+            format(Notation::from(RadicalFraction::new(1, 3, 2))),
+            "sqrt(3)/2"
+        );
+        assert_eq!(
+            format(Notation::from(Multiple::new(3, 4, Atom::Pi))),
+            "3*pi/4"
+        );
+        assert_eq!(
+            format(Notation::from(Trig::new(TrigFunction::Sin, 10))),
+            "sin(10deg)"
+        );
+        assert_eq!(
+            format(Notation::from(Complex::new(
+                1,
+                Radical { coef: -2, rad: 3 }
+            ))),
+            "1-2*i*sqrt(3)"
+        );
+        assert_eq!(
+            format(Notation::from(Complex::new(
+                Fraction::new(1, 2),
+                RadicalFraction::new(1, 3, 2)
+            ))),
+            "1/2+i*sqrt(3)/2"
+        );
+    }
+
+    #[test]
+    fn test_mixed_options() {
+        let options = FormatOptions::unicode()
+            .with_multiplication(Multiplication::Symbol)
+            .with_spacing(true);
+        let root = Notation::from(Radical { coef: -2, rad: 5 });
+        assert_eq!(root.format_with(&options), "-2 × √5");
+        let options = options.with_roots(RootStyle::Function);
+        assert_eq!(root.format_with(&options), "-2 × sqrt(5)");
+        let options = FormatOptions::ascii().with_roots(RootStyle::Symbol);
+        assert_eq!(root.format_with(&options), "-2*sqrt(5)");
+    }
+
+    #[test]
+    fn test_nested_fraction_groups_compound_parts() {
+        let nested = NestedFraction::new(Fraction::new(1, 2), Radical::new(3));
+        assert_eq!(nested.to_string(), "(1/2)/√3");
+        assert_eq!(nested.format_with(&FormatOptions::ascii()), "(1/2)/sqrt(3)");
+        let nested = NestedFraction::new(Atom::Pi, Radical::new(2) + Radical::from(1));
+        assert_eq!(nested.to_string(), "π/(√2+1)");
+    }
+
+    #[test]
+    fn test_roots_and_powers() {
+        let unicode = FormatOptions::default();
+        assert_eq!(unicode.nth_root(4, "x"), "⁴√x");
+        assert_eq!(unicode.nth_root(12, 7), "¹²√7");
+        assert_eq!(
+            unicode.with_roots(RootStyle::Function).nth_root(3, 2),
+            "2^(1/3)"
+        );
+        assert_eq!(
+            unicode.with_exponents(Exponents::Caret).nth_root(3, 2),
+            "2^(1/3)"
+        );
+        assert_eq!(FormatOptions::ascii().nth_root(2, 2), "sqrt(2)");
+        // ASCII never has superscripts, even if asked for
+        let ascii = FormatOptions::ascii().with_exponents(Exponents::Superscript);
+        assert_eq!(ascii.power("x", 10), "x^10");
+        assert_eq!(unicode.power("x", 0), "x⁰");
+        assert_eq!(superscript(i64::MIN), "⁻⁹²²³³⁷²⁰³⁶⁸⁵⁴⁷⁷⁵⁸⁰⁸");
+        assert_eq!(subscript(0), "₀");
+    }
+}
@@ -0,0 +1,747 @@
+//! A documented JSON encoding of notation trees, for front ends written in other languages.
+//!
+//! Unlike the `serde` feature, which encodes notation however Rust's types happen to be laid out,
+//! this schema is versioned and only changes along with [`SCHEMA_VERSION`].
+//! It needs no features or dependencies.
+//!
+//! A document is an object holding the schema version and the root node:
+//!
+//! ```json
+//! {"version":1,"root":{"type":"fraction","children":[{"type":"number","value":1},{"type":"number","value":2}]}}
+//! ```
+//!
+//! Every node is an object with a `"type"`. Atoms have no children, and expressions list theirs in order:
+//!
+//! | `type` | `value` | `children` |
+//! |---|---|---|
+//! | `number` | the integer | |
+//! | `pi`, `e`, `complex`, `undefined`, `huge`, `negative_huge`, `epsilon`, `negative_epsilon`, `unknown` | | |
+//! | `variable` | the name | |
+//! | `fraction` | | numerator, denominator (atoms) |
+//! | `radical` | | coefficient, radicand (numbers) |
+//! | `radical_fraction` | | numerator (radical), denominator (number) |
+//! | `multiple` | | coefficient (fraction), constant (atom) |
+//! | `trig` | `"sin"`, `"cos"`, or `"tan"` | the angle in degrees |
+//! | `complex_number` | | real part, imaginary part |
+//! | `sum` | | the terms |
+//! | `nested_fraction` | | numerator, denominator |
+//! | `nested_radical` | | coefficient (number), radicand |
+//! | `product` | | the factors |
+//! | `power` | | base, exponent |
+//!
+//! Reading ignores any fields not listed here, so that later versions can add fields older readers skip.
+
+use std::sync::Arc;
+
+use crate::{
+    notation::{
+        atom::Atom,
+        expr::{
+            complex::Complex,
+            fraction::Fraction,
+            multiple::Multiple,
+            nested_fraction::NestedFraction,
+            nested_radical::NestedRadical,
+            power::Power,
+            product::Product,
+            radical::Radical,
+            radical_fraction::RadicalFraction,
+            sum::Sum,
+            trig::{Trig, TrigFunction},
+            Expr,
+        },
+        Notation,
+    },
+    solve::var::Var,
+};
+
+/// The version of the schema written by [`to_json_ast`][Notation::to_json_ast],
+/// and the only version [`from_json_ast`][Notation::from_json_ast] reads.
+pub const SCHEMA_VERSION: i64 = 1;
+
+/// Reasons a JSON AST could not be read.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum JsonAstError {
+    /// The text isn't valid JSON.
+    ///
+    /// Contains the byte offset where reading failed.
+    Syntax(usize),
+
+    /// The document is for a version of the schema other than [`SCHEMA_VERSION`].
+    UnsupportedVersion(i64),
+
+    /// A required field is missing or has the wrong kind of JSON value.
+    ///
+    /// Contains the name of the field.
+    MissingField(&'static str),
+
+    /// A node has a `type` which isn't in the schema.
+    UnknownType(String),
+
+    /// A node has the wrong number or kind of children for its type.
+    ///
+    /// Contains the type of the node.
+    InvalidChildren(&'static str),
+
+    /// An integer doesn't fit in an [`i32`].
+    OutOfRange(i64),
+}
+
+impl std::fmt::Display for JsonAstError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use JsonAstError::*;
+        match self {
+            Syntax(offset) => format!("invalid JSON at byte {offset}").fmt(f),
+            UnsupportedVersion(version) => {
+                format!("schema version {version} is not supported, expected {SCHEMA_VERSION}")
+                    .fmt(f)
+            }
+            MissingField(field) => format!("missing or invalid field \"{field}\"").fmt(f),
+            UnknownType(kind) => format!("unknown node type \"{kind}\"").fmt(f),
+            InvalidChildren(kind) => format!("invalid children for a {kind} node").fmt(f),
+            OutOfRange(value) => format!("{value} does not fit in a 32-bit integer").fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for JsonAstError {}
+
+// # Writing
+
+/// The `type` of an atom.
+fn atom_type(atom: &Atom) -> &'static str {
+    use Atom::*;
+    match atom {
+        Number(_) => "number",
+        Complex => "complex",
+        Undefined => "undefined",
+        Huge => "huge",
+        NegativeHuge => "negative_huge",
+        Epsilon => "epsilon",
+        NegativeEpsilon => "negative_epsilon",
+        Unknown => "unknown",
+        Pi => "pi",
+        E => "e",
+        Variable(_) => "variable",
+    }
+}
+
+fn write_integer(value: i32) -> String {
+    format!("{{\"type\":\"number\",\"value\":{value}}}")
+}
+
+/// Writes text as a JSON string, escaping quotes, backslashes and control characters.
+fn write_string(text: &str) -> String {
+    let mut out = String::from("\"");
+    for c in text.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            c if c.is_control() => out.push_str(&format!("\\u{:04x}", c as u32)),
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+    out
+}
+
+fn write_atom(atom: &Atom) -> String {
+    match atom {
+        Atom::Number(n) => write_integer(n.value),
+        Atom::Variable(var) => format!("{{\"type\":\"variable\",\"value\":{}}}", write_string(var)),
+        atom => format!("{{\"type\":\"{}\"}}", atom_type(atom)),
+    }
+}
+
+/// Writes a node with children, and a string value if it has one.
+fn write_node(kind: &str, value: Option<&str>, children: &[String]) -> String {
+    let value = value
+        .map(|value| format!(",\"value\":\"{value}\""))
+        .unwrap_or_default();
+    format!(
+        "{{\"type\":\"{kind}\"{value},\"children\":[{}]}}",
+        children.join(",")
+    )
+}
+
+fn write_fraction(Fraction { num, den }: &Fraction) -> String {
+    write_node("fraction", None, &[write_atom(num), write_atom(den)])
+}
+
+fn write_radical(Radical { coef, rad }: &Radical) -> String {
+    write_node(
+        "radical",
+        None,
+        &[write_integer(*coef), write_integer(*rad)],
+    )
+}
+
+fn write(value: &Notation) -> String {
+    match value {
+        Notation::Atom(atom) => write_atom(atom),
+        Notation::Expr(expr) => match expr {
+            Expr::Fraction(value) => write_fraction(value),
+            Expr::Radical(value) => write_radical(value),
+            Expr::RadicalFraction(RadicalFraction { num, den }) => write_node(
+                "radical_fraction",
+                None,
+                &[write_radical(num), write_integer(*den)],
+            ),
+            Expr::Multiple(Multiple { coef, constant }) => write_node(
+                "multiple",
+                None,
+                &[write_fraction(coef), write_atom(constant)],
+            ),
+            Expr::Trig(Trig { func, arg }) => {
+                write_node("trig", Some(&func.to_string()), &[write(arg)])
+            }
+            Expr::Complex(Complex { re, im }) => {
+                write_node("complex_number", None, &[write(re), write(im)])
+            }
+            Expr::Sum(Sum { terms }) => {
+                write_node("sum", None, &terms.iter().map(write).collect::<Vec<_>>())
+            }
+            Expr::NestedFraction(NestedFraction { num, den }) => {
+                write_node("nested_fraction", None, &[write(num), write(den)])
+            }
+            Expr::NestedRadical(NestedRadical { coef, rad }) => {
+                write_node("nested_radical", None, &[write_integer(*coef), write(rad)])
+            }
+            Expr::Product(Product { factors }) => write_node(
+                "product",
+                None,
+                &factors.iter().map(write).collect::<Vec<_>>(),
+            ),
+            Expr::Power(Power { base, exp }) => {
+                write_node("power", None, &[write(base), write(exp)])
+            }
+        },
+    }
+}
+
+// # Reading
+
+/// A parsed JSON value.
+#[derive(Debug, PartialEq, Clone)]
+enum Json {
+    Null,
+    Bool(bool),
+    /// Integers are kept exact, and other numbers are only kept to be rejected where an integer is expected.
+    Integer(i64),
+    Float,
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl Json {
+    fn field(&self, name: &str) -> Option<&Json> {
+        match self {
+            Json::Object(fields) => fields
+                .iter()
+                .find(|(key, _)| key == name)
+                .map(|(_, value)| value),
+            _ => None,
+        }
+    }
+}
+
+/// A recursive descent parser over the bytes of a JSON document.
+struct Parser<'a> {
+    text: &'a [u8],
+    pos: usize,
+}
+
+impl Parser<'_> {
+    fn error<T>(&self) -> Result<T, JsonAstError> {
+        Err(JsonAstError::Syntax(self.pos))
+    }
+
+    fn skip_whitespace(&mut self) {
+        while matches!(self.text.get(self.pos), Some(b' ' | b'\t' | b'\n' | b'\r')) {
+            self.pos += 1;
+        }
+    }
+
+    fn peek(&mut self) -> Option<u8> {
+        self.skip_whitespace();
+        self.text.get(self.pos).copied()
+    }
+
+    fn expect(&mut self, byte: u8) -> Result<(), JsonAstError> {
+        match self.peek() {
+            Some(b) if b == byte => {
+                self.pos += 1;
+                Ok(())
+            }
+            _ => self.error(),
+        }
+    }
+
+    fn keyword(&mut self, word: &str, value: Json) -> Result<Json, JsonAstError> {
+        match self.text[self.pos..].starts_with(word.as_bytes()) {
+            true => {
+                self.pos += word.len();
+                Ok(value)
+            }
+            false => self.error(),
+        }
+    }
+
+    fn value(&mut self) -> Result<Json, JsonAstError> {
+        match self.peek() {
+            Some(b'{') => self.object(),
+            Some(b'[') => self.array(),
+            Some(b'"') => self.string().map(Json::String),
+            Some(b'-' | b'0'..=b'9') => self.number(),
+            Some(b't') => self.keyword("true", Json::Bool(true)),
+            Some(b'f') => self.keyword("false", Json::Bool(false)),
+            Some(b'n') => self.keyword("null", Json::Null),
+            _ => self.error(),
+        }
+    }
+
+    /// Reads a comma-separated list between `open` and `close`.
+    fn list<T>(
+        &mut self,
+        open: u8,
+        close: u8,
+        mut item: impl FnMut(&mut Self) -> Result<T, JsonAstError>,
+    ) -> Result<Vec<T>, JsonAstError> {
+        self.expect(open)?;
+        let mut items = Vec::new();
+        if self.peek() == Some(close) {
+            self.pos += 1;
+            return Ok(items);
+        }
+        loop {
+            items.push(item(self)?);
+            match self.peek() {
+                Some(b',') => self.pos += 1,
+                Some(b) if b == close => {
+                    self.pos += 1;
+                    return Ok(items);
+                }
+                _ => return self.error(),
+            }
+        }
+    }
+
+    fn object(&mut self) -> Result<Json, JsonAstError> {
+        self.list(b'{', b'}', |parser| {
+            parser.skip_whitespace();
+            let key = parser.string()?;
+            parser.expect(b':')?;
+            Ok((key, parser.value()?))
+        })
+        .map(Json::Object)
+    }
+
+    fn array(&mut self) -> Result<Json, JsonAstError> {
+        self.list(b'[', b']', Self::value).map(Json::Array)
+    }
+
+    fn number(&mut self) -> Result<Json, JsonAstError> {
+        let start = self.pos;
+        while matches!(
+            self.text.get(self.pos),
+            Some(b'-' | b'+' | b'.' | b'e' | b'E' | b'0'..=b'9')
+        ) {
+            self.pos += 1;
+        }
+        let text = std::str::from_utf8(&self.text[start..self.pos]).expect("digits are ASCII");
+        match (text.parse::<i64>(), text.parse::<f64>()) {
+            (Ok(value), _) => Ok(Json::Integer(value)),
+            (_, Ok(_)) => Ok(Json::Float),
+            _ => Err(JsonAstError::Syntax(start)),
+        }
+    }
+
+    fn hex4(&mut self) -> Result<u32, JsonAstError> {
+        let digits = self
+            .text
+            .get(self.pos..self.pos + 4)
+            .and_then(|digits| std::str::from_utf8(digits).ok())
+            .and_then(|digits| u32::from_str_radix(digits, 16).ok());
+        match digits {
+            Some(value) => {
+                self.pos += 4;
+                Ok(value)
+            }
+            None => self.error(),
+        }
+    }
+
+    fn string(&mut self) -> Result<String, JsonAstError> {
+        self.expect(b'"')?;
+        let mut bytes = Vec::new();
+        loop {
+            let Some(&byte) = self.text.get(self.pos) else {
+                return self.error();
+            };
+            self.pos += 1;
+            match byte {
+                b'"' => break,
+                b'\\' => {
+                    let Some(&escape) = self.text.get(self.pos) else {
+                        return self.error();
+                    };
+                    self.pos += 1;
+                    let c = match escape {
+                        b'"' => '"',
+                        b'\\' => '\\',
+                        b'/' => '/',
+                        b'b' => '\u{8}',
+                        b'f' => '\u{c}',
+                        b'n' => '\n',
+                        b'r' => '\r',
+                        b't' => '\t',
+                        b'u' => {
+                            let high = self.hex4()?;
+                            // Characters outside the basic plane are written as a pair of surrogates
+                            let code = if (0xD800..0xDC00).contains(&high)
+                                && self.text[self.pos..].starts_with(b"\\u")
+                            {
+                                self.pos += 2;
+                                let low = self.hex4()?;
+                                0x10000 + ((high - 0xD800) << 10) + (low.wrapping_sub(0xDC00))
+                            } else {
+                                high
+                            };
+                            match char::from_u32(code) {
+                                Some(c) => c,
+                                None => return self.error(),
+                            }
+                        }
+                        _ => return self.error(),
+                    };
+                    bytes.extend_from_slice(c.encode_utf8(&mut [0; 4]).as_bytes());
+                }
+                byte => bytes.push(byte),
+            }
+        }
+        String::from_utf8(bytes).or_else(|_| self.error())
+    }
+}
+
+fn parse(text: &str) -> Result<Json, JsonAstError> {
+    let mut parser = Parser {
+        text: text.as_bytes(),
+        pos: 0,
+    };
+    let value = parser.value()?;
+    match parser.peek() {
+        None => Ok(value),
+        Some(_) => parser.error(),
+    }
+}
+
+fn read_i32(node: &Json) -> Result<i32, JsonAstError> {
+    match (node.field("type"), node.field("value")) {
+        (Some(Json::String(kind)), Some(&Json::Integer(value))) if kind == "number" => {
+            i32::try_from(value).map_err(|_| JsonAstError::OutOfRange(value))
+        }
+        _ => Err(JsonAstError::InvalidChildren("number")),
+    }
+}
+
+/// Reads the children of a node of type `kind`, which must have exactly `COUNT` of them.
+fn children<'a, const COUNT: usize>(
+    node: &'a Json,
+    kind: &'static str,
+) -> Result<[&'a Json; COUNT], JsonAstError> {
+    match node.field("children") {
+        Some(Json::Array(children)) => children
+            .iter()
+            .collect::<Vec<_>>()
+            .try_into()
+            .map_err(|_| JsonAstError::InvalidChildren(kind)),
+        _ => Err(JsonAstError::MissingField("children")),
+    }
+}
+
+fn read_atom(node: &Json, parent: &'static str) -> Result<Atom, JsonAstError> {
+    match read(node)? {
+        Notation::Atom(atom) => Ok(atom),
+        Notation::Expr(_) => Err(JsonAstError::InvalidChildren(parent)),
+    }
+}
+
+fn read_fraction(node: &Json, parent: &'static str) -> Result<Fraction, JsonAstError> {
+    match read(node)? {
+        Notation::Expr(Expr::Fraction(value)) => Ok(value),
+        _ => Err(JsonAstError::InvalidChildren(parent)),
+    }
+}
+
+fn read_radical(node: &Json) -> Result<Radical, JsonAstError> {
+    let [coef, rad] = children(node, "radical")?;
+    Ok(Radical {
+        coef: read_i32(coef)?,
+        rad: read_i32(rad)?,
+    })
+}
+
+fn read(node: &Json) -> Result<Notation, JsonAstError> {
+    let Some(Json::String(kind)) = node.field("type") else {
+        return Err(JsonAstError::MissingField("type"));
+    };
+    let atom = |atom: Atom| Ok(Notation::from(atom));
+    match kind.as_str() {
+        "number" => match node.field("value") {
+            Some(&Json::Integer(value)) => i32::try_from(value)
+                .map(Notation::from)
+                .map_err(|_| JsonAstError::OutOfRange(value)),
+            _ => Err(JsonAstError::MissingField("value")),
+        },
+        "pi" => atom(Atom::Pi),
+        "e" => atom(Atom::E),
+        "complex" => atom(Atom::Complex),
+        "undefined" => atom(Atom::Undefined),
+        "huge" => atom(Atom::Huge),
+        "negative_huge" => atom(Atom::NegativeHuge),
+        "epsilon" => atom(Atom::Epsilon),
+        "negative_epsilon" => atom(Atom::NegativeEpsilon),
+        "unknown" => atom(Atom::Unknown),
+        "variable" => match node.field("value") {
+            Some(Json::String(name)) => atom(Atom::Variable(Var::new(name))),
+            _ => Err(JsonAstError::MissingField("value")),
+        },
+        "fraction" => {
+            let [num, den] = children(node, "fraction")?;
+            Ok(Notation::from(Fraction {
+                num: read_atom(num, "fraction")?,
+                den: read_atom(den, "fraction")?,
+            }))
+        }
+        "radical" => read_radical(node).map(Notation::from),
+        "radical_fraction" => {
+            let [num, den] = children(node, "radical_fraction")?;
+            Ok(Notation::from(RadicalFraction {
+                num: read_radical(num)?,
+                den: read_i32(den)?,
+            }))
+        }
+        "multiple" => {
+            let [coef, constant] = children(node, "multiple")?;
+            Ok(Notation::from(Multiple {
+                coef: read_fraction(coef, "multiple")?,
+                constant: read_atom(constant, "multiple")?,
+            }))
+        }
+        "trig" => {
+            let func = match node.field("value") {
+                Some(Json::String(func)) => match func.as_str() {
+                    "sin" => TrigFunction::Sin,
+                    "cos" => TrigFunction::Cos,
+                    "tan" => TrigFunction::Tan,
+                    _ => return Err(JsonAstError::MissingField("value")),
+                },
+                _ => return Err(JsonAstError::MissingField("value")),
+            };
+            let [arg] = children(node, "trig")?;
+            Ok(Notation::from(Trig {
+                func,
+                arg: Arc::new(read(arg)?),
+            }))
+        }
+        "complex_number" => {
+            let [re, im] = children(node, "complex_number")?;
+            Ok(Notation::from(Complex::new(read(re)?, read(im)?)))
+        }
+        "nested_fraction" => {
+            let [num, den] = children(node, "nested_fraction")?;
+            Ok(Notation::from(NestedFraction::new(read(num)?, read(den)?)))
+        }
+        "nested_radical" => {
+            let [coef, rad] = children(node, "nested_radical")?;
+            Ok(Notation::from(NestedRadical {
+                coef: read_i32(coef)?,
+                rad: Arc::new(read(rad)?),
+            }))
+        }
+        "sum" => match node.field("children") {
+            Some(Json::Array(terms)) => Ok(Notation::from(Sum::new(
+                terms.iter().map(read).collect::<Result<Vec<_>, _>>()?,
+            ))),
+            _ => Err(JsonAstError::MissingField("children")),
+        },
+        "product" => match node.field("children") {
+            Some(Json::Array(factors)) => Ok(Notation::from(Product::new(
+                factors.iter().map(read).collect::<Result<Vec<_>, _>>()?,
+            ))),
+            _ => Err(JsonAstError::MissingField("children")),
+        },
+        "power" => {
+            let [base, exp] = children(node, "power")?;
+            Ok(Notation::from(Power::new(read(base)?, read(exp)?)))
+        }
+        _ => Err(JsonAstError::UnknownType(kind.clone())),
+    }
+}
+
+impl Notation {
+    /// Writes the notation as a JSON document in the [schema][crate::notation::json] for [`SCHEMA_VERSION`].
+    ///
+    /// The notation is written as it is, without simplifying it.
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, expr::fraction::Fraction};
+    /// let half = Notation::from(Fraction::new(1, 2));
+    /// assert_eq!(
+    ///     half.to_json_ast(),
+    ///     r#"{"version":1,"root":{"type":"fraction","children":[{"type":"number","value":1},{"type":"number","value":2}]}}"#
+    /// );
+    /// ```
+    pub fn to_json_ast(&self) -> String {
+        format!("{{\"version\":{SCHEMA_VERSION},\"root\":{}}}", write(self))
+    }
+
+    /// Reads notation from a JSON document in the [schema][crate::notation::json],
+    /// such as one written by [`to_json_ast`][Notation::to_json_ast].
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, json::JsonAstError};
+    /// let json = r#"{"version": 1, "root": {"type": "radical", "children": [
+    ///     {"type": "number", "value": 2},
+    ///     {"type": "number", "value": 3}
+    /// ]}}"#;
+    /// assert_eq!(Notation::from_json_ast(json).unwrap().to_string(), "2√3");
+    ///
+    /// let json = r#"{"version": 2, "root": {"type": "pi"}}"#;
+    /// assert_eq!(Notation::from_json_ast(json), Err(JsonAstError::UnsupportedVersion(2)));
+    /// ```
+    pub fn from_json_ast(json: &str) -> Result<Notation, JsonAstError> {
+        let document = parse(json)?;
+        match document.field("version") {
+            Some(&Json::Integer(SCHEMA_VERSION)) => {}
+            Some(&Json::Integer(version)) => return Err(JsonAstError::UnsupportedVersion(version)),
+            _ => return Err(JsonAstError::MissingField("version")),
+        }
+        match document.field("root") {
+            Some(root) => read(root),
+            None => Err(JsonAstError::MissingField("root")),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::canonical::Canonical;
+
+    fn samples() -> Vec<Notation> {
+        use Atom::*;
+        let mut samples: Vec<Notation> = [
+            Complex,
+            Undefined,
+            Huge,
+            NegativeHuge,
+            Epsilon,
+            NegativeEpsilon,
+            Pi,
+            E,
+            Variable(Var::new("x")),
+            Variable(Var::new("a \"quoted\\name\"\n")),
+        ]
+        .into_iter()
+        .map(Notation::from)
+        .collect();
+        samples.extend([
+            Notation::from(i32::MIN),
+            Notation::from(Fraction {
+                num: Pi,
+                den: Atom::from(-3),
+            }),
+            Notation::from(Radical { coef: -2, rad: 7 }),
+            Notation::from(RadicalFraction::new(3, 5, 4)),
+            Notation::from(Multiple::new(2, 3, E)),
+            Notation::from(Trig::new(TrigFunction::Tan, Fraction::new(1, 2))),
+            Notation::from(super::Complex::new(1, Radical::new(2))),
+            Notation::from(Sum::new([
+                Notation::from(1),
+                Notation::from(Trig::new(TrigFunction::Cos, 10)),
+                Notation::from(Sum::new([])),
+            ])),
+            Notation::from(NestedFraction::new(Fraction::new(1, 2), Radical::new(3))),
+            Notation::from(NestedRadical {
+                coef: -2,
+                ..NestedRadical::new(Radical::from(1) + Radical::new(3))
+            }),
+            Notation::from(Product::new([
+                Notation::from(Pi),
+                Notation::from(Radical::new(2)),
+                Notation::from(Product::new([])),
+            ])),
+            Notation::from(Power::new(Radical::new(2), Fraction::new(1, 3))),
+        ]);
+        samples
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for value in samples() {
+            let json = value.to_json_ast();
+            assert_eq!(
+                Notation::from_json_ast(&json).map(Canonical),
+                Ok(Canonical(value)),
+                "{json}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_reads_any_valid_json() {
+        let json = " {\n\t\"extra\": [null, true, false, 1.5e3, {}],\r\n \"root\" : { \"type\" : \"trig\", \"value\" : \"sin\", \"children\" : [ {\"type\": \"number\", \"value\": 30, \"comment\": \"thirty \\\"degrees\\\" \\u00b0\"} ] }, \"version\" : 1 } ";
+        assert_eq!(
+            Notation::from_json_ast(json).map(|value| value.to_string()),
+            Ok("sin(30°)".to_string())
+        );
+        let escaped = r#"{"version":1,"root":{"type":"\u0070\u0069"}}"#;
+        assert!(Notation::from_json_ast(escaped).is_ok_and(|value| value.to_string() == "π"));
+    }
+
+    #[test]
+    fn test_errors() {
+        use JsonAstError::*;
+        let read =
+            |root: &str| Notation::from_json_ast(&format!("{{\"version\":1,\"root\":{root}}}"));
+        assert_eq!(Notation::from_json_ast(""), Err(Syntax(0)));
+        assert_eq!(Notation::from_json_ast("{\"version\":1,"), Err(Syntax(13)));
+        assert_eq!(Notation::from_json_ast("{} {}"), Err(Syntax(3)));
+        assert_eq!(
+            Notation::from_json_ast("{\"root\":{}}"),
+            Err(MissingField("version"))
+        );
+        assert_eq!(
+            Notation::from_json_ast("{\"version\":1}"),
+            Err(MissingField("root"))
+        );
+        assert_eq!(read("{}"), Err(MissingField("type")));
+        assert_eq!(
+            read(r#"{"type":"tau"}"#),
+            Err(UnknownType("tau".to_string()))
+        );
+        assert_eq!(
+            read(r#"{"type":"number","value":1.5}"#),
+            Err(MissingField("value"))
+        );
+        assert_eq!(
+            read(r#"{"type":"number","value":2147483648}"#),
+            Err(OutOfRange(2147483648))
+        );
+        assert_eq!(
+            read(r#"{"type":"fraction","children":[{"type":"pi"}]}"#),
+            Err(InvalidChildren("fraction"))
+        );
+        assert_eq!(
+            read(r#"{"type":"radical","children":[{"type":"pi"},{"type":"number","value":2}]}"#),
+            Err(InvalidChildren("number"))
+        );
+        assert_eq!(
+            read(r#"{"type":"trig","value":"sec","children":[{"type":"number","value":2}]}"#),
+            Err(MissingField("value"))
+        );
+        assert_eq!(read(r#"{"type":"sum"}"#), Err(MissingField("children")));
+    }
+}
@@ -0,0 +1,279 @@
+//! Export of notation to LaTeX math mode, for typesetting.
+
+use crate::notation::{
+    atom::{number::Number, Atom},
+    expr::{
+        complex::Complex, fraction::Fraction, multiple::Multiple, nested_fraction::NestedFraction,
+        nested_radical::NestedRadical, power::Power, product::Product, radical::Radical,
+        radical_fraction::RadicalFraction, sum::Sum, trig::Trig, Expr,
+    },
+    format::write_radical,
+    Notation,
+};
+
+/// Writes a fraction, moving a negative sign out in front of it.
+fn fraction(is_negative: bool, num: String, den: String) -> String {
+    let sign = if is_negative { "-" } else { "" };
+    format!("{sign}\\frac{{{num}}}{{{den}}}")
+}
+
+/// The magnitude of an atom, when its sign is written separately.
+fn abs(atom: &Atom) -> Atom {
+    match atom.is_negative() {
+        // The magnitude of i32::MIN doesn't fit, so it's left as it is
+        true => atom.checked_neg().unwrap_or_else(|| atom.clone()),
+        false => atom.clone(),
+    }
+}
+
+fn atom(atom: &Atom) -> String {
+    use Atom::*;
+    match atom {
+        Number(n) => n.to_string(),
+        Complex => "\\mathbb{C}".to_string(),
+        Undefined => "\\varnothing".to_string(),
+        Huge => "\\mathcal{H}".to_string(),
+        NegativeHuge => "-\\mathcal{H}".to_string(),
+        Epsilon => "\\varepsilon".to_string(),
+        NegativeEpsilon => "-\\varepsilon".to_string(),
+        Unknown => "?".to_string(),
+        Pi => "\\pi".to_string(),
+        E => "e".to_string(),
+        Variable(var) => var.to_string(),
+    }
+}
+
+/// Writes `coef × unit × √rad`, where `unit` is `i` or empty.
+fn radical_with(Radical { coef, rad }: &Radical, unit: &str) -> String {
+    write_radical(*coef, unit, *rad, "", |rad| format!("\\sqrt{{{rad}}}"))
+}
+
+fn radical(value: &Radical) -> String {
+    radical_with(value, "")
+}
+
+fn expr(value: &Expr) -> String {
+    use Atom::Number as Num;
+    match value {
+        Expr::Fraction(Fraction { num, den }) => {
+            fraction(value.is_negative(), atom(&abs(num)), atom(&abs(den)))
+        }
+        Expr::Radical(rad) => radical(rad),
+        Expr::RadicalFraction(RadicalFraction { num, den }) => fraction(
+            value.is_negative(),
+            radical(&Radical {
+                coef: num.coef.abs(),
+                rad: num.rad,
+            }),
+            den.abs().to_string(),
+        ),
+        Expr::Trig(Trig { func, arg }) => format!("\\{func}({}^\\circ)", notation(arg)),
+        Expr::Multiple(Multiple { coef, constant }) => {
+            let constant = atom(constant);
+            let scaled = |num: &Atom| match num {
+                Num(Number { value: 1 }) => constant.clone(),
+                Num(Number { value: -1 }) => format!("-{constant}"),
+                num => format!("{}{constant}", atom(num)),
+            };
+            match &coef.den {
+                Num(Number { value: 1 }) => scaled(&coef.num),
+                den => fraction(
+                    value.is_negative(),
+                    scaled(&abs(&coef.num)),
+                    atom(&abs(den)),
+                ),
+            }
+        }
+        Expr::Complex(value) => complex(value),
+        Expr::Sum(value) => sum(value),
+        Expr::NestedFraction(NestedFraction { num, den }) => {
+            format!("\\frac{{{}}}{{{}}}", notation(num), notation(den))
+        }
+        Expr::NestedRadical(NestedRadical { coef, rad }) => {
+            let root = format!("\\sqrt{{{}}}", notation(rad));
+            match coef {
+                1 => root,
+                -1 => format!("-{root}"),
+                coef => format!("{coef}{root}"),
+            }
+        }
+        Expr::Product(value) => product(value),
+        // Bases other than atoms are written in parentheses, and exponents are always grouped
+        Expr::Power(Power { base, exp }) => match base.as_ref() {
+            Notation::Atom(atom) if !atom.is_negative() => {
+                format!("{}^{{{}}}", notation(base), notation(exp))
+            }
+            base => format!("\\left({}\\right)^{{{}}}", notation(base), notation(exp)),
+        },
+    }
+}
+
+/// Writes the imaginary part with its i, such as `-2i\sqrt{3}` or `\frac{i}{2}`.
+fn imaginary(im: &Notation) -> String {
+    use Atom::Number as Num;
+    let radical_i = |value: &Radical| radical_with(value, "i");
+    let coef_i = |coef: i32| radical_i(&Radical::from(coef));
+    match im {
+        Notation::Atom(Num(Number { value })) => coef_i(*value),
+        Notation::Expr(Expr::Radical(rad)) => radical_i(rad),
+        Notation::Expr(
+            value @ Expr::Fraction(Fraction {
+                num: Num(Number { value: num }),
+                den,
+            }),
+        ) => fraction(value.is_negative(), coef_i(num.abs()), atom(&abs(den))),
+        Notation::Expr(value @ Expr::RadicalFraction(RadicalFraction { num, den })) => fraction(
+            value.is_negative(),
+            radical_i(&Radical {
+                coef: num.coef.abs(),
+                rad: num.rad,
+            }),
+            den.abs().to_string(),
+        ),
+        im @ Notation::Expr(Expr::Sum(_)) => format!("\\left({}\\right)i", notation(im)),
+        im => format!("{}i", notation(im)),
+    }
+}
+
+fn sum(Sum { terms }: &Sum) -> String {
+    let mut terms = terms.iter().map(notation);
+    let Some(mut result) = terms.next() else {
+        return "0".to_string();
+    };
+    for term in terms {
+        if !term.starts_with('-') {
+            result.push('+');
+        }
+        result += &term;
+    }
+    result
+}
+
+/// Writes the factors with dots between them, with negative factors and sums in parentheses.
+fn product(Product { factors }: &Product) -> String {
+    if factors.is_empty() {
+        return "1".to_string();
+    }
+    factors
+        .iter()
+        .map(|factor| match (factor, notation(factor)) {
+            (Notation::Expr(Expr::Sum(_) | Expr::Complex(_)), text) => {
+                format!("\\left({text}\\right)")
+            }
+            (_, text) if text.starts_with('-') => format!("\\left({text}\\right)"),
+            (_, text) => text,
+        })
+        .collect::<Vec<_>>()
+        .join(" \\cdot ")
+}
+
+fn complex(Complex { re, im }: &Complex) -> String {
+    let (re, imaginary) = (notation(re), imaginary(im));
+    // A real part of 0 isn't written
+    if re == "0" {
+        return imaginary;
+    }
+    match imaginary.strip_prefix('-') {
+        Some(magnitude) => format!("{re}-{magnitude}"),
+        None => format!("{re}+{imaginary}"),
+    }
+}
+
+fn notation(value: &Notation) -> String {
+    match value {
+        Notation::Atom(a) => atom(a),
+        Notation::Expr(e) => expr(e),
+    }
+}
+
+impl Notation {
+    /// Writes the notation as LaTeX, to be placed in math mode.
+    ///
+    /// Fractions are written with `\frac`, with any negative sign in front of them.
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, atom::Atom, expr::{multiple::Multiple, radical_fraction::RadicalFraction}};
+    /// assert_eq!(Notation::from(RadicalFraction::new(-1, 3, 2)).to_latex(), "-\\frac{\\sqrt{3}}{2}");
+    /// assert_eq!(Notation::from(Multiple::new(3, 4, Atom::Pi)).to_latex(), "\\frac{3\\pi}{4}");
+    /// ```
+    pub fn to_latex(&self) -> String {
+        notation(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::expr::trig::TrigFunction;
+
+    fn latex(value: impl Into<Notation>) -> String {
+        value.into().to_latex()
+    }
+
+    #[test]
+    fn test_atoms() {
+        assert_eq!(latex(-5), "-5");
+        assert_eq!(latex(Atom::Undefined), "\\varnothing");
+        assert_eq!(latex(Atom::NegativeEpsilon), "-\\varepsilon");
+    }
+
+    #[test]
+    fn test_exprs() {
+        assert_eq!(latex(Fraction::new(1, -2)), "-\\frac{1}{2}");
+        assert_eq!(latex(Fraction::new(-1, -2)), "\\frac{1}{2}");
+        assert_eq!(latex(Radical { coef: 2, rad: 3 }), "2\\sqrt{3}");
+        assert_eq!(latex(Radical { coef: -1, rad: 3 }), "-\\sqrt{3}");
+        assert_eq!(latex(Multiple::new(-2, 1, Atom::E)), "-2e");
+        assert_eq!(latex(Multiple::new(-1, 2, Atom::Pi)), "-\\frac{\\pi}{2}");
+        assert_eq!(
+            latex(Complex::new(3, Radical { coef: -2, rad: 5 })),
+            "3-2i\\sqrt{5}"
+        );
+        assert_eq!(latex(Complex::i()), "i");
+        assert_eq!(
+            latex(Complex::new(
+                Fraction::new(-1, 2),
+                RadicalFraction::new(-1, 3, 2)
+            )),
+            "-\\frac{1}{2}-\\frac{i\\sqrt{3}}{2}"
+        );
+        assert_eq!(
+            latex(Sum::new([
+                Fraction::new(1, 2).into(),
+                Radical { coef: -1, rad: 2 }.into()
+            ])),
+            "\\frac{1}{2}-\\sqrt{2}"
+        );
+        assert_eq!(
+            latex(Trig::new(TrigFunction::Cos, Fraction::new(1, 2))),
+            "\\cos(\\frac{1}{2}^\\circ)"
+        );
+        assert_eq!(
+            latex(NestedFraction::new(Fraction::new(1, 2), Radical::new(3))),
+            "\\frac{\\frac{1}{2}}{\\sqrt{3}}"
+        );
+        assert_eq!(
+            latex(NestedRadical {
+                coef: -2,
+                ..NestedRadical::new(Radical::from(2) + Radical::new(3))
+            }),
+            "-2\\sqrt{2+\\sqrt{3}}"
+        );
+        assert_eq!(
+            latex(Product::new([
+                Atom::Pi.into(),
+                (-2).into(),
+                Sum::new([1.into(), Radical::new(2).into()]).into()
+            ])),
+            "\\pi \\cdot \\left(-2\\right) \\cdot \\left(1+\\sqrt{2}\\right)"
+        );
+        assert_eq!(
+            latex(Power::new(Atom::Pi, Fraction::new(1, 3))),
+            "\\pi^{\\frac{1}{3}}"
+        );
+        assert_eq!(
+            latex(Power::new(Radical::new(2), Radical::new(2))),
+            "\\left(\\sqrt{2}\\right)^{\\sqrt{2}}"
+        );
+    }
+}
@@ -0,0 +1,362 @@
+//! Arithmetic which builds up an expression, and only computes it once simplified.
+
+use crate::notation::{
+    atom::Atom,
+    expr::simplify::Simplify,
+    format::{FormatOptions, FormatWith},
+    ops::{gcd, is_sum, ratio, rational},
+    Notation,
+};
+
+/// Arithmetic on notation which isn't computed until it is [simplified][Simplify::simplify].
+///
+/// Operators on [`Notation`] compute their result right away, so a value too large for an [`i32`]
+/// becomes [`Huge`][Atom::Huge] partway through a calculation even if later steps would bring it back in range.
+/// Operators on `Lazy` instead build up a tree of sums, products, and powers,
+/// and simplifying computes the whole tree at once, keeping rational values exact until the result.
+///
+/// ```
+/// # use algebra::notation::{Notation, lazy::Lazy, expr::simplify::Simplify};
+/// let eager = Notation::from(i32::MAX) * Notation::from(2) / Notation::from(4);
+/// assert!(eager.atom().is_some_and(|x| x.is_huge()));
+///
+/// let lazy = Lazy::from(i32::MAX) * 2 / 4;
+/// assert_eq!(lazy.to_string(), "2147483647 × 2 × 4⁻¹");
+/// assert_eq!(lazy.simplify().to_string(), "2147483647/2");
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub enum Lazy {
+    /// A value which is already known.
+    Value(Notation),
+
+    /// Terms added together.
+    Sum(Vec<Lazy>),
+
+    /// Factors multiplied together.
+    Product(Vec<Lazy>),
+
+    /// A base raised to an exponent.
+    Power(Box<Lazy>, Box<Lazy>),
+}
+
+impl From<Notation> for Lazy {
+    fn from(value: Notation) -> Self {
+        Self::Value(value)
+    }
+}
+
+impl From<i32> for Lazy {
+    fn from(value: i32) -> Self {
+        Self::Value(Notation::from(value))
+    }
+}
+
+impl Notation {
+    /// Starts arithmetic which isn't computed until it is simplified. See [`Lazy`].
+    pub fn lazy(self) -> Lazy {
+        Lazy::from(self)
+    }
+}
+
+impl Lazy {
+    /// Raises this to the power of `exp`, without computing it.
+    pub fn pow(self, exp: impl Into<Lazy>) -> Self {
+        Self::Power(Box::new(self), Box::new(exp.into()))
+    }
+
+    /// Computes the value, keeping rationals exact.
+    fn eval(self) -> Value {
+        match self {
+            Lazy::Value(value) => Value::from(value.simplify()),
+            Lazy::Sum(terms) => {
+                let mut total = Value::Exact(0, 1);
+                for term in terms {
+                    total = total.add(term.eval());
+                }
+                total
+            }
+            Lazy::Product(factors) => {
+                let mut total = Value::Exact(1, 1);
+                for factor in factors {
+                    total = total.mul(factor.eval());
+                }
+                total
+            }
+            Lazy::Power(base, exp) => base.eval().pow(exp.eval()),
+        }
+    }
+}
+
+/// A partly computed value.
+enum Value {
+    /// A ratio which may be too large for an [`i32`], and isn't necessarily reduced.
+    Exact(i128, i128),
+
+    /// Anything else, along with a rational part which hasn't been added to it yet.
+    Inexact(Notation, (i128, i128)),
+}
+
+impl From<Notation> for Value {
+    fn from(value: Notation) -> Self {
+        match ratio(&value) {
+            Some((num, den)) => Value::Exact(num as i128, den as i128),
+            None => Value::Inexact(value, (0, 1)),
+        }
+    }
+}
+
+/// Reduces a ratio, or returns [`None`] if computing it overflowed.
+fn reduced(num: Option<i128>, den: Option<i128>) -> Option<(i128, i128)> {
+    let (num, den) = (num?, den?);
+    let divisor = gcd(num, den).max(1);
+    Some((num / divisor, den / divisor))
+}
+
+fn add_exact((a, b): (i128, i128), (c, d): (i128, i128)) -> Option<(i128, i128)> {
+    let num = a
+        .checked_mul(d)
+        .and_then(|ad| c.checked_mul(b).and_then(|cb| ad.checked_add(cb)));
+    reduced(num, b.checked_mul(d))
+}
+
+fn mul_exact((a, b): (i128, i128), (c, d): (i128, i128)) -> Option<(i128, i128)> {
+    let (a, d) = reduced(Some(a), Some(d))?;
+    let (c, b) = reduced(Some(c), Some(b))?;
+    reduced(a.checked_mul(c), b.checked_mul(d))
+}
+
+impl Value {
+    /// Finishes computing the value, saturating it only now if it is too large.
+    fn into_notation(self) -> Notation {
+        match self {
+            Value::Exact(num, den) => rational(num, den),
+            Value::Inexact(value, (0, den)) if den != 0 => value,
+            Value::Inexact(value, (num, den)) => value + rational(num, den),
+        }
+    }
+
+    fn add(self, rhs: Self) -> Self {
+        match (self, rhs) {
+            (Value::Exact(a, b), Value::Exact(c, d)) => match add_exact((a, b), (c, d)) {
+                Some((num, den)) => Value::Exact(num, den),
+                None => Value::Inexact(rational(a, b) + rational(c, d), (0, 1)),
+            },
+            (Value::Inexact(value, part), Value::Exact(num, den))
+            | (Value::Exact(num, den), Value::Inexact(value, part)) => {
+                match add_exact(part, (num, den)) {
+                    Some(part) => Value::Inexact(value, part),
+                    None => Value::Inexact(
+                        value + rational(part.0, part.1) + rational(num, den),
+                        (0, 1),
+                    ),
+                }
+            }
+            (lhs, rhs) => Value::from(lhs.into_notation() + rhs.into_notation()),
+        }
+    }
+
+    fn mul(self, rhs: Self) -> Self {
+        match (self, rhs) {
+            (Value::Exact(a, b), Value::Exact(c, d)) => match mul_exact((a, b), (c, d)) {
+                Some((num, den)) => Value::Exact(num, den),
+                None => Value::from(rational(a, b) * rational(c, d)),
+            },
+            (lhs, rhs) => Value::from(lhs.into_notation() * rhs.into_notation()),
+        }
+    }
+
+    fn pow(self, exp: Self) -> Self {
+        if let (Value::Exact(num, den), Value::Exact(exp, 1)) = (&self, &exp) {
+            let (num, den) = match exp {
+                0.. => (*num, *den),
+                _ => (*den, *num),
+            };
+            let exact = u32::try_from(exp.unsigned_abs())
+                .ok()
+                .and_then(|exp| reduced(num.checked_pow(exp), den.checked_pow(exp)));
+            if let Some((num, den)) = exact {
+                return Value::Exact(num, den);
+            }
+        }
+        Value::from(self.into_notation().pow(exp.into_notation()))
+    }
+}
+
+impl Simplify for Lazy {
+    fn simplify(self) -> Notation {
+        self.eval().into_notation()
+    }
+}
+
+impl<T: Into<Lazy>> std::ops::Add<T> for Lazy {
+    type Output = Self;
+
+    /// Add two values, without computing the sum.
+    fn add(self, rhs: T) -> Self::Output {
+        match self {
+            Lazy::Sum(mut terms) => {
+                terms.push(rhs.into());
+                Lazy::Sum(terms)
+            }
+            lhs => Lazy::Sum(vec![lhs, rhs.into()]),
+        }
+    }
+}
+
+impl std::ops::Neg for Lazy {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Lazy::from(-1) * self
+    }
+}
+
+impl<T: Into<Lazy>> std::ops::Sub<T> for Lazy {
+    type Output = Self;
+
+    /// Subtract two values, without computing the difference.
+    fn sub(self, rhs: T) -> Self::Output {
+        self + -rhs.into()
+    }
+}
+
+impl<T: Into<Lazy>> std::ops::Mul<T> for Lazy {
+    type Output = Self;
+
+    /// Multiply two values, without computing the product.
+    fn mul(self, rhs: T) -> Self::Output {
+        match self {
+            Lazy::Product(mut factors) => {
+                factors.push(rhs.into());
+                Lazy::Product(factors)
+            }
+            lhs => Lazy::Product(vec![lhs, rhs.into()]),
+        }
+    }
+}
+
+impl<T: Into<Lazy>> std::ops::Div<T> for Lazy {
+    type Output = Self;
+
+    /// Divide two values, without computing the quotient.
+    fn div(self, rhs: T) -> Self::Output {
+        self * rhs.into().pow(-1)
+    }
+}
+
+impl FormatWith for Lazy {
+    fn format_with(&self, options: &FormatOptions) -> String {
+        // Children which would be ambiguous without parentheses
+        let grouped = |child: &Lazy, in_power: bool| match child {
+            Lazy::Value(value) if !is_sum(value) => child.format_with(options),
+            Lazy::Power(..) if !in_power => child.format_with(options),
+            Lazy::Product(_) if !in_power => child.format_with(options),
+            _ => format!("({})", child.format_with(options)),
+        };
+        match self {
+            Lazy::Value(value) => value.format_with(options),
+            Lazy::Sum(terms) => terms
+                .iter()
+                .map(|term| match term {
+                    Lazy::Sum(_) => format!("({})", term.format_with(options)),
+                    _ => term.format_with(options),
+                })
+                .collect::<Vec<_>>()
+                .join(" + "),
+            Lazy::Product(factors) => factors
+                .iter()
+                .map(|factor| match factor {
+                    Lazy::Product(_) => format!("({})", factor.format_with(options)),
+                    _ => grouped(factor, false),
+                })
+                .collect::<Vec<_>>()
+                .join(&format!(" {} ", options.glyph("×", "*"))),
+            Lazy::Power(base, exp) => match exp.as_ref() {
+                Lazy::Value(Notation::Atom(Atom::Number(n))) => {
+                    options.power(grouped(base, true), n.value as i64)
+                }
+                _ => format!("{}^{}", grouped(base, true), grouped(exp, true)),
+            },
+        }
+    }
+}
+
+impl std::fmt::Display for Lazy {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.format_with(&FormatOptions::default()).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::expr::{fraction::Fraction, radical::Radical};
+
+    #[test]
+    fn test_defers_overflow() {
+        let max = || Lazy::from(i32::MAX);
+        assert_eq!((max() + 1 - 1).simplify(), i32::MAX);
+        assert_eq!((max() * 4 / 8).simplify(), Fraction::new(i32::MAX, 2));
+        assert_eq!(
+            (Lazy::from(2).pow(40) / Lazy::from(2).pow(38)).simplify(),
+            4
+        );
+        assert_eq!((Lazy::from(i32::MIN) - 1 + 2).simplify(), i32::MIN + 1);
+    }
+
+    #[test]
+    fn test_saturates_result() {
+        assert!((Lazy::from(i32::MAX) + 1)
+            .simplify()
+            .atom()
+            .is_some_and(|x| x.is_huge()));
+        assert!((Lazy::from(1) / Lazy::from(i32::MAX).pow(2))
+            .simplify()
+            .atom()
+            .is_some_and(|x| x.is_epsilon()));
+    }
+
+    #[test]
+    fn test_undefined() {
+        let quotient = Lazy::from(1) / 0;
+        assert_eq!(quotient.to_string(), "1 × 0⁻¹");
+        assert!(quotient.simplify().atom().is_some_and(|x| x.is_undefined()));
+        assert!((Lazy::from(1) / 0 + 5)
+            .simplify()
+            .atom()
+            .is_some_and(|x| x.is_undefined()));
+    }
+
+    #[test]
+    fn test_irrational() {
+        let root = || Notation::from(Radical::new(2)).lazy();
+        assert_eq!((root() + 1 + 1).simplify().to_string(), "√2+2");
+        assert_eq!((root() * root() * 3).simplify(), 6);
+        assert_eq!((root() - root()).simplify(), 0);
+    }
+
+    #[test]
+    fn test_display() {
+        let value = (Lazy::from(2) + 3) * 4;
+        assert_eq!(value.to_string(), "(2 + 3) × 4");
+        assert_eq!(value.simplify(), 20);
+        assert_eq!(
+            (Lazy::from(2) * 3).pow(Lazy::from(1) + 1).to_string(),
+            "(2 × 3)^(1 + 1)"
+        );
+        assert_eq!((-Lazy::from(5)).to_string(), "-1 × 5");
+    }
+
+    #[test]
+    fn test_exponents() {
+        use crate::notation::format::Exponents;
+        let value = Lazy::from(2).pow(5) * (Lazy::from(1) + 2).pow(-2);
+        assert_eq!(value.to_string(), "2⁵ × (1 + 2)⁻²");
+        let caret = FormatOptions::default().with_exponents(Exponents::Caret);
+        assert_eq!(value.format_with(&caret), "2^5 × (1 + 2)^-2");
+        assert_eq!(
+            value.format_with(&FormatOptions::ascii()),
+            "2^5 * (1 + 2)^-2"
+        );
+    }
+}
@@ -0,0 +1,231 @@
+//! Export of notation to Content MathML, for exchanging expressions with computer algebra systems.
+//!
+//! Content MathML describes what an expression means rather than how it looks,
+//! so each node becomes the operation it stands for:
+//! a fraction is a `divide`, a radical a `root`, and a sum a `plus`.
+//! Complex numbers with integer parts are written as `cn` elements of type `complex-cartesian`.
+//!
+//! Angles are stored in degrees, but MathML's trigonometric functions take radians,
+//! so the angle is multiplied by π/180.
+//!
+//! [`Huge`][Atom::Huge], [`Epsilon`][Atom::Epsilon], [`Unknown`][Atom::Unknown] and the [`Complex`][Atom::Complex] atom
+//! have no MathML equivalent,
+//! and are written as `csymbol` elements in the `algebra` content dictionary.
+
+use crate::notation::{
+    atom::Atom,
+    expr::{
+        complex::Complex, fraction::Fraction, multiple::Multiple, nested_fraction::NestedFraction,
+        nested_radical::NestedRadical, power::Power, product::Product, radical::Radical,
+        radical_fraction::RadicalFraction, sum::Sum, trig::Trig, Expr,
+    },
+    Notation,
+};
+
+/// Applies the empty element `op` to the arguments.
+fn apply(op: &str, args: &[String]) -> String {
+    format!("<apply><{op}/>{}</apply>", args.concat())
+}
+
+fn integer(n: i32) -> String {
+    format!("<cn type=\"integer\">{n}</cn>")
+}
+
+fn symbol(name: &str) -> String {
+    format!("<csymbol cd=\"algebra\">{name}</csymbol>")
+}
+
+fn atom(atom: &Atom) -> String {
+    use Atom::*;
+    match atom {
+        Number(n) => integer(n.value),
+        Complex => symbol("complex"),
+        Undefined => "<notanumber/>".to_string(),
+        Huge => symbol("huge"),
+        NegativeHuge => apply("minus", &[symbol("huge")]),
+        Epsilon => symbol("epsilon"),
+        NegativeEpsilon => apply("minus", &[symbol("epsilon")]),
+        Unknown => symbol("unknown"),
+        Pi => "<pi/>".to_string(),
+        E => "<exponentiale/>".to_string(),
+        Variable(var) => format!("<ci>{var}</ci>"),
+    }
+}
+
+fn fraction(Fraction { num, den }: &Fraction) -> String {
+    apply("divide", &[atom(num), atom(den)])
+}
+
+fn radical(Radical { coef, rad }: &Radical) -> String {
+    let root = apply("root", &[integer(*rad)]);
+    match coef {
+        1 => root,
+        coef => apply("times", &[integer(*coef), root]),
+    }
+}
+
+fn expr(value: &Expr) -> String {
+    match value {
+        Expr::Fraction(value) => fraction(value),
+        Expr::Radical(value) => radical(value),
+        Expr::RadicalFraction(RadicalFraction { num, den }) => {
+            apply("divide", &[radical(num), integer(*den)])
+        }
+        Expr::Multiple(Multiple { coef, constant }) => {
+            apply("times", &[fraction(coef), atom(constant)])
+        }
+        Expr::Trig(Trig { func, arg }) => {
+            let radians = apply(
+                "times",
+                &[
+                    content(arg),
+                    apply("divide", &["<pi/>".to_string(), integer(180)]),
+                ],
+            );
+            apply(&func.to_string(), &[radians])
+        }
+        Expr::Complex(Complex { re, im }) => match (re.as_ref(), im.as_ref()) {
+            (Notation::Atom(Atom::Number(re)), Notation::Atom(Atom::Number(im))) => {
+                format!("<cn type=\"complex-cartesian\">{re}<sep/>{im}</cn>")
+            }
+            (re, im) => apply(
+                "plus",
+                &[
+                    content(re),
+                    apply("times", &[content(im), "<imaginaryi/>".to_string()]),
+                ],
+            ),
+        },
+        Expr::Sum(Sum { terms }) => match terms.as_ref() {
+            [] => integer(0),
+            terms => apply("plus", &terms.iter().map(content).collect::<Vec<_>>()),
+        },
+        Expr::NestedFraction(NestedFraction { num, den }) => {
+            apply("divide", &[content(num), content(den)])
+        }
+        Expr::NestedRadical(NestedRadical { coef, rad }) => {
+            let root = apply("root", &[content(rad)]);
+            match coef {
+                1 => root,
+                coef => apply("times", &[integer(*coef), root]),
+            }
+        }
+        Expr::Product(Product { factors }) => match factors.as_ref() {
+            [] => integer(1),
+            factors => apply("times", &factors.iter().map(content).collect::<Vec<_>>()),
+        },
+        Expr::Power(Power { base, exp }) => apply("power", &[content(base), content(exp)]),
+    }
+}
+
+fn content(value: &Notation) -> String {
+    match value {
+        Notation::Atom(value) => atom(value),
+        Notation::Expr(value) => expr(value),
+    }
+}
+
+impl Notation {
+    /// Writes the notation as a Content MathML `math` element, without simplifying it.
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, expr::fraction::Fraction};
+    /// let half = Notation::from(Fraction::new(1, 2));
+    /// assert_eq!(
+    ///     half.to_content_mathml(),
+    ///     r#"<math xmlns="http://www.w3.org/1998/Math/MathML"><apply><divide/><cn type="integer">1</cn><cn type="integer">2</cn></apply></math>"#
+    /// );
+    /// ```
+    pub fn to_content_mathml(&self) -> String {
+        format!(
+            "<math xmlns=\"http://www.w3.org/1998/Math/MathML\">{}</math>",
+            content(self)
+        )
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::expr::trig::TrigFunction;
+
+    /// The content of the `math` element.
+    fn mathml(value: impl Into<Notation>) -> String {
+        let text = value.into().to_content_mathml();
+        text.strip_prefix("<math xmlns=\"http://www.w3.org/1998/Math/MathML\">")
+            .and_then(|text| text.strip_suffix("</math>"))
+            .expect("should be wrapped in a math element")
+            .to_string()
+    }
+
+    #[test]
+    fn test_atoms() {
+        assert_eq!(mathml(-3), "<cn type=\"integer\">-3</cn>");
+        assert_eq!(mathml(Atom::Pi), "<pi/>");
+        assert_eq!(mathml(Atom::E), "<exponentiale/>");
+        assert_eq!(mathml(Atom::Complex), symbol("complex"));
+        assert_eq!(mathml(Atom::Undefined), "<notanumber/>");
+        assert_eq!(mathml(Atom::Huge), "<csymbol cd=\"algebra\">huge</csymbol>");
+        assert_eq!(
+            mathml(Atom::NegativeEpsilon),
+            "<apply><minus/><csymbol cd=\"algebra\">epsilon</csymbol></apply>"
+        );
+    }
+
+    #[test]
+    fn test_radicals() {
+        assert_eq!(
+            mathml(Radical::new(2)),
+            "<apply><root/><cn type=\"integer\">2</cn></apply>"
+        );
+        assert_eq!(
+            mathml(RadicalFraction::new(3, 5, 4)),
+            "<apply><divide/><apply><times/><cn type=\"integer\">3</cn><apply><root/><cn type=\"integer\">5</cn></apply></apply><cn type=\"integer\">4</cn></apply>"
+        );
+    }
+
+    #[test]
+    fn test_expressions() {
+        assert_eq!(
+            mathml(Multiple::new(2, 3, Atom::Pi)),
+            "<apply><times/><apply><divide/><cn type=\"integer\">2</cn><cn type=\"integer\">3</cn></apply><pi/></apply>"
+        );
+        assert_eq!(
+            mathml(Trig::new(TrigFunction::Sin, 30)),
+            "<apply><sin/><apply><times/><cn type=\"integer\">30</cn><apply><divide/><pi/><cn type=\"integer\">180</cn></apply></apply></apply>"
+        );
+        assert_eq!(
+            mathml(Sum::new([Notation::from(1), Notation::from(Atom::E)])),
+            "<apply><plus/><cn type=\"integer\">1</cn><exponentiale/></apply>"
+        );
+        assert_eq!(mathml(Sum::new([])), "<cn type=\"integer\">0</cn>");
+        assert_eq!(
+            mathml(Product::new([
+                Notation::from(Atom::Pi),
+                Notation::from(Atom::E)
+            ])),
+            "<apply><times/><pi/><exponentiale/></apply>"
+        );
+        assert_eq!(mathml(Product::new([])), "<cn type=\"integer\">1</cn>");
+        assert_eq!(
+            mathml(Power::new(2, Atom::Pi)),
+            "<apply><power/><cn type=\"integer\">2</cn><pi/></apply>"
+        );
+        assert_eq!(
+            mathml(NestedFraction::new(1, Radical::new(2))),
+            "<apply><divide/><cn type=\"integer\">1</cn><apply><root/><cn type=\"integer\">2</cn></apply></apply>"
+        );
+    }
+
+    #[test]
+    fn test_complex() {
+        assert_eq!(
+            mathml(Complex::new(1, -2)),
+            "<cn type=\"complex-cartesian\">1<sep/>-2</cn>"
+        );
+        assert_eq!(
+            mathml(Complex::new(1, Radical::new(2))),
+            "<apply><plus/><cn type=\"integer\">1</cn><apply><times/><apply><root/><cn type=\"integer\">2</cn></apply><imaginaryi/></apply></apply>"
+        );
+    }
+}
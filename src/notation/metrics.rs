@@ -0,0 +1,235 @@
+//! Measures of how large and how complicated notation is.
+
+use crate::notation::{
+    atom::Atom,
+    expr::{
+        complex::Complex, fraction::Fraction, multiple::Multiple, nested_fraction::NestedFraction,
+        nested_radical::NestedRadical, power::Power, product::Product, radical::Radical,
+        radical_fraction::RadicalFraction, sum::Sum, trig::Trig, Expr,
+    },
+    Notation,
+};
+
+/// How much more complex a trig function is than its argument.
+///
+/// Higher than any small exact value, so that exact values are preferred over unevaluated functions.
+const TRIG_COMPLEXITY: u32 = 12;
+
+/// The complexity of an integer: its number of bits, plus one for a negative sign.
+fn integer_complexity(n: i32) -> u32 {
+    let sign = if n < 0 { 1 } else { 0 };
+    1 + sign + (u32::BITS - n.unsigned_abs().leading_zeros())
+}
+
+/// The complexity of an integer multiplying something else, which isn't written when it is 1.
+fn coefficient_complexity(n: i32) -> u32 {
+    match n {
+        1 => 0,
+        -1 => 1,
+        n => integer_complexity(n),
+    }
+}
+
+fn atom_complexity(atom: &Atom) -> u32 {
+    use Atom::*;
+    match atom {
+        Number(n) => integer_complexity(n.value),
+        Complex | Undefined | Unknown | Huge | Epsilon | Pi | E | Variable(_) => 2,
+        NegativeHuge | NegativeEpsilon => 3,
+    }
+}
+
+fn fraction_complexity(Fraction { num, den }: &Fraction) -> u32 {
+    1 + atom_complexity(num) + atom_complexity(den)
+}
+
+/// The complexity of a radical.
+///
+/// The radicand counts twice as much as the coefficient, so that moving a square factor out from under the radical,
+/// as in √12 = 2√3, makes it simpler.
+fn radical_complexity(Radical { coef, rad }: &Radical) -> u32 {
+    match rad {
+        1 => integer_complexity(*coef),
+        rad => 1 + coefficient_complexity(*coef) + 2 * integer_complexity(*rad),
+    }
+}
+
+impl Notation {
+    /// Returns the number of nodes in the notation's tree, counting each atom and integer it holds.
+    ///
+    /// Matches the number of nodes drawn by [`to_dot`][Notation::to_dot].
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, expr::{fraction::Fraction, trig::{Trig, TrigFunction}}};
+    /// assert_eq!(Notation::from(5).node_count(), 1);
+    /// assert_eq!(Notation::from(Fraction::new(1, 2)).node_count(), 3);
+    /// assert_eq!(Notation::from(Trig::new(TrigFunction::Sin, Fraction::new(1, 2))).node_count(), 4);
+    /// ```
+    pub fn node_count(&self) -> usize {
+        use Expr::*;
+        self.fold(|node, children: Vec<usize>| {
+            let own = match node {
+                Notation::Atom(_) => 1,
+                Notation::Expr(expr) => match expr {
+                    Fraction(_) | Radical(_) => 3,
+                    RadicalFraction(_) | Multiple(_) => 5,
+                    Trig(_) | Complex(_) | Sum(_) | Product(_) | Power(_) | NestedFraction(_) => 1,
+                    NestedRadical(_) => 2,
+                },
+            };
+            own + children.into_iter().sum::<usize>()
+        })
+    }
+
+    /// Returns the number of levels in the notation's tree. An atom has a depth of 1.
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, expr::{radical_fraction::RadicalFraction, trig::{Trig, TrigFunction}}};
+    /// assert_eq!(Notation::from(5).depth(), 1);
+    /// assert_eq!(Notation::from(RadicalFraction::new(1, 3, 2)).depth(), 3);
+    /// assert_eq!(Notation::from(Trig::new(TrigFunction::Cos, 30)).depth(), 2);
+    /// ```
+    pub fn depth(&self) -> usize {
+        use Expr::*;
+        self.fold(|node, children: Vec<usize>| match node {
+            Notation::Atom(_) => 1,
+            Notation::Expr(expr) => match expr {
+                Fraction(_) | Radical(_) => 2,
+                RadicalFraction(_) | Multiple(_) => 3,
+                Trig(_) | Complex(_) | Sum(_) | Product(_) | Power(_) | NestedFraction(_)
+                | NestedRadical(_) => 1 + children.into_iter().max().unwrap_or(0),
+            },
+        })
+    }
+
+    /// Scores how complicated the notation is to read, for choosing the simplest of equivalent forms.
+    ///
+    /// Every node adds to the score, integers add more the larger they are,
+    /// and trig functions add more than any small exact value they could be replaced by.
+    /// Parts which aren't written, such as a coefficient of 1, add nothing.
+    /// The scale has no meaning beyond comparing two scores: lower is simpler.
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, expr::{fraction::Fraction, radical::Radical, radical_fraction::RadicalFraction, trig::{Trig, TrigFunction}}};
+    /// let complexity = |value: Notation| value.complexity();
+    /// assert!(complexity(Fraction::new(1, 2).into()) < complexity(Fraction::new(2, 4).into()));
+    /// assert!(complexity(Radical { coef: 2, rad: 2 }.into()) < complexity(Radical::new(8).into()));
+    /// assert!(
+    ///     complexity(RadicalFraction::new(1, 3, 2).into())
+    ///         < complexity(Trig::new(TrigFunction::Sin, 60).into())
+    /// );
+    /// ```
+    pub fn complexity(&self) -> u32 {
+        match self {
+            Notation::Atom(atom) => atom_complexity(atom),
+            Notation::Expr(expr) => match expr {
+                Expr::Fraction(frac) => fraction_complexity(frac),
+                Expr::Radical(rad) => radical_complexity(rad),
+                Expr::RadicalFraction(RadicalFraction { num, den }) => {
+                    1 + radical_complexity(num) + integer_complexity(*den)
+                }
+                Expr::Trig(Trig { arg, .. }) => TRIG_COMPLEXITY + arg.complexity(),
+                Expr::Multiple(Multiple { coef, constant }) => {
+                    let num = match &coef.num {
+                        Atom::Number(n) => coefficient_complexity(n.value),
+                        num => atom_complexity(num),
+                    };
+                    let den = match &coef.den {
+                        Atom::Number(n) if n.value == 1 => 0,
+                        den => atom_complexity(den),
+                    };
+                    1 + num + den + atom_complexity(constant)
+                }
+                Expr::Complex(Complex { re, im }) => {
+                    // A real part of 0 isn't written
+                    let re = if **re == 0 { 0 } else { re.complexity() };
+                    1 + re + im.complexity()
+                }
+                Expr::Sum(Sum { terms }) => 1 + terms.iter().map(Notation::complexity).sum::<u32>(),
+                Expr::NestedFraction(NestedFraction { num, den }) => {
+                    1 + num.complexity() + den.complexity()
+                }
+                Expr::NestedRadical(NestedRadical { coef, rad }) => {
+                    1 + coefficient_complexity(*coef) + rad.complexity()
+                }
+                Expr::Product(Product { factors }) => {
+                    1 + factors.iter().map(Notation::complexity).sum::<u32>()
+                }
+                Expr::Power(Power { base, exp }) => 1 + base.complexity() + exp.complexity(),
+            },
+        }
+    }
+
+    /// Returns whichever of two equivalent notations is simpler by [`complexity`][Notation::complexity],
+    /// preferring `self` when they are equally complex.
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, expr::fraction::Fraction};
+    /// let half = Notation::from(Fraction::new(1, 2));
+    /// assert_eq!(Notation::from(Fraction::new(3, 6)).simpler(half.clone()), half);
+    /// ```
+    pub fn simpler(self, other: Self) -> Self {
+        if other.complexity() < self.complexity() {
+            other
+        } else {
+            self
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::expr::{simplify::Simplify, trig::TrigFunction};
+
+    #[test]
+    fn test_integer_complexity() {
+        assert_eq!(integer_complexity(0), 1);
+        assert_eq!(integer_complexity(1), 2);
+        assert_eq!(integer_complexity(-1), 3);
+        assert_eq!(integer_complexity(i32::MIN), 34);
+    }
+
+    #[test]
+    fn test_counts_match_dot() {
+        let value = Notation::from(Trig::new(
+            TrigFunction::Tan,
+            Trig::new(TrigFunction::Sin, Multiple::new(1, 2, Atom::Pi)),
+        ));
+        assert_eq!(value.node_count(), 7);
+        assert_eq!(value.depth(), 5);
+        assert_eq!(value.to_dot().matches("[label=").count() - 6, 7);
+    }
+
+    #[test]
+    fn test_simplify_never_more_complex() {
+        let mut samples = Vec::new();
+        for a in -12..=12 {
+            for b in -12..=12 {
+                samples.push(Notation::from(Fraction::new(a, b)));
+                samples.push(Notation::from(Radical { coef: a, rad: b }));
+                samples.push(Notation::from(RadicalFraction::new(a, 12, b)));
+                samples.push(Notation::from(RadicalFraction::new(a, b, 6)));
+                samples.push(Notation::from(Multiple::new(a, b, Atom::Pi)));
+                samples.push(Notation::from(Fraction {
+                    num: Atom::E,
+                    den: Atom::from(b),
+                }));
+            }
+        }
+        for degrees in (-360..=360).step_by(15) {
+            samples.push(Notation::from(Trig::new(TrigFunction::Sin, degrees)));
+            samples.push(Notation::from(Trig::new(TrigFunction::Tan, degrees)));
+        }
+        for value in samples {
+            let Notation::Expr(expr) = value.clone() else {
+                unreachable!()
+            };
+            let simplified = expr.simplify();
+            assert!(
+                simplified.complexity() <= value.complexity(),
+                "{value} simplified to the more complex {simplified}"
+            );
+        }
+    }
+}
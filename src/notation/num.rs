@@ -0,0 +1,13 @@
+//! Interoperation with the [`num`](https://docs.rs/num) crates, so that notation can be used by generic numeric code.
+//!
+//! Each crate is enabled by the feature of the same name:
+//! - `num-traits` implements `Zero`, `One`, `Num` and `Signed` for [`Notation`][crate::notation::Notation].
+//! - `num-rational` converts between [`Fraction`][crate::notation::expr::fraction::Fraction] and `Ratio<i32>`.
+//! - `num-complex` converts between [`Complex`][crate::notation::expr::complex::Complex] and `num_complex::Complex<i32>`.
+
+#[cfg(feature = "num-complex")]
+mod complex;
+#[cfg(feature = "num-rational")]
+mod rational;
+#[cfg(feature = "num-traits")]
+mod traits;
@@ -0,0 +1,102 @@
+//! Conversions between complex numbers and [`num_complex::Complex`]es of integers.
+
+use crate::notation::{
+    atom::Atom,
+    expr::{complex::Complex, simplify::Simplify, Expr},
+    ConversionError, Notation,
+};
+use num_complex::Complex as NumComplex;
+
+impl From<NumComplex<i32>> for Complex {
+    /// Construct the complex number `re + im𝑖`.
+    ///
+    /// ```
+    /// # use algebra::notation::expr::complex::Complex;
+    /// let value = Complex::from(num_complex::Complex::new(3, -2));
+    /// assert_eq!(value, Complex::new(3, -2));
+    /// assert_eq!(value.to_string(), "3-2𝑖");
+    /// ```
+    fn from(value: NumComplex<i32>) -> Self {
+        Complex::new(value.re, value.im)
+    }
+}
+
+impl From<NumComplex<i32>> for Notation {
+    /// Construct the simplest notation equal to `re + im𝑖`, which is a [`Number`][Atom::Number] if `im` is 0.
+    fn from(value: NumComplex<i32>) -> Self {
+        Complex::from(value).simplify()
+    }
+}
+
+impl TryFrom<Complex> for NumComplex<i32> {
+    type Error = ConversionError;
+
+    /// Extracts both parts of a complex number, if they are both [`Number`][Atom::Number]s.
+    ///
+    /// The parts are not simplified first, the same as converting notation to an [`i32`].
+    fn try_from(value: Complex) -> Result<Self, Self::Error> {
+        let part = |part: &Notation| {
+            i32::try_from(part.clone()).map_err(|_| ConversionError::NotInteger(value.to_string()))
+        };
+        Ok(NumComplex::new(part(&value.re)?, part(&value.im)?))
+    }
+}
+
+impl TryFrom<Notation> for NumComplex<i32> {
+    type Error = ConversionError;
+
+    /// Extracts a [`Number`][Atom::Number] as a complex number with an imaginary part of 0,
+    /// or a [`Complex`] number whose parts are both numbers.
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, expr::{complex::Complex, radical::Radical}};
+    /// use num_complex::Complex as NumComplex;
+    /// assert_eq!(NumComplex::try_from(Notation::from(4)), Ok(NumComplex::new(4, 0)));
+    /// assert_eq!(NumComplex::try_from(Notation::from(Complex::new(1, -1))), Ok(NumComplex::new(1, -1)));
+    /// assert!(NumComplex::<i32>::try_from(Notation::from(Complex::new(Radical::new(2), 1))).is_err());
+    /// ```
+    fn try_from(value: Notation) -> Result<Self, Self::Error> {
+        match value {
+            Notation::Atom(Atom::Number(n)) => Ok(NumComplex::new(n.value, 0)),
+            Notation::Expr(Expr::Complex(complex)) => Self::try_from(complex),
+            Notation::Atom(Atom::Undefined | Atom::Complex | Atom::Huge | Atom::NegativeHuge) => {
+                Err(ConversionError::NotReal(value.to_string()))
+            }
+            other => Err(ConversionError::NotInteger(other.to_string())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::expr::fraction::Fraction;
+
+    #[test]
+    fn test_from_num_complex() {
+        assert_eq!(Complex::from(NumComplex::new(0, 1)), Complex::i());
+        assert_eq!(Notation::from(NumComplex::new(5, 0)), 5);
+        assert_eq!(Notation::from(NumComplex::new(-2, 7)), Complex::new(-2, 7));
+    }
+
+    #[test]
+    fn test_into_num_complex() {
+        assert_eq!(
+            NumComplex::try_from(Complex::new(-3, 8)),
+            Ok(NumComplex::new(-3, 8))
+        );
+        assert_eq!(
+            NumComplex::<i32>::try_from(Complex::new(Fraction::new(1, 2), 1)),
+            Err(ConversionError::NotInteger("1/2+𝑖".to_string()))
+        );
+        assert!(matches!(
+            NumComplex::<i32>::try_from(Notation::from(Atom::Undefined)),
+            Err(ConversionError::NotReal(_))
+        ));
+        for (re, im) in [(0, 0), (1, -1), (i32::MIN, i32::MAX)] {
+            let value = NumComplex::new(re, im);
+            assert_eq!(NumComplex::try_from(Complex::from(value)), Ok(value));
+            assert_eq!(NumComplex::try_from(Notation::from(value)), Ok(value));
+        }
+    }
+}
@@ -0,0 +1,116 @@
+//! Conversions between fractions and [`Ratio`]s.
+
+use crate::notation::{
+    atom::Atom,
+    expr::fraction::Fraction,
+    ops::{gcd, rational},
+    ConversionError, Notation,
+};
+use num_rational::Ratio;
+
+impl From<Ratio<i32>> for Fraction {
+    /// Construct a fraction from a ratio, which is always reduced.
+    ///
+    /// ```
+    /// # use algebra::notation::expr::fraction::Fraction;
+    /// use num_rational::Ratio;
+    /// assert_eq!(Fraction::from(Ratio::new(2, -4)), Fraction::new(-1, 2));
+    /// ```
+    fn from(value: Ratio<i32>) -> Self {
+        Fraction::new(*value.numer(), *value.denom())
+    }
+}
+
+impl From<Ratio<i32>> for Notation {
+    /// Construct the simplest notation equal to a ratio, which is a [`Number`][Atom::Number] for whole ratios.
+    fn from(value: Ratio<i32>) -> Self {
+        rational(*value.numer() as i128, *value.denom() as i128)
+    }
+}
+
+impl TryFrom<Notation> for Ratio<i32> {
+    type Error = ConversionError;
+
+    /// Extracts a [`Number`][Atom::Number] or a [`Fraction`] of numbers as a reduced ratio.
+    ///
+    /// Fails the same way as converting to a [`Fraction`],
+    /// and with [`TooLarge`][ConversionError::TooLarge] if the reduced ratio doesn't fit in an [`i32`], such as `-2147483648/-1`.
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, atom::Atom, expr::fraction::Fraction};
+    /// use num_rational::Ratio;
+    /// let half = Notation::from(Fraction::new(3, 6));
+    /// assert_eq!(Ratio::try_from(half), Ok(Ratio::new(1, 2)));
+    /// assert!(Ratio::<i32>::try_from(Notation::from(Atom::Pi)).is_err());
+    /// ```
+    fn try_from(value: Notation) -> Result<Self, Self::Error> {
+        let text = value.to_string();
+        let Fraction {
+            num: Atom::Number(num),
+            den: Atom::Number(den),
+        } = Fraction::try_from(value)?
+        else {
+            unreachable!("fractions converted from notation are made of numbers")
+        };
+        // Reduced before converting, since `Ratio::new` would overflow making the denominator positive
+        let (num, den) = (num.value as i128, den.value as i128);
+        let divisor = gcd(num, den) * den.signum();
+        match (i32::try_from(num / divisor), i32::try_from(den / divisor)) {
+            (Ok(num), Ok(den)) => Ok(Ratio::new_raw(num, den)),
+            _ => Err(ConversionError::TooLarge(text)),
+        }
+    }
+}
+
+impl TryFrom<Fraction> for Ratio<i32> {
+    type Error = ConversionError;
+
+    /// Converts a fraction of numbers to a reduced ratio, the same as converting it as [`Notation`].
+    fn try_from(value: Fraction) -> Result<Self, Self::Error> {
+        Self::try_from(Notation::from(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::expr::radical::Radical;
+
+    #[test]
+    fn test_from_ratio() {
+        assert_eq!(Fraction::from(Ratio::new(6, 4)), Fraction::new(3, 2));
+        assert_eq!(Notation::from(Ratio::new(6, 3)), 2);
+        assert_eq!(Notation::from(Ratio::new(-1, 3)), Fraction::new(-1, 3));
+    }
+
+    #[test]
+    fn test_into_ratio() {
+        assert_eq!(Ratio::try_from(Fraction::new(4, -6)), Ok(Ratio::new(-2, 3)));
+        assert_eq!(Ratio::try_from(Notation::from(5)), Ok(Ratio::from(5)));
+        assert_eq!(
+            Ratio::<i32>::try_from(Fraction::new(1, 0)),
+            Err(ConversionError::NotReal("1/0".to_string()))
+        );
+        assert!(matches!(
+            Ratio::<i32>::try_from(Notation::from(Radical::new(2))),
+            Err(ConversionError::NotRational(_))
+        ));
+        assert!(matches!(
+            Ratio::<i32>::try_from(Fraction::new(i32::MIN, -1)),
+            Err(ConversionError::TooLarge(_))
+        ));
+        assert_eq!(
+            Ratio::try_from(Fraction::new(i32::MIN, -2)),
+            Ok(Ratio::from(1 << 30))
+        );
+    }
+
+    #[test]
+    fn test_round_trip() {
+        for (num, den) in [(0, 1), (1, 2), (-7, 3), (i32::MAX, 2), (i32::MIN, 1)] {
+            let ratio = Ratio::new(num, den);
+            assert_eq!(Ratio::try_from(Fraction::from(ratio)), Ok(ratio));
+            assert_eq!(Ratio::try_from(Notation::from(ratio)), Ok(ratio));
+        }
+    }
+}
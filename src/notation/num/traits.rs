@@ -0,0 +1,275 @@
+//! [`num_traits`] for notation, so that it can be used by generic numeric code.
+//!
+//! Only [`Notation`] implements [`Zero`], [`One`], [`Num`] and [`Signed`]:
+//! arithmetic on a [`Fraction`][crate::notation::expr::fraction::Fraction] gives notation rather than another fraction,
+//! since the result may not fit in one.
+
+use crate::notation::{ops::table::class_of, rules::AtomClass, Notation};
+use num_traits::{Num, One, Signed, Zero};
+
+/// ```
+/// # use algebra::notation::{Notation, expr::fraction::Fraction};
+/// use num_traits::{Num, One, Zero};
+///
+/// fn dot<T: Zero + Clone + std::ops::Mul<Output = T>>(a: &[T], b: &[T]) -> T {
+///     a.iter().zip(b).fold(T::zero(), |sum, (a, b)| sum + a.clone() * b.clone())
+/// }
+///
+/// let a = [Notation::from(Fraction::new(1, 2)), Notation::from(3)];
+/// let b = [Notation::from(4), Notation::from(Fraction::new(1, 3))];
+/// assert_eq!(dot(&a, &b), 3);
+/// assert!(Notation::one().is_one());
+/// ```
+impl Zero for Notation {
+    fn zero() -> Self {
+        Notation::from(0)
+    }
+
+    /// Returns true if the notation simplifies to 0. See [`Notation::is_zero`].
+    fn is_zero(&self) -> bool {
+        Notation::is_zero(self)
+    }
+}
+
+impl One for Notation {
+    fn one() -> Self {
+        Notation::from(1)
+    }
+
+    /// Returns true if the notation simplifies to 1. See [`Notation::is_one`].
+    fn is_one(&self) -> bool {
+        Notation::is_one(self)
+    }
+}
+
+/// ```
+/// # use algebra::notation::Notation;
+/// use num_traits::Num;
+///
+/// fn is_multiple_of<T: Num + Clone>(value: T, factor: T) -> bool {
+///     (value % factor).is_zero()
+/// }
+///
+/// assert!(is_multiple_of(Notation::from(12), Notation::from(4)));
+/// assert_eq!(Notation::from_str_radix("-ff", 16).unwrap(), -255);
+/// ```
+impl Num for Notation {
+    type FromStrRadixErr = std::num::ParseIntError;
+
+    /// Parses an integer written in the radix, the same as [`i32::from_str_radix`].
+    fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
+        i32::from_str_radix(str, radix).map(Notation::from)
+    }
+}
+
+/// Finds the sign of a value as -1, 0 or 1, or [`None`] if it has no sign, as with complex and undefined values,
+/// or its sign isn't known, as with unknown values.
+fn sign(value: &Notation) -> Option<i32> {
+    use AtomClass as A;
+    match class_of(value) {
+        Some(A::Undefined | A::Complex) => None,
+        // An unknown value may have any sign, including none
+        Some(A::Unknown) => None,
+        Some(A::Zero) => Some(0),
+        Some(A::Negative | A::NegativeHuge | A::NegativeEpsilon) => Some(-1),
+        Some(A::Positive | A::Pi | A::E | A::Huge | A::Epsilon) => Some(1),
+        None if value.is_zero() => Some(0),
+        None => value
+            .eval_f64()
+            .map(|value| if value < 0.0 { -1 } else { 1 }),
+    }
+}
+
+/// Values without a sign, such as [`Complex`][crate::notation::atom::Atom::Complex] values and sums of huge values,
+/// are neither positive nor negative, and have an [`Undefined`][crate::notation::atom::Atom::Undefined] [`signum`][Signed::signum].
+/// So are [`Unknown`][crate::notation::atom::Atom::Unknown] values, whose sign isn't known.
+///
+/// ```
+/// # use algebra::notation::{Notation, atom::Atom, expr::radical::Radical};
+/// use num_traits::Signed;
+///
+/// fn distance<T: Signed>(a: T, b: T) -> T {
+///     (a - b).abs()
+/// }
+///
+/// assert_eq!(distance(Notation::from(2), Notation::from(7)), 5);
+/// assert_eq!(Notation::from(Radical { coef: -3, rad: 2 }).abs(), Radical { coef: 3, rad: 2 });
+/// assert!(Notation::from(Atom::NegativeEpsilon).is_negative());
+/// ```
+impl Signed for Notation {
+    /// Returns the value without its sign. [`i32::MIN`] gives [`Huge`][crate::notation::atom::Atom::Huge].
+    fn abs(&self) -> Self {
+        match self.is_negative() {
+            true => -self.clone(),
+            false => self.clone(),
+        }
+    }
+
+    /// Returns the difference if `self` is greater than `other`, and 0 otherwise.
+    fn abs_sub(&self, other: &Self) -> Self {
+        let difference = self.clone() - other.clone();
+        match difference.is_positive() {
+            true => difference,
+            false => Notation::zero(),
+        }
+    }
+
+    /// Returns -1, 0 or 1 for the sign of the value, or [`Undefined`][crate::notation::atom::Atom::Undefined] if it has none.
+    fn signum(&self) -> Self {
+        sign(self).map_or(
+            Notation::from(crate::notation::atom::Atom::Undefined),
+            Notation::from,
+        )
+    }
+
+    fn is_positive(&self) -> bool {
+        sign(self) == Some(1)
+    }
+
+    fn is_negative(&self) -> bool {
+        sign(self) == Some(-1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::expr::{fraction::Fraction, radical::Radical};
+
+    fn product<T: One>(values: impl IntoIterator<Item = T>) -> T {
+        values
+            .into_iter()
+            .fold(T::one(), |product, value| product * value)
+    }
+
+    fn sum<T: Zero>(values: impl IntoIterator<Item = T>) -> T {
+        values.into_iter().fold(T::zero(), |sum, value| sum + value)
+    }
+
+    #[test]
+    fn test_generic_sum_and_product() {
+        let values = [
+            Notation::from(Fraction::new(1, 2)),
+            Notation::from(Fraction::new(2, 3)),
+            Notation::from(3),
+        ];
+        assert_eq!(product(values.clone()), 1);
+        assert_eq!(sum(values), Fraction::new(25, 6));
+        assert_eq!(product::<Notation>([]), 1);
+        assert_eq!(sum::<Notation>([]), 0);
+        let root = Notation::from(Radical::new(2));
+        assert_eq!(product([root.clone(), root]), 2);
+    }
+
+    fn gcd<T: Num + Clone>(a: T, b: T) -> T {
+        match b.is_zero() {
+            true => a,
+            false => gcd(b.clone(), a % b),
+        }
+    }
+
+    #[test]
+    fn test_generic_remainder() {
+        assert_eq!(gcd(Notation::from(84), Notation::from(36)), 12);
+        assert_eq!(
+            gcd(
+                Notation::from(Fraction::new(3, 4)),
+                Notation::from(Fraction::new(1, 6))
+            ),
+            Fraction::new(1, 12)
+        );
+        assert_eq!(Notation::from_str_radix("101", 2).unwrap(), 5);
+        assert!(Notation::from_str_radix("12", 2).is_err());
+    }
+
+    #[test]
+    fn test_signs() {
+        use crate::notation::{atom::Atom::*, expr::sum::Sum};
+        let sum = |terms: [Notation; 2]| Notation::from(Sum::new(terms));
+        for (value, expected) in [
+            (Notation::from(-4), Some(-1)),
+            (Notation::from(Fraction::new(0, 7)), Some(0)),
+            (Notation::from(Fraction::new(3, -7)), Some(-1)),
+            (Notation::from(Pi), Some(1)),
+            (Notation::from(Epsilon), Some(1)),
+            (Notation::from(NegativeHuge), Some(-1)),
+            (sum([Notation::from(Pi), Notation::from(-4)]), Some(-1)),
+            (
+                sum([Notation::from(Radical::new(2)), Notation::from(-1)]),
+                Some(1),
+            ),
+            (Notation::from(Complex), None),
+            (Notation::from(Undefined), None),
+            (
+                sum([Notation::from(Huge), Notation::from(NegativeHuge)]),
+                None,
+            ),
+        ] {
+            assert_eq!(sign(&value), expected, "sign of {value}");
+            match expected {
+                Some(expected) => assert_eq!(value.signum(), expected),
+                None => assert!(value.signum().atom().is_some_and(|x| x.is_undefined())),
+            }
+        }
+    }
+
+    #[test]
+    fn test_unknown_sign() {
+        use crate::notation::atom::Atom::Unknown;
+        let unknown = Notation::from(Unknown);
+        assert_eq!(sign(&unknown), None);
+        assert!(unknown.signum().atom().is_some_and(|x| x.is_undefined()));
+        assert!(!Signed::is_positive(&unknown));
+        assert!(!Signed::is_negative(&unknown));
+    }
+
+    #[test]
+    fn test_abs() {
+        assert_eq!(Notation::from(-4).abs(), 4);
+        assert_eq!(
+            Notation::from(Fraction::new(-1, 3)).abs(),
+            Fraction::new(1, 3)
+        );
+        assert!(Notation::from(i32::MIN)
+            .abs()
+            .atom()
+            .is_some_and(|x| x.is_positive_huge()));
+        assert_eq!(Notation::from(5).abs_sub(&Notation::from(2)), 3);
+        assert_eq!(Notation::from(2).abs_sub(&Notation::from(5)), 0);
+    }
+
+    /// The determinant of a 2×2 matrix, as generic matrix code would find it.
+    fn determinant<T: Signed + Clone>([[a, b], [c, d]]: [[T; 2]; 2]) -> T {
+        a * d - b * c
+    }
+
+    #[test]
+    fn test_generic_matrix_code() {
+        let half = || Notation::from(Fraction::new(1, 2));
+        let matrix = [
+            [half(), Notation::from(3)],
+            [
+                Notation::from(Radical::new(2)),
+                Notation::from(Radical::new(8)),
+            ],
+        ];
+        // 1/2·√8 - 3·√2 = -2√2
+        let det = determinant(matrix);
+        assert_eq!(det, Radical { coef: -2, rad: 2 });
+        assert!(det.is_negative());
+        assert_eq!(det.abs(), Radical { coef: 2, rad: 2 });
+        assert_eq!(Notation::default(), Notation::zero());
+        assert_eq!(determinant([[half(), half()], [half(), half()]]), 0);
+    }
+
+    #[test]
+    fn test_identities() {
+        assert!(Zero::is_zero(&Notation::from(Fraction::new(0, 3))));
+        assert!(One::is_one(&Notation::from(Radical::new(1))));
+        let mut value = Notation::from(5);
+        value.set_zero();
+        assert_eq!(value, 0);
+        value.set_one();
+        assert_eq!(value, 1);
+    }
+}
@@ -2,8 +2,341 @@
 
 pub mod add;
 pub mod div;
+pub mod mixed;
 pub mod mul;
+pub mod neg;
 pub mod pow;
+pub mod primitive;
+pub mod rem;
+pub mod sqrt;
+pub mod sub;
+pub mod table;
+
+use crate::notation::{
+    atom::{
+        number::Number,
+        Atom::{self, *},
+    },
+    expr::{
+        complex::Complex, fraction::Fraction, multiple::Multiple, radical::Radical,
+        radical_fraction::RadicalFraction, simplify::Simplify, sum::Sum, Expr,
+    },
+    Notation,
+};
+
+/// If the notation is a [`Number`] or a [`Fraction`] of numbers, returns its numerator and denominator.
+///
+/// Fractions with a denominator of 0 are [`Undefined`], and have no ratio.
+pub(crate) fn ratio(value: &Notation) -> Option<(i32, i32)> {
+    match value {
+        Notation::Atom(Number(Number { value })) => Some((*value, 1)),
+        Notation::Expr(Expr::Fraction(Fraction {
+            num: Number(Number { value: num }),
+            den: Number(Number { value: den }),
+        })) if *den != 0 => Some((*num, *den)),
+        _ => None,
+    }
+}
+
+/// If the fraction is made of [`Number`]s, returns its numerator and denominator in lowest terms,
+/// with the denominator positive.
+pub(crate) fn lowest_terms(value: impl Into<Fraction>) -> Option<(i128, i128)> {
+    let (num, den) = ratio(&Notation::from(value.into()))?;
+    let (num, den) = (num as i128, den as i128);
+    let gcd = gcd(num, den) * den.signum();
+    Some((num / gcd, den / gcd))
+}
+
+/// If the notation is a constant or variable, or a rational [`Multiple`] of one,
+/// returns the coefficient's numerator and denominator along with the constant.
+pub(crate) fn multiple_of(value: &Notation) -> Option<(i32, i32, Atom)> {
+    match value {
+        Notation::Atom(constant @ (Pi | E | Variable(_))) => Some((1, 1, constant.clone())),
+        Notation::Expr(Expr::Multiple(Multiple {
+            coef:
+                Fraction {
+                    num: Number(Number { value: num }),
+                    den: Number(Number { value: den }),
+                },
+            constant,
+        })) if *den != 0 => Some((*num, *den, constant.clone())),
+        _ => None,
+    }
+}
+
+/// If the notation is a ratio or a real [`Radical`] or [`RadicalFraction`], returns `(coef, rad, den)`
+/// such that its value is `coef√rad / den`.
+///
+/// Ratios have a radicand of 1.
+pub(crate) fn radical_of(value: &Notation) -> Option<(i32, i32, i32)> {
+    match value {
+        Notation::Expr(Expr::Radical(Radical { coef, rad })) if *rad >= 0 => Some((*coef, *rad, 1)),
+        Notation::Expr(Expr::RadicalFraction(RadicalFraction {
+            num: Radical { coef, rad },
+            den,
+        })) if *rad >= 0 && *den != 0 => Some((*coef, *rad, *den)),
+        value => ratio(value).map(|(num, den)| (num, 1, den)),
+    }
+}
+
+/// Like [`radical_of`], but with the radicand made square-free so that like radicals have the same radicand.
+pub(crate) fn normalized_radical_of(value: &Notation) -> Option<(i32, i32, i32)> {
+    let (coef, rad, den) = radical_of(value)?;
+    let Radical { coef, rad } = Radical { coef, rad }.normalized();
+    Some((coef, rad, den))
+}
+
+/// If the notation is a [`Complex`] number, or the root of a negative such as √-4, returns it as a complex number.
+fn complex_of(value: &Notation) -> Option<Complex> {
+    match value {
+        Notation::Expr(Expr::Complex(value)) => Some(value.clone()),
+        Notation::Expr(
+            Expr::Radical(Radical { rad: ..=-1, .. })
+            | Expr::RadicalFraction(RadicalFraction {
+                num: Radical { rad: ..=-1, .. },
+                ..
+            }),
+        ) => value.clone().simplify().expr().and_then(Expr::complex),
+        _ => None,
+    }
+}
+
+/// If either notation is a [`Complex`] number and the other is real, returns both as complex numbers.
+///
+/// Roots of negatives count as complex numbers, so that √-4 ÷ √-9 is 2𝑖 ÷ 3𝑖.\
+/// Returns [`None`] if neither is complex, so that real arithmetic is never done by way of complex numbers.
+pub(crate) fn complex_pair(lhs: &Notation, rhs: &Notation) -> Option<(Complex, Complex)> {
+    let real = |value: &Notation| match value {
+        Notation::Atom(Atom::Complex | Undefined) => None,
+        real => Some(Complex::new(real.clone(), 0)),
+    };
+    match (complex_of(lhs), complex_of(rhs)) {
+        (None, None) => None,
+        (lhs_complex, rhs_complex) => Some((
+            lhs_complex.or_else(|| real(lhs))?,
+            rhs_complex.or_else(|| real(rhs))?,
+        )),
+    }
+}
+
+/// Returns true if the notation is a [`Sum`], false otherwise.
+pub(crate) fn is_sum(value: &Notation) -> bool {
+    match value {
+        Notation::Expr(expr) => expr.is_sum(),
+        _ => false,
+    }
+}
+
+/// Returns the terms of a [`Sum`], or the notation itself as a single term if it isn't a sum.
+pub(crate) fn terms_of(value: &Notation) -> Vec<Notation> {
+    match value {
+        Notation::Expr(Expr::Sum(Sum { terms })) => terms.to_vec(),
+        value => vec![value.clone()],
+    }
+}
+
+/// Greatest common divisor by Euclid's algorithm.
+pub(crate) fn gcd(mut a: i128, mut b: i128) -> i128 {
+    while b != 0 {
+        (a, b) = (b, a % b);
+    }
+    a.abs()
+}
+
+/// Least common multiple, which is never negative.
+fn lcm(a: i128, b: i128) -> i128 {
+    match gcd(a, b) {
+        0 => 0,
+        gcd => (a / gcd * b).abs(),
+    }
+}
+
+/// Adds `a/b + c/d` over their least common denominator, as `(a(l/b) + c(l/d)) / l` where `l = lcm(b, d)`.
+///
+/// Saturates the same way as [`rational`] if the sum doesn't fit in an [`i32`].
+pub(crate) fn add_ratios(lhs: (i32, i32), rhs: (i32, i32)) -> Notation {
+    combine_ratios(lhs, rhs, 1)
+}
+
+/// Subtracts `a/b - c/d` over their least common denominator, the same way as [`add_ratios`].
+pub(crate) fn sub_ratios(lhs: (i32, i32), rhs: (i32, i32)) -> Notation {
+    combine_ratios(lhs, rhs, -1)
+}
+
+/// `a/b + sign × c/d` over their least common denominator.
+fn combine_ratios((a, b): (i32, i32), (c, d): (i32, i32), sign: i128) -> Notation {
+    let (a, b, c, d) = (a as i128, b as i128, c as i128, d as i128);
+    let l = lcm(b, d);
+    rational(a * (l / b) + sign * c * (l / d), l)
+}
+
+/// Multiplies `a/b × c/d`, first cancelling any factor common to `a` and `d` or to `c` and `b`.
+///
+/// Cancelling first keeps the products as small as the result,
+/// so that the result only saturates the same way as [`rational`] when it doesn't fit in an [`i32`].
+pub(crate) fn mul_ratios((a, b): (i32, i32), (c, d): (i32, i32)) -> Notation {
+    let (a, b, c, d) = (a as i128, b as i128, c as i128, d as i128);
+    let (ad, cb) = (gcd(a, d).max(1), gcd(c, b).max(1));
+    rational((a / ad) * (c / cb), (b / cb) * (d / ad))
+}
+
+/// Divides `a/b ÷ c/d` by multiplying by `d/c` with [`mul_ratios`].
+pub(crate) fn div_ratios(lhs: (i32, i32), (c, d): (i32, i32)) -> Notation {
+    mul_ratios(lhs, (d, c))
+}
+
+/// Constructs the simplest notation equal to `num / den`.
+///
+/// Intermediate products of [`ratio`]s always fit in an [`i128`], so the result is exact
+/// unless the reduced numerator or denominator doesn't fit in an [`i32`].\
+/// In that case, returns [`Huge`] or [`NegativeHuge`] if the magnitude is at least 1,
+/// and [`Epsilon`] or [`NegativeEpsilon`] otherwise.
+pub(crate) fn rational(num: i128, den: i128) -> Notation {
+    if den == 0 {
+        return Notation::from(Undefined);
+    }
+    if num == 0 {
+        return Notation::from(0);
+    }
+
+    let gcd = gcd(num, den);
+    let (num, den) = (num / gcd, den / gcd);
+    match (i32::try_from(num), i32::try_from(den)) {
+        (Ok(num), Ok(den)) => Fraction::new(num, den).simplify(),
+        _ => saturate(num.abs() >= den.abs(), (num < 0) != (den < 0)),
+    }
+}
+
+/// Constructs the simplest notation equal to `coef√rad / den`, where `rad` is not negative.
+///
+/// Saturates the same way as [`rational`] if the reduced parts don't fit in an [`i32`].
+pub(crate) fn radical(coef: i128, rad: i128, den: i128) -> Notation {
+    if den == 0 {
+        return Notation::from(Undefined);
+    }
+    if coef == 0 || rad == 0 {
+        return Notation::from(0);
+    }
+
+    let gcd = gcd(coef, den);
+    let (coef, den) = (coef / gcd, den / gcd);
+    match (i32::try_from(coef), i32::try_from(rad), i32::try_from(den)) {
+        (Ok(coef), Ok(rad), Ok(den)) => RadicalFraction::new(coef, rad, den).simplify(),
+        _ => {
+            let magnitude = coef.abs() as f64 * (rad as f64).sqrt() / den.abs() as f64;
+            saturate(magnitude >= 1.0, (coef < 0) != (den < 0))
+        }
+    }
+}
+
+/// Returns the atom standing in for a value too large or too small to be operated on.
+///
+/// [`Huge`] or [`NegativeHuge`] if the magnitude is large, and [`Epsilon`] or [`NegativeEpsilon`] if it is small.
+pub(crate) fn saturate(is_large: bool, is_negative: bool) -> Notation {
+    Notation::from(match (is_large, is_negative) {
+        (true, false) => Huge,
+        (true, true) => NegativeHuge,
+        (false, false) => Epsilon,
+        (false, true) => NegativeEpsilon,
+    })
+}
+
+/// Constructs the simplest notation equal to `num / den` multiples of `constant`.
+///
+/// The coefficient saturates the same way as [`rational`].
+pub(crate) fn scaled(num: i128, den: i128, constant: Atom) -> Notation {
+    match rational(num, den) {
+        Notation::Atom(Number(Number { value })) => Multiple {
+            coef: Fraction::from(value),
+            constant,
+        }
+        .simplify(),
+        Notation::Expr(Expr::Fraction(coef)) => Multiple { coef, constant }.simplify(),
+        saturated => saturated,
+    }
+}
+
+#[cfg(test)]
+mod rational_tests {
+    use super::*;
+
+    #[test]
+    fn test_rational_reduces() {
+        assert_eq!(rational(6, 8), Fraction::new(3, 4));
+        assert_eq!(rational(6, -8), Fraction::new(-3, 4));
+        assert_eq!(rational(-8, -4), 2);
+        assert!(rational(1, 0).atom().is_some_and(|x| x.is_undefined()));
+    }
+
+    #[test]
+    fn test_rational_saturates() {
+        let big = i32::MAX as i128 * 3;
+        assert!(rational(big, 2)
+            .atom()
+            .is_some_and(|x| x.is_positive_huge()));
+        assert!(rational(-big, 2)
+            .atom()
+            .is_some_and(|x| x.is_negative_huge()));
+        assert!(rational(1, big)
+            .atom()
+            .is_some_and(|x| x.is_positive_epsilon()));
+        assert!(rational(1, -big)
+            .atom()
+            .is_some_and(|x| x.is_negative_epsilon()));
+    }
+
+    #[test]
+    fn test_add_ratios() {
+        assert_eq!(add_ratios((1, 6), (1, 10)), Fraction::new(4, 15));
+        assert_eq!(add_ratios((1, -6), (1, 4)), Fraction::new(1, 12));
+        assert_eq!(add_ratios((1, 2), (-1, 2)), 0);
+        assert!(add_ratios((i32::MAX, 1), (1, 1))
+            .atom()
+            .is_some_and(|x| x.is_positive_huge()));
+        assert!(add_ratios((i32::MIN, 1), (-1, 2))
+            .atom()
+            .is_some_and(|x| x.is_negative_huge()));
+        assert!(add_ratios((1, i32::MAX), (1, i32::MAX - 1))
+            .atom()
+            .is_some_and(|x| x.is_positive_epsilon()));
+        assert_eq!(sub_ratios((1, 4), (1, 6)), Fraction::new(1, 12));
+        assert_eq!(sub_ratios((0, 1), (i32::MIN, i32::MIN)), -1);
+        assert!(sub_ratios((1, 1), (i32::MIN, 1))
+            .atom()
+            .is_some_and(|x| x.is_positive_huge()));
+    }
+
+    #[test]
+    fn test_mul_ratios() {
+        assert_eq!(mul_ratios((999_999, 4), (8, 3)), 666_666);
+        assert_eq!(mul_ratios((2, 3), (-9, 4)), Fraction::new(-3, 2));
+        assert_eq!(mul_ratios((0, 5), (7, 3)), 0);
+        assert_eq!(mul_ratios((i32::MAX, 6), (6, i32::MAX)), 1);
+        assert!(mul_ratios((i32::MAX, 1), (3, 2))
+            .atom()
+            .is_some_and(|x| x.is_positive_huge()));
+        assert_eq!(div_ratios((3, 4), (9, 8)), Fraction::new(2, 3));
+        assert!(div_ratios((1, 2), (0, 1))
+            .atom()
+            .is_some_and(|x| x.is_undefined()));
+    }
+
+    #[test]
+    fn test_radical_reduces() {
+        assert_eq!(radical(2, 3, 4), RadicalFraction::new(1, 3, 2));
+        assert_eq!(radical(3, 12, -2), Radical { coef: -3, rad: 3 });
+        assert_eq!(radical(5, 9, 3), 5);
+        assert_eq!(radical(0, 7, 2), 0);
+        assert!(radical(1, 3, 0).atom().is_some_and(|x| x.is_undefined()));
+        let big = i32::MAX as i128 * 3;
+        assert!(radical(big, 2, 1)
+            .atom()
+            .is_some_and(|x| x.is_positive_huge()));
+        assert!(radical(-1, 3, big)
+            .atom()
+            .is_some_and(|x| x.is_negative_epsilon()));
+    }
+}
 
 #[cfg(test)]
 mod assumption_tests {
@@ -1,158 +1,172 @@
-//! Algebraic addition and subtraction
+//! Algebraic addition
 
+use super::{
+    add_ratios, complex_pair, is_sum, multiple_of, normalized_radical_of,
+    primitive::algebraic_add,
+    radical, ratio, rational, saturate, scaled,
+    table::{class_of, impl_op, outcome, settle, Op, Outcome},
+};
 use crate::notation::{
-    atom::{
-        number::Number,
-        Atom::{self, *},
-    },
+    atom::{number::Number, Atom},
+    canonical::{Canonical, TermOrder},
+    expr::{nested_radical::NestedRadical, product::Product, simplify::Simplify, sum::Sum, Expr},
     Notation,
 };
 
-/// If the result overflows, returns [`Huge`].\
-/// If the result underflows, returns [`NegativeHuge`].\
-/// Otherwise returns a [`Number`] with the value of the result.
-fn algebraic_add(lhs: i32, rhs: i32) -> Notation {
-    match lhs.checked_add(rhs) {
-        // All is well
-        Some(sum) => Notation::from(sum),
-
-        // Over or under flow (need to figure out which)
-        None => match lhs.saturating_add(rhs) {
-            i32::MAX => Notation::from(Huge),
-            i32::MIN => Notation::from(NegativeHuge),
-            _ => unreachable!("Saturated over/underflow should be equal to max/min respectively."),
-        },
-    }
-}
-
-#[cfg(test)]
-mod algebraic_add_tests {
-    use super::*;
-
-    #[test]
-    fn test_basic_addition() {
-        for a in -10..=10 {
-            for b in -10..=10 {
-                assert_eq!(algebraic_add(a, b), a + b);
+/// Adds two values if they can be combined into a single term, such as two ratios or two like radicals.
+///
+/// Otherwise gives back both values unchanged, so that they can be kept as a [`Sum`].
+pub(crate) fn combine(lhs: Notation, rhs: Notation) -> Result<Notation, (Notation, Notation)> {
+    // Special atoms are combined by the table, unless it keeps them apart
+    if let (Notation::Atom(_), _) | (_, Notation::Atom(_)) = (&lhs, &rhs) {
+        if let (Some(a), Some(b)) = (class_of(&lhs), class_of(&rhs)) {
+            if outcome(Op::Add, a, b) == Outcome::Kept {
+                return Err((lhs, rhs));
             }
         }
     }
-
-    #[test]
-    fn test_positive_huge_zero_addition() {
-        let sum = algebraic_add(0, i32::MAX).atom().unwrap();
-        assert_eq!(sum, i32::MAX);
-    }
-
-    #[test]
-    fn test_positive_huge_positive_addition() {
-        for i in 1..=10 {
-            let sum = algebraic_add(i, i32::MAX).atom().unwrap();
-            assert!(sum.is_positive_huge());
+    let (lhs, rhs) = match settle(Op::Add, lhs, rhs) {
+        Ok(sum) => return Ok(sum),
+        Err(operands) => operands,
+    };
+    match (lhs, rhs) {
+        (
+            Notation::Atom(Atom::Number(Number { value: num_a })),
+            Notation::Atom(Atom::Number(Number { value: num_b })),
+        ) => Ok(algebraic_add(num_a, num_b)),
+        // Sums are combined term by term
+        (lhs @ Notation::Expr(Expr::Sum(_)), rhs) | (lhs, rhs @ Notation::Expr(Expr::Sum(_))) => {
+            Err((lhs, rhs))
         }
-    }
-
-    #[test]
-    fn test_positive_huge_negative_addition() {
-        for i in -1..=-10 {
-            let sum = algebraic_add(i, i32::MAX).atom().unwrap();
-            assert_eq!(sum, i32::MAX + i);
+        // aπ𝑒 + bπ𝑒 = (a + b)π𝑒, and likewise for powers such as π²
+        (lhs @ Notation::Expr(Expr::Product(_) | Expr::Power(_)), rhs)
+        | (lhs, rhs @ Notation::Expr(Expr::Product(_) | Expr::Power(_))) => {
+            match (product_parts(&lhs), product_parts(&rhs)) {
+                (Some((a, b, lhs_units)), Some((c, d, rhs_units)))
+                    if lhs_units.len() == rhs_units.len()
+                        && lhs_units
+                            .iter()
+                            .zip(&rhs_units)
+                            .all(|(x, y)| Canonical(x.clone()) == Canonical(y.clone())) =>
+                {
+                    let coef = a
+                        .checked_mul(d)
+                        .zip(c.checked_mul(b))
+                        .and_then(|(ad, cb)| ad.checked_add(cb))
+                        .zip(b.checked_mul(d));
+                    match coef {
+                        Some((num, den)) => Ok(Product::new(
+                            std::iter::once(rational(num, den)).chain(lhs_units),
+                        )
+                        .simplify()),
+                        None => Err((lhs, rhs)),
+                    }
+                }
+                _ => Err((lhs, rhs)),
+            }
         }
-    }
-
-    #[test]
-    fn test_positive_addition_becomes_huge() {
-        const HUGE_PART: i32 = i32::MAX / 2 + 1;
-        let sum = algebraic_add(HUGE_PART, HUGE_PART).atom().unwrap();
-        assert!(sum.is_positive_huge());
-    }
-
-    #[test]
-    fn test_negative_huge_addition() {
-        let sum = algebraic_add(0, i32::MIN).atom().unwrap();
-        assert_eq!(sum, i32::MIN);
-    }
-
-    #[test]
-    fn test_negative_huge_positive_addition() {
-        for i in 1..=10 {
-            let sum = algebraic_add(i, i32::MIN).atom().unwrap();
-            assert_eq!(sum, i32::MIN + i);
+        // a/b + c/b = (a + c)/b, when a + c is a single term
+        (Notation::Expr(Expr::NestedFraction(lhs)), Notation::Expr(Expr::NestedFraction(rhs)))
+            if Canonical(lhs.den.as_ref().clone()) == Canonical(rhs.den.as_ref().clone()) =>
+        {
+            match combine(lhs.num.as_ref().clone(), rhs.num.as_ref().clone()) {
+                Ok(num) if !is_sum(&num) => Ok(num / lhs.den.as_ref().clone()),
+                _ => Err((Notation::from(lhs), Notation::from(rhs))),
+            }
         }
-    }
-
-    #[test]
-    fn test_negative_huge_negative_addition() {
-        for i in -1..=-10 {
-            let sum = algebraic_add(i, i32::MIN).atom().unwrap();
-            assert!(sum.is_negative_huge());
+        // a√x + b√x = (a + b)√x
+        (Notation::Expr(Expr::NestedRadical(lhs)), Notation::Expr(Expr::NestedRadical(rhs)))
+            if Canonical(lhs.rad.as_ref().clone()) == Canonical(rhs.rad.as_ref().clone()) =>
+        {
+            match lhs.coef.checked_add(rhs.coef) {
+                Some(coef) => Ok(NestedRadical { coef, ..lhs }.simplify()),
+                None => Ok(saturate(true, lhs.coef < 0)),
+            }
         }
+        (lhs, rhs) => match (ratio(&lhs), ratio(&rhs)) {
+            // a/b + c/d over their least common denominator
+            (Some(lhs), Some(rhs)) => Ok(add_ratios(lhs, rhs)),
+            _ => match (multiple_of(&lhs), multiple_of(&rhs)) {
+                // (a/b)π + (c/d)π = ((ad + cb)/bd)π
+                (Some((a, b, lhs)), Some((c, d, rhs))) if lhs == rhs => {
+                    let (a, b, c, d) = (a as i128, b as i128, c as i128, d as i128);
+                    Ok(scaled(a * d + c * b, b * d, lhs))
+                }
+                // Zero is the additive identity
+                _ if rhs.is_zero() => Ok(lhs.simplify()),
+                _ if lhs.is_zero() => Ok(rhs.simplify()),
+                _ => match (normalized_radical_of(&lhs), normalized_radical_of(&rhs)) {
+                    // a√m/b + c√m/d = (ad + cb)√m/bd
+                    (Some((a, m, b)), Some((c, n, d))) if m == n => {
+                        let (a, b, c, d) = (a as i128, b as i128, c as i128, d as i128);
+                        Ok(radical(a * d + c * b, m as i128, b * d))
+                    }
+                    _ => match complex_pair(&lhs, &rhs) {
+                        Some((lhs, rhs)) => Ok(lhs + rhs),
+                        None => Err((lhs, rhs)),
+                    },
+                },
+            },
+        },
     }
+}
 
-    #[test]
-    fn test_negative_addition_becomes_negative_huge() {
-        const NHUGE_PART: i32 = i32::MIN / 2 - 1;
-        let sum = algebraic_add(NHUGE_PART, NHUGE_PART).atom().unwrap();
-        assert!(sum.is_negative_huge());
-    }
-
-    #[test]
-    fn test_positive_and_negative_huge_addition_not_huge() {
-        let sum_huges = algebraic_add(i32::MIN, i32::MAX).atom().unwrap();
-        assert_eq!(sum_huges, -1);
+/// Splits a product into its rational coefficient, as `(numerator, denominator)`, and its other factors,
+/// taking the coefficient out of any multiple among them, so that 2π·𝑒 is 2 times π·𝑒.
+///
+/// A power is a product of itself alone, so that π² and 2·π² are alike.
+/// The other factors are sorted, so that products of the same factors in another order are alike.
+/// Returns [`None`] if the value isn't a product or power, or its coefficient doesn't fit.
+fn product_parts(value: &Notation) -> Option<(i128, i128, Vec<Notation>)> {
+    let factors = match value {
+        Notation::Expr(Expr::Product(Product { factors })) => factors,
+        Notation::Expr(Expr::Power(_)) => return Some((1, 1, vec![value.clone()])),
+        _ => return None,
+    };
+    let (mut num, mut den) = (1i128, 1i128);
+    let mut units = Vec::with_capacity(factors.len());
+    for factor in factors.iter() {
+        let (a, b) = match (ratio(factor), multiple_of(factor)) {
+            (Some(coef), _) => coef,
+            (None, Some((a, b, constant))) => {
+                units.push(Notation::from(constant));
+                (a, b)
+            }
+            (None, None) => {
+                units.push(factor.clone());
+                (1, 1)
+            }
+        };
+        num = num.checked_mul(a as i128)?;
+        den = den.checked_mul(b as i128)?;
     }
+    units.sort_by(TermOrder::canonical_cmp);
+    Some((num, den, units))
 }
 
-impl std::ops::Add for Notation {
-    type Output = Self;
-
-    /// Add two values.
-    ///
-    /// If the result overflows, returns [`Huge`].\
-    /// Otherwise returns a [`Number`] with the value of the result.
-    fn add(self, rhs: Self) -> Self::Output {
-        match (self, rhs) {
-            (Notation::Atom(atom_a), Notation::Atom(atom_b)) => match (atom_a, atom_b) {
-                (Atom::Number(Number { value: num_a }), Atom::Number(Number { value: num_b })) => {
-                    algebraic_add(num_a, num_b)
-                }
-
-                _ => todo!(),
-            },
-            _ => todo!(),
-        }
+/// Adds values which aren't settled by the [tables](super::table).
+fn add(lhs: Notation, rhs: Notation) -> Notation {
+    match combine(lhs, rhs) {
+        Ok(sum) => sum,
+        Err((lhs, rhs)) => Sum::new([lhs, rhs]).simplify(),
     }
 }
 
-impl std::ops::Sub for Notation {
-    type Output = Self;
-
-    /// Subtract two values.
+impl_op!(
+    /// Add two values.
     ///
-    /// If the result overflows, returns [`Huge`].\
-    /// Otherwise returns a [`Number`] with the value of the result.
-    fn sub(self, rhs: Self) -> Self::Output {
-        match (self, rhs) {
-            (Notation::Atom(atom_a), Notation::Atom(atom_b)) => match (atom_a, atom_b) {
-                (Atom::Number(Number { value: num_a }), Atom::Number(Number { value: num_b })) => {
-                    match num_b.checked_neg() {
-                        Some(sub_b) => algebraic_add(num_a, sub_b),
-                        // The edge cases where we can salvage lost information are too rare to worry about at the moment.
-                        // The fact this case is reached already implies the user is working with numbers dangerously close to Huge anyway.
-                        None => Notation::from(NegativeHuge),
-                    }
-                }
-
-                _ => todo!(),
-            },
-            _ => todo!(),
-        }
-    }
-}
+    /// If the result overflows, returns [`Huge`][Atom::Huge].\
+    /// Values which can't be combined into a single term, such as 1 + √2, are kept as a [`Sum`].
+    Add::add,
+    Op::Add,
+    add
+);
 
 #[cfg(test)]
 mod add_tests {
     use super::*;
+    use crate::notation::expr::{fraction::Fraction, radical::Radical};
+    use Atom::*;
 
     #[test]
     fn test_basic_addition() {
@@ -162,4 +176,69 @@ mod add_tests {
             }
         }
     }
+
+    #[test]
+    fn test_fraction_addition() {
+        let half = Notation::from(Fraction::new(1, 2));
+        let third = Notation::from(Fraction::new(1, 3));
+        assert_eq!(half.clone() + third.clone(), Fraction::new(5, 6));
+        assert_eq!(half.clone() + half.clone(), 1);
+        assert_eq!(Notation::from(2) + third, Fraction::new(7, 3));
+    }
+
+    #[test]
+    fn test_zero_identity() {
+        let root = Notation::from(Radical { coef: 2, rad: 3 });
+        assert_eq!(Notation::from(0) + root.clone(), root);
+        assert_eq!(root.clone() - Notation::from(0), root);
+        assert_eq!(Notation::from(0) - root, Radical { coef: -2, rad: 3 });
+    }
+
+    #[test]
+    fn test_like_products() {
+        use crate::notation::expr::{multiple::Multiple, product::Product};
+        let pi_e = || Notation::from(Pi) * Notation::from(E);
+        assert_eq!(
+            pi_e() + pi_e(),
+            Product::new([Multiple::new(2, 1, Pi).into(), E.into()])
+        );
+        assert_eq!(pi_e() - pi_e(), 0);
+        // The order of the factors doesn't matter
+        assert_eq!(pi_e() - Notation::from(E) * Notation::from(Pi), 0);
+        assert!((pi_e() + Notation::from(Pi))
+            .expr()
+            .is_some_and(|x| x.is_sum()));
+
+        let pi_squared = || Notation::from(Pi).pow(Notation::from(2));
+        assert_eq!(pi_squared() - pi_squared(), 0);
+        assert_eq!((pi_squared() + pi_squared()).to_string(), "2·π²");
+    }
+
+    #[test]
+    fn test_constant_addition() {
+        use crate::notation::expr::multiple::Multiple;
+        let pi = Notation::from(Pi);
+        assert_eq!(pi.clone() + pi.clone(), Multiple::new(2, 1, Pi));
+        assert_eq!(
+            Notation::from(Multiple::new(1, 2, E)) + Notation::from(E),
+            Multiple::new(3, 2, E)
+        );
+        assert_eq!(pi.clone() - pi, 0);
+    }
+
+    #[test]
+    fn test_radical_addition() {
+        use crate::notation::expr::radical_fraction::RadicalFraction;
+        let root = Notation::from(Radical::new(3));
+        assert_eq!(root.clone() + root.clone(), Radical { coef: 2, rad: 3 });
+        assert_eq!(
+            root.clone() + Notation::from(RadicalFraction::new(1, 3, 2)),
+            RadicalFraction::new(3, 3, 2)
+        );
+        assert_eq!(
+            Notation::from(RadicalFraction::new(1, 3, 2)) - root.clone(),
+            RadicalFraction::new(-1, 3, 2)
+        );
+        assert_eq!(root.clone() - root, 0);
+    }
 }
@@ -1,47 +1,124 @@
 //! Algebraic division
 
+use super::{
+    complex_pair, div_ratios, gcd, is_sum, multiple_of,
+    primitive::algebraic_div,
+    radical, radical_of, ratio, scaled,
+    table::{impl_op, Op},
+    terms_of,
+};
 #[allow(unused_imports)]
 use crate::notation::{
     atom::{
         number::Number,
         Atom::{self, *},
     },
-    expr::{fraction::Fraction, simplify::Simplify, Expr},
+    expr::{
+        binomial::Binomial, fraction::Fraction, nested_fraction::NestedFraction,
+        simplify::Simplify, Expr,
+    },
     Notation,
 };
+use std::sync::Arc;
+
+/// Divides values which aren't settled by the [tables](super::table).
+fn div(lhs: Notation, rhs: Notation) -> Notation {
+    match (lhs, rhs) {
+        (
+            Notation::Atom(Atom::Number(Number { value: num })),
+            Notation::Atom(Atom::Number(Number { value: den })),
+        ) => algebraic_div(num, den),
+        (Notation::Atom(num), Notation::Atom(den)) => Fraction { num, den }.simplify(),
+
+        // (a/b)/c = a/bc
+        (Notation::Expr(Expr::NestedFraction(NestedFraction { num, den })), rhs) => {
+            Arc::unwrap_or_clone(num) / (Arc::unwrap_or_clone(den) * rhs)
+        }
+        // a/(b/c) = ac/b
+        (lhs, Notation::Expr(Expr::NestedFraction(NestedFraction { num, den }))) => {
+            (lhs * Arc::unwrap_or_clone(den)) / Arc::unwrap_or_clone(num)
+        }
+
+        (lhs, rhs) => match (ratio(&lhs), ratio(&rhs)) {
+            // a/b ÷ c/d = ad/bc, cancelling across first
+            (Some(lhs), Some(rhs)) => div_ratios(lhs, rhs),
 
-impl std::ops::Div for Notation {
-    type Output = Self;
+            (_, rhs_ratio) => match (multiple_of(&lhs), multiple_of(&rhs), rhs_ratio) {
+                // (a/b)π ÷ c/d = (ad/bc)π
+                (Some((a, b, constant)), _, Some((c, d))) => {
+                    let (a, b, c, d) = (a as i128, b as i128, c as i128, d as i128);
+                    scaled(a * d, b * c, constant)
+                }
+
+                // (a/b)π ÷ (c/d)π = ad/bc
+                (Some((a, b, lhs)), Some((c, d, rhs)), _) if lhs == rhs => {
+                    div_ratios((a, b), (c, d))
+                }
 
+                _ => match (radical_of(&lhs), radical_of(&rhs)) {
+                    // a√m/b ÷ c√n/d = ad√mn/bcn, with any factor common to m and n cancelled first
+                    (Some((a, m, b)), Some((c, n, d))) => {
+                        let (a, m, b, c, n, d) = (
+                            a as i128, m as i128, b as i128, c as i128, n as i128, d as i128,
+                        );
+                        let g = gcd(m, n).max(1);
+                        let (m, n) = (m / g, n / g);
+                        radical(a * d, m * n, b * c * n)
+                    }
+                    _ => match complex_pair(&lhs, &rhs) {
+                        Some((lhs, rhs)) => lhs / rhs,
+                        None => match Binomial::of(&rhs) {
+                            // Multiply by the conjugate, which leaves a rational denominator
+                            Some(den) => lhs * den.recip(),
+                            // (a + b)/c = a/c + b/c
+                            None if is_sum(&lhs) => terms_of(&lhs)
+                                .into_iter()
+                                .map(|term| term / rhs.clone())
+                                .fold(Notation::from(0), |sum, quotient| sum + quotient),
+                            None => div_fractions(lhs, rhs),
+                        },
+                    },
+                },
+            },
+        },
+    }
+}
+
+impl_op!(
     /// Divide two values.
     ///
     /// If the result overflows, returns [`Huge`].\
     /// If the result underflows, returns [`NegativeHuge`].\
     /// If the result has a [`Huge`] or [`NegativeHuge`] denominator, returns [`Epsilon`] if positive overall and [`NegativeEpsilon`] if overall negative.\
     /// If the result has a denominator of 0, or contains [`Undefined`], returns [`Undefined`].\
+    /// If the result can't be determined, as with [`Epsilon`] divided by [`Epsilon`], or contains [`Unknown`], returns [`Unknown`].\
     /// If the result an integer, returns a [`Number`] with the value of the result.\
     /// Otherwise returns a [`Fraction`].
-    fn div(self, rhs: Self) -> Self::Output {
-        match (self, rhs) {
-            (Notation::Atom(num), Notation::Atom(den)) => Fraction { num, den }.simplify(),
-
-            (Notation::Expr(Expr::Fraction(frac_num)), Notation::Atom(den)) => {
-                if let Notation::Atom(num) = frac_num.simplify() {
-                    Fraction { num, den }.simplify()
-                } else {
-                    todo!()
-                }
+    Div::div,
+    Op::Div,
+    div
+);
+
+/// Divides fractions whose parts aren't both integers.
+///
+/// A quotient which can't be written any more simply, such as π/√2, is kept as a [`NestedFraction`].
+fn div_fractions(lhs: Notation, rhs: Notation) -> Notation {
+    match (lhs, rhs) {
+        (Notation::Expr(Expr::Fraction(frac_num)), Notation::Atom(den)) => {
+            match frac_num.simplify() {
+                Notation::Atom(num) => Fraction { num, den }.simplify(),
+                num => Notation::from(NestedFraction::new(num, den)),
             }
-            (Notation::Atom(num), Notation::Expr(Expr::Fraction(frac_den))) => {
-                if let Notation::Atom(den) = frac_den.simplify() {
-                    Fraction { num, den }.simplify()
-                } else {
-                    todo!()
-                }
+        }
+        (Notation::Atom(num), Notation::Expr(Expr::Fraction(frac_den))) => {
+            match frac_den.simplify() {
+                Notation::Atom(den) => Fraction { num, den }.simplify(),
+                den => Notation::from(NestedFraction::new(num, den)),
             }
-
-            _ => todo!(),
         }
+
+        (lhs, rhs) if rhs.is_one() => lhs.simplify(),
+        (lhs, rhs) => Notation::from(NestedFraction::new(lhs.simplify(), rhs.simplify())),
     }
 }
 
@@ -80,7 +157,7 @@ mod div_tests {
 
     #[test]
     fn test_negative_over_huge_is_negative_epsilon() {
-        for num in -1..=-10 {
+        for num in -10..=-1 {
             let epsilon = (Notation::from(num) / Notation::from(Huge)).atom().unwrap();
             assert!(epsilon.is_negative_epsilon())
         }
@@ -92,9 +169,82 @@ mod div_tests {
         assert_eq!(zero, 0)
     }
 
+    #[test]
+    fn test_fraction_over_fraction_division() {
+        let half = Notation::from(Fraction::new(1, 2));
+        let three_quarters = Notation::from(Fraction::new(3, 4));
+        assert_eq!(half.clone() / three_quarters.clone(), Fraction::new(2, 3));
+        assert_eq!(three_quarters / half.clone(), Fraction::new(3, 2));
+        assert_eq!(half.clone() / half, 1);
+    }
+
+    #[test]
+    fn test_fraction_over_zero_division() {
+        let half = Notation::from(Fraction::new(1, 2));
+        let undefined = (half / Notation::from(0)).atom().unwrap();
+        assert!(undefined.is_undefined())
+    }
+
     #[test]
     fn test_fraction_over_fraction() {
         let zero = Notation::from(0) / Notation::from(Huge);
         assert_eq!(zero, 0)
     }
+
+    #[test]
+    fn test_constant_division() {
+        use crate::notation::expr::multiple::Multiple;
+        let pi = Notation::from(Pi);
+        assert_eq!(pi.clone() / Notation::from(2), Multiple::new(1, 2, Pi));
+        assert_eq!(
+            Notation::from(Multiple::new(3, 2, Pi)) / Notation::from(3),
+            Multiple::new(1, 2, Pi)
+        );
+        assert_eq!(
+            Notation::from(Multiple::new(3, 2, Pi)) / pi.clone(),
+            Fraction::new(3, 2)
+        );
+        assert_eq!(pi.clone() / pi.clone(), 1);
+        assert_eq!((Notation::from(1) / pi).to_string(), "1/π");
+    }
+
+    #[test]
+    fn test_radical_division() {
+        use crate::notation::expr::{radical::Radical, radical_fraction::RadicalFraction};
+        let root = |rad| Notation::from(Radical::new(rad));
+        assert_eq!(root(6) / root(2), Radical::new(3));
+        assert_eq!(root(2) / root(6), RadicalFraction::new(1, 3, 3));
+        assert_eq!(root(3) / Notation::from(2), RadicalFraction::new(1, 3, 2));
+        assert_eq!(Notation::from(2) / root(2), Radical::new(2));
+        assert!((root(2) / root(0)).atom().is_some_and(|x| x.is_undefined()));
+    }
+
+    #[test]
+    fn test_imaginary_root_division() {
+        use crate::notation::expr::{complex::Complex, radical::Radical};
+        let root = |rad| Notation::from(Radical::new(rad));
+        // 2𝑖 / 3𝑖
+        assert_eq!(root(-4) / root(-9), Fraction::new(2, 3));
+        assert_eq!(root(-4) / Notation::from(2), Complex::i());
+        assert_eq!(Notation::from(6) / root(-9), Complex::new(0, -2));
+        assert_eq!(root(-2) / root(2), Complex::i());
+    }
+
+    #[test]
+    fn test_unknown_division() {
+        use crate::notation::expr::radical::Radical;
+        let unknown = || Notation::from(Unknown);
+        assert!((Notation::from(Epsilon) / Notation::from(NegativeEpsilon))
+            .atom()
+            .is_some_and(|x| x.is_unknown()));
+        assert!((unknown() / Notation::from(Fraction::new(1, 2)))
+            .atom()
+            .is_some_and(|x| x.is_unknown()));
+        assert!((Notation::from(Radical::new(2)) / unknown())
+            .atom()
+            .is_some_and(|x| x.is_unknown()));
+        assert!((unknown() / Notation::from(0))
+            .atom()
+            .is_some_and(|x| x.is_undefined()));
+    }
 }
@@ -0,0 +1,125 @@
+//! Arithmetic between notation and plain integers, on either side.
+//!
+//! Both sides are converted to [`Notation`], so a result which doesn't fit in an [`i32`] saturates
+//! the way every operator does under the default [`OverflowPolicy`][crate::context::OverflowPolicy],
+//! rather than overflowing.
+//!
+//! ```
+//! # use algebra::notation::{Notation, atom::Atom, expr::{fraction::Fraction, radical::Radical}};
+//! assert_eq!(Notation::from(Atom::Pi) * 2 - Notation::from(Atom::Pi), Atom::Pi);
+//! assert_eq!(1 - Fraction::new(1, 3), Fraction::new(2, 3));
+//! assert!((i32::MAX * Radical::from_ints(2, 2)).atom().is_some_and(|x| x.is_positive_huge()));
+//! ```
+
+use crate::notation::{
+    expr::{fraction::Fraction, radical::Radical},
+    Notation,
+};
+use std::ops::{Add, Div, Mul, Sub};
+
+/// Implements an operator between `$ty` and [`i32`] in both directions,
+/// by converting both sides to [`Notation`] and using the operator on notation.
+macro_rules! impl_mixed {
+    ($ty:ty, $($op:ident::$method:ident),+) => {$(
+        impl $op<i32> for $ty {
+            type Output = Notation;
+
+            fn $method(self, rhs: i32) -> Self::Output {
+                Notation::from(self).$method(Notation::from(rhs))
+            }
+        }
+
+        impl $op<$ty> for i32 {
+            type Output = Notation;
+
+            fn $method(self, rhs: $ty) -> Self::Output {
+                Notation::from(self).$method(Notation::from(rhs))
+            }
+        }
+    )+};
+}
+
+impl_mixed!(Notation, Add::add, Sub::sub, Mul::mul, Div::div);
+impl_mixed!(Fraction, Add::add, Sub::sub, Mul::mul, Div::div);
+
+impl_mixed!(Radical, Add::add, Sub::sub, Div::div);
+
+impl Mul<Radical> for i32 {
+    type Output = Notation;
+
+    /// Multiplies the radical, such as 3 × 2√3 = 6√3.
+    ///
+    /// Unlike [`Radical`] × [`i32`], a coefficient too large for an [`i32`] saturates to [`Huge`][crate::notation::atom::Atom::Huge],
+    /// the same as any other operator on notation.
+    fn mul(self, rhs: Radical) -> Self::Output {
+        Notation::from(self) * Notation::from(rhs)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::expr::{complex::Complex, radical_fraction::RadicalFraction};
+
+    #[test]
+    fn test_notation_and_integer() {
+        let half = Notation::from(Fraction::new(1, 2));
+        assert_eq!(half.clone() + 1, Fraction::new(3, 2));
+        assert_eq!(1 + half.clone(), Fraction::new(3, 2));
+        assert_eq!(half.clone() - 1, Fraction::new(-1, 2));
+        assert_eq!(1 - half.clone(), Fraction::new(1, 2));
+        assert_eq!(half.clone() * 4, 2);
+        assert_eq!(4 * half.clone(), 2);
+        assert_eq!(half.clone() / 2, Fraction::new(1, 4));
+        assert_eq!(2 / half, 4);
+        assert!((Notation::from(i32::MAX) + 1)
+            .atom()
+            .is_some_and(|x| x.is_positive_huge()));
+    }
+
+    #[test]
+    fn test_fraction_and_integer() {
+        let third = Fraction::new(1, 3);
+        assert_eq!(third.clone() + 1, Fraction::new(4, 3));
+        assert_eq!(2 - third.clone(), Fraction::new(5, 3));
+        assert_eq!(third.clone() * 3, 1);
+        assert_eq!(3 / third.clone(), 9);
+        assert!((third / 0).atom().is_some_and(|x| x.is_undefined()));
+    }
+
+    #[test]
+    fn test_radical_plus_integer() {
+        let root = Radical::new(2);
+        assert_eq!((root.clone() + 1).to_string(), "√2+1");
+        assert_eq!((1 - root.clone()).to_string(), "1-√2");
+        assert_eq!(Radical::new(4) + 1, 3);
+        assert_eq!(2 - Radical::new(9), -1);
+        assert_eq!(root.clone() - 0, root);
+    }
+
+    #[test]
+    fn test_radical_and_integer() {
+        let root = Radical { coef: 2, rad: 3 };
+        assert_eq!(3 * root.clone(), Radical { coef: 6, rad: 3 });
+        assert_eq!(root.clone() / 4, RadicalFraction::new(1, 3, 2));
+        assert_eq!(root.clone() / 2, Radical::new(3));
+        assert_eq!(3 / Radical::new(3), Radical::new(3));
+        assert_eq!(1 / root, RadicalFraction::new(1, 3, 6));
+        assert_eq!(4 / Radical::new(4), 2);
+        // Coefficients which don't fit saturate rather than overflowing
+        assert!((i32::MAX * Radical::from_ints(2, 2))
+            .atom()
+            .is_some_and(|x| x.is_positive_huge()));
+        assert!((3 / Radical::from_ints(100000, 100003))
+            .atom()
+            .is_some_and(|x| x.is_positive_epsilon()));
+        assert!((1 / Radical::new(0))
+            .atom()
+            .is_some_and(|x| x.is_undefined()));
+        // 1/(𝑖√3) = -𝑖√3/3
+        assert_eq!(
+            1 / Radical::new(-3),
+            Complex::new(0, RadicalFraction::new(-1, 3, 3))
+        );
+    }
+}
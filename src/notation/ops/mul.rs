@@ -1,87 +1,187 @@
 //! Algebraic multiplication
 
+use super::{
+    complex_pair, gcd, is_sum, mul_ratios, multiple_of,
+    pow::pow_simplified,
+    primitive::algebraic_mul,
+    radical, radical_of, ratio, scaled,
+    table::{class_of, impl_op, outcome, settle, Op, Outcome},
+    terms_of,
+};
 use crate::notation::{
     atom::{
         number::Number,
         Atom::{self, *},
     },
+    canonical::Canonical,
+    expr::{
+        nested_fraction::NestedFraction, power::Power, product::Product, simplify::Simplify, Expr,
+    },
     Notation,
 };
+use std::sync::Arc;
 
-/// If the result overflows, returns [`Huge`].\
-/// If the result underflows, returns [`NegativeHuge`].\
-/// Otherwise returns a [`Number`] with the value of the result.
-fn algebraic_mul(lhs: i32, rhs: i32) -> Notation {
-    match lhs.checked_mul(rhs) {
-        // All is well
-        Some(prod) => Notation::from(prod),
-
-        // Over or under flow (need to figure out which)
-        None => match lhs.saturating_mul(rhs) {
-            i32::MAX => Notation::from(Huge),
-            i32::MIN => Notation::from(NegativeHuge),
-            _ => unreachable!("Saturated over/underflow should be equal to max/min respectively."),
-        },
+/// Multiplies two values if they can be combined into a single factor, such as two ratios or two radicals,
+/// or two powers of the same base, such as π × π² = π³.
+///
+/// Otherwise gives back both values unchanged, so that they can be kept as a [`Product`].
+pub(crate) fn combine(lhs: Notation, rhs: Notation) -> Result<Notation, (Notation, Notation)> {
+    combine_values(lhs, rhs).or_else(|(lhs, rhs)| combine_powers(lhs, rhs))
+}
+
+/// Splits a value into its base and exponent, such as π² into π and 2, or π into π and 1.
+fn base_and_exp(value: &Notation) -> (&Notation, Notation) {
+    match value {
+        Notation::Expr(Expr::Power(Power { base, exp })) => (base, exp.as_ref().clone()),
+        value => (value, Notation::from(1)),
     }
 }
 
-#[cfg(test)]
-mod algebraic_mul_tests {
-    use super::*;
+/// Multiplies two powers of the same base by adding their exponents, such as π × π = π².
+///
+/// Gives back both values if their bases differ.
+fn combine_powers(lhs: Notation, rhs: Notation) -> Result<Notation, (Notation, Notation)> {
+    let ((base, a), (other, b)) = (base_and_exp(&lhs), base_and_exp(&rhs));
+    // Products are combined factor by factor
+    if matches!(base, Notation::Expr(Expr::Product(_)))
+        || Canonical(base.clone()) != Canonical(other.clone())
+    {
+        return Err((lhs, rhs));
+    }
+    let (base, exp) = (base.clone(), a + b);
+    match (ratio(&base), ratio(&exp)) {
+        // Raising the base would multiply it by itself, which combines back into a power
+        (None, Some((0, 1))) => Ok(Notation::from(1)),
+        (None, Some((1, 1))) => Ok(base),
+        (None, Some((-1, 1))) => Ok(Notation::from(1) / base),
+        (None, Some((num, 1))) => Ok(Notation::from(Power::new(base, num))),
+        _ => Ok(pow_simplified(base, exp, Notation::from(1))),
+    }
+}
 
-    #[test]
-    fn test_basic_multiplication() {
-        for a in -10..=10 {
-            for b in -10..=10 {
-                assert_eq!(algebraic_mul(a, b), a * b);
-                assert_eq!(algebraic_mul(b, a), b * a);
+/// Multiplies two values which aren't powers of the same base.
+fn combine_values(lhs: Notation, rhs: Notation) -> Result<Notation, (Notation, Notation)> {
+    // Special atoms are combined by the table, unless it keeps them apart
+    if let (Notation::Atom(_), _) | (_, Notation::Atom(_)) = (&lhs, &rhs) {
+        if let (Some(a), Some(b)) = (class_of(&lhs), class_of(&rhs)) {
+            if outcome(Op::Mul, a, b) == Outcome::Kept {
+                return Err((lhs, rhs));
             }
         }
     }
-
-    #[test]
-    fn test_overflowing_multiplication() {
-        let prod = algebraic_mul(i32::MAX, 2).atom().unwrap();
-        assert!(prod.is_positive_huge());
-
-        let prod = algebraic_mul(2, i32::MAX).atom().unwrap();
-        assert!(prod.is_positive_huge());
+    let (lhs, rhs) = match settle(Op::Mul, lhs, rhs) {
+        Ok(product) => return Ok(product),
+        Err(operands) => operands,
+    };
+    match (lhs, rhs) {
+        (
+            Notation::Atom(Atom::Number(Number { value: num_a })),
+            Notation::Atom(Atom::Number(Number { value: num_b })),
+        ) => Ok(algebraic_mul(num_a, num_b)),
+        // Products are combined factor by factor
+        (lhs @ Notation::Expr(Expr::Product(_)), rhs)
+        | (lhs, rhs @ Notation::Expr(Expr::Product(_))) => Err((lhs, rhs)),
+        // (a/b)c = ac/b
+        (Notation::Expr(Expr::NestedFraction(NestedFraction { num, den })), other)
+        | (other, Notation::Expr(Expr::NestedFraction(NestedFraction { num, den }))) => {
+            Ok((Arc::unwrap_or_clone(num) * other) / Arc::unwrap_or_clone(den))
+        }
+        (Notation::Expr(Expr::NestedRadical(root)), other)
+        | (other, Notation::Expr(Expr::NestedRadical(root))) => match (ratio(&other), other) {
+            // a × c√x = ac√x
+            (Some((num, 1)), _) => Ok((root * num).simplify()),
+            // (a/b) × c√x = ac√x/b
+            (Some((num, den)), _) => Ok((root * num).simplify() / Notation::from(den)),
+            // a√x × b√x = abx
+            (None, Notation::Expr(Expr::NestedRadical(other)))
+                if Canonical(root.rad.as_ref().clone())
+                    == Canonical(other.rad.as_ref().clone()) =>
+            {
+                Ok(Notation::from(root.coef)
+                    * Notation::from(other.coef)
+                    * Arc::unwrap_or_clone(root.rad))
+            }
+            (None, other) => mul_irrational(Notation::from(root), other),
+        },
+        (lhs, rhs) => match (ratio(&lhs), ratio(&rhs)) {
+            // a/b × c/d = ac/bd, cancelling across first
+            (Some(lhs), Some(rhs)) => Ok(mul_ratios(lhs, rhs)),
+            // a/b × (c/d)π = (ac/bd)π
+            (Some((a, b)), None) | (None, Some((a, b))) => {
+                match multiple_of(&lhs).or(multiple_of(&rhs)) {
+                    Some((c, d, constant)) => {
+                        let (a, b, c, d) = (a as i128, b as i128, c as i128, d as i128);
+                        Ok(scaled(a * c, b * d, constant))
+                    }
+                    None => mul_irrational(lhs, rhs),
+                }
+            }
+            _ => mul_irrational(lhs, rhs),
+        },
     }
+}
 
-    #[test]
-    fn test_underflowing_multiplication() {
-        let prod = algebraic_mul(i32::MAX, -2).atom().unwrap();
-        assert!(prod.is_negative_huge());
-
-        let prod = algebraic_mul(-2, i32::MAX).atom().unwrap();
-        assert!(prod.is_negative_huge());
+/// Multiplies values which aren't settled by the [tables](super::table).
+fn mul(lhs: Notation, rhs: Notation) -> Notation {
+    match combine(lhs, rhs) {
+        Ok(product) => product,
+        Err((lhs, rhs)) => Product::new([lhs, rhs]).simplify(),
     }
 }
 
-impl std::ops::Mul for Notation {
-    type Output = Self;
-
+impl_op!(
     /// Multiply two values.
     ///
     /// If the result overflows, returns [`Huge`].\
-    /// Otherwise returns a [`Number`] with the value of the result.
-    fn mul(self, rhs: Self) -> Self::Output {
-        match (self, rhs) {
-            (Notation::Atom(atom_a), Notation::Atom(atom_b)) => match (atom_a, atom_b) {
-                (Atom::Number(Number { value: num_a }), Atom::Number(Number { value: num_b })) => {
-                    algebraic_mul(num_a, num_b)
-                }
+    /// Values which can't be combined into a single factor, such as π × 𝑒, are kept as a [`Product`].
+    Mul::mul,
+    Op::Mul,
+    mul
+);
 
-                _ => todo!(),
-            },
-            _ => todo!(),
+/// Returns true if the notation simplifies to [`Undefined`], false otherwise.
+fn is_undefined(value: &Notation) -> bool {
+    matches!(value.simplified(), Notation::Atom(Undefined))
+}
+
+/// Multiplies values which aren't both rational, such as radicals and complex numbers.
+///
+/// Gives back both values if they can't be combined.
+fn mul_irrational(lhs: Notation, rhs: Notation) -> Result<Notation, (Notation, Notation)> {
+    match (radical_of(&lhs), radical_of(&rhs)) {
+        // a√m/b × c√n/d = ac√mn/bd, with any factor common to m and n moved out from under the root
+        (Some((a, m, b)), Some((c, n, d))) => {
+            let (a, m, b, c, n, d) = (
+                a as i128, m as i128, b as i128, c as i128, n as i128, d as i128,
+            );
+            let g = gcd(m, n).max(1);
+            Ok(radical(a * c * g, (m / g) * (n / g), b * d))
         }
+        // (a + b)(c + d) = ac + ad + bc + bd
+        _ if is_sum(&lhs) || is_sum(&rhs) => {
+            let rhs = terms_of(&rhs);
+            Ok(terms_of(&lhs)
+                .into_iter()
+                .flat_map(|a| rhs.iter().map(move |b| a.clone() * b.clone()))
+                .fold(Notation::from(0), |sum, product| sum + product))
+        }
+        // 1x = x
+        _ if lhs.is_one() => Ok(rhs.simplify()),
+        _ if rhs.is_one() => Ok(lhs.simplify()),
+        // 0x = 0, unless x is undefined
+        _ if lhs.is_zero() && !is_undefined(&rhs) => Ok(Notation::from(0)),
+        _ if rhs.is_zero() && !is_undefined(&lhs) => Ok(Notation::from(0)),
+        _ => match complex_pair(&lhs, &rhs) {
+            Some((lhs, rhs)) => Ok(lhs * rhs),
+            None => Err((lhs, rhs)),
+        },
     }
 }
 
 #[cfg(test)]
 mod mul_tests {
     use super::*;
+    use crate::notation::expr::fraction::Fraction;
 
     #[test]
     fn test_basic_multiplication() {
@@ -91,4 +191,162 @@ mod mul_tests {
             }
         }
     }
+
+    #[test]
+    fn test_fraction_multiplication() {
+        let half = Notation::from(Fraction::new(1, 2));
+        let two_thirds = Notation::from(Fraction::new(2, 3));
+        assert_eq!(half.clone() * two_thirds.clone(), Fraction::new(1, 3));
+        assert_eq!(Notation::from(3) * two_thirds, 2);
+        assert_eq!(half * Notation::from(-1), Fraction::new(-1, 2));
+    }
+
+    #[test]
+    fn test_identities() {
+        use crate::notation::expr::{
+            radical::Radical,
+            trig::{Trig, TrigFunction::*},
+        };
+        let sin = Notation::from(Trig::new(Sin, 10));
+        assert_eq!(sin.clone() * Notation::from(Fraction::new(2, 2)), sin);
+        assert_eq!(Notation::from(Radical::new(1)) * sin.clone(), sin);
+        assert_eq!(sin.clone() * Notation::from(Radical { coef: 0, rad: 3 }), 0);
+        assert_eq!(Notation::from(Huge) * Notation::from(0), 0);
+    }
+
+    #[test]
+    fn test_constant_multiplication() {
+        use crate::notation::expr::multiple::Multiple;
+        let pi = Notation::from(Pi);
+        assert_eq!(Notation::from(2) * pi.clone(), Multiple::new(2, 1, Pi));
+        assert_eq!(
+            pi.clone() * Notation::from(Fraction::new(3, 4)),
+            Multiple::new(3, 4, Pi)
+        );
+        assert_eq!(
+            Notation::from(Multiple::new(2, 3, E)) * Notation::from(Fraction::new(3, 2)),
+            E
+        );
+        assert_eq!(pi * Notation::from(0), 0);
+    }
+
+    #[test]
+    fn test_kept_products() {
+        use crate::notation::expr::{
+            multiple::Multiple, power::Power, product::Product, trig::Trig,
+        };
+        let (pi, e) = (Notation::from(Pi), Notation::from(E));
+        assert_eq!(
+            pi.clone() * e.clone(),
+            Product::new([pi.clone(), e.clone()])
+        );
+        // Powers of the same base are combined by adding their exponents
+        assert_eq!(pi.clone() * pi.clone(), pi.clone().pow(Notation::from(2)));
+        assert_eq!(pi.clone().pow(Notation::from(2)), Power::new(Pi, 2));
+        assert_eq!((pi.clone() * pi.clone()).to_string(), "π²");
+        assert_eq!(
+            Notation::from(Power::new(Pi, 2)) * pi.clone(),
+            Power::new(Pi, 3)
+        );
+        assert_eq!((pi.clone() * e.clone() * pi.clone()).to_string(), "π²·𝑒");
+        assert_eq!(
+            (pi.clone() + Notation::from(1))
+                .pow(Notation::from(2))
+                .to_string(),
+            "π²+2π+1"
+        );
+        // Rational factors are combined into the first constant
+        assert_eq!(
+            Notation::from(2) * pi.clone() * e.clone() * Notation::from(Fraction::new(1, 4)),
+            Product::new([Multiple::new(1, 2, Pi).into(), e.clone()])
+        );
+        let sin = Notation::from(Trig::new(
+            crate::notation::expr::trig::TrigFunction::Sin,
+            10,
+        ));
+        assert_eq!((pi.clone() * sin.clone()).to_string(), format!("π·{sin}"));
+        assert_eq!(sin.clone() * sin.clone(), Power::new(sin.clone(), 2));
+        assert!((pi * Notation::from(Unknown))
+            .atom()
+            .is_some_and(|x| x.is_unknown()));
+    }
+
+    #[test]
+    fn test_variable_multiplication() {
+        use crate::{
+            notation::expr::{multiple::Multiple, power::Power, product::Product},
+            solve::var::Var,
+        };
+        let x = || Notation::from(Variable(Var::new("x")));
+        assert_eq!(
+            x() * Notation::from(2),
+            Multiple::new(2, 1, Variable(Var::new("x")))
+        );
+        assert_eq!(
+            Notation::from(Fraction::new(1, 2)) * x(),
+            Multiple::new(1, 2, Variable(Var::new("x")))
+        );
+        assert_eq!(x() * x(), Power::new(Variable(Var::new("x")), 2));
+        assert_eq!(
+            x().pow(Notation::from(2)),
+            Power::new(Variable(Var::new("x")), 2)
+        );
+        assert_eq!(x() * x() * x(), Power::new(Variable(Var::new("x")), 3));
+        assert_eq!((x() * x()).to_string(), "x²");
+        assert_eq!(
+            (x() + Notation::from(1)).pow(Notation::from(2)).to_string(),
+            "x²+2x+1"
+        );
+        let y = Notation::from(Variable(Var::new("y")));
+        assert_eq!(
+            x() * y.clone() * x(),
+            Product::new([Power::new(Variable(Var::new("x")), 2).into(), y])
+        );
+        assert_eq!(x() * Notation::from(0), 0);
+        assert_eq!((x() + x()).to_string(), "2x");
+        assert_eq!(x() - x(), 0);
+        assert_eq!((-x()).to_string(), "-x");
+    }
+
+    #[test]
+    fn test_sum_multiplication() {
+        use crate::notation::expr::{radical::Radical, sum::Sum};
+        let sum = |a: i32, b: i32| {
+            Notation::from(Sum::new([a.into(), Radical { coef: b, rad: 2 }.into()]))
+        };
+        // (1 + √2)(1 - √2) = 1 - 2
+        assert_eq!(sum(1, 1) * sum(1, -1), -1);
+        assert_eq!((sum(1, 1) * sum(1, 1)).to_string(), "3+2√2");
+        assert_eq!((Notation::from(2) * sum(1, 1)).to_string(), "2+2√2");
+    }
+
+    #[test]
+    fn test_complex_multiplication() {
+        use crate::notation::expr::complex::Complex;
+        let i = Notation::from(Complex::i());
+        assert_eq!(i.clone() * i.clone(), -1);
+        assert_eq!(Notation::from(2) * i.clone(), Complex::new(0, 2));
+        assert_eq!(i.clone() + Notation::from(1), Complex::new(1, 1));
+        assert_eq!(Notation::from(1) / i.clone(), Complex::new(0, -1));
+        let half = Notation::from(Fraction::new(1, 2));
+        assert_eq!(half * i, Complex::new(0, Fraction::new(1, 2)));
+    }
+
+    #[test]
+    fn test_radical_multiplication() {
+        use crate::notation::expr::{radical::Radical, radical_fraction::RadicalFraction};
+        let root = |rad| Notation::from(Radical::new(rad));
+        assert_eq!(root(2) * root(3), Radical::new(6));
+        assert_eq!(root(6) * root(10), Radical { coef: 2, rad: 15 });
+        assert_eq!(root(5) * root(5), 5);
+        assert_eq!(
+            Notation::from(Fraction::new(1, 2)) * root(3),
+            RadicalFraction::new(1, 3, 2)
+        );
+        assert_eq!(
+            Notation::from(RadicalFraction::new(1, 3, 2))
+                * Notation::from(RadicalFraction::new(1, 3, 2)),
+            Fraction::new(3, 4)
+        );
+    }
 }
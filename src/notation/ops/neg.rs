@@ -0,0 +1,290 @@
+//! Algebraic negation
+
+use std::sync::Arc;
+
+use crate::notation::{
+    atom::{
+        number::Number,
+        Atom::{self, *},
+    },
+    expr::{
+        fraction::Fraction,
+        multiple::Multiple,
+        nested_fraction::NestedFraction,
+        nested_radical::NestedRadical,
+        product::Product,
+        radical::Radical,
+        radical_fraction::RadicalFraction,
+        sum::Sum,
+        trig::{Trig, TrigFunction},
+        Expr,
+    },
+    Notation,
+};
+
+/// Negates `num / den`, moving the sign to the denominator if the numerator is [`i32::MIN`].
+pub(super) fn neg_ratio(num: i32, den: i32) -> (i32, i32) {
+    match (num.checked_neg(), den.checked_neg()) {
+        (Some(num), _) => (num, den),
+        (None, Some(den)) => (num, den),
+        // i32::MIN / i32::MIN is 1
+        (None, None) => (-1, 1),
+    }
+}
+
+fn neg_fraction(Fraction { num, den }: Fraction) -> Notation {
+    if let Some(num) = num.checked_neg() {
+        return Notation::from(Fraction { num, den });
+    }
+    if let Some(den) = den.checked_neg() {
+        return Notation::from(Fraction { num, den });
+    }
+    // Both parts are i32::MIN, π, or 𝑒
+    match (num, den) {
+        (num, den) if num == den => Notation::from(-1),
+        // -i32::MIN is i32::MAX + 1
+        (Number(_), den) => Notation::from(Sum::new([
+            Notation::from(Fraction {
+                num: Atom::from(i32::MAX),
+                den: den.clone(),
+            }),
+            Notation::from(Fraction {
+                num: Atom::from(1),
+                den,
+            }),
+        ])),
+        // -(c/d) = (-1/d)c
+        (constant, den) => Notation::from(Multiple {
+            coef: Fraction {
+                num: Atom::from(-1),
+                den,
+            },
+            constant,
+        }),
+    }
+}
+
+fn neg_multiple(Multiple { coef, constant }: Multiple) -> Notation {
+    let coef = match coef {
+        Fraction {
+            num: Number(Number { value: num }),
+            den: Number(Number { value: den }),
+        } => {
+            let (num, den) = neg_ratio(num, den);
+            Fraction::new(num, den)
+        }
+        coef => match neg_fraction(coef) {
+            Notation::Expr(Expr::Fraction(coef)) => coef,
+            Notation::Atom(Number(Number { value })) => Fraction::from(value),
+            // A multiple's coefficient is meant to be rational
+            _ => return Notation::from(Undefined),
+        },
+    };
+    Notation::from(Multiple { coef, constant })
+}
+
+impl std::ops::Neg for Notation {
+    type Output = Self;
+
+    /// Negates a value, negating the children of expressions such as sums and complex numbers.
+    ///
+    /// The negative of [`i32::MIN`] doesn't fit in a [`Number`], so it returns [`Huge`].
+    /// Inside a fraction or radical, the sign moves to the denominator instead, so no value is lost.\
+    /// The negative of π, 𝑒, or a [`Variable`] is a [`Multiple`] of it.\
+    /// A [`Multiple`] whose coefficient isn't rational has no negative, and returns [`Undefined`].
+    ///
+    /// The result isn't simplified.
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, atom::Atom, expr::{multiple::Multiple, radical::Radical, sum::Sum}};
+    /// assert_eq!(-Notation::from(3), -3);
+    /// assert_eq!(-Notation::from(Atom::Pi), Multiple::new(-1, 1, Atom::Pi));
+    /// assert!((-Notation::from(i32::MIN)).atom().is_some_and(|x| x.is_positive_huge()));
+    ///
+    /// let sum = Notation::from(Sum::new([Notation::from(1), Notation::from(Radical::new(2))]));
+    /// assert_eq!((-sum).to_string(), "-1-√2");
+    /// ```
+    fn neg(self) -> Self::Output {
+        match self {
+            Notation::Atom(atom) => match atom.checked_neg() {
+                Some(atom) => Notation::from(atom),
+                None if atom.is_number() => Notation::from(Huge),
+                None => Notation::from(Multiple::new(-1, 1, atom)),
+            },
+            Notation::Expr(expr) => match expr {
+                Expr::Fraction(Fraction {
+                    num: Number(Number { value: num }),
+                    den: Number(Number { value: den }),
+                }) => {
+                    let (num, den) = neg_ratio(num, den);
+                    Notation::from(Fraction::new(num, den))
+                }
+                Expr::Fraction(value) => neg_fraction(value),
+                Expr::Radical(Radical { coef, rad }) => match coef.checked_neg() {
+                    Some(coef) => Notation::from(Radical { coef, rad }),
+                    None => Notation::from(RadicalFraction::new(coef, rad, -1)),
+                },
+                Expr::RadicalFraction(RadicalFraction {
+                    num: Radical { coef, rad },
+                    den,
+                }) => {
+                    let (coef, den) = neg_ratio(coef, den);
+                    Notation::from(RadicalFraction::new(coef, rad, den))
+                }
+                Expr::Multiple(value) => neg_multiple(value),
+                Expr::Complex(value) => Notation::from(-value),
+                Expr::Sum(value) => Notation::from(-value),
+                Expr::Product(value) => Notation::from(-value),
+                // -(a^b) = -1·a^b, since negating the base would change the power
+                Expr::Power(value) => {
+                    Notation::from(Product::new([Notation::from(-1), Notation::from(value)]))
+                }
+                Expr::NestedRadical(NestedRadical { coef, rad }) => match coef.checked_neg() {
+                    Some(coef) => Notation::from(NestedRadical { coef, rad }),
+                    None => Notation::from(NestedFraction::new(NestedRadical { coef, rad }, -1)),
+                },
+                // -(a/b) = (-a)/b
+                Expr::NestedFraction(NestedFraction { num, den }) => {
+                    Notation::from(NestedFraction {
+                        num: Arc::new(-Arc::unwrap_or_clone(num)),
+                        den,
+                    })
+                }
+                // sin and tan are odd; cos is negated by half a turn
+                Expr::Trig(Trig { func, arg }) => Notation::from(Trig {
+                    func,
+                    arg: Arc::new(match func {
+                        TrigFunction::Sin | TrigFunction::Tan => -Arc::unwrap_or_clone(arg),
+                        TrigFunction::Cos => Arc::unwrap_or_clone(arg) + Notation::from(180),
+                    }),
+                }),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod neg_tests {
+    use super::*;
+    use crate::notation::{
+        canonical::Canonical,
+        expr::{complex::Complex, simplify::Simplify},
+    };
+
+    /// Checks that the negative adds to the value to make 0, and that negating twice gives the same value.
+    fn check(value: Notation) {
+        assert!(
+            (value.clone() + -value.clone()).is_zero(),
+            "{value} + -({value}) should be 0"
+        );
+        assert_eq!(
+            Canonical((-(-value.clone())).simplified()),
+            Canonical(value.simplified()),
+            "-(-({value})) should be {value}"
+        );
+    }
+
+    #[test]
+    fn test_atoms() {
+        for n in [-5, -1, 0, 1, 7, i32::MAX, i32::MIN + 1] {
+            assert_eq!(-Notation::from(n), -n);
+        }
+        assert!((-Notation::from(i32::MIN))
+            .atom()
+            .is_some_and(|x| x.is_positive_huge()));
+        assert!((-Notation::from(Huge))
+            .atom()
+            .is_some_and(|x| x.is_negative_huge()));
+        assert!((-Notation::from(NegativeEpsilon))
+            .atom()
+            .is_some_and(|x| x.is_positive_epsilon()));
+        assert!((-Notation::from(Undefined))
+            .atom()
+            .is_some_and(|x| x.is_undefined()));
+        assert_eq!(-Notation::from(E), Multiple::new(-1, 1, E));
+    }
+
+    #[test]
+    fn test_exprs() {
+        check(Notation::from(Fraction::new(3, 4)));
+        check(Notation::from(Radical { coef: -2, rad: 3 }));
+        check(Notation::from(RadicalFraction::new(5, 2, 3)));
+        check(Notation::from(Multiple::new(1, 2, Pi)));
+        check(Notation::from(Complex::new(1, Fraction::new(-1, 2))));
+        check(Notation::from(Sum::new([
+            Notation::from(Radical::new(2)),
+            Notation::from(Pi),
+        ])));
+        check(Notation::from(NestedFraction::new(Pi, Radical::new(2))));
+        check(Notation::from(NestedRadical::new(
+            Radical::from(2) + Radical::new(2),
+        )));
+        assert_eq!(
+            -Notation::from(Fraction {
+                num: Pi,
+                den: Atom::from(3)
+            }),
+            Fraction {
+                num: Pi,
+                den: Atom::from(-3)
+            }
+        );
+    }
+
+    #[test]
+    fn test_min_moves_sign_to_denominator() {
+        check(Notation::from(Fraction::new(i32::MIN, 3)));
+        check(Notation::from(Radical {
+            coef: i32::MIN,
+            rad: 2,
+        }));
+        check(Notation::from(RadicalFraction::new(i32::MIN, 2, 5)));
+        check(Notation::from(Multiple::new(i32::MIN, 3, Pi)));
+        assert_eq!(
+            -Notation::from(Fraction::new(i32::MIN, 3)),
+            Fraction::new(i32::MIN, -3)
+        );
+        assert_eq!(
+            -Notation::from(Fraction::new(i32::MIN, i32::MIN)),
+            Fraction::new(-1, 1)
+        );
+        assert_eq!(
+            -Notation::from(RadicalFraction::new(i32::MIN, 2, i32::MIN)),
+            RadicalFraction::new(-1, 2, 1)
+        );
+    }
+
+    #[test]
+    fn test_no_negatable_part() {
+        let pi_over = |den: Atom| Fraction { num: Pi, den };
+        assert_eq!(
+            Canonical(-Notation::from(pi_over(E))),
+            Canonical(Notation::from(Multiple {
+                coef: Fraction {
+                    num: Atom::from(-1),
+                    den: E
+                },
+                constant: Pi
+            }))
+        );
+        assert_eq!(-Notation::from(pi_over(Pi)), -1);
+        let min_over_pi = Notation::from(Fraction {
+            num: Atom::from(i32::MIN),
+            den: Pi,
+        });
+        assert_eq!((-min_over_pi).to_string(), "2147483647/π+1/π");
+    }
+
+    #[test]
+    fn test_trig() {
+        use TrigFunction::*;
+        assert_eq!(
+            (-Notation::from(Trig::new(Sin, 30))).simplified(),
+            Fraction::new(-1, 2)
+        );
+        assert_eq!(
+            (-Notation::from(Trig::new(Cos, 60))).simplified(),
+            Fraction::new(-1, 2)
+        );
+    }
+}
@@ -1,54 +1,320 @@
 //! Algebraic exponentiation
 
+use super::{gcd, ratio, rational, saturate};
 use crate::notation::{
-    atom::{
-        number::Number as Num,
-        Atom::{self, *},
-    },
+    atom::{number::Number as Num, Atom::*},
+    expr::{fraction::Fraction, power::Power, simplify::Simplify, Expr},
     Notation,
 };
+use std::sync::Arc;
+
+/// Raises `num / den` to the power of `exp`, as `num^exp / den^exp`.
+///
+/// A negative exponent gives the reciprocal, `den^-exp / num^-exp`.
+/// Results which don't fit in an [`i32`] saturate the same way as [`rational`].
+fn rational_pow(num: i32, den: i32, exp: i32) -> Notation {
+    let (num, den) = match exp {
+        0.. => (num as i128, den as i128),
+        _ => (den as i128, num as i128),
+    };
+    // Reduce first, so that a base with a magnitude of 1 never overflows
+    let divisor = gcd(num, den).max(1);
+    let (num, den) = (num / divisor, den / divisor);
+    let exp = exp.unsigned_abs();
+    match (num.checked_pow(exp), den.checked_pow(exp)) {
+        (Some(num), Some(den)) => rational(num, den),
+        // Too large for even an i128
+        _ => saturate(
+            num.abs() > den.abs(),
+            ((num < 0) != (den < 0)) && exp % 2 == 1,
+        ),
+    }
+}
+
+/// The `index`th root of `n`, if it is a whole number.
+fn root_i(n: i32, index: u32) -> Option<i32> {
+    if n < 0 {
+        return None;
+    }
+    // The floating point root is close enough that the exact root is next to it, if there is one
+    let guess = (n as f64).powf(1.0 / index as f64).round() as i64;
+    (guess.max(1) - 1..=guess + 1)
+        .find(|root| root.checked_pow(index) == Some(n as i64))
+        .map(|root| root as i32)
+}
+
+/// The most terms an integer power of a sum is expanded into.
+///
+/// Each term of the expansion takes a multiplication to find, so larger powers, such as (π+1)^64, are kept as a [`Power`].
+const MAX_EXPANDED_TERMS: u64 = 16;
+
+/// The number of terms in `(a+b+…)^exp`, where the sum has `terms` terms which don't combine,
+/// or [`None`] if there are more than [`MAX_EXPANDED_TERMS`].
+///
+/// Each term is a way to choose `exp` of the sum's terms, allowing repeats, which is `C(terms-1+exp, exp)`.
+fn expanded_terms(terms: usize, exp: u32) -> Option<u64> {
+    (1..terms as u64).try_fold(1_u64, |count, i| {
+        let count = count * (exp as u64 + i) / i;
+        (count <= MAX_EXPANDED_TERMS).then_some(count)
+    })
+}
+
+/// Returns the number of terms in the value when written as a sum.
+///
+/// A [`Complex`][crate::notation::expr::complex::Complex] number whose parts are single terms, such as 2+4𝑖,
+/// counts as one, since its powers have single terms for parts too.
+fn terms(value: &Notation) -> usize {
+    match value {
+        Notation::Expr(Expr::Sum(sum)) => sum.terms.iter().map(terms).sum(),
+        Notation::Expr(Expr::Complex(complex)) => match (terms(&complex.re), terms(&complex.im)) {
+            (1, 1) => 1,
+            (re, im) => re + im,
+        },
+        _ => 1,
+    }
+}
+
+/// Raises the base to an integer power.
+///
+/// Powers whose factors can't be combined, such as π², are kept as a [`Power`],
+/// as are powers of sums with more than [`MAX_EXPANDED_TERMS`] terms when expanded, such as (π+1)^64.
+fn integer_pow(base: Notation, exp: i32) -> Notation {
+    if let Some((num, den)) = ratio(&base) {
+        return rational_pow(num, den, exp);
+    }
+    match base {
+        // A negative base stays negative only for odd powers, and a negative power swaps large and small
+        Notation::Atom(atom @ (Huge | NegativeHuge | Epsilon | NegativeEpsilon)) => saturate(
+            atom.is_huge() == (exp > 0),
+            atom.is_negative() && exp % 2 != 0,
+        ),
+        // (a^b)^c = a^(bc) for whole numbers c
+        Notation::Expr(Expr::Power(Power { base, exp: inner })) => pow_simplified(
+            Arc::unwrap_or_clone(base),
+            Arc::unwrap_or_clone(inner) * Notation::from(exp),
+            Notation::from(1),
+        ),
+        base if expanded_terms(terms(&base), exp.unsigned_abs()).is_none() => {
+            Notation::from(Power::new(base, exp))
+        }
+        base => {
+            // Squaring and multiplying takes a step per bit of the exponent, rather than one per factor
+            let (mut result, mut square) = (Notation::from(1), base.clone());
+            let mut bits = exp.unsigned_abs();
+            while bits > 0 {
+                if bits % 2 == 1 {
+                    result = result * square.clone();
+                }
+                bits /= 2;
+                if bits > 0 {
+                    square = square.clone() * square;
+                }
+                // Terms which combine make fewer, but terms which don't are kept from growing without limit
+                let too_long = |value: &Notation| terms(value) as u64 > MAX_EXPANDED_TERMS;
+                if is_kept(&result) || is_kept(&square) || too_long(&result) || too_long(&square) {
+                    return Notation::from(Power::new(base, exp));
+                }
+            }
+
+            if exp.is_positive() {
+                result
+            } else {
+                Notation::from(1) / result
+            }
+        }
+    }
+}
+
+/// Returns true if the value is a [`Product`][crate::notation::expr::product::Product] or a [`Power`],
+/// which multiplying only keeps or combines back into a power, false otherwise.
+fn is_kept(value: &Notation) -> bool {
+    matches!(value, Notation::Expr(Expr::Product(_) | Expr::Power(_)))
+}
+
+/// Raises a base which isn't negative to the power of `num / den`, where `den` is greater than 1,
+/// as the `den`th root of `base^num`.
+///
+/// Rational roots of rational bases are exact, such as 8^(2/3) = 4.
+/// Even roots are taken as square roots, such as 2^(1/2) = √2 and 2^(1/4) = √√2.
+/// Other roots are kept as a [`Power`], such as 2^(1/3).
+fn fraction_pow(base: Notation, num: i32, den: i32) -> Notation {
+    if let Some((a, b)) = ratio(&base) {
+        let power = rational_pow(a, b, num);
+        let Some((a, b)) = ratio(&power) else {
+            // The root of a saturated power is saturated too
+            return power;
+        };
+        if let (Some(a), Some(b)) = (root_i(a, den as u32), root_i(b, den as u32)) {
+            return Notation::from(Fraction::new(a, b)).simplify();
+        }
+    }
+    match den % 2 {
+        0 => pow_simplified(
+            base,
+            Notation::from(Fraction::new(num, den / 2)).simplify(),
+            Notation::from(1),
+        )
+        .sqrt(),
+        _ => Notation::from(Power::new(base, Fraction::new(num, den))),
+    }
+}
+
+/// Raises a negative base to a power which isn't an integer.
+///
+/// Odd roots of a negative base are real, such as (-8)^(1/3) = -2,
+/// and its square roots are imaginary, the same as [`sqrt`][Notation::sqrt], such as (-4)^(1/2) = 2𝑖.
+/// Other powers, such as (-4)^(1/4) and (-2)^π, have no notation, and give [`Complex`].
+fn negative_pow(base: Notation, exp: Notation) -> Notation {
+    let rational = ratio(&exp).and_then(|(num, den)| match den < 0 {
+        true => Some((num.checked_neg()?, den.checked_neg()?)),
+        false => Some((num, den)),
+    });
+    match rational {
+        // The square root first, so that (-4)^(3/2) = (2𝑖)³ = -8𝑖
+        Some((num, 2)) => integer_pow(base.sqrt(), num),
+        // (-b)^(n/d) = (-1)^n · b^(n/d) when d is odd
+        Some((num, den)) if den % 2 == 1 => {
+            let power = pow_simplified(-base, exp, Notation::from(1));
+            match num % 2 {
+                0 => power,
+                _ => -power,
+            }
+        }
+        _ => Notation::from(Complex),
+    }
+}
+
+/// Compares a real value to `n`, or returns [`None`] if it has no real value, or its value isn't known.
+fn cmp_real(value: &Notation, n: i32) -> Option<std::cmp::Ordering> {
+    value
+        .compare(&Notation::from(n))
+        .or_else(|| value.eval_f64()?.partial_cmp(&(n as f64)))
+}
 
 impl Notation {
     /// Puts one value to the power of another.
     ///
-    /// If the result overflows, returns [`Huge`].\
-    /// If the result has a [`Huge`] denominator, returns [`Epsilon`].\
-    /// If the result has a denominator of 0, returns [`Undefined`].\
-    /// If the base and exponent are both negative, returns [`Complex`].\
+    /// 0⁰ is 1. Use [`pow_with`][Notation::pow_with] to give it another value.
+    ///
+    /// If the result overflows, returns [`Huge`] or [`NegativeHuge`], with the sign of the result.\
+    /// If the result has a [`Huge`] denominator, returns [`Epsilon`] or [`NegativeEpsilon`], with the sign of the result.\
+    /// A [`Huge`] exponent makes bases larger than 1 [`Huge`] and smaller than 1 [`Epsilon`].
+    /// The sign of a negative base to a [`Huge`] power is unknown, so it returns [`Undefined`].\
+    /// If the result has a denominator of 0, such as 0 to a negative power, or either value is [`Undefined`], returns [`Undefined`].
+    /// 0 to a power whose sign isn't known, such as 0^x, is kept as a [`Power`].\
+    /// If the base is negative and the exponent is a fraction with an odd denominator, takes the real root, such as (-8)^(1/3) = -2.\
+    /// If the base is negative and the exponent is a fraction with a denominator of 2, takes the imaginary square root, such as (-4)^(1/2) = 2𝑖.\
+    /// If the base is negative and the exponent is any other non-integer, or either value is [`Complex`], returns [`Complex`].\
+    /// If the exponent is [`Epsilon`] or [`NegativeEpsilon`], the result is too close to 1 to operate on, and returns 1.\
+    /// If either value is [`Unknown`], returns [`Unknown`], unless the result is the same for any value, as with 1 to any power.\
+    /// If the exponent is a fraction, takes the root of the base, such as 4^(1/2) = 2 and 2^(1/2) = √2.\
+    /// Powers which can't be written any other way, such as 2^π, 2^(1/3), and π², are kept as a [`Power`],
+    /// as are powers of sums which would expand into too many terms, such as (π+1)^64.\
+    /// If the base is a fraction or the exponent is negative, returns the simplest fraction equal to the result.\
     /// Otherwise returns a [`Number`] with the value of the result.
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, atom::Atom, expr::{fraction::Fraction, power::Power, radical::Radical}};
+    /// assert_eq!(Notation::from(4).pow(Notation::from(Fraction::new(1, 2))), 2);
+    /// assert_eq!(Notation::from(2).pow(Notation::from(Fraction::new(1, 2))), Radical::new(2));
+    /// assert_eq!(Notation::from(2).pow(Notation::from(Atom::Pi)), Power::new(2, Atom::Pi));
+    /// assert_eq!(Notation::from(0).pow(Notation::from(0)), 1);
+    /// assert!(Notation::from(0).pow(Notation::from(-1)).atom().is_some_and(|x| x.is_undefined()));
+    /// assert_eq!(Notation::from(7).pow(Notation::from(Atom::Epsilon)), 1);
+    /// assert_eq!(Notation::from(2).pow(Notation::from(Fraction::new(6, 3))), 4);
+    /// assert!(Notation::from(Atom::NegativeHuge).pow(Notation::from(3)).atom().is_some_and(|x| x.is_negative_huge()));
+    /// assert!(Notation::from(Atom::Huge).pow(Notation::from(-1)).atom().is_some_and(|x| x.is_positive_epsilon()));
+    /// ```
     pub fn pow(self, rhs: Self) -> Self {
-        match self {
-            Notation::Atom(Number(Num { value: 0 | 1 })) => self,
-            base => match rhs {
-                Notation::Atom(atom) => match atom {
-                    Number(Num { value: exp }) => {
-                        let mut result = Notation::from(1);
-                        for _ in 0..exp.abs() {
-                            result = result * base.clone(); // This seems needlessly expensive...
-                        }
-
-                        if exp.is_positive() {
-                            result
-                        } else {
-                            Notation::from(1) / result
-                        }
-                    }
-                    Complex => todo!(),
-                    Undefined => todo!(),
-                    Huge => Notation::from(Huge), // is Huge even or odd??
-                    NegativeHuge => Notation::from(Epsilon),
-                    Epsilon => todo!(),
-                    NegativeEpsilon => todo!(),
-                },
-                Notation::Expr(_expr) => todo!(),
-            },
+        self.pow_with(rhs, Notation::from(1))
+    }
+
+    /// Puts one value to the power of another, the same as [`pow`][Notation::pow],
+    /// except that 0⁰ is `zero_to_the_zero`.
+    ///
+    /// 0⁰ has no single agreed value: it is usually 1 in algebra, but is left undefined when working with limits.
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, atom::Atom};
+    /// let zero = Notation::from(0);
+    /// let undefined = zero.clone().pow_with(zero.clone(), Notation::from(Atom::Undefined));
+    /// assert!(undefined.atom().is_some_and(|x| x.is_undefined()));
+    /// assert_eq!(zero.clone().pow_with(Notation::from(2), Notation::from(Atom::Undefined)), 0);
+    /// ```
+    pub fn pow_with(self, rhs: Self, zero_to_the_zero: Self) -> Self {
+        // Expressions such as 4/2 may simplify to atoms, which have exact rules
+        pow_simplified(self.simplify(), rhs.simplify(), zero_to_the_zero)
+    }
+}
+
+/// Puts one simplified value to the power of another, the same as [`Notation::pow_with`].
+pub(crate) fn pow_simplified(
+    base: Notation,
+    exp: Notation,
+    zero_to_the_zero: Notation,
+) -> Notation {
+    match (base, exp) {
+        (Notation::Atom(Undefined), _) | (_, Notation::Atom(Undefined)) => {
+            Notation::from(Undefined)
         }
+        (base, exp) if exp == 0 => match base == 0 {
+            true => zero_to_the_zero,
+            false => Notation::from(1),
+        },
+        (Notation::Atom(Complex), _) | (_, Notation::Atom(Complex)) => Notation::from(Complex),
+        (base, exp) if exp == 1 => base,
+        (base, _) if base.is_one() => base,
+        // Whether 0 to an unknown power is defined depends on the power's sign
+        (Notation::Atom(Unknown), _) | (_, Notation::Atom(Unknown)) => Notation::from(Unknown),
+        // 0 to a power of unknown sign, such as 0^x, may be 0, 1, or undefined
+        (base, exp) if base == 0 => match cmp_real(&exp, 0) {
+            Some(std::cmp::Ordering::Greater) => Notation::from(0),
+            Some(_) => Notation::from(Undefined),
+            None => Notation::from(Power::new(base, exp)),
+        },
+
+        (base, Notation::Atom(Number(Num { value: exp }))) => integer_pow(base, exp),
+
+        // Not integers, so a negative base only has a real power for odd roots
+        (base, exp @ (Notation::Atom(Epsilon | NegativeEpsilon | Pi | E) | Notation::Expr(_)))
+            if base.is_negative() =>
+        {
+            negative_pow(base, exp)
+        }
+
+        (_, Notation::Atom(Epsilon | NegativeEpsilon)) => Notation::from(1),
+        // Huge may be even, odd, or not an integer, so the sign of a negative base's power is unknown
+        (base, Notation::Atom(Huge | NegativeHuge)) if base.is_negative() => {
+            Notation::from(Undefined)
+        }
+        // A base greater than 1 grows without limit, and a base less than 1 shrinks
+        (base, Notation::Atom(exp @ (Huge | NegativeHuge))) => match cmp_real(&base, 1) {
+            Some(std::cmp::Ordering::Equal) => Notation::from(1),
+            Some(order) => saturate(order.is_gt() == exp.is_positive(), false),
+            None => Notation::from(Undefined),
+        },
+        (_, Notation::Expr(Expr::Complex(_))) => Notation::from(Complex),
+        // A positive power keeps large values large and small values small, and a negative power swaps them
+        (
+            Notation::Atom(base @ (Huge | Epsilon)),
+            exp @ (Notation::Atom(Pi | E) | Notation::Expr(_)),
+        ) => saturate(base.is_huge() != exp.is_negative(), false),
+        (base, exp) => match ratio(&exp) {
+            Some((num, den)) if den > 1 => fraction_pow(base, num, den),
+            Some((num, den)) if den < -1 => match (num.checked_neg(), den.checked_neg()) {
+                (Some(num), Some(den)) => fraction_pow(base, num, den),
+                _ => Notation::from(Power::new(base, exp)),
+            },
+            // Irrational and variable powers, such as 2^π, can only be kept as they are
+            _ => Notation::from(Power::new(base, exp)),
+        },
     }
 }
 
 #[cfg(test)]
 mod pow_test {
     use super::*;
+    use crate::notation::expr::fraction::Fraction;
 
     #[test]
     fn test_pow_simple() {
@@ -56,4 +322,319 @@ mod pow_test {
             assert_eq!(Notation::from(1).pow(Notation::from(exp)), 1);
         }
     }
+
+    #[test]
+    fn test_pow_integer() {
+        assert_eq!(Notation::from(2).pow(Notation::from(10)), 1024);
+        assert_eq!(Notation::from(-3).pow(Notation::from(3)), -27);
+        assert_eq!(Notation::from(7).pow(Notation::from(0)), 1);
+        assert_eq!(
+            Notation::from(2).pow(Notation::from(-3)),
+            Fraction::new(1, 8)
+        );
+    }
+
+    #[test]
+    fn test_pow_fraction() {
+        let two_thirds = Notation::from(Fraction::new(2, 3));
+        assert_eq!(
+            two_thirds.clone().pow(Notation::from(2)),
+            Fraction::new(4, 9)
+        );
+        assert_eq!(
+            two_thirds.clone().pow(Notation::from(-2)),
+            Fraction::new(9, 4)
+        );
+        assert_eq!(two_thirds.pow(Notation::from(0)), 1);
+        assert_eq!(
+            Notation::from(Fraction::new(-1, 2)).pow(Notation::from(3)),
+            Fraction::new(-1, 8)
+        );
+        assert_eq!(
+            Notation::from(Fraction::new(4, -6)).pow(Notation::from(-1)),
+            Fraction::new(-3, 2)
+        );
+        assert_eq!(
+            Notation::from(Fraction::new(3, 3)).pow(Notation::from(i32::MAX)),
+            1
+        );
+    }
+
+    #[test]
+    fn test_pow_saturates() {
+        let is = |value: Notation, check: fn(&crate::notation::atom::Atom) -> bool| {
+            assert!(value.atom().is_some_and(|x| check(&x)));
+        };
+        is(Notation::from(2).pow(Notation::from(40)), |x| {
+            x.is_positive_huge()
+        });
+        is(Notation::from(-2).pow(Notation::from(41)), |x| {
+            x.is_negative_huge()
+        });
+        is(Notation::from(-2).pow(Notation::from(200)), |x| {
+            x.is_positive_huge()
+        });
+        is(Notation::from(-2).pow(Notation::from(201)), |x| {
+            x.is_negative_huge()
+        });
+        is(
+            Notation::from(Fraction::new(1, 2)).pow(Notation::from(40)),
+            |x| x.is_positive_epsilon(),
+        );
+        is(
+            Notation::from(Fraction::new(-1, 2)).pow(Notation::from(-201)),
+            |x| x.is_negative_huge(),
+        );
+    }
+
+    #[test]
+    fn test_pow_zero() {
+        use crate::notation::atom::Atom;
+        let zero = Notation::from(0);
+        assert_eq!(zero.clone().pow(Notation::from(3)), 0);
+        assert_eq!(zero.clone().pow(Notation::from(Pi)), 0);
+        assert_eq!(zero.clone().pow(Notation::from(Epsilon)), 0);
+        assert_eq!(zero.clone().pow(Notation::from(Fraction::new(1, 2))), 0);
+        for exp in [
+            Notation::from(-2),
+            Notation::from(NegativeEpsilon),
+            Notation::from(NegativeHuge),
+            Notation::from(Fraction::new(-1, 2)),
+        ] {
+            let result = zero.clone().pow(exp.clone());
+            assert!(result.atom().is_some_and(|x| x.is_undefined()), "0^{exp}");
+        }
+        // Whether 0^x is 0, 1, or undefined depends on the sign of x
+        let x = Atom::Variable("x".into());
+        assert_eq!(
+            zero.clone().pow(Notation::from(x.clone())),
+            Power::new(0, x)
+        );
+        assert_eq!(zero.clone().pow(Notation::from(Huge)), 0);
+    }
+
+    #[test]
+    fn test_pow_exponent_table() {
+        use crate::notation::atom::Atom;
+        let result = |base: Notation, exp: Atom| base.pow(Notation::from(exp)).atom().unwrap();
+        for base in [
+            Notation::from(-3),
+            Notation::from(Fraction::new(5, 2)),
+            Notation::from(Pi),
+        ] {
+            assert!(result(base.clone(), Undefined).is_undefined());
+            assert!(result(base.clone(), Complex).is_complex());
+            assert_eq!(result(base.clone(), Atom::from(0)), 1);
+        }
+        assert!(result(Notation::from(Undefined), Atom::from(0)).is_undefined());
+        assert_eq!(result(Notation::from(Complex), Atom::from(0)), 1);
+        assert!(result(Notation::from(Complex), Atom::from(2)).is_complex());
+
+        assert_eq!(result(Notation::from(5), Epsilon), 1);
+        assert_eq!(
+            result(Notation::from(Fraction::new(1, 5)), NegativeEpsilon),
+            1
+        );
+        assert!(result(Notation::from(-5), Epsilon).is_complex());
+        assert!(result(Notation::from(NegativeHuge), Pi).is_complex());
+        assert!(result(Notation::from(Huge), E).is_positive_huge());
+        assert!(result(Notation::from(Epsilon), Pi).is_positive_epsilon());
+        assert_eq!(result(Notation::from(1), Pi), 1);
+    }
+
+    #[test]
+    fn test_pow_simplifies_first() {
+        assert_eq!(
+            Notation::from(Fraction::new(4, 2)).pow(Notation::from(Fraction::new(-6, 3))),
+            Fraction::new(1, 4)
+        );
+        assert_eq!(
+            Notation::from(-2).pow(Notation::from(Fraction::new(2, 4))),
+            Notation::from(-2).sqrt()
+        );
+    }
+
+    #[test]
+    fn test_pow_atom_matrix() {
+        use crate::notation::atom::Atom;
+        let atoms = [
+            Atom::from(-2),
+            Atom::from(-1),
+            Atom::from(0),
+            Atom::from(1),
+            Atom::from(2),
+            Huge,
+            NegativeHuge,
+            Epsilon,
+            NegativeEpsilon,
+            Pi,
+            E,
+            Complex,
+            Undefined,
+            Unknown,
+        ];
+        // Each row is a base and each column an exponent, in the order above.
+        let expected = [
+            [
+                "1/4", "-1/2", "1", "-2", "4", "∅", "∅", "ℂ", "ℂ", "ℂ", "ℂ", "ℂ", "∅", "?",
+            ],
+            [
+                "1", "-1", "1", "-1", "1", "∅", "∅", "ℂ", "ℂ", "ℂ", "ℂ", "ℂ", "∅", "?",
+            ],
+            [
+                "∅", "∅", "1", "0", "0", "0", "∅", "0", "∅", "0", "0", "ℂ", "∅", "?",
+            ],
+            [
+                "1", "1", "1", "1", "1", "1", "1", "1", "1", "1", "1", "ℂ", "∅", "1",
+            ],
+            [
+                "1/4", "1/2", "1", "2", "4", "𝓗", "ε", "1", "1", "2^π", "2^𝑒", "ℂ", "∅", "?",
+            ],
+            [
+                "ε", "ε", "1", "𝓗", "𝓗", "𝓗", "ε", "1", "1", "𝓗", "𝓗", "ℂ", "∅", "?",
+            ],
+            [
+                "ε", "-ε", "1", "-𝓗", "𝓗", "∅", "∅", "ℂ", "ℂ", "ℂ", "ℂ", "ℂ", "∅", "?",
+            ],
+            [
+                "𝓗", "𝓗", "1", "ε", "ε", "ε", "𝓗", "1", "1", "ε", "ε", "ℂ", "∅", "?",
+            ],
+            [
+                "𝓗", "-𝓗", "1", "-ε", "ε", "∅", "∅", "ℂ", "ℂ", "ℂ", "ℂ", "ℂ", "∅", "?",
+            ],
+            [
+                "π⁻²", "1/π", "1", "π", "π²", "𝓗", "ε", "1", "1", "π^π", "π^𝑒", "ℂ", "∅", "?",
+            ],
+            [
+                "𝑒⁻²",
+                "1/𝑒",
+                "1",
+                "𝑒",
+                "𝑒²",
+                "𝓗",
+                "ε",
+                "1",
+                "1",
+                "𝑒^π",
+                "𝑒^𝑒",
+                "ℂ",
+                "∅",
+                "?",
+            ],
+            [
+                "ℂ", "ℂ", "1", "ℂ", "ℂ", "ℂ", "ℂ", "ℂ", "ℂ", "ℂ", "ℂ", "ℂ", "∅", "ℂ",
+            ],
+            [
+                "∅", "∅", "∅", "∅", "∅", "∅", "∅", "∅", "∅", "∅", "∅", "∅", "∅", "∅",
+            ],
+            [
+                "?", "?", "1", "?", "?", "?", "?", "?", "?", "?", "?", "ℂ", "∅", "?",
+            ],
+        ];
+        for (base, row) in atoms.iter().zip(expected) {
+            for (exp, expected) in atoms.iter().zip(row) {
+                let result = Notation::from(base.clone()).pow(Notation::from(exp.clone()));
+                assert_eq!(result.to_string(), expected, "{base}^{exp}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_pow_roots() {
+        use crate::notation::expr::radical::Radical;
+        let pow = |base: i32, num: i32, den: i32| {
+            Notation::from(base).pow(Notation::from(Fraction::new(num, den)))
+        };
+        assert_eq!(pow(4, 1, 2), 2);
+        assert_eq!(pow(8, 2, 3), 4);
+        assert_eq!(pow(8, -1, 3), Fraction::new(1, 2));
+        assert_eq!(pow(2, 1, 2), Radical::new(2));
+        assert_eq!(pow(2, 1, 4), Notation::from(Radical::new(2)).sqrt());
+        assert_eq!(pow(2, 1, 3), Power::new(2, Fraction::new(1, 3)));
+        // Odd roots of negatives are real
+        assert_eq!(pow(-8, 1, 3), -2);
+        assert_eq!(pow(-8, 2, 3), 4);
+        assert_eq!(pow(-27, -1, 3), Fraction::new(-1, 3));
+        assert_eq!(pow(-2, 1, 3).to_string(), "(-1)·2^(1/3)");
+        // Square roots of negatives are imaginary, the same as `sqrt`
+        assert_eq!(pow(-4, 1, 2), Notation::from(-4).sqrt());
+        assert_eq!(pow(-4, 1, 2).to_string(), "2𝑖");
+        assert_eq!(pow(-4, 3, 2).to_string(), "-8𝑖");
+        assert_eq!(pow(-4, -1, 2).to_string(), "-𝑖/2");
+        assert!(pow(-4, 1, 4).atom().is_some_and(|x| x.is_complex()));
+    }
+
+    #[test]
+    fn test_pow_kept() {
+        use crate::notation::{atom::Atom, expr::radical::Radical};
+        let root = || Notation::from(Radical::new(2));
+        assert_eq!(Notation::from(2).pow(Notation::from(Pi)), Power::new(2, Pi));
+        assert_eq!(
+            root().pow(root()),
+            Power::new(Radical::new(2), Radical::new(2))
+        );
+        assert_eq!(Notation::from(Pi).pow(Notation::from(2)), Power::new(Pi, 2));
+        assert_eq!(
+            Notation::from(Pi).pow(Notation::from(-1)).to_string(),
+            "1/π"
+        );
+        let x = || Notation::from(Atom::Variable("x".into()));
+        assert_eq!(
+            x().pow(Notation::from(Pi)),
+            Power::new(Atom::Variable("x".into()), Pi)
+        );
+        // (a^b)^c = a^(bc)
+        assert_eq!(
+            Notation::from(Power::new(Pi, 2)).pow(Notation::from(3)),
+            Power::new(Pi, 6)
+        );
+        assert!(Notation::from(Huge)
+            .pow(root())
+            .atom()
+            .is_some_and(|x| x.is_positive_huge()));
+        assert!(root()
+            .pow(Notation::from(1000))
+            .atom()
+            .is_some_and(|x| x.is_positive_huge()));
+    }
+
+    #[test]
+    fn test_pow_sums_kept() {
+        use crate::notation::{expr::simplify::Simplify, parse::ParseOptions};
+        let pow = |text: &str| {
+            Notation::parse_with(text, &ParseOptions::untrusted())
+                .unwrap()
+                .simplify_full()
+                .to_string()
+        };
+        // Expanding these would take millions of multiplications, or never finish
+        assert_eq!(pow("(π+1)^64"), "(π+1)⁶⁴");
+        assert_eq!(pow("(x+1)^40"), "(x+1)⁴⁰");
+        assert_eq!(pow("(2+4𝑖+y)^-537591"), "(2+y+4𝑖)⁻⁵³⁷⁵⁹¹");
+        // Powers with few enough terms are still expanded
+        assert_eq!(pow("(1+√2)^2"), "3+2√2");
+        assert_eq!(pow("(1+𝑖)^20"), "-1024");
+        assert_eq!(expanded_terms(2, 15), Some(16));
+        assert_eq!(expanded_terms(3, 4), Some(15));
+        assert_eq!(expanded_terms(2, 16), None);
+        assert_eq!(expanded_terms(2, u32::MAX), None);
+    }
+
+    #[test]
+    fn test_pow_huge_exponent() {
+        let huge = || Notation::from(Huge);
+        let neg_huge = || Notation::from(NegativeHuge);
+        let half = || Notation::from(Fraction::new(1, 2));
+        assert_eq!(half().pow(huge()).to_string(), "ε");
+        assert_eq!(half().pow(neg_huge()).to_string(), "𝓗");
+        assert_eq!(
+            Notation::from(Fraction::new(3, 2)).pow(huge()).to_string(),
+            "𝓗"
+        );
+        assert_eq!(
+            Notation::from(Fraction::new(-1, 2)).pow(huge()).to_string(),
+            "∅"
+        );
+        assert_eq!(Notation::from(Fraction::new(3, 3)).pow(huge()), 1);
+    }
 }
@@ -0,0 +1,283 @@
+//! Arithmetic on plain integers, with the same saturation as the operators on [`Notation`].
+//!
+//! Rather than overflowing, a result too large for an [`i32`] becomes [`Huge`] or [`NegativeHuge`],
+//! so these are the building blocks for custom expression logic which should agree with the operators.
+
+use super::{rational, saturate};
+use crate::notation::{atom::Atom::*, Notation};
+
+/// Adds two integers, saturating instead of overflowing.
+///
+/// If the result overflows, returns [`Huge`].\
+/// If the result underflows, returns [`NegativeHuge`].\
+/// Otherwise returns a [`Number`] with the value of the result.
+///
+/// ```
+/// # use algebra::notation::ops::primitive::algebraic_add;
+/// assert_eq!(algebraic_add(2, 3), 5);
+/// assert!(algebraic_add(i32::MAX, 1).atom().is_some_and(|x| x.is_positive_huge()));
+/// ```
+pub fn algebraic_add(lhs: i32, rhs: i32) -> Notation {
+    match lhs.checked_add(rhs) {
+        // All is well
+        Some(sum) => Notation::from(sum),
+
+        // Over or under flow (need to figure out which)
+        None => match lhs.saturating_add(rhs) {
+            i32::MAX => Notation::from(Huge),
+            i32::MIN => Notation::from(NegativeHuge),
+            _ => unreachable!("Saturated over/underflow should be equal to max/min respectively."),
+        },
+    }
+}
+
+#[cfg(test)]
+mod algebraic_add_tests {
+    use super::*;
+
+    #[test]
+    fn test_basic_addition() {
+        for a in -10..=10 {
+            for b in -10..=10 {
+                assert_eq!(algebraic_add(a, b), a + b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_positive_huge_zero_addition() {
+        let sum = algebraic_add(0, i32::MAX).atom().unwrap();
+        assert_eq!(sum, i32::MAX);
+    }
+
+    #[test]
+    fn test_positive_huge_positive_addition() {
+        for i in 1..=10 {
+            let sum = algebraic_add(i, i32::MAX).atom().unwrap();
+            assert!(sum.is_positive_huge());
+        }
+    }
+
+    #[test]
+    fn test_positive_huge_negative_addition() {
+        for i in -10..=-1 {
+            let sum = algebraic_add(i, i32::MAX).atom().unwrap();
+            assert_eq!(sum, i32::MAX + i);
+        }
+    }
+
+    #[test]
+    fn test_positive_addition_becomes_huge() {
+        const HUGE_PART: i32 = i32::MAX / 2 + 1;
+        let sum = algebraic_add(HUGE_PART, HUGE_PART).atom().unwrap();
+        assert!(sum.is_positive_huge());
+    }
+
+    #[test]
+    fn test_negative_huge_addition() {
+        let sum = algebraic_add(0, i32::MIN).atom().unwrap();
+        assert_eq!(sum, i32::MIN);
+    }
+
+    #[test]
+    fn test_negative_huge_positive_addition() {
+        for i in 1..=10 {
+            let sum = algebraic_add(i, i32::MIN).atom().unwrap();
+            assert_eq!(sum, i32::MIN + i);
+        }
+    }
+
+    #[test]
+    fn test_negative_huge_negative_addition() {
+        for i in -10..=-1 {
+            let sum = algebraic_add(i, i32::MIN).atom().unwrap();
+            assert!(sum.is_negative_huge());
+        }
+    }
+
+    #[test]
+    fn test_negative_addition_becomes_negative_huge() {
+        const NHUGE_PART: i32 = i32::MIN / 2 - 1;
+        let sum = algebraic_add(NHUGE_PART, NHUGE_PART).atom().unwrap();
+        assert!(sum.is_negative_huge());
+    }
+
+    #[test]
+    fn test_positive_and_negative_huge_addition_not_huge() {
+        let sum_huges = algebraic_add(i32::MIN, i32::MAX).atom().unwrap();
+        assert_eq!(sum_huges, -1);
+    }
+}
+
+/// Subtracts one integer from another, saturating instead of overflowing.
+///
+/// If the result overflows, returns [`Huge`].\
+/// If the result underflows, returns [`NegativeHuge`].\
+/// Otherwise returns a [`Number`] with the value of the result.
+///
+/// ```
+/// # use algebra::notation::ops::primitive::algebraic_sub;
+/// assert_eq!(algebraic_sub(2, 3), -1);
+/// assert!(algebraic_sub(0, i32::MIN).atom().is_some_and(|x| x.is_positive_huge()));
+/// ```
+pub fn algebraic_sub(lhs: i32, rhs: i32) -> Notation {
+    match lhs.checked_sub(rhs) {
+        // All is well
+        Some(difference) => Notation::from(difference),
+
+        // Subtracting a positive number can only underflow, and subtracting a negative one can only overflow
+        None => saturate(true, rhs > 0),
+    }
+}
+
+#[cfg(test)]
+mod algebraic_sub_tests {
+    use super::*;
+
+    #[test]
+    fn test_basic_subtraction() {
+        for a in -10..=10 {
+            for b in -10..=10 {
+                assert_eq!(algebraic_sub(a, b), a - b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_overflowing_subtraction() {
+        let difference = algebraic_sub(i32::MAX, -1).atom().unwrap();
+        assert!(difference.is_positive_huge());
+
+        // The negative of i32::MIN doesn't fit in an i32, but the difference can
+        let difference = algebraic_sub(-1, i32::MIN).atom().unwrap();
+        assert_eq!(difference, i32::MAX);
+
+        let difference = algebraic_sub(0, i32::MIN).atom().unwrap();
+        assert!(difference.is_positive_huge());
+    }
+
+    #[test]
+    fn test_underflowing_subtraction() {
+        let difference = algebraic_sub(i32::MIN, 1).atom().unwrap();
+        assert!(difference.is_negative_huge());
+
+        let difference = algebraic_sub(-2, i32::MAX).atom().unwrap();
+        assert!(difference.is_negative_huge());
+
+        let difference = algebraic_sub(-1, i32::MAX).atom().unwrap();
+        assert_eq!(difference, i32::MIN);
+    }
+}
+
+/// Multiplies two integers, saturating instead of overflowing.
+///
+/// If the result overflows, returns [`Huge`].\
+/// If the result underflows, returns [`NegativeHuge`].\
+/// Otherwise returns a [`Number`] with the value of the result.
+///
+/// ```
+/// # use algebra::notation::ops::primitive::algebraic_mul;
+/// assert_eq!(algebraic_mul(-2, 3), -6);
+/// assert!(algebraic_mul(i32::MAX, -2).atom().is_some_and(|x| x.is_negative_huge()));
+/// ```
+pub fn algebraic_mul(lhs: i32, rhs: i32) -> Notation {
+    match lhs.checked_mul(rhs) {
+        // All is well
+        Some(prod) => Notation::from(prod),
+
+        // Over or under flow (need to figure out which)
+        None => match lhs.saturating_mul(rhs) {
+            i32::MAX => Notation::from(Huge),
+            i32::MIN => Notation::from(NegativeHuge),
+            _ => unreachable!("Saturated over/underflow should be equal to max/min respectively."),
+        },
+    }
+}
+
+#[cfg(test)]
+mod algebraic_mul_tests {
+    use super::*;
+
+    #[test]
+    fn test_basic_multiplication() {
+        for a in -10..=10 {
+            for b in -10..=10 {
+                assert_eq!(algebraic_mul(a, b), a * b);
+                assert_eq!(algebraic_mul(b, a), b * a);
+            }
+        }
+    }
+
+    #[test]
+    fn test_overflowing_multiplication() {
+        let prod = algebraic_mul(i32::MAX, 2).atom().unwrap();
+        assert!(prod.is_positive_huge());
+
+        let prod = algebraic_mul(2, i32::MAX).atom().unwrap();
+        assert!(prod.is_positive_huge());
+    }
+
+    #[test]
+    fn test_underflowing_multiplication() {
+        let prod = algebraic_mul(i32::MAX, -2).atom().unwrap();
+        assert!(prod.is_negative_huge());
+
+        let prod = algebraic_mul(-2, i32::MAX).atom().unwrap();
+        assert!(prod.is_negative_huge());
+    }
+}
+
+/// Divides one integer by another exactly, saturating instead of overflowing.
+///
+/// If the divisor is 0, returns [`Undefined`].\
+/// If the result overflows, which only `i32::MIN / -1` does, returns [`Huge`].\
+/// If the result is an integer, returns a [`Number`] with the value of the result.\
+/// Otherwise returns the [`Fraction`][crate::notation::expr::fraction::Fraction] in lowest terms.
+///
+/// ```
+/// # use algebra::notation::{ops::primitive::algebraic_div, expr::fraction::Fraction};
+/// assert_eq!(algebraic_div(6, -3), -2);
+/// assert_eq!(algebraic_div(6, 4), Fraction::new(3, 2));
+/// assert!(algebraic_div(1, 0).atom().is_some_and(|x| x.is_undefined()));
+/// ```
+pub fn algebraic_div(lhs: i32, rhs: i32) -> Notation {
+    rational(lhs as i128, rhs as i128)
+}
+
+#[cfg(test)]
+mod algebraic_div_tests {
+    use super::*;
+    use crate::notation::expr::fraction::Fraction;
+
+    #[test]
+    fn test_exact_division() {
+        for a in -10..=10 {
+            for b in (-10..=10).filter(|b| *b != 0 && a % b == 0) {
+                assert_eq!(algebraic_div(a, b), a / b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_lowest_terms() {
+        assert_eq!(algebraic_div(4, 6), Fraction::new(2, 3));
+        assert_eq!(algebraic_div(4, -6), Fraction::new(-2, 3));
+        assert_eq!(
+            algebraic_div(i32::MIN, i32::MAX),
+            Fraction::new(i32::MIN, i32::MAX)
+        );
+    }
+
+    #[test]
+    fn test_division_by_zero() {
+        assert!(algebraic_div(0, 0).atom().unwrap().is_undefined());
+        assert!(algebraic_div(i32::MAX, 0).atom().unwrap().is_undefined());
+    }
+
+    #[test]
+    fn test_overflowing_division() {
+        let quotient = algebraic_div(i32::MIN, -1).atom().unwrap();
+        assert!(quotient.is_positive_huge());
+        assert_eq!(algebraic_div(i32::MIN, 1), i32::MIN);
+    }
+}
@@ -0,0 +1,218 @@
+//! Algebraic remainder and Euclidean division
+
+use super::{ratio, rational, table::class_of};
+use crate::notation::{atom::Atom::*, rules::AtomClass, Notation};
+
+/// Which of the operations sharing a quotient is taken.
+#[derive(Clone, Copy, PartialEq)]
+enum Kind {
+    /// The remainder after truncating the quotient, with the sign of the dividend.
+    Rem,
+
+    /// The remainder after flooring the quotient for a positive modulus, which is never negative.
+    RemEuclid,
+
+    /// The quotient which leaves the remainder given by [`Kind::RemEuclid`].
+    DivEuclid,
+}
+
+fn euclid(kind: Kind, lhs: Notation, rhs: Notation) -> Notation {
+    use AtomClass as A;
+    if let (Some((a, b)), Some((c, d))) = (ratio(&lhs), ratio(&rhs)) {
+        // Over the common denominator bd, a/b and c/d have the integer numerators ad and cb
+        let (a, b, c, d) = (a as i128, b as i128, c as i128, d as i128);
+        let sign = b.signum() * d.signum();
+        let (num, modulus, den) = (a * d * sign, c * b * sign, (b * d).abs());
+        if modulus == 0 {
+            return Notation::from(Undefined);
+        }
+        return match kind {
+            Kind::Rem => rational(num % modulus, den),
+            Kind::RemEuclid => rational(num.rem_euclid(modulus), den),
+            Kind::DivEuclid => rational(num.div_euclid(modulus), 1),
+        };
+    }
+    let (Some(num), Some(modulus)) = (class_of(&lhs), class_of(&rhs)) else {
+        return Notation::from(Undefined);
+    };
+    match (num, modulus) {
+        (A::Undefined | A::Complex, _) | (_, A::Undefined | A::Complex | A::Zero) => {
+            Notation::from(Undefined)
+        }
+        // Which remainder a huge value leaves can't be known, so it stays huge
+        (A::Huge | A::NegativeHuge, _) => match kind {
+            Kind::Rem => lhs,
+            Kind::RemEuclid => Notation::from(Huge),
+            Kind::DivEuclid => lhs / rhs,
+        },
+        // A huge modulus is larger than any ratio, which is its own remainder
+        (num, A::Huge | A::NegativeHuge) if ratio(&lhs).is_some() => {
+            match (kind, num == A::Negative) {
+                (Kind::Rem, _) | (Kind::RemEuclid, false) => lhs,
+                // Adding the huge magnitude of the modulus leaves a huge remainder
+                (Kind::RemEuclid, true) => Notation::from(Huge),
+                (Kind::DivEuclid, false) => Notation::from(0),
+                (Kind::DivEuclid, true) if modulus == A::Huge => Notation::from(-1),
+                (Kind::DivEuclid, true) => Notation::from(1),
+            }
+        }
+        // Irrational values, and values very close to 0, have no exact remainder
+        _ => Notation::from(Undefined),
+    }
+}
+
+impl std::ops::Rem for Notation {
+    type Output = Self;
+
+    /// Finds the remainder of dividing two rational values, with the sign of the dividend, the same as [`i32`]'s `%`.
+    ///
+    /// If the modulus is 0, or either value is [`Undefined`] or [`Complex`], returns [`Undefined`].\
+    /// A [`Huge`] or [`NegativeHuge`] dividend is returned unchanged, since its remainder can't be known,
+    /// and a rational value is its own remainder for a huge modulus.\
+    /// Irrational values and values very close to 0 have no exact remainder, and give [`Undefined`].
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, expr::fraction::Fraction};
+    /// assert_eq!(Notation::from(-7) % Notation::from(3), -1);
+    /// assert_eq!(Notation::from(Fraction::new(7, 2)) % Notation::from(1), Fraction::new(1, 2));
+    /// ```
+    fn rem(self, rhs: Self) -> Self::Output {
+        euclid(Kind::Rem, self, rhs)
+    }
+}
+
+impl Notation {
+    /// Finds the remainder of dividing two rational values, which is never negative, the same as [`i32::rem_euclid`].
+    ///
+    /// Special values are handled the same way as by [`%`](#impl-Rem-for-Notation),
+    /// except that the remainder of a huge dividend, or of a negative dividend by a huge modulus, is [`Huge`].
+    ///
+    /// ```
+    /// # use algebra::notation::Notation;
+    /// assert_eq!(Notation::from(-7).rem_euclid(Notation::from(3)), 2);
+    /// assert_eq!(Notation::from(-7).rem_euclid(Notation::from(-3)), 2);
+    /// ```
+    pub fn rem_euclid(self, rhs: Self) -> Self {
+        euclid(Kind::RemEuclid, self, rhs)
+    }
+
+    /// Finds the integer quotient which leaves the remainder given by [`rem_euclid`][Notation::rem_euclid],
+    /// the same as [`i32::div_euclid`].
+    ///
+    /// If the modulus is 0, or either value is [`Undefined`] or [`Complex`], returns [`Undefined`].\
+    /// A [`Huge`] or [`NegativeHuge`] dividend gives the same quotient as [`/`](#impl-Div-for-Notation),
+    /// and a quotient which overflows returns [`Huge`] or [`NegativeHuge`].
+    ///
+    /// ```
+    /// # use algebra::notation::Notation;
+    /// assert_eq!(Notation::from(-7).div_euclid(Notation::from(3)), -3);
+    /// assert!(Notation::from(i32::MIN).div_euclid(Notation::from(-1)).atom().is_some_and(|x| x.is_positive_huge()));
+    /// ```
+    pub fn div_euclid(self, rhs: Self) -> Self {
+        euclid(Kind::DivEuclid, self, rhs)
+    }
+}
+
+#[cfg(test)]
+mod rem_tests {
+    use super::*;
+    use crate::notation::expr::{fraction::Fraction, radical::Radical};
+
+    fn is(value: Notation, test: impl Fn(&crate::notation::atom::Atom) -> bool) -> bool {
+        value.atom().is_some_and(|x| test(&x))
+    }
+
+    #[test]
+    fn test_matches_integers() {
+        for a in -12..=12 {
+            for b in (-5..=5).filter(|b| *b != 0) {
+                let (lhs, rhs) = (Notation::from(a), Notation::from(b));
+                assert_eq!(lhs.clone() % rhs.clone(), a % b);
+                assert_eq!(lhs.clone().rem_euclid(rhs.clone()), a.rem_euclid(b));
+                assert_eq!(lhs.div_euclid(rhs), a.div_euclid(b));
+            }
+        }
+    }
+
+    #[test]
+    fn test_extremes() {
+        assert_eq!(Notation::from(i32::MIN) % Notation::from(-1), 0);
+        assert_eq!(
+            Notation::from(i32::MIN).rem_euclid(Notation::from(i32::MAX)),
+            i32::MAX - 1
+        );
+        assert!(is(
+            Notation::from(i32::MIN).div_euclid(Notation::from(-1)),
+            |x| x.is_positive_huge()
+        ));
+    }
+
+    #[test]
+    fn test_ratios() {
+        let half = || Notation::from(Fraction::new(1, 2));
+        assert_eq!(
+            Notation::from(Fraction::new(-7, 2)) % Notation::from(1),
+            Fraction::new(-1, 2)
+        );
+        assert_eq!(
+            Notation::from(Fraction::new(-7, 2)).rem_euclid(Notation::from(1)),
+            half()
+        );
+        assert_eq!(Notation::from(Fraction::new(7, 3)).div_euclid(half()), 4);
+        assert_eq!(
+            Notation::from(Fraction::new(7, 3)) % Notation::from(Fraction::new(-1, 2)),
+            Fraction::new(1, 3)
+        );
+        // Unsimplified fractions with negative denominators
+        assert_eq!(
+            Notation::from(Fraction::new(7, -2)).rem_euclid(Notation::from(Fraction::new(3, -1))),
+            Fraction::new(5, 2)
+        );
+    }
+
+    #[test]
+    fn test_zero_modulus() {
+        assert!(is(Notation::from(5) % Notation::from(0), |x| x.is_undefined()));
+        assert!(is(
+            Notation::from(5).rem_euclid(Notation::from(Fraction::new(0, 2))),
+            |x| x.is_undefined()
+        ));
+        assert!(is(
+            Notation::from(Huge).div_euclid(Notation::from(0)),
+            |x| x.is_undefined()
+        ));
+    }
+
+    #[test]
+    fn test_huge() {
+        let (huge, negative_huge) = (|| Notation::from(Huge), || Notation::from(NegativeHuge));
+        assert!(is(huge() % Notation::from(7), |x| x.is_positive_huge()));
+        assert!(is(negative_huge() % Notation::from(7), |x| x
+            .is_negative_huge()));
+        assert!(is(negative_huge().rem_euclid(Notation::from(7)), |x| x
+            .is_positive_huge()));
+        assert!(is(huge().div_euclid(Notation::from(-7)), |x| x
+            .is_negative_huge()));
+
+        assert_eq!(Notation::from(-3) % huge(), -3);
+        assert_eq!(Notation::from(3).rem_euclid(negative_huge()), 3);
+        assert!(is(Notation::from(-3).rem_euclid(huge()), |x| x
+            .is_positive_huge()));
+        assert_eq!(Notation::from(3).div_euclid(huge()), 0);
+        assert_eq!(Notation::from(-3).div_euclid(huge()), -1);
+        assert_eq!(Notation::from(-3).div_euclid(negative_huge()), 1);
+    }
+
+    #[test]
+    fn test_no_exact_remainder() {
+        for (lhs, rhs) in [
+            (Notation::from(Pi), Notation::from(2)),
+            (Notation::from(Radical::new(2)), Notation::from(1)),
+            (Notation::from(5), Notation::from(Epsilon)),
+            (Notation::from(Complex), Notation::from(2)),
+            (Notation::from(2), Notation::from(Undefined)),
+        ] {
+            assert!(is(lhs % rhs, |x| x.is_undefined()));
+        }
+    }
+}
@@ -0,0 +1,152 @@
+//! Algebraic square roots
+
+use super::{neg::neg_ratio, ratio, saturate};
+use crate::notation::{
+    atom::Atom::*,
+    expr::{
+        complex::Complex, nested_radical::NestedRadical, radical::sqrt_i,
+        radical_fraction::RadicalFraction, simplify::Simplify,
+    },
+    Notation,
+};
+
+/// Takes the square root of `num / den` as a radical over an integer.
+///
+/// The root of the denominator is moved to the numerator, as `√(num × den) / den`,
+/// unless the denominator is a perfect square.
+/// If that radicand doesn't fit in an [`i32`], saturates the same way as [`rational`][super::rational].\
+/// The root of a negative is imaginary, as `𝑖√(-num / den)`.
+fn rational_sqrt(num: i32, den: i32) -> Notation {
+    if num != 0 && (num < 0) != (den < 0) {
+        let (num, den) = neg_ratio(num, den);
+        return Complex::new(0, rational_sqrt(num, den)).simplify();
+    }
+    let (num, den) = match (num.checked_abs(), den.checked_abs()) {
+        (Some(num), Some(den)) => (num, den),
+        // The magnitude of i32::MIN is 2^31, whose root is 2^15 × √2
+        (None, Some(den)) => return Notation::from(1 << 15) * rational_sqrt(2, den),
+        (Some(num), None) => return rational_sqrt(num, 2) / Notation::from(1 << 15),
+        (None, None) => return Notation::from(1),
+    };
+    if let Some(root) = sqrt_i(den) {
+        return RadicalFraction::new(1, num, root).simplify();
+    }
+    match num.checked_mul(den) {
+        Some(rad) => RadicalFraction::new(1, rad, den).simplify(),
+        None => saturate(num >= den, false),
+    }
+}
+
+impl Notation {
+    /// Takes the principal square root, which is never negative.
+    ///
+    /// The square root of a fraction is written with the root in the numerator, such as √(1/2) = √2/2.\
+    /// [`Huge`] and [`Epsilon`] are their own square roots, and the square root of [`Unknown`] is unknown.\
+    /// The square root of a negative rational number is imaginary, such as √-4 = 2𝑖,
+    /// as are the square roots of [`NegativeHuge`] and [`NegativeEpsilon`].\
+    /// If the value is [`Complex`], returns [`Complex`].\
+    /// If the value is [`Undefined`], returns [`Undefined`].\
+    /// The square root of any other value is a [`NestedRadical`], denested if possible, such as √(3+2√2) = 1+√2.
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, atom::Atom, expr::{complex::Complex, fraction::Fraction, radical_fraction::RadicalFraction}};
+    /// assert_eq!(Notation::from(Fraction::new(4, 9)).sqrt(), Fraction::new(2, 3));
+    /// assert_eq!(Notation::from(Fraction::new(1, 2)).sqrt(), RadicalFraction::new(1, 2, 2));
+    /// assert!(Notation::from(Atom::Huge).sqrt().atom().is_some_and(|x| x.is_positive_huge()));
+    /// assert_eq!(Notation::from(-4).sqrt(), Complex::new(0, 2));
+    /// assert_ne!(Notation::from(-4).sqrt(), Notation::from(-9).sqrt());
+    /// ```
+    pub fn sqrt(self) -> Self {
+        let value = self.simplify();
+        if let Some((num, den)) = ratio(&value) {
+            return rational_sqrt(num, den);
+        }
+        match value {
+            Notation::Atom(atom @ (Undefined | Complex | Unknown | Huge | Epsilon)) => {
+                Notation::from(atom)
+            }
+            Notation::Atom(atom @ (NegativeHuge | NegativeEpsilon)) => {
+                Complex::new(0, -atom).simplify()
+            }
+            value => NestedRadical::new(value).simplify(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::expr::{fraction::Fraction, radical::Radical};
+
+    #[test]
+    fn test_sqrt_integer() {
+        assert_eq!(Notation::from(9).sqrt(), 3);
+        assert_eq!(Notation::from(0).sqrt(), 0);
+        assert_eq!(Notation::from(12).sqrt(), Radical { coef: 2, rad: 3 });
+        assert_eq!(Notation::from(-1).sqrt(), Complex::i());
+        assert_eq!(
+            Notation::from(-12).sqrt(),
+            Complex::new(0, Radical { coef: 2, rad: 3 })
+        );
+        assert_eq!(
+            Notation::from(i32::MIN).sqrt(),
+            Complex::new(
+                0,
+                Radical {
+                    coef: 1 << 15,
+                    rad: 2
+                }
+            )
+        );
+    }
+
+    #[test]
+    fn test_sqrt_fraction() {
+        let sqrt = |num, den| Notation::from(Fraction::new(num, den)).sqrt();
+        assert_eq!(sqrt(4, 9), Fraction::new(2, 3));
+        assert_eq!(sqrt(1, 2), RadicalFraction::new(1, 2, 2));
+        assert_eq!(sqrt(2, 9), RadicalFraction::new(1, 2, 3));
+        assert_eq!(sqrt(8, 3), RadicalFraction::new(2, 6, 3));
+        assert_eq!(sqrt(8, 2), 2);
+        assert_eq!(sqrt(-4, -9), Fraction::new(2, 3));
+        assert_eq!(sqrt(1, -4), Complex::new(0, Fraction::new(1, 2)));
+        assert_eq!(sqrt(-2, 9), Complex::new(0, RadicalFraction::new(1, 2, 3)));
+        assert_eq!(sqrt(i32::MIN, -2), 1 << 15);
+        assert_eq!(sqrt(-2, i32::MIN), Fraction::new(1, 1 << 15));
+        assert_eq!(
+            sqrt(2, i32::MIN),
+            Complex::new(0, Fraction::new(1, 1 << 15))
+        );
+        assert!(sqrt(1, 0).atom().is_some_and(|x| x.is_undefined()));
+    }
+
+    #[test]
+    fn test_sqrt_atoms() {
+        let sqrt = |atom| Notation::from(atom).sqrt();
+        assert!(sqrt(Huge).atom().is_some_and(|x| x.is_positive_huge()));
+        assert!(sqrt(Epsilon)
+            .atom()
+            .is_some_and(|x| x.is_positive_epsilon()));
+        assert_eq!(sqrt(NegativeHuge).to_string(), "𝓗𝑖");
+        assert_eq!(sqrt(NegativeEpsilon).to_string(), "ε𝑖");
+        assert!(sqrt(Complex).atom().is_some_and(|x| x.is_complex()));
+        assert!(sqrt(Undefined).atom().is_some_and(|x| x.is_undefined()));
+    }
+
+    #[test]
+    fn test_sqrt_expressions() {
+        let root = (Radical::from(3) + Radical { coef: 2, rad: 2 }).sqrt();
+        assert_eq!(root.to_string(), "√2+1");
+        assert_eq!(Notation::from(Pi).sqrt(), NestedRadical::new(Pi));
+        assert_eq!((-Notation::from(Pi)).sqrt().to_string(), "𝑖√π");
+        let root = (Radical::from(2) + Radical::new(2)).sqrt();
+        assert_eq!(root.to_string(), "√(2+√2)");
+        assert_eq!(root.clone() * root, Radical::from(2) + Radical::new(2));
+    }
+
+    #[test]
+    fn test_sqrt_saturates() {
+        let big = Notation::from(Fraction::new(i32::MAX, 3)).sqrt();
+        assert!(big.atom().is_some_and(|x| x.is_positive_huge()));
+    }
+}
@@ -0,0 +1,176 @@
+//! Algebraic subtraction
+
+use super::{
+    multiple_of,
+    primitive::algebraic_sub,
+    ratio, scaled, sub_ratios,
+    table::{impl_op, Op},
+};
+use crate::notation::{
+    atom::{number::Number, Atom},
+    expr::simplify::Simplify,
+    Notation,
+};
+
+/// Subtracts values which aren't settled by the [tables](super::table).
+fn sub(lhs: Notation, rhs: Notation) -> Notation {
+    match (lhs, rhs) {
+        (
+            Notation::Atom(Atom::Number(Number { value: num_a })),
+            Notation::Atom(Atom::Number(Number { value: num_b })),
+        ) => algebraic_sub(num_a, num_b),
+        (lhs, rhs) => match (ratio(&lhs), ratio(&rhs)) {
+            // a/b - c/d over their least common denominator
+            (Some(lhs), Some(rhs)) => sub_ratios(lhs, rhs),
+            _ => match (multiple_of(&lhs), multiple_of(&rhs)) {
+                // (a/b)π - (c/d)π = ((ad - cb)/bd)π
+                (Some((a, b, lhs)), Some((c, d, rhs))) if lhs == rhs => {
+                    let (a, b, c, d) = (a as i128, b as i128, c as i128, d as i128);
+                    scaled(a * d - c * b, b * d, lhs)
+                }
+                _ if rhs.is_zero() => lhs.simplify(),
+                _ if lhs.is_zero() => (-rhs).simplify(),
+                // a - b = a + -b
+                _ => lhs + -rhs,
+            },
+        },
+    }
+}
+
+impl_op!(
+    /// Subtract two values.
+    ///
+    /// If the result overflows, returns [`Huge`][Atom::Huge], and if it underflows, returns [`NegativeHuge`][Atom::NegativeHuge].\
+    /// Subtracting from or by a special value follows the [subtraction table](super::table),
+    /// so 𝓗 - (-1/2) is [`Huge`][Atom::Huge], while 𝓗 - 1/2 is kept as a [`Sum`](crate::notation::expr::sum::Sum).\
+    /// Otherwise returns a [`Number`] with the value of the result.
+    Sub::sub,
+    Op::Sub,
+    sub
+);
+
+#[cfg(test)]
+mod sub_tests {
+    use super::*;
+    use crate::notation::expr::{fraction::Fraction, multiple::Multiple, radical::Radical, Expr};
+    use Atom::*;
+
+    fn is_sum(value: &Notation) -> bool {
+        matches!(value, Notation::Expr(Expr::Sum(_)))
+    }
+
+    #[test]
+    fn test_basic_subtraction() {
+        for a in -10..=10 {
+            for b in -10..=10 {
+                assert_eq!(Notation::from(a) - Notation::from(b), a - b);
+            }
+        }
+    }
+
+    #[test]
+    fn test_fraction_subtraction() {
+        let half = Notation::from(Fraction::new(1, 2));
+        let third = Notation::from(Fraction::new(1, 3));
+        assert_eq!(third.clone() - half.clone(), Fraction::new(-1, 6));
+        assert_eq!(half.clone() - half, 0);
+        assert_eq!(Notation::from(1) - third, Fraction::new(2, 3));
+    }
+
+    #[test]
+    fn test_saturation() {
+        let difference = (Notation::from(i32::MAX) - Notation::from(-1))
+            .atom()
+            .unwrap();
+        assert!(difference.is_positive_huge());
+        let difference = (Notation::from(i32::MIN) - Notation::from(1))
+            .atom()
+            .unwrap();
+        assert!(difference.is_negative_huge());
+        let difference = (Notation::from(0) - Notation::from(i32::MIN))
+            .atom()
+            .unwrap();
+        assert!(difference.is_positive_huge());
+        assert_eq!(Notation::from(-1) - Notation::from(i32::MIN), i32::MAX);
+        assert!(
+            (Notation::from(Fraction::new(i32::MIN, 1)) - Notation::from(Fraction::new(1, 2)))
+                .atom()
+                .is_some_and(|x| x.is_negative_huge())
+        );
+    }
+
+    #[test]
+    fn test_special_values() {
+        let huge = || Notation::from(Huge);
+        let epsilon = || Notation::from(Epsilon);
+        assert!((huge() - Notation::from(-1))
+            .atom()
+            .is_some_and(|x| x.is_positive_huge()));
+        assert!((Notation::from(-1) - huge())
+            .atom()
+            .is_some_and(|x| x.is_negative_huge()));
+        assert!((huge() - Notation::from(NegativeHuge))
+            .atom()
+            .is_some_and(|x| x.is_positive_huge()));
+        assert!((epsilon() - huge())
+            .atom()
+            .is_some_and(|x| x.is_negative_huge()));
+        assert!((Notation::from(0) - epsilon())
+            .atom()
+            .is_some_and(|x| x.is_negative_epsilon()));
+        assert!((epsilon() - Notation::from(NegativeEpsilon))
+            .atom()
+            .is_some_and(|x| x.is_positive_epsilon()));
+        assert!((Notation::from(Undefined) - huge())
+            .atom()
+            .is_some_and(|x| x.is_undefined()));
+
+        // Differences which could be any size are unknown, as 𝓗/𝓗 is
+        assert!((huge() - huge()).atom().is_some_and(|x| x.is_unknown()));
+        assert!(
+            (Notation::from(NegativeHuge) - Notation::from(NegativeHuge))
+                .atom()
+                .is_some_and(|x| x.is_unknown())
+        );
+
+        // Differences which are near one of the values are kept
+        assert!(is_sum(&(huge() - Notation::from(1))));
+        assert!(is_sum(&(epsilon() - epsilon())));
+        assert!(is_sum(&(Notation::from(3) - epsilon())));
+    }
+
+    #[test]
+    fn test_mixed_atoms_and_exprs() {
+        let huge = || Notation::from(Huge);
+        assert!((huge() - Notation::from(Fraction::new(-1, 2)))
+            .atom()
+            .is_some_and(|x| x.is_positive_huge()));
+        assert!(
+            (Notation::from(NegativeHuge) - Notation::from(Radical::new(2)))
+                .atom()
+                .is_some_and(|x| x.is_negative_huge())
+        );
+        assert!((Notation::from(Multiple::new(-2, 3, Pi)) - huge())
+            .atom()
+            .is_some_and(|x| x.is_negative_huge()));
+        assert!(
+            (Notation::from(Fraction::new(0, 5)) - Notation::from(Epsilon))
+                .atom()
+                .is_some_and(|x| x.is_negative_epsilon())
+        );
+        assert!(
+            (Notation::from(Radical::new(3)) - Notation::from(Undefined))
+                .atom()
+                .is_some_and(|x| x.is_undefined())
+        );
+
+        assert!(is_sum(&(huge() - Notation::from(Fraction::new(1, 2)))));
+        assert!(is_sum(
+            &(Notation::from(Epsilon) - Notation::from(Radical::new(2)))
+        ));
+        assert_eq!(
+            Notation::from(Pi) - Notation::from(Fraction::new(1, 2)),
+            Notation::from(Pi) + Notation::from(Fraction::new(-1, 2))
+        );
+    }
+}
@@ -0,0 +1,558 @@
+//! The result of every arithmetic operation between two atoms, as data.
+//!
+//! Each operation has a table with a row for every [class](AtomClass) of left operand
+//! and a column for every class of right operand.
+//! Most entries involving a special value, such as [`Huge`][Atom::Huge] or [`Undefined`][Atom::Undefined],
+//! are always the same atom.
+//! Entries between ordinary values, such as 2 + 3 or 2 × π, depend on the values and are computed from them.
+//! An [`Unknown`][Atom::Unknown] operand gives an unknown result, unless the result would be the same for any value,
+//! as with 0 × ? or ∅ + ?.
+//!
+//! The operators on [`Notation`] settle every pair of atoms by these tables first,
+//! along with an atom paired with a real expression of known sign, such as 𝓗 + 1/2,
+//! so the tables are the one place the behavior of special values is defined.
+//! The reason for each special quotient is recorded alongside them, in [`DIVISION_REASONS`].
+//!
+//! ```
+//! # use algebra::notation::{Notation, atom::Atom, ops::table::{outcome, Op, Outcome}, rules::AtomClass};
+//! assert_eq!(outcome(Op::Mul, AtomClass::Negative, AtomClass::Huge), Outcome::Is(AtomClass::NegativeHuge));
+//! let product = Notation::from(-2) * Notation::from(Atom::Huge);
+//! assert!(product.atom().is_some_and(|x| x.is_negative_huge()));
+//! ```
+
+use super::{multiple_of, radical_of};
+use crate::notation::{
+    atom::Atom,
+    expr::{product::Product, simplify::Simplify, sum::Sum},
+    rules::AtomClass,
+    Notation,
+};
+
+/// An arithmetic operation between two values.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub enum Op {
+    /// Addition.
+    Add,
+
+    /// Subtraction.
+    Sub,
+
+    /// Multiplication.
+    Mul,
+
+    /// Division.
+    Div,
+}
+
+impl Op {
+    /// Every operation, in order.
+    pub const ALL: [Op; 4] = [Op::Add, Op::Sub, Op::Mul, Op::Div];
+}
+
+impl std::fmt::Display for Op {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Op::Add => "+",
+            Op::Sub => "-",
+            Op::Mul => "×",
+            Op::Div => "÷",
+        }
+        .fmt(f)
+    }
+}
+
+/// The result of an operation between two classes of atoms.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Outcome {
+    /// Always an atom of this class, which holds a single atom.
+    Is(AtomClass),
+
+    /// The left operand, unchanged, such as 𝓗 + 0.
+    Left,
+
+    /// The right operand, unchanged, such as 0 + 𝓗.
+    Right,
+
+    /// Depends on the values of the operands, and is computed from them, such as 2 + 3 = 5 or 2 × π = 2π.
+    Value,
+
+    /// Isn't any single atom, and is kept as a [`Sum`] of the operands, such as 2 + 𝓗,
+    /// or a [`Product`] of them, such as π × 𝑒, or a power of one, such as π × π = π².
+    ///
+    /// Only sums, differences, and products are kept.
+    Kept,
+}
+
+use AtomClass as A;
+use Outcome::*;
+
+const Z: Outcome = Is(A::Zero);
+const C: Outcome = Is(A::Complex);
+const U: Outcome = Is(A::Undefined);
+const H: Outcome = Is(A::Huge);
+const NH: Outcome = Is(A::NegativeHuge);
+const EP: Outcome = Is(A::Epsilon);
+const NE: Outcome = Is(A::NegativeEpsilon);
+const UN: Outcome = Is(A::Unknown);
+const L: Outcome = Left;
+const R: Outcome = Right;
+const V: Outcome = Value;
+const K: Outcome = Kept;
+
+/// A table of outcomes, indexed by the left and then the right operand's position in [`AtomClass::ALL`].
+type Table = [[Outcome; 12]; 12];
+
+/// Adding a huge value to one of the opposite sign could give a number of any size, so it is [`Unknown`][Atom::Unknown],
+/// as 𝓗/𝓗 is in [`DIVISION`].
+/// Adding a huge value to a real of the opposite sign, or a value very close to 0 to a nonzero real,
+/// gives a number near one of them, which is kept as a sum.
+/// Adding any other value to a complex number gives a complex number, so it is [`Complex`][Atom::Complex],
+/// as it is in [`MULTIPLICATION`].
+#[rustfmt::skip]
+const ADDITION: Table = [
+    //             0  +  -  π  𝑒  ℂ  ∅  𝓗  -𝓗 ε  -ε ?
+    /* 0   */     [Z, R, R, R, R, R, U, R, R, R, R, R],
+    /* +   */     [L, V, V, V, V, C, U, H, K, K, K, UN],
+    /* -   */     [L, V, V, V, V, C, U, K, NH,K, K, UN],
+    /* π   */     [L, V, V, V, V, C, U, H, K, K, K, UN],
+    /* 𝑒   */     [L, V, V, V, V, C, U, H, K, K, K, UN],
+    /* ℂ   */     [L, C, C, C, C, C, U, C, C, C, C, C],
+    /* ∅   */     [U, U, U, U, U, U, U, U, U, U, U, U],
+    /* 𝓗   */     [L, H, K, H, H, C, U, H, UN,H, H, UN],
+    /* -𝓗  */     [L, K, NH,K, K, C, U, UN,NH,NH,NH,UN],
+    /* ε   */     [L, K, K, K, K, C, U, H, NH,EP,K, UN],
+    /* -ε  */     [L, K, K, K, K, C, U, H, NH,K, NE,UN],
+    /* ?   */     [L, UN,UN,UN,UN,C, U, UN,UN,UN,UN,UN],
+];
+
+/// Subtracting is adding the negative, so each entry matches [`ADDITION`] with the right operand negated.
+#[rustfmt::skip]
+const SUBTRACTION: Table = [
+    //             0  +  -  π  𝑒  ℂ  ∅  𝓗  -𝓗 ε  -ε ?
+    /* 0   */     [Z, V, V, V, V, C, U, NH,H, NE,EP,UN],
+    /* +   */     [L, V, V, V, V, C, U, K, H, K, K, UN],
+    /* -   */     [L, V, V, V, V, C, U, NH,K, K, K, UN],
+    /* π   */     [L, V, V, V, V, C, U, K, H, K, K, UN],
+    /* 𝑒   */     [L, V, V, V, V, C, U, K, H, K, K, UN],
+    /* ℂ   */     [L, C, C, C, C, C, U, C, C, C, C, C],
+    /* ∅   */     [U, U, U, U, U, U, U, U, U, U, U, U],
+    /* 𝓗   */     [L, K, H, K, K, C, U, UN,H, H, H, UN],
+    /* -𝓗  */     [L, NH,K, NH,NH,C, U, NH,UN,NH,NH,UN],
+    /* ε   */     [L, K, K, K, K, C, U, NH,H, K, EP,UN],
+    /* -ε  */     [L, K, K, K, K, C, U, NH,H, NE,K, UN],
+    /* ?   */     [L, UN,UN,UN,UN,C, U, UN,UN,UN,UN,UN],
+];
+
+/// Multiplying by a value very close to 0 matches dividing by a huge value in [`DIVISION`], and the reverse,
+/// so a huge value times one very close to 0 is [`Unknown`][Atom::Unknown], as 𝓗/𝓗 is.
+/// Products of two constants, such as π × 𝑒, are kept as a product, or as a power of one constant, such as π × π = π².
+#[rustfmt::skip]
+const MULTIPLICATION: Table = [
+    //             0  +  -  π  𝑒  ℂ  ∅  𝓗  -𝓗 ε  -ε ?
+    /* 0   */     [Z, Z, Z, Z, Z, Z, U, Z, Z, Z, Z, Z],
+    /* +   */     [Z, V, V, V, V, C, U, H, NH,EP,NE,UN],
+    /* -   */     [Z, V, V, V, V, C, U, NH,H, NE,EP,UN],
+    /* π   */     [Z, V, V, K, K, C, U, H, NH,EP,NE,UN],
+    /* 𝑒   */     [Z, V, V, K, K, C, U, H, NH,EP,NE,UN],
+    /* ℂ   */     [Z, C, C, C, C, C, U, C, C, C, C, C],
+    /* ∅   */     [U, U, U, U, U, U, U, U, U, U, U, U],
+    /* 𝓗   */     [Z, H, NH,H, H, C, U, H, NH,UN,UN,UN],
+    /* -𝓗  */     [Z, NH,H, NH,NH,C, U, NH,H, UN,UN,UN],
+    /* ε   */     [Z, EP,NE,EP,EP,C, U, UN,UN,EP,NE,UN],
+    /* -ε  */     [Z, NE,EP,NE,NE,C, U, UN,UN,NE,EP,UN],
+    /* ?   */     [Z, UN,UN,UN,UN,C, U, UN,UN,UN,UN,UN],
+];
+
+/// Nothing can be divided by 0, and dividing by a huge value gives one very close to 0, and the reverse.
+/// Dividing one huge value by another, or one value very close to 0 by another,
+/// could give any size of result, so it is [`Unknown`][Atom::Unknown].
+#[rustfmt::skip]
+const DIVISION: Table = [
+    //             0  +  -  π  𝑒  ℂ  ∅  𝓗  -𝓗 ε  -ε ?
+    /* 0   */     [U, Z, Z, Z, Z, Z, U, Z, Z, Z, Z, Z],
+    /* +   */     [U, V, V, V, V, C, U, EP,NE,H, NH,UN],
+    /* -   */     [U, V, V, V, V, C, U, NE,EP,NH,H, UN],
+    /* π   */     [U, V, V, V, V, C, U, EP,NE,H, NH,UN],
+    /* 𝑒   */     [U, V, V, V, V, C, U, EP,NE,H, NH,UN],
+    /* ℂ   */     [U, C, C, C, C, C, U, C, C, C, C, C],
+    /* ∅   */     [U, U, U, U, U, U, U, U, U, U, U, U],
+    /* 𝓗   */     [U, H, NH,H, H, C, U, UN,UN,H, NH,UN],
+    /* -𝓗  */     [U, NH,H, NH,NH,C, U, UN,UN,NH,H, UN],
+    /* ε   */     [U, EP,NE,EP,EP,C, U, EP,NE,UN,UN,UN],
+    /* -ε  */     [U, NE,EP,NE,NE,C, U, NE,EP,UN,UN,UN],
+    /* ?   */     [U, UN,UN,UN,UN,C, U, UN,UN,UN,UN,UN],
+];
+
+fn table(op: Op) -> &'static Table {
+    match op {
+        Op::Add => &ADDITION,
+        Op::Sub => &SUBTRACTION,
+        Op::Mul => &MULTIPLICATION,
+        Op::Div => &DIVISION,
+    }
+}
+
+/// Returns the outcome of an operation between atoms of two classes.
+pub fn outcome(op: Op, lhs: AtomClass, rhs: AtomClass) -> Outcome {
+    table(op)[lhs.index()][rhs.index()]
+}
+
+/// Returns every entry of an operation's table, as `(left, right, outcome)`, in order.
+pub fn entries(op: Op) -> impl Iterator<Item = (AtomClass, AtomClass, Outcome)> {
+    AtomClass::ALL.into_iter().flat_map(move |lhs| {
+        AtomClass::ALL
+            .into_iter()
+            .map(move |rhs| (lhs, rhs, outcome(op, lhs, rhs)))
+    })
+}
+
+/// Returns the class of an atom, or of a real [`Expr`](crate::notation::expr::Expr) whose sign is known, such as 1/2 or -√3.
+///
+/// Returns [`None`] for anything else, such as a [`Sum`].
+pub(crate) fn class_of(value: &Notation) -> Option<AtomClass> {
+    let sign = match value {
+        Notation::Atom(atom) => return AtomClass::of(atom),
+        value => match (radical_of(value), multiple_of(value)) {
+            (Some((coef, rad, den)), _) => coef.signum() * rad.signum() * den.signum(),
+            // A variable could have either sign
+            (_, Some((_, _, Atom::Variable(_)))) => return None,
+            (_, Some((num, den, _))) => num.signum() * den.signum(),
+            (None, None) => return None,
+        },
+    };
+    Some(match sign {
+        0 => AtomClass::Zero,
+        1 => AtomClass::Positive,
+        _ => AtomClass::Negative,
+    })
+}
+
+/// Settles an operation by the tables if the outcome doesn't depend on the values of the operands.
+///
+/// Either both operands are atoms, or one is an atom and the other is a real expression of known sign,
+/// such as 𝓗 + 1/2.
+/// Otherwise gives back both operands, to be computed from their values.
+pub(crate) fn settle(
+    op: Op,
+    lhs: Notation,
+    rhs: Notation,
+) -> Result<Notation, (Notation, Notation)> {
+    let is_atom = |value: &Notation| matches!(value, Notation::Atom(_));
+    let (Some(a), Some(b)) = (class_of(&lhs), class_of(&rhs)) else {
+        return Err((lhs, rhs));
+    };
+    if !is_atom(&lhs) && !is_atom(&rhs) {
+        return Err((lhs, rhs));
+    }
+    match outcome(op, a, b) {
+        Is(class) => Ok(Notation::from(
+            class.atom().expect("table entries are single atoms"),
+        )),
+        Left => Ok(lhs.simplify()),
+        Right => Ok(rhs.simplify()),
+        Value => Err((lhs, rhs)),
+        Kept => Ok(match op {
+            Op::Add => Sum::new([lhs, rhs]).simplify(),
+            Op::Sub => Sum::new([lhs, -rhs]).simplify(),
+            Op::Mul => Product::new([lhs, rhs]).simplify(),
+            Op::Div => unreachable!("no quotient is kept"),
+        }),
+    }
+}
+
+const EVERY: &[AtomClass] = &AtomClass::ALL;
+const HUGE: &[AtomClass] = &[A::Huge, A::NegativeHuge];
+const TINY: &[AtomClass] = &[A::Epsilon, A::NegativeEpsilon];
+
+/// Why each special entry of the division table is the atom it is, as `(numerators, denominators, reason)`.
+///
+/// The first row holding both classes of a quotient gives its reason.
+/// Every entry which is always the same atom has a reason, and no other entry does.
+///
+/// ```
+/// # use algebra::notation::{ops::table::division_reason, rules::AtomClass};
+/// assert_eq!(
+///     division_reason(AtomClass::Epsilon, AtomClass::NegativeEpsilon),
+///     Some("dividing two numbers very close to 0 could give a number of any size")
+/// );
+/// assert_eq!(division_reason(AtomClass::Positive, AtomClass::Pi), None);
+/// ```
+#[rustfmt::skip]
+pub const DIVISION_REASONS: &[(&[AtomClass], &[AtomClass], &str)] = &[
+    (&[A::Undefined], EVERY,           "part of it is undefined"),
+    (EVERY,           &[A::Undefined], "part of it is undefined"),
+    (EVERY,           &[A::Zero],      "nothing can be divided by 0"),
+    (&[A::Zero],      EVERY,           "0 divided by anything else is 0"),
+    (&[A::Complex],   EVERY,           "part of it is complex"),
+    (EVERY,           &[A::Complex],   "part of it is complex"),
+    (&[A::Unknown],   EVERY,           "part of it is unknown"),
+    (EVERY,           &[A::Unknown],   "part of it is unknown"),
+    (HUGE,            HUGE,            "dividing two huge numbers could give a number of any size"),
+    (TINY,            TINY,            "dividing two numbers very close to 0 could give a number of any size"),
+    (EVERY,           HUGE,            "dividing by a huge number gives a number very close to 0"),
+    (EVERY,           TINY,            "dividing by a number very close to 0 gives a huge number"),
+    (HUGE,            EVERY,           "dividing a huge number by a real number leaves it huge"),
+    (TINY,            EVERY,           "dividing a number very close to 0 by a real number leaves it very close to 0"),
+];
+
+/// Returns why the built-in division table gives the quotient it does for two classes,
+/// or [`None`] if the quotient depends on their values.
+pub fn division_reason(num: AtomClass, den: AtomClass) -> Option<&'static str> {
+    DIVISION_REASONS
+        .iter()
+        .find(|(nums, dens, _)| nums.contains(&num) && dens.contains(&den))
+        .map(|&(_, _, reason)| reason)
+}
+
+/// Returns the atom for an entry of the built-in division table,
+/// or [`None`] if the quotient of the two atoms depends on their values.
+pub(crate) fn special_quotient(num: &Atom, den: &Atom) -> Option<Atom> {
+    match outcome(Op::Div, AtomClass::of(num)?, AtomClass::of(den)?) {
+        Is(class) => class.atom(),
+        _ => None,
+    }
+}
+
+/// Implements an operator on [`Notation`], settling pairs of atoms by the [tables](self)
+/// and passing everything else to `$general` to compute from the values.
+macro_rules! impl_op {
+    ($(#[$attr:meta])* $trait:ident::$method:ident, $op:expr, $general:path) => {
+        impl std::ops::$trait for Notation {
+            type Output = Self;
+
+            $(#[$attr])*
+            fn $method(self, rhs: Self) -> Self::Output {
+                match $crate::notation::ops::table::settle($op, self, rhs) {
+                    Ok(result) => result,
+                    Err((lhs, rhs)) => $general(lhs, rhs),
+                }
+            }
+        }
+    };
+}
+
+pub(crate) use impl_op;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::{
+        canonical::Canonical,
+        expr::{power::Power, Expr},
+    };
+
+    /// Atoms of every class, with the largest and smallest numbers for the classes holding more than one.
+    fn samples(class: AtomClass) -> Vec<Atom> {
+        match class {
+            A::Positive => vec![Atom::from(1), Atom::from(2), Atom::from(i32::MAX)],
+            A::Negative => vec![Atom::from(-1), Atom::from(-3), Atom::from(i32::MIN)],
+            class => vec![class.atom().unwrap()],
+        }
+    }
+
+    fn apply(op: Op, lhs: Notation, rhs: Notation) -> Notation {
+        match op {
+            Op::Add => lhs + rhs,
+            Op::Sub => lhs - rhs,
+            Op::Mul => lhs * rhs,
+            Op::Div => lhs / rhs,
+        }
+    }
+
+    /// The exact result of an operation between two numbers, or [`None`] for division by 0.
+    fn exact(op: Op, a: i32, b: i32) -> Option<(i64, i64)> {
+        let (a, b) = (a as i64, b as i64);
+        match op {
+            Op::Add => Some((a + b, 1)),
+            Op::Sub => Some((a - b, 1)),
+            Op::Mul => Some((a * b, 1)),
+            Op::Div => (b != 0).then_some((a, b)),
+        }
+    }
+
+    #[test]
+    fn test_operators_follow_tables() {
+        for op in Op::ALL {
+            for (lhs_class, rhs_class, outcome) in entries(op) {
+                for (a, b) in samples(lhs_class)
+                    .into_iter()
+                    .flat_map(|a| samples(rhs_class).into_iter().map(move |b| (a.clone(), b)))
+                {
+                    let (lhs, rhs) = (Notation::from(a.clone()), Notation::from(b.clone()));
+                    let context = format!("{a} {op} {b}");
+                    let result = apply(op, lhs.clone(), rhs.clone());
+                    match outcome {
+                        Is(class) => assert!(
+                            matches!(&result, Notation::Atom(x) if AtomClass::of(x) == Some(class)),
+                            "{context} = {result}, expected {class:?}"
+                        ),
+                        Left => assert_eq!(Canonical(result), Canonical(lhs), "{context}"),
+                        Right => assert_eq!(Canonical(result), Canonical(rhs), "{context}"),
+                        // -i32::MIN saturates to 𝓗, which may absorb the other term
+                        Kept if op == Op::Sub && b == i32::MIN => {
+                            assert_eq!(
+                                Canonical(result),
+                                Canonical(lhs + Notation::from(Atom::Huge)),
+                                "{context}"
+                            )
+                        }
+                        // A constant times itself is kept as a power of it
+                        Kept if op == Op::Mul && a == b => assert_eq!(
+                            Canonical(result),
+                            Canonical(Power::new(a.clone(), 2).into()),
+                            "{context}"
+                        ),
+                        Kept if op == Op::Mul => assert!(
+                            matches!(result, Notation::Expr(Expr::Product(_))),
+                            "{context} = {result}, expected a product"
+                        ),
+                        Kept => assert!(
+                            matches!(result, Notation::Expr(Expr::Sum(_))),
+                            "{context} = {result}, expected a sum"
+                        ),
+                        Value => {
+                            if let (Atom::Number(x), Atom::Number(y)) = (&a, &b) {
+                                let (num, den) = exact(op, x.value, y.value).unwrap();
+                                let fits = |n: i64| i32::try_from(n).is_ok();
+                                if fits(num) && fits(den) {
+                                    let expected =
+                                        crate::notation::ops::rational(num as i128, den as i128);
+                                    assert_eq!(Canonical(result), Canonical(expected), "{context}");
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_class_of() {
+        use crate::notation::expr::{fraction::Fraction, multiple::Multiple, radical::Radical};
+        let class = |value: Notation| class_of(&value);
+        assert_eq!(class(Notation::from(Atom::Huge)), Some(A::Huge));
+        assert_eq!(
+            class(Notation::from(Fraction::new(-1, 2))),
+            Some(A::Negative)
+        );
+        assert_eq!(class(Notation::from(Fraction::new(0, 3))), Some(A::Zero));
+        assert_eq!(class(Notation::from(Radical::new(2))), Some(A::Positive));
+        assert_eq!(
+            class(Notation::from(Multiple::new(1, -2, Atom::E))),
+            Some(A::Negative)
+        );
+        assert_eq!(class(Notation::from(Radical::new(-2))), None);
+        assert_eq!(class(Notation::from(Sum::new([]))), None);
+    }
+
+    #[test]
+    fn test_entries_are_single_atoms() {
+        for op in Op::ALL {
+            for (lhs, rhs, outcome) in entries(op) {
+                if let Is(class) = outcome {
+                    assert!(class.atom().is_some(), "{lhs:?} {op} {rhs:?}");
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn test_undefined_dominates() {
+        for op in Op::ALL {
+            for class in AtomClass::ALL {
+                assert_eq!(outcome(op, A::Undefined, class), U, "∅ {op} {class:?}");
+                assert_eq!(outcome(op, class, A::Undefined), U, "{class:?} {op} ∅");
+            }
+        }
+    }
+
+    #[test]
+    fn test_commutative() {
+        for op in [Op::Add, Op::Mul] {
+            for (lhs, rhs, outcome) in entries(op) {
+                let swapped = match super::outcome(op, rhs, lhs) {
+                    Left => Right,
+                    Right => Left,
+                    swapped => swapped,
+                };
+                assert_eq!(outcome, swapped, "{lhs:?} {op} {rhs:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_division_reasons() {
+        for (num, den, quotient) in entries(Op::Div) {
+            let reason = division_reason(num, den);
+            assert_eq!(
+                reason.is_some(),
+                matches!(quotient, Is(_)),
+                "{num:?} / {den:?} = {quotient:?} has reason {reason:?}"
+            );
+            // An indeterminate quotient is unknown, whatever its classes
+            if reason.is_some_and(|reason| reason.ends_with("a number of any size")) {
+                assert_eq!(quotient, UN, "{num:?} / {den:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_epsilon_division() {
+        // Each row is a numerator, and each column a denominator, in the order of `AtomClass::ALL`
+        #[rustfmt::skip]
+        let expected: [(AtomClass, [Outcome; 12]); 2] = [
+            //                    0  +  -  π  𝑒  ℂ  ∅  𝓗  -𝓗 ε  -ε ?
+            (A::Epsilon,         [U, EP,NE,EP,EP,C, U, EP,NE,UN,UN,UN]),
+            (A::NegativeEpsilon, [U, NE,EP,NE,NE,C, U, NE,EP,UN,UN,UN]),
+        ];
+        for (num, row) in expected {
+            for (den, quotient) in AtomClass::ALL.into_iter().zip(row) {
+                assert_eq!(outcome(Op::Div, num, den), quotient, "{num:?} / {den:?}");
+            }
+        }
+    }
+
+    #[test]
+    fn test_multiplying_by_epsilon_is_dividing_by_huge() {
+        for class in AtomClass::ALL {
+            for (tiny, huge) in [(A::Epsilon, A::Huge), (A::NegativeEpsilon, A::NegativeHuge)] {
+                assert_eq!(
+                    outcome(Op::Mul, class, tiny),
+                    outcome(Op::Div, class, huge),
+                    "{class:?} × {tiny:?}"
+                );
+                assert_eq!(
+                    outcome(Op::Mul, class, huge),
+                    outcome(Op::Div, class, tiny),
+                    "{class:?} × {huge:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn test_subtraction_adds_the_negative() {
+        // π and 𝑒 negate to ordinary negative values
+        let neg = |class| match class {
+            A::Positive | A::Pi | A::E => A::Negative,
+            A::Negative => A::Positive,
+            A::Huge => A::NegativeHuge,
+            A::NegativeHuge => A::Huge,
+            A::Epsilon => A::NegativeEpsilon,
+            A::NegativeEpsilon => A::Epsilon,
+            class => class,
+        };
+        for (lhs, rhs, difference) in entries(Op::Sub) {
+            // 0 - x is the negative of x, rather than x itself
+            if lhs == A::Zero {
+                continue;
+            }
+            assert_eq!(
+                difference,
+                outcome(Op::Add, lhs, neg(rhs)),
+                "{lhs:?} - {rhs:?}"
+            );
+        }
+    }
+}
@@ -0,0 +1,1116 @@
+//! Reading notation back from text.
+
+use crate::{
+    notation::{
+        atom::Atom,
+        expr::{
+            complex::Complex,
+            fraction::Fraction,
+            multiple::Multiple,
+            nested_fraction::NestedFraction,
+            nested_radical::NestedRadical,
+            power::Power,
+            product::Product,
+            radical::Radical,
+            radical_fraction::RadicalFraction,
+            sum::Sum,
+            trig::{Trig, TrigFunction},
+        },
+        Notation,
+    },
+    solve::var::Interner,
+};
+use std::sync::Arc;
+
+/// Reasons text could not be read as [`Notation`].
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ParseError {
+    /// There was nothing to read.
+    Empty,
+
+    /// Text was found that doesn't belong at that position.
+    Unexpected {
+        /// The text which couldn't be read.
+        found: String,
+
+        /// The byte offset where it starts.
+        at: usize,
+    },
+
+    /// The text ended partway through the notation.
+    UnexpectedEnd,
+
+    /// An integer is too large to fit in an [`i32`].
+    IntegerOverflow(String),
+
+    /// The text is well-formed, but there is no notation which represents it, such as 𝑖 divided by π.
+    Unrepresentable(String),
+
+    /// The text has more tokens than [`ParseOptions::max_tokens`] allows.
+    TooManyTokens {
+        /// The most tokens allowed.
+        max: usize,
+    },
+
+    /// Functions or parentheses are nested inside one another more deeply than [`ParseOptions::max_depth`] allows.
+    TooDeep {
+        /// The most levels of nesting allowed.
+        max: usize,
+
+        /// The byte offset of the function or parenthesis which went too deep.
+        at: usize,
+    },
+
+    /// An integer is larger than [`ParseOptions::max_integer`] allows, ignoring its sign.
+    IntegerTooLarge {
+        /// The integer, as it was written.
+        found: String,
+
+        /// The largest magnitude allowed.
+        max: u32,
+    },
+}
+
+impl std::fmt::Display for ParseError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use ParseError::*;
+        match self {
+            Empty => "no notation to parse".fmt(f),
+            Unexpected { found, at } => format!("unexpected \"{found}\" at {at}").fmt(f),
+            UnexpectedEnd => "unexpected end of notation".fmt(f),
+            IntegerOverflow(text) => format!("integer {text} is too large").fmt(f),
+            Unrepresentable(text) => format!("\"{text}\" cannot be represented").fmt(f),
+            TooManyTokens { max } => format!("notation has more than {max} tokens").fmt(f),
+            TooDeep { max, at } => format!("functions nested more than {max} deep at {at}").fmt(f),
+            IntegerTooLarge { found, max } => {
+                format!("integer {found} is larger than {max}").fmt(f)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Limits on how much text [`Notation::parse_with`] reads, for reading text which can't be trusted,
+/// such as answers submitted to a web service.
+///
+/// Reading stops with a [`ParseError`] as soon as a limit is passed.
+/// The default only limits nesting, deeply enough for any notation written by hand,
+/// so that reading can't run out of stack; [`untrusted`][ParseOptions::untrusted] limits everything.
+///
+/// ```
+/// # use algebra::notation::{Notation, expr::fraction::Fraction, parse::{ParseError, ParseOptions}};
+/// let options = ParseOptions::default().with_max_integer(100);
+/// assert_eq!(Notation::parse_with("3/4", &options), Ok(Notation::from(Fraction::new(3, 4))));
+/// assert_eq!(
+///     Notation::parse_with("-101", &options),
+///     Err(ParseError::IntegerTooLarge { found: "-101".to_string(), max: 100 })
+/// );
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct ParseOptions {
+    /// The most tokens the text may have, such as numbers, symbols, and function names.
+    ///
+    /// Spaces aren't counted.
+    pub max_tokens: usize,
+
+    /// The most functions or parentheses which may be nested inside one another, such as the two in `sin(cos(30°)°)`.
+    pub max_depth: usize,
+
+    /// The largest magnitude an integer may have, ignoring its sign.
+    pub max_integer: u32,
+}
+
+impl Default for ParseOptions {
+    fn default() -> Self {
+        Self {
+            max_tokens: usize::MAX,
+            max_depth: 256,
+            max_integer: u32::MAX,
+        }
+    }
+}
+
+impl ParseOptions {
+    /// Limits for text typed by a student: up to 1,000 tokens, 32 levels of nesting, and integers up to a million.
+    pub fn untrusted() -> Self {
+        Self {
+            max_tokens: 1_000,
+            max_depth: 32,
+            max_integer: 1_000_000,
+        }
+    }
+
+    /// Sets the most tokens the text may have.
+    pub fn with_max_tokens(self, max_tokens: usize) -> Self {
+        Self { max_tokens, ..self }
+    }
+
+    /// Sets the most functions which may be nested inside one another.
+    pub fn with_max_depth(self, max_depth: usize) -> Self {
+        Self { max_depth, ..self }
+    }
+
+    /// Sets the largest magnitude an integer may have.
+    pub fn with_max_integer(self, max_integer: u32) -> Self {
+        Self {
+            max_integer,
+            ..self
+        }
+    }
+}
+
+/// The part of notation that can appear on either side of a `/`.
+enum Term {
+    Atom(Atom),
+    Radical(Radical),
+    Multiple(i32, Atom),
+    Trig(Trig),
+
+    /// The imaginary part of a complex number, such as 2𝑖√3.
+    Imaginary(Radical),
+
+    /// Notation in parentheses, such as the factor (1/2) of a product.
+    Group(Notation),
+
+    /// An atom or notation in parentheses raised to a power, such as π² or 2^π.
+    Power(Power),
+
+    /// The root of anything other than an integer, such as √(2+√3).
+    NestedRadical(NestedRadical),
+}
+
+impl From<Term> for Notation {
+    fn from(term: Term) -> Self {
+        match term {
+            Term::Atom(atom) => Notation::from(atom),
+            Term::Radical(rad) => Notation::from(rad),
+            Term::Multiple(coef, constant) => Notation::from(Multiple::new(coef, 1, constant)),
+            Term::Trig(trig) => Notation::from(trig),
+            Term::Imaginary(im) => Notation::from(Complex::new(0, imaginary(im, None))),
+            Term::Group(value) => value,
+            Term::Power(power) => Notation::from(power),
+            Term::NestedRadical(root) => Notation::from(root),
+        }
+    }
+}
+
+/// The term `coef√rad`, which is a [`Radical`] if the radicand is an integer.
+fn radical(coef: i32, rad: Notation) -> Term {
+    match rad {
+        Notation::Atom(Atom::Number(rad)) => Term::Radical(Radical {
+            coef,
+            rad: rad.value,
+        }),
+        rad => Term::NestedRadical(NestedRadical {
+            coef,
+            rad: Arc::new(rad),
+        }),
+    }
+}
+
+/// The imaginary part `coef√rad / den` of a complex number, without the 𝑖.
+fn imaginary(Radical { coef, rad }: Radical, den: Option<i32>) -> Notation {
+    match (rad, den) {
+        (1, None) => Notation::from(coef),
+        (1, Some(den)) => Notation::from(Fraction::new(coef, den)),
+        (rad, None) => Notation::from(Radical { coef, rad }),
+        (rad, Some(den)) => Notation::from(RadicalFraction {
+            num: Radical { coef, rad },
+            den,
+        }),
+    }
+}
+
+struct Parser<'a> {
+    text: &'a str,
+    pos: usize,
+    options: &'a ParseOptions,
+
+    /// The interner which variables are read into.
+    interner: &'a Interner,
+
+    /// The number of tokens read so far.
+    tokens: usize,
+
+    /// The number of functions the parser is inside.
+    depth: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn rest(&self) -> &'a str {
+        &self.text[self.pos..]
+    }
+
+    fn skip_space(&mut self) {
+        let rest = self.rest();
+        self.pos += rest.len() - rest.trim_start().len();
+    }
+
+    /// Consumes the first of the tokens that comes next, if any.
+    fn eat(&mut self, tokens: &[&str]) -> bool {
+        self.skip_space();
+        match tokens.iter().find(|token| self.rest().starts_with(*token)) {
+            Some(token) => {
+                self.pos += token.len();
+                self.tokens += 1;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn expect(&mut self, tokens: &[&str]) -> Result<(), ParseError> {
+        if self.eat(tokens) {
+            Ok(())
+        } else {
+            Err(self.unexpected())
+        }
+    }
+
+    /// An error for whatever comes next.
+    fn unexpected(&mut self) -> ParseError {
+        self.skip_space();
+        match self.rest().chars().next() {
+            Some(c) => ParseError::Unexpected {
+                found: c.to_string(),
+                at: self.pos,
+            },
+            None => ParseError::UnexpectedEnd,
+        }
+    }
+
+    /// Reads an integer, which may be negative.
+    fn integer(&mut self) -> Result<Option<i32>, ParseError> {
+        self.skip_space();
+        let rest = self.rest();
+        let unsigned = rest.strip_prefix('-').unwrap_or(rest);
+        let digits = unsigned.len()
+            - unsigned
+                .trim_start_matches(|c: char| c.is_ascii_digit())
+                .len();
+        if digits == 0 {
+            return Ok(None);
+        }
+        let len = rest.len() - unsigned.len() + digits;
+        let text = &rest[..len];
+        self.pos += len;
+        self.tokens += 1;
+        self.bounded(text, text).map(Some)
+    }
+
+    /// Reads an integer written in superscript, such as `⁻²`, if next.
+    fn superscript(&mut self) -> Result<Option<i32>, ParseError> {
+        const DIGITS: [char; 10] = ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+        let rest = self.rest();
+        let unsigned = rest.strip_prefix('⁻').unwrap_or(rest);
+        let digits = unsigned.len() - unsigned.trim_start_matches(DIGITS).len();
+        if digits == 0 {
+            return Ok(None);
+        }
+        let len = rest.len() - unsigned.len() + digits;
+        let text = &rest[..len];
+        self.pos += len;
+        self.tokens += 1;
+        let ascii = text
+            .chars()
+            .map(|c| match DIGITS.iter().position(|&digit| digit == c) {
+                Some(digit) => char::from(b'0' + digit as u8),
+                None => '-',
+            })
+            .collect::<String>();
+        self.bounded(text, &ascii).map(Some)
+    }
+
+    /// Converts the `digits` of an integer written as `text`, failing if it is larger than the options allow.
+    fn bounded(&self, text: &str, digits: &str) -> Result<i32, ParseError> {
+        let n = digits
+            .parse::<i32>()
+            .map_err(|_| ParseError::IntegerOverflow(text.to_string()))?;
+        match n.unsigned_abs() <= self.options.max_integer {
+            true => Ok(n),
+            false => Err(ParseError::IntegerTooLarge {
+                found: text.to_string(),
+                max: self.options.max_integer,
+            }),
+        }
+    }
+
+    /// Fails if more tokens have been read than the options allow.
+    fn check_tokens(&self) -> Result<(), ParseError> {
+        match self.tokens <= self.options.max_tokens {
+            true => Ok(()),
+            false => Err(ParseError::TooManyTokens {
+                max: self.options.max_tokens,
+            }),
+        }
+    }
+
+    /// Reads a square root sign and its radicand, if next.
+    ///
+    /// The radicand is an integer or another atom, or any notation in parentheses, such as `√(2+√3)`.
+    fn root(&mut self) -> Result<Option<Notation>, ParseError> {
+        self.skip_space();
+        let start = self.pos;
+        if self.eat(&["√(", "sqrt("]) {
+            let rad = self.nested(start)?;
+            self.expect(&[")"])?;
+            return Ok(Some(rad));
+        }
+        if !self.eat(&["√"]) {
+            return Ok(None);
+        }
+        if let Some(rad) = self.integer()? {
+            return Ok(Some(Notation::from(rad)));
+        }
+        match self.base()? {
+            Term::Atom(rad) => Ok(Some(Notation::from(rad))),
+            _ => {
+                let text = self.text[start..self.pos].trim().to_string();
+                Err(ParseError::Unrepresentable(text))
+            }
+        }
+    }
+
+    /// Reads the radical after 𝑖, if there is one, giving the imaginary term `coef𝑖√rad`.
+    ///
+    fn imaginary(&mut self, coef: i32) -> Result<Term, ParseError> {
+        let start = self.pos;
+        let times = self.eat(&["×", "*"]);
+        Ok(match self.root()? {
+            Some(Notation::Atom(Atom::Number(rad))) => Term::Imaginary(Radical {
+                coef,
+                rad: rad.value,
+            }),
+            Some(_) => {
+                let text = self.text[start..self.pos].trim().to_string();
+                return Err(ParseError::Unrepresentable(text));
+            }
+            None if times => return Err(self.unexpected()),
+            None => Term::Imaginary(Radical::from(coef)),
+        })
+    }
+
+    /// Reads the `+b𝑖√c/d` after the real part of a complex number, if next.
+    ///
+    /// Reads nothing if what follows the sign isn't imaginary.
+    fn imaginary_part(&mut self) -> Result<Option<Notation>, ParseError> {
+        let (start, tokens) = (self.pos, self.tokens);
+        let backtrack = |parser: &mut Self| {
+            parser.pos = start;
+            parser.tokens = tokens;
+            Ok(None)
+        };
+        let sign = if self.eat(&["+"]) {
+            1
+        } else if self.eat(&["-"]) {
+            -1
+        } else {
+            return Ok(None);
+        };
+        // Only a coefficient can come before the 𝑖, and reading anything else,
+        // such as a function or a root, only to read it again would take exponential time
+        self.skip_space();
+        let rest = self.rest().trim_start_matches(|c: char| c.is_ascii_digit());
+        let rest = rest.trim_start();
+        let rest = rest.strip_prefix(['×', '*']).unwrap_or(rest).trim_start();
+        if !rest.starts_with(['𝑖', 'i']) {
+            return backtrack(self);
+        }
+        let im = match self.term() {
+            Ok(Term::Imaginary(im)) => im * sign,
+            _ => return backtrack(self),
+        };
+        // An imaginary part over anything but an integer, such as 𝑖/π, is a term of the sum instead
+        let den = match self.eat(&["/"]) {
+            true => match self.term()? {
+                Term::Atom(Atom::Number(den)) => Some(den.value),
+                _ => return backtrack(self),
+            },
+            false => None,
+        };
+        Ok(Some(imaginary(im, den)))
+    }
+
+    /// Reads a constant such as π, if next.
+    fn constant(&mut self) -> Option<Atom> {
+        if self.eat(&["π", "pi"]) {
+            Some(Atom::Pi)
+        } else if self.eat(&["𝑒", "e"]) {
+            Some(Atom::E)
+        } else {
+            None
+        }
+    }
+
+    /// Reads a variable, such as `x` or `θ`, if next.
+    ///
+    /// A variable's name is a single letter, so that `xy` isn't mistaken for one variable,
+    /// and it can't begin the spelling of an atom or function, so that the `i` of `ideg` is still 𝑖.
+    fn variable(&mut self) -> Option<Atom> {
+        const RESERVED: [&str; 18] = [
+            "π",
+            "pi",
+            "𝑒",
+            "e",
+            "𝑖",
+            "i",
+            "ε",
+            "𝓗",
+            "huge",
+            "ℂ",
+            "complex",
+            "undefined",
+            "unknown",
+            "sqrt",
+            "sin",
+            "cos",
+            "tan",
+            "deg",
+        ];
+        self.skip_space();
+        let rest = self.rest();
+        let letter = rest.chars().next().filter(|c| c.is_alphabetic())?;
+        if RESERVED.iter().any(|word| rest.starts_with(word)) {
+            return None;
+        }
+        self.pos += letter.len_utf8();
+        self.tokens += 1;
+        Some(Atom::Variable(
+            self.interner.intern(&rest[..letter.len_utf8()]),
+        ))
+    }
+
+    /// Reads notation nested inside a function or parentheses which started at `start`,
+    /// failing if it is nested more deeply than the options allow.
+    fn nested(&mut self, start: usize) -> Result<Notation, ParseError> {
+        if self.depth == self.options.max_depth {
+            return Err(ParseError::TooDeep {
+                max: self.options.max_depth,
+                at: start,
+            });
+        }
+        self.depth += 1;
+        let value = self.notation()?;
+        self.depth -= 1;
+        Ok(value)
+    }
+
+    /// Reads a term, with the power it is raised to, if any.
+    fn term(&mut self) -> Result<Term, ParseError> {
+        let base = self.base()?;
+        // Any other base is written in parentheses
+        if !matches!(base, Term::Atom(_) | Term::Group(_)) {
+            return Ok(base);
+        }
+        let exp = if let Some(exp) = self.superscript()? {
+            Notation::from(exp)
+        } else if self.eat(&["^"]) {
+            // Whole number exponents are read alone, so that the product `pi^2*e` isn't read as `pi^(2*e)`
+            match self.integer()? {
+                Some(exp) => Notation::from(exp),
+                None => Notation::from(self.base()?),
+            }
+        } else {
+            return Ok(base);
+        };
+        Ok(Term::Power(Power::new(base, exp)))
+    }
+
+    fn base(&mut self) -> Result<Term, ParseError> {
+        self.check_tokens()?;
+        self.skip_space();
+        if self.rest().is_empty() {
+            return Err(ParseError::UnexpectedEnd);
+        }
+
+        let start = self.pos;
+        for (name, func) in [
+            ("sin(", TrigFunction::Sin),
+            ("cos(", TrigFunction::Cos),
+            ("tan(", TrigFunction::Tan),
+        ] {
+            if self.eat(&[name]) {
+                let arg = self.nested(start)?;
+                self.expect(&["°", "deg"])?;
+                self.expect(&[")"])?;
+                return Ok(Term::Trig(Trig::new(func, arg)));
+            }
+        }
+        if self.eat(&["("]) {
+            let value = self.nested(start)?;
+            self.expect(&[")"])?;
+            return Ok(Term::Group(value));
+        }
+
+        if let Some(coef) = self.integer()? {
+            let (before_times, tokens) = (self.pos, self.tokens);
+            let times = self.eat(&["×", "*"]);
+            if let Some(rad) = self.root()? {
+                return Ok(radical(coef, rad));
+            }
+            // ε is spelled "epsilon", which would otherwise be read as 𝑒
+            self.skip_space();
+            if !self.rest().starts_with("epsilon") {
+                if let Some(constant) = self.variable().or_else(|| self.constant()) {
+                    return Ok(Term::Multiple(coef, constant));
+                }
+            }
+            if self.eat(&["𝑖", "i"]) {
+                return self.imaginary(coef);
+            }
+            // Anything else after the sign is the next factor of a product
+            if times {
+                self.pos = before_times;
+                self.tokens = tokens;
+            }
+            return Ok(Term::Atom(Atom::from(coef)));
+        }
+
+        let negative = self.eat(&["-"]);
+        let sign = if negative { -1 } else { 1 };
+        if let Some(rad) = self.root()? {
+            return Ok(radical(sign, rad));
+        }
+        if let Some(var) = self.variable() {
+            return Ok(match negative {
+                true => Term::Multiple(-1, var),
+                false => Term::Atom(var),
+            });
+        }
+        // Before constants, since 𝑒 is spelled "e"
+        if self.eat(&["ε", "epsilon"]) {
+            let eps = if negative {
+                Atom::NegativeEpsilon
+            } else {
+                Atom::Epsilon
+            };
+            return Ok(Term::Atom(eps));
+        }
+        if let Some(constant) = self.constant() {
+            return Ok(match negative {
+                true => Term::Multiple(-1, constant),
+                false => Term::Atom(constant),
+            });
+        }
+        if self.eat(&["𝓗", "huge"]) {
+            let huge = if negative {
+                Atom::NegativeHuge
+            } else {
+                Atom::Huge
+            };
+            return Ok(Term::Atom(huge));
+        }
+        if self.eat(&["𝑖", "i"]) {
+            return self.imaginary(sign);
+        }
+        if !negative && self.eat(&["∅", "undefined"]) {
+            return Ok(Term::Atom(Atom::Undefined));
+        }
+        if !negative && self.eat(&["?", "unknown"]) {
+            return Ok(Term::Atom(Atom::Unknown));
+        }
+        if !negative && self.eat(&["ℂ", "complex"]) {
+            return Ok(Term::Atom(Atom::Complex));
+        }
+        Err(self.unexpected())
+    }
+
+    /// Reads products separated by `+` and `-`, which are a [`Sum`] if there is more than one.
+    fn notation(&mut self) -> Result<Notation, ParseError> {
+        let mut terms = vec![self.product()?];
+        loop {
+            if self.eat(&["+"]) {
+                terms.push(self.product()?);
+            } else if self.eat(&["-"]) {
+                // A sign written against its term belongs to the term, the way `Display` writes it
+                if self.rest().starts_with(char::is_whitespace) {
+                    terms.push(-self.product()?);
+                } else {
+                    self.pos -= "-".len();
+                    terms.push(self.product()?);
+                }
+            } else {
+                break;
+            }
+        }
+        Ok(match terms.len() {
+            1 => terms.remove(0),
+            _ => Notation::from(Sum::new(terms)),
+        })
+    }
+
+    /// Reads values separated by `·`, `×`, or `*`, which are a [`Product`] if there is more than one.
+    fn product(&mut self) -> Result<Notation, ParseError> {
+        let mut factors = vec![self.value(true)?];
+        while self.eat(&["·", "×", "*"]) {
+            // A complex number is written in parentheses when it is a factor,
+            // so a sign after a later factor begins the next term of a sum
+            factors.push(self.value(false)?);
+        }
+        Ok(match factors.len() {
+            1 => factors.remove(0),
+            _ => Notation::from(Product::new(factors)),
+        })
+    }
+
+    /// Reads a single value, which may be a fraction, or a complex number if `may_be_complex`.
+    fn value(&mut self, may_be_complex: bool) -> Result<Notation, ParseError> {
+        let start = self.pos;
+        let num = self.term()?;
+        // Only a number or radical can be the real part of a complex number
+        let is_real = matches!(num, Term::Atom(Atom::Number(_)) | Term::Radical(_));
+        let value = if self.eat(&["/"]) {
+            let den = self.term()?;
+            self.fraction(start, num, den)?
+        } else {
+            Notation::from(num)
+        };
+        // A real part of 0 is never written, so 0 followed by an imaginary part is a sum of them
+        let is_zero = matches!(&value, Notation::Atom(Atom::Number(n)) if n.value == 0);
+        if may_be_complex && is_real && !is_zero {
+            if let Some(im) = self.imaginary_part()? {
+                return Ok(Notation::from(Complex::new(value, im)));
+            }
+        }
+        Ok(value)
+    }
+
+    /// Combines the terms on either side of a `/` which started at `start`.
+    fn fraction(&mut self, start: usize, num: Term, den: Term) -> Result<Notation, ParseError> {
+        use Atom::Number as Num;
+        Ok(match (num, den) {
+            // A multiple would be written without the "/1"
+            (
+                Term::Atom(constant @ (Atom::Pi | Atom::E | Atom::Variable(_))),
+                Term::Atom(Num(den)),
+            ) if den != 1 => Notation::from(Multiple::new(1, den.value, constant)),
+            (Term::Multiple(coef, constant), Term::Atom(Num(den))) => {
+                Notation::from(Multiple::new(coef, den.value, constant))
+            }
+            (Term::Radical(num), Term::Atom(Num(den))) => Notation::from(RadicalFraction {
+                num,
+                den: den.value,
+            }),
+            (Term::Atom(num), Term::Atom(den)) => Notation::from(Fraction { num, den }),
+            (Term::Imaginary(im), Term::Atom(Num(den))) => {
+                Notation::from(Complex::new(0, imaginary(im, Some(den.value))))
+            }
+            // An imaginary part is only written over a whole number
+            (Term::Imaginary(_), _) | (_, Term::Imaginary(_)) => {
+                let text = self.text[start..self.pos].trim().to_string();
+                return Err(ParseError::Unrepresentable(text));
+            }
+            // Any other quotient, such as (1/2)/(3/4) or π/√2
+            (num, den) => Notation::from(NestedFraction::new(num, den)),
+        })
+    }
+}
+
+impl std::str::FromStr for Notation {
+    type Err = ParseError;
+
+    /// Reads notation from the text that [`Display`][std::fmt::Display] writes.
+    ///
+    /// Displaying then parsing gives back the same text, for every notation.
+    /// Some notations display the same, such as the number 2 and the radical 2√1; parsing gives the simpler of the two.
+    ///
+    /// Also reads the ASCII spellings of [`FormatOptions::ascii()`][crate::notation::format::FormatOptions::ascii()],
+    /// such as `sqrt(2)` and `pi`, and allows spaces between parts.
+    /// Variables are read from single letters, such as `x` and `θ`.
+    ///
+    /// Reads within the [default][ParseOptions::default] limits; use [`parse_with`][Notation::parse_with] to choose others.
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, expr::{radical::Radical, radical_fraction::RadicalFraction}};
+    /// assert_eq!("-3/4".parse::<Notation>().unwrap().to_string(), "-3/4");
+    /// assert_eq!("2√3".parse::<Notation>(), Ok(Notation::from(Radical { coef: 2, rad: 3 })));
+    /// assert_eq!("sqrt(3) / 2".parse::<Notation>(), Ok(Notation::from(RadicalFraction::new(1, 3, 2))));
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Notation::parse_with(s, &ParseOptions::default())
+    }
+}
+
+impl Notation {
+    /// Reads notation from text like [`from_str`][std::str::FromStr::from_str], failing if the text passes any of the limits in `options`.
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, parse::{ParseError, ParseOptions}};
+    /// let options = ParseOptions::untrusted().with_max_depth(1);
+    /// assert!(Notation::parse_with("sin(30°)", &options).is_ok());
+    /// assert_eq!(
+    ///     Notation::parse_with("sin(cos(30°)°)", &options),
+    ///     Err(ParseError::TooDeep { max: 1, at: 4 })
+    /// );
+    /// ```
+    pub fn parse_with(s: &str, options: &ParseOptions) -> Result<Self, ParseError> {
+        Notation::parse_into(s, options, Interner::global())
+    }
+
+    /// Reads notation from text like [`parse_with`][Notation::parse_with], interning its variables in `interner`.
+    pub(crate) fn parse_into(
+        s: &str,
+        options: &ParseOptions,
+        interner: &Interner,
+    ) -> Result<Self, ParseError> {
+        if s.trim().is_empty() {
+            return Err(ParseError::Empty);
+        }
+        let mut parser = Parser {
+            text: s,
+            pos: 0,
+            options,
+            interner,
+            tokens: 0,
+            depth: 0,
+        };
+        let notation = parser.notation()?;
+        parser.check_tokens()?;
+        parser.skip_space();
+        if parser.rest().is_empty() {
+            Ok(notation)
+        } else {
+            Err(parser.unexpected())
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        notation::{
+            arbitrary,
+            expr::Expr,
+            format::{FormatOptions, FormatWith},
+        },
+        solve::var::Var,
+    };
+    use proptest::prelude::*;
+
+    fn parse(s: &str) -> Notation {
+        s.parse().unwrap()
+    }
+
+    #[test]
+    fn test_atoms() {
+        assert_eq!(parse("42"), 42);
+        assert_eq!(parse("-7"), -7);
+        assert!(parse("π").atom().is_some_and(|x| x.is_pi()));
+        assert!(parse("-𝓗").atom().is_some_and(|x| x.is_negative_huge()));
+        assert!(parse("epsilon")
+            .atom()
+            .is_some_and(|x| x.is_positive_epsilon()));
+        assert!(parse("∅").atom().is_some_and(|x| x.is_undefined()));
+        assert!(parse("?").atom().is_some_and(|x| x.is_unknown()));
+        assert!(parse("ℂ").atom().is_some_and(|x| x.is_complex()));
+        assert!(parse("complex").atom().is_some_and(|x| x.is_complex()));
+    }
+
+    #[test]
+    fn test_exprs() {
+        assert_eq!(parse("1/-2"), Fraction::new(1, -2));
+        assert_eq!(parse("√-4"), Radical::new(-4));
+        assert_eq!(parse("-√5"), Radical { coef: -1, rad: 5 });
+        assert_eq!(parse("-3√2/4"), RadicalFraction::new(-3, 2, 4));
+        assert_eq!(parse("2π"), Multiple::new(2, 1, Atom::Pi));
+        assert_eq!(parse("-𝑒/3"), Multiple::new(-1, 3, Atom::E));
+        assert_eq!(
+            parse("2/π"),
+            Fraction {
+                num: Atom::from(2),
+                den: Atom::Pi
+            }
+        );
+        assert_eq!(
+            parse("π/1"),
+            Fraction {
+                num: Atom::Pi,
+                den: Atom::from(1)
+            }
+        );
+        assert_eq!(parse("2𝑖√3"), Complex::new(0, Radical { coef: 2, rad: 3 }));
+        assert_eq!(parse("-𝑖"), Complex::new(0, -1));
+        assert_eq!(
+            parse("1 - 𝑖√2"),
+            Complex::new(1, Radical { coef: -1, rad: 2 })
+        );
+        assert_eq!(parse("-3+𝑖"), Complex::new(-3, 1));
+        assert_eq!(
+            parse("1/2+𝑖√3/2"),
+            Complex::new(Fraction::new(1, 2), RadicalFraction::new(1, 3, 2))
+        );
+        assert_eq!(
+            parse("-√2-3𝑖/4"),
+            Complex::new(Radical { coef: -1, rad: 2 }, Fraction::new(-3, 4))
+        );
+        assert_eq!(
+            parse("-𝑖√5/2"),
+            Complex::new(0, RadicalFraction::new(-1, 5, 2))
+        );
+        assert_eq!(
+            parse("1+√2-√3/2"),
+            Sum::new([
+                1.into(),
+                Radical::new(2).into(),
+                RadicalFraction::new(-1, 3, 2).into()
+            ])
+        );
+        assert_eq!(
+            parse("1 - 2 + π"),
+            Sum::new([1.into(), (-2).into(), Atom::Pi.into()])
+        );
+        assert_eq!(
+            parse("2+2𝑖+√3"),
+            Sum::new([Complex::new(2, 2).into(), Radical::new(3).into()])
+        );
+        assert_eq!(parse("1-2147483648"), Sum::new([1.into(), i32::MIN.into()]));
+        assert_eq!(
+            parse("cos(π/2°)"),
+            Trig::new(TrigFunction::Cos, Multiple::new(1, 2, Atom::Pi))
+        );
+    }
+
+    #[test]
+    fn test_ascii() {
+        assert_eq!(parse("2*sqrt(3)"), Radical { coef: 2, rad: 3 });
+        assert_eq!(parse("3 * pi / 4"), Multiple::new(3, 4, Atom::Pi));
+        assert_eq!(parse("sin(30deg)"), Trig::new(TrigFunction::Sin, 30));
+        assert_eq!(
+            parse("4+2*i*sqrt(5)"),
+            Complex::new(4, Radical { coef: 2, rad: 5 })
+        );
+    }
+
+    #[test]
+    fn test_products() {
+        assert_eq!(
+            parse("π·𝑒"),
+            Product::new([Atom::Pi.into(), Atom::E.into()])
+        );
+        assert_eq!(
+            parse("2·√3·(-2)"),
+            Product::new([2.into(), Radical::new(3).into(), (-2).into()])
+        );
+        assert_eq!(
+            parse("(1/2)*(1+√2)"),
+            Product::new([
+                Fraction::new(1, 2).into(),
+                Sum::new([1.into(), Radical::new(2).into()]).into()
+            ])
+        );
+        // A coefficient's sign only joins it to a root, a constant, or 𝑖
+        assert!(parse("2*epsilon")
+            .expr()
+            .and_then(Expr::product)
+            .is_some_and(|product| matches!(
+                product.factors.as_ref(),
+                [
+                    Notation::Atom(Atom::Number(_)),
+                    Notation::Atom(Atom::Epsilon)
+                ]
+            )));
+        assert_eq!(parse("2*e"), Multiple::new(2, 1, Atom::E));
+        // A sign after a factor begins the next term
+        assert_eq!(
+            parse("π·2+𝑖"),
+            Sum::new([
+                Product::new([Atom::Pi.into(), 2.into()]).into(),
+                Complex::i().into()
+            ])
+        );
+    }
+
+    #[test]
+    fn test_nested_fractions() {
+        assert_eq!(
+            parse("(1/2)/(3/4)"),
+            NestedFraction::new(Fraction::new(1, 2), Fraction::new(3, 4))
+        );
+        assert_eq!(
+            parse("π/√2"),
+            NestedFraction::new(Atom::Pi, Radical::new(2))
+        );
+        assert_eq!(
+            parse("sin(1°)/2"),
+            NestedFraction::new(Trig::new(TrigFunction::Sin, 1), 2)
+        );
+        assert_eq!(
+            parse("1+(1+√2)/3"),
+            Sum::new([
+                1.into(),
+                NestedFraction::new(Sum::new([1.into(), Radical::new(2).into()]), 3).into()
+            ])
+        );
+    }
+
+    #[test]
+    fn test_nested_radicals() {
+        let two_plus_root_three = || Sum::new([2.into(), Radical::new(3).into()]);
+        assert_eq!(parse("√(2+√3)"), NestedRadical::new(two_plus_root_three()));
+        assert_eq!(
+            parse("-2*sqrt(2+sqrt(3))"),
+            NestedRadical {
+                coef: -2,
+                rad: Arc::new(two_plus_root_three().into())
+            }
+        );
+        assert_eq!(parse("√π"), NestedRadical::new(Atom::Pi));
+        assert_eq!(parse("sqrt(5)"), Radical::new(5));
+        assert_eq!(
+            parse("√(1/2)/3"),
+            NestedFraction::new(NestedRadical::new(Fraction::new(1, 2)), 3)
+        );
+        assert_eq!(
+            "𝑖√(2+√3)".parse::<Notation>(),
+            Err(ParseError::Unrepresentable("√(2+√3)".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_variables() {
+        let var = |name: &str| Atom::Variable(Var::new(name));
+        assert_eq!(parse("x"), var("x"));
+        assert_eq!(parse("θ"), var("θ"));
+        assert_eq!(parse("-x"), Multiple::new(-1, 1, var("x")));
+        assert_eq!(parse("2x/3"), Multiple::new(2, 3, var("x")));
+        assert_eq!(parse("2*x"), Multiple::new(2, 1, var("x")));
+        assert_eq!(parse("x²"), Power::new(var("x"), 2));
+        assert_eq!(
+            parse("x·y"),
+            Product::new([var("x").into(), var("y").into()])
+        );
+        // Atoms and functions are read before variables
+        assert!(parse("e").atom().is_some_and(|x| x.is_e()));
+        assert_eq!(parse("sin(xdeg)"), Trig::new(TrigFunction::Sin, var("x")));
+        assert_eq!(
+            "xy".parse::<Notation>(),
+            Err(ParseError::Unexpected {
+                found: "y".to_string(),
+                at: 1
+            })
+        );
+    }
+
+    #[test]
+    fn test_powers() {
+        assert_eq!(parse("π²"), Power::new(Atom::Pi, 2));
+        assert_eq!(parse("pi^-2"), Power::new(Atom::Pi, -2));
+        assert_eq!(parse("2^π"), Power::new(2, Atom::Pi));
+        assert_eq!(
+            parse("(√2)^(√2)"),
+            Power::new(Radical::new(2), Radical::new(2))
+        );
+        assert_eq!(parse("(-2)^(1/3)"), Power::new(-2, Fraction::new(1, 3)));
+        // A whole number exponent ends at the next factor
+        assert_eq!(
+            parse("pi^2*e"),
+            Product::new([Power::new(Atom::Pi, 2).into(), Atom::E.into()])
+        );
+        assert_eq!(
+            "π³⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰".parse::<Notation>(),
+            Err(ParseError::IntegerOverflow("³⁰⁰⁰⁰⁰⁰⁰⁰⁰⁰".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        use ParseError::*;
+        assert_eq!("  ".parse::<Notation>(), Err(Empty));
+        assert_eq!("1/".parse::<Notation>(), Err(UnexpectedEnd));
+        assert_eq!(
+            "1 2".parse::<Notation>(),
+            Err(Unexpected {
+                found: "2".to_string(),
+                at: 2
+            })
+        );
+        assert_eq!("1 +".parse::<Notation>(), Err(UnexpectedEnd));
+        assert_eq!(
+            "3000000000".parse::<Notation>(),
+            Err(IntegerOverflow("3000000000".to_string()))
+        );
+        assert_eq!(
+            "𝑖/π".parse::<Notation>(),
+            Err(Unrepresentable("𝑖/π".to_string()))
+        );
+        // The same term after a real part is still a term, not an imaginary part followed by `/π`
+        assert_eq!(
+            "1+𝑖/π".parse::<Notation>(),
+            Err(Unrepresentable("𝑖/π".to_string()))
+        );
+        assert_eq!("2*".parse::<Notation>(), Err(UnexpectedEnd));
+    }
+
+    #[test]
+    fn test_limits() {
+        use ParseError::*;
+        let parse = |s: &str, options: ParseOptions| Notation::parse_with(s, &options);
+        let untrusted = ParseOptions::untrusted();
+
+        // 1, +, √, 2, +, π
+        assert!(parse("1+√2+π", untrusted.with_max_tokens(6)).is_ok());
+        assert_eq!(
+            parse("1+√2+π", untrusted.with_max_tokens(5)),
+            Err(TooManyTokens { max: 5 })
+        );
+        let long = vec!["1"; 1_000].join("+");
+        assert_eq!(parse(&long, untrusted), Err(TooManyTokens { max: 1_000 }));
+
+        let nested = |depth| "sin(".repeat(depth) + "30" + &"°)".repeat(depth);
+        assert!(parse(&nested(32), untrusted).is_ok());
+        assert_eq!(
+            parse(&nested(33), untrusted),
+            Err(TooDeep { max: 32, at: 128 })
+        );
+        assert_eq!(
+            parse(&nested(257), ParseOptions::default()),
+            Err(TooDeep { max: 256, at: 1024 })
+        );
+
+        assert_eq!(parse("-1000000", untrusted), Ok(Notation::from(-1_000_000)));
+        assert_eq!(
+            parse("2√1000001", untrusted),
+            Err(IntegerTooLarge {
+                found: "1000001".to_string(),
+                max: 1_000_000
+            })
+        );
+        // Limits are passed the same way with or without spaces
+        assert_eq!(
+            parse("1 + 𝑖 × √ 3000000", untrusted),
+            Err(IntegerTooLarge {
+                found: "3000000".to_string(),
+                max: 1_000_000
+            })
+        );
+    }
+
+    #[test]
+    fn test_nested_sums_parse_in_linear_time() {
+        // Each sum could be read as a complex number, which would be tried and given up on at every level
+        let depth = 64;
+        let text = "1+sin(".repeat(depth) + "1" + &"°)".repeat(depth);
+        assert_eq!(parse(&text).to_string(), text);
+        let text = "1+√(".repeat(depth) + "1+π" + &")".repeat(depth);
+        assert_eq!(parse(&text).to_string(), text);
+    }
+
+    proptest! {
+        #[test]
+        fn test_display_round_trip(value in arbitrary::notation()) {
+            let text = value.to_string();
+            let parsed: Notation = text.parse().unwrap();
+            prop_assert_eq!(parsed.to_string(), text);
+        }
+
+        #[test]
+        fn test_ascii_round_trip(value in arbitrary::notation()) {
+            let ascii = FormatOptions::ascii();
+            let text = value.format_with(&ascii);
+            let parsed: Notation = text.parse().unwrap();
+            prop_assert_eq!(parsed.format_with(&ascii), text);
+        }
+    }
+}
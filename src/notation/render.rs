@@ -0,0 +1,397 @@
+//! Multi-line rendering of notation, laid out like a textbook.
+
+use crate::notation::{
+    atom::Atom,
+    expr::{
+        complex::Complex, fraction::Fraction, multiple::Multiple, nested_fraction::NestedFraction,
+        nested_radical::NestedRadical, power::Power, product::Product, radical::Radical,
+        radical_fraction::RadicalFraction, sum::Sum, trig::Trig, Expr,
+    },
+    Notation,
+};
+
+/// A rectangle of text with a row that it lines up on when placed beside other blocks.
+struct Block {
+    lines: Vec<String>,
+
+    /// The row which lines up with the middle of its neighbors, such as the bar of a fraction.
+    baseline: usize,
+}
+
+impl Block {
+    /// A single line of text.
+    fn text(text: impl ToString) -> Self {
+        Self {
+            lines: vec![text.to_string()],
+            baseline: 0,
+        }
+    }
+
+    fn width(&self) -> usize {
+        self.lines
+            .iter()
+            .map(|line| line.chars().count())
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Places one block above another, with a bar between them as wide as the wider of the two.
+    fn over(num: Block, den: Block) -> Self {
+        let width = num.width().max(den.width());
+        let center = |block: Block| {
+            // Round up, so narrow parts sit just right of center rather than just left
+            let pad = " ".repeat((width - block.width()).div_ceil(2));
+            block
+                .lines
+                .into_iter()
+                .map(move |line| format!("{pad}{line}"))
+        };
+
+        let baseline = num.lines.len();
+        let mut lines = center(num).collect::<Vec<_>>();
+        lines.push("─".repeat(width));
+        lines.extend(center(den));
+        Self { lines, baseline }
+    }
+
+    /// Places two blocks side by side, lining up their baselines.
+    fn beside(self, other: Block) -> Self {
+        let above = self.baseline.max(other.baseline);
+        let below = (self.lines.len() - self.baseline).max(other.lines.len() - other.baseline);
+        let pad = |block: Block| {
+            let width = block.width();
+            let top = above - block.baseline;
+            let bottom = above + below - top - block.lines.len();
+            let blank = std::iter::repeat_n(String::new(), top);
+            blank
+                .chain(block.lines)
+                .chain(std::iter::repeat_n(String::new(), bottom))
+                .map(move |line| format!("{line:<width$}"))
+                .collect::<Vec<_>>()
+        };
+        let lines = pad(self)
+            .into_iter()
+            .zip(pad(other))
+            .map(|(lhs, rhs)| lhs + &rhs)
+            .collect();
+        Self {
+            lines,
+            baseline: above,
+        }
+    }
+
+    /// Draws a radical sign over a radicand, preceded by its coefficient.
+    fn radical(coef: i32, rad: Block) -> Self {
+        let coef = match coef {
+            1 => String::new(),
+            -1 => "-".to_string(),
+            c => c.to_string(),
+        };
+        let indent = coef.chars().count() + 1;
+        let mut lines = vec![format!("{}{}", " ".repeat(indent), "_".repeat(rad.width()))];
+        let baseline = rad.baseline + 1;
+        lines.extend(rad.lines.into_iter().enumerate().map(|(row, line)| {
+            if row == rad.baseline {
+                format!("{coef}√{line}")
+            } else {
+                format!("{}{line}", " ".repeat(indent))
+            }
+        }));
+        Self { lines, baseline }
+    }
+
+    /// Raises an exponent above the top right corner of its base.
+    fn power(base: Block, exp: Block) -> Self {
+        let indent = " ".repeat(base.width());
+        let baseline = exp.lines.len() + base.baseline;
+        let mut lines = exp
+            .lines
+            .into_iter()
+            .map(|line| format!("{indent}{line}"))
+            .collect::<Vec<_>>();
+        lines.extend(base.lines);
+        Self { lines, baseline }
+    }
+
+    /// Draws a fraction, moving a negative sign out in front of the bar.
+    fn fraction(is_negative: bool, num: Block, den: Block) -> Self {
+        let bar = Self::over(num, den);
+        if is_negative {
+            Self::text("- ").beside(bar)
+        } else {
+            bar
+        }
+    }
+
+    fn of(value: &Notation) -> Self {
+        match value {
+            Notation::Atom(atom) => Self::text(atom),
+            Notation::Expr(expr) => Self::of_expr(expr),
+        }
+    }
+
+    fn of_expr(expr: &Expr) -> Self {
+        /// The magnitude of an atom, when its sign is shown separately.
+        fn abs(atom: &Atom) -> Atom {
+            match atom.is_negative() {
+                // The magnitude of i32::MIN doesn't fit, so it's left as it is
+                true => atom.checked_neg().unwrap_or_else(|| atom.clone()),
+                false => atom.clone(),
+            }
+        }
+
+        match expr {
+            Expr::Fraction(Fraction { num, den }) => Self::fraction(
+                expr.is_negative(),
+                Self::text(abs(num)),
+                Self::text(abs(den)),
+            ),
+            Expr::Radical(Radical { coef, rad: 1 }) => Self::text(coef),
+            Expr::Radical(Radical { coef, rad }) => Self::radical(*coef, Self::text(rad)),
+            Expr::RadicalFraction(RadicalFraction { num, den }) => Self::fraction(
+                expr.is_negative(),
+                Self::of_expr(&Expr::Radical(Radical {
+                    coef: num.coef.abs(),
+                    rad: num.rad,
+                })),
+                Self::text(den.abs()),
+            ),
+            Expr::Trig(Trig { func, arg }) => Self::text(format!("{func}("))
+                .beside(Self::of(arg))
+                .beside(Self::text("°)")),
+            Expr::Multiple(Multiple { coef, .. }) if coef.den == 1 => Self::text(expr),
+            Expr::Multiple(Multiple { coef, constant }) => {
+                let num = match abs(&coef.num) {
+                    Atom::Number(n) if n == 1 => constant.to_string(),
+                    num => format!("{num}{constant}"),
+                };
+                Self::fraction(
+                    expr.is_negative(),
+                    Self::text(num),
+                    Self::text(abs(&coef.den)),
+                )
+            }
+            Expr::Complex(Complex { re, im }) => {
+                let magnitude = Self::imaginary(&if im.is_negative() {
+                    -im.as_ref().clone()
+                } else {
+                    im.as_ref().clone()
+                });
+                match (**re == 0, im.is_negative()) {
+                    (true, false) => magnitude,
+                    (true, true) => Self::text("-").beside(magnitude),
+                    (false, is_negative) => Self::of(re)
+                        .beside(Self::text(if is_negative { " - " } else { " + " }))
+                        .beside(magnitude),
+                }
+            }
+            Expr::Sum(Sum { terms }) => {
+                let Some((first, rest)) = terms.split_first() else {
+                    return Self::text(0);
+                };
+                rest.iter()
+                    .fold(Self::of(first), |block, term| match term.is_negative() {
+                        true => block
+                            .beside(Self::text(" - "))
+                            .beside(Self::of(&-term.clone())),
+                        false => block.beside(Self::text(" + ")).beside(Self::of(term)),
+                    })
+            }
+            Expr::NestedFraction(NestedFraction { num, den }) => {
+                // The bar reaches past both parts, so that it stands out from any bar inside them
+                let padded = |part| {
+                    Self::text(" ")
+                        .beside(Self::of(part))
+                        .beside(Self::text(" "))
+                };
+                Self::over(padded(num), padded(den))
+            }
+            Expr::NestedRadical(NestedRadical { coef, rad }) => Self::radical(*coef, Self::of(rad)),
+            Expr::Product(Product { factors }) => {
+                // Negative factors and sums are drawn in parentheses, as they are written on one line
+                let factor = |factor: &Notation| match factor {
+                    Notation::Expr(Expr::Sum(_) | Expr::Complex(_)) => true,
+                    factor => factor.is_negative(),
+                };
+                let draw = |value: &Notation| match factor(value) {
+                    true => Self::text("(")
+                        .beside(Self::of(value))
+                        .beside(Self::text(")")),
+                    false => Self::of(value),
+                };
+                let Some((first, rest)) = factors.split_first() else {
+                    return Self::text(1);
+                };
+                rest.iter().fold(draw(first), |block, value| {
+                    block.beside(Self::text(" · ")).beside(draw(value))
+                })
+            }
+            Expr::Power(Power { base, exp }) => {
+                // A base other than an atom is drawn in parentheses, so that the exponent applies to all of it
+                let base = match base.as_ref() {
+                    Notation::Atom(_) if !base.is_negative() => Self::of(base),
+                    base => Self::text("(")
+                        .beside(Self::of(base))
+                        .beside(Self::text(")")),
+                };
+                Self::power(base, Self::of(exp))
+            }
+        }
+    }
+
+    /// Draws a positive imaginary part with its 𝑖, which goes after the coefficient and before any root.
+    fn imaginary(im: &Notation) -> Self {
+        let coef_i = |coef: i32| match coef {
+            1 => Self::text("𝑖"),
+            coef => Self::text(format!("{coef}𝑖")),
+        };
+        let radical_i = |Radical { coef, rad }: &Radical| match rad {
+            1 => coef_i(*coef),
+            rad => coef_i(*coef).beside(Self::radical(1, Self::text(rad))),
+        };
+        match im {
+            Notation::Atom(Atom::Number(n)) => coef_i(n.value),
+            Notation::Expr(Expr::Radical(rad)) => radical_i(rad),
+            Notation::Expr(Expr::Fraction(Fraction {
+                num: Atom::Number(n),
+                den,
+            })) => Self::over(coef_i(n.value), Self::text(den)),
+            Notation::Expr(Expr::RadicalFraction(RadicalFraction { num, den })) => {
+                Self::over(radical_i(num), Self::text(den))
+            }
+            im @ Notation::Expr(Expr::Sum(_)) => Self::text("(")
+                .beside(Self::of(im))
+                .beside(Self::text(")𝑖")),
+            im => Self::of(im).beside(Self::text("𝑖")),
+        }
+    }
+}
+
+impl Notation {
+    /// Renders the notation across multiple lines, the way it would be handwritten:
+    /// fractions are stacked over a horizontal bar, and radicals have a bar over their radicand.
+    ///
+    /// Use [`Display`][std::fmt::Display] for a single line instead.
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, expr::{fraction::Fraction, radical_fraction::RadicalFraction}};
+    /// assert_eq!(Notation::from(Fraction::new(1, 12)).render_block(), " 1\n──\n12");
+    /// assert_eq!(Notation::from(RadicalFraction::new(-1, 3, 2)).render_block(), "   _\n  √3\n- ──\n   2");
+    /// ```
+    pub fn render_block(&self) -> String {
+        Block::of(self)
+            .lines
+            .iter()
+            .map(|line| line.trim_end())
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::expr::trig::TrigFunction;
+
+    fn render(value: impl Into<Notation>) -> String {
+        value.into().render_block()
+    }
+
+    #[test]
+    fn test_atoms() {
+        assert_eq!(render(-5), "-5");
+        assert_eq!(render(Atom::Pi), "π");
+    }
+
+    #[test]
+    fn test_fraction() {
+        assert_eq!(render(Fraction::new(3, 4)), "3\n─\n4");
+        assert_eq!(render(Fraction::new(-3, 4)), "  3\n- ─\n  4");
+        assert_eq!(render(Fraction::new(100, 7)), "100\n───\n 7");
+    }
+
+    #[test]
+    fn test_radical() {
+        assert_eq!(render(Radical::new(2)), " _\n√2");
+        assert_eq!(render(Radical { coef: 3, rad: 10 }), "  __\n3√10");
+        assert_eq!(render(Radical { coef: -1, rad: 5 }), "  _\n-√5");
+        assert_eq!(render(Radical::from(4)), "4");
+    }
+
+    #[test]
+    fn test_radical_fraction() {
+        assert_eq!(render(RadicalFraction::new(2, 3, 5)), "  _\n2√3\n───\n 5");
+    }
+
+    #[test]
+    fn test_multiple() {
+        assert_eq!(render(Multiple::new(2, 1, Atom::Pi)), "2π");
+        assert_eq!(render(Multiple::new(1, 2, Atom::Pi)), "π\n─\n2");
+        assert_eq!(render(Multiple::new(-3, 4, Atom::E)), "  3𝑒\n- ──\n   4");
+    }
+
+    #[test]
+    fn test_complex() {
+        assert_eq!(render(Complex::new(3, 1)), "3 + 𝑖");
+        assert_eq!(
+            render(Complex::new(1, Radical { coef: -2, rad: 3 })),
+            "       _\n1 - 2𝑖√3"
+        );
+        assert_eq!(
+            render(Complex::new(
+                Fraction::new(1, 2),
+                RadicalFraction::new(-1, 3, 2)
+            )),
+            "      _\n1   𝑖√3\n─ - ───\n2    2"
+        );
+    }
+
+    #[test]
+    fn test_sum() {
+        assert_eq!(
+            render(Sum::new([1.into(), Radical { coef: -1, rad: 2 }.into()])),
+            "     _\n1 - √2"
+        );
+        assert_eq!(
+            render(Sum::new([
+                RadicalFraction::new(1, 3, 2).into(),
+                Fraction::new(1, 2).into()
+            ])),
+            " _\n√3   1\n── + ─\n 2   2"
+        );
+    }
+
+    #[test]
+    fn test_nested_fraction() {
+        assert_eq!(
+            render(NestedFraction::new(Fraction::new(1, 2), Radical::new(3))),
+            "  1\n  ─\n  2\n────\n  _\n √3"
+        );
+        assert_eq!(
+            render(NestedFraction::new(Fraction::new(1, 2), 3)),
+            " 1\n ─\n 2\n───\n 3"
+        );
+    }
+
+    #[test]
+    fn test_nested_radical() {
+        let root = NestedRadical::new(Radical::from(2) + Radical::new(3));
+        assert_eq!(render(root), " ______\n      _\n√2 + √3");
+    }
+
+    #[test]
+    fn test_power() {
+        assert_eq!(render(Power::new(2, Atom::Pi)), " π\n2");
+        assert_eq!(
+            render(Power::new(Atom::Pi, Fraction::new(1, 3))),
+            " 1\n ─\n 3\nπ"
+        );
+        assert_eq!(render(Power::new(-2, Atom::E)), "    𝑒\n(-2)");
+    }
+
+    #[test]
+    fn test_trig_aligns_baselines() {
+        let trig = Trig::new(TrigFunction::Sin, Fraction::new(1, 2));
+        assert_eq!(render(trig), "    1\nsin(─°)\n    2");
+    }
+}
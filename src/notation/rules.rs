@@ -0,0 +1,728 @@
+//! The rules used to simplify notation, as data which can be inspected and changed.
+
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    sync::Arc,
+};
+
+use crate::{
+    factor::gcf,
+    notation::{
+        atom::Atom,
+        expr::{
+            complex::Complex, fraction::Fraction, nested_fraction::NestedFraction,
+            nested_radical::NestedRadical, power::Power, product::Product, radical::Radical,
+            radical_fraction::RadicalFraction, simplify::Simplify, sum::Sum, trig::Trig, Expr,
+        },
+        ops::table::{self, Op, Outcome},
+        Notation,
+    },
+};
+
+/// A kind of simplification, which can be turned on or off in a [`RuleSet`].
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub enum Rule {
+    /// Dividing by special values such as 0, [`Huge`][Atom::Huge], and [`Epsilon`][Atom::Epsilon],
+    /// following the [division table](RuleSet::quotient).
+    SpecialValues,
+
+    /// Cancelling common factors of fractions, as in 6/8 = 3/4, including the coefficients of constants and radicals,
+    /// and dividing out fractions with compound parts, as in (1/2)/(3/4) = 2/3.
+    ReduceFractions,
+
+    /// Taking square factors out from under radicals, as in √12 = 2√3, and denesting roots, as in √(3+2√2) = 1+√2.
+    ///
+    /// Also raises powers, since a fractional power is a root, as in 4^(1/2) = 2.
+    SimplifyRadicals,
+
+    /// Evaluating trig functions at special angles, as in sin(30°) = 1/2.
+    EvaluateTrig,
+
+    /// Combining the parts of complex numbers, as in (1 + 2𝑖)𝑖 = -2 + 𝑖.
+    CombineComplex,
+
+    /// Combining like terms of sums, as in 2√3 + 5√3 = 7√3, and the factors of products which can be, as in 2·π·𝑒 = 2π·𝑒.
+    CombineLikeTerms,
+}
+
+impl Rule {
+    /// Every rule, in order.
+    pub const ALL: [Rule; 6] = [
+        Rule::SpecialValues,
+        Rule::ReduceFractions,
+        Rule::SimplifyRadicals,
+        Rule::EvaluateTrig,
+        Rule::CombineComplex,
+        Rule::CombineLikeTerms,
+    ];
+
+    /// Describes what the rule does.
+    pub fn description(&self) -> &'static str {
+        use Rule::*;
+        match self {
+            SpecialValues => "divide by special values such as 0, huge, and epsilon",
+            ReduceFractions => "cancel common factors of fractions",
+            SimplifyRadicals => "take square factors out from under radicals",
+            EvaluateTrig => "evaluate trig functions at special angles",
+            CombineComplex => "combine the parts of complex numbers",
+            CombineLikeTerms => "combine like terms of sums and factors of products",
+        }
+    }
+}
+
+/// Stable identifiers for each way a [`Rule`] can rewrite notation, one for each kind of [explanation][crate::notation::explain::SimplificationStep::explain].
+///
+/// The identifiers never change between versions, so front ends can use them as keys for explanations
+/// written in a student's own language, rather than reading the English ones.
+///
+/// ```
+/// # use algebra::notation::{Notation, expr::fraction::Fraction, rules::{Rule, RuleId}};
+/// let steps = Notation::from(Fraction::new(12, 18)).simplify_steps(&Default::default());
+/// assert_eq!(steps[0].id(), RuleId::ReduceByGcf);
+/// assert_eq!(steps[0].id().as_str(), "reduce_by_gcf");
+/// assert_eq!(steps[0].id().rule(), Rule::ReduceFractions);
+/// ```
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub enum RuleId {
+    /// Dividing by or into a special value, as in 5/0 = ∅.
+    SpecialDivision,
+
+    /// Dividing the numerator and denominator by their greatest common factor, as in 6/8 = 3/4.
+    ReduceByGcf,
+
+    /// Moving a fraction's sign to its numerator, as in 1/-2 = -1/2.
+    MoveSign,
+
+    /// Dropping a denominator of 1, as in 7/1 = 7.
+    DivideByOne,
+
+    /// Reducing any other fraction, such as a fraction with compound parts.
+    ReduceFraction,
+
+    /// Taking the root of a perfect square, as in √16 = 4.
+    PerfectSquare,
+
+    /// Taking the root of a negative number, as in √-4 = 2𝑖.
+    ImaginaryRoot,
+
+    /// Taking a square factor out from under a root, as in √12 = 2√3.
+    ExtractSquareFactor,
+
+    /// Simplifying any other root, such as denesting one, or raising a power.
+    SimplifyRoot,
+
+    /// Evaluating a trig function at a special angle, as in sin(30°) = 1/2.
+    TrigSpecialAngle,
+
+    /// Combining the parts of complex numbers.
+    CombineComplex,
+
+    /// Combining like terms of sums.
+    CombineLikeTerms,
+}
+
+impl RuleId {
+    /// Every identifier, in order.
+    pub const ALL: [RuleId; 12] = [
+        RuleId::SpecialDivision,
+        RuleId::ReduceByGcf,
+        RuleId::MoveSign,
+        RuleId::DivideByOne,
+        RuleId::ReduceFraction,
+        RuleId::PerfectSquare,
+        RuleId::ImaginaryRoot,
+        RuleId::ExtractSquareFactor,
+        RuleId::SimplifyRoot,
+        RuleId::TrigSpecialAngle,
+        RuleId::CombineComplex,
+        RuleId::CombineLikeTerms,
+    ];
+
+    /// Returns the stable identifier, in `snake_case`.
+    pub fn as_str(&self) -> &'static str {
+        use RuleId::*;
+        match self {
+            SpecialDivision => "special_division",
+            ReduceByGcf => "reduce_by_gcf",
+            MoveSign => "move_sign",
+            DivideByOne => "divide_by_one",
+            ReduceFraction => "reduce_fraction",
+            PerfectSquare => "perfect_square",
+            ImaginaryRoot => "imaginary_root",
+            ExtractSquareFactor => "extract_square_factor",
+            SimplifyRoot => "simplify_root",
+            TrigSpecialAngle => "trig_special_angle",
+            CombineComplex => "combine_complex",
+            CombineLikeTerms => "combine_like_terms",
+        }
+    }
+
+    /// Returns the rule which rewrites notation this way.
+    pub fn rule(&self) -> Rule {
+        use RuleId::*;
+        match self {
+            SpecialDivision => Rule::SpecialValues,
+            ReduceByGcf | MoveSign | DivideByOne | ReduceFraction => Rule::ReduceFractions,
+            PerfectSquare | ImaginaryRoot | ExtractSquareFactor | SimplifyRoot => {
+                Rule::SimplifyRadicals
+            }
+            TrigSpecialAngle => Rule::EvaluateTrig,
+            CombineComplex => Rule::CombineComplex,
+            CombineLikeTerms => Rule::CombineLikeTerms,
+        }
+    }
+}
+
+impl std::fmt::Display for RuleId {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// The classes of atoms which arithmetic treats differently, as used by the [operation tables](crate::notation::ops::table).
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Clone, Copy)]
+pub enum AtomClass {
+    /// The number 0.
+    Zero,
+
+    /// A number greater than 0.
+    Positive,
+
+    /// A number less than 0.
+    Negative,
+
+    /// [`Atom::Pi`].
+    Pi,
+
+    /// [`Atom::E`].
+    E,
+
+    /// [`Atom::Complex`].
+    Complex,
+
+    /// [`Atom::Undefined`].
+    Undefined,
+
+    /// [`Atom::Huge`].
+    Huge,
+
+    /// [`Atom::NegativeHuge`].
+    NegativeHuge,
+
+    /// [`Atom::Epsilon`].
+    Epsilon,
+
+    /// [`Atom::NegativeEpsilon`].
+    NegativeEpsilon,
+
+    /// [`Atom::Unknown`].
+    Unknown,
+}
+
+impl AtomClass {
+    /// Every class, in order.
+    pub const ALL: [AtomClass; 12] = {
+        use AtomClass::*;
+        [
+            Zero,
+            Positive,
+            Negative,
+            Pi,
+            E,
+            Complex,
+            Undefined,
+            Huge,
+            NegativeHuge,
+            Epsilon,
+            NegativeEpsilon,
+            Unknown,
+        ]
+    };
+
+    /// Returns the position of the class in [`AtomClass::ALL`].
+    pub(crate) fn index(self) -> usize {
+        self as usize
+    }
+
+    /// Returns the class of an atom, or [`None`] for a [`Variable`][Atom::Variable], which could be in any class.
+    pub fn of(atom: &Atom) -> Option<Self> {
+        use AtomClass::*;
+        Some(match atom {
+            Atom::Number(n) => match n.value {
+                0 => Zero,
+                1.. => Positive,
+                ..=-1 => Negative,
+            },
+            Atom::Pi => Pi,
+            Atom::E => E,
+            Atom::Complex => Complex,
+            Atom::Undefined => Undefined,
+            Atom::Huge => Huge,
+            Atom::NegativeHuge => NegativeHuge,
+            Atom::Epsilon => Epsilon,
+            Atom::NegativeEpsilon => NegativeEpsilon,
+            Atom::Unknown => Unknown,
+            Atom::Variable(_) => return None,
+        })
+    }
+
+    /// Returns the atom in the class, or [`None`] for [`Positive`][AtomClass::Positive] and [`Negative`][AtomClass::Negative],
+    /// which hold more than one atom.
+    pub fn atom(self) -> Option<Atom> {
+        use AtomClass::*;
+        Some(match self {
+            Zero => Atom::from(0),
+            Positive | Negative => return None,
+            Pi => Atom::Pi,
+            E => Atom::E,
+            Complex => Atom::Complex,
+            Undefined => Atom::Undefined,
+            Huge => Atom::Huge,
+            NegativeHuge => Atom::NegativeHuge,
+            Epsilon => Atom::Epsilon,
+            NegativeEpsilon => Atom::NegativeEpsilon,
+            Unknown => Atom::Unknown,
+        })
+    }
+
+    /// Returns true for the classes whose quotients depend on their values rather than only their class:
+    /// nonzero numbers, π, and 𝑒.
+    fn is_ordinary(self) -> bool {
+        use AtomClass::*;
+        matches!(self, Positive | Negative | Pi | E)
+    }
+}
+
+/// The rules used to simplify notation, which can be inspected, narrowed down, or overridden.
+///
+/// [`RuleSet::default`] holds every rule, with the same division table [`simplify`][Simplify::simplify] uses.
+/// A teacher who wants students to take squares out of radicals themselves could turn that rule off:
+///
+/// ```
+/// # use algebra::notation::{Notation, expr::{fraction::Fraction, radical::Radical}, rules::{Rule, RuleSet}};
+/// let rules = RuleSet::default().without(Rule::SimplifyRadicals);
+/// assert_eq!(Notation::from(Radical::new(12)).simplify_with_rules(&rules).to_string(), "√12");
+/// assert_eq!(Notation::from(Fraction::new(6, 8)).simplify_with_rules(&rules).to_string(), "3/4");
+/// ```
+///
+/// The division table can be read and changed one entry at a time:
+///
+/// ```
+/// # use algebra::notation::{Notation, atom::Atom, expr::fraction::Fraction, rules::{AtomClass, RuleSet}};
+/// let mut rules = RuleSet::default();
+/// assert_eq!(rules.quotient(AtomClass::Positive, AtomClass::Zero), Some(AtomClass::Undefined));
+///
+/// rules.set_quotient(AtomClass::Huge, AtomClass::Huge, AtomClass::Undefined);
+/// let huges = Notation::from(Fraction { num: Atom::Huge, den: Atom::Huge });
+/// assert_eq!(huges.simplify_with_rules(&rules).to_string(), "∅");
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct RuleSet {
+    enabled: BTreeSet<Rule>,
+    division: BTreeMap<(AtomClass, AtomClass), AtomClass>,
+}
+
+impl Default for RuleSet {
+    /// Returns every rule, with the built-in division table.
+    fn default() -> Self {
+        Self {
+            enabled: Rule::ALL.into_iter().collect(),
+            division: table::entries(Op::Div)
+                .filter_map(|(num, den, quotient)| match quotient {
+                    Outcome::Is(quotient) => Some(((num, den), quotient)),
+                    _ => None,
+                })
+                .collect(),
+        }
+    }
+}
+
+impl RuleSet {
+    /// Returns only the rules given, with the built-in division table.
+    pub fn only(rules: impl IntoIterator<Item = Rule>) -> Self {
+        Self {
+            enabled: rules.into_iter().collect(),
+            ..Self::default()
+        }
+    }
+
+    /// Turns a rule on.
+    pub fn with(mut self, rule: Rule) -> Self {
+        self.enabled.insert(rule);
+        self
+    }
+
+    /// Turns a rule off.
+    pub fn without(mut self, rule: Rule) -> Self {
+        self.enabled.remove(&rule);
+        self
+    }
+
+    /// Returns the same rules with only `rule` on, if it was on before, keeping the division table.
+    pub(crate) fn restricted_to(&self, rule: Rule) -> Self {
+        Self {
+            enabled: self
+                .enabled
+                .iter()
+                .copied()
+                .filter(|&r| r == rule)
+                .collect(),
+            division: self.division.clone(),
+        }
+    }
+
+    /// Returns true if the rule is on, false otherwise.
+    pub fn is_enabled(&self, rule: Rule) -> bool {
+        self.enabled.contains(&rule)
+    }
+
+    /// Returns the rules which are on, in order.
+    pub fn rules(&self) -> impl Iterator<Item = Rule> + '_ {
+        self.enabled.iter().copied()
+    }
+
+    /// Returns the class of the quotient of two classes of atoms,
+    /// or [`None`] if the quotient depends on their values or has been removed from the table.
+    pub fn quotient(&self, num: AtomClass, den: AtomClass) -> Option<AtomClass> {
+        self.division.get(&(num, den)).copied()
+    }
+
+    /// Sets the quotient of two classes of atoms.
+    ///
+    /// # Panics
+    /// Panics if `quotient` is [`Positive`][AtomClass::Positive] or [`Negative`][AtomClass::Negative],
+    /// since those don't say which number the quotient is.
+    pub fn set_quotient(&mut self, num: AtomClass, den: AtomClass, quotient: AtomClass) {
+        assert!(
+            quotient.atom().is_some(),
+            "quotient must be a single atom, not {quotient:?}"
+        );
+        self.division.insert((num, den), quotient);
+    }
+
+    /// Removes a quotient from the table, so that fractions of those classes are left as they are.
+    pub fn remove_quotient(&mut self, num: AtomClass, den: AtomClass) {
+        self.division.remove(&(num, den));
+    }
+
+    /// Returns every entry of the division table, as `(numerator, denominator, quotient)`, in order.
+    pub fn division_table(&self) -> impl Iterator<Item = (AtomClass, AtomClass, AtomClass)> + '_ {
+        self.division
+            .iter()
+            .map(|(&(num, den), &quotient)| (num, den, quotient))
+    }
+
+    /// Simplifies a fraction of atoms.
+    fn fraction(&self, fraction: Fraction) -> Notation {
+        let (Some(num), Some(den)) = (AtomClass::of(&fraction.num), AtomClass::of(&fraction.den))
+        else {
+            return fraction.simplify();
+        };
+        let rule = match num.is_ordinary() && den.is_ordinary() {
+            true => Rule::ReduceFractions,
+            false => Rule::SpecialValues,
+        };
+        if !self.is_enabled(rule) {
+            return Notation::from(fraction);
+        }
+        match rule {
+            Rule::SpecialValues => match self.quotient(num, den).and_then(AtomClass::atom) {
+                Some(quotient) => Notation::from(quotient),
+                None => Notation::from(fraction),
+            },
+            _ => fraction.simplify(),
+        }
+    }
+
+    /// Simplifies a radical divided by an integer.
+    fn radical_fraction(&self, value: RadicalFraction) -> Notation {
+        match (
+            self.is_enabled(Rule::ReduceFractions),
+            self.is_enabled(Rule::SimplifyRadicals),
+        ) {
+            (true, true) => value.simplify(),
+            (false, true) => match Notation::from(value.num).simplify() {
+                Notation::Expr(Expr::Radical(num)) => Notation::from(RadicalFraction {
+                    num,
+                    den: value.den,
+                }),
+                num => Notation::from(Fraction::new(1, value.den)) * num,
+            },
+            (true, false) => {
+                let RadicalFraction {
+                    num: Radical { coef, rad },
+                    den,
+                } = value;
+                let (coef, den) = match gcf([coef, den]) {
+                    0 => (coef, den),
+                    gcf => (coef / gcf * den.signum(), den.abs() / gcf),
+                };
+                match den {
+                    1 => Notation::from(Radical { coef, rad }),
+                    den => Notation::from(RadicalFraction {
+                        num: Radical { coef, rad },
+                        den,
+                    }),
+                }
+            }
+            (false, false) => Notation::from(value),
+        }
+    }
+}
+
+impl Notation {
+    /// Simplifies the notation once, like [`simplify`][Simplify::simplify], using only the rules which are on.
+    ///
+    /// Parts which no rule applies to are left as they are.
+    /// Combining complex numbers and like terms, and dividing out nested fractions,
+    /// does arithmetic on the parts being combined, which always follows every rule.
+    pub fn simplify_with_rules(self, rules: &RuleSet) -> Notation {
+        let simplify = |value: Notation| value.simplify_with_rules(rules);
+        match self {
+            Notation::Atom(atom) => Notation::from(atom),
+            Notation::Expr(expr) => match expr {
+                Expr::Fraction(value) => rules.fraction(value),
+                Expr::Radical(value) => match rules.is_enabled(Rule::SimplifyRadicals) {
+                    true => value.simplify(),
+                    false => Notation::from(value),
+                },
+                Expr::RadicalFraction(value) => rules.radical_fraction(value),
+                Expr::Multiple(value) => match rules.is_enabled(Rule::ReduceFractions) {
+                    true => value.simplify(),
+                    false => Notation::from(value),
+                },
+                Expr::Trig(value) => match rules.is_enabled(Rule::EvaluateTrig) {
+                    true => value.simplify_with(simplify),
+                    false => Notation::from(Trig::new(
+                        value.func,
+                        simplify(Arc::unwrap_or_clone(value.arg)),
+                    )),
+                },
+                Expr::Complex(value) => match rules.is_enabled(Rule::CombineComplex) {
+                    true => value.simplify_with(simplify),
+                    false => Notation::from(Complex::new(
+                        simplify(Arc::unwrap_or_clone(value.re)),
+                        simplify(Arc::unwrap_or_clone(value.im)),
+                    )),
+                },
+                Expr::Sum(value) => match rules.is_enabled(Rule::CombineLikeTerms) {
+                    true => value.simplify_with(simplify),
+                    false => Notation::from(Sum::new(value.terms.iter().cloned().map(simplify))),
+                },
+                Expr::Product(value) => match rules.is_enabled(Rule::CombineLikeTerms) {
+                    true => value.simplify_with(simplify),
+                    false => {
+                        Notation::from(Product::new(value.factors.iter().cloned().map(simplify)))
+                    }
+                },
+                Expr::Power(value) => match rules.is_enabled(Rule::SimplifyRadicals) {
+                    true => value.simplify_with(simplify),
+                    false => Notation::from(Power::new(
+                        simplify(Arc::unwrap_or_clone(value.base)),
+                        simplify(Arc::unwrap_or_clone(value.exp)),
+                    )),
+                },
+                Expr::NestedRadical(value) => match rules.is_enabled(Rule::SimplifyRadicals) {
+                    true => value.simplify_with(simplify),
+                    false => Notation::from(NestedRadical {
+                        coef: value.coef,
+                        rad: Arc::new(simplify(Arc::unwrap_or_clone(value.rad))),
+                    }),
+                },
+                Expr::NestedFraction(value) => match rules.is_enabled(Rule::ReduceFractions) {
+                    true => value.simplify_with(simplify),
+                    false => Notation::from(NestedFraction::new(
+                        simplify(Arc::unwrap_or_clone(value.num)),
+                        simplify(Arc::unwrap_or_clone(value.den)),
+                    )),
+                },
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::{
+        canonical::Canonical,
+        expr::{multiple::Multiple, trig::TrigFunction},
+    };
+
+    #[test]
+    fn test_table_is_complete() {
+        let rules = RuleSet::default();
+        for num in AtomClass::ALL {
+            for den in AtomClass::ALL {
+                let quotient = rules.quotient(num, den);
+                match num.is_ordinary() && den.is_ordinary() {
+                    true => assert_eq!(quotient, None, "{num:?}/{den:?}"),
+                    false => assert!(
+                        quotient.is_some_and(|class| class.atom().is_some()),
+                        "{num:?}/{den:?}"
+                    ),
+                }
+            }
+        }
+        // Every pair but the 16 between ordinary classes
+        assert_eq!(rules.division_table().count(), 144 - 16);
+    }
+
+    #[test]
+    fn test_classes() {
+        for class in AtomClass::ALL {
+            if let Some(atom) = class.atom() {
+                assert_eq!(AtomClass::of(&atom), Some(class));
+            }
+        }
+        assert_eq!(
+            AtomClass::of(&Atom::from(i32::MIN)),
+            Some(AtomClass::Negative)
+        );
+        assert_eq!(AtomClass::of(&Atom::Variable("x".into())), None);
+    }
+
+    fn samples() -> Vec<Notation> {
+        vec![
+            Fraction::new(6, -8).into(),
+            Fraction::new(5, 0).into(),
+            Fraction {
+                num: Atom::Pi,
+                den: Atom::Huge,
+            }
+            .into(),
+            Radical::new(12).into(),
+            RadicalFraction::new(6, 8, 4).into(),
+            RadicalFraction::new(6, 8, -9).into(),
+            Multiple::new(4, 6, Atom::E).into(),
+            Trig::new(TrigFunction::Sin, 30).into(),
+            Trig::new(TrigFunction::Cos, Fraction::new(90, 3)).into(),
+            Complex::new(Radical::new(8), Fraction::new(2, 4)).into(),
+            Sum::new([Radical::new(12).into(), Radical::new(3).into(), 1.into()]).into(),
+        ]
+    }
+
+    #[test]
+    fn test_default_matches_simplify() {
+        let rules = RuleSet::default();
+        for value in samples() {
+            assert_eq!(
+                Canonical(value.clone().simplify_with_rules(&rules)),
+                Canonical(value.clone().simplify()),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_no_rules_changes_nothing() {
+        let rules = RuleSet::only([]);
+        assert_eq!(rules.rules().count(), 0);
+        for value in samples() {
+            assert_eq!(
+                Canonical(value.clone().simplify_with_rules(&rules)),
+                Canonical(value.clone()),
+                "{value}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_single_rules() {
+        let simplify = |value: Notation, rules: &[Rule]| {
+            value
+                .simplify_with_rules(&RuleSet::only(rules.iter().copied()))
+                .to_string()
+        };
+        let radical_fraction = Notation::from(RadicalFraction::new(6, 8, 4));
+        assert_eq!(
+            simplify(radical_fraction.clone(), &[Rule::ReduceFractions]),
+            "3√8/2"
+        );
+        assert_eq!(
+            simplify(radical_fraction, &[Rule::SimplifyRadicals]),
+            "12√2/4"
+        );
+        assert_eq!(
+            simplify(
+                RadicalFraction::new(-6, 8, -6).into(),
+                &[Rule::ReduceFractions]
+            ),
+            "√8"
+        );
+        // The argument is still simplified when trig functions aren't evaluated
+        assert_eq!(
+            simplify(
+                Trig::new(TrigFunction::Sin, Fraction::new(60, 2)).into(),
+                &[Rule::ReduceFractions]
+            ),
+            "sin(30°)"
+        );
+        assert_eq!(
+            simplify(
+                Sum::new([Radical::new(12).into(), Radical::new(3).into()]).into(),
+                &[Rule::SimplifyRadicals]
+            ),
+            "2√3+√3"
+        );
+        assert_eq!(
+            simplify(Fraction::new(1, 0).into(), &[Rule::ReduceFractions]),
+            "1/0"
+        );
+        let rules = RuleSet::default().without(Rule::EvaluateTrig);
+        assert!(!rules.is_enabled(Rule::EvaluateTrig));
+        assert!(rules
+            .with(Rule::EvaluateTrig)
+            .is_enabled(Rule::EvaluateTrig));
+    }
+
+    #[test]
+    fn test_override_quotient() {
+        let mut rules = RuleSet::default();
+        let huge_over_two = Notation::from(Fraction {
+            num: Atom::Huge,
+            den: Atom::from(2),
+        });
+        rules.remove_quotient(AtomClass::Huge, AtomClass::Positive);
+        assert_eq!(rules.quotient(AtomClass::Huge, AtomClass::Positive), None);
+        assert_eq!(
+            huge_over_two
+                .clone()
+                .simplify_with_rules(&rules)
+                .to_string(),
+            "𝓗/2"
+        );
+        rules.set_quotient(AtomClass::Huge, AtomClass::Positive, AtomClass::Complex);
+        assert_eq!(huge_over_two.simplify_with_rules(&rules).to_string(), "ℂ");
+        // The built-in table is unchanged
+        assert_ne!(rules, RuleSet::default());
+    }
+
+    #[test]
+    #[should_panic(expected = "quotient must be a single atom")]
+    fn test_quotient_must_be_an_atom() {
+        RuleSet::default().set_quotient(AtomClass::Pi, AtomClass::Zero, AtomClass::Positive);
+    }
+
+    #[test]
+    fn test_descriptions() {
+        for rule in Rule::ALL {
+            assert!(!rule.description().is_empty());
+        }
+        assert!(Rule::ALL.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn test_rule_ids() {
+        let mut ids = RuleId::ALL.map(|id| id.as_str());
+        ids.sort();
+        ids.windows(2).for_each(|pair| assert_ne!(pair[0], pair[1]));
+        for rule in Rule::ALL {
+            assert!(RuleId::ALL.iter().any(|id| id.rule() == rule), "{rule:?}");
+        }
+        assert_eq!(
+            RuleId::ExtractSquareFactor.to_string(),
+            "extract_square_factor"
+        );
+    }
+}
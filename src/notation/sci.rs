@@ -0,0 +1,318 @@
+//! Scientific notation, for very large and very small measurements.
+
+use crate::notation::{
+    atom::Atom,
+    ops::{ratio, rational},
+    Notation,
+};
+
+/// Displays a number or fraction in scientific notation: `a.bc × 10^n`.
+///
+/// Parsing reads scientific notation (`1.5e9` or `1.5 × 10^9`) back into an exact value,
+/// so that measurements can be worked with as fractions rather than floats.
+///
+/// Notation other than numbers and fractions, such as radicals, is displayed as normal.
+///
+/// ```
+/// # use algebra::notation::{Notation, expr::fraction::Fraction, sci::SciNotation};
+/// let light_speed: SciNotation = "2.998e8".parse().unwrap();
+/// assert_eq!(light_speed.value, 299_800_000);
+/// assert_eq!(light_speed.to_string(), "3.00 × 10^8");
+///
+/// let charge: SciNotation = "1.6 × 10^-3".parse().unwrap();
+/// assert_eq!(charge.value, Fraction::new(1, 625));
+///
+/// let exact_speed = SciNotation::new(299_792_458).with_precision(3);
+/// assert_eq!(exact_speed.to_string(), "2.998 × 10^8");
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct SciNotation {
+    /// The exact value.
+    pub value: Notation,
+
+    /// How many digits are displayed after the decimal point.
+    pub precision: usize,
+}
+
+impl SciNotation {
+    /// Wraps a value to display in scientific notation, with two digits after the decimal point.
+    pub fn new(value: impl Into<Notation>) -> Self {
+        Self {
+            value: value.into(),
+            precision: 2,
+        }
+    }
+
+    /// Sets how many digits are displayed after the decimal point.
+    pub fn with_precision(self, precision: usize) -> Self {
+        Self { precision, ..self }
+    }
+}
+
+impl From<Notation> for SciNotation {
+    fn from(value: Notation) -> Self {
+        Self::new(value)
+    }
+}
+
+/// Returns the exponent `n` for which `10^n ≤ num/den < 10^(n + 1)`.
+///
+/// Both `num` and `den` must be positive.
+fn exponent(num: i128, den: i128) -> i32 {
+    let mut n = num.ilog10() as i32 - den.ilog10() as i32;
+    // The difference in digit counts can be one too high
+    let (lhs, rhs) = match n {
+        0.. => (num, den * 10i128.pow(n as u32)),
+        _ => (num * 10i128.pow(n.unsigned_abs()), den),
+    };
+    if lhs < rhs {
+        n -= 1;
+    }
+    n
+}
+
+impl std::fmt::Display for SciNotation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Some((num, den)) = ratio(&self.value) else {
+            return self.value.fmt(f);
+        };
+        let sign = if (num < 0) != (den < 0) { "-" } else { "" };
+        let (num, den) = ((num as i128).abs(), (den as i128).abs());
+        if num == 0 {
+            return format!("{:.*} × 10^0", self.precision, 0.0).fmt(f);
+        }
+
+        // Past this, the scaled mantissa may not fit in an i128
+        let precision = self.precision.min(18);
+        let mut n = exponent(num, den);
+
+        // Round the mantissa, scaled up to a whole number of `precision + 1` digits
+        let shift = precision as i32 - n;
+        let (scaled_num, scaled_den) = match shift {
+            0.. => (num * 10i128.pow(shift as u32), den),
+            _ => (num, den * 10i128.pow(shift.unsigned_abs())),
+        };
+        let mut mantissa = (2 * scaled_num + scaled_den) / (2 * scaled_den);
+        if mantissa == 10i128.pow(precision as u32 + 1) {
+            // Rounded up to the next power of ten, e.g. 9.999 to 10.00
+            mantissa /= 10;
+            n += 1;
+        }
+
+        let digits = mantissa.to_string();
+        let (whole, fraction) = digits.split_at(1);
+        let fraction = format!("{fraction:0<width$}", width = self.precision);
+        if fraction.is_empty() {
+            format!("{sign}{whole} × 10^{n}").fmt(f)
+        } else {
+            format!("{sign}{whole}.{fraction} × 10^{n}").fmt(f)
+        }
+    }
+}
+
+/// Reasons text could not be read as scientific notation.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ParseSciError {
+    /// There was no number to read.
+    Empty,
+
+    /// The part before the exponent isn't a decimal number.
+    InvalidMantissa(String),
+
+    /// The exponent isn't an integer.
+    InvalidExponent(String),
+
+    /// The mantissa has too many digits to be stored exactly.
+    TooPrecise,
+}
+
+impl std::fmt::Display for ParseSciError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use ParseSciError::*;
+        match self {
+            Empty => "no number to parse".fmt(f),
+            InvalidMantissa(text) => format!("invalid mantissa \"{text}\"").fmt(f),
+            InvalidExponent(text) => format!("invalid exponent \"{text}\"").fmt(f),
+            TooPrecise => "mantissa has too many digits".fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ParseSciError {}
+
+/// Splits text into its mantissa and exponent, if it has one.
+fn split_exponent(s: &str) -> (&str, Option<&str>) {
+    if let Some(at) = s.find(['e', 'E']) {
+        return (&s[..at], Some(&s[at + 1..]));
+    }
+    for times in ['×', 'x', '*'] {
+        if let Some(at) = s.find(times) {
+            let exp = s[at + times.len_utf8()..].trim_start();
+            return (&s[..at], Some(exp.strip_prefix("10^").unwrap_or(exp)));
+        }
+    }
+    (s, None)
+}
+
+/// Reads a decimal number as an exact fraction.
+fn parse_decimal(text: &str) -> Result<(i128, i128), ParseSciError> {
+    let invalid = || ParseSciError::InvalidMantissa(text.to_string());
+    let (sign, digits) = match text.strip_prefix('-') {
+        Some(rest) => (-1, rest),
+        None => (1, text.strip_prefix('+').unwrap_or(text)),
+    };
+    let (whole, fraction) = digits.split_once('.').unwrap_or((digits, ""));
+    if whole.is_empty() && fraction.is_empty() {
+        return Err(invalid());
+    }
+
+    let mut num: i128 = 0;
+    for c in whole.chars().chain(fraction.trim_end_matches('0').chars()) {
+        let digit = c.to_digit(10).ok_or_else(invalid)?;
+        num = num
+            .checked_mul(10)
+            .and_then(|num| num.checked_add(digit as i128))
+            .ok_or(ParseSciError::TooPrecise)?;
+    }
+    let places = fraction.trim_end_matches('0').len() as u32;
+    let den = 10i128
+        .checked_pow(places)
+        .ok_or(ParseSciError::TooPrecise)?;
+    Ok((sign * num, den))
+}
+
+impl std::str::FromStr for SciNotation {
+    type Err = ParseSciError;
+
+    /// Reads `1.5e9`, `1.5E9`, `1.5 × 10^9`, or a plain decimal such as `0.25`, as an exact value.
+    ///
+    /// Values whose numerator or denominator don't fit in an [`i32`] become
+    /// [`Huge`][Atom::Huge] or [`Epsilon`][Atom::Epsilon] (or their negatives).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let s = s.trim();
+        if s.is_empty() {
+            return Err(ParseSciError::Empty);
+        }
+
+        let (mantissa, exp) = split_exponent(s);
+        let (num, den) = parse_decimal(mantissa.trim())?;
+        let exp: i32 = match exp {
+            Some(exp) => exp
+                .trim()
+                .parse()
+                .map_err(|_| ParseSciError::InvalidExponent(exp.to_string()))?,
+            None => 0,
+        };
+
+        let scale = 10i128.checked_pow(exp.unsigned_abs());
+        let value = match (scale, exp) {
+            (Some(scale), 0..) => num.checked_mul(scale).map(|num| rational(num, den)),
+            (Some(scale), _) => den.checked_mul(scale).map(|den| rational(num, den)),
+            (None, _) => None,
+        };
+        let value = value.unwrap_or_else(|| match (num.signum(), exp > 0) {
+            (0, _) => Notation::from(0),
+            (1, true) => Notation::from(Atom::Huge),
+            (_, true) => Notation::from(Atom::NegativeHuge),
+            (1, false) => Notation::from(Atom::Epsilon),
+            (_, false) => Notation::from(Atom::NegativeEpsilon),
+        });
+        Ok(Self::new(value))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::expr::fraction::Fraction;
+
+    fn parse(s: &str) -> Notation {
+        s.parse::<SciNotation>().unwrap().value
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(SciNotation::new(1500).to_string(), "1.50 × 10^3");
+        assert_eq!(SciNotation::new(-42).to_string(), "-4.20 × 10^1");
+        assert_eq!(SciNotation::new(7).to_string(), "7.00 × 10^0");
+        assert_eq!(SciNotation::new(0).to_string(), "0.00 × 10^0");
+        assert_eq!(
+            SciNotation::new(Fraction::new(1, 8)).to_string(),
+            "1.25 × 10^-1"
+        );
+        assert_eq!(
+            SciNotation::new(Fraction::new(-1, 3000)).to_string(),
+            "-3.33 × 10^-4"
+        );
+    }
+
+    #[test]
+    fn test_display_rounding() {
+        assert_eq!(SciNotation::new(9999).to_string(), "1.00 × 10^4");
+        assert_eq!(SciNotation::new(12345).to_string(), "1.23 × 10^4");
+        assert_eq!(SciNotation::new(12355).to_string(), "1.24 × 10^4");
+        assert_eq!(
+            SciNotation::new(Fraction::new(2, 3))
+                .with_precision(0)
+                .to_string(),
+            "7 × 10^-1"
+        );
+        assert_eq!(
+            SciNotation::new(i32::MAX).with_precision(9).to_string(),
+            "2.147483647 × 10^9"
+        );
+        assert_eq!(
+            SciNotation::new(Fraction::new(1, i32::MAX))
+                .with_precision(20)
+                .to_string(),
+            "4.65661287524579692400 × 10^-10"
+        );
+    }
+
+    #[test]
+    fn test_display_other_notation() {
+        let root = Notation::from(crate::notation::expr::radical::Radical::new(2));
+        assert_eq!(SciNotation::new(root).to_string(), "√2");
+    }
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(parse("1.5e9"), 1_500_000_000);
+        assert_eq!(parse("2.5E-3"), Fraction::new(1, 400));
+        assert_eq!(parse("-6.25 × 10^2"), -625);
+        assert_eq!(parse("3x10^-1"), Fraction::new(3, 10));
+        assert_eq!(parse("0.125"), Fraction::new(1, 8));
+        assert_eq!(parse("4.000e0"), 4);
+        assert_eq!(parse(".5"), Fraction::new(1, 2));
+    }
+
+    #[test]
+    fn test_parse_saturates() {
+        assert!(parse("6.022e23")
+            .atom()
+            .is_some_and(|x| x.is_positive_huge()));
+        assert!(parse("-1e100").atom().is_some_and(|x| x.is_negative_huge()));
+        assert!(parse("1.6e-19")
+            .atom()
+            .is_some_and(|x| x.is_positive_epsilon()));
+        assert_eq!(parse("0e100"), 0);
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        use ParseSciError::*;
+        assert_eq!("".parse::<SciNotation>(), Err(Empty));
+        assert_eq!(
+            "1.2.3e4".parse::<SciNotation>(),
+            Err(InvalidMantissa("1.2.3".to_string()))
+        );
+        assert_eq!(
+            "1e".parse::<SciNotation>(),
+            Err(InvalidExponent("".to_string()))
+        );
+        assert_eq!(
+            "1.5 × 10^x".parse::<SciNotation>(),
+            Err(InvalidExponent("x".to_string()))
+        );
+    }
+}
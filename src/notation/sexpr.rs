@@ -0,0 +1,483 @@
+//! A compact Lisp-style encoding of notation trees, for test fixtures and other files edited by hand.
+//!
+//! Each expression is a list headed by its form, and each atom other than a number is a bare symbol:
+//!
+//! | Notation | S-expression |
+//! |---|---|
+//! | 2 | `(num 2)` |
+//! | π, e | `pi`, `e` |
+//! | special values | `complex`, `undefined`, `huge`, `-huge`, `epsilon`, `-epsilon`, `unknown` |
+//! | x | `(var x)` |
+//! | 1/2 | `(frac (num 1) (num 2))` |
+//! | 2√3 | `(rad 2 3)` |
+//! | 2√3/5 | `(radfrac (rad 2 3) 5)` |
+//! | 2π/3 | `(mul (frac (num 2) (num 3)) pi)` |
+//! | sin(30°) | `(sin (num 30))`, and likewise `cos` and `tan` |
+//! | 1 + 2i | `(cplx (num 1) (num 2))` |
+//! | 1 + π | `(sum (num 1) pi)` |
+//! | (1/2)/√3 | `(nfrac (frac (num 1) (num 2)) (rad 1 3))` |
+//! | 2√(1+√3) | `(nrad 2 (sum (num 1) (rad 1 3)))` |
+//! | π·e | `(prod pi e)` |
+//! | 2^π | `(pow (num 2) pi)` |
+//!
+//! When reading, a bare integer can be written anywhere `(num n)` can, and any run of whitespace separates items.
+
+use std::sync::Arc;
+
+use crate::{
+    notation::{
+        atom::Atom,
+        expr::{
+            complex::Complex,
+            fraction::Fraction,
+            multiple::Multiple,
+            nested_fraction::NestedFraction,
+            nested_radical::NestedRadical,
+            power::Power,
+            product::Product,
+            radical::Radical,
+            radical_fraction::RadicalFraction,
+            sum::Sum,
+            trig::{Trig, TrigFunction},
+            Expr,
+        },
+        Notation,
+    },
+    solve::var::Var,
+};
+
+/// Reasons an s-expression could not be read.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum SexprError {
+    /// A parenthesis is missing or out of place.
+    ///
+    /// Contains the byte offset of the problem.
+    Unbalanced(usize),
+
+    /// There is more than one expression, or nothing at all.
+    NotOneExpression,
+
+    /// A symbol or form name isn't recognized.
+    Unknown(String),
+
+    /// A form has the wrong number or kind of arguments.
+    ///
+    /// Contains the name of the form.
+    InvalidArguments(String),
+
+    /// An integer doesn't fit in an [`i32`].
+    OutOfRange(String),
+}
+
+impl std::fmt::Display for SexprError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use SexprError::*;
+        match self {
+            Unbalanced(offset) => format!("unbalanced parentheses at byte {offset}").fmt(f),
+            NotOneExpression => "expected exactly one expression".fmt(f),
+            Unknown(name) => format!("unknown symbol \"{name}\"").fmt(f),
+            InvalidArguments(form) => format!("invalid arguments to \"{form}\"").fmt(f),
+            OutOfRange(value) => format!("{value} does not fit in a 32-bit integer").fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for SexprError {}
+
+// # Writing
+
+fn atom_sexpr(atom: &Atom) -> String {
+    use Atom::*;
+    match atom {
+        Number(n) => format!("(num {n})"),
+        Complex => "complex".to_string(),
+        Undefined => "undefined".to_string(),
+        Huge => "huge".to_string(),
+        NegativeHuge => "-huge".to_string(),
+        Epsilon => "epsilon".to_string(),
+        NegativeEpsilon => "-epsilon".to_string(),
+        Unknown => "unknown".to_string(),
+        Pi => "pi".to_string(),
+        E => "e".to_string(),
+        Variable(var) => format!("(var {var})"),
+    }
+}
+
+fn fraction_sexpr(Fraction { num, den }: &Fraction) -> String {
+    format!("(frac {} {})", atom_sexpr(num), atom_sexpr(den))
+}
+
+fn radical_sexpr(Radical { coef, rad }: &Radical) -> String {
+    format!("(rad {coef} {rad})")
+}
+
+fn sexpr(value: &Notation) -> String {
+    match value {
+        Notation::Atom(atom) => atom_sexpr(atom),
+        Notation::Expr(expr) => match expr {
+            Expr::Fraction(value) => fraction_sexpr(value),
+            Expr::Radical(value) => radical_sexpr(value),
+            Expr::RadicalFraction(RadicalFraction { num, den }) => {
+                format!("(radfrac {} {den})", radical_sexpr(num))
+            }
+            Expr::Multiple(Multiple { coef, constant }) => {
+                format!("(mul {} {})", fraction_sexpr(coef), atom_sexpr(constant))
+            }
+            Expr::Trig(Trig { func, arg }) => format!("({func} {})", sexpr(arg)),
+            Expr::Complex(Complex { re, im }) => format!("(cplx {} {})", sexpr(re), sexpr(im)),
+            Expr::NestedFraction(NestedFraction { num, den }) => {
+                format!("(nfrac {} {})", sexpr(num), sexpr(den))
+            }
+            Expr::NestedRadical(NestedRadical { coef, rad }) => {
+                format!("(nrad {coef} {})", sexpr(rad))
+            }
+            Expr::Power(Power { base, exp }) => format!("(pow {} {})", sexpr(base), sexpr(exp)),
+            Expr::Sum(Sum { terms }) => {
+                let mut text = "(sum".to_string();
+                for term in terms.iter() {
+                    text.push(' ');
+                    text.push_str(&sexpr(term));
+                }
+                text.push(')');
+                text
+            }
+            Expr::Product(Product { factors }) => {
+                let mut text = "(prod".to_string();
+                for factor in factors.iter() {
+                    text.push(' ');
+                    text.push_str(&sexpr(factor));
+                }
+                text.push(')');
+                text
+            }
+        },
+    }
+}
+
+// # Reading
+
+/// A parsed s-expression, before it is interpreted as notation.
+#[derive(Debug, PartialEq, Clone)]
+enum Item<'a> {
+    Symbol(&'a str),
+    List(Vec<Item<'a>>),
+}
+
+/// Splits the text into parentheses and symbols, paired with their byte offsets.
+fn tokenize(text: &str) -> Vec<(usize, &str)> {
+    let mut tokens = Vec::new();
+    let mut start = None;
+    for (i, c) in text.char_indices() {
+        let is_paren = c == '(' || c == ')';
+        if is_paren || c.is_whitespace() {
+            if let Some(start) = start.take() {
+                tokens.push((start, &text[start..i]));
+            }
+            if is_paren {
+                tokens.push((i, &text[i..i + 1]));
+            }
+        } else if start.is_none() {
+            start = Some(i);
+        }
+    }
+    if let Some(start) = start {
+        tokens.push((start, &text[start..]));
+    }
+    tokens
+}
+
+fn parse(text: &str) -> Result<Item<'_>, SexprError> {
+    // Lists still being read, innermost last
+    let mut open: Vec<(usize, Vec<Item>)> = Vec::new();
+    let mut complete = Vec::new();
+    for (offset, token) in tokenize(text) {
+        let item = match token {
+            "(" => {
+                open.push((offset, Vec::new()));
+                continue;
+            }
+            ")" => match open.pop() {
+                Some((_, items)) => Item::List(items),
+                None => return Err(SexprError::Unbalanced(offset)),
+            },
+            symbol => Item::Symbol(symbol),
+        };
+        match open.last_mut() {
+            Some((_, items)) => items.push(item),
+            None => complete.push(item),
+        }
+    }
+    if let Some(&(offset, _)) = open.last() {
+        return Err(SexprError::Unbalanced(offset));
+    }
+    match <[Item; 1]>::try_from(complete) {
+        Ok([item]) => Ok(item),
+        Err(_) => Err(SexprError::NotOneExpression),
+    }
+}
+
+fn read_i32(item: &Item, form: &str) -> Result<i32, SexprError> {
+    match item {
+        Item::Symbol(symbol) => integer(symbol),
+        Item::List(items) => match items.as_slice() {
+            [Item::Symbol("num"), Item::Symbol(symbol)] => integer(symbol),
+            _ => Err(SexprError::InvalidArguments(form.to_string())),
+        },
+    }
+}
+
+fn integer(symbol: &str) -> Result<i32, SexprError> {
+    symbol.parse().map_err(|_| {
+        match symbol
+            .strip_prefix('-')
+            .unwrap_or(symbol)
+            .bytes()
+            .all(|b| b.is_ascii_digit())
+        {
+            true if !symbol.is_empty() && symbol != "-" => {
+                SexprError::OutOfRange(symbol.to_string())
+            }
+            _ => SexprError::Unknown(symbol.to_string()),
+        }
+    })
+}
+
+fn read_atom(item: &Item, form: &str) -> Result<Atom, SexprError> {
+    match read(item)? {
+        Notation::Atom(atom) => Ok(atom),
+        Notation::Expr(_) => Err(SexprError::InvalidArguments(form.to_string())),
+    }
+}
+
+fn read_fraction(item: &Item, form: &str) -> Result<Fraction, SexprError> {
+    match read(item)? {
+        Notation::Expr(Expr::Fraction(value)) => Ok(value),
+        _ => Err(SexprError::InvalidArguments(form.to_string())),
+    }
+}
+
+fn read_radical(item: &Item, form: &str) -> Result<Radical, SexprError> {
+    match read(item)? {
+        Notation::Expr(Expr::Radical(value)) => Ok(value),
+        _ => Err(SexprError::InvalidArguments(form.to_string())),
+    }
+}
+
+fn read(item: &Item) -> Result<Notation, SexprError> {
+    let items = match item {
+        Item::Symbol(symbol) => {
+            return Ok(Notation::from(match *symbol {
+                "pi" => Atom::Pi,
+                "e" => Atom::E,
+                "complex" => Atom::Complex,
+                "undefined" => Atom::Undefined,
+                "huge" => Atom::Huge,
+                "-huge" => Atom::NegativeHuge,
+                "epsilon" => Atom::Epsilon,
+                "-epsilon" => Atom::NegativeEpsilon,
+                "unknown" => Atom::Unknown,
+                symbol => Atom::from(integer(symbol)?),
+            }))
+        }
+        Item::List(items) => items,
+    };
+    let (form, args) = match items.split_first() {
+        Some((Item::Symbol(form), args)) => (*form, args),
+        _ => return Err(SexprError::InvalidArguments(String::new())),
+    };
+    let invalid = || SexprError::InvalidArguments(form.to_string());
+    let trig = |func| match args {
+        [arg] => Ok(Notation::from(Trig {
+            func,
+            arg: Arc::new(read(arg)?),
+        })),
+        _ => Err(invalid()),
+    };
+    match (form, args) {
+        ("num", [n]) => read_i32(n, form).map(Notation::from),
+        ("var", [Item::Symbol(name)]) => Ok(Notation::from(Atom::Variable(Var::new(name)))),
+        ("frac", [num, den]) => Ok(Notation::from(Fraction {
+            num: read_atom(num, form)?,
+            den: read_atom(den, form)?,
+        })),
+        ("rad", [coef, rad]) => Ok(Notation::from(Radical {
+            coef: read_i32(coef, form)?,
+            rad: read_i32(rad, form)?,
+        })),
+        ("radfrac", [num, den]) => Ok(Notation::from(RadicalFraction {
+            num: read_radical(num, form)?,
+            den: read_i32(den, form)?,
+        })),
+        ("mul", [coef, constant]) => Ok(Notation::from(Multiple {
+            coef: read_fraction(coef, form)?,
+            constant: read_atom(constant, form)?,
+        })),
+        ("sin", _) => trig(TrigFunction::Sin),
+        ("cos", _) => trig(TrigFunction::Cos),
+        ("tan", _) => trig(TrigFunction::Tan),
+        ("cplx", [re, im]) => Ok(Notation::from(Complex::new(read(re)?, read(im)?))),
+        ("nfrac", [num, den]) => Ok(Notation::from(NestedFraction::new(read(num)?, read(den)?))),
+        ("nrad", [coef, rad]) => Ok(Notation::from(NestedRadical {
+            coef: read_i32(coef, form)?,
+            rad: Arc::new(read(rad)?),
+        })),
+        ("pow", [base, exp]) => Ok(Notation::from(Power::new(read(base)?, read(exp)?))),
+        ("sum", terms) => Ok(Notation::from(Sum::new(
+            terms.iter().map(read).collect::<Result<Vec<_>, _>>()?,
+        ))),
+        ("prod", factors) => Ok(Notation::from(Product::new(
+            factors.iter().map(read).collect::<Result<Vec<_>, _>>()?,
+        ))),
+        (
+            "num" | "var" | "frac" | "rad" | "radfrac" | "mul" | "cplx" | "nfrac" | "nrad" | "pow",
+            _,
+        ) => Err(invalid()),
+        _ => Err(SexprError::Unknown(form.to_string())),
+    }
+}
+
+impl Notation {
+    /// Writes the notation as an [s-expression][crate::notation::sexpr], without simplifying it.
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, expr::fraction::Fraction};
+    /// let half = Notation::from(Fraction::new(1, 2));
+    /// assert_eq!(half.to_sexpr(), "(frac (num 1) (num 2))");
+    /// ```
+    pub fn to_sexpr(&self) -> String {
+        sexpr(self)
+    }
+
+    /// Reads notation from an [s-expression][crate::notation::sexpr],
+    /// such as one written by [`to_sexpr`][Notation::to_sexpr].
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, sexpr::SexprError};
+    /// let value = Notation::from_sexpr("(sum (rad 2 3) (frac 1 2))").unwrap();
+    /// assert_eq!(value.to_string(), "2√3+1/2");
+    ///
+    /// assert_eq!(Notation::from_sexpr("(frac 1"), Err(SexprError::Unbalanced(0)));
+    /// ```
+    pub fn from_sexpr(text: &str) -> Result<Notation, SexprError> {
+        read(&parse(text)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::canonical::Canonical;
+
+    #[test]
+    fn test_round_trip() {
+        use Atom::*;
+        let values = [
+            Complex,
+            Undefined,
+            Huge,
+            NegativeHuge,
+            Epsilon,
+            NegativeEpsilon,
+            Pi,
+            E,
+            Variable(Var::new("x")),
+        ]
+        .into_iter()
+        .map(Notation::from)
+        .chain([
+            Notation::from(i32::MIN),
+            Notation::from(Fraction {
+                num: E,
+                den: Atom::from(-7),
+            }),
+            Notation::from(Radical { coef: -2, rad: 7 }),
+            Notation::from(RadicalFraction::new(3, 5, -4)),
+            Notation::from(Multiple::new(2, 3, Pi)),
+            Notation::from(Trig::new(TrigFunction::Cos, Fraction::new(1, 2))),
+            Notation::from(super::Complex::new(1, Radical::new(2))),
+            Notation::from(Sum::new([
+                Notation::from(1),
+                Notation::from(Trig::new(TrigFunction::Tan, 45)),
+                Notation::from(Sum::new([])),
+            ])),
+            Notation::from(NestedFraction::new(Fraction::new(1, 2), Radical::new(3))),
+            Notation::from(NestedRadical {
+                coef: 2,
+                ..NestedRadical::new(Radical::from(1) + Radical::new(3))
+            }),
+            Notation::from(Product::new([
+                Notation::from(Pi),
+                Notation::from(Variable(Var::new("x"))),
+                Notation::from(Product::new([])),
+            ])),
+            Notation::from(Power::new(Variable(Var::new("x")), Fraction::new(1, 3))),
+        ]);
+        for value in values {
+            let text = value.to_sexpr();
+            assert_eq!(
+                Notation::from_sexpr(&text).map(Canonical),
+                Ok(Canonical(value)),
+                "{text}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_written_forms() {
+        let value = Notation::from(Sum::new([
+            Notation::from(RadicalFraction::new(2, 3, 5)),
+            Notation::from(Multiple::new(2, 3, Atom::Pi)),
+            Notation::from(Trig::new(TrigFunction::Sin, 30)),
+            Notation::from(super::Complex::new(1, 2)),
+            Notation::from(Atom::NegativeEpsilon),
+        ]));
+        assert_eq!(
+            value.to_sexpr(),
+            "(sum (radfrac (rad 2 3) 5) (mul (frac (num 2) (num 3)) pi) (sin (num 30)) (cplx (num 1) (num 2)) -epsilon)"
+        );
+    }
+
+    #[test]
+    fn test_hand_written() {
+        let text = "
+            (sum
+              (frac (num -1)   2)
+              (tan 45))";
+        assert_eq!(
+            Notation::from_sexpr(text).map(|value| value.to_string()),
+            Ok("-1/2+tan(45°)".to_string())
+        );
+        assert_eq!(
+            Notation::from_sexpr("(sum)").map(Canonical),
+            Ok(Canonical(Notation::from(Sum::new([]))))
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        use SexprError::*;
+        let read = Notation::from_sexpr;
+        assert_eq!(read(""), Err(NotOneExpression));
+        assert_eq!(read("pi e"), Err(NotOneExpression));
+        assert_eq!(read("(num 1))"), Err(Unbalanced(7)));
+        assert_eq!(read("(sum (num 1)"), Err(Unbalanced(0)));
+        assert_eq!(read("tau"), Err(Unknown("tau".to_string())));
+        assert_eq!(read("(sec 30)"), Err(Unknown("sec".to_string())));
+        assert_eq!(read("(frac 1)"), Err(InvalidArguments("frac".to_string())));
+        assert_eq!(
+            read("(frac (rad 1 2) 3)"),
+            Err(InvalidArguments("frac".to_string()))
+        );
+        assert_eq!(
+            read("(radfrac 2 3)"),
+            Err(InvalidArguments("radfrac".to_string()))
+        );
+        assert_eq!(read("(sin 1 2)"), Err(InvalidArguments("sin".to_string())));
+        assert_eq!(read("()"), Err(InvalidArguments(String::new())));
+        assert_eq!(
+            read("(num 2147483648)"),
+            Err(OutOfRange("2147483648".to_string()))
+        );
+        assert_eq!(read("(num 1.5)"), Err(Unknown("1.5".to_string())));
+    }
+}
@@ -0,0 +1,200 @@
+//! Export of notation to Python source for SymPy, for operations this crate doesn't support.
+//!
+//! The output is a Python expression which evaluates to the same exact value
+//! after `from sympy import *`, such as `Rational(1,2)*sqrt(3)`.
+//! Integers are wrapped in `Integer` so that dividing them stays exact rather than becoming a float.
+//!
+//! Angles are stored in degrees, but SymPy's trigonometric functions take radians,
+//! so the angle is multiplied by `pi/180`.
+//!
+//! [`Huge`][Atom::Huge] and [`Epsilon`][Atom::Epsilon] have no SymPy equivalent,
+//! and are written as positive symbols named `huge` and `epsilon`.
+//! [`Unknown`][Atom::Unknown] and the [`Complex`][Atom::Complex] atom are written as `Dummy`s,
+//! so that no two such values are the same symbol.
+
+use crate::notation::{
+    atom::Atom,
+    expr::{
+        complex::Complex, fraction::Fraction, multiple::Multiple, nested_fraction::NestedFraction,
+        nested_radical::NestedRadical, power::Power, product::Product, radical::Radical,
+        radical_fraction::RadicalFraction, sum::Sum, trig::Trig, Expr,
+    },
+    Notation,
+};
+
+fn atom(atom: &Atom) -> String {
+    use Atom::*;
+    match atom {
+        Number(n) => format!("Integer({n})"),
+        Complex => "Dummy('complex')".to_string(),
+        Undefined => "nan".to_string(),
+        Huge => "Symbol('huge', positive=True)".to_string(),
+        NegativeHuge => "-Symbol('huge', positive=True)".to_string(),
+        Epsilon => "Symbol('epsilon', positive=True)".to_string(),
+        NegativeEpsilon => "-Symbol('epsilon', positive=True)".to_string(),
+        Unknown => "Dummy('unknown')".to_string(),
+        Pi => "pi".to_string(),
+        E => "E".to_string(),
+        Variable(var) => format!("Symbol('{var}')"),
+    }
+}
+
+fn fraction(Fraction { num, den }: &Fraction) -> String {
+    match (num, den) {
+        (Atom::Number(num), Atom::Number(den)) => format!("Rational({num},{den})"),
+        (num, den) => format!("{}/{}", atom(num), atom(den)),
+    }
+}
+
+fn radical(Radical { coef, rad }: &Radical) -> String {
+    match coef {
+        1 => format!("sqrt({rad})"),
+        -1 => format!("-sqrt({rad})"),
+        coef => format!("{coef}*sqrt({rad})"),
+    }
+}
+
+/// Writes the notation to be multiplied, in parentheses if it is made of added terms.
+fn factor(value: &Notation) -> String {
+    match value {
+        Notation::Expr(Expr::Sum(_) | Expr::Complex(_) | Expr::NestedFraction(_)) => {
+            format!("({})", sympy(value))
+        }
+        value => sympy(value),
+    }
+}
+
+fn sympy(value: &Notation) -> String {
+    match value {
+        Notation::Atom(value) => atom(value),
+        Notation::Expr(expr) => match expr {
+            Expr::Fraction(value) => fraction(value),
+            Expr::Radical(value) => radical(value),
+            Expr::RadicalFraction(RadicalFraction {
+                num: Radical { coef, rad },
+                den,
+            }) => format!("Rational({coef},{den})*sqrt({rad})"),
+            Expr::Multiple(Multiple { coef, constant }) => {
+                format!("{}*{}", fraction(coef), atom(constant))
+            }
+            Expr::Trig(Trig { func, arg }) => format!("{func}(pi*{}/180)", factor(arg)),
+            Expr::Complex(Complex { re, im }) => format!("{} + {}*I", sympy(re), factor(im)),
+            Expr::Sum(Sum { terms }) => match terms.as_ref() {
+                [] => "Integer(0)".to_string(),
+                terms => terms.iter().map(sympy).collect::<Vec<_>>().join(" + "),
+            },
+            Expr::NestedFraction(NestedFraction { num, den }) => {
+                format!("({})/({})", sympy(num), sympy(den))
+            }
+            Expr::NestedRadical(NestedRadical { coef, rad }) => match coef {
+                1 => format!("sqrt({})", sympy(rad)),
+                -1 => format!("-sqrt({})", sympy(rad)),
+                coef => format!("{coef}*sqrt({})", sympy(rad)),
+            },
+            Expr::Product(Product { factors }) => match factors.as_ref() {
+                [] => "Integer(1)".to_string(),
+                factors => factors.iter().map(factor).collect::<Vec<_>>().join("*"),
+            },
+            Expr::Power(Power { base, exp }) => format!("Pow({}, {})", sympy(base), sympy(exp)),
+        },
+    }
+}
+
+impl Notation {
+    /// Writes the notation as a [SymPy][crate::notation::sympy] expression, without simplifying it.
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, expr::radical_fraction::RadicalFraction};
+    /// let value = Notation::from(RadicalFraction::new(1, 3, 2));
+    /// assert_eq!(value.to_python_sympy(), "Rational(1,2)*sqrt(3)");
+    /// ```
+    pub fn to_python_sympy(&self) -> String {
+        sympy(self)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::expr::trig::TrigFunction;
+
+    fn python(value: impl Into<Notation>) -> String {
+        value.into().to_python_sympy()
+    }
+
+    #[test]
+    fn test_atoms() {
+        assert_eq!(python(-3), "Integer(-3)");
+        assert_eq!(python(Atom::Pi), "pi");
+        assert_eq!(python(Atom::E), "E");
+        assert_eq!(python(Atom::Complex), "Dummy('complex')");
+        assert_eq!(python(Atom::Undefined), "nan");
+        assert_eq!(python(Atom::NegativeHuge), "-Symbol('huge', positive=True)");
+    }
+
+    #[test]
+    fn test_expressions() {
+        assert_eq!(python(Fraction::new(-1, 2)), "Rational(-1,2)");
+        assert_eq!(
+            python(Fraction {
+                num: Atom::Pi,
+                den: Atom::from(3)
+            }),
+            "pi/Integer(3)"
+        );
+        assert_eq!(python(Radical::new(2)), "sqrt(2)");
+        assert_eq!(python(Radical { coef: -4, rad: 5 }), "-4*sqrt(5)");
+        assert_eq!(python(Multiple::new(2, 3, Atom::E)), "Rational(2,3)*E");
+        assert_eq!(
+            python(Trig::new(TrigFunction::Cos, 60)),
+            "cos(pi*Integer(60)/180)"
+        );
+        assert_eq!(python(Sum::new([])), "Integer(0)");
+        assert_eq!(
+            python(Sum::new([
+                Notation::from(1),
+                Notation::from(Radical::new(3))
+            ])),
+            "Integer(1) + sqrt(3)"
+        );
+        assert_eq!(
+            python(NestedFraction::new(
+                Fraction::new(1, 2),
+                RadicalFraction::new(1, 3, 2)
+            )),
+            "(Rational(1,2))/(Rational(1,2)*sqrt(3))"
+        );
+        assert_eq!(
+            python(NestedRadical {
+                coef: 3,
+                ..NestedRadical::new(Radical::from(2) + Radical::new(3))
+            }),
+            "3*sqrt(Integer(2) + sqrt(3))"
+        );
+        assert_eq!(python(Product::new([])), "Integer(1)");
+        assert_eq!(
+            python(Product::new([
+                Notation::from(Atom::Pi),
+                Sum::new([Notation::from(1), Notation::from(Radical::new(3))]).into()
+            ])),
+            "pi*(Integer(1) + sqrt(3))"
+        );
+        assert_eq!(
+            python(Power::new(2, Fraction::new(1, 3))),
+            "Pow(Integer(2), Rational(1,3))"
+        );
+    }
+
+    #[test]
+    fn test_grouping() {
+        let sum = Notation::from(Sum::new([Notation::from(1), Notation::from(Atom::Pi)]));
+        assert_eq!(
+            python(Complex::new(2, sum.clone())),
+            "Integer(2) + (Integer(1) + pi)*I"
+        );
+        assert_eq!(
+            python(Trig::new(TrigFunction::Tan, sum)),
+            "tan(pi*(Integer(1) + pi)/180)"
+        );
+    }
+}
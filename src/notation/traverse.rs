@@ -0,0 +1,305 @@
+//! Walking notation trees without recursion.
+//!
+//! Notation can be nested as deeply as whatever built it, such as the parser reading untrusted input.
+//! Walking it by recursion takes a stack frame for every level and can run out of stack,
+//! so these walks keep their own stack of work on the heap instead.
+//! [`simplify`][crate::notation::expr::simplify::Simplify::simplify], [`depth`][Notation::depth],
+//! [`node_count`][Notation::node_count], [`Display`][std::fmt::Display],
+//! and [`Canonical`][crate::notation::canonical::Canonical] comparisons are built on them.
+//!
+//! Dropping notation still goes one level at a time, so input which may be nested without limit,
+//! such as text typed by a student, should also have its depth limited when it is read,
+//! as with [`ParseOptions::max_depth`][crate::notation::parse::ParseOptions::max_depth].
+
+use crate::notation::{
+    expr::{
+        complex::Complex, nested_fraction::NestedFraction, nested_radical::NestedRadical,
+        power::Power, product::Product, sum::Sum, trig::Trig, Expr,
+    },
+    Notation,
+};
+use std::sync::Arc;
+
+/// Moves the notation out of an [`Arc`] if nothing else shares it, leaving a placeholder behind.
+fn take(part: &mut Arc<Notation>) -> Notation {
+    match Arc::get_mut(part) {
+        Some(part) => std::mem::take(part),
+        None => Notation::clone(part),
+    }
+}
+
+impl Notation {
+    /// Returns true if the notation can hold other notation, such as a trig function, a sum, or a product.
+    ///
+    /// Atoms and expressions made only of numbers, such as fractions and radicals, can't.
+    pub fn is_compound(&self) -> bool {
+        matches!(
+            self,
+            Notation::Expr(
+                Expr::Trig(_)
+                    | Expr::Complex(_)
+                    | Expr::Sum(_)
+                    | Expr::NestedFraction(_)
+                    | Expr::NestedRadical(_)
+                    | Expr::Product(_)
+                    | Expr::Power(_)
+            )
+        )
+    }
+
+    /// Returns the notation directly inside this notation, in the order it is written.
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, expr::{complex::Complex, fraction::Fraction}};
+    /// let value = Notation::from(Complex::new(Fraction::new(1, 2), 3));
+    /// assert_eq!(value.children(), [&Notation::from(Fraction::new(1, 2)), &Notation::from(3)]);
+    /// assert!(Notation::from(Fraction::new(1, 2)).children().is_empty());
+    /// ```
+    pub fn children(&self) -> Vec<&Notation> {
+        match self {
+            Notation::Atom(_) => Vec::new(),
+            Notation::Expr(expr) => match expr {
+                Expr::Fraction(_)
+                | Expr::Radical(_)
+                | Expr::RadicalFraction(_)
+                | Expr::Multiple(_) => Vec::new(),
+                Expr::Trig(value) => vec![&value.arg],
+                Expr::Complex(value) => vec![&value.re, &value.im],
+                Expr::Sum(value) => value.terms.iter().collect(),
+                Expr::NestedFraction(value) => vec![&value.num, &value.den],
+                Expr::NestedRadical(value) => vec![&value.rad],
+                Expr::Product(value) => value.factors.iter().collect(),
+                Expr::Power(value) => vec![&value.base, &value.exp],
+            },
+        }
+    }
+
+    /// Takes the children out of the notation, in the same order as [`children`][Notation::children].
+    ///
+    /// Children which aren't shared with another clone are moved rather than copied.
+    /// The notation is given back with placeholders where they were, to be filled in by [`with_children`][Notation::with_children].
+    pub(crate) fn take_children(self) -> (Notation, Vec<Notation>) {
+        let Notation::Expr(expr) = self else {
+            return (self, Vec::new());
+        };
+        let (expr, children) = match expr {
+            Expr::Trig(mut value) => {
+                let arg = take(&mut value.arg);
+                (Expr::Trig(value), vec![arg])
+            }
+            Expr::Complex(mut value) => {
+                let parts = vec![take(&mut value.re), take(&mut value.im)];
+                (Expr::Complex(value), parts)
+            }
+            Expr::Sum(value) => {
+                let mut shared = value.terms;
+                let terms = match Arc::get_mut(&mut shared) {
+                    Some(terms) => terms.iter_mut().map(std::mem::take).collect(),
+                    None => shared.to_vec(),
+                };
+                (Expr::Sum(Sum::new([])), terms)
+            }
+            Expr::NestedFraction(mut value) => {
+                let parts = vec![take(&mut value.num), take(&mut value.den)];
+                (Expr::NestedFraction(value), parts)
+            }
+            Expr::NestedRadical(mut value) => {
+                let rad = take(&mut value.rad);
+                (Expr::NestedRadical(value), vec![rad])
+            }
+            Expr::Product(value) => {
+                let mut shared = value.factors;
+                let factors = match Arc::get_mut(&mut shared) {
+                    Some(factors) => factors.iter_mut().map(std::mem::take).collect(),
+                    None => shared.to_vec(),
+                };
+                (Expr::Product(Product::new([])), factors)
+            }
+            Expr::Power(mut value) => {
+                let parts = vec![take(&mut value.base), take(&mut value.exp)];
+                (Expr::Power(value), parts)
+            }
+            expr => (expr, Vec::new()),
+        };
+        (Notation::Expr(expr), children)
+    }
+
+    /// Replaces the children of the notation, in the same order as [`children`][Notation::children].
+    ///
+    /// A sum takes every child given as a term, and a product every child given as a factor.
+    /// Any other notation ignores children past the ones it holds, and keeps its own where too few are given.
+    pub(crate) fn with_children(self, children: impl IntoIterator<Item = Notation>) -> Notation {
+        let mut children = children.into_iter();
+        match self {
+            Notation::Expr(Expr::Sum(_)) => return Notation::from(Sum::new(children)),
+            Notation::Expr(Expr::Product(_)) => return Notation::from(Product::new(children)),
+            _ => (),
+        }
+        let mut next = |part: Arc<Notation>| children.next().map(Arc::new).unwrap_or(part);
+        match self {
+            Notation::Expr(expr) => Notation::from(match expr {
+                Expr::Trig(Trig { func, arg }) => Expr::Trig(Trig {
+                    func,
+                    arg: next(arg),
+                }),
+                Expr::Complex(Complex { re, im }) => Expr::Complex(Complex {
+                    re: next(re),
+                    im: next(im),
+                }),
+                Expr::NestedFraction(NestedFraction { num, den }) => {
+                    Expr::NestedFraction(NestedFraction {
+                        num: next(num),
+                        den: next(den),
+                    })
+                }
+                Expr::NestedRadical(NestedRadical { coef, rad }) => {
+                    Expr::NestedRadical(NestedRadical {
+                        coef,
+                        rad: next(rad),
+                    })
+                }
+                Expr::Power(Power { base, exp }) => Expr::Power(Power {
+                    base: next(base),
+                    exp: next(exp),
+                }),
+                expr => expr,
+            }),
+            atom => atom,
+        }
+    }
+
+    /// Combines the notation from its leaves up, without recursing.
+    ///
+    /// `combine` is called once for every node of the tree, after every node inside it,
+    /// and is given the results for its [`children`][Notation::children] in order.
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, expr::{complex::Complex, sum::Sum}};
+    /// let value = Notation::from(Sum::new([1.into(), Complex::new(2, 3).into()]));
+    /// let atoms = value.fold(|node, children: Vec<usize>| match children.is_empty() {
+    ///     true => 1,
+    ///     false => children.into_iter().sum(),
+    /// });
+    /// assert_eq!(atoms, 3);
+    /// ```
+    pub fn fold<T>(&self, mut combine: impl FnMut(&Notation, Vec<T>) -> T) -> T {
+        enum Work<'a> {
+            Enter(&'a Notation),
+            Exit(&'a Notation, usize),
+        }
+        let mut work = vec![Work::Enter(self)];
+        let mut results = Vec::new();
+        while let Some(next) = work.pop() {
+            match next {
+                Work::Enter(node) => {
+                    let children = node.children();
+                    work.push(Work::Exit(node, children.len()));
+                    work.extend(children.into_iter().rev().map(Work::Enter));
+                }
+                Work::Exit(node, count) => {
+                    let children = results.split_off(results.len() - count);
+                    results.push(combine(node, children));
+                }
+            }
+        }
+        results.pop().expect("every node leaves one result")
+    }
+
+    /// Returns every node of the notation, each before the nodes inside it, in the order they are written.
+    ///
+    /// ```
+    /// # use algebra::notation::{Notation, expr::complex::Complex};
+    /// let value = Notation::from(Complex::new(2, 3));
+    /// let nodes = value.preorder().map(|node| node.to_string()).collect::<Vec<_>>();
+    /// assert_eq!(nodes, ["2+3𝑖", "2", "3"]);
+    /// ```
+    pub fn preorder(&self) -> impl Iterator<Item = &Notation> {
+        let mut work = vec![self];
+        std::iter::from_fn(move || {
+            let node = work.pop()?;
+            work.extend(node.children().into_iter().rev());
+            Some(node)
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::{
+        atom::Atom,
+        canonical::Canonical,
+        expr::{
+            fraction::Fraction,
+            radical::Radical,
+            simplify::Simplify,
+            trig::{Trig, TrigFunction},
+        },
+    };
+
+    /// sin(sin(...sin(2/4)...)), with `depth` levels in all.
+    fn nested_trig(depth: usize) -> Notation {
+        (2..depth).fold(Notation::from(Fraction::new(2, 4)), |arg, _| {
+            Notation::from(Trig::new(TrigFunction::Sin, arg))
+        })
+    }
+
+    #[test]
+    fn test_children_round_trip() {
+        let values = [
+            Notation::from(Atom::Pi),
+            Notation::from(Radical::new(2)),
+            Notation::from(Trig::new(TrigFunction::Cos, Fraction::new(1, 2))),
+            Notation::from(Complex::new(1, Radical::new(3))),
+            Notation::from(Sum::new([1.into(), Atom::E.into(), Radical::new(5).into()])),
+            Notation::from(NestedFraction::new(Atom::Pi, Radical::new(2))),
+            Notation::from(NestedRadical::new(Radical::from(2) + Radical::new(2))),
+        ];
+        for value in values {
+            let children = value.children().into_iter().cloned().collect::<Vec<_>>();
+            let (shell, taken) = value.clone().take_children();
+            assert_eq!(taken, children);
+            assert_eq!(shell.with_children(taken), value);
+        }
+    }
+
+    #[test]
+    fn test_fold_matches_depth() {
+        let value = Notation::from(Complex::new(
+            Trig::new(TrigFunction::Sin, NestedFraction::new(1, Radical::new(2))),
+            Sum::new([1.into(), Atom::E.into()]),
+        ));
+        let depth = value.fold(|node, children: Vec<usize>| match children.iter().max() {
+            Some(depth) => 1 + depth,
+            None => node.depth(),
+        });
+        assert_eq!(depth, value.depth());
+        assert_eq!(value.preorder().count(), 8);
+    }
+
+    #[test]
+    fn test_deep_notation() {
+        // Deep enough to overflow the stack of a test thread if simplified by recursion
+        let value = nested_trig(5_000);
+        assert_eq!(value.depth(), 5_000);
+        assert_eq!(value.node_count(), 5_001);
+        assert_eq!(value.preorder().count(), 4_999);
+        let simplified = value.simplify();
+        assert_eq!(simplified.depth(), 5_000);
+        assert_eq!(Canonical(simplified.clone()), Canonical(simplified.clone()));
+        assert_eq!(
+            simplified.preorder().last(),
+            Some(&Notation::from(Fraction::new(1, 2)))
+        );
+    }
+
+    #[test]
+    fn test_deep_display() {
+        // Deep enough to overflow the stack of a test thread if written by recursion
+        let value = nested_trig(5_000);
+        let text = value.to_string();
+        assert_eq!(text.matches("sin(").count(), 4_998);
+        assert!(text.starts_with("sin(sin("));
+        assert!(text.contains("(2/4°)°)°)"));
+    }
+}
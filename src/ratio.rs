@@ -0,0 +1,167 @@
+//! Ratios between any number of quantities, such as 2:3:5.
+//!
+//! Unlike a [`Fraction`][crate::notation::expr::fraction::Fraction], which is one number,
+//! a ratio compares several parts of a whole, as in "flour, sugar, and butter are mixed 2:3:5".
+
+use crate::notation::{
+    ops::{gcd, rational},
+    Notation,
+};
+
+/// An ordered ratio between any number of integer parts, such as 2:3:5.
+///
+/// ```
+/// # use algebra::{ratio::Ratio, notation::expr::fraction::Fraction};
+/// let ratio = Ratio::new([4, 6, 10]);
+/// assert_eq!(ratio.to_string(), "4:6:10");
+/// assert_eq!(ratio.simplified(), Ratio::new([2, 3, 5]));
+///
+/// // 40 cups of mix, split 2:3:5
+/// assert_eq!(ratio.scale_to(40), [8, 12, 20]);
+/// assert_eq!(ratio.fractions(), [Fraction::new(1, 5), Fraction::new(3, 10), Fraction::new(1, 2)]);
+/// ```
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Ratio {
+    /// The parts, in order.
+    pub parts: Vec<i32>,
+}
+
+impl Ratio {
+    /// Constructs a ratio between the parts, in the order given.
+    pub fn new(parts: impl Into<Vec<i32>>) -> Self {
+        Self {
+            parts: parts.into(),
+        }
+    }
+
+    /// Returns the greatest common factor of every part, which is never negative.
+    ///
+    /// A ratio without any nonzero parts has a GCF of 0.
+    pub fn gcf(&self) -> i32 {
+        let gcf = self
+            .parts
+            .iter()
+            .fold(0, |gcf, &part| gcd(gcf, part as i128));
+        // Only every part being i32::MIN gives a GCF too large for an i32
+        i32::try_from(gcf).unwrap_or(i32::MIN)
+    }
+
+    /// Returns the ratio with every part divided by their greatest common factor, such as 4:6:10 to 2:3:5.
+    ///
+    /// A ratio without any nonzero parts is already as simple as it can be.
+    pub fn simplified(&self) -> Self {
+        let gcf = self.gcf() as i128;
+        match gcf {
+            0 | 1 => self.clone(),
+            gcf => Self::new(
+                self.parts
+                    .iter()
+                    .map(|&part| (part as i128 / gcf.abs()) as i32)
+                    .collect::<Vec<_>>(),
+            ),
+        }
+    }
+
+    /// Returns true if the ratios simplify to the same parts, such as 2:3 and 4:6.
+    pub fn is_equivalent(&self, other: &Self) -> bool {
+        self.simplified() == other.simplified()
+    }
+
+    /// Returns the sum of the parts: the size of the whole the ratio splits.
+    pub fn total(&self) -> i64 {
+        self.parts.iter().map(|&part| part as i64).sum()
+    }
+
+    /// Splits `total` into parts in this ratio, each as an exact, reduced fraction.
+    ///
+    /// Splitting 40 by 2:3:5 gives 8, 12, and 20.
+    /// Parts of a ratio whose parts add to 0 are [`Undefined`][crate::notation::atom::Atom::Undefined].
+    pub fn scale_to(&self, total: i32) -> Vec<Notation> {
+        let whole = self.total() as i128;
+        self.parts
+            .iter()
+            .map(|&part| rational(total as i128 * part as i128, whole))
+            .collect()
+    }
+
+    /// Returns what fraction of the whole each part is, such as 1/5, 3/10, and 1/2 for 2:3:5.
+    ///
+    /// The same as splitting 1 into parts with [`scale_to`][Ratio::scale_to].
+    pub fn fractions(&self) -> Vec<Notation> {
+        self.scale_to(1)
+    }
+}
+
+impl<const N: usize> From<[i32; N]> for Ratio {
+    fn from(parts: [i32; N]) -> Self {
+        Self::new(parts)
+    }
+}
+
+impl std::fmt::Display for Ratio {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let parts = self
+            .parts
+            .iter()
+            .map(|part| part.to_string())
+            .collect::<Vec<_>>();
+        parts.join(":").fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::expr::fraction::Fraction;
+
+    #[test]
+    fn test_simplified() {
+        assert_eq!(Ratio::from([6, 9, 15]).simplified(), Ratio::from([2, 3, 5]));
+        assert_eq!(Ratio::from([2, 3]).simplified(), Ratio::from([2, 3]));
+        assert_eq!(Ratio::from([0, 4]).simplified(), Ratio::from([0, 1]));
+        assert_eq!(Ratio::from([0, 0]).simplified(), Ratio::from([0, 0]));
+        assert_eq!(Ratio::from([-4, 8]).simplified(), Ratio::from([-1, 2]));
+        assert_eq!(Ratio::default().simplified(), Ratio::default());
+        assert_eq!(
+            Ratio::from([i32::MIN, i32::MIN]).simplified(),
+            Ratio::from([-1, -1])
+        );
+        assert!(Ratio::from([2, 3]).is_equivalent(&Ratio::from([10, 15])));
+        assert!(!Ratio::from([2, 3]).is_equivalent(&Ratio::from([3, 2])));
+    }
+
+    #[test]
+    fn test_scale_to() {
+        let ratio = Ratio::from([1, 2]);
+        assert_eq!(ratio.total(), 3);
+        assert_eq!(ratio.scale_to(12), [4, 8]);
+        assert_eq!(
+            ratio.scale_to(10),
+            [Fraction::new(10, 3), Fraction::new(20, 3)]
+        );
+        assert_eq!(
+            Ratio::from([i32::MAX, i32::MAX]).scale_to(i32::MAX),
+            [Fraction::new(i32::MAX, 2), Fraction::new(i32::MAX, 2)]
+        );
+        for part in Ratio::from([1, -1]).scale_to(5) {
+            assert!(part.atom().is_some_and(|x| x.is_undefined()));
+        }
+    }
+
+    #[test]
+    fn test_fractions() {
+        assert_eq!(
+            Ratio::from([3, 1]).fractions(),
+            [Fraction::new(3, 4), Fraction::new(1, 4)]
+        );
+        assert_eq!(Ratio::from([5]).fractions(), [1]);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Ratio::from([2, 3, 5]).to_string(), "2:3:5");
+        assert_eq!(Ratio::from([7]).to_string(), "7");
+        assert_eq!(format!("{:>6}", Ratio::from([1, 2])), "   1:2");
+    }
+}
@@ -0,0 +1,444 @@
+//! An interactive read-eval-print loop, keeping the values of variables between lines.
+//!
+//! Enabled by the `cli` feature, and run by `algebra --repl`.
+//!
+//! ```
+//! # use algebra::repl::Repl;
+//! let mut repl = Repl::new();
+//! assert_eq!(repl.eval("x = 6/8").unwrap(), "x = 3/4");
+//! assert_eq!(repl.eval("4x + 1").unwrap(), "4");
+//! assert_eq!(repl.eval("solve 2y - 1 = y + x").unwrap(), "y = 7/4");
+//! assert_eq!(repl.eval("factor 12").unwrap(), "12 = 1 × 12 = 2 × 6 = 3 × 4");
+//! ```
+
+use crate::{
+    factor::Factoring,
+    notation::{expr::simplify::Simplify, parse::ParseError, Notation},
+    solve::{
+        env::Env,
+        equation::Equation,
+        inequality::{Inequality, Relation},
+        linear::Linear,
+        system::{solve_system, SystemSolution},
+        SolveError,
+    },
+};
+
+/// The commands understood by [`Repl::eval`], besides assignment and plain expressions.
+pub const COMMANDS: [&str; 5] = ["simplify", "factor", "solve", "vars", "help"];
+
+/// The functions and constants which can be written in notation.
+pub const FUNCTIONS: [&str; 10] = [
+    "sin",
+    "cos",
+    "tan",
+    "sqrt",
+    "pi",
+    "e",
+    "i",
+    "huge",
+    "epsilon",
+    "undefined",
+];
+
+const HELP: &str = "\
+x = 3/4               Sets the value of x
+2x + 1                Simplifies an expression, using the values set so far
+simplify 6/8          The same as writing the expression alone
+factor 12             Lists the pairs of factors of an integer
+solve 2x - 1 = x      Solves an equation or inequality (<, <=, >, >=) for its unknown
+solve 2x - 1 = y for x
+solve x + y = 3; x - y = 1
+vars                  Lists the values set so far";
+
+/// Reasons a line could not be evaluated.
+#[derive(Debug, PartialEq, Clone)]
+pub enum ReplError {
+    /// Part of the line was not notation.
+    Parse(ParseError),
+
+    /// The equation or inequality could not be solved.
+    Solve(SolveError),
+
+    /// The name cannot be used for a variable, such as a command or function name.
+    InvalidName(String),
+
+    /// The value has unknowns where only a constant is allowed.
+    ///
+    /// Contains the names of the unknowns.
+    Unknowns(Vec<String>),
+
+    /// Only integers can be factored.
+    NotInteger(String),
+
+    /// The command was used incorrectly.
+    ///
+    /// Contains an example of its correct use.
+    Usage(&'static str),
+}
+
+impl std::fmt::Display for ReplError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use ReplError::*;
+        match self {
+            Parse(e) => e.fmt(f),
+            Solve(e) => e.fmt(f),
+            InvalidName(name) => format!("\"{name}\" cannot be a variable").fmt(f),
+            Unknowns(vars) => format!("unknown value of {}", vars.join(", ")).fmt(f),
+            NotInteger(value) => format!("{value} is not an integer").fmt(f),
+            Usage(example) => format!("usage: {example}").fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ReplError {}
+
+impl From<ParseError> for ReplError {
+    fn from(e: ParseError) -> Self {
+        Self::Parse(e)
+    }
+}
+
+impl From<SolveError> for ReplError {
+    fn from(e: SolveError) -> Self {
+        Self::Solve(e)
+    }
+}
+
+/// Returns true if `name` can be used as a variable, false otherwise.
+///
+/// Variables are made of ASCII letters, and cannot share a name with a command or function.
+pub fn is_variable_name(name: &str) -> bool {
+    !name.is_empty()
+        && name.chars().all(|c| c.is_ascii_alphabetic())
+        && !COMMANDS.contains(&name)
+        && !FUNCTIONS.contains(&name)
+        && name != "deg"
+        && name != "for"
+}
+
+/// Splits text at each `+` and `-` between terms, keeping the sign with the term that follows.
+fn terms(text: &str) -> Vec<String> {
+    let mut terms = vec![String::new()];
+    let mut depth = 0;
+    for c in text.chars() {
+        let previous = terms.last().unwrap().trim_end().chars().last();
+        let is_binary = match previous {
+            Some('/' | '*' | '×' | '(' | '+' | '-') | None => false,
+            Some(_) => depth == 0,
+        };
+        match c {
+            '(' => depth += 1,
+            ')' => depth -= 1,
+            '+' | '-' if is_binary => terms.push(String::new()),
+            _ => (),
+        }
+        let term = terms.last_mut().unwrap();
+        if c == '-' || !(c == '+' && is_binary) {
+            term.push(c);
+        }
+    }
+    terms
+}
+
+/// Interactive evaluation of algebra, one line at a time.
+#[derive(Debug, Default, Clone)]
+pub struct Repl {
+    /// The value of each variable that has been set, keyed by its name.
+    pub vars: Env,
+}
+
+impl Repl {
+    /// Constructs a REPL with no variables set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Evaluates one line, returning what to print in response.
+    pub fn eval(&mut self, line: &str) -> Result<String, ReplError> {
+        let line = line.trim();
+        let (command, rest) = match line.split_once(char::is_whitespace) {
+            Some((command, rest)) => (command, rest.trim()),
+            None => (line, ""),
+        };
+        match command {
+            "" => Ok(String::new()),
+            "help" => Ok(HELP.to_string()),
+            "vars" => Ok(self
+                .vars
+                .iter()
+                .map(|(name, value)| format!("{name} = {value}"))
+                .collect::<Vec<_>>()
+                .join("\n")),
+            "simplify" if rest.is_empty() => Err(ReplError::Usage("simplify 6/8")),
+            "simplify" => Ok(self.linear(rest)?.to_string()),
+            "factor" => self.factor(rest),
+            "solve" => self.solve(rest),
+            _ => match line.split_once('=') {
+                Some((name, value)) if !value.starts_with('=') => self.assign(name.trim(), value),
+                _ => Ok(self.linear(line)?.to_string()),
+            },
+        }
+    }
+
+    /// Returns the commands, functions, and variables which start with `prefix`, in alphabetical order.
+    pub fn completions(&self, prefix: &str) -> Vec<String> {
+        let mut names = COMMANDS
+            .iter()
+            .chain(FUNCTIONS.iter())
+            .map(|name| name.to_string())
+            .chain(self.vars.iter().map(|(name, _)| name.to_string()))
+            .filter(|name| name.starts_with(prefix))
+            .collect::<Vec<_>>();
+        names.sort();
+        names.dedup();
+        names
+    }
+
+    fn assign(&mut self, name: &str, value: &str) -> Result<String, ReplError> {
+        if !is_variable_name(name) {
+            return Err(ReplError::InvalidName(name.to_string()));
+        }
+        let value = self.linear(value)?;
+        if !value.is_constant() {
+            return Err(ReplError::Unknowns(
+                value.variables().map(String::from).collect(),
+            ));
+        }
+        let text = format!("{name} = {}", value.constant);
+        self.vars.set(name, value.constant);
+        Ok(text)
+    }
+
+    /// Reads a sum of terms such as `2x - 3/4 + y`, replacing variables which have been set with their values.
+    fn linear(&self, text: &str) -> Result<Linear, ReplError> {
+        let mut sum = Linear::from(0);
+        for term in terms(text) {
+            sum = sum + self.term(term.trim())?;
+        }
+        Ok(sum)
+    }
+
+    /// Reads a single term, such as `-2x`, `3/4 × y`, or `√2`.
+    fn term(&self, text: &str) -> Result<Linear, ReplError> {
+        // Let the sign be spaced apart from the rest of the term, as in `x - 1`
+        let text = &match text.strip_prefix('-') {
+            Some(rest) => format!("-{}", rest.trim_start()),
+            None => text.to_string(),
+        };
+        let split = text.len()
+            - text
+                .chars()
+                .rev()
+                .take_while(char::is_ascii_alphabetic)
+                .count();
+        let (coef, var) = text.split_at(split);
+        if !is_variable_name(var) {
+            return Ok(Linear::from(text.parse::<Notation>()?.simplify()));
+        }
+
+        let coef = coef.trim().trim_end_matches(['*', '×']).trim_end();
+        let coef = match coef {
+            "" | "+" => Notation::from(1),
+            "-" => Notation::from(-1),
+            coef => coef.parse::<Notation>()?.simplify(),
+        };
+        Ok(match self.vars.get(var) {
+            Some(value) => Linear::from(coef * value.clone()),
+            None => Linear::term(coef, var),
+        })
+    }
+
+    fn factor(&self, text: &str) -> Result<String, ReplError> {
+        if text.is_empty() {
+            return Err(ReplError::Usage("factor 12"));
+        }
+        let value = self.linear(text)?;
+        if !value.is_constant() {
+            return Err(ReplError::Unknowns(
+                value.variables().map(String::from).collect(),
+            ));
+        }
+        let n = i32::try_from(value.constant.clone())
+            .map_err(|_| ReplError::NotInteger(value.constant.to_string()))?;
+        let pairs = n
+            .factors()
+            .into_iter()
+            .take_while(|factor| factor.common.unsigned_abs() <= factor.associated.unsigned_abs())
+            .map(|factor| format!("{} × {}", factor.common, factor.associated))
+            .collect::<Vec<_>>();
+        Ok(format!("{n} = {}", pairs.join(" = ")))
+    }
+
+    fn solve(&self, text: &str) -> Result<String, ReplError> {
+        const USAGE: &str = "solve 2x - 1 = x [for x]";
+        if text.contains(';') {
+            return self.solve_system(text);
+        }
+
+        let (text, var) = match text.rsplit_once(" for ") {
+            Some((text, var)) => (text, Some(var.trim())),
+            None => (text, None),
+        };
+        let relations = [
+            ("<=", Some(Relation::LessOrEqual)),
+            ("≤", Some(Relation::LessOrEqual)),
+            (">=", Some(Relation::GreaterOrEqual)),
+            ("≥", Some(Relation::GreaterOrEqual)),
+            ("<", Some(Relation::Less)),
+            (">", Some(Relation::Greater)),
+            ("=", None),
+        ];
+        let Some((lhs, rhs, relation)) = relations.iter().find_map(|(symbol, relation)| {
+            let (lhs, rhs) = text.split_once(symbol)?;
+            Some((lhs, rhs, *relation))
+        }) else {
+            return Err(ReplError::Usage(USAGE));
+        };
+        let (lhs, rhs) = (self.linear(lhs)?, self.linear(rhs)?);
+
+        let var = match var {
+            Some(var) => var.to_string(),
+            None => {
+                let mut vars = lhs.variables().chain(rhs.variables()).collect::<Vec<_>>();
+                vars.sort();
+                vars.dedup();
+                match vars[..] {
+                    [var] => var.to_string(),
+                    _ => return Err(ReplError::Usage(USAGE)),
+                }
+            }
+        };
+        let solution = match relation {
+            Some(relation) => Inequality { lhs, rhs, relation }.solve_for(&var)?,
+            None => Equation { lhs, rhs }.solve_for(&var)?,
+        };
+        Ok(solution.in_terms_of(&var))
+    }
+
+    fn solve_system(&self, text: &str) -> Result<String, ReplError> {
+        const USAGE: &str = "solve x + y = 3; x - y = 1";
+        let equations = text
+            .split(';')
+            .map(|equation| {
+                let (lhs, rhs) = equation.split_once('=').ok_or(ReplError::Usage(USAGE))?;
+                Ok(Equation {
+                    lhs: self.linear(lhs)?,
+                    rhs: self.linear(rhs)?,
+                })
+            })
+            .collect::<Result<Vec<_>, ReplError>>()?;
+        Ok(match solve_system(&equations)? {
+            SystemSolution::Unique(values) => values
+                .iter()
+                .map(|(var, value)| format!("{var} = {value}"))
+                .collect::<Vec<_>>()
+                .join(", "),
+            SystemSolution::Inconsistent => "no solution".to_string(),
+            SystemSolution::Dependent => "infinitely many solutions".to_string(),
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_terms() {
+        assert_eq!(terms("2x - 3/4 + y"), ["2x ", "- 3/4 ", " y"]);
+        assert_eq!(terms("-x-1/-2"), ["-x", "-1/-2"]);
+        assert_eq!(terms("sin(-30°) + 1"), ["sin(-30°) ", " 1"]);
+    }
+
+    #[test]
+    fn test_assign() {
+        let mut repl = Repl::new();
+        assert_eq!(repl.eval("x = 2").unwrap(), "x = 2");
+        assert_eq!(repl.eval("y = 3x - 1").unwrap(), "y = 5");
+        assert_eq!(repl.eval("vars").unwrap(), "x = 2\ny = 5");
+        assert_eq!(
+            repl.eval("sin = 1"),
+            Err(ReplError::InvalidName("sin".to_string()))
+        );
+        assert_eq!(
+            repl.eval("z = w + 1"),
+            Err(ReplError::Unknowns(vec!["w".to_string()]))
+        );
+    }
+
+    #[test]
+    fn test_expressions() {
+        let mut repl = Repl::new();
+        assert_eq!(repl.eval("simplify 6/8").unwrap(), "3/4");
+        assert_eq!(repl.eval("2x - x + 1/2").unwrap(), "x + 1/2");
+        assert_eq!(repl.eval("2 * pi").unwrap(), "2π");
+        assert!(matches!(repl.eval("2 +"), Err(ReplError::Parse(_))));
+    }
+
+    #[test]
+    fn test_factor() {
+        let mut repl = Repl::new();
+        assert_eq!(repl.eval("factor 7").unwrap(), "7 = 1 × 7");
+        assert_eq!(repl.eval("factor -6").unwrap(), "-6 = 1 × -6 = 2 × -3");
+        let min = repl.eval("factor -2147483648").unwrap();
+        assert!(min.starts_with("-2147483648 = 1 × -2147483648 = 2 × -1073741824"));
+        assert!(min.ends_with("= 32768 × -65536"));
+        assert_eq!(
+            repl.eval("factor 1/2"),
+            Err(ReplError::NotInteger("1/2".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_no_panics() {
+        // Lines the library can't give a simpler form for still give an answer or an error
+        let mut repl = Repl::new();
+        for line in [
+            "pi * pi",
+            "-pi",
+            "sqrt(-4)",
+            "1/0",
+            "2147483647 + 1",
+            "-2147483648/-1",
+            "huge - huge",
+            "x^2",
+            "factor 2147483647",
+            "solve 2147483647x = -2147483648",
+        ] {
+            let _ = repl.eval(line);
+        }
+    }
+
+    #[test]
+    fn test_solve() {
+        let mut repl = Repl::new();
+        assert_eq!(repl.eval("solve 2x - 1 = x").unwrap(), "x = 1");
+        assert_eq!(repl.eval("solve -2x + 1 <= -2").unwrap(), "x ≥ 3/2");
+        assert_eq!(
+            repl.eval("solve 2x = y for x").unwrap_err().to_string(),
+            "cannot solve with unknowns y"
+        );
+        repl.eval("y = 4").unwrap();
+        assert_eq!(repl.eval("solve 2x = y for x").unwrap(), "x = 2");
+        assert_eq!(
+            repl.eval("solve a + b = 3; a - b = 1").unwrap(),
+            "a = 2, b = 1"
+        );
+        assert_eq!(
+            repl.eval("solve 1 = 2"),
+            Err(ReplError::Usage("solve 2x - 1 = x [for x]"))
+        );
+    }
+
+    #[test]
+    fn test_completions() {
+        let mut repl = Repl::new();
+        repl.eval("size = 1").unwrap();
+        assert_eq!(
+            repl.completions("s"),
+            ["simplify", "sin", "size", "solve", "sqrt"]
+        );
+        assert!(repl.completions("q").is_empty());
+    }
+}
@@ -0,0 +1,47 @@
+//! Solving equations and inequalities for unknown variables.
+
+pub mod condition;
+pub mod domain;
+pub mod env;
+pub mod equation;
+pub mod function;
+pub mod inequality;
+pub mod interval;
+pub mod linear;
+pub mod polynomial;
+pub mod proportion;
+pub mod set;
+pub mod system;
+pub mod term;
+pub mod var;
+
+/// Reasons an equation or inequality could not be solved.
+#[derive(Debug, PartialEq, Clone)]
+pub enum SolveError {
+    /// Variables other than the one being solved for remain after isolating it.
+    ///
+    /// Contains the names of the remaining variables.
+    OtherVariables(Vec<String>),
+
+    /// A coefficient isn't an integer or fraction, or became too large to be operated on exactly.
+    Inexact,
+
+    /// The variable appears where it can't be isolated exactly,
+    /// such as in a denominator or radicand which isn't linear, or in the angle of a tangent.
+    Unsupported,
+}
+
+impl std::fmt::Display for SolveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use SolveError::*;
+        match self {
+            OtherVariables(vars) => {
+                format!("cannot solve with unknowns {}", vars.join(", ")).fmt(f)
+            }
+            Inexact => "coefficients cannot be operated on exactly".fmt(f),
+            Unsupported => "variable cannot be isolated exactly".fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for SolveError {}
@@ -0,0 +1,640 @@
+//! Conditions on variables, built from equations and inequalities with "and", "or", and "not".
+
+use super::{
+    env::{Env, EvalError},
+    equation::Equation,
+    inequality::{Inequality, Relation},
+    interval::{Bound, Interval},
+    linear::Linear,
+    set::SolutionSet,
+    SolveError,
+};
+use crate::notation::Notation;
+
+/// A statement about variables which is either true or false once they have values,
+/// such as `x > 3 ∧ x ≠ 5`.
+///
+/// ```
+/// # use algebra::solve::{condition::Condition, env::Env, inequality::{Inequality, Relation}, linear::Linear};
+/// let above = |value| Condition::from(Inequality {
+///     lhs: Linear::var("x"),
+///     rhs: Linear::from(value),
+///     relation: Relation::Greater,
+/// });
+/// let both = above(3) & above(5);
+/// assert_eq!(both.to_string(), "x > 3 ∧ x > 5");
+/// assert_eq!(both.simplified().to_string(), "x > 5");
+/// assert_eq!(both.eval_with(&Env::new().with("x", 4)), Ok(false));
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub enum Condition {
+    /// Holds no matter the values of the variables.
+    Always,
+
+    /// Never holds, no matter the values of the variables.
+    Never,
+
+    /// Holds when the sides of the equation are equal.
+    Equation(Equation),
+
+    /// Holds when the sides of the inequality compare by its relation.
+    Inequality(Inequality),
+
+    /// Holds when every one of the conditions holds: `a ∧ b`.
+    And(Vec<Condition>),
+
+    /// Holds when any of the conditions hold: `a ∨ b`.
+    Or(Vec<Condition>),
+
+    /// Holds when the condition doesn't: `¬a`.
+    Not(Box<Condition>),
+}
+
+impl From<Equation> for Condition {
+    fn from(value: Equation) -> Self {
+        Self::Equation(value)
+    }
+}
+
+impl From<Inequality> for Condition {
+    fn from(value: Inequality) -> Self {
+        Self::Inequality(value)
+    }
+}
+
+impl Condition {
+    /// Returns the names of all variables in the condition, in alphabetical order.
+    pub fn variables(&self) -> Vec<&str> {
+        use Condition::*;
+        let mut vars = match self {
+            Always | Never => Vec::new(),
+            Equation(eq) => eq.lhs.variables().chain(eq.rhs.variables()).collect(),
+            Inequality(ineq) => ineq.lhs.variables().chain(ineq.rhs.variables()).collect(),
+            And(conditions) | Or(conditions) => {
+                conditions.iter().flat_map(Condition::variables).collect()
+            }
+            Not(condition) => condition.variables(),
+        };
+        vars.sort_unstable();
+        vars.dedup();
+        vars
+    }
+
+    /// Returns whether the condition holds with the values of the variables from `env`.
+    ///
+    /// Sides which can't be compared (see [`Notation::compare()`]) are neither equal nor unequal,
+    /// so equations and inequalities between them don't hold.
+    ///
+    /// Fails with [`EvalError::Unbound`] if any variable in the condition has no value.
+    pub fn eval_with(&self, env: &Env) -> Result<bool, EvalError> {
+        env.check_bound(self.variables())?;
+        Ok(self.holds(env))
+    }
+
+    /// Whether the condition holds, once every variable is known to have a value.
+    fn holds(&self, env: &Env) -> bool {
+        use Condition::*;
+        let compare = |lhs: &Linear, rhs: &Linear| {
+            let (lhs, rhs) = (lhs.substitute(env).constant, rhs.substitute(env).constant);
+            lhs.compare(&rhs)
+        };
+        match self {
+            Always => true,
+            Never => false,
+            Equation(eq) => compare(&eq.lhs, &eq.rhs) == Some(std::cmp::Ordering::Equal),
+            Inequality(ineq) => {
+                compare(&ineq.lhs, &ineq.rhs).is_some_and(|cmp| ineq.relation.holds(cmp))
+            }
+            And(conditions) => conditions.iter().all(|x| x.holds(env)),
+            Or(conditions) => conditions.iter().any(|x| x.holds(env)),
+            Not(condition) => !condition.holds(env),
+        }
+    }
+
+    /// Returns the set of real values of `var` for which the condition holds.
+    ///
+    /// Fails if any variable other than `var` appears in the condition.
+    ///
+    /// ```
+    /// # use algebra::solve::{condition::Condition, inequality::{Inequality, Relation}, linear::Linear};
+    /// let outside = |relation, value| Condition::from(Inequality {
+    ///     lhs: Linear::var("x"),
+    ///     rhs: Linear::from(value),
+    ///     relation,
+    /// });
+    /// let condition = outside(Relation::Less, -1) | outside(Relation::Greater, 1);
+    /// assert_eq!(condition.solve_for("x").unwrap().to_string(), "(-∞, -1) ∪ (1, ∞)");
+    /// assert_eq!((!condition).solve_for("x").unwrap().to_string(), "[-1, 1]");
+    /// ```
+    pub fn solve_for(&self, var: &str) -> Result<SolutionSet, SolveError> {
+        use Condition::*;
+        Ok(match self {
+            Always => SolutionSet::AllReals,
+            Never => SolutionSet::Empty,
+            Equation(eq) => eq.solve_for(var)?,
+            Inequality(ineq) => ineq.solve_for(var)?,
+            And(conditions) => {
+                let mut set = SolutionSet::AllReals;
+                for condition in conditions {
+                    set = set.intersection(&condition.solve_for(var)?);
+                }
+                set
+            }
+            Or(conditions) => {
+                let mut set = SolutionSet::Empty;
+                for condition in conditions {
+                    set = set.union(condition.solve_for(var)?);
+                }
+                merged(set)
+            }
+            Not(condition) => complement(condition.solve_for(var)?),
+        })
+    }
+
+    /// Returns an equivalent condition which is simpler, or the same condition if it can't be simplified.
+    ///
+    /// Conditions on a single variable are solved, and written as the fewest comparisons of the variable with values,
+    /// so `x > 3 ∧ x > 5` becomes `x > 5` and `2x ≤ 4 ∨ x < 1` becomes `x ≤ 2`.
+    /// Conditions without variables become [`Always`][Condition::Always] or [`Never`][Condition::Never].
+    ///
+    /// Conditions on several variables keep their shape, but have "not" moved inward past "and" and "or",
+    /// and the parts which only depend on one variable combined and simplified.
+    ///
+    /// ```
+    /// # use algebra::solve::{condition::Condition, equation::Equation, linear::Linear};
+    /// let is = |var, value| Condition::from(Equation { lhs: Linear::var(var), rhs: Linear::from(value) });
+    /// let condition = !(is("x", 1) | is("y", 2));
+    /// assert_eq!(condition.to_string(), "¬(x = 1 ∨ y = 2)");
+    /// assert_eq!(condition.simplified().to_string(), "x ≠ 1 ∧ y ≠ 2");
+    /// ```
+    pub fn simplified(&self) -> Self {
+        use Condition::*;
+        match self.variables().as_slice() {
+            [] => match self.holds(&Env::new()) {
+                true => Always,
+                false => Never,
+            },
+            [var] => match self.solve_for(var) {
+                Ok(set) => in_terms_of(var, &set),
+                Err(_) => self.clone(),
+            },
+            _ => match self {
+                And(conditions) => combine(conditions.iter().map(Self::simplified), true),
+                Or(conditions) => combine(conditions.iter().map(Self::simplified), false),
+                Not(condition) => negated(condition.simplified()),
+                condition => condition.clone(),
+            },
+        }
+    }
+}
+
+/// Moves "not" inward, so that only equations are negated.
+fn negated(condition: Condition) -> Condition {
+    use Condition::*;
+    match condition {
+        Always => Never,
+        Never => Always,
+        Inequality(ineq) => Inequality(super::inequality::Inequality {
+            relation: ineq.relation.negated(),
+            ..ineq
+        }),
+        And(conditions) => Or(conditions.into_iter().map(negated).collect()),
+        Or(conditions) => And(conditions.into_iter().map(negated).collect()),
+        Not(condition) => *condition,
+        condition @ Equation(_) => Not(Box::new(condition)),
+    }
+}
+
+/// Joins simplified conditions with "and" if `is_and`, otherwise with "or".
+///
+/// Conditions on the same single variable are combined and simplified together.
+fn combine(conditions: impl IntoIterator<Item = Condition>, is_and: bool) -> Condition {
+    use Condition::*;
+    let (identity, absorbing) = match is_and {
+        true => (Always, Never),
+        false => (Never, Always),
+    };
+    // Each single variable's conditions, kept where the first of them appeared
+    let mut parts: Vec<(Option<String>, Vec<Condition>)> = Vec::new();
+    let mut add = |condition: Condition| {
+        let var = match condition.variables().as_slice() {
+            [var] => Some(var.to_string()),
+            _ => None,
+        };
+        match parts
+            .iter_mut()
+            .find(|(other, _)| var.is_some() && *other == var)
+        {
+            Some((_, group)) => group.push(condition),
+            None => parts.push((var, vec![condition])),
+        }
+    };
+    for condition in conditions {
+        match (condition, is_and) {
+            (And(conditions), true) | (Or(conditions), false) => {
+                conditions.into_iter().for_each(&mut add)
+            }
+            (condition, _) => add(condition),
+        }
+    }
+
+    let mut result = Vec::new();
+    for (_, mut group) in parts {
+        let condition = match group.len() {
+            1 => group.remove(0),
+            _ if is_and => And(group).simplified(),
+            _ => Or(group).simplified(),
+        };
+        match condition {
+            condition if condition == absorbing => return absorbing,
+            condition if condition == identity => {}
+            condition => result.push(condition),
+        }
+    }
+    match result.len() {
+        0 => identity,
+        1 => result.remove(0),
+        _ if is_and => And(result),
+        _ => Or(result),
+    }
+}
+
+/// Returns the set of real numbers not in `set`.
+fn complement(set: SolutionSet) -> SolutionSet {
+    use SolutionSet::*;
+    let outside = |lower: &Bound, upper: &Bound| {
+        let flip = |bound: &Bound| match bound {
+            Bound::Unbounded => None,
+            Bound::Open(value) => Some(Bound::Closed(value.clone())),
+            Bound::Closed(value) => Some(Bound::Open(value.clone())),
+        };
+        let mut parts = Empty;
+        if let Some(upper) = flip(lower) {
+            parts = parts.union(Interval(super::interval::Interval {
+                lower: Bound::Unbounded,
+                upper,
+            }));
+        }
+        if let Some(lower) = flip(upper) {
+            parts = parts.union(Interval(super::interval::Interval {
+                lower,
+                upper: Bound::Unbounded,
+            }));
+        }
+        parts
+    };
+    match set {
+        Empty => AllReals,
+        AllReals => Empty,
+        Interval(interval) if interval.is_empty() => AllReals,
+        Interval(interval) => outside(&interval.lower, &interval.upper),
+        Finite(values) => values.iter().fold(AllReals, |set, value| {
+            let point = Bound::Closed(value.clone());
+            set.intersection(&outside(&point, &point))
+        }),
+        Union(sets) => sets
+            .into_iter()
+            .fold(AllReals, |set, part| set.intersection(&complement(part))),
+    }
+}
+
+/// Joins the overlapping intervals of a union, and drops values already within an interval.
+fn merged(set: SolutionSet) -> SolutionSet {
+    use SolutionSet::*;
+    let Union(sets) = set else {
+        return set;
+    };
+    let mut intervals: Vec<super::interval::Interval> = Vec::new();
+    let mut values = Vec::new();
+    // Taken from the back, so reversed to keep the order they were given in
+    let mut pending = sets;
+    pending.reverse();
+    while let Some(set) = pending.pop() {
+        match set {
+            Empty => {}
+            AllReals => return AllReals,
+            Finite(more) => values.extend(more),
+            Union(more) => pending.extend(more.into_iter().rev()),
+            Interval(mut interval) => {
+                // Joining two intervals can close the gap to one already kept
+                while let Some(i) = intervals.iter().position(|x| x.union(&interval).is_some()) {
+                    let other = intervals.remove(i);
+                    interval = other.union(&interval).expect("the intervals overlap");
+                }
+                intervals.push(interval);
+            }
+        }
+    }
+    if intervals
+        .iter()
+        .any(|x| *x == super::interval::Interval::all())
+    {
+        return AllReals;
+    }
+    values.retain(|value| !intervals.iter().any(|x| x.contains(value)));
+    let mut sets = intervals.into_iter().map(Interval).collect::<Vec<_>>();
+    if !values.is_empty() {
+        sets.push(Finite(values));
+    }
+    match sets.len() {
+        0 => Empty,
+        1 => sets.remove(0),
+        _ => Union(sets),
+    }
+}
+
+/// Writes a set of values of `var` as comparisons of the variable with values.
+fn in_terms_of(var: &str, set: &SolutionSet) -> Condition {
+    use std::cmp::Ordering::*;
+    use Condition::*;
+    let is = |value: &Notation| {
+        Equation(super::equation::Equation {
+            lhs: Linear::var(var),
+            rhs: Linear::from(value.clone()),
+        })
+    };
+    let compare = |relation, value: &Notation| {
+        Inequality(super::inequality::Inequality {
+            lhs: Linear::var(var),
+            rhs: Linear::from(value.clone()),
+            relation,
+        })
+    };
+    let interval = |interval: &Interval| {
+        let lower = match &interval.lower {
+            Bound::Unbounded => None,
+            Bound::Open(value) => Some(compare(Relation::Greater, value)),
+            Bound::Closed(value) => Some(compare(Relation::GreaterOrEqual, value)),
+        };
+        let upper = match &interval.upper {
+            Bound::Unbounded => None,
+            Bound::Open(value) => Some(compare(Relation::Less, value)),
+            Bound::Closed(value) => Some(compare(Relation::LessOrEqual, value)),
+        };
+        match (lower, upper) {
+            (None, None) => Always,
+            (Some(bound), None) | (None, Some(bound)) => bound,
+            _ if interval.is_empty() => Never,
+            (Some(lower), Some(upper)) => match (&interval.lower, &interval.upper) {
+                (Bound::Closed(lo), Bound::Closed(hi)) if lo.compare(hi) == Some(Equal) => is(lo),
+                _ => And(vec![lower, upper]),
+            },
+        }
+    };
+    match set {
+        SolutionSet::Empty => Never,
+        SolutionSet::AllReals => Always,
+        SolutionSet::Finite(values) => match values.as_slice() {
+            [] => Never,
+            [value] => is(value),
+            values => Or(values.iter().map(is).collect()),
+        },
+        SolutionSet::Interval(x) => interval(x),
+        SolutionSet::Union(sets) => {
+            // Every value but one
+            if let [SolutionSet::Interval(a), SolutionSet::Interval(b)] = sets.as_slice() {
+                for (below, above) in [(a, b), (b, a)] {
+                    if let (Bound::Unbounded, Bound::Open(hi), Bound::Open(lo), Bound::Unbounded) =
+                        (&below.lower, &below.upper, &above.lower, &above.upper)
+                    {
+                        if hi.compare(lo) == Some(Equal) {
+                            return Not(Box::new(is(hi)));
+                        }
+                    }
+                }
+            }
+            Or(sets.iter().map(|set| in_terms_of(var, set)).collect())
+        }
+    }
+}
+
+impl std::ops::BitAnd for Condition {
+    type Output = Self;
+
+    /// Joins two conditions with "and", continuing an existing "and" on the left.
+    fn bitand(self, rhs: Self) -> Self::Output {
+        match self {
+            Condition::And(mut conditions) => {
+                conditions.push(rhs);
+                Condition::And(conditions)
+            }
+            lhs => Condition::And(vec![lhs, rhs]),
+        }
+    }
+}
+
+impl std::ops::BitOr for Condition {
+    type Output = Self;
+
+    /// Joins two conditions with "or", continuing an existing "or" on the left.
+    fn bitor(self, rhs: Self) -> Self::Output {
+        match self {
+            Condition::Or(mut conditions) => {
+                conditions.push(rhs);
+                Condition::Or(conditions)
+            }
+            lhs => Condition::Or(vec![lhs, rhs]),
+        }
+    }
+}
+
+impl std::ops::Not for Condition {
+    type Output = Self;
+
+    /// Negates the condition.
+    fn not(self) -> Self::Output {
+        Condition::Not(Box::new(self))
+    }
+}
+
+impl std::fmt::Display for Condition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use Condition::*;
+        // Conditions joined inside of others are grouped with parentheses
+        let part = |condition: &Condition| match condition {
+            And(_) | Or(_) => format!("({condition})"),
+            condition => condition.to_string(),
+        };
+        let join = |conditions: &[Condition], op: &str| {
+            conditions.iter().map(part).collect::<Vec<_>>().join(op)
+        };
+        match self {
+            Always => "true".fmt(f),
+            Never => "false".fmt(f),
+            Equation(eq) => eq.fmt(f),
+            Inequality(ineq) => ineq.fmt(f),
+            And(conditions) => join(conditions, " ∧ ").fmt(f),
+            Or(conditions) => join(conditions, " ∨ ").fmt(f),
+            Not(condition) => match condition.as_ref() {
+                Equation(eq) => format!("{} ≠ {}", eq.lhs, eq.rhs).fmt(f),
+                condition => format!("¬({condition})").fmt(f),
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Relation::*, *};
+    use crate::notation::expr::fraction::Fraction;
+
+    fn cmp(var: &str, relation: Relation, value: i32) -> Condition {
+        Condition::from(Inequality {
+            lhs: Linear::var(var),
+            rhs: Linear::from(value),
+            relation,
+        })
+    }
+
+    fn is(var: &str, value: i32) -> Condition {
+        Condition::from(Equation {
+            lhs: Linear::var(var),
+            rhs: Linear::from(value),
+        })
+    }
+
+    fn simplified(condition: Condition) -> String {
+        condition.simplified().to_string()
+    }
+
+    #[test]
+    fn test_eval() {
+        let condition = (cmp("x", Greater, 0) & cmp("y", LessOrEqual, 2)) | is("x", -5);
+        let env = |x, y| Env::new().with("x", x).with("y", y);
+        assert_eq!(condition.eval_with(&env(1, 2)), Ok(true));
+        assert_eq!(condition.eval_with(&env(1, 3)), Ok(false));
+        assert_eq!(condition.eval_with(&env(-5, 3)), Ok(true));
+        assert_eq!(condition.eval_with(&env(0, 0)), Ok(false));
+        assert_eq!((!condition.clone()).eval_with(&env(0, 0)), Ok(true));
+        assert_eq!(
+            condition.eval_with(&Env::new().with("x", 1)),
+            Err(EvalError::Unbound(vec!["y".to_string()]))
+        );
+        assert_eq!(Condition::Always.eval_with(&Env::new()), Ok(true));
+    }
+
+    #[test]
+    fn test_eval_fractions() {
+        // 3x < 1
+        let condition = Condition::from(Inequality {
+            lhs: Linear::term(3, "x"),
+            rhs: Linear::from(1),
+            relation: Less,
+        });
+        let at = |value| condition.eval_with(&Env::new().with("x", value));
+        assert_eq!(at(Fraction::new(1, 3)), Ok(false));
+        assert_eq!(at(Fraction::new(1, 4)), Ok(true));
+    }
+
+    #[test]
+    fn test_simplify_one_variable() {
+        assert_eq!(
+            simplified(cmp("x", Greater, 3) & cmp("x", Greater, 5)),
+            "x > 5"
+        );
+        assert_eq!(
+            simplified(cmp("x", Greater, 3) | cmp("x", Greater, 5)),
+            "x > 3"
+        );
+        assert_eq!(
+            simplified(cmp("x", Greater, 1) & cmp("x", LessOrEqual, 4)),
+            "x > 1 ∧ x ≤ 4"
+        );
+        assert_eq!(
+            simplified(cmp("x", Greater, 5) & cmp("x", Less, 3)),
+            "false"
+        );
+        assert_eq!(
+            simplified(cmp("x", GreaterOrEqual, 3) | cmp("x", Less, 5)),
+            "true"
+        );
+        assert_eq!(
+            simplified(cmp("x", GreaterOrEqual, 3) & cmp("x", LessOrEqual, 3)),
+            "x = 3"
+        );
+        assert_eq!(
+            simplified(cmp("x", Less, 3) | cmp("x", Greater, 3)),
+            "x ≠ 3"
+        );
+        assert_eq!(simplified(!cmp("x", Less, 3)), "x ≥ 3");
+        assert_eq!(simplified(is("x", 2) | cmp("x", Less, 5)), "x < 5");
+        assert_eq!(
+            simplified(cmp("x", Less, 0) | cmp("x", Greater, 2) | cmp("x", Less, 3)),
+            "true"
+        );
+        // 2x + 1 ≥ 7
+        let condition = Condition::from(Inequality {
+            lhs: Linear::term(2, "x") + Linear::from(1),
+            rhs: Linear::from(7),
+            relation: GreaterOrEqual,
+        });
+        assert_eq!(simplified(condition), "x ≥ 3");
+    }
+
+    #[test]
+    fn test_simplify_constants() {
+        let constant = |relation, value| {
+            Condition::from(Inequality {
+                lhs: Linear::from(1),
+                rhs: Linear::from(value),
+                relation,
+            })
+        };
+        assert_eq!(constant(Less, 2).simplified(), Condition::Always);
+        assert_eq!(constant(Greater, 2).simplified(), Condition::Never);
+        assert_eq!(
+            (cmp("x", Less, 0) & constant(Less, 2)).simplified(),
+            cmp("x", Less, 0)
+        );
+    }
+
+    #[test]
+    fn test_simplify_several_variables() {
+        let condition = cmp("x", Greater, 3) & cmp("y", Less, 0) & cmp("x", Greater, 5);
+        assert_eq!(simplified(condition), "x > 5 ∧ y < 0");
+        let condition = (cmp("x", Less, 1) | is("y", 0)) & cmp("x", Less, 2);
+        assert_eq!(simplified(condition.clone()), "(x < 1 ∨ y = 0) ∧ x < 2");
+        assert_eq!(simplified(!condition), "(x ≥ 1 ∧ y ≠ 0) ∨ x ≥ 2");
+        assert_eq!(
+            simplified(cmp("x", Less, 1) | (is("y", 0) & Condition::Never)),
+            "x < 1"
+        );
+        assert_eq!(
+            simplified(cmp("x", Less, 1) & !(is("y", 0) | Condition::Always)),
+            "false"
+        );
+    }
+
+    #[test]
+    fn test_solve_for() {
+        let not_two = !is("x", 2);
+        assert_eq!(
+            not_two.solve_for("x").unwrap().to_string(),
+            "(-∞, 2) ∪ (2, ∞)"
+        );
+        assert_eq!(simplified(not_two.clone()), "x ≠ 2");
+        assert_eq!(simplified(not_two & is("x", 2)), "false");
+        assert_eq!(
+            (is("x", 1) | is("x", 4))
+                .solve_for("x")
+                .unwrap()
+                .to_string(),
+            "{1, 4}"
+        );
+        assert_eq!(simplified(is("x", 1) | is("x", 4)), "x = 1 ∨ x = 4");
+        assert_eq!(
+            cmp("x", Less, 1).solve_for("y"),
+            Err(SolveError::OtherVariables(vec!["x".to_string()]))
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Condition::Always.to_string(), "true");
+        assert_eq!((!cmp("x", Less, 1)).to_string(), "¬(x < 1)");
+        assert_eq!(
+            (cmp("x", Less, 1) | (is("y", 0) & !is("z", 1))).to_string(),
+            "x < 1 ∨ (y = 0 ∧ z ≠ 1)"
+        );
+    }
+}
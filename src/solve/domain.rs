@@ -0,0 +1,213 @@
+//! Where notation containing variables is defined.
+
+use super::{
+    condition::Condition, equation::Equation, inequality::Inequality, inequality::Relation,
+    linear::Linear, set::SolutionSet, SolveError,
+};
+use crate::notation::{
+    atom::Atom,
+    expr::{trig::TrigFunction, Expr},
+    Notation,
+};
+
+/// Returns the atoms held directly by a node, not counting those of its children.
+fn atoms(node: &Notation) -> Vec<&Atom> {
+    match node {
+        Notation::Atom(atom) => vec![atom],
+        Notation::Expr(Expr::Fraction(fraction)) => vec![&fraction.num, &fraction.den],
+        Notation::Expr(Expr::Multiple(multiple)) => {
+            vec![&multiple.coef.num, &multiple.coef.den, &multiple.constant]
+        }
+        Notation::Expr(_) => Vec::new(),
+    }
+}
+
+impl Notation {
+    /// Returns the names of all variables in the notation, in alphabetical order.
+    ///
+    /// ```
+    /// # use algebra::{notation::{Notation, expr::sum::Sum}, solve::var::Var};
+    /// let value = Notation::from(Sum::new([Var::new("y").into(), Var::new("x").into(), 1.into()]));
+    /// assert_eq!(value.variables(), ["x", "y"]);
+    /// ```
+    pub fn variables(&self) -> Vec<&str> {
+        let mut vars = self
+            .preorder()
+            .flat_map(atoms)
+            .filter_map(|atom| match atom {
+                Atom::Variable(var) => Some(var.as_str()),
+                _ => None,
+            })
+            .collect::<Vec<_>>();
+        vars.sort_unstable();
+        vars.dedup();
+        vars
+    }
+
+    /// Returns the set of real values of `var` for which the notation is defined:
+    /// where no denominator is 0 and no radicand is negative.
+    ///
+    /// Notation which is [`Undefined`][Atom::Undefined] no matter the value has an empty domain.
+    ///
+    /// Fails with [`SolveError::OtherVariables`] if whether the notation is defined depends on variables other than `var`,
+    /// and with [`SolveError::Unsupported`] if a denominator or radicand isn't linear,
+    /// or if a variable is in the angle of a tangent, which is undefined infinitely many times.
+    ///
+    /// ```
+    /// # use algebra::{notation::{Notation, expr::{nested_fraction::NestedFraction, nested_radical::NestedRadical}}, solve::var::Var};
+    /// let x = || Notation::from(Var::new("x"));
+    /// let reciprocal = Notation::from(NestedFraction::new(1, x() - Notation::from(2)));
+    /// assert_eq!(reciprocal.domain("x").unwrap().to_string(), "(-∞, 2) ∪ (2, ∞)");
+    ///
+    /// let root = Notation::from(NestedRadical::new(x() + Notation::from(3)));
+    /// assert_eq!(root.domain("x").unwrap().to_string(), "[-3, ∞)");
+    /// ```
+    pub fn domain(&self, var: &str) -> Result<SolutionSet, SolveError> {
+        let linear = |value: &Notation| Linear::from_notation(value).ok_or(SolveError::Unsupported);
+        let not_zero = |value: &Notation| -> Result<Condition, SolveError> {
+            Ok(!Condition::from(Equation {
+                lhs: linear(value)?,
+                rhs: Linear::from(0),
+            }))
+        };
+
+        let mut conditions = Vec::new();
+        for node in self.preorder() {
+            let has_variable = |value: &Notation| !value.variables().is_empty();
+            match node {
+                Notation::Atom(Atom::Undefined) => conditions.push(Condition::Never),
+                Notation::Expr(Expr::Fraction(fraction)) => match &fraction.den {
+                    Atom::Number(den) if den.value == 0 => conditions.push(Condition::Never),
+                    den @ Atom::Variable(_) => {
+                        conditions.push(not_zero(&Notation::from(den.clone()))?)
+                    }
+                    _ => {}
+                },
+                Notation::Expr(Expr::NestedFraction(fraction)) => {
+                    if has_variable(&fraction.den) {
+                        conditions.push(not_zero(&fraction.den)?);
+                    } else if fraction.den.is_zero() {
+                        conditions.push(Condition::Never);
+                    }
+                }
+                Notation::Expr(Expr::NestedRadical(root)) if has_variable(&root.rad) => {
+                    conditions.push(Condition::from(Inequality {
+                        lhs: linear(&root.rad)?,
+                        rhs: Linear::from(0),
+                        relation: Relation::GreaterOrEqual,
+                    }));
+                }
+                Notation::Expr(Expr::Trig(trig))
+                    if trig.func == TrigFunction::Tan && has_variable(&trig.arg) =>
+                {
+                    return Err(SolveError::Unsupported);
+                }
+                _ => {}
+            }
+        }
+        Condition::And(conditions).solve_for(var)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        notation::expr::{
+            fraction::Fraction, multiple::Multiple, nested_fraction::NestedFraction,
+            nested_radical::NestedRadical, sum::Sum, trig::Trig,
+        },
+        solve::var::Var,
+    };
+
+    fn var(name: &str) -> Notation {
+        Notation::from(Var::new(name))
+    }
+
+    #[test]
+    fn test_variables() {
+        let value = Notation::from(NestedFraction::new(
+            Fraction {
+                num: Atom::from(Var::new("b")),
+                den: Atom::from(2),
+            },
+            Sum::new([var("a"), var("b")]),
+        ));
+        assert_eq!(value.variables(), ["a", "b"]);
+        assert!(Notation::from(Atom::Pi).variables().is_empty());
+    }
+
+    #[test]
+    fn test_defined_everywhere() {
+        assert_eq!(Notation::from(5).domain("x"), Ok(SolutionSet::AllReals));
+        assert_eq!(var("x").domain("x"), Ok(SolutionSet::AllReals));
+        // sin(x) has no denominator or radicand
+        let sine = Notation::from(Trig::new(TrigFunction::Sin, var("x")));
+        assert_eq!(sine.domain("x"), Ok(SolutionSet::AllReals));
+        // Only denominators and radicands limit the domain, not other variables
+        assert_eq!(
+            Notation::from(Sum::new([var("x"), var("y")]))
+                .domain("x")
+                .map(|x| x.to_string()),
+            Ok("ℝ".to_string())
+        );
+    }
+
+    #[test]
+    fn test_denominators() {
+        let over_x = Notation::from(Fraction {
+            num: Atom::from(1),
+            den: Atom::from(Var::new("x")),
+        });
+        assert_eq!(over_x.domain("x").unwrap().to_string(), "(-∞, 0) ∪ (0, ∞)");
+        // 1/(2x + 1)
+        let value = Notation::from(NestedFraction::new(
+            1,
+            Sum::new([
+                Multiple::new(2, 1, Atom::from(Var::new("x"))).into(),
+                1.into(),
+            ]),
+        ));
+        assert_eq!(
+            value.domain("x").unwrap().to_string(),
+            "(-∞, -1/2) ∪ (-1/2, ∞)"
+        );
+        assert!(Notation::from(NestedFraction::new(var("x"), 0))
+            .domain("x")
+            .unwrap()
+            .is_empty());
+        assert!(Notation::from(Atom::Undefined)
+            .domain("x")
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_radicands() {
+        // √(4 - x)/x
+        let value = Notation::from(NestedFraction::new(
+            NestedRadical::new(Notation::from(4) - var("x")),
+            var("x"),
+        ));
+        assert_eq!(value.domain("x").unwrap().to_string(), "(-∞, 0) ∪ (0, 4]");
+        // √x and √(-x) are both defined only at 0
+        let value = Notation::from(Sum::new([
+            NestedRadical::new(var("x")).into(),
+            NestedRadical::new(-var("x")).into(),
+        ]));
+        assert_eq!(value.domain("x").unwrap().to_string(), "[0, 0]");
+    }
+
+    #[test]
+    fn test_unsupported() {
+        let value = Notation::from(NestedFraction::new(1, var("x") - var("y")));
+        assert_eq!(
+            value.domain("x"),
+            Err(SolveError::OtherVariables(vec!["y".to_string()]))
+        );
+        let tangent = Notation::from(Trig::new(TrigFunction::Tan, var("x")));
+        assert_eq!(tangent.domain("x"), Err(SolveError::Unsupported));
+        let nested = Notation::from(NestedFraction::new(1, NestedRadical::new(var("x"))));
+        assert_eq!(nested.domain("x"), Err(SolveError::Unsupported));
+    }
+}
@@ -0,0 +1,145 @@
+//! Values of variables, for evaluating expressions which contain them.
+
+use std::collections::BTreeMap;
+
+use super::var::Var;
+use crate::notation::Notation;
+
+/// Reasons an expression could not be evaluated.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum EvalError {
+    /// Variables in the expression have no value.
+    ///
+    /// Contains the names of the variables, in alphabetical order.
+    Unbound(Vec<String>),
+}
+
+impl std::fmt::Display for EvalError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            EvalError::Unbound(vars) => format!("no value for {}", vars.join(", ")).fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for EvalError {}
+
+/// The value of each variable, keyed by the variable's name.
+///
+/// ```
+/// # use algebra::{notation::{Notation, expr::fraction::Fraction}, solve::{env::Env, linear::Linear}};
+/// let env = Env::new().with("x", 2).with("y", Fraction::new(1, 2));
+/// let expr = Linear::term(3, "x") + Linear::var("y");
+/// assert_eq!(expr.eval_with(&env), Ok(Notation::from(Fraction::new(13, 2))));
+/// ```
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct Env {
+    values: BTreeMap<Var, Notation>,
+}
+
+impl From<BTreeMap<Var, Notation>> for Env {
+    /// Construct an environment from a map of values, such as a [solved system][crate::solve::system::SystemSolution::Unique].
+    fn from(values: BTreeMap<Var, Notation>) -> Self {
+        Self { values }
+    }
+}
+
+impl FromIterator<(Var, Notation)> for Env {
+    fn from_iter<T: IntoIterator<Item = (Var, Notation)>>(iter: T) -> Self {
+        Self {
+            values: iter.into_iter().collect(),
+        }
+    }
+}
+
+impl Env {
+    /// Constructs an environment without any variables.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the environment with the variable named `var` set to `value`.
+    pub fn with(mut self, var: &str, value: impl Into<Notation>) -> Self {
+        self.set(var, value);
+        self
+    }
+
+    /// Sets the variable named `var` to `value`, returning its previous value if it had one.
+    pub fn set(&mut self, var: &str, value: impl Into<Notation>) -> Option<Notation> {
+        self.values.insert(Var::new(var), value.into())
+    }
+
+    /// Removes the variable named `var`, returning its value if it had one.
+    pub fn remove(&mut self, var: &str) -> Option<Notation> {
+        self.values.remove(&Var::new(var))
+    }
+
+    /// Returns the value of the variable named `var`, or [`None`] if it has no value.
+    pub fn get(&self, var: &str) -> Option<&Notation> {
+        self.values.get(&Var::new(var))
+    }
+
+    /// Returns true if the variable named `var` has a value, false otherwise.
+    pub fn contains(&self, var: &str) -> bool {
+        self.values.contains_key(&Var::new(var))
+    }
+
+    /// Returns the number of variables with values.
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    /// Returns true if no variables have values, false otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    /// Returns each variable's name and value, in alphabetical order.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Notation)> {
+        self.values.iter().map(|(var, value)| (var.as_str(), value))
+    }
+
+    /// Returns an error listing the variables which have no value, if there are any.
+    pub(crate) fn check_bound<'a>(
+        &self,
+        vars: impl IntoIterator<Item = &'a str>,
+    ) -> Result<(), EvalError> {
+        let mut unbound = vars
+            .into_iter()
+            .filter(|var| !self.contains(var))
+            .map(String::from)
+            .collect::<Vec<_>>();
+        unbound.sort();
+        unbound.dedup();
+        match unbound.is_empty() {
+            true => Ok(()),
+            false => Err(EvalError::Unbound(unbound)),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_set_and_remove() {
+        let mut env = Env::new().with("x", 1);
+        assert_eq!(env.set("x", 2), Some(Notation::from(1)));
+        assert_eq!(env.get("x"), Some(&Notation::from(2)));
+        assert_eq!(env.len(), 1);
+        assert_eq!(env.remove("x"), Some(Notation::from(2)));
+        assert!(env.is_empty());
+        assert!(!env.contains("x"));
+    }
+
+    #[test]
+    fn test_check_bound() {
+        let env = Env::new().with("x", 1);
+        assert_eq!(env.check_bound(["x", "x"]), Ok(()));
+        assert_eq!(
+            env.check_bound(["z", "x", "y", "z"]),
+            Err(EvalError::Unbound(vec!["y".to_string(), "z".to_string()]))
+        );
+    }
+}
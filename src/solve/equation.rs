@@ -0,0 +1,103 @@
+//! Equations between linear expressions.
+
+use super::{linear::Linear, set::SolutionSet, SolveError};
+
+/// A statement that two linear expressions are equal.
+///
+/// ```
+/// # use algebra::solve::{equation::Equation, linear::Linear};
+/// // 4x - 3 = x
+/// let eq = Equation {
+///     lhs: Linear::term(4, "x") - Linear::from(3),
+///     rhs: Linear::var("x"),
+/// };
+/// assert_eq!(eq.to_string(), "4x - 3 = x");
+/// assert_eq!(eq.solve_for("x").unwrap().to_string(), "{1}");
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Equation {
+    /// The left side of the equation.
+    pub lhs: Linear,
+
+    /// The right side of the equation.
+    pub rhs: Linear,
+}
+
+impl Equation {
+    /// Returns the equivalent expression which equals zero: `lhs - rhs`.
+    pub fn zeroed(&self) -> Linear {
+        self.lhs.clone() - self.rhs.clone()
+    }
+
+    /// Returns the set of values of `var` for which the equation holds.
+    ///
+    /// Fails if any variable other than `var` appears in the equation.
+    pub fn solve_for(&self, var: &str) -> Result<SolutionSet, SolveError> {
+        // Move everything to the left: 𝑎𝑥 + 𝑏 = 0
+        let diff = self.zeroed();
+
+        let others = diff
+            .variables()
+            .filter(|&v| v != var)
+            .map(String::from)
+            .collect::<Vec<_>>();
+        if !others.is_empty() {
+            return Err(SolveError::OtherVariables(others));
+        }
+
+        let coef = diff.coefficient(var);
+        let constant = diff.constant;
+
+        Ok(if coef != 0 {
+            // 𝑥 = -𝑏/𝑎
+            SolutionSet::Finite(vec![-constant / coef])
+        } else if constant == 0 {
+            SolutionSet::AllReals
+        } else {
+            SolutionSet::Empty
+        })
+    }
+}
+
+impl std::fmt::Display for Equation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Self { lhs, rhs } = self;
+        format!("{lhs} = {rhs}").fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::{expr::fraction::Fraction, Notation};
+
+    #[test]
+    fn test_solve_for() {
+        // 3x + 1 = 2
+        let eq = Equation {
+            lhs: Linear::term(3, "x") + Linear::from(1),
+            rhs: Linear::from(2),
+        };
+        assert_eq!(
+            eq.solve_for("x"),
+            Ok(SolutionSet::Finite(vec![Notation::from(Fraction::new(
+                1, 3
+            ))]))
+        );
+    }
+
+    #[test]
+    fn test_identity_and_contradiction() {
+        let identity = Equation {
+            lhs: Linear::var("x") + Linear::from(1),
+            rhs: Linear::from(1) + Linear::var("x"),
+        };
+        assert_eq!(identity.solve_for("x"), Ok(SolutionSet::AllReals));
+
+        let contradiction = Equation {
+            lhs: Linear::var("x"),
+            rhs: Linear::var("x") + Linear::from(1),
+        };
+        assert_eq!(contradiction.solve_for("x"), Ok(SolutionSet::Empty));
+    }
+}
@@ -0,0 +1,233 @@
+//! Named functions of variables, written in function notation: 𝑓(𝑥) = 2𝑥 + 1.
+
+use super::{env::Env, linear::Linear, var::Var};
+
+/// Reasons a function could not be applied.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ApplyError {
+    /// The function was given a different number of arguments than it has parameters.
+    Arity {
+        /// The number of parameters the function has.
+        expected: usize,
+
+        /// The number of arguments it was given.
+        found: usize,
+    },
+}
+
+impl std::fmt::Display for ApplyError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ApplyError::Arity { expected, found } => {
+                format!("expected {expected} arguments, found {found}").fmt(f)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ApplyError {}
+
+/// A named function whose value is a linear expression of its parameters, such as 𝑓(𝑥) = 2𝑥 + 1.
+///
+/// Variables in the body which aren't parameters are left as they are when the function is applied,
+/// like the 𝑏 in 𝑓(𝑥) = 𝑥 + 𝑏.
+///
+/// ```
+/// # use algebra::solve::{function::Function, linear::Linear};
+/// let f = Function::new("f", &["x"], Linear::term(2, "x") + Linear::from(1));
+/// assert_eq!(f.to_string(), "f(x) = 2x + 1");
+/// assert_eq!(f.apply(&[Linear::from(3)]).unwrap().to_string(), "7");
+/// assert_eq!(f.apply(&[Linear::var("a") - Linear::from(1)]).unwrap().to_string(), "2a - 1");
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Function {
+    /// The name the function is written with, such as 𝑓.
+    pub name: String,
+
+    /// The parameters, in the order arguments are given.
+    pub params: Vec<Var>,
+
+    /// The value of the function, in terms of its parameters.
+    pub body: Linear,
+}
+
+impl Function {
+    /// Constructs the function `name(params) = body`.
+    pub fn new(name: &str, params: &[&str], body: Linear) -> Self {
+        Self {
+            name: name.to_string(),
+            params: params.iter().map(|&param| Var::new(param)).collect(),
+            body,
+        }
+    }
+
+    /// Returns the body with each parameter replaced by the argument in the same position, all at once.
+    ///
+    /// Fails with [`ApplyError::Arity`] if there aren't exactly as many arguments as parameters.
+    ///
+    /// ```
+    /// # use algebra::solve::{function::Function, linear::Linear};
+    /// // Parameters are replaced together, so swapping them works
+    /// let f = Function::new("f", &["x", "y"], Linear::var("x") - Linear::term(2, "y"));
+    /// let swapped = f.apply(&[Linear::var("y"), Linear::var("x")]).unwrap();
+    /// assert_eq!(swapped.to_string(), "-2x + y");
+    /// ```
+    pub fn apply(&self, args: &[Linear]) -> Result<Linear, ApplyError> {
+        if args.len() != self.params.len() {
+            return Err(ApplyError::Arity {
+                expected: self.params.len(),
+                found: args.len(),
+            });
+        }
+        let mut result = Linear::from(self.body.constant.clone());
+        for (var, coef) in &self.body.terms {
+            result = result
+                + match self.params.iter().position(|param| param == var) {
+                    Some(i) => args[i].clone() * coef.clone(),
+                    None => Linear::term(coef.clone(), var),
+                };
+        }
+        Ok(result)
+    }
+
+    /// Applies the function to the values of its parameters set in `env`,
+    /// leaving parameters without a value as they are.
+    ///
+    /// ```
+    /// # use algebra::solve::{env::Env, function::Function, linear::Linear};
+    /// let f = Function::new("f", &["x", "y"], Linear::var("x") + Linear::var("y"));
+    /// assert_eq!(f.apply_with(&Env::new().with("x", 4)).to_string(), "y + 4");
+    /// ```
+    pub fn apply_with(&self, env: &Env) -> Linear {
+        let args = self
+            .params
+            .iter()
+            .map(|param| match env.get(param) {
+                Some(value) => Linear::from(value.clone()),
+                None => Linear::var(param),
+            })
+            .collect::<Vec<_>>();
+        self.apply(&args)
+            .expect("there is one argument for every parameter")
+    }
+
+    /// Returns the composition `(self∘inner)`, which applies `inner` and then `self`: (𝑓∘𝑔)(𝑥) = 𝑓(𝑔(𝑥)).
+    ///
+    /// The composition takes the parameters of `inner`.
+    /// Fails with [`ApplyError::Arity`] unless `self` has exactly one parameter to pass the value of `inner` to.
+    ///
+    /// ```
+    /// # use algebra::solve::{function::Function, linear::Linear};
+    /// let f = Function::new("f", &["x"], Linear::term(2, "x") + Linear::from(1));
+    /// let g = Function::new("g", &["x"], Linear::var("x") - Linear::from(3));
+    /// assert_eq!(f.compose(&g).unwrap().to_string(), "(f∘g)(x) = 2x - 5");
+    /// assert_eq!(g.compose(&f).unwrap().to_string(), "(g∘f)(x) = 2x - 2");
+    /// ```
+    pub fn compose(&self, inner: &Function) -> Result<Function, ApplyError> {
+        Ok(Self {
+            name: format!("({}∘{})", self.name, inner.name),
+            params: inner.params.clone(),
+            body: self.apply(std::slice::from_ref(&inner.body))?,
+        })
+    }
+}
+
+impl std::fmt::Display for Function {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let params = self
+            .params
+            .iter()
+            .map(|param| param.as_str())
+            .collect::<Vec<_>>();
+        format!("{}({}) = {}", self.name, params.join(", "), self.body).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::{expr::fraction::Fraction, Notation};
+
+    fn linear(coef: i32, constant: i32) -> Function {
+        Function::new(
+            "f",
+            &["x"],
+            Linear::term(coef, "x") + Linear::from(constant),
+        )
+    }
+
+    #[test]
+    fn test_apply() {
+        let f = linear(3, -2);
+        assert_eq!(f.apply(&[Linear::from(0)]), Ok(Linear::from(-2)));
+        assert_eq!(
+            f.apply(&[Linear::from(Notation::from(Fraction::new(1, 3)))]),
+            Ok(Linear::from(-1))
+        );
+        assert_eq!(
+            f.apply(&[Linear::var("x")]),
+            Ok(Linear::term(3, "x") - Linear::from(2))
+        );
+        // Applying to an expression of x combines like terms
+        assert_eq!(
+            f.apply(&[Linear::var("x") + Linear::term(2, "y")]),
+            Ok(Linear::term(3, "x") + Linear::term(6, "y") - Linear::from(2))
+        );
+    }
+
+    #[test]
+    fn test_free_variables() {
+        let f = Function::new("f", &["x"], Linear::var("x") + Linear::var("b"));
+        assert_eq!(f.apply(&[Linear::from(2)]).unwrap().to_string(), "b + 2");
+        assert_eq!(f.apply(&[Linear::var("b")]), Ok(Linear::term(2, "b")));
+    }
+
+    #[test]
+    fn test_arity() {
+        let f = linear(1, 0);
+        assert_eq!(
+            f.apply(&[]),
+            Err(ApplyError::Arity {
+                expected: 1,
+                found: 0
+            })
+        );
+        let g = Function::new("g", &["x", "y"], Linear::var("x"));
+        // Only a function of one variable can take the value of another function
+        assert_eq!(
+            g.compose(&f),
+            Err(ApplyError::Arity {
+                expected: 2,
+                found: 1
+            })
+        );
+        assert_eq!(f.compose(&g).unwrap().params, g.params);
+    }
+
+    #[test]
+    fn test_compose() {
+        // The inverse of f(x) = 2x + 4 is g(x) = x/2 - 2
+        let f = linear(2, 4);
+        let g = Function::new(
+            "g",
+            &["x"],
+            Linear::term(Fraction::new(1, 2), "x") - Linear::from(2),
+        );
+        let identity = Linear::var("x");
+        assert_eq!(f.compose(&g).unwrap().body, identity);
+        assert_eq!(g.compose(&f).unwrap().body, identity);
+        let h = f.compose(&g).unwrap().compose(&f).unwrap();
+        assert_eq!(h.to_string(), "((f∘g)∘f)(x) = 2x + 4");
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(linear(0, 5).to_string(), "f(x) = 5");
+        let sum = Function::new("s", &["a", "b"], Linear::var("a") + Linear::var("b"));
+        assert_eq!(sum.to_string(), "s(a, b) = a + b");
+        assert_eq!(
+            Function::new("c", &[], Linear::from(1)).to_string(),
+            "c() = 1"
+        );
+    }
+}
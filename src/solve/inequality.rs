@@ -0,0 +1,269 @@
+//! Inequalities between linear expressions.
+
+use super::{
+    interval::{Bound, Interval},
+    linear::Linear,
+    set::SolutionSet,
+    SolveError,
+};
+
+/// The way two sides of an [`Inequality`] compare.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub enum Relation {
+    /// `<`
+    Less,
+
+    /// `≤`
+    LessOrEqual,
+
+    /// `>`
+    Greater,
+
+    /// `≥`
+    GreaterOrEqual,
+}
+
+impl Relation {
+    /// Returns the relation obtained by swapping its sides, or by multiplying both sides by a negative.
+    ///
+    /// `<` becomes `>`, `≤` becomes `≥`, and vice versa.
+    pub fn flipped(self) -> Self {
+        use Relation::*;
+        match self {
+            Less => Greater,
+            LessOrEqual => GreaterOrEqual,
+            Greater => Less,
+            GreaterOrEqual => LessOrEqual,
+        }
+    }
+
+    /// Returns the relation which holds exactly when this one doesn't.
+    ///
+    /// `<` becomes `≥`, `≤` becomes `>`, and vice versa.
+    pub fn negated(self) -> Self {
+        use Relation::*;
+        match self {
+            Less => GreaterOrEqual,
+            LessOrEqual => Greater,
+            Greater => LessOrEqual,
+            GreaterOrEqual => Less,
+        }
+    }
+
+    /// Returns true if the relation holds between two sides which compare as `ordering`, false otherwise.
+    ///
+    /// ```
+    /// # use algebra::solve::inequality::Relation;
+    /// use std::cmp::Ordering;
+    /// assert!(Relation::LessOrEqual.holds(Ordering::Equal));
+    /// assert!(!Relation::Less.holds(Ordering::Equal));
+    /// ```
+    pub fn holds(self, ordering: std::cmp::Ordering) -> bool {
+        use std::cmp::Ordering;
+        use Relation::*;
+        matches!(
+            (self, ordering),
+            (Less | LessOrEqual, Ordering::Less)
+                | (Greater | GreaterOrEqual, Ordering::Greater)
+                | (LessOrEqual | GreaterOrEqual, Ordering::Equal)
+        )
+    }
+
+    /// Returns true for [`Less`][Relation::Less] and [`Greater`][Relation::Greater], false otherwise.
+    ///
+    /// Strict relations do not hold when both sides are equal.
+    pub fn is_strict(self) -> bool {
+        use Relation::*;
+        matches!(self, Less | Greater)
+    }
+}
+
+impl std::fmt::Display for Relation {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use Relation::*;
+        match self {
+            Less => "<",
+            LessOrEqual => "≤",
+            Greater => ">",
+            GreaterOrEqual => "≥",
+        }
+        .fmt(f)
+    }
+}
+
+/// A comparison between two linear expressions.
+///
+/// ```
+/// # use algebra::solve::{inequality::{Inequality, Relation}, linear::Linear};
+/// // -2x + 1 ≤ -2
+/// let ineq = Inequality {
+///     lhs: Linear::term(-2, "x") + Linear::from(1),
+///     rhs: Linear::from(-2),
+///     relation: Relation::LessOrEqual,
+/// };
+/// assert_eq!(ineq.to_string(), "-2x + 1 ≤ -2");
+///
+/// let solution = ineq.solve_for("x").unwrap();
+/// assert_eq!(solution.to_string(), "[3/2, ∞)");
+/// assert_eq!(solution.in_terms_of("x"), "x ≥ 3/2");
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Inequality {
+    /// The left side of the inequality.
+    pub lhs: Linear,
+
+    /// The right side of the inequality.
+    pub rhs: Linear,
+
+    /// How the left side compares to the right side.
+    pub relation: Relation,
+}
+
+impl Inequality {
+    /// Returns the set of values of `var` for which the inequality holds.
+    ///
+    /// Multiplying or dividing both sides by a negative flips the relation.
+    ///
+    /// Fails if any variable other than `var` appears in the inequality.
+    pub fn solve_for(&self, var: &str) -> Result<SolutionSet, SolveError> {
+        // Move everything to the left: 𝑎𝑥 + 𝑏 ⋚ 0
+        let diff = self.lhs.clone() - self.rhs.clone();
+
+        let others = diff
+            .variables()
+            .filter(|&v| v != var)
+            .map(String::from)
+            .collect::<Vec<_>>();
+        if !others.is_empty() {
+            return Err(SolveError::OtherVariables(others));
+        }
+
+        let coef = diff.coefficient(var);
+        let constant = diff.constant;
+
+        if coef == 0 {
+            // 𝑏 ⋚ 0 holds for every value of the variable, or for none of them
+            use Relation::*;
+            let is_zero = constant == 0;
+            let is_negative = constant.is_negative();
+            let holds = match self.relation {
+                Less => is_negative,
+                LessOrEqual => is_negative || is_zero,
+                Greater => !is_negative && !is_zero,
+                GreaterOrEqual => !is_negative,
+            };
+            return Ok(if holds {
+                SolutionSet::AllReals
+            } else {
+                SolutionSet::Empty
+            });
+        }
+
+        // 𝑥 ⋚ -𝑏/𝑎, flipped when 𝑎 is negative
+        let relation = if coef.is_negative() {
+            self.relation.flipped()
+        } else {
+            self.relation
+        };
+        let value = -constant / coef;
+        let bound = if relation.is_strict() {
+            Bound::Open(value)
+        } else {
+            Bound::Closed(value)
+        };
+
+        use Relation::*;
+        Ok(SolutionSet::Interval(match relation {
+            Less | LessOrEqual => Interval {
+                lower: Bound::Unbounded,
+                upper: bound,
+            },
+            Greater | GreaterOrEqual => Interval {
+                lower: bound,
+                upper: Bound::Unbounded,
+            },
+        }))
+    }
+}
+
+impl std::fmt::Display for Inequality {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let Self { lhs, rhs, relation } = self;
+        format!("{lhs} {relation} {rhs}").fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Relation::*, *};
+    use crate::notation::{expr::fraction::Fraction, Notation};
+
+    fn ineq(lhs: Linear, relation: Relation, rhs: Linear) -> Inequality {
+        Inequality { lhs, rhs, relation }
+    }
+
+    #[test]
+    fn test_positive_coefficient_keeps_relation() {
+        // 2x + 1 < 4
+        let solution = ineq(
+            Linear::term(2, "x") + Linear::from(1),
+            Less,
+            Linear::from(4),
+        )
+        .solve_for("x")
+        .unwrap();
+        assert_eq!(
+            solution,
+            SolutionSet::Interval(Interval {
+                lower: Bound::Unbounded,
+                upper: Bound::Open(Notation::from(Fraction::new(3, 2))),
+            })
+        );
+    }
+
+    #[test]
+    fn test_negative_coefficient_flips_relation() {
+        // -3x ≤ 6
+        let solution = ineq(Linear::term(-3, "x"), LessOrEqual, Linear::from(6))
+            .solve_for("x")
+            .unwrap();
+        assert_eq!(solution.in_terms_of("x"), "x ≥ -2");
+    }
+
+    #[test]
+    fn test_variable_on_both_sides() {
+        // x + 5 > 3x - 1
+        let solution = ineq(
+            Linear::var("x") + Linear::from(5),
+            Greater,
+            Linear::term(3, "x") - Linear::from(1),
+        )
+        .solve_for("x")
+        .unwrap();
+        assert_eq!(solution.in_terms_of("x"), "x < 3");
+    }
+
+    #[test]
+    fn test_cancelled_variable() {
+        // x < x + 1
+        let always = ineq(Linear::var("x"), Less, Linear::var("x") + Linear::from(1));
+        assert_eq!(always.solve_for("x"), Ok(SolutionSet::AllReals));
+
+        // x > x + 1
+        let never = ineq(
+            Linear::var("x"),
+            Greater,
+            Linear::var("x") + Linear::from(1),
+        );
+        assert_eq!(never.solve_for("x"), Ok(SolutionSet::Empty));
+    }
+
+    #[test]
+    fn test_other_variables() {
+        let result = ineq(Linear::var("x"), Less, Linear::var("y")).solve_for("x");
+        assert_eq!(
+            result,
+            Err(SolveError::OtherVariables(vec!["y".to_string()]))
+        );
+    }
+}
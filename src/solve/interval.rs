@@ -0,0 +1,291 @@
+//! Continuous ranges of values.
+
+use crate::notation::Notation;
+
+/// One end of an [`Interval`].
+#[derive(Debug, PartialEq, Clone)]
+pub enum Bound {
+    /// The interval continues forever in this direction.
+    Unbounded,
+
+    /// The interval approaches, but does not include, this value.
+    Open(Notation),
+
+    /// The interval includes this value.
+    Closed(Notation),
+}
+
+impl Bound {
+    /// If the bound is [`Open`][Bound::Open] or [`Closed`][Bound::Closed], returns its value. Otherwise returns [`None`].
+    pub fn value(&self) -> Option<&Notation> {
+        match self {
+            Bound::Open(value) | Bound::Closed(value) => Some(value),
+            Bound::Unbounded => None,
+        }
+    }
+
+    /// Returns true for [`Closed`][Bound::Closed], false otherwise.
+    pub fn is_closed(&self) -> bool {
+        matches!(self, Bound::Closed(_))
+    }
+}
+
+/// A range of real numbers.
+///
+/// ```
+/// # use algebra::{notation::Notation, solve::interval::{Bound, Interval}};
+/// let interval = Interval {
+///     lower: Bound::Closed(Notation::from(1)),
+///     upper: Bound::Unbounded,
+/// };
+/// assert_eq!(interval.to_string(), "[1, ∞)");
+/// assert_eq!(interval.in_terms_of("x"), "x ≥ 1");
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Interval {
+    /// The lesser end of the interval.
+    pub lower: Bound,
+
+    /// The greater end of the interval.
+    pub upper: Bound,
+}
+
+impl Interval {
+    /// Constructs the interval containing every real number.
+    pub fn all() -> Self {
+        Self {
+            lower: Bound::Unbounded,
+            upper: Bound::Unbounded,
+        }
+    }
+
+    /// Returns true if `value` lies within the interval, false otherwise.
+    ///
+    /// Values which can't be compared with the bounds (see [`Notation::compare()`]) are not contained.
+    pub fn contains(&self, value: &Notation) -> bool {
+        use std::cmp::Ordering::*;
+        let above_lower = match &self.lower {
+            Bound::Unbounded => true,
+            Bound::Open(lo) => value.compare(lo) == Some(Greater),
+            Bound::Closed(lo) => matches!(value.compare(lo), Some(Greater | Equal)),
+        };
+        let below_upper = match &self.upper {
+            Bound::Unbounded => true,
+            Bound::Open(hi) => value.compare(hi) == Some(Less),
+            Bound::Closed(hi) => matches!(value.compare(hi), Some(Less | Equal)),
+        };
+        above_lower && below_upper
+    }
+
+    /// Returns true if no value can lie within the interval, false otherwise.
+    ///
+    /// Intervals whose bounds can't be compared are not considered empty.
+    pub fn is_empty(&self) -> bool {
+        use std::cmp::Ordering::*;
+        match (&self.lower, &self.upper) {
+            (Bound::Closed(lo), Bound::Closed(hi)) => lo.compare(hi) == Some(Greater),
+            (Bound::Open(lo) | Bound::Closed(lo), Bound::Open(hi) | Bound::Closed(hi)) => {
+                matches!(lo.compare(hi), Some(Greater | Equal))
+            }
+            _ => false,
+        }
+    }
+
+    /// Returns the interval of values lying within both intervals.
+    ///
+    /// Returns [`None`] if the bounds can't be compared.
+    /// The result may be [empty][Interval::is_empty()].
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        Some(Self {
+            lower: tighter(&self.lower, &other.lower, std::cmp::Ordering::Greater)?,
+            upper: tighter(&self.upper, &other.upper, std::cmp::Ordering::Less)?,
+        })
+    }
+
+    /// Returns the interval of values lying within either interval,
+    /// if they overlap or meet at an included end so that together they are one continuous range.
+    ///
+    /// Returns [`None`] if there is a gap between them, or if the bounds can't be compared.
+    ///
+    /// ```
+    /// # use algebra::{notation::Notation, solve::interval::{Bound, Interval}};
+    /// let below = Interval { lower: Bound::Unbounded, upper: Bound::Closed(Notation::from(2)) };
+    /// let above = Interval { lower: Bound::Open(Notation::from(2)), upper: Bound::Unbounded };
+    /// assert_eq!(below.union(&above), Some(Interval::all()));
+    ///
+    /// let above = Interval { lower: Bound::Open(Notation::from(3)), upper: Bound::Unbounded };
+    /// assert_eq!(below.union(&above), None);
+    /// ```
+    pub fn union(&self, other: &Self) -> Option<Self> {
+        use std::cmp::Ordering::*;
+        // Ranges which touch at a value either of them includes have no gap between them
+        let meets = |upper: &Bound, lower: &Bound| match (upper.value(), lower.value()) {
+            (Some(hi), Some(lo)) => {
+                (upper.is_closed() || lower.is_closed()) && hi.compare(lo) == Some(Equal)
+            }
+            _ => false,
+        };
+        let overlaps = !self.intersection(other)?.is_empty()
+            || meets(&self.upper, &other.lower)
+            || meets(&other.upper, &self.lower);
+        if !overlaps {
+            return None;
+        }
+        Some(Self {
+            lower: looser(&self.lower, &other.lower, Greater)?,
+            upper: looser(&self.upper, &other.upper, Less)?,
+        })
+    }
+
+    /// Describes the interval as an inequality on the variable named `var`.
+    ///
+    /// For example, `[1, 2)` in terms of `x` is `1 ≤ x < 2`.
+    pub fn in_terms_of(&self, var: &str) -> String {
+        use Bound::*;
+        let lower_rel = if self.lower.is_closed() { "≤" } else { "<" };
+        let upper_rel = if self.upper.is_closed() { "≤" } else { "<" };
+        let greater_rel = if self.lower.is_closed() { "≥" } else { ">" };
+        match (&self.lower, &self.upper) {
+            (Unbounded, Unbounded) => format!("{var} ∈ ℝ"),
+            (Unbounded, Open(hi) | Closed(hi)) => format!("{var} {upper_rel} {hi}"),
+            (Open(lo) | Closed(lo), Unbounded) => format!("{var} {greater_rel} {lo}"),
+            (Open(lo) | Closed(lo), Open(hi) | Closed(hi)) => {
+                format!("{lo} {lower_rel} {var} {upper_rel} {hi}")
+            }
+        }
+    }
+}
+
+/// Of two bounds on the same side of an interval, returns the one which excludes more values.
+///
+/// `inward` is the direction the bounds restrict values towards: [`Greater`][std::cmp::Ordering::Greater] for lower bounds.
+fn tighter(a: &Bound, b: &Bound, inward: std::cmp::Ordering) -> Option<Bound> {
+    use std::cmp::Ordering::*;
+    match (a, b) {
+        (Bound::Unbounded, bound) | (bound, Bound::Unbounded) => Some(bound.clone()),
+        _ => {
+            let (value_a, value_b) = (a.value()?, b.value()?);
+            Some(match value_a.compare(value_b)? {
+                Equal if a.is_closed() => b.clone(),
+                Equal => a.clone(),
+                cmp if cmp == inward => a.clone(),
+                _ => b.clone(),
+            })
+        }
+    }
+}
+
+/// Of two bounds on the same side of an interval, returns the one which includes more values.
+///
+/// `inward` is the direction the bounds restrict values towards, the same as for [`tighter`].
+fn looser(a: &Bound, b: &Bound, inward: std::cmp::Ordering) -> Option<Bound> {
+    use std::cmp::Ordering::*;
+    match (a, b) {
+        (Bound::Unbounded, _) | (_, Bound::Unbounded) => Some(Bound::Unbounded),
+        _ => {
+            let (value_a, value_b) = (a.value()?, b.value()?);
+            Some(match value_a.compare(value_b)? {
+                Equal if a.is_closed() => a.clone(),
+                Equal => b.clone(),
+                cmp if cmp == inward => b.clone(),
+                _ => a.clone(),
+            })
+        }
+    }
+}
+
+impl std::fmt::Display for Interval {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use Bound::*;
+        let lower = match &self.lower {
+            Unbounded => "(-∞".to_string(),
+            Open(value) => format!("({value}"),
+            Closed(value) => format!("[{value}"),
+        };
+        let upper = match &self.upper {
+            Unbounded => "∞)".to_string(),
+            Open(value) => format!("{value})"),
+            Closed(value) => format!("{value}]"),
+        };
+        format!("{lower}, {upper}").fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Interval::all().to_string(), "(-∞, ∞)");
+        let interval = Interval {
+            lower: Bound::Open(Notation::from(-2)),
+            upper: Bound::Closed(Notation::from(3)),
+        };
+        assert_eq!(interval.to_string(), "(-2, 3]");
+        assert_eq!(interval.in_terms_of("y"), "-2 < y ≤ 3");
+    }
+
+    #[test]
+    fn test_contains() {
+        let interval = Interval {
+            lower: Bound::Open(Notation::from(-2)),
+            upper: Bound::Closed(Notation::from(3)),
+        };
+        assert!(!interval.contains(&Notation::from(-2)));
+        assert!(interval.contains(&Notation::from(-1)));
+        assert!(interval.contains(&Notation::from(3)));
+        assert!(!interval.contains(&Notation::from(4)));
+        assert!(Interval::all().contains(&Notation::from(100)));
+    }
+
+    #[test]
+    fn test_intersection() {
+        let a = Interval {
+            lower: Bound::Closed(Notation::from(0)),
+            upper: Bound::Unbounded,
+        };
+        let b = Interval {
+            lower: Bound::Open(Notation::from(0)),
+            upper: Bound::Closed(Notation::from(5)),
+        };
+        assert_eq!(a.intersection(&b), Some(b.clone()));
+
+        let c = Interval {
+            lower: Bound::Unbounded,
+            upper: Bound::Open(Notation::from(0)),
+        };
+        assert!(a.intersection(&c).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_union() {
+        let a = Interval {
+            lower: Bound::Closed(Notation::from(0)),
+            upper: Bound::Open(Notation::from(5)),
+        };
+        let b = Interval {
+            lower: Bound::Open(Notation::from(3)),
+            upper: Bound::Closed(Notation::from(8)),
+        };
+        let hull = Interval {
+            lower: Bound::Closed(Notation::from(0)),
+            upper: Bound::Closed(Notation::from(8)),
+        };
+        assert_eq!(a.union(&b), Some(hull.clone()));
+        assert_eq!(b.union(&a), Some(hull));
+
+        // (0, 5) and (5, 8] leave out 5
+        let c = Interval {
+            lower: Bound::Closed(Notation::from(5)),
+            upper: Bound::Closed(Notation::from(8)),
+        };
+        assert!(a.union(&c).is_some());
+        let d = Interval {
+            lower: Bound::Open(Notation::from(5)),
+            ..c
+        };
+        assert_eq!(a.union(&d), None);
+        assert_eq!(a.union(&Interval::all()), Some(Interval::all()));
+    }
+}
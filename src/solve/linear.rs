@@ -0,0 +1,335 @@
+//! Linear expressions over any number of variables.
+
+use std::collections::BTreeMap;
+
+use super::{
+    env::{Env, EvalError},
+    var::Var,
+};
+use crate::notation::{
+    expr::{fraction::Fraction, simplify::Simplify},
+    Notation,
+};
+
+/// A linear expression.
+///
+/// A sum of variables each multiplied by a coefficient, plus a constant: 𝑎𝑥 + 𝑏𝑦 + … + 𝑐.
+///
+/// ```
+/// # use algebra::solve::linear::Linear;
+/// let expr = Linear::term(2, "x") + Linear::from(1);
+/// assert_eq!(expr.to_string(), "2x + 1");
+///
+/// let expr = Linear::var("x") - Linear::var("y") - Linear::from(3);
+/// assert_eq!(expr.to_string(), "x - y - 3");
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub struct Linear {
+    /// The coefficient of each variable, keyed by the variable's name.
+    ///
+    /// Variables with a coefficient of 0 are not stored.
+    pub terms: BTreeMap<Var, Notation>,
+
+    /// The constant term.
+    ///
+    /// The part of the expression not multiplied by any variable.
+    pub constant: Notation,
+}
+
+impl From<i32> for Linear {
+    /// Construct a constant expression containing no variables.
+    fn from(value: i32) -> Self {
+        Self::from(Notation::from(value))
+    }
+}
+
+impl From<Notation> for Linear {
+    /// Construct a constant expression containing no variables.
+    fn from(constant: Notation) -> Self {
+        Self {
+            terms: BTreeMap::new(),
+            constant,
+        }
+    }
+}
+
+impl Linear {
+    /// Constructs the expression `1𝑥`, where 𝑥 is the variable named `var`.
+    pub fn var(var: &str) -> Self {
+        Self::term(1, var)
+    }
+
+    /// Constructs the expression `coef𝑥`, where 𝑥 is the variable named `var`.
+    pub fn term(coef: impl Into<Notation>, var: &str) -> Self {
+        let mut terms = BTreeMap::new();
+        let coef = coef.into();
+        if coef != 0 {
+            terms.insert(Var::new(var), coef);
+        }
+        Self {
+            terms,
+            constant: Notation::from(0),
+        }
+    }
+
+    /// Reads notation as a linear expression, if it is one:
+    /// a sum of rational multiples of variables and notation without any variables.
+    ///
+    /// Returns [`None`] if a variable is multiplied by anything other than a ratio,
+    /// or appears inside any other notation, such as a root.
+    ///
+    /// ```
+    /// # use algebra::{notation::{Notation, atom::Atom, expr::{multiple::Multiple, nested_radical::NestedRadical, sum::Sum}}, solve::{linear::Linear, var::Var}};
+    /// let x = Atom::from(Var::new("x"));
+    /// let value = Notation::from(Sum::new([Multiple::new(3, 1, x.clone()).into(), (-Notation::from(Atom::Pi)).into()]));
+    /// assert_eq!(Linear::from_notation(&value).unwrap().to_string(), "3x - π");
+    /// assert_eq!(Linear::from_notation(&NestedRadical::new(x).into()), None);
+    /// ```
+    pub fn from_notation(value: &Notation) -> Option<Self> {
+        use crate::notation::{atom::Atom, expr::Expr};
+        if value.variables().is_empty() {
+            return Some(Self::from(value.clone()));
+        }
+        match value {
+            Notation::Atom(Atom::Variable(var)) => Some(Self::var(var)),
+            Notation::Expr(Expr::Fraction(fraction)) => match (&fraction.num, &fraction.den) {
+                (Atom::Variable(var), Atom::Number(den)) if den.value != 0 => {
+                    Some(Self::term(Fraction::new(1, den.value).simplify(), var))
+                }
+                _ => None,
+            },
+            Notation::Expr(Expr::Multiple(multiple)) => match &multiple.constant {
+                Atom::Variable(var) => Some(Self::term(multiple.coef.clone().simplify(), var)),
+                _ => None,
+            },
+            Notation::Expr(Expr::Sum(sum)) => {
+                sum.terms.iter().try_fold(Self::from(0), |sum, term| {
+                    Some(sum + Self::from_notation(term)?)
+                })
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the coefficient of the variable named `var`.
+    ///
+    /// Variables which don't appear in the expression have a coefficient of 0.
+    pub fn coefficient(&self, var: &str) -> Notation {
+        self.terms
+            .get(&Var::new(var))
+            .cloned()
+            .unwrap_or_else(|| Notation::from(0))
+    }
+
+    /// Returns the names of all variables in the expression, in alphabetical order.
+    pub fn variables(&self) -> impl Iterator<Item = &str> {
+        self.terms.keys().map(|var| var.as_str())
+    }
+
+    /// Returns true if the expression contains no variables, false otherwise.
+    pub fn is_constant(&self) -> bool {
+        self.terms.is_empty()
+    }
+
+    /// Substitutes the values of the variables set in `env`, leaving the others as they are.
+    pub fn substitute(&self, env: &Env) -> Self {
+        let mut result = Self::from(self.constant.clone());
+        for (var, coef) in &self.terms {
+            result = result
+                + match env.get(var) {
+                    Some(value) => Self::from(coef.clone() * value.clone()),
+                    None => Self::term(coef.clone(), var),
+                };
+        }
+        result
+    }
+
+    /// Substitutes the value of every variable from `env`, returning the value of the expression.
+    ///
+    /// Fails with [`EvalError::Unbound`] if any variable in the expression has no value.
+    ///
+    /// ```
+    /// # use algebra::solve::{env::{Env, EvalError}, linear::Linear};
+    /// let expr = Linear::term(2, "x") - Linear::var("y");
+    /// assert_eq!(expr.eval_with(&Env::new().with("x", 3).with("y", 1)), Ok(5.into()));
+    /// assert_eq!(expr.eval_with(&Env::new().with("x", 3)), Err(EvalError::Unbound(vec!["y".to_string()])));
+    /// ```
+    pub fn eval_with(&self, env: &Env) -> Result<Notation, EvalError> {
+        env.check_bound(self.variables())?;
+        Ok(self.substitute(env).constant)
+    }
+}
+
+impl std::ops::Add for Linear {
+    type Output = Self;
+
+    /// Add two expressions, combining the coefficients of like variables.
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut terms = self.terms;
+        for (var, coef) in rhs.terms {
+            let sum = match terms.remove(&var) {
+                Some(existing) => existing + coef,
+                None => coef,
+            };
+            if sum != 0 {
+                terms.insert(var, sum);
+            }
+        }
+        Self {
+            terms,
+            constant: self.constant + rhs.constant,
+        }
+    }
+}
+
+impl std::ops::Sub for Linear {
+    type Output = Self;
+
+    /// Subtract two expressions, combining the coefficients of like variables.
+    fn sub(self, rhs: Self) -> Self::Output {
+        let mut terms = self.terms;
+        for (var, coef) in rhs.terms {
+            let diff = match terms.remove(&var) {
+                Some(existing) => existing - coef,
+                None => -coef,
+            };
+            if diff != 0 {
+                terms.insert(var, diff);
+            }
+        }
+        Self {
+            terms,
+            constant: self.constant - rhs.constant,
+        }
+    }
+}
+
+impl std::ops::Neg for Linear {
+    type Output = Self;
+
+    /// Negate every term of the expression, so that the variable `x` becomes `-x`.
+    ///
+    /// ```
+    /// # use algebra::solve::linear::Linear;
+    /// assert_eq!((-Linear::var("x")).to_string(), "-x");
+    /// assert_eq!((-(Linear::var("x") - Linear::from(2))).to_string(), "-x + 2");
+    /// ```
+    fn neg(self) -> Self::Output {
+        Self {
+            terms: self
+                .terms
+                .into_iter()
+                .map(|(var, coef)| (var, -coef))
+                .collect(),
+            constant: -self.constant,
+        }
+    }
+}
+
+impl std::ops::Mul<Notation> for Linear {
+    type Output = Self;
+
+    /// Multiply every term of the expression by a constant.
+    fn mul(self, rhs: Notation) -> Self::Output {
+        if rhs == 0 {
+            return Self::from(0);
+        }
+        Self {
+            terms: self
+                .terms
+                .into_iter()
+                .map(|(var, coef)| (var, coef * rhs.clone()))
+                .collect(),
+            constant: self.constant * rhs,
+        }
+    }
+}
+
+impl std::fmt::Display for Linear {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let mut parts = self
+            .terms
+            .iter()
+            .map(|(var, coef)| (coef.clone(), var.as_str()))
+            .collect::<Vec<_>>();
+        if self.constant != 0 || parts.is_empty() {
+            parts.push((self.constant.clone(), ""));
+        }
+
+        let mut result = String::new();
+        for (i, (coef, var)) in parts.into_iter().enumerate() {
+            let is_negative = coef.is_negative();
+            let magnitude = if is_negative { -coef } else { coef };
+
+            result += match (i, is_negative) {
+                (0, false) => "",
+                (0, true) => "-",
+                (_, false) => " + ",
+                (_, true) => " - ",
+            };
+
+            if var.is_empty() || magnitude != 1 {
+                result += &magnitude.to_string();
+            }
+            result += var;
+        }
+        result.fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_add_combines_like_terms() {
+        let sum = Linear::term(2, "x") + Linear::term(3, "x") + Linear::var("y");
+        assert_eq!(sum.coefficient("x"), 5);
+        assert_eq!(sum.coefficient("y"), 1);
+        assert_eq!(sum.coefficient("z"), 0);
+    }
+
+    #[test]
+    fn test_sub_removes_cancelled_terms() {
+        let diff = Linear::term(2, "x") + Linear::from(4) - Linear::term(2, "x");
+        assert!(diff.is_constant());
+        assert_eq!(diff.constant, 4);
+    }
+
+    #[test]
+    fn test_substitute() {
+        let expr = Linear::term(2, "x") + Linear::term(3, "y") + Linear::from(1);
+        let env = Env::new().with("x", -1).with("z", 5);
+        assert_eq!(
+            expr.substitute(&env),
+            Linear::term(3, "y") - Linear::from(1)
+        );
+        assert_eq!(expr.substitute(&Env::new()), expr);
+        assert_eq!(
+            expr.eval_with(&env.with("y", 0))
+                .map(|value| value.to_string()),
+            Ok("-1".to_string())
+        );
+    }
+
+    #[test]
+    fn test_neg() {
+        let x = Linear::var("x");
+        assert_eq!((-x.clone()).coefficient("x"), -1);
+        assert_eq!(-(-x.clone()), x);
+        assert_eq!(-x.clone() + x, Linear::from(0));
+        assert_eq!(-Linear::from(0), Linear::from(0));
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Linear::from(0).to_string(), "0");
+        assert_eq!(Linear::from(-5).to_string(), "-5");
+        assert_eq!(Linear::var("x").to_string(), "x");
+        assert_eq!(Linear::term(-1, "x").to_string(), "-x");
+        assert_eq!(
+            (Linear::term(-3, "x") + Linear::term(2, "y") - Linear::from(1)).to_string(),
+            "-3x + 2y - 1"
+        );
+    }
+}
@@ -0,0 +1,295 @@
+//! Sums of terms over any number of variables.
+
+use std::collections::BTreeMap;
+
+use crate::{
+    notation::{
+        format::{FormatOptions, FormatWith},
+        Notation,
+    },
+    solve::{
+        env::{Env, EvalError},
+        term::Term,
+        var::Var,
+    },
+};
+
+/// A sum of [`Term`]s with integer coefficients, such as `x² + 2xy - 3`.
+///
+/// Like terms are always combined, terms with a coefficient of 0 are dropped,
+/// and the terms are kept from greatest to least by [`Term::cmp_vars`].
+///
+/// ```
+/// # use algebra::solve::{polynomial::Polynomial, term::Term};
+/// let (x, y) = (Polynomial::from(Term::var("x")), Polynomial::from(Term::var("y")));
+/// let square = (x.clone() + y.clone()) * (x - y.clone());
+/// assert_eq!(square.to_string(), "x² - y²");
+/// assert_eq!(square.degree_in("y"), 2);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Default)]
+pub struct Polynomial {
+    terms: Vec<Term>,
+}
+
+impl From<Term> for Polynomial {
+    fn from(term: Term) -> Self {
+        Self::from_terms([term])
+    }
+}
+
+impl From<i32> for Polynomial {
+    /// Construct a constant polynomial containing no variables.
+    fn from(value: i32) -> Self {
+        Self::from(Term::from(value))
+    }
+}
+
+impl Polynomial {
+    /// Constructs the sum of the terms, combining like terms.
+    pub fn from_terms(terms: impl IntoIterator<Item = Term>) -> Self {
+        let mut terms = terms.into_iter().collect::<Vec<_>>();
+        terms.sort_by(|a, b| b.cmp_vars(a));
+        let mut combined: Vec<Term> = Vec::with_capacity(terms.len());
+        for term in terms {
+            match combined.last_mut() {
+                Some(last) if last.vars == term.vars => last.coef += term.coef,
+                _ => combined.push(term),
+            }
+        }
+        combined.retain(|term| term.coef != 0);
+        Self { terms: combined }
+    }
+
+    /// Returns the terms, from greatest to least.
+    pub fn terms(&self) -> &[Term] {
+        &self.terms
+    }
+
+    /// Returns true if the polynomial contains no variables, false otherwise.
+    pub fn is_constant(&self) -> bool {
+        self.terms.iter().all(Term::is_constant)
+    }
+
+    /// Returns the highest [degree][Term::degree] of any of its terms. A constant has a degree of 0.
+    pub fn degree(&self) -> u32 {
+        self.terms.iter().map(Term::degree).max().unwrap_or(0)
+    }
+
+    /// Returns the highest power the variable named `var` is raised to in any of its terms.
+    pub fn degree_in(&self, var: &str) -> u32 {
+        self.terms
+            .iter()
+            .map(|term| term.degree_in(var))
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// Returns the names of all variables in the polynomial, in alphabetical order.
+    pub fn variables(&self) -> Vec<&str> {
+        let mut vars = self
+            .terms
+            .iter()
+            .flat_map(Term::variables)
+            .collect::<Vec<_>>();
+        vars.sort_unstable();
+        vars.dedup();
+        vars
+    }
+
+    /// Substitutes values for some of the polynomial's variables, leaving the others as they are.
+    ///
+    /// Returns [`None`] if a coefficient overflows.
+    ///
+    /// ```
+    /// # use algebra::solve::{polynomial::Polynomial, term::Term, var::Var};
+    /// # use std::collections::BTreeMap;
+    /// let value = Polynomial::from_terms([Term::new(1, "x", 2) * Term::var("y"), Term::var("x")]);
+    /// let x = BTreeMap::from([(Var::new("x"), 3)]);
+    /// assert_eq!(value.eval(&x).unwrap().to_string(), "9y + 3");
+    /// ```
+    pub fn eval(&self, values: &BTreeMap<Var, i32>) -> Option<Self> {
+        let terms = self
+            .terms
+            .iter()
+            .map(|term| term.eval(values))
+            .collect::<Option<Vec<_>>>()?;
+        Some(Self::from_terms(terms))
+    }
+
+    /// Substitutes the value of every variable from `env`, returning the value of the polynomial.
+    ///
+    /// Unlike [`eval`][Polynomial::eval], values may be any notation, such as fractions.
+    /// Fails with [`EvalError::Unbound`] if any variable in the polynomial has no value.
+    ///
+    /// ```
+    /// # use algebra::{notation::expr::fraction::Fraction, solve::{env::Env, polynomial::Polynomial, term::Term}};
+    /// let value = Polynomial::from_terms([Term::new(4, "x", 2), Term::from(1)]);
+    /// let env = Env::new().with("x", Fraction::new(1, 2));
+    /// assert_eq!(value.eval_with(&env), Ok(2.into()));
+    /// ```
+    pub fn eval_with(&self, env: &Env) -> Result<Notation, EvalError> {
+        env.check_bound(self.variables())?;
+        let mut sum = Notation::from(0);
+        for term in &self.terms {
+            let mut product = Notation::from(term.coef);
+            for (var, &exp) in &term.vars {
+                // Every variable was checked to be bound
+                let value = env.get(var).unwrap().clone();
+                product = product * value.pow(Notation::from(exp as i32));
+            }
+            sum = sum + product;
+        }
+        Ok(sum)
+    }
+}
+
+impl std::ops::Add for Polynomial {
+    type Output = Self;
+
+    /// Add two polynomials, combining like terms.
+    fn add(self, rhs: Self) -> Self::Output {
+        Self::from_terms(self.terms.into_iter().chain(rhs.terms))
+    }
+}
+
+impl std::ops::Neg for Polynomial {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            terms: self.terms.into_iter().map(|term| -term).collect(),
+        }
+    }
+}
+
+impl std::ops::Sub for Polynomial {
+    type Output = Self;
+
+    /// Subtract two polynomials, combining like terms.
+    fn sub(self, rhs: Self) -> Self::Output {
+        self + -rhs
+    }
+}
+
+impl std::ops::Mul for Polynomial {
+    type Output = Self;
+
+    /// Multiply two polynomials, multiplying every term of one by every term of the other.
+    fn mul(self, rhs: Self) -> Self::Output {
+        Self::from_terms(
+            self.terms
+                .iter()
+                .flat_map(|a| rhs.terms.iter().map(|b| a.clone() * b.clone())),
+        )
+    }
+}
+
+impl FormatWith for Polynomial {
+    fn format_with(&self, options: &FormatOptions) -> String {
+        if self.terms.is_empty() {
+            return "0".to_string();
+        }
+        let mut result = String::new();
+        for (i, term) in self.terms.iter().enumerate() {
+            let is_negative = term.coef < 0;
+            result += match (i, is_negative) {
+                (0, false) => "",
+                (0, true) => "-",
+                (_, false) => " + ",
+                (_, true) => " - ",
+            };
+            let magnitude = if is_negative {
+                -term.clone()
+            } else {
+                term.clone()
+            };
+            result += &magnitude.format_with(options);
+        }
+        result
+    }
+}
+
+impl std::fmt::Display for Polynomial {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.format_with(&FormatOptions::default()).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn x() -> Polynomial {
+        Polynomial::from(Term::var("x"))
+    }
+
+    fn y() -> Polynomial {
+        Polynomial::from(Term::var("y"))
+    }
+
+    #[test]
+    fn test_combines_like_terms() {
+        let sum = x() + y() + x() - Polynomial::from(2) + Polynomial::from(2);
+        assert_eq!(sum.terms(), [Term::new(2, "x", 1), Term::var("y")]);
+        assert_eq!(x() - x(), Polynomial::default());
+        assert!((x() - x()).is_constant());
+    }
+
+    #[test]
+    fn test_mul() {
+        let square = (x() + y()) * (x() + y());
+        assert_eq!(square.to_string(), "x² + 2xy + y²");
+        assert_eq!(square.degree(), 2);
+        assert_eq!(square.variables(), ["x", "y"]);
+        let cube = square * (x() + Polynomial::from(1));
+        assert_eq!(cube.to_string(), "x³ + 2x²y + x² + xy² + 2xy + y²");
+        assert_eq!(cube.degree(), 3);
+        assert_eq!(cube.degree_in("y"), 2);
+        assert_eq!(cube.degree_in("z"), 0);
+    }
+
+    #[test]
+    fn test_eval() {
+        let value = x() * y() - y() + Polynomial::from(1);
+        let values = |pairs: &[(&str, i32)]| {
+            pairs
+                .iter()
+                .map(|&(var, value)| (Var::new(var), value))
+                .collect::<BTreeMap<_, _>>()
+        };
+        assert_eq!(value.eval(&values(&[("x", 1)])), Some(Polynomial::from(1)));
+        assert_eq!(
+            value.eval(&values(&[("y", 2)])).unwrap().to_string(),
+            "2x - 1"
+        );
+        assert_eq!(
+            value.eval(&values(&[("x", 3), ("y", 2)])),
+            Some(Polynomial::from(5))
+        );
+    }
+
+    #[test]
+    fn test_eval_with() {
+        let value = x() * x() * y() - Polynomial::from(3);
+        let env = Env::new().with("x", -2).with("y", 5);
+        assert_eq!(value.eval_with(&env), Ok(Notation::from(17)));
+        assert_eq!(
+            value.eval_with(&Env::new().with("y", 1)),
+            Err(EvalError::Unbound(vec!["x".to_string()]))
+        );
+        assert_eq!(
+            Polynomial::from(7).eval_with(&Env::new()),
+            Ok(Notation::from(7))
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Polynomial::default().to_string(), "0");
+        assert_eq!((-x() - Polynomial::from(1)).to_string(), "-x - 1");
+        assert_eq!(
+            (y() * y() * Polynomial::from(-3) + x()).to_string(),
+            "x - 3y²"
+        );
+    }
+}
@@ -0,0 +1,297 @@
+//! Proportions and percentages.
+
+use crate::notation::{
+    expr::fraction::Fraction,
+    ops::{gcd, ratio, rational},
+    Notation,
+};
+
+/// Reasons a proportion could not be solved.
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub enum ProportionError {
+    /// The proportion doesn't have exactly one unknown.
+    ///
+    /// Contains the number of unknowns.
+    Unknowns(usize),
+
+    /// A known term isn't an integer or fraction.
+    NotRational(String),
+
+    /// One of the known denominators is 0.
+    ZeroDenominator,
+
+    /// No value of the unknown makes the ratios equal.
+    NoSolution,
+
+    /// Every value of the unknown makes the ratios equal, such as in `0/2 = 0/𝑥`.
+    Indeterminate,
+
+    /// The unknown is too large or too small to represent exactly.
+    TooLarge,
+}
+
+impl std::fmt::Display for ProportionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use ProportionError::*;
+        match self {
+            Unknowns(count) => format!("expected 1 unknown, found {count}").fmt(f),
+            NotRational(term) => format!("{term} is not an integer or fraction").fmt(f),
+            ZeroDenominator => "a ratio has a denominator of 0".fmt(f),
+            NoSolution => "the ratios are never equal".fmt(f),
+            Indeterminate => "the ratios are equal for any value".fmt(f),
+            TooLarge => "the solution is too large to represent exactly".fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for ProportionError {}
+
+/// Solves the proportion `a/b = c/d` for whichever of `[a, b, c, d]` is [`None`].
+///
+/// Cross-multiplies to `ad = bc`, then divides by the term opposite the unknown.
+/// The result is exact, since every known term must be an integer or fraction.
+///
+/// ```
+/// # use algebra::{notation::{Notation, expr::fraction::Fraction}, solve::proportion::{proportion, ProportionError}};
+/// // 3/4 = 𝑥/10
+/// let x = proportion([Some(3.into()), Some(4.into()), None, Some(10.into())]);
+/// assert_eq!(x, Ok(Notation::from(Fraction::new(15, 2))));
+///
+/// // 0/5 = 2/𝑥
+/// let x = proportion([Some(0.into()), Some(5.into()), Some(2.into()), None]);
+/// assert_eq!(x, Err(ProportionError::NoSolution));
+/// ```
+pub fn proportion(terms: [Option<Notation>; 4]) -> Result<Notation, ProportionError> {
+    let unknowns = terms.iter().filter(|term| term.is_none()).count();
+    let (Some(unknown), 1) = (terms.iter().position(Option::is_none), unknowns) else {
+        return Err(ProportionError::Unknowns(unknowns));
+    };
+    let known = |index: usize| {
+        let term = terms[index].as_ref().expect("only one term is unknown");
+        ratio(term)
+            .map(|(num, den)| (num as i128, den as i128))
+            .ok_or_else(|| ProportionError::NotRational(term.to_string()))
+    };
+    // `a` and `d` are opposite each other, as are `b` and `c`
+    let opposite = 3 - unknown;
+    let others = match unknown {
+        0 | 3 => [1, 2],
+        _ => [0, 3],
+    };
+    let is_zero = |index| known(index).is_ok_and(|(num, _)| num == 0);
+    if [1, 3].into_iter().any(|den| den != unknown && is_zero(den)) {
+        return Err(ProportionError::ZeroDenominator);
+    }
+
+    let ((p, q), (r, s), (t, u)) = (known(others[0])?, known(others[1])?, known(opposite)?);
+    // 𝑥 = (p/q)(r/s) / (t/u)
+    let (num, den) = (p * r * u, q * s * t);
+    let is_den = unknown % 2 == 1;
+    match (num, den) {
+        (0, 0) => Err(ProportionError::Indeterminate),
+        (_, 0) => Err(ProportionError::NoSolution),
+        // A denominator of 0 would leave its ratio undefined
+        (0, _) if is_den => Err(ProportionError::NoSolution),
+        _ => {
+            let value = rational(num, den);
+            ratio(&value)
+                .map(|_| value)
+                .ok_or(ProportionError::TooLarge)
+        }
+    }
+}
+
+/// Returns the reduced fraction `num/den` with a positive denominator,
+/// or [`None`] if `den` is 0 or the result doesn't fit in an [`i32`].
+fn reduced(num: i128, den: i128) -> Option<Fraction> {
+    if den == 0 {
+        return None;
+    }
+    let divisor = gcd(num, den) * den.signum();
+    Some(Fraction::new(
+        i32::try_from(num / divisor).ok()?,
+        i32::try_from(den / divisor).ok()?,
+    ))
+}
+
+/// Returns the numerator and denominator of a fraction of [`Number`][crate::notation::atom::Atom::Number]s.
+fn parts(value: &Fraction) -> Option<(i128, i128)> {
+    ratio(&Notation::from(value.clone())).map(|(num, den)| (num as i128, den as i128))
+}
+
+/// Returns `percent`% of `whole`, as a reduced fraction.
+///
+/// Returns [`None`] if either isn't a fraction of numbers, or if the result doesn't fit in an [`i32`].
+///
+/// ```
+/// # use algebra::{notation::expr::fraction::Fraction, solve::proportion::percent_of};
+/// assert_eq!(percent_of(Fraction::from(15), Fraction::from(80)), Some(Fraction::from(12)));
+/// assert_eq!(percent_of(Fraction::new(25, 2), Fraction::from(3)), Some(Fraction::new(3, 8)));
+/// ```
+pub fn percent_of(percent: Fraction, whole: Fraction) -> Option<Fraction> {
+    let ((a, b), (c, d)) = (parts(&percent)?, parts(&whole)?);
+    reduced(a * c, b * d * 100)
+}
+
+/// Returns what percent `part` is of `whole`, as a reduced fraction.
+///
+/// Returns [`None`] if either isn't a fraction of numbers, if `whole` is 0, or if the result doesn't fit in an [`i32`].
+///
+/// ```
+/// # use algebra::{notation::expr::fraction::Fraction, solve::proportion::percent};
+/// assert_eq!(percent(Fraction::from(3), Fraction::from(12)), Some(Fraction::from(25)));
+/// assert_eq!(percent(Fraction::from(1), Fraction::from(3)), Some(Fraction::new(100, 3)));
+/// ```
+pub fn percent(part: Fraction, whole: Fraction) -> Option<Fraction> {
+    let ((a, b), (c, d)) = (parts(&part)?, parts(&whole)?);
+    reduced(a * d * 100, b * c)
+}
+
+/// Returns the percent change from `old` to `new`, as a reduced fraction which is negative for a decrease.
+///
+/// The change is relative to the magnitude of `old`, so going from -4 to -2 is an increase of 50%.
+///
+/// Returns [`None`] if either isn't a fraction of numbers, if `old` is 0, or if the result doesn't fit in an [`i32`].
+///
+/// ```
+/// # use algebra::{notation::expr::fraction::Fraction, solve::proportion::percent_change};
+/// assert_eq!(percent_change(Fraction::from(40), Fraction::from(50)), Some(Fraction::from(25)));
+/// assert_eq!(percent_change(Fraction::from(3), Fraction::from(2)), Some(Fraction::new(-100, 3)));
+/// ```
+pub fn percent_change(old: Fraction, new: Fraction) -> Option<Fraction> {
+    let ((a, b), (c, d)) = (parts(&old)?, parts(&new)?);
+    // (c/d - a/b) / |a/b| = (bc - ad) / d|a|, with the sign of b moved onto the numerator
+    reduced((b * c - a * d) * 100 * b.signum(), d * a.abs())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::expr::radical::Radical;
+
+    fn terms(terms: [Option<i32>; 4]) -> [Option<Notation>; 4] {
+        terms.map(|term| term.map(Notation::from))
+    }
+
+    #[test]
+    fn test_proportion() {
+        // 2/3 = 4/x, 2/3 = x/6, 2/x = 4/6, x/3 = 4/6
+        assert_eq!(
+            proportion(terms([Some(2), Some(3), Some(4), None])),
+            Ok(6.into())
+        );
+        assert_eq!(
+            proportion(terms([Some(2), Some(3), None, Some(6)])),
+            Ok(4.into())
+        );
+        assert_eq!(
+            proportion(terms([Some(2), None, Some(4), Some(6)])),
+            Ok(3.into())
+        );
+        assert_eq!(
+            proportion(terms([None, Some(3), Some(4), Some(6)])),
+            Ok(2.into())
+        );
+        assert_eq!(
+            proportion([
+                Some(Fraction::new(1, 2).into()),
+                Some(3.into()),
+                None,
+                Some(Fraction::new(-2, 3).into()),
+            ]),
+            Ok(Notation::from(Fraction::new(-1, 9)))
+        );
+    }
+
+    #[test]
+    fn test_proportion_errors() {
+        use ProportionError::*;
+        assert_eq!(
+            proportion(terms([Some(1), Some(2), Some(3), Some(4)])),
+            Err(Unknowns(0))
+        );
+        assert_eq!(
+            proportion(terms([None, Some(2), Some(3), None])),
+            Err(Unknowns(2))
+        );
+        assert_eq!(
+            proportion(terms([Some(1), Some(0), Some(3), None])),
+            Err(ZeroDenominator)
+        );
+        assert_eq!(
+            proportion(terms([Some(1), None, Some(3), Some(0)])),
+            Err(ZeroDenominator)
+        );
+        // 0/2 = 0/x holds for any x but 0
+        assert_eq!(
+            proportion(terms([Some(0), Some(2), Some(0), None])),
+            Err(Indeterminate)
+        );
+        // 1/2 = 0/x, 1/x = 0/2, and 0/2 = 1/x have no solution
+        assert_eq!(
+            proportion(terms([Some(1), Some(2), Some(0), None])),
+            Err(NoSolution)
+        );
+        assert_eq!(
+            proportion(terms([Some(1), None, Some(0), Some(2)])),
+            Err(NoSolution)
+        );
+        assert_eq!(
+            proportion(terms([Some(0), Some(2), Some(1), None])),
+            Err(NoSolution)
+        );
+        assert_eq!(
+            proportion([
+                Some(Radical::new(2).into()),
+                Some(1.into()),
+                None,
+                Some(1.into())
+            ]),
+            Err(NotRational("√2".to_string()))
+        );
+        assert_eq!(
+            proportion(terms([Some(1), Some(i32::MAX), Some(i32::MAX), None])),
+            Err(TooLarge)
+        );
+    }
+
+    #[test]
+    fn test_percent() {
+        assert_eq!(
+            percent_of(Fraction::from(0), Fraction::from(7)),
+            Some(Fraction::from(0))
+        );
+        assert_eq!(
+            percent_of(Fraction::from(150), Fraction::new(-1, 3)),
+            Some(Fraction::new(-1, 2))
+        );
+        assert_eq!(percent_of(Fraction::new(1, 0), Fraction::from(1)), None);
+        assert_eq!(percent(Fraction::from(5), Fraction::from(0)), None);
+        assert_eq!(
+            percent(Fraction::from(-1), Fraction::new(4, -1)),
+            Some(Fraction::from(25))
+        );
+        assert_eq!(percent(Fraction::from(i32::MAX), Fraction::from(1)), None);
+    }
+
+    #[test]
+    fn test_percent_change() {
+        assert_eq!(
+            percent_change(Fraction::from(4), Fraction::from(4)),
+            Some(Fraction::from(0))
+        );
+        assert_eq!(
+            percent_change(Fraction::from(-4), Fraction::from(-2)),
+            Some(Fraction::from(50))
+        );
+        assert_eq!(
+            percent_change(Fraction::from(-4), Fraction::from(-6)),
+            Some(Fraction::from(-50))
+        );
+        assert_eq!(
+            percent_change(Fraction::new(1, -2), Fraction::from(0)),
+            Some(Fraction::from(100))
+        );
+        assert_eq!(percent_change(Fraction::from(0), Fraction::from(1)), None);
+    }
+}
@@ -0,0 +1,245 @@
+//! Sets of values satisfying an equation or inequality.
+
+use super::interval::Interval;
+use crate::notation::Notation;
+
+/// The set of values which satisfy an equation or inequality.
+///
+/// Returned by solvers such as [`Inequality::solve_for()`][super::inequality::Inequality::solve_for()].
+///
+/// [`Finite`][SolutionSet::Finite] sets are not limited to real numbers, and may contain [`Complex`][crate::notation::atom::Atom::Complex] values.
+/// Whether a solver includes them depends on whether it is solving over the reals or the complex numbers.
+///
+/// ```
+/// # use algebra::{notation::Notation, solve::{interval::{Bound, Interval}, set::SolutionSet}};
+/// let nonnegative = SolutionSet::Interval(Interval {
+///     lower: Bound::Closed(Notation::from(0)),
+///     upper: Bound::Unbounded,
+/// });
+/// let some = SolutionSet::Finite(vec![Notation::from(-1), Notation::from(2)]);
+/// assert!(nonnegative.contains(&Notation::from(5)));
+/// assert_eq!(nonnegative.intersection(&some).to_string(), "{2}");
+/// ```
+#[derive(Debug, PartialEq, Clone)]
+pub enum SolutionSet {
+    /// No value is a solution.
+    Empty,
+
+    /// Only the listed values are solutions.
+    Finite(Vec<Notation>),
+
+    /// Every value within a continuous range is a solution.
+    ///
+    /// See [`Interval`].
+    Interval(Interval),
+
+    /// Every value in any of the contained sets is a solution.
+    Union(Vec<SolutionSet>),
+
+    /// Every real number is a solution.
+    AllReals,
+}
+
+impl SolutionSet {
+    /// Returns true if `value` is in the set, false otherwise.
+    pub fn contains(&self, value: &Notation) -> bool {
+        use SolutionSet::*;
+        match self {
+            Empty => false,
+            Finite(values) => values
+                .iter()
+                .any(|x| x.compare(value) == Some(std::cmp::Ordering::Equal)),
+            Interval(interval) => interval.contains(value),
+            Union(sets) => sets.iter().any(|set| set.contains(value)),
+            AllReals => value.compare(&Notation::from(0)).is_some(),
+        }
+    }
+
+    /// Returns true if the set contains no values, false otherwise.
+    pub fn is_empty(&self) -> bool {
+        use SolutionSet::*;
+        match self {
+            Empty => true,
+            Finite(values) => values.is_empty(),
+            Interval(interval) => interval.is_empty(),
+            Union(sets) => sets.iter().all(SolutionSet::is_empty),
+            AllReals => false,
+        }
+    }
+
+    /// Returns the set of values in either set.
+    pub fn union(self, other: Self) -> Self {
+        use SolutionSet::*;
+        match (self, other) {
+            (set, other) if other.is_empty() => set,
+            (set, other) if set.is_empty() => other,
+            (AllReals, _) | (_, AllReals) => AllReals,
+            (Finite(mut values), Finite(others)) => {
+                for value in others {
+                    if !values
+                        .iter()
+                        .any(|x| x.compare(&value) == Some(std::cmp::Ordering::Equal))
+                    {
+                        values.push(value);
+                    }
+                }
+                Finite(values)
+            }
+            (Union(mut sets), Union(others)) => {
+                sets.extend(others);
+                Union(sets)
+            }
+            (Union(mut sets), set) | (set, Union(mut sets)) => {
+                sets.push(set);
+                Union(sets)
+            }
+            (set, other) => Union(vec![set, other]),
+        }
+    }
+
+    /// Returns the set of values in both sets.
+    pub fn intersection(&self, other: &Self) -> Self {
+        use SolutionSet::*;
+        match (self, other) {
+            (Empty, _) | (_, Empty) => Empty,
+            (AllReals, set) | (set, AllReals) => set.clone(),
+            (Finite(values), set) | (set, Finite(values)) => {
+                let values = values
+                    .iter()
+                    .filter(|x| set.contains(x))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                if values.is_empty() {
+                    Empty
+                } else {
+                    Finite(values)
+                }
+            }
+            (Union(sets), set) | (set, Union(sets)) => sets
+                .iter()
+                .map(|x| x.intersection(set))
+                .fold(Empty, SolutionSet::union),
+            (Interval(a), Interval(b)) => match a.intersection(b) {
+                Some(interval) if interval.is_empty() => Empty,
+                Some(interval) => Interval(interval),
+                None => Empty,
+            },
+        }
+    }
+
+    /// Describes the set as conditions on the variable named `var`.
+    ///
+    /// For example, `{1, 2}` in terms of `x` is `x = 1 or x = 2`.
+    pub fn in_terms_of(&self, var: &str) -> String {
+        use SolutionSet::*;
+        match self {
+            Empty => format!("{var} ∈ ∅"),
+            Finite(values) if values.is_empty() => format!("{var} ∈ ∅"),
+            Finite(values) => values
+                .iter()
+                .map(|x| format!("{var} = {x}"))
+                .collect::<Vec<_>>()
+                .join(" or "),
+            Interval(interval) => interval.in_terms_of(var),
+            Union(sets) => sets
+                .iter()
+                .map(|x| x.in_terms_of(var))
+                .collect::<Vec<_>>()
+                .join(" or "),
+            AllReals => format!("{var} ∈ ℝ"),
+        }
+    }
+}
+
+impl From<Interval> for SolutionSet {
+    fn from(value: Interval) -> Self {
+        Self::Interval(value)
+    }
+}
+
+impl std::fmt::Display for SolutionSet {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use SolutionSet::*;
+        match self {
+            Empty => "∅".fmt(f),
+            Finite(values) => format!(
+                "{{{}}}",
+                values
+                    .iter()
+                    .map(|x| x.to_string())
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+            .fmt(f),
+            Interval(interval) => interval.fmt(f),
+            Union(sets) => sets
+                .iter()
+                .map(|x| x.to_string())
+                .collect::<Vec<_>>()
+                .join(" ∪ ")
+                .fmt(f),
+            AllReals => "ℝ".fmt(f),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{SolutionSet::*, *};
+    use crate::solve::interval::Bound;
+
+    fn above(value: i32) -> SolutionSet {
+        SolutionSet::Interval(super::Interval {
+            lower: Bound::Open(Notation::from(value)),
+            upper: Bound::Unbounded,
+        })
+    }
+
+    fn below(value: i32) -> SolutionSet {
+        SolutionSet::Interval(super::Interval {
+            lower: Bound::Unbounded,
+            upper: Bound::Open(Notation::from(value)),
+        })
+    }
+
+    #[test]
+    fn test_union_membership() {
+        let outside = below(-1).union(above(1));
+        assert!(outside.contains(&Notation::from(-2)));
+        assert!(!outside.contains(&Notation::from(0)));
+        assert!(outside.contains(&Notation::from(2)));
+        assert_eq!(outside.to_string(), "(-∞, -1) ∪ (1, ∞)");
+    }
+
+    #[test]
+    fn test_union_with_empty() {
+        assert_eq!(Empty.union(above(0)), above(0));
+        assert_eq!(above(0).union(Empty), above(0));
+        assert_eq!(AllReals.union(above(0)), AllReals);
+    }
+
+    #[test]
+    fn test_finite_union_removes_duplicates() {
+        let a = Finite(vec![Notation::from(1), Notation::from(2)]);
+        let b = Finite(vec![Notation::from(2), Notation::from(3)]);
+        assert_eq!(a.union(b).to_string(), "{1, 2, 3}");
+    }
+
+    #[test]
+    fn test_intersection() {
+        assert!(below(0).intersection(&above(0)).is_empty());
+        assert_eq!(AllReals.intersection(&above(0)), above(0));
+
+        let between = below(5).intersection(&above(1));
+        assert!(between.contains(&Notation::from(3)));
+        assert!(!between.contains(&Notation::from(5)));
+
+        let outside = below(-1).union(above(1));
+        let finite = Finite(vec![
+            Notation::from(-3),
+            Notation::from(0),
+            Notation::from(3),
+        ]);
+        assert_eq!(outside.intersection(&finite).to_string(), "{-3, 3}");
+    }
+}
@@ -0,0 +1,170 @@
+//! Systems of linear equations.
+
+use std::collections::{BTreeMap, BTreeSet};
+
+use super::{equation::Equation, var::Var, SolveError};
+use crate::{matrix::Matrix, notation::Notation};
+
+/// The result of solving a system of linear equations.
+#[derive(Debug, PartialEq, Clone)]
+pub enum SystemSolution {
+    /// Exactly one value of each variable satisfies every equation.
+    ///
+    /// Contains the value of each variable, keyed by the variable's name.
+    Unique(BTreeMap<Var, Notation>),
+
+    /// The equations contradict each other, so no values satisfy all of them.
+    Inconsistent,
+
+    /// Infinitely many values satisfy every equation, because some equations depend on the others.
+    Dependent,
+}
+
+/// Solves a system of linear equations by Gauss-Jordan elimination.
+///
+/// Meant for the systems of two or three variables seen in algebra courses, but any number of variables is supported.
+///
+/// Every coefficient must be an integer or fraction, and arithmetic is exact;
+/// fails with [`SolveError::Inexact`] if a value becomes too large to represent.
+///
+/// ```
+/// # use algebra::solve::{equation::Equation, linear::Linear, system::{solve_system, SystemSolution}, var::Var};
+/// // x + y = 3
+/// // x - y = 1
+/// let system = [
+///     Equation { lhs: Linear::var("x") + Linear::var("y"), rhs: Linear::from(3) },
+///     Equation { lhs: Linear::var("x") - Linear::var("y"), rhs: Linear::from(1) },
+/// ];
+/// let SystemSolution::Unique(values) = solve_system(&system).unwrap() else { unreachable!() };
+/// assert_eq!(values[&Var::new("x")], 2);
+/// assert_eq!(values[&Var::new("y")], 1);
+/// ```
+pub fn solve_system(equations: &[Equation]) -> Result<SystemSolution, SolveError> {
+    let zeroed = equations.iter().map(Equation::zeroed).collect::<Vec<_>>();
+    let vars = zeroed
+        .iter()
+        .flat_map(|eq| eq.variables().map(Var::new))
+        .collect::<BTreeSet<_>>()
+        .into_iter()
+        .collect::<Vec<_>>();
+
+    // Augmented matrix: each row is 𝑎𝑥 + 𝑏𝑦 + … = 𝑐
+    let augmented = Matrix::new(
+        zeroed
+            .into_iter()
+            .map(|eq| {
+                let mut row = vars
+                    .iter()
+                    .map(|var| eq.coefficient(var))
+                    .collect::<Vec<_>>();
+                row.push(-eq.constant);
+                row
+            })
+            .collect(),
+    );
+
+    let (reduced, pivots) = augmented
+        .row_reduced_with_pivots()
+        .ok_or(SolveError::Inexact)?;
+
+    // A pivot in the last column means some row reads 0 = 𝑐
+    if pivots.last() == Some(&vars.len()) {
+        return Ok(SystemSolution::Inconsistent);
+    }
+
+    if pivots.len() < vars.len() {
+        return Ok(SystemSolution::Dependent);
+    }
+
+    Ok(SystemSolution::Unique(
+        vars.into_iter()
+            .enumerate()
+            .map(|(row, var)| (var, reduced[(row, reduced.cols() - 1)].clone()))
+            .collect(),
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{notation::expr::fraction::Fraction, solve::linear::Linear};
+
+    fn eq(lhs: Linear, rhs: i32) -> Equation {
+        Equation {
+            lhs,
+            rhs: Linear::from(rhs),
+        }
+    }
+
+    #[test]
+    fn test_two_variables() {
+        // 2x + 3y = 7
+        // 4x -  y = 0
+        let system = [
+            eq(Linear::term(2, "x") + Linear::term(3, "y"), 7),
+            eq(Linear::term(4, "x") - Linear::var("y"), 0),
+        ];
+        let SystemSolution::Unique(values) = solve_system(&system).unwrap() else {
+            panic!("expected a unique solution");
+        };
+        assert_eq!(values[&Var::new("x")], Fraction::new(1, 2));
+        assert_eq!(values[&Var::new("y")], 2);
+    }
+
+    #[test]
+    fn test_three_variables() {
+        //  x + y + z = 6
+        // 2y + 5z = -4
+        // 2x + 5y - z = 27
+        let system = [
+            eq(Linear::var("x") + Linear::var("y") + Linear::var("z"), 6),
+            eq(Linear::term(2, "y") + Linear::term(5, "z"), -4),
+            eq(
+                Linear::term(2, "x") + Linear::term(5, "y") - Linear::var("z"),
+                27,
+            ),
+        ];
+        let SystemSolution::Unique(values) = solve_system(&system).unwrap() else {
+            panic!("expected a unique solution");
+        };
+        assert_eq!(values[&Var::new("x")], 5);
+        assert_eq!(values[&Var::new("y")], 3);
+        assert_eq!(values[&Var::new("z")], -2);
+    }
+
+    #[test]
+    fn test_inconsistent() {
+        // x + y = 1
+        // 2x + 2y = 3
+        let system = [
+            eq(Linear::var("x") + Linear::var("y"), 1),
+            eq(Linear::term(2, "x") + Linear::term(2, "y"), 3),
+        ];
+        assert_eq!(solve_system(&system), Ok(SystemSolution::Inconsistent));
+    }
+
+    #[test]
+    fn test_dependent() {
+        // x + y = 1
+        // 3x + 3y = 3
+        let system = [
+            eq(Linear::var("x") + Linear::var("y"), 1),
+            eq(Linear::term(3, "x") + Linear::term(3, "y"), 3),
+        ];
+        assert_eq!(solve_system(&system), Ok(SystemSolution::Dependent));
+    }
+
+    #[test]
+    fn test_overdetermined() {
+        // x = 1, y = 2, x + y = 3
+        let system = [
+            eq(Linear::var("x"), 1),
+            eq(Linear::var("y"), 2),
+            eq(Linear::var("x") + Linear::var("y"), 3),
+        ];
+        assert!(matches!(
+            solve_system(&system),
+            Ok(SystemSolution::Unique(_))
+        ));
+    }
+}
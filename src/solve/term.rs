@@ -0,0 +1,307 @@
+//! Single terms of a polynomial: an integer coefficient times powers of variables.
+
+use std::collections::BTreeMap;
+
+use super::var::Var;
+use crate::notation::format::{FormatOptions, FormatWith};
+
+/// A coefficient multiplied by variables raised to powers, such as `3x²` or `-xy³`.
+///
+/// A term without variables is a constant.
+///
+/// ```
+/// # use algebra::solve::term::Term;
+/// let term = Term::new(3, "x", 2);
+/// assert_eq!(term.to_string(), "3x²");
+/// assert_eq!((term.clone() * Term::new(-2, "x", 1)).to_string(), "-6x³");
+/// assert_eq!((term.clone() * Term::new(1, "y", 2)).to_string(), "3x²y²");
+/// assert_eq!(term.checked_add(Term::new(1, "x", 2)), Some(Term::new(4, "x", 2)));
+/// assert_eq!(Term::new(3, "x", 2).checked_add(Term::new(1, "x", 1)), None);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Term {
+    /// The integer multiplying the variables.
+    pub coef: i32,
+
+    /// The power each variable is raised to, keyed by the variable's name.
+    ///
+    /// Variables raised to the power of 0 are not stored.
+    pub vars: BTreeMap<Var, u32>,
+}
+
+impl From<i32> for Term {
+    /// Construct a constant term, containing no variables.
+    fn from(coef: i32) -> Self {
+        Self {
+            coef,
+            vars: BTreeMap::new(),
+        }
+    }
+}
+
+impl Term {
+    /// Constructs the term `coef𝑥^exp`, where 𝑥 is the variable named `var`.
+    ///
+    /// Multiply terms to construct a term with more than one variable.
+    pub fn new(coef: i32, var: &str, exp: u32) -> Self {
+        let mut term = Self::from(coef);
+        if exp != 0 && coef != 0 {
+            term.vars.insert(Var::new(var), exp);
+        }
+        term
+    }
+
+    /// Constructs the term `1𝑥`, where 𝑥 is the variable named `var`.
+    pub fn var(var: &str) -> Self {
+        Self::new(1, var, 1)
+    }
+
+    /// Returns true if the term has no variable part, false otherwise.
+    pub fn is_constant(&self) -> bool {
+        self.vars.is_empty() || self.coef == 0
+    }
+
+    /// Returns true if the terms have the same variables raised to the same powers, so that they can be added.
+    ///
+    /// Constants are like each other, as is any term with a coefficient of 0.
+    pub fn is_like(&self, other: &Self) -> bool {
+        self.vars == other.vars || self.coef == 0 || other.coef == 0
+    }
+
+    /// Returns the power the variable named `var` is raised to, which is 0 if it doesn't appear in the term.
+    pub fn degree_in(&self, var: &str) -> u32 {
+        self.vars.get(&Var::new(var)).copied().unwrap_or(0)
+    }
+
+    /// Returns the sum of the powers of every variable in the term, so that `3xy²` has a degree of 3.
+    pub fn degree(&self) -> u32 {
+        self.vars.values().sum()
+    }
+
+    /// Returns the names of all variables in the term, in alphabetical order.
+    pub fn variables(&self) -> impl Iterator<Item = &str> {
+        self.vars.keys().map(|var| var.as_str())
+    }
+
+    /// Adds two like terms by adding their coefficients.
+    ///
+    /// Returns [`None`] if the terms are not [like][Term::is_like] each other, or the coefficient overflows.
+    pub fn checked_add(self, rhs: Self) -> Option<Self> {
+        if !self.is_like(&rhs) {
+            return None;
+        }
+        let coef = self.coef.checked_add(rhs.coef)?;
+        let like = if self.coef == 0 { rhs } else { self };
+        Some(match coef {
+            0 => Self::from(0),
+            _ => Self { coef, ..like },
+        })
+    }
+
+    /// Substitutes values for some of the term's variables, leaving the others as they are.
+    ///
+    /// Returns [`None`] if the coefficient overflows.
+    ///
+    /// ```
+    /// # use algebra::solve::{term::Term, var::Var};
+    /// # use std::collections::BTreeMap;
+    /// let term = Term::new(3, "x", 2) * Term::var("y");
+    /// let x = BTreeMap::from([(Var::new("x"), 2)]);
+    /// assert_eq!(term.eval(&x), Some(Term::new(12, "y", 1)));
+    /// ```
+    pub fn eval(&self, values: &BTreeMap<Var, i32>) -> Option<Self> {
+        let mut result = Self::from(self.coef);
+        for (var, &exp) in &self.vars {
+            match values.get(var) {
+                Some(value) => result.coef = result.coef.checked_mul(value.checked_pow(exp)?)?,
+                None => {
+                    result.vars.insert(*var, exp);
+                }
+            }
+        }
+        if result.coef == 0 {
+            result.vars.clear();
+        }
+        Some(result)
+    }
+
+    /// Compares the variable parts of two terms, ignoring their coefficients.
+    ///
+    /// Uses lexicographic order: the powers of each variable are compared in alphabetical order,
+    /// and the term with the higher power of the first variable they differ in is greater,
+    /// so that `x² > xy > x > y² > y > 1`.
+    pub fn cmp_vars(&self, other: &Self) -> std::cmp::Ordering {
+        let mut vars = self
+            .variables()
+            .chain(other.variables())
+            .collect::<Vec<_>>();
+        vars.sort_unstable();
+        vars.into_iter()
+            .map(|var| self.degree_in(var).cmp(&other.degree_in(var)))
+            .find(|order| order.is_ne())
+            .unwrap_or(std::cmp::Ordering::Equal)
+    }
+}
+
+// # Ordering
+
+impl PartialOrd for Term {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Term {
+    /// Orders terms by their variables with [`cmp_vars`][Term::cmp_vars], then by their coefficients.
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        self.cmp_vars(other).then(self.coef.cmp(&other.coef))
+    }
+}
+
+// # Arithmetic
+
+impl std::ops::Mul for Term {
+    type Output = Self;
+
+    /// Multiply two terms, multiplying their coefficients and adding the powers of each variable.
+    fn mul(self, rhs: Self) -> Self::Output {
+        let coef = self.coef * rhs.coef;
+        if coef == 0 {
+            return Self::from(0);
+        }
+        let mut vars = self.vars;
+        for (var, exp) in rhs.vars {
+            *vars.entry(var).or_insert(0) += exp;
+        }
+        Self { coef, vars }
+    }
+}
+
+impl std::ops::Mul<i32> for Term {
+    type Output = Self;
+
+    /// Multiply the term's coefficient.
+    fn mul(self, rhs: i32) -> Self::Output {
+        self * Self::from(rhs)
+    }
+}
+
+impl std::ops::Neg for Term {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            coef: -self.coef,
+            ..self
+        }
+    }
+}
+
+impl FormatWith for Term {
+    fn format_with(&self, options: &FormatOptions) -> String {
+        if self.is_constant() {
+            return self.coef.to_string();
+        }
+        let mut result = match self.coef {
+            1 => String::new(),
+            -1 => "-".to_string(),
+            coef => coef.to_string(),
+        };
+        for (var, &exp) in &self.vars {
+            result += &match exp {
+                1 => var.to_string(),
+                exp => options.power(var, exp as i64),
+            };
+        }
+        result
+    }
+}
+
+impl std::fmt::Display for Term {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.format_with(&FormatOptions::default()).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_mul() {
+        let x = Term::var("x");
+        assert_eq!(x.clone() * x.clone(), Term::new(1, "x", 2));
+        assert_eq!(x.clone() * 3, Term::new(3, "x", 1));
+        assert_eq!(Term::from(2) * Term::from(3), Term::from(6));
+        assert_eq!(x.clone() * Term::from(0), Term::from(0));
+        let xy = x * Term::var("y");
+        assert_eq!(xy.degree_in("x"), 1);
+        assert_eq!(xy.degree_in("y"), 1);
+        assert_eq!(xy.degree_in("z"), 0);
+        assert_eq!(xy.degree(), 2);
+    }
+
+    #[test]
+    fn test_checked_add() {
+        let x = Term::new(2, "x", 1);
+        assert_eq!(x.clone().checked_add(-x.clone()), Some(Term::from(0)));
+        assert_eq!(x.clone().checked_add(Term::from(0)), Some(x.clone()));
+        assert_eq!(Term::from(0).checked_add(x.clone()), Some(x.clone()));
+        assert_eq!(x.clone().checked_add(Term::new(2, "y", 1)), None);
+        assert_eq!(x.checked_add(Term::from(1)), None);
+        assert_eq!(Term::from(i32::MAX).checked_add(Term::from(1)), None);
+    }
+
+    #[test]
+    fn test_eval() {
+        let values = |pairs: &[(&str, i32)]| {
+            pairs
+                .iter()
+                .map(|&(var, value)| (Var::new(var), value))
+                .collect::<BTreeMap<_, _>>()
+        };
+        let term = Term::new(3, "x", 2) * Term::new(1, "y", 3);
+        assert_eq!(term.eval(&values(&[])), Some(term.clone()));
+        assert_eq!(
+            term.eval(&values(&[("x", -2)])),
+            Some(Term::new(12, "y", 3))
+        );
+        assert_eq!(
+            term.eval(&values(&[("x", -2), ("y", -1), ("z", 5)])),
+            Some(Term::from(-12))
+        );
+        assert_eq!(term.eval(&values(&[("y", 0)])), Some(Term::from(0)));
+        assert_eq!(Term::new(1, "x", 32).eval(&values(&[("x", 2)])), None);
+    }
+
+    #[test]
+    fn test_ordering() {
+        let (x, y) = (Term::var("x"), Term::var("y"));
+        let mut terms = [
+            Term::from(1),
+            y.clone(),
+            x.clone() * y.clone(),
+            y.clone() * y.clone(),
+            x.clone(),
+            x.clone() * x.clone(),
+        ];
+        terms.sort_by(|a, b| b.cmp(a));
+        let terms = terms.iter().map(Term::to_string).collect::<Vec<_>>();
+        assert_eq!(terms, ["x²", "xy", "x", "y²", "y", "1"]);
+        assert!(Term::new(2, "x", 1) > x);
+    }
+
+    #[test]
+    fn test_display() {
+        assert_eq!(Term::var("x").to_string(), "x");
+        assert_eq!(Term::new(-1, "y", 3).to_string(), "-y³");
+        assert_eq!(Term::new(-2, "x", 0).to_string(), "-2");
+        assert_eq!(Term::new(0, "x", 2).to_string(), "0");
+        assert_eq!(Term::new(4, "t", 12).to_string(), "4t¹²");
+        assert_eq!((Term::new(3, "y", 2) * Term::var("x")).to_string(), "3xy²");
+        assert_eq!(
+            (Term::new(3, "y", 2) * Term::var("x")).format_with(&FormatOptions::ascii()),
+            "3xy^2"
+        );
+    }
+}
@@ -0,0 +1,307 @@
+//! Interned variable names.
+
+use std::{
+    collections::HashMap,
+    sync::{Mutex, OnceLock},
+};
+
+use crate::context::AlgebraContext;
+
+/// The name of a variable, such as `x`.
+///
+/// Names are interned: each distinct name is stored once for the life of the program,
+/// and every `Var` with that name refers to the same copy.
+/// Creating, copying, and comparing variables never allocates,
+/// so expressions with many occurrences of the same few variables stay cheap.
+/// A `Var` only holds the number of its name, so it is no larger than an [`i32`].
+///
+/// Variables are equal and hash by their number, without looking up their names.
+/// They are ordered alphabetically by name, so that sums of terms are written in the same order every time,
+/// and names are read without taking a lock.
+///
+/// ```
+/// # use algebra::solve::var::Var;
+/// # use std::collections::BTreeMap;
+/// let x = Var::new("x");
+/// assert_eq!(x, Var::from("x"));
+/// assert_eq!(x.as_str(), "x");
+/// assert!(Var::new("a") < x);
+///
+/// let map = BTreeMap::from([(x, 2)]);
+/// assert_eq!(map.get(&Var::new("x")), Some(&2));
+/// ```
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Var(u32);
+
+/// How many buckets of names there are. Bucket `b` holds `2^b` names, so together they hold every [`u32`].
+const BUCKETS: usize = 33;
+
+/// A bucket of names, each written at most once.
+type Bucket = Box<[OnceLock<Box<str>>]>;
+
+/// Every name interned by any [`Interner`], stored by number.
+///
+/// The names are kept in buckets which double in size and are never moved or resized,
+/// so a name can be read by its number without a lock once it is written.
+struct Names {
+    buckets: [OnceLock<Bucket>; BUCKETS],
+}
+
+impl Names {
+    /// The bucket a number's name is in, and its place in the bucket.
+    fn place(number: u32) -> (usize, usize) {
+        let index = number as u64 + 1;
+        let bucket = index.ilog2();
+        (bucket as usize, (index - (1 << bucket)) as usize)
+    }
+
+    /// Returns the name with the number, if it has been written.
+    fn get(&'static self, number: u32) -> Option<&'static str> {
+        let (bucket, slot) = Self::place(number);
+        self.buckets[bucket].get()?[slot].get().map(|name| &**name)
+    }
+
+    /// Writes the name for the number, returning where it is stored.
+    fn set(&'static self, number: u32, name: &str) -> &'static str {
+        let (bucket, slot) = Self::place(number);
+        let bucket = self.buckets[bucket]
+            .get_or_init(|| (0..1 << bucket).map(|_| OnceLock::new()).collect());
+        bucket[slot].get_or_init(|| name.into())
+    }
+}
+
+fn names() -> &'static Names {
+    static NAMES: Names = Names {
+        buckets: [const { OnceLock::new() }; BUCKETS],
+    };
+    &NAMES
+}
+
+/// The number of every name interned by any [`Interner`].
+fn numbers() -> &'static Mutex<HashMap<&'static str, u32>> {
+    static NUMBERS: OnceLock<Mutex<HashMap<&'static str, u32>>> = OnceLock::new();
+    NUMBERS.get_or_init(Default::default)
+}
+
+/// A set of interned names, which gives out a [`Var`] for each.
+///
+/// [`Var::new`] uses one global interner.
+/// An [`AlgebraContext`] has its own,
+/// so that threads working in different contexts only wait on each other the first time the program sees a name.
+/// Names are stored once for the whole program and never freed, whichever interner first saw them.
+///
+/// Variables with the same name are equal even if they came from different interners.
+#[derive(Debug, Default)]
+pub struct Interner {
+    vars: Mutex<HashMap<&'static str, Var>>,
+}
+
+impl Interner {
+    /// Constructs an interner which hasn't interned any names.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the variable named `name`, storing the name only the first time the program sees it.
+    pub fn intern(&self, name: &str) -> Var {
+        // Names are only ever added, so a panic while holding a lock can't leave it inconsistent
+        let mut vars = self.vars.lock().unwrap_or_else(|e| e.into_inner());
+        if let Some(&var) = vars.get(name) {
+            return var;
+        }
+        let mut numbers = numbers().lock().unwrap_or_else(|e| e.into_inner());
+        let (name, number) = match numbers.get_key_value(name) {
+            Some((&name, &number)) => (name, number),
+            None => {
+                let number = u32::try_from(numbers.len()).expect("too many variable names");
+                let name = names().set(number, name);
+                numbers.insert(name, number);
+                (name, number)
+            }
+        };
+        vars.insert(name, Var(number));
+        Var(number)
+    }
+
+    /// Returns the interner used by [`Var::new`].
+    pub(crate) fn global() -> &'static Interner {
+        static GLOBAL: OnceLock<Interner> = OnceLock::new();
+        GLOBAL.get_or_init(Interner::new)
+    }
+
+    /// Returns the number of distinct names interned.
+    pub fn len(&self) -> usize {
+        self.vars.lock().unwrap_or_else(|e| e.into_inner()).len()
+    }
+
+    /// Returns true if no names have been interned, false otherwise.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl Var {
+    /// Returns the variable named `name` from the global [`Interner`],
+    /// storing the name only the first time it is used.
+    pub fn new(name: &str) -> Self {
+        Interner::global().intern(name)
+    }
+
+    /// Returns the variable named `name` from the context's interner. See [`AlgebraContext::var`].
+    pub fn new_with_ctx(name: &str, ctx: &AlgebraContext) -> Self {
+        ctx.var(name)
+    }
+
+    /// Returns the name of the variable.
+    pub fn as_str(&self) -> &'static str {
+        // A `Var` is only made after its name is written
+        names()
+            .get(self.0)
+            .expect("variable names are written before use")
+    }
+}
+
+impl PartialOrd for Var {
+    fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for Var {
+    fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+        match self.0 == other.0 {
+            true => std::cmp::Ordering::Equal,
+            false => self.as_str().cmp(other.as_str()),
+        }
+    }
+}
+
+impl From<&str> for Var {
+    fn from(name: &str) -> Self {
+        Self::new(name)
+    }
+}
+
+impl From<&String> for Var {
+    fn from(name: &String) -> Self {
+        Self::new(name)
+    }
+}
+
+impl std::ops::Deref for Var {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        self.as_str()
+    }
+}
+
+impl std::fmt::Debug for Var {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+impl std::fmt::Display for Var {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        self.as_str().fmt(f)
+    }
+}
+
+/// Written as the name, and interned again in the global [`Interner`] when read.
+#[cfg(feature = "serde")]
+impl serde::Serialize for Var {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(self.as_str())
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de> serde::Deserialize<'de> for Var {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        String::deserialize(deserializer).map(|name| Var::new(&name))
+    }
+}
+
+impl PartialEq<str> for Var {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Var {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_interning() {
+        let name = String::from("interned");
+        let (a, b) = (Var::new(&name), Var::new("interned"));
+        assert_eq!(a, b);
+        // Both refer to the same allocation
+        assert!(std::ptr::eq(a.as_str(), b.as_str()));
+        assert!(!std::ptr::eq(a.as_str(), name.as_str()));
+        assert_ne!(a, Var::new("other"));
+    }
+
+    #[test]
+    fn test_separate_interners() {
+        let interner = Interner::new();
+        assert!(interner.is_empty());
+        let (a, b) = (interner.intern("z"), interner.intern("z"));
+        assert!(std::ptr::eq(a.as_str(), b.as_str()));
+        assert_eq!(interner.len(), 1);
+        assert_eq!(a, Var::new("z"));
+    }
+
+    #[test]
+    fn test_lookup() {
+        assert_eq!(std::mem::size_of::<Var>(), std::mem::size_of::<i32>());
+        let set = std::collections::HashSet::from([Var::new("x"), Var::new("y")]);
+        assert!(set.contains(&Var::new("x")) && !set.contains(&Var::new("z")));
+    }
+
+    #[test]
+    fn test_names_by_number() {
+        assert_eq!(Names::place(0), (0, 0));
+        assert_eq!(Names::place(1), (1, 0));
+        assert_eq!(Names::place(2), (1, 1));
+        assert_eq!(Names::place(6), (2, 3));
+        assert_eq!(Names::place(7), (3, 0));
+        assert_eq!(Names::place(u32::MAX), (BUCKETS - 1, 0));
+        // Enough names to fill several buckets, each still read back by its number
+        let vars = (0..100)
+            .map(|n| Var::new(&format!("v{n}")))
+            .collect::<Vec<_>>();
+        for (n, var) in vars.iter().enumerate() {
+            assert_eq!(var.as_str(), format!("v{n}"));
+        }
+    }
+
+    #[test]
+    fn test_order() {
+        let mut vars = ["y", "x", "alpha", "x₁"].map(Var::new);
+        vars.sort();
+        assert_eq!(vars.map(|var| var.as_str()), ["alpha", "x", "x₁", "y"]);
+    }
+
+    #[test]
+    fn test_threads() {
+        let handles = (0..8)
+            .map(|_| std::thread::spawn(|| Var::new("shared between threads")))
+            .collect::<Vec<_>>();
+        let vars = handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect::<Vec<_>>();
+        assert!(vars
+            .windows(2)
+            .all(|pair| std::ptr::eq(pair[0].as_str(), pair[1].as_str())));
+    }
+}
@@ -0,0 +1,301 @@
+//! Statistics of lists of numbers, computed exactly.
+//!
+//! Values can be integers or [`Fraction`]s, and every result is a reduced fraction,
+//! where averaging with floats would round.
+//! A result too large to write down as a fraction saturates to [`Huge`][Atom::Huge] the same way arithmetic does.
+//!
+//! ```
+//! # use algebra::{stats::mean, notation::expr::fraction::Fraction};
+//! // 0.1 + 0.2 + 0.6 is not 0.9 in floating point, but these are exact
+//! let tenths = [Fraction::new(1, 10), Fraction::new(2, 10), Fraction::new(6, 10)];
+//! assert_eq!(mean(&tenths), Fraction::new(3, 10));
+//! ```
+
+use crate::notation::{
+    atom::Atom,
+    expr::fraction::Fraction,
+    ops::{gcd, lowest_terms, rational, saturate},
+    Notation,
+};
+
+/// Every value in lowest terms, or [`None`] if any of them isn't a ratio of numbers.
+fn ratios<T: Clone + Into<Fraction>>(values: &[T]) -> Option<Vec<(i128, i128)>> {
+    values.iter().cloned().map(lowest_terms).collect()
+}
+
+/// Adds two ratios in lowest terms, or returns [`None`] if the sum doesn't fit in an [`i128`].
+fn add((a, b): (i128, i128), (c, d): (i128, i128)) -> Option<(i128, i128)> {
+    let g = gcd(b, d);
+    let den = (b / g).checked_mul(d)?;
+    let num = a.checked_mul(d / g)?.checked_add(c.checked_mul(b / g)?)?;
+    let g = gcd(num, den).max(1);
+    Some((num / g, den / g))
+}
+
+/// Divides the exact sum of `terms` by `count`, falling back to saturating by the size of `estimate` if the sum overflows.
+fn quotient(
+    terms: impl IntoIterator<Item = Option<(i128, i128)>>,
+    count: (i128, i128),
+    estimate: impl FnOnce() -> f64,
+) -> Notation {
+    let sum = terms
+        .into_iter()
+        .try_fold((0, 1), |sum, term| add(sum, term?));
+    let quotient = sum.and_then(|(num, den)| {
+        let num = num.checked_mul(count.1)?;
+        Some((num, den.checked_mul(count.0)?))
+    });
+    match quotient {
+        Some((num, den)) => rational(num, den),
+        None if count.0 == 0 => Notation::from(Atom::Undefined),
+        None => {
+            let estimate = estimate();
+            saturate(estimate.abs() >= 1.0, estimate < 0.0)
+        }
+    }
+}
+
+/// The nearest [`f64`] to a ratio, for estimating results which overflow.
+fn to_f64((num, den): (i128, i128)) -> f64 {
+    num as f64 / den as f64
+}
+
+/// Returns the values in lowest terms from least to greatest.
+fn sorted<T: Clone + Into<Fraction>>(values: &[T]) -> Option<Vec<(i128, i128)>> {
+    let mut values = ratios(values)?;
+    // Both denominators are positive, so cross-multiplying keeps the order
+    values.sort_by(|(a, b), (c, d)| (a * d).cmp(&(c * b)));
+    Some(values)
+}
+
+/// Returns the mean of the values: their sum divided by how many there are.
+///
+/// Returns [`Undefined`][Atom::Undefined] if there are no values, or if any of them isn't a ratio of numbers.
+///
+/// ```
+/// # use algebra::{stats::mean, notation::expr::fraction::Fraction};
+/// assert_eq!(mean(&[1, 2, 3, 4]), Fraction::new(5, 2));
+/// assert_eq!(mean(&[Fraction::new(1, 2), Fraction::new(1, 3)]), Fraction::new(5, 12));
+/// ```
+pub fn mean<T: Clone + Into<Fraction>>(values: &[T]) -> Notation {
+    let Some(values) = ratios(values) else {
+        return Notation::from(Atom::Undefined);
+    };
+    let count = values.len() as i128;
+    quotient(values.iter().copied().map(Some), (count, 1), || {
+        values.iter().copied().map(to_f64).sum::<f64>() / count as f64
+    })
+}
+
+/// Returns the mean of the values, where each value counts as many times as its weight:
+/// the sum of each value times its weight, divided by the sum of the weights.
+///
+/// Returns [`Undefined`][Atom::Undefined] if the weights add to 0, or if any value or weight isn't a ratio of numbers.
+///
+/// ```
+/// # use algebra::{stats::weighted_mean, notation::expr::fraction::Fraction};
+/// // Tests worth 60% and homework worth 40%
+/// let grades = [(85, Fraction::new(3, 5)), (90, Fraction::new(2, 5))];
+/// assert_eq!(weighted_mean(&grades), 87);
+/// ```
+pub fn weighted_mean<V, W>(values: &[(V, W)]) -> Notation
+where
+    V: Clone + Into<Fraction>,
+    W: Clone + Into<Fraction>,
+{
+    let pairs = values
+        .iter()
+        .map(|(value, weight)| {
+            let value = lowest_terms(value.clone())?;
+            Some((value, lowest_terms(weight.clone())?))
+        })
+        .collect::<Option<Vec<_>>>();
+    let Some(pairs) = pairs else {
+        return Notation::from(Atom::Undefined);
+    };
+    let total = pairs
+        .iter()
+        .try_fold((0, 1), |total, &(_, weight)| add(total, weight));
+    let Some(total) = total else {
+        // The weights alone are too large, so the result is an estimate either way
+        let estimate = pairs
+            .iter()
+            .map(|&(value, weight)| to_f64(value) * to_f64(weight))
+            .sum::<f64>()
+            / pairs.iter().map(|&(_, weight)| to_f64(weight)).sum::<f64>();
+        return saturate(estimate.abs() >= 1.0, estimate < 0.0);
+    };
+    let products = pairs.iter().map(|&((a, b), (c, d))| {
+        let (ad, cb) = (gcd(a, d).max(1), gcd(c, b).max(1));
+        Some(((a / ad) * (c / cb), (b / cb) * (d / ad)))
+    });
+    quotient(products, total, || {
+        pairs
+            .iter()
+            .map(|&(value, weight)| to_f64(value) * to_f64(weight))
+            .sum::<f64>()
+            / to_f64(total)
+    })
+}
+
+/// Returns the median of the values: the middle value once they are sorted,
+/// or the mean of the middle two if there are an even number of them.
+///
+/// Returns [`Undefined`][Atom::Undefined] if there are no values, or if any of them isn't a ratio of numbers.
+///
+/// ```
+/// # use algebra::{stats::median, notation::expr::fraction::Fraction};
+/// assert_eq!(median(&[7, 1, 3]), 3);
+/// assert_eq!(median(&[7, 1, 3, 4]), Fraction::new(7, 2));
+/// ```
+pub fn median<T: Clone + Into<Fraction>>(values: &[T]) -> Notation {
+    let values = match sorted(values) {
+        Some(values) if !values.is_empty() => values,
+        _ => return Notation::from(Atom::Undefined),
+    };
+    let middle = values.len() / 2;
+    match values.len() % 2 {
+        1 => rational(values[middle].0, values[middle].1),
+        _ => {
+            let ((a, b), (c, d)) = (values[middle - 1], values[middle]);
+            // Values started as i32s, so these products fit
+            rational(a * d + c * b, 2 * b * d)
+        }
+    }
+}
+
+/// Returns the mode of the values: the value which appears the most times, from least to greatest.
+///
+/// Values are compared once reduced, so 1/2 and 2/4 are the same value.
+/// If several values tie for appearing the most, every one of them is returned,
+/// so values which each appear once are all modes.
+///
+/// Returns no values if there are none, or if any of them isn't a ratio of numbers.
+///
+/// ```
+/// # use algebra::{stats::mode, notation::expr::fraction::Fraction};
+/// assert_eq!(mode(&[3, 1, 3, 2]), [3]);
+/// assert_eq!(mode(&[2, 1, 2, 1, 5]), [1, 2]);
+/// assert_eq!(mode(&[Fraction::new(1, 2), Fraction::new(2, 4), Fraction::new(1, 3)]), [Fraction::new(1, 2)]);
+/// ```
+pub fn mode<T: Clone + Into<Fraction>>(values: &[T]) -> Vec<Notation> {
+    let Some(values) = sorted(values) else {
+        return Vec::new();
+    };
+    // Equal values are next to each other once sorted
+    let runs = values
+        .chunk_by(|lhs, rhs| lhs == rhs)
+        .map(|run| (run[0], run.len()))
+        .collect::<Vec<_>>();
+    let most = runs.iter().map(|&(_, count)| count).max().unwrap_or(0);
+    runs.into_iter()
+        .filter(|&(_, count)| count == most)
+        .map(|((num, den), _)| rational(num, den))
+        .collect()
+}
+
+/// Returns the range of the values: the greatest value less the least.
+///
+/// Returns [`Undefined`][Atom::Undefined] if there are no values, or if any of them isn't a ratio of numbers.
+///
+/// ```
+/// # use algebra::{stats::range, notation::expr::fraction::Fraction};
+/// assert_eq!(range(&[4, -2, 9]), 11);
+/// assert_eq!(range(&[Fraction::new(1, 4), Fraction::new(2, 3)]), Fraction::new(5, 12));
+/// ```
+pub fn range<T: Clone + Into<Fraction>>(values: &[T]) -> Notation {
+    match sorted(values).as_deref() {
+        Some([(a, b), .., (c, d)]) => rational(c * b - a * d, b * d),
+        Some([_]) => Notation::from(0),
+        _ => Notation::from(Atom::Undefined),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_undefined(value: Notation) -> bool {
+        value.atom().is_some_and(|x| x.is_undefined())
+    }
+
+    #[test]
+    fn test_mean() {
+        assert_eq!(mean(&[5]), 5);
+        assert_eq!(mean(&[-3, 3]), 0);
+        assert_eq!(mean(&[1, 2]), Fraction::new(3, 2));
+        assert_eq!(mean(&[i32::MAX, i32::MAX, i32::MAX]), i32::MAX);
+        assert_eq!(mean(&[i32::MIN, i32::MAX]), Fraction::new(-1, 2));
+        assert!(is_undefined(mean::<i32>(&[])));
+        assert!(is_undefined(mean(&[Fraction::new(1, 0)])));
+    }
+
+    #[test]
+    fn test_mean_saturates() {
+        // Denominators this large with few common factors overflow an i128 when added
+        let primes = [
+            2147483647, 2147483629, 2147483587, 2147483579, 2147483563, 2147483549,
+        ];
+        let values = primes.map(|den| Fraction::new(1, den));
+        assert!(mean(&values)
+            .atom()
+            .is_some_and(|x| x.is_positive_epsilon()));
+        let values = primes.map(|den| Fraction::new(i32::MIN, den));
+        assert!(mean(&values).atom().is_some_and(|x| x.is_negative_huge()));
+    }
+
+    #[test]
+    fn test_weighted_mean() {
+        assert_eq!(weighted_mean(&[(1, 1), (4, 2)]), 3);
+        assert_eq!(weighted_mean(&[(1, 3), (2, 1)]), Fraction::new(5, 4));
+        assert_eq!(
+            weighted_mean(&[(Fraction::new(1, 2), 2), (Fraction::new(1, 3), 1)]),
+            Fraction::new(4, 9)
+        );
+        assert!(is_undefined(weighted_mean::<i32, i32>(&[])));
+        assert!(is_undefined(weighted_mean(&[(1, 1), (2, -1)])));
+    }
+
+    #[test]
+    fn test_median() {
+        assert_eq!(median(&[4]), 4);
+        assert_eq!(median(&[2, 1]), Fraction::new(3, 2));
+        assert_eq!(median(&[i32::MAX, i32::MAX]), i32::MAX);
+        assert_eq!(
+            median(&[
+                Fraction::new(1, 3),
+                Fraction::new(1, 2),
+                Fraction::new(1, 4)
+            ]),
+            Fraction::new(1, 3)
+        );
+        assert_eq!(
+            median(&[Fraction::new(-1, 3), Fraction::new(1, -2)]),
+            Fraction::new(-5, 12)
+        );
+        assert!(is_undefined(median::<i32>(&[])));
+    }
+
+    #[test]
+    fn test_mode() {
+        assert_eq!(mode(&[1, 2, 3]), [1, 2, 3]);
+        assert_eq!(mode(&[-1, -1, 0]), [-1]);
+        assert_eq!(
+            mode(&[Fraction::new(2, -4), Fraction::new(-1, 2)]),
+            [Fraction::new(-1, 2)]
+        );
+        assert!(mode::<i32>(&[]).is_empty());
+        assert!(mode(&[Fraction::new(1, 0), Fraction::new(1, 0)]).is_empty());
+    }
+
+    #[test]
+    fn test_range() {
+        assert_eq!(range(&[3]), 0);
+        assert_eq!(
+            range(&[i32::MIN, i32::MAX]).to_string(),
+            Notation::from(Atom::Huge).to_string()
+        );
+        assert_eq!(range(&[Fraction::new(1, 2), Fraction::new(-1, 2)]), 1);
+        assert!(is_undefined(range::<i32>(&[])));
+    }
+}
@@ -0,0 +1,261 @@
+//! Golden-file testing of how notation is written out as text.
+//!
+//! A snapshot renders every expression in a corpus, one per line,
+//! keyed by the expression's [s-expression][Notation::to_sexpr] so that a change to one line is easy to trace.
+//! [`assert_golden`] compares a snapshot against the copy saved in a file,
+//! and fails listing every line which changed.
+//!
+//! When a change in formatting is intended, rerun the tests with the environment variable
+//! [`UPDATE_VAR`] set to `1` to overwrite the saved files, then review the changes to them.
+//! A golden file which doesn't exist yet is always written.
+//!
+//! ```no_run
+//! # use algebra::testing::{assert_golden, corpus, snapshot};
+//! let display = snapshot(&corpus(), |value| value.to_string());
+//! assert_golden("tests/golden/display.txt", &display);
+//! ```
+
+use std::path::{Path, PathBuf};
+
+use crate::notation::{
+    atom::Atom,
+    expr::{
+        complex::Complex,
+        fraction::Fraction,
+        multiple::Multiple,
+        radical::Radical,
+        radical_fraction::RadicalFraction,
+        sum::Sum,
+        trig::{Trig, TrigFunction},
+    },
+    Notation,
+};
+
+/// The environment variable which, when set to `1`, makes golden files be overwritten rather than compared.
+pub const UPDATE_VAR: &str = "ALGEBRA_UPDATE_GOLDEN";
+
+/// Reasons a snapshot didn't match its golden file.
+#[derive(Debug)]
+pub enum GoldenError {
+    /// The golden file couldn't be read or written.
+    Io(PathBuf, std::io::Error),
+
+    /// The snapshot differs from the golden file.
+    Mismatch {
+        /// The golden file.
+        path: PathBuf,
+
+        /// The lines which differ, as given by [`diff`].
+        diff: String,
+    },
+}
+
+impl std::fmt::Display for GoldenError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GoldenError::Io(path, e) => format!("{}: {e}", path.display()).fmt(f),
+            GoldenError::Mismatch { path, diff } => format!(
+                "{} does not match (set {UPDATE_VAR}=1 to update it):\n{diff}",
+                path.display()
+            )
+            .fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for GoldenError {}
+
+/// Returns expressions of every kind, including each special atom, for rendering into a snapshot.
+///
+/// The expressions are left unsimplified, so that how each kind of node is written out is covered.
+/// New expressions are only ever added to the end, so that existing snapshots only grow.
+pub fn corpus() -> Vec<Notation> {
+    let atoms = [
+        Atom::from(0),
+        Atom::from(7),
+        Atom::from(-12),
+        Atom::Complex,
+        Atom::Undefined,
+        Atom::Huge,
+        Atom::NegativeHuge,
+        Atom::Epsilon,
+        Atom::NegativeEpsilon,
+        Atom::Pi,
+        Atom::E,
+    ];
+    let mut corpus: Vec<Notation> = atoms.into_iter().map(Notation::from).collect();
+    corpus.extend([
+        Notation::from(Fraction::new(1, 2)),
+        Notation::from(Fraction::new(-6, 4)),
+        Notation::from(Fraction {
+            num: Atom::Pi,
+            den: Atom::from(3),
+        }),
+        Notation::from(Radical::new(2)),
+        Notation::from(Radical { coef: -1, rad: 3 }),
+        Notation::from(Radical { coef: 2, rad: 12 }),
+        Notation::from(Radical { coef: 5, rad: 1 }),
+        Notation::from(RadicalFraction::new(1, 3, 2)),
+        Notation::from(RadicalFraction::new(-3, 5, 4)),
+        Notation::from(Multiple::new(2, 1, Atom::Pi)),
+        Notation::from(Multiple::new(-1, 2, Atom::E)),
+        Notation::from(Trig::new(TrigFunction::Sin, 30)),
+        Notation::from(Trig::new(TrigFunction::Cos, Fraction::new(1, 2))),
+        Notation::from(Trig::new(TrigFunction::Tan, Multiple::new(1, 4, Atom::Pi))),
+        Notation::from(Complex::new(1, 2)),
+        Notation::from(Complex::new(0, -1)),
+        Notation::from(Complex::new(Fraction::new(1, 2), Radical::new(3))),
+        Notation::from(Complex::new(3, Fraction::new(-1, 2))),
+        Notation::from(Sum::new([])),
+        Notation::from(Sum::new([
+            Notation::from(1),
+            Notation::from(Radical::new(2)),
+        ])),
+        Notation::from(Sum::new([
+            Notation::from(Atom::Pi),
+            Notation::from(-3),
+            Notation::from(Fraction::new(-1, 3)),
+        ])),
+        Notation::from(Complex::new(
+            1,
+            Sum::new([Notation::from(1), Notation::from(Radical::new(2))]),
+        )),
+    ]);
+    corpus
+}
+
+/// Renders each expression on its own line, after its s-expression and a tab.
+///
+/// ```
+/// # use algebra::{notation::{Notation, expr::fraction::Fraction}, testing::snapshot};
+/// let values = [Notation::from(Fraction::new(1, 2))];
+/// assert_eq!(snapshot(&values, |value| value.to_latex()), "(frac (num 1) (num 2))\t\\frac{1}{2}\n");
+/// ```
+pub fn snapshot(values: &[Notation], render: impl Fn(&Notation) -> String) -> String {
+    values
+        .iter()
+        .map(|value| format!("{}\t{}\n", value.to_sexpr(), render(value)))
+        .collect()
+}
+
+/// Lists the lines which differ between two snapshots, or returns [`None`] if they are the same.
+///
+/// Lines are compared by their position, with `-` before the expected line and `+` before the actual one.
+///
+/// ```
+/// # use algebra::testing::diff;
+/// assert_eq!(diff("a\nb\n", "a\nc\n"), Some("line 2:\n- b\n+ c\n".to_string()));
+/// assert_eq!(diff("a\n", "a\n"), None);
+/// ```
+pub fn diff(expected: &str, actual: &str) -> Option<String> {
+    let (expected, actual): (Vec<_>, Vec<_>) =
+        (expected.lines().collect(), actual.lines().collect());
+    let mut diff = String::new();
+    for i in 0..expected.len().max(actual.len()) {
+        let (old, new) = (expected.get(i), actual.get(i));
+        if old == new {
+            continue;
+        }
+        diff += &format!("line {}:\n", i + 1);
+        if let Some(old) = old {
+            diff += &format!("- {old}\n");
+        }
+        if let Some(new) = new {
+            diff += &format!("+ {new}\n");
+        }
+    }
+    (!diff.is_empty()).then_some(diff)
+}
+
+/// Compares the snapshot against the golden file at `path`,
+/// writing the file instead if it doesn't exist or [`UPDATE_VAR`] is set to `1`.
+pub fn check_golden(path: impl AsRef<Path>, actual: &str) -> Result<(), GoldenError> {
+    let path = path.as_ref();
+    let io_error = |e| GoldenError::Io(path.to_path_buf(), e);
+    let update = std::env::var(UPDATE_VAR).is_ok_and(|value| value == "1");
+    if update || !path.exists() {
+        if let Some(dir) = path.parent() {
+            std::fs::create_dir_all(dir).map_err(io_error)?;
+        }
+        return std::fs::write(path, actual).map_err(io_error);
+    }
+    let expected = std::fs::read_to_string(path).map_err(io_error)?;
+    match diff(&expected, actual) {
+        None => Ok(()),
+        Some(diff) => Err(GoldenError::Mismatch {
+            path: path.to_path_buf(),
+            diff,
+        }),
+    }
+}
+
+/// Asserts that the snapshot matches the golden file at `path`. See [`check_golden`].
+///
+/// # Panics
+/// Panics with the lines which differ if the snapshot doesn't match,
+/// or if the file couldn't be read or written.
+#[track_caller]
+pub fn assert_golden(path: impl AsRef<Path>, actual: &str) {
+    if let Err(e) = check_golden(path, actual) {
+        panic!("{e}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::notation::format::{FormatOptions, FormatWith};
+
+    fn golden(name: &str) -> PathBuf {
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/golden")
+            .join(name)
+    }
+
+    #[test]
+    fn test_display() {
+        assert_golden(
+            golden("display.txt"),
+            &snapshot(&corpus(), |value| value.to_string()),
+        );
+    }
+
+    #[test]
+    fn test_ascii() {
+        let options = FormatOptions::ascii();
+        assert_golden(
+            golden("ascii.txt"),
+            &snapshot(&corpus(), |value| value.format_with(&options)),
+        );
+    }
+
+    #[test]
+    fn test_latex() {
+        assert_golden(
+            golden("latex.txt"),
+            &snapshot(&corpus(), Notation::to_latex),
+        );
+    }
+
+    #[test]
+    fn test_corpus_keys_are_unique() {
+        let keys = corpus().iter().map(Notation::to_sexpr).collect::<Vec<_>>();
+        let unique = keys.iter().collect::<std::collections::HashSet<_>>();
+        assert_eq!(unique.len(), keys.len());
+    }
+
+    #[test]
+    fn test_mismatch() {
+        let path = std::env::temp_dir().join(format!("algebra-golden-{}.txt", std::process::id()));
+        std::fs::write(&path, "pi\tπ\ne\t𝑒\n").unwrap();
+        assert!(check_golden(&path, "pi\tπ\ne\t𝑒\n").is_ok());
+        let error = check_golden(&path, "pi\tpi\ne\t𝑒\nextra\n").unwrap_err();
+        std::fs::remove_file(&path).unwrap();
+        match error {
+            GoldenError::Mismatch { diff, .. } => {
+                assert_eq!(diff, "line 1:\n- pi\tπ\n+ pi\tpi\nline 3:\n+ extra\n")
+            }
+            e => panic!("unexpected error: {e}"),
+        }
+    }
+}
@@ -0,0 +1,204 @@
+//! Vectors and points with integer components.
+
+use crate::notation::{
+    atom::Atom,
+    expr::{radical::Radical, simplify::Simplify},
+    Notation,
+};
+
+/// A vector, or a point, with `N` integer components.
+///
+/// Lengths are exact: rather than a float, the magnitude is a simplified [`Radical`].
+///
+/// ```
+/// # use algebra::{notation::expr::radical::Radical, vector::Vec2};
+/// let v = Vec2::from([1, 1]);
+/// assert_eq!(v.magnitude(), Radical::new(2));
+///
+/// let v = Vec2::from([3, 4]);
+/// assert_eq!(v.magnitude(), 5);
+/// ```
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+pub struct VecN<const N: usize> {
+    /// The components along each axis.
+    pub components: [i32; N],
+}
+
+/// A two-dimensional vector or point.
+pub type Vec2 = VecN<2>;
+
+/// A three-dimensional vector or point.
+pub type Vec3 = VecN<3>;
+
+impl<const N: usize> From<[i32; N]> for VecN<N> {
+    fn from(components: [i32; N]) -> Self {
+        Self { components }
+    }
+}
+
+/// Converts a result which may be larger than an [`i32`] into [`Notation`], saturating to [`Huge`][Atom::Huge] or [`NegativeHuge`][Atom::NegativeHuge].
+fn saturate(value: i128) -> Notation {
+    match i32::try_from(value) {
+        Ok(value) => Notation::from(value),
+        Err(_) if value > 0 => Notation::from(Atom::Huge),
+        Err(_) => Notation::from(Atom::NegativeHuge),
+    }
+}
+
+impl<const N: usize> VecN<N> {
+    /// Constructs the vector whose components are all 0.
+    pub fn zero() -> Self {
+        Self { components: [0; N] }
+    }
+
+    /// Returns the dot product of two vectors: the sum of the products of their components.
+    ///
+    /// If the result overflows, returns [`Huge`][Atom::Huge] or [`NegativeHuge`][Atom::NegativeHuge].
+    pub fn dot(&self, other: &Self) -> Notation {
+        saturate(
+            self.components
+                .iter()
+                .zip(other.components)
+                .map(|(&a, b)| a as i128 * b as i128)
+                .sum(),
+        )
+    }
+
+    /// Returns the square of the vector's length.
+    ///
+    /// If the result overflows, returns [`Huge`][Atom::Huge].
+    pub fn magnitude_squared(&self) -> Notation {
+        self.dot(self)
+    }
+
+    /// Returns the length of the vector as a simplified [`Radical`] (or integer, when the length is whole).
+    ///
+    /// If the square of the length overflows, returns [`Huge`][Atom::Huge].
+    pub fn magnitude(&self) -> Notation {
+        Self::zero().distance(self)
+    }
+
+    /// Returns the distance between two points.
+    ///
+    /// If the square of the distance overflows, returns [`Huge`][Atom::Huge].
+    pub fn distance(&self, other: &Self) -> Notation {
+        let square: i128 = self
+            .components
+            .iter()
+            .zip(other.components)
+            .map(|(&a, b)| {
+                let diff = b as i128 - a as i128;
+                diff * diff
+            })
+            .sum();
+        match saturate(square) {
+            Notation::Atom(Atom::Number(n)) => Radical::new(n.value).simplify(),
+            huge => huge,
+        }
+    }
+}
+
+impl<const N: usize> std::ops::Add for VecN<N> {
+    type Output = Self;
+
+    /// Add two vectors component by component.
+    fn add(self, rhs: Self) -> Self::Output {
+        let mut components = self.components;
+        for (a, b) in components.iter_mut().zip(rhs.components) {
+            *a += b;
+        }
+        Self { components }
+    }
+}
+
+impl<const N: usize> std::ops::Sub for VecN<N> {
+    type Output = Self;
+
+    /// Subtract two vectors component by component.
+    fn sub(self, rhs: Self) -> Self::Output {
+        let mut components = self.components;
+        for (a, b) in components.iter_mut().zip(rhs.components) {
+            *a -= b;
+        }
+        Self { components }
+    }
+}
+
+impl<const N: usize> std::ops::Neg for VecN<N> {
+    type Output = Self;
+
+    fn neg(self) -> Self::Output {
+        Self {
+            components: self.components.map(|x| -x),
+        }
+    }
+}
+
+impl<const N: usize> std::ops::Mul<i32> for VecN<N> {
+    type Output = Self;
+
+    /// Scale every component of the vector.
+    fn mul(self, rhs: i32) -> Self::Output {
+        Self {
+            components: self.components.map(|x| x * rhs),
+        }
+    }
+}
+
+impl<const N: usize> std::fmt::Display for VecN<N> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let components = self
+            .components
+            .iter()
+            .map(|x| x.to_string())
+            .collect::<Vec<_>>();
+        format!("({})", components.join(", ")).fmt(f)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_dot() {
+        let a = Vec3::from([1, 2, 3]);
+        let b = Vec3::from([4, -5, 6]);
+        assert_eq!(a.dot(&b), 12);
+        assert_eq!(Vec2::from([1, 0]).dot(&Vec2::from([0, 1])), 0);
+    }
+
+    #[test]
+    fn test_dot_overflow() {
+        let big = Vec2::from([i32::MAX, i32::MAX]);
+        assert!(big.dot(&big).atom().is_some_and(|x| x.is_positive_huge()));
+        assert!(big.dot(&-big).atom().is_some_and(|x| x.is_negative_huge()));
+    }
+
+    #[test]
+    fn test_magnitude() {
+        assert_eq!(Vec2::zero().magnitude(), 0);
+        assert_eq!(Vec2::from([0, -7]).magnitude(), 7);
+        assert_eq!(Vec2::from([2, 2]).magnitude(), Radical { coef: 2, rad: 2 });
+        assert_eq!(Vec3::from([1, 1, 1]).magnitude(), Radical::new(3));
+    }
+
+    #[test]
+    fn test_distance() {
+        let a = Vec2::from([1, 2]);
+        let b = Vec2::from([4, 6]);
+        assert_eq!(a.distance(&b), 5);
+        assert_eq!(b.distance(&a), 5);
+        assert_eq!(a.distance(&Vec2::from([2, 3])), Radical::new(2));
+    }
+
+    #[test]
+    fn test_arithmetic() {
+        let a = Vec2::from([1, 2]);
+        let b = Vec2::from([3, -1]);
+        assert_eq!(a + b, Vec2::from([4, 1]));
+        assert_eq!(a - b, Vec2::from([-2, 3]));
+        assert_eq!(a * 3, Vec2::from([3, 6]));
+        assert_eq!((a * 3).to_string(), "(3, 6)");
+    }
+}
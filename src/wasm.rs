@@ -0,0 +1,124 @@
+//! JavaScript bindings, for running the crate in a browser through WebAssembly.
+//!
+//! Enabled by the `wasm` feature. Notation is passed to and from JavaScript as JSON,
+//! in the shape given by its [`Serialize`][serde::Serialize] implementation:
+//!
+//! ```
+//! # use algebra::notation::{Notation, expr::fraction::Fraction};
+//! let json = serde_json::to_string(&Notation::from(Fraction::new(1, 2))).unwrap();
+//! assert_eq!(json, r#"{"Expr":{"Fraction":{"num":{"Number":{"value":1}},"den":{"Number":{"value":2}}}}}"#);
+//! ```
+
+use crate::notation::{expr::simplify::Simplify, parse::ParseError, Notation};
+use wasm_bindgen::prelude::*;
+
+/// Reasons a binding could not produce a result.
+#[derive(Debug)]
+pub enum BindingError {
+    /// The text given to [`parse`] was not notation.
+    Parse(ParseError),
+
+    /// The JSON given was not notation.
+    Json(serde_json::Error),
+
+    /// The notation has no real value, such as [`Undefined`][crate::notation::atom::Atom::Undefined].
+    NotReal(Notation),
+}
+
+impl std::fmt::Display for BindingError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        use BindingError::*;
+        match self {
+            Parse(e) => e.fmt(f),
+            Json(e) => format!("invalid notation JSON: {e}").fmt(f),
+            NotReal(value) => format!("{value} has no real value").fmt(f),
+        }
+    }
+}
+
+impl std::error::Error for BindingError {}
+
+impl From<ParseError> for BindingError {
+    fn from(e: ParseError) -> Self {
+        Self::Parse(e)
+    }
+}
+
+impl From<serde_json::Error> for BindingError {
+    fn from(e: serde_json::Error) -> Self {
+        Self::Json(e)
+    }
+}
+
+fn from_json(json: &str) -> Result<Notation, BindingError> {
+    Ok(serde_json::from_str(json)?)
+}
+
+fn to_json(value: &Notation) -> Result<String, BindingError> {
+    Ok(serde_json::to_string(value)?)
+}
+
+fn parse_json(input: &str) -> Result<String, BindingError> {
+    to_json(&input.parse()?)
+}
+
+fn simplify_json(json: &str) -> Result<String, BindingError> {
+    to_json(&from_json(json)?.simplify())
+}
+
+fn to_latex_json(json: &str) -> Result<String, BindingError> {
+    Ok(from_json(json)?.to_latex())
+}
+
+fn eval_json(json: &str) -> Result<f64, BindingError> {
+    let value = from_json(json)?;
+    value.eval_f64().ok_or(BindingError::NotReal(value))
+}
+
+/// Reads notation from text such as `"3√2/4"`, returning it as JSON.
+#[wasm_bindgen]
+pub fn parse(input: &str) -> Result<String, JsError> {
+    Ok(parse_json(input)?)
+}
+
+/// Simplifies notation given as JSON, returning the simplified notation as JSON.
+#[wasm_bindgen]
+pub fn simplify(json: &str) -> Result<String, JsError> {
+    Ok(simplify_json(json)?)
+}
+
+/// Writes notation given as JSON as LaTeX.
+#[wasm_bindgen]
+pub fn to_latex(json: &str) -> Result<String, JsError> {
+    Ok(to_latex_json(json)?)
+}
+
+/// Approximates notation given as JSON as a decimal number.
+#[wasm_bindgen]
+pub fn eval(json: &str) -> Result<f64, JsError> {
+    Ok(eval_json(json)?)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_round_trip() {
+        let json = parse_json("6/8").unwrap();
+        let simplified = simplify_json(&json).unwrap();
+        assert_eq!(to_latex_json(&simplified).unwrap(), "\\frac{3}{4}");
+        assert_eq!(eval_json(&simplified).unwrap(), 0.75);
+    }
+
+    #[test]
+    fn test_errors() {
+        assert!(matches!(parse_json("1/"), Err(BindingError::Parse(_))));
+        assert!(matches!(simplify_json("{}"), Err(BindingError::Json(_))));
+        let undefined = parse_json("∅").unwrap();
+        assert!(matches!(
+            eval_json(&undefined),
+            Err(BindingError::NotReal(_))
+        ));
+    }
+}